@@ -2,8 +2,9 @@ use std::{
     any,
     fs::File,
     io::BufReader,
-    net::{IpAddr, TcpListener},
+    net::{IpAddr, Ipv4Addr, Ipv6Addr, TcpListener, UdpSocket},
     path::PathBuf,
+    time::Duration,
 };
 
 use amethyst::{
@@ -11,15 +12,25 @@ use amethyst::{
     Application, Error, GameDataBuilder, LoggerConfig, SimpleState,
 };
 use frame_rate::strategy::frame_rate_limit_config;
-use net_play::{NetListenerSystem, NetListenerSystemDesc};
+use log::warn;
+use net_model::play::{NetDiscoveryEvent, NetProtocolVersion, NetServerAnnouncement};
+use net_play::{
+    NetDiscoveryResponderSystem, NetHandshakeResponderSystem, NetHandshakeResponderSystemDesc,
+    NetListenerSystem, NetListenerSystemDesc,
+};
 use structopt::StructOpt;
 
-use crate::system::{
-    NetworkInputResponderSystem, NetworkInputResponderSystemDesc,
-    SessionDeviceDisconnectResponderSystem, SessionDeviceDisconnectResponderSystemDesc,
-    SessionHostResponderSystem, SessionHostResponderSystemDesc, SessionJoinResponderSystem,
-    SessionJoinResponderSystemDesc, SessionLobbyResponderSystem, SessionLobbyResponderSystemDesc,
-    SessionMessageResponderSystem, SessionMessageResponderSystemDesc,
+use crate::{
+    model::{SessionLimits, SessionReconnectConfig},
+    system::{
+        NetworkInputResponderSystem, NetworkInputResponderSystemDesc, SessionBrowseResponderSystem,
+        SessionBrowseResponderSystemDesc, SessionDeviceDisconnectResponderSystem,
+        SessionDeviceDisconnectResponderSystemDesc, SessionDeviceReservationExpirySystem,
+        SessionHostResponderSystem, SessionHostResponderSystemDesc, SessionJoinResponderSystem,
+        SessionJoinResponderSystemDesc, SessionLobbyResponderSystem,
+        SessionLobbyResponderSystemDesc, SessionMessageResponderSystem,
+        SessionMessageResponderSystemDesc,
+    },
 };
 
 pub mod model;
@@ -46,11 +57,23 @@ pub struct Opt {
     frame_rate: Option<u32>,
 
     /// Address to bind to.
+    ///
+    /// Use `0.0.0.0` to accept connections from, and be discoverable on, the local network.
     #[structopt(long, default_value = "127.0.0.1")]
     address: IpAddr,
     /// Port that the session server is listening on.
     #[structopt(long, default_value = "1234")]
     port: u16,
+
+    /// Maximum number of devices in a session, including spectators.
+    #[structopt(long, default_value = "8")]
+    max_devices: usize,
+    /// Maximum number of player controllers across all devices in a session.
+    #[structopt(long, default_value = "8")]
+    max_controllers: usize,
+    /// Number of seconds to keep a disconnected device's place in a started session.
+    #[structopt(long, default_value = "30")]
+    reconnect_grace_period: u64,
 }
 
 fn logger_setup(logger_config_path: Option<PathBuf>) -> Result<(), Error> {
@@ -89,6 +112,24 @@ fn logger_setup(logger_config_path: Option<PathBuf>) -> Result<(), Error> {
     Ok(())
 }
 
+/// Returns the socket to receive session server discovery probes on.
+///
+/// When the session server is bound to a loopback address, probes are only received from the same
+/// machine. Otherwise, probes broadcast on the local network are received.
+fn discovery_socket(address: IpAddr) -> std::io::Result<UdpSocket> {
+    let discovery_address = if address.is_loopback() {
+        address
+    } else if address.is_ipv4() {
+        IpAddr::V4(Ipv4Addr::UNSPECIFIED)
+    } else {
+        IpAddr::V6(Ipv6Addr::UNSPECIFIED)
+    };
+    let udp_socket = UdpSocket::bind((discovery_address, NetDiscoveryEvent::PORT))?;
+    udp_socket.set_nonblocking(true)?;
+
+    Ok(udp_socket)
+}
+
 fn main() -> Result<(), Error> {
     let opt = Opt::from_args();
 
@@ -98,23 +139,45 @@ fn main() -> Result<(), Error> {
     tcp_listener.set_nonblocking(true)?;
 
     let assets_dir = application_root_dir()?.join("./");
+    let session_limits = SessionLimits::new(opt.max_devices, opt.max_controllers);
+    let session_reconnect_config =
+        SessionReconnectConfig::new(Duration::from_secs(opt.reconnect_grace_period));
 
-    let game_data = GameDataBuilder::default()
+    let mut game_data = GameDataBuilder::default()
         .with_bundle(WebSocketNetworkBundle::new(Some(tcp_listener)))?
         .with_system_desc(
             NetListenerSystemDesc::default(),
             any::type_name::<NetListenerSystem>(),
             &["network_recv"],
         )
+        .with_system_desc(
+            NetHandshakeResponderSystemDesc::default(),
+            any::type_name::<NetHandshakeResponderSystem>(),
+            &[any::type_name::<NetListenerSystem>()],
+        )
         .with_system_desc(
             SessionHostResponderSystemDesc::default(),
             any::type_name::<SessionHostResponderSystem>(),
-            &[any::type_name::<NetListenerSystem>()],
+            &[
+                any::type_name::<NetListenerSystem>(),
+                any::type_name::<NetHandshakeResponderSystem>(),
+            ],
         )
         .with_system_desc(
             SessionJoinResponderSystemDesc::default(),
             any::type_name::<SessionJoinResponderSystem>(),
-            &[any::type_name::<NetListenerSystem>()],
+            &[
+                any::type_name::<NetListenerSystem>(),
+                any::type_name::<NetHandshakeResponderSystem>(),
+            ],
+        )
+        .with_system_desc(
+            SessionBrowseResponderSystemDesc::default(),
+            any::type_name::<SessionBrowseResponderSystem>(),
+            &[
+                any::type_name::<NetListenerSystem>(),
+                any::type_name::<NetHandshakeResponderSystem>(),
+            ],
         )
         .with_system_desc(
             SessionLobbyResponderSystemDesc::default(),
@@ -137,11 +200,38 @@ fn main() -> Result<(), Error> {
         .with_system_desc(
             SessionDeviceDisconnectResponderSystemDesc::default(),
             any::type_name::<SessionDeviceDisconnectResponderSystem>(),
-            &[any::type_name::<NetListenerSystem>()],
+            &[
+                any::type_name::<NetListenerSystem>(),
+                any::type_name::<SessionJoinResponderSystem>(),
+                any::type_name::<SessionMessageResponderSystem>(),
+            ],
+        )
+        .with(
+            SessionDeviceReservationExpirySystem::new(),
+            any::type_name::<SessionDeviceReservationExpirySystem>(),
+            &[any::type_name::<SessionDeviceDisconnectResponderSystem>()],
         );
 
+    match discovery_socket(opt.address) {
+        Ok(udp_socket) => {
+            let net_server_announcement =
+                NetServerAnnouncement::new(opt.port, NetProtocolVersion::CURRENT);
+            game_data = game_data.with(
+                NetDiscoveryResponderSystem::new(udp_socket, net_server_announcement),
+                any::type_name::<NetDiscoveryResponderSystem>(),
+                &[],
+            );
+        }
+        Err(e) => warn!(
+            "Failed to bind socket for session server discovery, clients will need the server address. Error: `{}`.",
+            e
+        ),
+    }
+
     let mut game = Application::build(assets_dir, RunState)?
         .with_frame_limit_config(frame_rate_limit_config(opt.frame_rate))
+        .with_resource(session_limits)
+        .with_resource(session_reconnect_config)
         .build(game_data)?;
     game.run();
 
@@ -2,20 +2,50 @@
 
 pub use self::{
     game_input_tick_status::GameInputTickStatus,
+    session_character_selection_statuses::SessionCharacterSelectionStatuses,
+    session_checksum_status::SessionChecksumStatus,
+    session_checksum_statuses::SessionChecksumStatuses,
     session_code_id::SessionCodeId,
     session_code_to_id::SessionCodeToId,
+    session_device_key::SessionDeviceKey,
     session_device_mappings::{SessionDeviceMappings, SessionDeviceMappingsRead},
+    session_device_reservation::SessionDeviceReservation,
+    session_device_reservations::SessionDeviceReservations,
     session_device_tick_statuses::SessionDeviceTickStatuses,
     session_id_to_device_mappings::SessionIdToDeviceMappings,
+    session_input_histories::SessionInputHistories,
+    session_limits::SessionLimits,
+    session_public_ids::SessionPublicIds,
+    session_ready_statuses::SessionReadyStatuses,
+    session_reconnect_config::SessionReconnectConfig,
+    session_reconnect_tokens::SessionReconnectTokens,
+    session_spectators::SessionSpectators,
+    session_tick_confirm_status::SessionTickConfirmStatus,
+    session_tick_confirm_statuses::SessionTickConfirmStatuses,
     session_tick_statuses::SessionTickStatuses,
     socket_to_device_id::SocketToDeviceId,
 };
 
 mod game_input_tick_status;
+mod session_character_selection_statuses;
+mod session_checksum_status;
+mod session_checksum_statuses;
 mod session_code_id;
 mod session_code_to_id;
+mod session_device_key;
 mod session_device_mappings;
+mod session_device_reservation;
+mod session_device_reservations;
 mod session_device_tick_statuses;
 mod session_id_to_device_mappings;
+mod session_input_histories;
+mod session_limits;
+mod session_public_ids;
+mod session_ready_statuses;
+mod session_reconnect_config;
+mod session_reconnect_tokens;
+mod session_spectators;
+mod session_tick_confirm_status;
+mod session_tick_confirm_statuses;
 mod session_tick_statuses;
 mod socket_to_device_id;
@@ -0,0 +1,13 @@
+use std::collections::{HashMap, HashSet};
+
+use derive_deref::{Deref, DerefMut};
+use derive_new::new;
+use network_session_model::play::SessionDeviceId;
+
+use crate::model::SessionCodeId;
+
+/// Tracks which devices in each started `Session` have sent their character selections.
+///
+/// `HashMap<SessionCodeId, HashSet<SessionDeviceId>>` newtype.
+#[derive(Clone, Debug, Default, Deref, DerefMut, PartialEq, new)]
+pub struct SessionCharacterSelectionStatuses(pub HashMap<SessionCodeId, HashSet<SessionDeviceId>>);
@@ -0,0 +1,97 @@
+use std::collections::{BTreeMap, HashMap};
+
+use derive_new::new;
+use network_session_model::play::{
+    SessionChecksum, SessionDesync, SessionDeviceId, SessionTickCount,
+};
+
+/// Checksums received from the devices in a started `Session`, awaiting comparison.
+#[derive(Clone, Debug, Default, PartialEq, new)]
+pub struct SessionChecksumStatus {
+    /// Checksums received for each session tick, by device.
+    #[new(default)]
+    pub session_checksums: BTreeMap<SessionTickCount, HashMap<SessionDeviceId, SessionChecksum>>,
+    /// Last session tick whose checksums have been compared.
+    #[new(default)]
+    pub session_tick_count_compared: Option<SessionTickCount>,
+    /// Whether the devices' game play state has diverged.
+    ///
+    /// Checksums are no longer compared once this is set, so that only the first diverging tick
+    /// is reported.
+    #[new(default)]
+    pub desynced: bool,
+}
+
+impl SessionChecksumStatus {
+    /// Records a device's checksum, and compares the tick's checksums once all devices have sent
+    /// theirs.
+    ///
+    /// Returns the `SessionDesync` if the checksums differ.
+    ///
+    /// # Parameters
+    ///
+    /// * `session_device_ids`: IDs of the devices whose checksums are compared.
+    /// * `session_device_id`: ID of the device that sent the checksum.
+    /// * `session_checksum`: The checksum that was sent.
+    pub fn insert(
+        &mut self,
+        session_device_ids: &[SessionDeviceId],
+        session_device_id: SessionDeviceId,
+        session_checksum: SessionChecksum,
+    ) -> Option<SessionDesync> {
+        let session_tick_count = session_checksum.session_tick_count;
+        let tick_compared = self
+            .session_tick_count_compared
+            .map(|session_tick_count_compared| session_tick_count <= session_tick_count_compared)
+            .unwrap_or(false);
+        if self.desynced || tick_compared {
+            return None;
+        }
+
+        let session_device_checksums = self
+            .session_checksums
+            .entry(session_tick_count)
+            .or_insert_with(HashMap::new);
+        session_device_checksums.insert(session_device_id, session_checksum);
+
+        let all_received = session_device_ids
+            .iter()
+            .all(|session_device_id| session_device_checksums.contains_key(session_device_id));
+        if !all_received {
+            return None;
+        }
+
+        // Checksums for earlier ticks that are still pending will never be complete.
+        let session_checksums_later = self
+            .session_checksums
+            .split_off(&SessionTickCount(session_tick_count.0 + 1));
+        let session_device_checksums =
+            std::mem::replace(&mut self.session_checksums, session_checksums_later)
+                .remove(&session_tick_count)
+                .unwrap_or_default();
+        self.session_tick_count_compared = Some(session_tick_count);
+
+        let session_device_id_reference = session_device_ids.iter().copied().min()?;
+        let session_checksum_reference =
+            session_device_checksums.get(&session_device_id_reference)?;
+        let session_checksums = session_device_ids
+            .iter()
+            .copied()
+            .filter(|session_device_id| *session_device_id != session_device_id_reference)
+            .filter_map(|session_device_id| {
+                session_device_checksums
+                    .get(&session_device_id)
+                    .map(|session_checksum| (session_device_id, session_checksum))
+            });
+
+        let session_desync = SessionDesync::detect(
+            (session_device_id_reference, session_checksum_reference),
+            session_checksums,
+        );
+        if session_desync.is_some() {
+            self.desynced = true;
+        }
+
+        session_desync
+    }
+}
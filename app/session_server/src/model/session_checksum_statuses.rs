@@ -0,0 +1,12 @@
+use std::collections::HashMap;
+
+use derive_deref::{Deref, DerefMut};
+use derive_new::new;
+
+use crate::model::{SessionChecksumStatus, SessionCodeId};
+
+/// Tracks the `SessionChecksumStatus` for each started `Session`.
+///
+/// `HashMap<SessionCodeId, SessionChecksumStatus>` newtype.
+#[derive(Clone, Debug, Default, Deref, DerefMut, PartialEq, new)]
+pub struct SessionChecksumStatuses(pub HashMap<SessionCodeId, SessionChecksumStatus>);
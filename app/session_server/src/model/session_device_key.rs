@@ -0,0 +1,16 @@
+use derive_new::new;
+use network_session_model::play::SessionDeviceId;
+
+use crate::model::SessionCodeId;
+
+/// Identifies a device within a session.
+///
+/// `SessionDeviceId`s are only unique within a session, so they are paired with the
+/// `SessionCodeId`.
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq, new)]
+pub struct SessionDeviceKey {
+    /// ID of the session.
+    pub session_code_id: SessionCodeId,
+    /// ID of the device within the session.
+    pub session_device_id: SessionDeviceId,
+}
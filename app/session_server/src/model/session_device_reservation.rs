@@ -0,0 +1,13 @@
+use std::time::Instant;
+
+use derive_new::new;
+use network_session_model::play::SessionDevice;
+
+/// Place in a started session that is kept for a disconnected device.
+#[derive(Clone, Debug, PartialEq, new)]
+pub struct SessionDeviceReservation {
+    /// The disconnected device.
+    pub session_device: SessionDevice,
+    /// When the device disconnected.
+    pub disconnected_at: Instant,
+}
@@ -0,0 +1,27 @@
+use std::collections::HashMap;
+
+use derive_deref::{Deref, DerefMut};
+use derive_new::new;
+
+use crate::model::{SessionCodeId, SessionDeviceKey, SessionDeviceReservation};
+
+/// Tracks the `SessionDeviceReservation`s for devices that may rejoin their session.
+///
+/// `HashMap<SessionDeviceKey, SessionDeviceReservation>` newtype.
+#[derive(Clone, Debug, Default, Deref, DerefMut, PartialEq, new)]
+pub struct SessionDeviceReservations(pub HashMap<SessionDeviceKey, SessionDeviceReservation>);
+
+impl SessionDeviceReservations {
+    /// Returns whether any device in the given session has a reservation.
+    pub fn contains_session(&self, session_code_id: SessionCodeId) -> bool {
+        self.0
+            .keys()
+            .any(|session_device_key| session_device_key.session_code_id == session_code_id)
+    }
+
+    /// Removes the reservations for all devices in the given session.
+    pub fn remove_session(&mut self, session_code_id: SessionCodeId) {
+        self.0
+            .retain(|session_device_key, _| session_device_key.session_code_id != session_code_id);
+    }
+}
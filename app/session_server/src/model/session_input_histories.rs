@@ -0,0 +1,13 @@
+use std::collections::HashMap;
+
+use derive_deref::{Deref, DerefMut};
+use derive_new::new;
+use rollback_model::play::SessionInputHistory;
+
+use crate::model::SessionCodeId;
+
+/// Tracks the `SessionInputHistory` for each started `Session`.
+///
+/// `HashMap<SessionCodeId, SessionInputHistory>` newtype.
+#[derive(Clone, Debug, Default, Deref, DerefMut, PartialEq, new)]
+pub struct SessionInputHistories(pub HashMap<SessionCodeId, SessionInputHistory>);
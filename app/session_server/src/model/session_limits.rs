@@ -0,0 +1,10 @@
+use derive_new::new;
+
+/// Maximum number of devices and controllers that may be in a session.
+#[derive(Clone, Copy, Debug, PartialEq, new)]
+pub struct SessionLimits {
+    /// Maximum number of devices in a session, including spectators.
+    pub devices_max: usize,
+    /// Maximum number of player controllers across all devices in a session.
+    pub controllers_max: usize,
+}
@@ -0,0 +1,12 @@
+use std::collections::HashSet;
+
+use derive_deref::{Deref, DerefMut};
+use derive_new::new;
+
+use crate::model::SessionCodeId;
+
+/// Sessions that are listed in the session browser.
+///
+/// `HashSet<SessionCodeId>` newtype.
+#[derive(Clone, Debug, Default, Deref, DerefMut, PartialEq, new)]
+pub struct SessionPublicIds(pub HashSet<SessionCodeId>);
@@ -0,0 +1,13 @@
+use std::collections::HashMap;
+
+use derive_deref::{Deref, DerefMut};
+use derive_new::new;
+use session_lobby_model::play::SessionDeviceReadies;
+
+use crate::model::SessionCodeId;
+
+/// Tracks the `SessionDeviceReadies` for each `Session` that has not started.
+///
+/// `HashMap<SessionCodeId, SessionDeviceReadies>` newtype.
+#[derive(Clone, Debug, Default, Deref, DerefMut, PartialEq, new)]
+pub struct SessionReadyStatuses(pub HashMap<SessionCodeId, SessionDeviceReadies>);
@@ -0,0 +1,10 @@
+use std::time::Duration;
+
+use derive_new::new;
+
+/// Configuration for devices rejoining a started session.
+#[derive(Clone, Copy, Debug, PartialEq, new)]
+pub struct SessionReconnectConfig {
+    /// Duration to keep a disconnected device's place in its session.
+    pub grace_period: Duration,
+}
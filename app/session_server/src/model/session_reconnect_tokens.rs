@@ -0,0 +1,21 @@
+use std::collections::HashMap;
+
+use derive_deref::{Deref, DerefMut};
+use derive_new::new;
+use network_session_model::play::SessionReconnectToken;
+
+use crate::model::{SessionCodeId, SessionDeviceKey};
+
+/// Tracks the `SessionReconnectToken` issued to each session device.
+///
+/// `HashMap<SessionDeviceKey, SessionReconnectToken>` newtype.
+#[derive(Clone, Debug, Default, Deref, DerefMut, PartialEq, new)]
+pub struct SessionReconnectTokens(pub HashMap<SessionDeviceKey, SessionReconnectToken>);
+
+impl SessionReconnectTokens {
+    /// Removes the tokens for all devices in the given session.
+    pub fn remove_session(&mut self, session_code_id: SessionCodeId) {
+        self.0
+            .retain(|session_device_key, _| session_device_key.session_code_id != session_code_id);
+    }
+}
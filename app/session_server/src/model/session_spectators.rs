@@ -0,0 +1,12 @@
+use std::{collections::HashSet, net::SocketAddr};
+
+use derive_deref::{Deref, DerefMut};
+use derive_new::new;
+
+/// Tracks the `SocketAddr`s of devices that spectate a `Session`.
+///
+/// Spectators receive the session's input, but do not contribute any.
+///
+/// `HashSet<SocketAddr>` newtype.
+#[derive(Clone, Debug, Default, Deref, DerefMut, PartialEq, new)]
+pub struct SessionSpectators(pub HashSet<SocketAddr>);
@@ -0,0 +1,63 @@
+use std::collections::HashMap;
+
+use derive_new::new;
+use network_session_model::play::SessionDeviceId;
+use rollback_model::play::{RollbackTick, TickedGameInputEvent};
+
+/// Rollback ticks confirmed by the devices in a started `Session`, and the input that has not been
+/// sent to spectators.
+#[derive(Clone, Debug, Default, PartialEq, new)]
+pub struct SessionTickConfirmStatus {
+    /// Latest tick confirmed by each device.
+    #[new(default)]
+    pub device_ticks_confirmed: HashMap<SessionDeviceId, RollbackTick>,
+    /// `TickedGameInputEvent`s of ticks that have not been confirmed by every device.
+    #[new(default)]
+    pub ticked_game_input_events_unconfirmed: Vec<TickedGameInputEvent>,
+}
+
+impl SessionTickConfirmStatus {
+    /// Records input to send to spectators once its tick is confirmed.
+    pub fn push(&mut self, ticked_game_input_event: TickedGameInputEvent) {
+        self.ticked_game_input_events_unconfirmed
+            .push(ticked_game_input_event);
+    }
+
+    /// Records a device's confirmed tick, and returns the latest tick that every device has
+    /// confirmed.
+    ///
+    /// Returns `None` if any device has not confirmed a tick.
+    ///
+    /// # Parameters
+    ///
+    /// * `session_device_ids`: IDs of the devices that play in the session.
+    /// * `session_device_id`: ID of the device that confirmed the tick.
+    /// * `tick`: The tick that was confirmed.
+    pub fn confirm(
+        &mut self,
+        session_device_ids: &[SessionDeviceId],
+        session_device_id: SessionDeviceId,
+        tick: RollbackTick,
+    ) -> Option<RollbackTick> {
+        self.device_ticks_confirmed.insert(session_device_id, tick);
+
+        session_device_ids
+            .iter()
+            .map(|session_device_id| self.device_ticks_confirmed.get(session_device_id).copied())
+            .collect::<Option<Vec<RollbackTick>>>()
+            .and_then(|ticks_confirmed| ticks_confirmed.into_iter().min())
+    }
+
+    /// Removes and returns the input of ticks up to and including the given tick.
+    pub fn take_confirmed(&mut self, tick_confirmed: RollbackTick) -> Vec<TickedGameInputEvent> {
+        let (ticked_game_input_events_confirmed, ticked_game_input_events_unconfirmed) =
+            std::mem::take(&mut self.ticked_game_input_events_unconfirmed)
+                .into_iter()
+                .partition(|ticked_game_input_event| {
+                    ticked_game_input_event.tick <= tick_confirmed
+                });
+        self.ticked_game_input_events_unconfirmed = ticked_game_input_events_unconfirmed;
+
+        ticked_game_input_events_confirmed
+    }
+}
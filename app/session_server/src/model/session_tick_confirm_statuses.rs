@@ -0,0 +1,12 @@
+use std::collections::HashMap;
+
+use derive_deref::{Deref, DerefMut};
+use derive_new::new;
+
+use crate::model::{SessionCodeId, SessionTickConfirmStatus};
+
+/// Tracks the `SessionTickConfirmStatus` for each started `Session`.
+///
+/// `HashMap<SessionCodeId, SessionTickConfirmStatus>` newtype.
+#[derive(Clone, Debug, Default, Deref, DerefMut, PartialEq, new)]
+pub struct SessionTickConfirmStatuses(pub HashMap<SessionCodeId, SessionTickConfirmStatus>);
@@ -7,13 +7,16 @@ use game_input_model::{
 use log::debug;
 use net_model::play::{NetSessionDevice, NetSessionDevices};
 use network_session_model::play::{
-    Session, SessionCode, SessionDevice, SessionDeviceId, SessionDevices, Sessions,
+    Session, SessionCode, SessionDevice, SessionDeviceId, SessionDeviceName, SessionDevices,
+    Sessions,
 };
 use network_session_play::SessionCodeGenerator;
 use session_host_model::play::SessionHostRequestParams;
-use session_join_model::play::{SessionJoinError, SessionJoinRequestParams};
+use session_join_model::play::{
+    SessionJoinError, SessionJoinRequestParams, SessionSpectateRequestParams,
+};
 
-use crate::model::SessionDeviceMappings;
+use crate::model::{SessionDeviceMappings, SessionLimits};
 
 /// Updates tracking data for sessions.
 #[derive(Debug)]
@@ -41,10 +44,11 @@ impl<'s> SessionTracker<'s> {
         let SessionHostRequestParams {
             session_device_name,
             player_controllers,
+            ..
         } = session_host_request_params;
 
         let session_code = self.generate_session_code(session_code_generator);
-        let session_device_id = SessionDeviceId::new(0); // ID for host
+        let session_device_id = SessionDeviceId::HOST;
         let session_device = SessionDevice::new(
             session_device_id,
             session_device_name.clone(),
@@ -67,10 +71,20 @@ impl<'s> SessionTracker<'s> {
         (session, session_device_id, player_controllers.clone())
     }
 
+    /// Adds a device to an existing session.
+    ///
+    /// # Parameters
+    ///
+    /// * `socket_addr`: `SocketAddr` of the session device.
+    /// * `session_join_request_params`: Parameters from the session join request.
+    /// * `session_limits`: Maximum number of devices and controllers in a session.
+    /// * `session_started`: Whether the session has started game play.
     pub fn append_device(
         &mut self,
         socket_addr: SocketAddr,
         session_join_request_params: &SessionJoinRequestParams,
+        session_limits: SessionLimits,
+        session_started: bool,
     ) -> Result<
         (
             Session,
@@ -87,13 +101,13 @@ impl<'s> SessionTracker<'s> {
         } = session_join_request_params;
 
         if let Some(session) = self.sessions.get_mut(session_code) {
-            let session_device_id = session
-                .session_devices
-                .iter()
-                .map(|session_device| session_device.id)
-                .max()
-                .map(|session_device_id| SessionDeviceId::new(*session_device_id + 1))
-                .unwrap_or_else(|| SessionDeviceId::new(0));
+            if session_started {
+                return Err(SessionJoinError::AlreadyInGame);
+            }
+            Self::session_device_name_check(session, session_device_name)?;
+            Self::session_capacity_check(session, session_limits, player_controllers.len())?;
+
+            let session_device_id = Self::session_device_id_next(session);
 
             // Mutate the `ControllerId`s on the `player_controllers`.
             let controller_id_offset: ControllerIdOffset = ControllerIdOffset::new(
@@ -125,13 +139,7 @@ impl<'s> SessionTracker<'s> {
                 session_code, session_device.name, session_device.id
             );
 
-            // Compute combined player controllers
-            let player_controllers_all = session
-                .session_devices
-                .iter()
-                .flat_map(|session_device| session_device.player_controllers.iter().cloned())
-                .collect::<Vec<PlayerController>>();
-            let player_controllers_all = PlayerControllers::new(player_controllers_all);
+            let player_controllers_all = Self::player_controllers_all(session);
 
             Ok((
                 session.clone(),
@@ -144,6 +152,88 @@ impl<'s> SessionTracker<'s> {
         }
     }
 
+    /// Adds a spectating device to an existing session.
+    ///
+    /// Spectators have no player controllers, so the session's player controllers are unchanged.
+    ///
+    /// # Parameters
+    ///
+    /// * `socket_addr`: `SocketAddr` of the spectator.
+    /// * `session_spectate_request_params`: Parameters from the session spectate request.
+    /// * `session_limits`: Maximum number of devices and controllers in a session.
+    pub fn append_spectator(
+        &mut self,
+        socket_addr: SocketAddr,
+        session_spectate_request_params: &SessionSpectateRequestParams,
+        session_limits: SessionLimits,
+    ) -> Result<(Session, SessionDevice, PlayerControllers), SessionJoinError> {
+        let SessionSpectateRequestParams {
+            session_code,
+            session_device_name,
+        } = session_spectate_request_params;
+
+        if let Some(session) = self.sessions.get_mut(session_code) {
+            Self::session_device_name_check(session, session_device_name)?;
+            Self::session_capacity_check(session, session_limits, 0)?;
+
+            let session_device_id = Self::session_device_id_next(session);
+            let session_device = SessionDevice::new(
+                session_device_id,
+                session_device_name.clone(),
+                PlayerControllers::default(),
+            );
+            session.session_devices.push(session_device.clone());
+
+            let net_session_device = NetSessionDevice::new(socket_addr, session_device.clone());
+            self.session_device_mappings
+                .append(session_code, net_session_device);
+
+            debug!(
+                "Session `{}` spectated by `{}` with id: `{}`.",
+                session_code, session_device.name, session_device.id
+            );
+
+            let player_controllers_all = Self::player_controllers_all(session);
+
+            Ok((session.clone(), session_device, player_controllers_all))
+        } else {
+            Err(SessionJoinError::SessionCodeNotFound)
+        }
+    }
+
+    /// Reconnects a device whose place was kept in a started session.
+    ///
+    /// The device is still part of the `Session`, so only its `SocketAddr` mapping is added.
+    ///
+    /// # Parameters
+    ///
+    /// * `socket_addr`: New `SocketAddr` of the session device.
+    /// * `session_code`: Code of the session.
+    /// * `session_device`: The device that is reconnecting.
+    pub fn rejoin_device(
+        &mut self,
+        socket_addr: SocketAddr,
+        session_code: &SessionCode,
+        session_device: SessionDevice,
+    ) -> Result<(Session, PlayerControllers), SessionJoinError> {
+        if let Some(session) = self.sessions.get(session_code) {
+            debug!(
+                "Session `{}` rejoined by `{}` with id: `{}`.",
+                session_code, session_device.name, session_device.id
+            );
+
+            let net_session_device = NetSessionDevice::new(socket_addr, session_device);
+            self.session_device_mappings
+                .append(session_code, net_session_device);
+
+            let player_controllers_all = Self::player_controllers_all(session);
+
+            Ok((session.clone(), player_controllers_all))
+        } else {
+            Err(SessionJoinError::ReconnectTokenInvalid)
+        }
+    }
+
     /// Removes the device from any previous session, returning it alongside the session code.
     ///
     /// # Parameters
@@ -170,6 +260,65 @@ impl<'s> SessionTracker<'s> {
         session_code_and_device
     }
 
+    /// Returns an error if a device in the session already uses the given name.
+    fn session_device_name_check(
+        session: &Session,
+        session_device_name: &SessionDeviceName,
+    ) -> Result<(), SessionJoinError> {
+        let name_used = session
+            .session_devices
+            .iter()
+            .any(|session_device| &session_device.name == session_device_name);
+
+        if name_used {
+            Err(SessionJoinError::DuplicateDeviceName)
+        } else {
+            Ok(())
+        }
+    }
+
+    /// Returns an error if adding a device with the given number of controllers exceeds the limits.
+    fn session_capacity_check(
+        session: &Session,
+        session_limits: SessionLimits,
+        controller_count: usize,
+    ) -> Result<(), SessionJoinError> {
+        let device_count = session.session_devices.len();
+        let controller_count_existing = session
+            .session_devices
+            .iter()
+            .map(|session_device| session_device.player_controllers.len())
+            .sum::<usize>();
+
+        if device_count + 1 > session_limits.devices_max
+            || controller_count_existing + controller_count > session_limits.controllers_max
+        {
+            Err(SessionJoinError::SessionFull)
+        } else {
+            Ok(())
+        }
+    }
+
+    fn session_device_id_next(session: &Session) -> SessionDeviceId {
+        session
+            .session_devices
+            .iter()
+            .map(|session_device| session_device.id)
+            .max()
+            .map(|session_device_id| SessionDeviceId::new(*session_device_id + 1))
+            .unwrap_or_else(|| SessionDeviceId::new(0))
+    }
+
+    /// Returns the combined player controllers of all devices in the session.
+    fn player_controllers_all(session: &Session) -> PlayerControllers {
+        let player_controllers_all = session
+            .session_devices
+            .iter()
+            .flat_map(|session_device| session_device.player_controllers.iter().cloned())
+            .collect::<Vec<PlayerController>>();
+        PlayerControllers::new(player_controllers_all)
+    }
+
     fn generate_session_code(
         &mut self,
         session_code_generator: &mut SessionCodeGenerator,
@@ -9,10 +9,14 @@ pub use self::{
     network_input_responder_system::{
         NetworkInputResponderSystem, NetworkInputResponderSystemDesc,
     },
+    session_browse_responder_system::{
+        SessionBrowseResponderSystem, SessionBrowseResponderSystemDesc,
+    },
     session_cleaner::SessionCleaner,
     session_device_disconnect_responder_system::{
         SessionDeviceDisconnectResponderSystem, SessionDeviceDisconnectResponderSystemDesc,
     },
+    session_device_reservation_expiry_system::SessionDeviceReservationExpirySystem,
     session_host_responder_system::{SessionHostResponderSystem, SessionHostResponderSystemDesc},
     session_join_responder_system::{SessionJoinResponderSystem, SessionJoinResponderSystemDesc},
     session_lobby_responder_system::{
@@ -24,8 +28,10 @@ pub use self::{
 };
 
 mod network_input_responder_system;
+mod session_browse_responder_system;
 mod session_cleaner;
 mod session_device_disconnect_responder_system;
+mod session_device_reservation_expiry_system;
 mod session_host_responder_system;
 mod session_join_responder_system;
 mod session_lobby_responder_system;
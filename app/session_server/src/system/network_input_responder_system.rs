@@ -11,17 +11,40 @@ use derivative::Derivative;
 use derive_new::new;
 use game_input_model::GameInputEvent;
 use log::{debug, error};
-use net_model::play::{NetData, NetEventChannel, NetMessageEvent};
+use net_model::play::{NetData, NetEventChannel, NetMessageEvent, NetSessionDevices};
+use network_session_model::{
+    play::{SessionDeviceId, SessionInputError},
+    SessionMessageEvent,
+};
+use rollback_model::play::{RollbackTickConfirm, TickedGameInputEvent};
 
-use crate::model::{SessionCodeToId, SessionDeviceMappingsRead, SessionIdToDeviceMappings};
+use crate::model::{
+    SessionCodeId, SessionCodeToId, SessionDeviceMappingsRead, SessionIdToDeviceMappings,
+    SessionInputHistories, SessionSpectators, SessionTickConfirmStatuses, SocketToDeviceId,
+};
 
 /// Broadcasts `InputEvent`s to connected devices within the same session.
+///
+/// `TickedGameInputEvent`s are only sent to the other devices in the session, as the sender has
+/// already applied its own input. Spectators are sent `TickedGameInputEvent`s once every device
+/// has sent a `RollbackTickConfirm` for their tick, followed by a `RollbackTickConfirm` for the
+/// latest tick that every device has confirmed.
+///
+/// Input from spectators, and from devices that are not in a session, is rejected with a
+/// `SessionMessageEvent::SessionInputReject`. Input for a started session is recorded in its
+/// `SessionInputHistory`.
 #[derive(Debug, SystemDesc, new)]
 #[system_desc(name(NetworkInputResponderSystemDesc))]
 pub struct NetworkInputResponderSystem {
     /// Reader ID for the `GameInputEvent` channel.
     #[system_desc(event_channel_reader)]
     game_input_event_rid: ReaderId<NetData<GameInputEvent>>,
+    /// Reader ID for the `TickedGameInputEvent` channel.
+    #[system_desc(event_channel_reader)]
+    ticked_game_input_event_rid: ReaderId<NetData<TickedGameInputEvent>>,
+    /// Reader ID for the `RollbackTickConfirm` channel.
+    #[system_desc(event_channel_reader)]
+    rollback_tick_confirm_rid: ReaderId<NetData<RollbackTickConfirm>>,
 }
 
 #[derive(Derivative, SystemData)]
@@ -30,25 +53,200 @@ pub struct NetworkInputResponderSystemData<'s> {
     /// `InputEvent` channel.
     #[derivative(Debug = "ignore")]
     pub network_input_nec: Read<'s, NetEventChannel<GameInputEvent>>,
+    /// `TickedGameInputEvent` channel.
+    #[derivative(Debug = "ignore")]
+    pub ticked_game_input_nec: Read<'s, NetEventChannel<TickedGameInputEvent>>,
+    /// `RollbackTickConfirm` channel.
+    #[derivative(Debug = "ignore")]
+    pub rollback_tick_confirm_nec: Read<'s, NetEventChannel<RollbackTickConfirm>>,
     /// `SessionCodeToId` resource.
     #[derivative(Debug = "ignore")]
     pub session_code_to_id: Read<'s, SessionCodeToId>,
     /// `SessionIdToDeviceMappings` resource.
     #[derivative(Debug = "ignore")]
     pub session_id_to_device_mappings: Read<'s, SessionIdToDeviceMappings>,
+    /// `SocketToDeviceId` resource.
+    #[derivative(Debug = "ignore")]
+    pub socket_to_device_id: Read<'s, SocketToDeviceId>,
+    /// `SessionSpectators` resource.
+    #[derivative(Debug = "ignore")]
+    pub session_spectators: Read<'s, SessionSpectators>,
+    /// `SessionInputHistories` resource.
+    #[derivative(Debug = "ignore")]
+    pub session_input_histories: Write<'s, SessionInputHistories>,
+    /// `SessionTickConfirmStatuses` resource.
+    #[derivative(Debug = "ignore")]
+    pub session_tick_confirm_statuses: Write<'s, SessionTickConfirmStatuses>,
     /// `TransportResource` resource.
     #[derivative(Debug = "ignore")]
     pub transport_resource: Write<'s, TransportResource>,
 }
 
 impl NetworkInputResponderSystem {
-    fn send_game_input_event(
+    /// Returns the reason to reject input from the given socket, if any.
+    fn session_input_error(
+        session_device_mappings_read: &SessionDeviceMappingsRead<'_>,
+        session_spectators: &SessionSpectators,
+        socket_addr: &SocketAddr,
+    ) -> Option<SessionInputError> {
+        if session_spectators.contains(socket_addr) {
+            Some(SessionInputError::Spectator)
+        } else if session_device_mappings_read
+            .session_code(socket_addr)
+            .is_none()
+        {
+            Some(SessionInputError::SessionNotFound)
+        } else {
+            None
+        }
+    }
+
+    /// Sends a `SessionMessageEvent::SessionInputReject` to the device that sent the input.
+    fn reject(
         transport_resource: &mut TransportResource,
-        socket_addrs: impl Iterator<Item = SocketAddr>,
-        game_input_event: GameInputEvent,
+        socket_addr: SocketAddr,
+        session_input_error: SessionInputError,
+    ) {
+        debug!(
+            "Rejecting input from {}. Error: `{}`",
+            socket_addr, session_input_error
+        );
+
+        Self::send_net_message_event(
+            transport_resource,
+            std::iter::once(socket_addr),
+            NetMessageEvent::from(SessionMessageEvent::SessionInputReject(session_input_error)),
+        );
+    }
+
+    /// Returns the `SocketAddr`s of the devices that play in the session.
+    fn player_socket_addrs<'d>(
+        net_session_devices: &'d NetSessionDevices,
+        session_spectators: &'d SessionSpectators,
+    ) -> impl Iterator<Item = SocketAddr> + 'd {
+        net_session_devices
+            .iter()
+            .map(|net_session_device| net_session_device.socket_addr)
+            .filter(move |socket_addr| !session_spectators.contains(socket_addr))
+    }
+
+    /// Returns the `SocketAddr`s of the devices that spectate the session.
+    fn spectator_socket_addrs<'d>(
+        net_session_devices: &'d NetSessionDevices,
+        session_spectators: &'d SessionSpectators,
+    ) -> impl Iterator<Item = SocketAddr> + 'd {
+        net_session_devices
+            .iter()
+            .map(|net_session_device| net_session_device.socket_addr)
+            .filter(move |socket_addr| session_spectators.contains(socket_addr))
+    }
+
+    /// Records a device's `RollbackTickConfirm`, and sends newly confirmed input to spectators.
+    fn tick_confirm(
+        session_id_to_device_mappings: &SessionIdToDeviceMappings,
+        socket_to_device_id: &SocketToDeviceId,
+        session_spectators: &SessionSpectators,
+        session_input_histories: &mut SessionInputHistories,
+        session_tick_confirm_statuses: &mut SessionTickConfirmStatuses,
+        transport_resource: &mut TransportResource,
+        net_rollback_tick_confirm: &NetData<RollbackTickConfirm>,
+    ) {
+        let NetData {
+            socket_addr,
+            data: rollback_tick_confirm,
+        } = net_rollback_tick_confirm;
+
+        let session_device_id = socket_to_device_id.get(socket_addr).copied();
+        let session_code_id = session_id_to_device_mappings.session_code_id(socket_addr);
+        let net_session_devices = session_code_id.and_then(|session_code_id| {
+            session_id_to_device_mappings.net_session_devices(session_code_id)
+        });
+
+        if let (Some(session_device_id), Some(session_code_id), Some(net_session_devices)) =
+            (session_device_id, session_code_id, net_session_devices)
+        {
+            let session_device_ids = net_session_devices
+                .iter()
+                .filter(|net_session_device| {
+                    !session_spectators.contains(&net_session_device.socket_addr)
+                })
+                .map(|net_session_device| net_session_device.data.id)
+                .collect::<Vec<SessionDeviceId>>();
+            let session_tick_confirm_status = session_tick_confirm_statuses
+                .entry(session_code_id)
+                .or_default();
+            let tick_confirmed = session_tick_confirm_status.confirm(
+                &session_device_ids,
+                session_device_id,
+                rollback_tick_confirm.tick,
+            );
+
+            let session_input_history = session_input_histories.get_mut(&session_code_id);
+            if let (Some(tick_confirmed), Some(session_input_history)) =
+                (tick_confirmed, session_input_history)
+            {
+                let tick_confirmed_advanced = session_input_history
+                    .tick_confirmed
+                    .map(|tick_confirmed_previous| tick_confirmed > tick_confirmed_previous)
+                    .unwrap_or(true);
+                if !tick_confirmed_advanced {
+                    return;
+                }
+                session_input_history.tick_confirmed = Some(tick_confirmed);
+
+                let ticked_game_input_events =
+                    session_tick_confirm_status.take_confirmed(tick_confirmed);
+                Self::send_to_spectators(
+                    transport_resource,
+                    net_session_devices,
+                    session_spectators,
+                    session_code_id,
+                    ticked_game_input_events,
+                    RollbackTickConfirm::new(tick_confirmed),
+                );
+            }
+        } else {
+            debug!(
+                "Received `{:?}` from {}, but no session tracked for that socket.",
+                rollback_tick_confirm, socket_addr
+            );
+        }
+    }
+
+    /// Sends confirmed input, and the tick it is confirmed up to, to spectators.
+    fn send_to_spectators(
+        transport_resource: &mut TransportResource,
+        net_session_devices: &NetSessionDevices,
+        session_spectators: &SessionSpectators,
+        session_code_id: SessionCodeId,
+        ticked_game_input_events: Vec<TickedGameInputEvent>,
+        rollback_tick_confirm: RollbackTickConfirm,
     ) {
-        let net_message_event = NetMessageEvent::from(game_input_event);
+        debug!(
+            "Sending input confirmed up to tick `{}` to spectators of session: `{}`.",
+            rollback_tick_confirm.tick, session_code_id
+        );
+
+        ticked_game_input_events
+            .into_iter()
+            .map(NetMessageEvent::from)
+            .chain(std::iter::once(NetMessageEvent::from(
+                rollback_tick_confirm,
+            )))
+            .for_each(|net_message_event| {
+                Self::send_net_message_event(
+                    transport_resource,
+                    Self::spectator_socket_addrs(net_session_devices, session_spectators),
+                    net_message_event,
+                );
+            });
+    }
 
+    fn send_net_message_event(
+        transport_resource: &mut TransportResource,
+        socket_addrs: impl Iterator<Item = SocketAddr>,
+        net_message_event: NetMessageEvent,
+    ) {
         match bincode::serialize(&net_message_event) {
             Ok(payload) => {
                 socket_addrs.for_each(|socket_addr| {
@@ -66,8 +264,8 @@ impl NetworkInputResponderSystem {
             }
             Err(e) => {
                 error!(
-                    "Failed to serialize `NetMessageEvent::InputEvent`. Error: `{}`.",
-                    e
+                    "Failed to serialize `{:?}`. Error: `{}`.",
+                    net_message_event, e
                 );
             }
         }
@@ -81,8 +279,14 @@ impl<'s> System<'s> for NetworkInputResponderSystem {
         &mut self,
         NetworkInputResponderSystemData {
             network_input_nec,
+            ticked_game_input_nec,
+            rollback_tick_confirm_nec,
             session_code_to_id,
             session_id_to_device_mappings,
+            socket_to_device_id,
+            session_spectators,
+            mut session_input_histories,
+            mut session_tick_confirm_statuses,
             mut transport_resource,
         }: Self::SystemData,
     ) {
@@ -101,6 +305,15 @@ impl<'s> System<'s> for NetworkInputResponderSystem {
                     data: game_input_event,
                 } = net_game_input_event;
 
+                if let Some(session_input_error) = Self::session_input_error(
+                    &session_device_mappings_read,
+                    &session_spectators,
+                    socket_addr,
+                ) {
+                    Self::reject(&mut transport_resource, *socket_addr, session_input_error);
+                    return;
+                }
+
                 if let Some(session_code) = session_device_mappings_read.session_code(&socket_addr)
                 {
                     if let Some(net_session_devices) =
@@ -111,19 +324,96 @@ impl<'s> System<'s> for NetworkInputResponderSystem {
                         let socket_addrs = net_session_devices
                             .iter()
                             .map(|net_session_device| net_session_device.socket_addr);
-                        Self::send_game_input_event(
+                        Self::send_net_message_event(
                             &mut transport_resource,
                             socket_addrs,
-                            *game_input_event,
+                            NetMessageEvent::from(*game_input_event),
                         );
+
+                        if let Some(session_input_history) = session_id_to_device_mappings
+                            .session_code_id(&socket_addr)
+                            .and_then(|session_code_id| {
+                                session_input_histories.get_mut(&session_code_id)
+                            })
+                        {
+                            session_input_history.push_game_input_event(*game_input_event);
+                        }
                     }
-                } else {
-                    debug!(
-                        "Received `{:?}` from {:?}, but no session code tracked for that socket.",
-                        game_input_event, socket_addr
-                    );
-                    // TODO: reject
                 }
             });
+
+        ticked_game_input_nec
+            .read(&mut self.ticked_game_input_event_rid)
+            .for_each(|net_ticked_game_input_event| {
+                let NetData {
+                    socket_addr,
+                    data: ticked_game_input_event,
+                } = net_ticked_game_input_event;
+
+                if let Some(session_input_error) = Self::session_input_error(
+                    &session_device_mappings_read,
+                    &session_spectators,
+                    socket_addr,
+                ) {
+                    Self::reject(&mut transport_resource, *socket_addr, session_input_error);
+                    return;
+                }
+
+                if let Some(session_code) = session_device_mappings_read.session_code(&socket_addr)
+                {
+                    if let Some(net_session_devices) =
+                        session_device_mappings_read.net_session_devices(session_code)
+                    {
+                        debug!(
+                            "Sending `TickedGameInputEvent` for session: `{}`.",
+                            session_code
+                        );
+
+                        // Spectators are sent the input once its tick is confirmed.
+                        let socket_addrs =
+                            Self::player_socket_addrs(net_session_devices, &session_spectators)
+                                .filter(|device_socket_addr| device_socket_addr != socket_addr);
+                        Self::send_net_message_event(
+                            &mut transport_resource,
+                            socket_addrs,
+                            NetMessageEvent::from(*ticked_game_input_event),
+                        );
+
+                        if let Some(session_code_id) =
+                            session_id_to_device_mappings.session_code_id(&socket_addr)
+                        {
+                            session_tick_confirm_statuses
+                                .entry(session_code_id)
+                                .or_default()
+                                .push(*ticked_game_input_event);
+
+                            if let Some(session_input_history) =
+                                session_input_histories.get_mut(&session_code_id)
+                            {
+                                session_input_history
+                                    .push_ticked_game_input_event(*ticked_game_input_event);
+                            }
+                        }
+                    }
+                }
+            });
+
+        rollback_tick_confirm_nec
+            .read(&mut self.rollback_tick_confirm_rid)
+            .filter(|net_rollback_tick_confirm| {
+                // Spectators do not send input, so their ticks are not waited for.
+                !session_spectators.contains(&net_rollback_tick_confirm.socket_addr)
+            })
+            .for_each(|net_rollback_tick_confirm| {
+                Self::tick_confirm(
+                    session_id_to_device_mappings,
+                    &socket_to_device_id,
+                    &session_spectators,
+                    &mut session_input_histories,
+                    &mut session_tick_confirm_statuses,
+                    &mut transport_resource,
+                    net_rollback_tick_confirm,
+                );
+            });
     }
 }
@@ -0,0 +1,177 @@
+use std::net::SocketAddr;
+
+use amethyst::{
+    derive::SystemDesc,
+    ecs::{Read, System, World, Write},
+    network::simulation::{DeliveryRequirement, TransportResource, UrgencyRequirement},
+    shred::{ResourceId, SystemData},
+    shrev::ReaderId,
+};
+use derivative::Derivative;
+use derive_new::new;
+use log::{error, warn};
+use net_model::play::{NetData, NetEventChannel, NetMessageEvent, NetPeerVersions};
+use network_session_model::play::{SessionDeviceId, Sessions};
+use session_browse_model::{
+    play::{SessionListResponse, SessionListing, SessionListingStatus, SessionListings},
+    SessionBrowseEvent,
+};
+
+use crate::model::{SessionCodeToId, SessionInputHistories, SessionPublicIds};
+
+/// Responds to requests for the list of public sessions.
+#[derive(Debug, SystemDesc, new)]
+#[system_desc(name(SessionBrowseResponderSystemDesc))]
+pub struct SessionBrowseResponderSystem {
+    /// Reader ID for the `SessionBrowseEvent` channel.
+    #[system_desc(event_channel_reader)]
+    session_browse_event_rid: ReaderId<NetData<SessionBrowseEvent>>,
+}
+
+#[derive(Derivative, SystemData)]
+#[derivative(Debug)]
+pub struct SessionBrowseResponderSystemData<'s> {
+    /// `SessionBrowseEvent` channel.
+    #[derivative(Debug = "ignore")]
+    pub session_browse_nec: Read<'s, NetEventChannel<SessionBrowseEvent>>,
+    /// `Sessions` resource.
+    #[derivative(Debug = "ignore")]
+    pub sessions: Read<'s, Sessions>,
+    /// `SessionCodeToId` resource.
+    #[derivative(Debug = "ignore")]
+    pub session_code_to_id: Read<'s, SessionCodeToId>,
+    /// `SessionPublicIds` resource.
+    #[derivative(Debug = "ignore")]
+    pub session_public_ids: Read<'s, SessionPublicIds>,
+    /// `SessionInputHistories` resource.
+    #[derivative(Debug = "ignore")]
+    pub session_input_histories: Read<'s, SessionInputHistories>,
+    /// `NetPeerVersions` resource.
+    #[derivative(Debug = "ignore")]
+    pub net_peer_versions: Read<'s, NetPeerVersions>,
+    /// `TransportResource` resource.
+    #[derivative(Debug = "ignore")]
+    pub transport_resource: Write<'s, TransportResource>,
+}
+
+impl SessionBrowseResponderSystem {
+    /// Returns the listings of public sessions, sorted by session code.
+    fn session_listings(
+        sessions: &Sessions,
+        session_code_to_id: &SessionCodeToId,
+        session_public_ids: &SessionPublicIds,
+        session_input_histories: &SessionInputHistories,
+    ) -> SessionListings {
+        let mut session_listings = session_public_ids
+            .iter()
+            .filter_map(|session_code_id| {
+                let session = session_code_to_id
+                    .code(*session_code_id)
+                    .and_then(|session_code| sessions.get(session_code))?;
+
+                // The host may have left a started session, in which case the next device is shown.
+                let session_host = session
+                    .session_devices
+                    .iter()
+                    .find(|session_device| session_device.id == SessionDeviceId::HOST)
+                    .or_else(|| session.session_devices.first())?;
+
+                let session_listing_status =
+                    if session_input_histories.contains_key(session_code_id) {
+                        SessionListingStatus::InGame
+                    } else {
+                        SessionListingStatus::Lobby
+                    };
+
+                Some(SessionListing::new(
+                    session.session_code.clone(),
+                    session_host.name.clone(),
+                    session.session_devices.len(),
+                    session_listing_status,
+                ))
+            })
+            .collect::<Vec<SessionListing>>();
+        session_listings.sort_by(|listing_a, listing_b| {
+            listing_a.session_code.0.cmp(&listing_b.session_code.0)
+        });
+
+        SessionListings::new(session_listings)
+    }
+
+    fn send(
+        transport_resource: &mut TransportResource,
+        socket_addr: SocketAddr,
+        net_message_event: &NetMessageEvent,
+    ) {
+        match bincode::serialize(net_message_event) {
+            Ok(payload) => {
+                transport_resource.send_with_requirements(
+                    socket_addr,
+                    &payload,
+                    DeliveryRequirement::ReliableOrdered(None),
+                    UrgencyRequirement::OnTick,
+                );
+            }
+            Err(e) => {
+                error!(
+                    "Failed to serialize `NetMessageEvent::SessionBrowseEvent`. Error: `{}`.",
+                    e
+                );
+            }
+        }
+    }
+}
+
+impl<'s> System<'s> for SessionBrowseResponderSystem {
+    type SystemData = SessionBrowseResponderSystemData<'s>;
+
+    fn run(
+        &mut self,
+        SessionBrowseResponderSystemData {
+            session_browse_nec,
+            sessions,
+            session_code_to_id,
+            session_public_ids,
+            session_input_histories,
+            net_peer_versions,
+            mut transport_resource,
+        }: Self::SystemData,
+    ) {
+        session_browse_nec
+            .read(&mut self.session_browse_event_rid)
+            .filter_map(|session_browse_event| {
+                if let NetData {
+                    socket_addr,
+                    data: SessionBrowseEvent::SessionListRequest,
+                } = session_browse_event
+                {
+                    Some(*socket_addr)
+                } else {
+                    None
+                }
+            })
+            .for_each(|socket_addr| {
+                if !net_peer_versions.is_compatible(&socket_addr) {
+                    warn!(
+                        "Ignoring request to list sessions from `{}`, which has an incompatible \
+                         protocol version.",
+                        socket_addr
+                    );
+                    return;
+                }
+
+                let session_listings = Self::session_listings(
+                    &sessions,
+                    &session_code_to_id,
+                    &session_public_ids,
+                    &session_input_histories,
+                );
+                let net_message_event =
+                    NetMessageEvent::from(SessionBrowseEvent::SessionListResponse(
+                        SessionListResponse::new(session_listings),
+                    ));
+
+                Self::send(&mut transport_resource, socket_addr, &net_message_event);
+            });
+    }
+}
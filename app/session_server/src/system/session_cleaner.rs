@@ -5,7 +5,7 @@ use net_model::play::NetSessionDevices;
 use network_session_model::play::{SessionCode, SessionDeviceName, Sessions};
 
 use crate::{
-    model::{SessionDeviceMappings, SocketToDeviceId},
+    model::{SessionDeviceMappings, SessionSpectators, SocketToDeviceId},
     play::SessionTracker,
 };
 
@@ -60,6 +60,7 @@ impl SessionCleaner {
     pub fn client_forget(
         session_tracker: &mut SessionTracker<'_>,
         socket_to_device_id: &mut SocketToDeviceId,
+        session_spectators: &mut SessionSpectators,
         socket_addr: SocketAddr,
         session_device_name: &SessionDeviceName,
     ) {
@@ -72,6 +73,8 @@ impl SessionCleaner {
             );
         }
 
+        session_spectators.remove(&socket_addr);
+
         let device_id_previous = socket_to_device_id.remove(&socket_addr);
         if let Some(device_id_previous) = device_id_previous {
             debug!(
@@ -1,20 +1,35 @@
+use std::time::Instant;
+
 use amethyst::{
     derive::SystemDesc,
-    ecs::{Read, System, World, Write},
-    network::simulation::NetworkSimulationEvent,
+    ecs::{Read, ReadExpect, System, World, Write},
+    network::simulation::{NetworkSimulationEvent, TransportResource},
     shred::{ResourceId, SystemData},
     shrev::{EventChannel, ReaderId},
 };
 use derivative::Derivative;
 use derive_new::new;
+use log::debug;
+use net_model::play::NetPeerVersions;
 use network_session_model::play::Sessions;
 
 use crate::{
-    model::{SessionCodeToId, SessionDeviceMappings, SessionIdToDeviceMappings, SocketToDeviceId},
-    system::SessionCleaner,
+    model::{
+        SessionCharacterSelectionStatuses, SessionChecksumStatuses, SessionCodeToId,
+        SessionDeviceKey, SessionDeviceMappings, SessionDeviceReservation,
+        SessionDeviceReservations, SessionIdToDeviceMappings, SessionInputHistories,
+        SessionPublicIds, SessionReadyStatuses, SessionReconnectConfig, SessionReconnectTokens,
+        SessionSpectators, SessionTickConfirmStatuses, SessionTickStatuses, SocketToDeviceId,
+    },
+    system::{SessionCleaner, SessionMessageResponderSystem},
 };
 
 /// Listens for client disconnects, and removes them from the sessions.
+///
+/// When a spectator disconnects, only the spectator is removed from its session.
+///
+/// When a device disconnects from a started session, its place is kept for the reconnect grace
+/// period, and the remaining devices continue without it.
 #[derive(Debug, SystemDesc, new)]
 #[system_desc(name(SessionDeviceDisconnectResponderSystemDesc))]
 pub struct SessionDeviceDisconnectResponderSystem {
@@ -41,6 +56,45 @@ pub struct SessionDeviceDisconnectResponderSystemData<'s> {
     /// `SessionIdToDeviceMappings` resource.
     #[derivative(Debug = "ignore")]
     pub session_id_to_device_mappings: Write<'s, SessionIdToDeviceMappings>,
+    /// `SessionSpectators` resource.
+    #[derivative(Debug = "ignore")]
+    pub session_spectators: Write<'s, SessionSpectators>,
+    /// `SessionInputHistories` resource.
+    #[derivative(Debug = "ignore")]
+    pub session_input_histories: Write<'s, SessionInputHistories>,
+    /// `SessionTickStatuses` resource.
+    #[derivative(Debug = "ignore")]
+    pub session_tick_statuses: Write<'s, SessionTickStatuses>,
+    /// `SessionReadyStatuses` resource.
+    #[derivative(Debug = "ignore")]
+    pub session_ready_statuses: Write<'s, SessionReadyStatuses>,
+    /// `SessionCharacterSelectionStatuses` resource.
+    #[derivative(Debug = "ignore")]
+    pub session_character_selection_statuses: Write<'s, SessionCharacterSelectionStatuses>,
+    /// `SessionChecksumStatuses` resource.
+    #[derivative(Debug = "ignore")]
+    pub session_checksum_statuses: Write<'s, SessionChecksumStatuses>,
+    /// `SessionTickConfirmStatuses` resource.
+    #[derivative(Debug = "ignore")]
+    pub session_tick_confirm_statuses: Write<'s, SessionTickConfirmStatuses>,
+    /// `SessionPublicIds` resource.
+    #[derivative(Debug = "ignore")]
+    pub session_public_ids: Write<'s, SessionPublicIds>,
+    /// `SessionReconnectConfig` resource.
+    #[derivative(Debug = "ignore")]
+    pub session_reconnect_config: ReadExpect<'s, SessionReconnectConfig>,
+    /// `SessionReconnectTokens` resource.
+    #[derivative(Debug = "ignore")]
+    pub session_reconnect_tokens: Write<'s, SessionReconnectTokens>,
+    /// `SessionDeviceReservations` resource.
+    #[derivative(Debug = "ignore")]
+    pub session_device_reservations: Write<'s, SessionDeviceReservations>,
+    /// `NetPeerVersions` resource.
+    #[derivative(Debug = "ignore")]
+    pub net_peer_versions: Write<'s, NetPeerVersions>,
+    /// `TransportResource` resource.
+    #[derivative(Debug = "ignore")]
+    pub transport_resource: Write<'s, TransportResource>,
 }
 
 impl<'s> System<'s> for SessionDeviceDisconnectResponderSystem {
@@ -54,6 +108,19 @@ impl<'s> System<'s> for SessionDeviceDisconnectResponderSystem {
             mut session_code_to_id,
             mut socket_to_device_id,
             mut session_id_to_device_mappings,
+            mut session_spectators,
+            mut session_input_histories,
+            mut session_tick_statuses,
+            mut session_ready_statuses,
+            mut session_character_selection_statuses,
+            mut session_checksum_statuses,
+            mut session_tick_confirm_statuses,
+            mut session_public_ids,
+            session_reconnect_config,
+            mut session_reconnect_tokens,
+            mut session_device_reservations,
+            mut net_peer_versions,
+            mut transport_resource,
         }: Self::SystemData,
     ) {
         let session_code_to_id = &mut *session_code_to_id;
@@ -64,6 +131,84 @@ impl<'s> System<'s> for SessionDeviceDisconnectResponderSystem {
             .read(&mut self.network_simulation_event_rid)
             .for_each(|ev| {
                 if let NetworkSimulationEvent::Disconnect(socket_addr) = ev {
+                    net_peer_versions.remove(socket_addr);
+
+                    if session_spectators.remove(socket_addr) {
+                        // Forget only the spectator.
+                        if let Some((session_code, net_session_device)) =
+                            session_device_mappings.remove_device(socket_addr)
+                        {
+                            if let Some(session) = sessions.get_mut(session_code) {
+                                session.session_devices.retain(|session_device| {
+                                    session_device.id != net_session_device.data.id
+                                });
+                            }
+                            debug!(
+                                "Removed spectator `{}` from session: `{}`.",
+                                &net_session_device.data.name, session_code
+                            );
+                        }
+                        socket_to_device_id.remove(socket_addr);
+
+                        return;
+                    }
+
+                    let session_code_id = session_device_mappings
+                        .as_read()
+                        .session_id_to_device_mappings
+                        .session_code_id(socket_addr);
+
+                    // Only started sessions have input history.
+                    let session_code_id_started = session_code_id.filter(|session_code_id| {
+                        session_input_histories.contains_key(session_code_id)
+                    });
+                    if let Some(session_code_id) = session_code_id_started {
+                        // Keep the device's place in the session, so that it may reconnect.
+                        if let Some((session_code, net_session_device)) =
+                            session_device_mappings.remove_device(socket_addr)
+                        {
+                            debug!(
+                                "Keeping place for `{}` in session `{}` for {:?}.",
+                                &net_session_device.data.name,
+                                session_code,
+                                session_reconnect_config.grace_period
+                            );
+
+                            let session_device = net_session_device.data;
+                            let session_device_id = session_device.id;
+                            let session_device_key =
+                                SessionDeviceKey::new(session_code_id, session_device_id);
+                            session_device_reservations.insert(
+                                session_device_key,
+                                SessionDeviceReservation::new(session_device, Instant::now()),
+                            );
+
+                            // Stop waiting for the device's input, and tick the session if the
+                            // remaining devices have already sent theirs.
+                            if let Some(session_device_tick_statuses) =
+                                session_tick_statuses.get_mut(&session_code_id)
+                            {
+                                session_device_tick_statuses.remove(&session_device_id);
+
+                                if !session_device_tick_statuses.is_empty() {
+                                    let session_device_mappings_read =
+                                        session_device_mappings.as_read();
+                                    SessionMessageResponderSystem::session_tick_if_all_received(
+                                        session_device_mappings_read.session_id_to_device_mappings,
+                                        session_device_mappings_read.session_code_to_id,
+                                        &mut session_input_histories,
+                                        &mut transport_resource,
+                                        session_device_tick_statuses,
+                                        session_code_id,
+                                    );
+                                }
+                            }
+                        }
+                        socket_to_device_id.remove(socket_addr);
+
+                        return;
+                    }
+
                     // Forget all clients in the session.
                     let session_code_and_devices = SessionCleaner::session_forget(
                         &mut sessions,
@@ -72,7 +217,22 @@ impl<'s> System<'s> for SessionDeviceDisconnectResponderSystem {
                         *socket_addr,
                     );
 
-                    if let Some((_session_code, _net_session_devices)) = session_code_and_devices {
+                    if let Some((_session_code, net_session_devices)) = session_code_and_devices {
+                        net_session_devices.iter().for_each(|net_session_device| {
+                            session_spectators.remove(&net_session_device.socket_addr);
+                        });
+                        if let Some(session_code_id) = session_code_id {
+                            session_input_histories.remove(&session_code_id);
+                            session_tick_statuses.remove(&session_code_id);
+                            session_ready_statuses.remove(&session_code_id);
+                            session_character_selection_statuses.remove(&session_code_id);
+                            session_checksum_statuses.remove(&session_code_id);
+                            session_tick_confirm_statuses.remove(&session_code_id);
+                            session_public_ids.remove(&session_code_id);
+                            session_reconnect_tokens.remove_session(session_code_id);
+                            session_device_reservations.remove_session(session_code_id);
+                        }
+
                         // TODO: Send disconnect message to all clients except the one that disconnected.
                     }
                 }
@@ -0,0 +1,156 @@
+use amethyst::{
+    ecs::{ReadExpect, System, World, Write},
+    shred::{ResourceId, SystemData},
+};
+use derivative::Derivative;
+use derive_new::new;
+use log::debug;
+use network_session_model::play::Sessions;
+
+use crate::model::{
+    SessionCharacterSelectionStatuses, SessionChecksumStatuses, SessionCodeToId, SessionDeviceKey,
+    SessionDeviceMappings, SessionDeviceReservations, SessionIdToDeviceMappings,
+    SessionInputHistories, SessionPublicIds, SessionReconnectConfig, SessionReconnectTokens,
+    SessionTickConfirmStatuses, SessionTickStatuses,
+};
+
+/// Removes disconnected devices from their session once the reconnect grace period has passed.
+///
+/// When no devices remain connected to a session, the session is removed.
+#[derive(Debug, new)]
+pub struct SessionDeviceReservationExpirySystem;
+
+/// `SessionDeviceReservationExpirySystemData`.
+#[derive(Derivative, SystemData)]
+#[derivative(Debug)]
+pub struct SessionDeviceReservationExpirySystemData<'s> {
+    /// `SessionReconnectConfig` resource.
+    #[derivative(Debug = "ignore")]
+    pub session_reconnect_config: ReadExpect<'s, SessionReconnectConfig>,
+    /// `SessionDeviceReservations` resource.
+    #[derivative(Debug = "ignore")]
+    pub session_device_reservations: Write<'s, SessionDeviceReservations>,
+    /// `SessionReconnectTokens` resource.
+    #[derivative(Debug = "ignore")]
+    pub session_reconnect_tokens: Write<'s, SessionReconnectTokens>,
+    /// `Sessions` resource.
+    #[derivative(Debug = "ignore")]
+    pub sessions: Write<'s, Sessions>,
+    /// `SessionCodeToId` resource.
+    #[derivative(Debug = "ignore")]
+    pub session_code_to_id: Write<'s, SessionCodeToId>,
+    /// `SessionIdToDeviceMappings` resource.
+    #[derivative(Debug = "ignore")]
+    pub session_id_to_device_mappings: Write<'s, SessionIdToDeviceMappings>,
+    /// `SessionInputHistories` resource.
+    #[derivative(Debug = "ignore")]
+    pub session_input_histories: Write<'s, SessionInputHistories>,
+    /// `SessionTickStatuses` resource.
+    #[derivative(Debug = "ignore")]
+    pub session_tick_statuses: Write<'s, SessionTickStatuses>,
+    /// `SessionCharacterSelectionStatuses` resource.
+    #[derivative(Debug = "ignore")]
+    pub session_character_selection_statuses: Write<'s, SessionCharacterSelectionStatuses>,
+    /// `SessionChecksumStatuses` resource.
+    #[derivative(Debug = "ignore")]
+    pub session_checksum_statuses: Write<'s, SessionChecksumStatuses>,
+    /// `SessionTickConfirmStatuses` resource.
+    #[derivative(Debug = "ignore")]
+    pub session_tick_confirm_statuses: Write<'s, SessionTickConfirmStatuses>,
+    /// `SessionPublicIds` resource.
+    #[derivative(Debug = "ignore")]
+    pub session_public_ids: Write<'s, SessionPublicIds>,
+}
+
+impl<'s> System<'s> for SessionDeviceReservationExpirySystem {
+    type SystemData = SessionDeviceReservationExpirySystemData<'s>;
+
+    fn run(
+        &mut self,
+        SessionDeviceReservationExpirySystemData {
+            session_reconnect_config,
+            mut session_device_reservations,
+            mut session_reconnect_tokens,
+            mut sessions,
+            mut session_code_to_id,
+            mut session_id_to_device_mappings,
+            mut session_input_histories,
+            mut session_tick_statuses,
+            mut session_character_selection_statuses,
+            mut session_checksum_statuses,
+            mut session_tick_confirm_statuses,
+            mut session_public_ids,
+        }: Self::SystemData,
+    ) {
+        if session_device_reservations.is_empty() {
+            return;
+        }
+
+        let grace_period = session_reconnect_config.grace_period;
+        let session_device_keys_expired = session_device_reservations
+            .iter()
+            .filter(|(_, session_device_reservation)| {
+                session_device_reservation.disconnected_at.elapsed() >= grace_period
+            })
+            .map(|(session_device_key, _)| *session_device_key)
+            .collect::<Vec<SessionDeviceKey>>();
+
+        let session_code_to_id = &mut *session_code_to_id;
+        let session_id_to_device_mappings = &mut *session_id_to_device_mappings;
+        let mut session_device_mappings =
+            SessionDeviceMappings::new(session_code_to_id, session_id_to_device_mappings);
+
+        session_device_keys_expired
+            .into_iter()
+            .for_each(|session_device_key| {
+                let SessionDeviceKey {
+                    session_code_id,
+                    session_device_id,
+                } = session_device_key;
+
+                session_device_reservations.remove(&session_device_key);
+                session_reconnect_tokens.remove(&session_device_key);
+
+                let session_device_mappings_read = session_device_mappings.as_read();
+                let session_code = session_device_mappings_read
+                    .session_code_to_id
+                    .code(session_code_id)
+                    .cloned();
+                let session_code = if let Some(session_code) = session_code {
+                    session_code
+                } else {
+                    return;
+                };
+
+                if let Some(session) = sessions.get_mut(&session_code) {
+                    session
+                        .session_devices
+                        .retain(|session_device| session_device.id != session_device_id);
+                }
+                debug!(
+                    "Reconnect grace period expired for device `{}` in session `{}`.",
+                    session_device_id, session_code
+                );
+
+                let session_devices_connected = session_device_mappings_read
+                    .net_session_devices(&session_code)
+                    .map(|net_session_devices| !net_session_devices.is_empty())
+                    .unwrap_or(false);
+                let session_devices_reserved =
+                    session_device_reservations.contains_session(session_code_id);
+                if !session_devices_connected && !session_devices_reserved {
+                    debug!("Removing session: `{}`.", session_code);
+
+                    sessions.remove(&session_code);
+                    session_device_mappings.remove(&session_code);
+                    session_input_histories.remove(&session_code_id);
+                    session_tick_statuses.remove(&session_code_id);
+                    session_character_selection_statuses.remove(&session_code_id);
+                    session_checksum_statuses.remove(&session_code_id);
+                    session_tick_confirm_statuses.remove(&session_code_id);
+                    session_public_ids.remove(&session_code_id);
+                    session_reconnect_tokens.remove_session(session_code_id);
+                }
+            });
+    }
+}
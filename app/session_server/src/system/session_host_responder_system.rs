@@ -10,16 +10,21 @@ use amethyst::{
 use derivative::Derivative;
 use derive_new::new;
 use log::{error, warn};
-use net_model::play::{NetData, NetEventChannel, NetMessageEvent};
+use net_model::play::{NetData, NetEventChannel, NetMessageEvent, NetPeerVersions};
 use network_session_model::play::Sessions;
-use network_session_play::SessionCodeGenerator;
+use network_session_play::{SessionCodeGenerator, SessionReconnectTokenGenerator};
 use session_host_model::{
-    play::{SessionAcceptResponse, SessionHostRequestParams, SessionRejectResponse},
+    play::{
+        SessionAcceptResponse, SessionHostError, SessionHostRequestParams, SessionRejectResponse,
+    },
     SessionHostEvent,
 };
 
 use crate::{
-    model::{SessionCodeToId, SessionDeviceMappings, SessionIdToDeviceMappings, SocketToDeviceId},
+    model::{
+        SessionCodeToId, SessionDeviceKey, SessionDeviceMappings, SessionIdToDeviceMappings,
+        SessionPublicIds, SessionReconnectTokens, SessionSpectators, SocketToDeviceId,
+    },
     play::SessionTracker,
     system::SessionCleaner,
 };
@@ -45,6 +50,12 @@ pub struct SessionHostResponderSystemData<'s> {
     /// `SessionCodeGenerator` resource.
     #[derivative(Debug = "ignore")]
     pub session_code_generator: Write<'s, SessionCodeGenerator>,
+    /// `SessionReconnectTokenGenerator` resource.
+    #[derivative(Debug = "ignore")]
+    pub session_reconnect_token_generator: Write<'s, SessionReconnectTokenGenerator>,
+    /// `SessionReconnectTokens` resource.
+    #[derivative(Debug = "ignore")]
+    pub session_reconnect_tokens: Write<'s, SessionReconnectTokens>,
     /// `Sessions` resource.
     #[derivative(Debug = "ignore")]
     pub sessions: Write<'s, Sessions>,
@@ -57,6 +68,15 @@ pub struct SessionHostResponderSystemData<'s> {
     /// `SocketToDeviceId` resource.
     #[derivative(Debug = "ignore")]
     pub socket_to_device_id: Write<'s, SocketToDeviceId>,
+    /// `SessionSpectators` resource.
+    #[derivative(Debug = "ignore")]
+    pub session_spectators: Write<'s, SessionSpectators>,
+    /// `SessionPublicIds` resource.
+    #[derivative(Debug = "ignore")]
+    pub session_public_ids: Write<'s, SessionPublicIds>,
+    /// `NetPeerVersions` resource.
+    #[derivative(Debug = "ignore")]
+    pub net_peer_versions: Read<'s, NetPeerVersions>,
     /// `TransportResource` resource.
     #[derivative(Debug = "ignore")]
     pub transport_resource: Write<'s, TransportResource>,
@@ -66,7 +86,11 @@ impl SessionHostResponderSystem {
     fn handle_session_request(
         session_tracker: &mut SessionTracker<'_>,
         session_code_generator: &mut SessionCodeGenerator,
+        session_reconnect_token_generator: &mut SessionReconnectTokenGenerator,
+        session_reconnect_tokens: &mut SessionReconnectTokens,
         socket_to_device_id: &mut SocketToDeviceId,
+        session_spectators: &mut SessionSpectators,
+        session_public_ids: &mut SessionPublicIds,
         socket_addr: SocketAddr,
         session_host_request_params: &SessionHostRequestParams,
     ) -> SessionHostEvent {
@@ -75,6 +99,7 @@ impl SessionHostResponderSystem {
         SessionCleaner::client_forget(
             session_tracker,
             socket_to_device_id,
+            session_spectators,
             socket_addr,
             session_device_name,
         );
@@ -88,8 +113,30 @@ impl SessionHostResponderSystem {
 
             socket_to_device_id.insert(socket_addr, session_device_id);
 
-            let session_accept_response =
-                SessionAcceptResponse::new(session, session_device_id, player_controllers);
+            let session_reconnect_token = session_reconnect_token_generator.generate();
+            if let Some(session_code_id) = session_tracker
+                .session_device_mappings
+                .as_read()
+                .session_code_to_id
+                .id(&session.session_code)
+            {
+                let session_device_key = SessionDeviceKey::new(session_code_id, session_device_id);
+                session_reconnect_tokens.insert(session_device_key, session_reconnect_token);
+
+                // Session codes may be reused, so a previous session's flag is overwritten.
+                if session_host_request_params.session_public {
+                    session_public_ids.insert(session_code_id);
+                } else {
+                    session_public_ids.remove(&session_code_id);
+                }
+            }
+
+            let session_accept_response = SessionAcceptResponse::new(
+                session,
+                session_device_id,
+                player_controllers,
+                session_reconnect_token,
+            );
 
             SessionHostEvent::SessionAccept(session_accept_response)
         } else {
@@ -99,7 +146,9 @@ impl SessionHostResponderSystem {
                 session_device_name
             );
 
-            SessionHostEvent::SessionReject(SessionRejectResponse::new())
+            SessionHostEvent::SessionReject(SessionRejectResponse::new(
+                SessionHostError::SessionCountLimitReached,
+            ))
         }
     }
 }
@@ -112,10 +161,15 @@ impl<'s> System<'s> for SessionHostResponderSystem {
         SessionHostResponderSystemData {
             session_host_nec,
             mut session_code_generator,
+            mut session_reconnect_token_generator,
+            mut session_reconnect_tokens,
             mut sessions,
             mut session_code_to_id,
             mut session_id_to_device_mappings,
             mut socket_to_device_id,
+            mut session_spectators,
+            mut session_public_ids,
+            net_peer_versions,
             mut transport_resource,
         }: Self::SystemData,
     ) {
@@ -142,10 +196,27 @@ impl<'s> System<'s> for SessionHostResponderSystem {
                 }
             })
             .map(|(socket_addr, session_host_request_params)| {
+                if !net_peer_versions.is_compatible(&socket_addr) {
+                    warn!(
+                        "Rejecting request to host session from `{}`, which has an incompatible \
+                         protocol version.",
+                        socket_addr
+                    );
+
+                    let session_host_event = SessionHostEvent::SessionReject(
+                        SessionRejectResponse::new(SessionHostError::ProtocolVersionMismatch),
+                    );
+                    return (socket_addr, NetMessageEvent::from(session_host_event));
+                }
+
                 let session_host_event = Self::handle_session_request(
                     &mut session_tracker,
                     &mut session_code_generator,
+                    &mut session_reconnect_token_generator,
+                    &mut session_reconnect_tokens,
                     &mut socket_to_device_id,
+                    &mut session_spectators,
+                    &mut session_public_ids,
                     socket_addr,
                     session_host_request_params,
                 );
@@ -2,31 +2,48 @@ use std::net::SocketAddr;
 
 use amethyst::{
     derive::SystemDesc,
-    ecs::{Read, System, World, Write},
+    ecs::{Read, ReadExpect, System, World, Write},
     network::simulation::{DeliveryRequirement, TransportResource, UrgencyRequirement},
     shred::{ResourceId, SystemData},
     shrev::ReaderId,
 };
 use derivative::Derivative;
 use derive_new::new;
+use game_input_model::play::ControllerIdOffset;
 use log::{debug, error};
-use net_model::play::{NetData, NetEventChannel, NetMessageEvent};
+use net_model::play::{NetData, NetEventChannel, NetMessageEvent, NetPeerVersions};
 use network_session_model::{
     play::{SessionDeviceJoin, Sessions},
     SessionMessageEvent,
 };
+use network_session_play::SessionReconnectTokenGenerator;
+use rollback_model::play::SessionInputHistory;
 use session_join_model::{
-    play::{SessionAcceptResponse, SessionJoinRequestParams, SessionRejectResponse},
+    play::{
+        SessionAcceptResponse, SessionJoinError, SessionJoinRequestParams,
+        SessionReconnectAcceptResponse, SessionReconnectRequestParams, SessionRejectResponse,
+        SessionSpectateAcceptResponse, SessionSpectateRequestParams,
+    },
     SessionJoinEvent,
 };
 
 use crate::{
-    model::{SessionCodeToId, SessionDeviceMappings, SessionIdToDeviceMappings, SocketToDeviceId},
+    model::{
+        SessionCodeToId, SessionDeviceKey, SessionDeviceMappings, SessionDeviceReservations,
+        SessionIdToDeviceMappings, SessionInputHistories, SessionLimits, SessionReconnectTokens,
+        SessionSpectators, SocketToDeviceId,
+    },
     play::SessionTracker,
     system::SessionCleaner,
 };
 
 /// Accepts or rejects session requests, and sends the response to the requester.
+///
+/// Spectators of a session that has already started are sent the session's input history, with
+/// only the rollback input of ticks that every device has confirmed.
+///
+/// Devices that reconnect to a started session with a valid token are sent the input that they
+/// have not yet applied.
 #[derive(Debug, SystemDesc, new)]
 #[system_desc(name(SessionJoinResponderSystemDesc))]
 pub struct SessionJoinResponderSystem {
@@ -53,6 +70,27 @@ pub struct SessionJoinResponderSystemData<'s> {
     /// `SocketToDeviceId` resource.
     #[derivative(Debug = "ignore")]
     pub socket_to_device_id: Write<'s, SocketToDeviceId>,
+    /// `SessionSpectators` resource.
+    #[derivative(Debug = "ignore")]
+    pub session_spectators: Write<'s, SessionSpectators>,
+    /// `SessionInputHistories` resource.
+    #[derivative(Debug = "ignore")]
+    pub session_input_histories: Read<'s, SessionInputHistories>,
+    /// `SessionLimits` resource.
+    #[derivative(Debug = "ignore")]
+    pub session_limits: ReadExpect<'s, SessionLimits>,
+    /// `SessionReconnectTokenGenerator` resource.
+    #[derivative(Debug = "ignore")]
+    pub session_reconnect_token_generator: Write<'s, SessionReconnectTokenGenerator>,
+    /// `SessionReconnectTokens` resource.
+    #[derivative(Debug = "ignore")]
+    pub session_reconnect_tokens: Write<'s, SessionReconnectTokens>,
+    /// `SessionDeviceReservations` resource.
+    #[derivative(Debug = "ignore")]
+    pub session_device_reservations: Write<'s, SessionDeviceReservations>,
+    /// `NetPeerVersions` resource.
+    #[derivative(Debug = "ignore")]
+    pub net_peer_versions: Read<'s, NetPeerVersions>,
     /// `TransportResource` resource.
     #[derivative(Debug = "ignore")]
     pub transport_resource: Write<'s, TransportResource>,
@@ -62,6 +100,11 @@ impl SessionJoinResponderSystem {
     fn handle_session_request(
         session_tracker: &mut SessionTracker,
         socket_to_device_id: &mut SocketToDeviceId,
+        session_spectators: &mut SessionSpectators,
+        session_input_histories: &SessionInputHistories,
+        session_limits: SessionLimits,
+        session_reconnect_token_generator: &mut SessionReconnectTokenGenerator,
+        session_reconnect_tokens: &mut SessionReconnectTokens,
         socket_addr: SocketAddr,
         session_join_request_params: &SessionJoinRequestParams,
     ) -> (SessionJoinEvent, Option<SessionMessageEvent>) {
@@ -74,19 +117,44 @@ impl SessionJoinResponderSystem {
         SessionCleaner::client_forget(
             session_tracker,
             socket_to_device_id,
+            session_spectators,
             socket_addr,
             session_device_name,
         );
 
-        match session_tracker.append_device(socket_addr, session_join_request_params) {
+        let session_code_id = session_tracker
+            .session_device_mappings
+            .as_read()
+            .session_code_to_id
+            .id(session_code);
+
+        // Only started sessions have input history.
+        let session_started = session_code_id
+            .map(|session_code_id| session_input_histories.contains_key(&session_code_id))
+            .unwrap_or(false);
+
+        match session_tracker.append_device(
+            socket_addr,
+            session_join_request_params,
+            session_limits,
+            session_started,
+        ) {
             Ok((session, session_device, player_controllers, controller_id_offset)) => {
                 socket_to_device_id.insert(socket_addr, session_device.id);
 
+                let session_reconnect_token = session_reconnect_token_generator.generate();
+                if let Some(session_code_id) = session_code_id {
+                    let session_device_key =
+                        SessionDeviceKey::new(session_code_id, session_device.id);
+                    session_reconnect_tokens.insert(session_device_key, session_reconnect_token);
+                }
+
                 let session_accept_response = SessionAcceptResponse::new(
                     session,
                     session_device.id,
                     player_controllers.clone(),
                     controller_id_offset,
+                    session_reconnect_token,
                 );
                 let session_join_event = SessionJoinEvent::SessionAccept(session_accept_response);
 
@@ -100,7 +168,96 @@ impl SessionJoinResponderSystem {
             }
             Err(e) => {
                 debug!(
-                    "Rejecting request to join session `{}` joined from `{}`. Error: `{:?}`",
+                    "Rejecting request to join session `{}` joined from `{}`. Error: `{}`",
+                    session_code, session_device_name, e
+                );
+
+                let session_join_event = SessionJoinEvent::SessionReject(
+                    SessionRejectResponse::new(session_code.clone(), e),
+                );
+
+                (session_join_event, None)
+            }
+        }
+    }
+
+    fn handle_session_spectate_request(
+        session_tracker: &mut SessionTracker,
+        socket_to_device_id: &mut SocketToDeviceId,
+        session_spectators: &mut SessionSpectators,
+        session_input_histories: &SessionInputHistories,
+        session_limits: SessionLimits,
+        socket_addr: SocketAddr,
+        session_spectate_request_params: &SessionSpectateRequestParams,
+    ) -> (SessionJoinEvent, Option<SessionMessageEvent>) {
+        let SessionSpectateRequestParams {
+            session_device_name,
+            session_code,
+        } = session_spectate_request_params;
+
+        SessionCleaner::client_forget(
+            session_tracker,
+            socket_to_device_id,
+            session_spectators,
+            socket_addr,
+            session_device_name,
+        );
+
+        // Only started sessions have input history.
+        let session_input_history = session_tracker
+            .session_device_mappings
+            .as_read()
+            .session_code_to_id
+            .id(session_code)
+            .and_then(|session_code_id| session_input_histories.get(&session_code_id));
+
+        // Spectators apply the session's input from the start, which is not possible once earlier
+        // ticks have been discarded. Rollback input is only sent for ticks that every device has
+        // confirmed, and later ticks are sent as they are confirmed.
+        let spectate_result = match session_input_history {
+            Some(session_input_history) if !session_input_history.is_complete() => {
+                Err(SessionJoinError::SessionInputHistoryDiscarded)
+            }
+            _ => Ok(session_input_history.map(SessionInputHistory::confirmed)),
+        }
+        .and_then(|session_input_history| {
+            session_tracker
+                .append_spectator(socket_addr, session_spectate_request_params, session_limits)
+                .map(|(session, session_device, player_controllers)| {
+                    (
+                        session,
+                        session_device,
+                        player_controllers,
+                        session_input_history,
+                    )
+                })
+        });
+
+        match spectate_result {
+            Ok((session, session_device, player_controllers, session_input_history)) => {
+                socket_to_device_id.insert(socket_addr, session_device.id);
+                session_spectators.insert(socket_addr);
+
+                let session_spectate_accept_response = SessionSpectateAcceptResponse::new(
+                    session,
+                    session_device.id,
+                    player_controllers.clone(),
+                    session_input_history,
+                );
+                let session_join_event =
+                    SessionJoinEvent::SessionSpectateAccept(session_spectate_accept_response);
+
+                let session_message_event = {
+                    let session_device_join =
+                        SessionDeviceJoin::new(session_device, player_controllers);
+                    SessionMessageEvent::SessionDeviceJoin(session_device_join)
+                };
+
+                (session_join_event, Some(session_message_event))
+            }
+            Err(e) => {
+                debug!(
+                    "Rejecting request to spectate session `{}` from `{}`. Error: `{}`",
                     session_code, session_device_name, e
                 );
 
@@ -113,6 +270,119 @@ impl SessionJoinResponderSystem {
         }
     }
 
+    fn handle_session_reconnect_request(
+        session_tracker: &mut SessionTracker,
+        socket_to_device_id: &mut SocketToDeviceId,
+        session_spectators: &mut SessionSpectators,
+        session_input_histories: &SessionInputHistories,
+        session_reconnect_tokens: &SessionReconnectTokens,
+        session_device_reservations: &mut SessionDeviceReservations,
+        socket_addr: SocketAddr,
+        session_reconnect_request_params: &SessionReconnectRequestParams,
+    ) -> (SessionJoinEvent, Option<SessionMessageEvent>) {
+        let SessionReconnectRequestParams {
+            session_code,
+            session_device_id,
+            session_reconnect_token,
+            session_tick_count,
+        } = session_reconnect_request_params;
+
+        let session_device_key = session_tracker
+            .session_device_mappings
+            .as_read()
+            .session_code_to_id
+            .id(session_code)
+            .map(|session_code_id| SessionDeviceKey::new(session_code_id, *session_device_id))
+            .filter(|session_device_key| {
+                session_reconnect_tokens.get(session_device_key) == Some(session_reconnect_token)
+            });
+        // The device catches up from the tick it last applied, so the ticks since then must still
+        // be in the history.
+        let session_input_history = session_device_key
+            .ok_or(SessionJoinError::ReconnectTokenInvalid)
+            .and_then(|session_device_key| {
+                session_input_histories
+                    .get(&session_device_key.session_code_id)
+                    .map(|session_input_history| {
+                        session_input_history
+                            .since_tick(session_tick_count.0 as usize)
+                            .ok_or(SessionJoinError::SessionInputHistoryDiscarded)
+                    })
+                    .unwrap_or_else(|| Ok(SessionInputHistory::default()))
+            });
+
+        let rejoin_result = session_input_history.and_then(|session_input_history| {
+            session_device_key
+                .and_then(|session_device_key| {
+                    session_device_reservations.remove(&session_device_key)
+                })
+                .ok_or(SessionJoinError::ReconnectTokenInvalid)
+                .and_then(|session_device_reservation| {
+                    let session_device = session_device_reservation.session_device;
+
+                    SessionCleaner::client_forget(
+                        session_tracker,
+                        socket_to_device_id,
+                        session_spectators,
+                        socket_addr,
+                        &session_device.name,
+                    );
+
+                    // The first controller of a device is offset by the controllers of the devices
+                    // that joined before it.
+                    let controller_id_offset = ControllerIdOffset::new(
+                        session_device
+                            .player_controllers
+                            .iter()
+                            .map(|player_controller| player_controller.controller_id)
+                            .min()
+                            .unwrap_or(0),
+                    );
+
+                    session_tracker
+                        .rejoin_device(socket_addr, session_code, session_device)
+                        .map(|(session, player_controllers)| {
+                            (
+                                session,
+                                player_controllers,
+                                controller_id_offset,
+                                session_input_history,
+                            )
+                        })
+                })
+        });
+
+        match rejoin_result {
+            Ok((session, player_controllers, controller_id_offset, session_input_history)) => {
+                socket_to_device_id.insert(socket_addr, *session_device_id);
+
+                let session_reconnect_accept_response = SessionReconnectAcceptResponse::new(
+                    session,
+                    *session_device_id,
+                    player_controllers,
+                    controller_id_offset,
+                    session_input_history,
+                );
+                let session_join_event =
+                    SessionJoinEvent::SessionReconnectAccept(session_reconnect_accept_response);
+
+                (session_join_event, None)
+            }
+            Err(e) => {
+                debug!(
+                    "Rejecting request to rejoin session `{}` as device `{}`. Error: `{}`",
+                    session_code, session_device_id, e
+                );
+
+                let session_join_event = SessionJoinEvent::SessionReject(
+                    SessionRejectResponse::new(session_code.clone(), e),
+                );
+
+                (session_join_event, None)
+            }
+        }
+    }
+
     fn send_session_join_event(
         transport_resource: &mut TransportResource,
         socket_addr: SocketAddr,
@@ -203,6 +473,13 @@ impl<'s> System<'s> for SessionJoinResponderSystem {
             mut session_code_to_id,
             mut session_id_to_device_mappings,
             mut socket_to_device_id,
+            mut session_spectators,
+            session_input_histories,
+            session_limits,
+            mut session_reconnect_token_generator,
+            mut session_reconnect_tokens,
+            mut session_device_reservations,
+            net_peer_versions,
             mut transport_resource,
         }: Self::SystemData,
     ) {
@@ -217,26 +494,80 @@ impl<'s> System<'s> for SessionJoinResponderSystem {
 
         session_join_nec
             .read(&mut self.session_join_event_rid)
-            .filter_map(|session_join_event| {
-                if let NetData {
-                    socket_addr,
-                    data: SessionJoinEvent::SessionJoinRequest(session_join_request_params),
-                } = session_join_event
-                {
-                    Some((*socket_addr, session_join_request_params))
-                } else {
-                    None
-                }
-            })
-            .map(|(socket_addr, session_join_request_params)| {
-                let session_join_and_message_events = Self::handle_session_request(
-                    &mut session_tracker,
-                    &mut socket_to_device_id,
+            .filter_map(|net_session_join_event| {
+                let NetData {
                     socket_addr,
-                    session_join_request_params,
-                );
+                    data: session_join_event,
+                } = net_session_join_event;
+
+                match session_join_event {
+                    SessionJoinEvent::SessionJoinRequest(SessionJoinRequestParams {
+                        session_code,
+                        ..
+                    })
+                    | SessionJoinEvent::SessionSpectateRequest(SessionSpectateRequestParams {
+                        session_code,
+                        ..
+                    })
+                    | SessionJoinEvent::SessionReconnectRequest(SessionReconnectRequestParams {
+                        session_code,
+                        ..
+                    }) if !net_peer_versions.is_compatible(socket_addr) => {
+                        debug!(
+                            "Rejecting request to join session `{}` from `{}`, which has an \
+                             incompatible protocol version.",
+                            session_code, socket_addr
+                        );
 
-                (socket_addr, session_join_and_message_events)
+                        let session_join_event =
+                            SessionJoinEvent::SessionReject(SessionRejectResponse::new(
+                                session_code.clone(),
+                                SessionJoinError::ProtocolVersionMismatch,
+                            ));
+
+                        Some((session_join_event, None))
+                    }
+                    SessionJoinEvent::SessionJoinRequest(session_join_request_params) => {
+                        Some(Self::handle_session_request(
+                            &mut session_tracker,
+                            &mut socket_to_device_id,
+                            &mut session_spectators,
+                            &session_input_histories,
+                            *session_limits,
+                            &mut session_reconnect_token_generator,
+                            &mut session_reconnect_tokens,
+                            *socket_addr,
+                            session_join_request_params,
+                        ))
+                    }
+                    SessionJoinEvent::SessionSpectateRequest(session_spectate_request_params) => {
+                        Some(Self::handle_session_spectate_request(
+                            &mut session_tracker,
+                            &mut socket_to_device_id,
+                            &mut session_spectators,
+                            &session_input_histories,
+                            *session_limits,
+                            *socket_addr,
+                            session_spectate_request_params,
+                        ))
+                    }
+                    SessionJoinEvent::SessionReconnectRequest(session_reconnect_request_params) => {
+                        Some(Self::handle_session_reconnect_request(
+                            &mut session_tracker,
+                            &mut socket_to_device_id,
+                            &mut session_spectators,
+                            &session_input_histories,
+                            &session_reconnect_tokens,
+                            &mut session_device_reservations,
+                            *socket_addr,
+                            session_reconnect_request_params,
+                        ))
+                    }
+                    _ => None,
+                }
+                .map(|session_join_and_message_events| {
+                    (*socket_addr, session_join_and_message_events)
+                })
             })
             .collect::<Vec<_>>()
             .into_iter()
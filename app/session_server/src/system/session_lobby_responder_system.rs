@@ -1,4 +1,4 @@
-use std::net::SocketAddr;
+use std::{collections::HashSet, net::SocketAddr};
 
 use amethyst::{
     derive::SystemDesc,
@@ -10,12 +10,35 @@ use amethyst::{
 use derivative::Derivative;
 use derive_new::new;
 use log::{debug, error};
-use net_model::play::{NetData, NetEventChannel, NetMessageEvent};
-use session_lobby_model::{play::SessionStartRequestParams, SessionLobbyEvent};
+use net_model::play::{NetData, NetEventChannel, NetMessageEvent, NetSessionDevices};
+use network_session_model::play::{SessionDeviceId, Sessions};
+use rollback_model::play::SessionInputHistory;
+use session_lobby_model::{
+    play::{
+        SessionCharacterSelectionParams, SessionChatMessage, SessionChatRequestParams,
+        SessionDeviceReady, SessionMapSelectionParams, SessionReadyRequestParams,
+        SessionStartRequestParams,
+    },
+    SessionLobbyEvent,
+};
+
+use crate::model::{
+    SessionCharacterSelectionStatuses, SessionChecksumStatus, SessionChecksumStatuses,
+    SessionCodeId, SessionCodeToId, SessionDeviceMappingsRead, SessionIdToDeviceMappings,
+    SessionInputHistories, SessionReadyStatuses, SessionSpectators, SocketToDeviceId,
+};
 
-use crate::model::{SessionCodeToId, SessionDeviceMappingsRead, SessionIdToDeviceMappings};
+/// Maximum number of characters in a lobby chat message.
+const SESSION_CHAT_MESSAGE_LENGTH_MAX: usize = 200;
 
 /// Accepts or rejects session start requests, and notifies all connected devices.
+///
+/// Starting a session begins recording its input history, so that spectators may catch up. Only
+/// the session host may start the session, and only when every device is ready.
+///
+/// Chat messages, ready flags, and character selections are relayed to all devices in the
+/// sender's session, including the sender. The host's map selection is relayed once every device
+/// has sent its character selections.
 #[derive(Debug, SystemDesc, new)]
 #[system_desc(name(SessionLobbyResponderSystemDesc))]
 pub struct SessionLobbyResponderSystem {
@@ -30,18 +53,418 @@ pub struct SessionLobbyResponderSystemData<'s> {
     /// `SessionLobbyEvent` channel.
     #[derivative(Debug = "ignore")]
     pub session_lobby_nec: Read<'s, NetEventChannel<SessionLobbyEvent>>,
+    /// `SessionLobbyResources`.
+    #[derivative(Debug = "ignore")]
+    pub session_lobby_resources: SessionLobbyResources<'s>,
+}
+
+/// Resources used to respond to `SessionLobbyEvent`s.
+#[derive(Derivative, SystemData)]
+#[derivative(Debug)]
+pub struct SessionLobbyResources<'s> {
     /// `SessionCodeToId` resource.
     #[derivative(Debug = "ignore")]
     pub session_code_to_id: Read<'s, SessionCodeToId>,
     /// `SessionIdToDeviceMappings` resource.
     #[derivative(Debug = "ignore")]
     pub session_id_to_device_mappings: Read<'s, SessionIdToDeviceMappings>,
+    /// `Sessions` resource.
+    #[derivative(Debug = "ignore")]
+    pub sessions: Read<'s, Sessions>,
+    /// `SocketToDeviceId` resource.
+    #[derivative(Debug = "ignore")]
+    pub socket_to_device_id: Read<'s, SocketToDeviceId>,
+    /// `SessionSpectators` resource.
+    #[derivative(Debug = "ignore")]
+    pub session_spectators: Read<'s, SessionSpectators>,
+    /// `SessionReadyStatuses` resource.
+    #[derivative(Debug = "ignore")]
+    pub session_ready_statuses: Write<'s, SessionReadyStatuses>,
+    /// `SessionCharacterSelectionStatuses` resource.
+    #[derivative(Debug = "ignore")]
+    pub session_character_selection_statuses: Write<'s, SessionCharacterSelectionStatuses>,
+    /// `SessionChecksumStatuses` resource.
+    #[derivative(Debug = "ignore")]
+    pub session_checksum_statuses: Write<'s, SessionChecksumStatuses>,
+    /// `SessionInputHistories` resource.
+    #[derivative(Debug = "ignore")]
+    pub session_input_histories: Write<'s, SessionInputHistories>,
     /// `TransportResource` resource.
     #[derivative(Debug = "ignore")]
     pub transport_resource: Write<'s, TransportResource>,
 }
 
+impl<'s> SessionLobbyResources<'s> {
+    /// Returns the `SessionCodeId` and `NetSessionDevices` of the session the socket is in.
+    fn session_code_id_and_devices(
+        &self,
+        socket_addr: SocketAddr,
+    ) -> Option<(SessionCodeId, &NetSessionDevices)> {
+        let session_code_id = self
+            .session_id_to_device_mappings
+            .session_code_id(&socket_addr)?;
+        let net_session_devices = self
+            .session_id_to_device_mappings
+            .net_session_devices(session_code_id)?;
+
+        Some((session_code_id, net_session_devices))
+    }
+}
+
 impl SessionLobbyResponderSystem {
+    fn handle_session_start_request(
+        session_lobby_resources: &mut SessionLobbyResources<'_>,
+        socket_addr: SocketAddr,
+        session_start_request_params: &SessionStartRequestParams,
+    ) {
+        let SessionStartRequestParams { session_code } = session_start_request_params;
+
+        if session_lobby_resources
+            .session_spectators
+            .contains(&socket_addr)
+        {
+            debug!(
+                "Received `{:?}` from {:?}, but spectators may not start a session.",
+                session_start_request_params, socket_addr
+            );
+            return;
+        }
+
+        if session_lobby_resources
+            .socket_to_device_id
+            .get(&socket_addr)
+            != Some(&SessionDeviceId::HOST)
+        {
+            debug!(
+                "Received `{:?}` from {:?}, but only the session host may start a session.",
+                session_start_request_params, socket_addr
+            );
+            return;
+        }
+
+        let session_device_mappings_read = SessionDeviceMappingsRead::new(
+            &session_lobby_resources.session_code_to_id,
+            &session_lobby_resources.session_id_to_device_mappings,
+        );
+
+        // Make sure the start request is for the correct `session_code`.
+        if let Some(session_code_tracked) = session_device_mappings_read.session_code(&socket_addr)
+        {
+            if session_code_tracked == session_code {
+                let session_code_id = session_device_mappings_read
+                    .session_code_to_id
+                    .id(session_code);
+                let all_ready = session_code_id
+                    .and_then(|session_code_id| {
+                        session_lobby_resources
+                            .session_ready_statuses
+                            .get(&session_code_id)
+                    })
+                    .and_then(|session_device_readies| {
+                        session_lobby_resources
+                            .sessions
+                            .get(session_code)
+                            .map(|session| {
+                                session_device_readies.all_ready(&session.session_devices)
+                            })
+                    })
+                    .unwrap_or(false);
+                if !all_ready {
+                    debug!(
+                        "Received `{:?}` from {:?}, but not every device is ready.",
+                        session_start_request_params, socket_addr
+                    );
+                    return;
+                }
+
+                if let Some(net_session_devices) =
+                    session_device_mappings_read.net_session_devices(session_code)
+                {
+                    debug!(
+                        "Sending `SessionStartNotify` for session: `{}`.",
+                        session_code
+                    );
+
+                    let socket_addrs = net_session_devices
+                        .iter()
+                        .map(|net_session_device| net_session_device.socket_addr);
+                    Self::send_session_lobby_event(
+                        &mut session_lobby_resources.transport_resource,
+                        socket_addrs,
+                        SessionLobbyEvent::SessionStartNotify,
+                    );
+
+                    if let Some(session_code_id) = session_code_id {
+                        session_lobby_resources
+                            .session_input_histories
+                            .insert(session_code_id, SessionInputHistory::default());
+                        session_lobby_resources
+                            .session_ready_statuses
+                            .remove(&session_code_id);
+                        session_lobby_resources
+                            .session_character_selection_statuses
+                            .insert(session_code_id, HashSet::new());
+                        session_lobby_resources
+                            .session_checksum_statuses
+                            .insert(session_code_id, SessionChecksumStatus::default());
+                    }
+                }
+            } else {
+                debug!(
+                    "Received `{:?}` from {:?}, but session code tracked is `{}`.",
+                    session_start_request_params, socket_addr, session_code_tracked,
+                );
+                // TODO: reject because the session code doesn't match
+            }
+        } else {
+            debug!(
+                "Received `{:?}` from {:?}, but no session code tracked for that socket.",
+                session_start_request_params, socket_addr
+            );
+            // TODO: reject
+        }
+    }
+
+    fn handle_session_chat_request(
+        session_lobby_resources: &mut SessionLobbyResources<'_>,
+        socket_addr: SocketAddr,
+        session_chat_request_params: &SessionChatRequestParams,
+    ) {
+        let SessionLobbyResources {
+            session_code_to_id,
+            session_id_to_device_mappings,
+            transport_resource,
+            ..
+        } = session_lobby_resources;
+        let session_device_mappings_read =
+            SessionDeviceMappingsRead::new(session_code_to_id, session_id_to_device_mappings);
+        let net_session_devices = session_device_mappings_read
+            .session_code(&socket_addr)
+            .and_then(|session_code| {
+                session_device_mappings_read.net_session_devices(session_code)
+            });
+
+        if let Some(net_session_devices) = net_session_devices {
+            let session_device_name = net_session_devices
+                .iter()
+                .find(|net_session_device| net_session_device.socket_addr == socket_addr)
+                .map(|net_session_device| net_session_device.data.name.clone());
+
+            if let Some(session_device_name) = session_device_name {
+                let message = session_chat_request_params
+                    .message
+                    .chars()
+                    .take(SESSION_CHAT_MESSAGE_LENGTH_MAX)
+                    .collect::<String>();
+                let session_chat_message = SessionChatMessage::new(session_device_name, message);
+
+                let socket_addrs = net_session_devices
+                    .iter()
+                    .map(|net_session_device| net_session_device.socket_addr);
+                Self::send_session_lobby_event(
+                    transport_resource,
+                    socket_addrs,
+                    SessionLobbyEvent::SessionChatNotify(session_chat_message),
+                );
+            }
+        } else {
+            debug!(
+                "Received `{:?}` from {:?}, but no session code tracked for that socket.",
+                session_chat_request_params, socket_addr
+            );
+        }
+    }
+
+    fn handle_session_ready_request(
+        session_lobby_resources: &mut SessionLobbyResources<'_>,
+        socket_addr: SocketAddr,
+        session_ready_request_params: SessionReadyRequestParams,
+    ) {
+        let SessionLobbyResources {
+            session_id_to_device_mappings,
+            socket_to_device_id,
+            session_spectators,
+            session_ready_statuses,
+            session_input_histories,
+            transport_resource,
+            ..
+        } = session_lobby_resources;
+
+        if session_spectators.contains(&socket_addr) {
+            return;
+        }
+
+        let session_code_id = session_id_to_device_mappings.session_code_id(&socket_addr);
+        let net_session_devices = session_code_id.and_then(|session_code_id| {
+            session_id_to_device_mappings.net_session_devices(session_code_id)
+        });
+        let session_device_id = socket_to_device_id.get(&socket_addr).copied();
+
+        if let (Some(session_code_id), Some(net_session_devices), Some(session_device_id)) =
+            (session_code_id, net_session_devices, session_device_id)
+        {
+            if session_input_histories.contains_key(&session_code_id) {
+                debug!(
+                    "Received `{:?}` from {:?}, but the session has already started.",
+                    session_ready_request_params, socket_addr
+                );
+                return;
+            }
+
+            let SessionReadyRequestParams { ready } = session_ready_request_params;
+            session_ready_statuses
+                .entry(session_code_id)
+                .or_default()
+                .set(session_device_id, ready);
+
+            let socket_addrs = net_session_devices
+                .iter()
+                .map(|net_session_device| net_session_device.socket_addr);
+            Self::send_session_lobby_event(
+                transport_resource,
+                socket_addrs,
+                SessionLobbyEvent::SessionReadyNotify(SessionDeviceReady::new(
+                    session_device_id,
+                    ready,
+                )),
+            );
+        } else {
+            debug!(
+                "Received `{:?}` from {:?}, but no session code tracked for that socket.",
+                session_ready_request_params, socket_addr
+            );
+        }
+    }
+
+    fn handle_session_character_select_request(
+        session_lobby_resources: &mut SessionLobbyResources<'_>,
+        socket_addr: SocketAddr,
+        session_character_selection_params: &SessionCharacterSelectionParams,
+    ) {
+        let session_code_id_and_devices =
+            session_lobby_resources.session_code_id_and_devices(socket_addr);
+        let net_session_device =
+            session_code_id_and_devices.and_then(|(_, net_session_devices)| {
+                net_session_devices
+                    .iter()
+                    .find(|net_session_device| net_session_device.socket_addr == socket_addr)
+            });
+
+        if let (Some((session_code_id, net_session_devices)), Some(net_session_device)) =
+            (session_code_id_and_devices, net_session_device)
+        {
+            if !session_lobby_resources
+                .session_character_selection_statuses
+                .contains_key(&session_code_id)
+            {
+                debug!(
+                    "Received `{:?}` from {:?}, but the session has not started.",
+                    session_character_selection_params, socket_addr
+                );
+                return;
+            }
+
+            // Devices may only select characters for their own controllers.
+            let player_controllers = &net_session_device.data.player_controllers;
+            let mut session_character_selection_params = session_character_selection_params.clone();
+            session_character_selection_params
+                .character_selections
+                .retain(|session_character_selection| {
+                    player_controllers.iter().any(|player_controller| {
+                        player_controller.controller_id == session_character_selection.controller_id
+                    })
+                });
+
+            let session_device_id = net_session_device.data.id;
+            let socket_addrs = net_session_devices
+                .iter()
+                .map(|net_session_device| net_session_device.socket_addr)
+                .collect::<Vec<SocketAddr>>();
+
+            if let Some(session_device_ids) = session_lobby_resources
+                .session_character_selection_statuses
+                .get_mut(&session_code_id)
+            {
+                session_device_ids.insert(session_device_id);
+            }
+            Self::send_session_lobby_event(
+                &mut session_lobby_resources.transport_resource,
+                socket_addrs.into_iter(),
+                SessionLobbyEvent::SessionCharacterSelectNotify(session_character_selection_params),
+            );
+        } else {
+            debug!(
+                "Received `{:?}` from {:?}, but no session code tracked for that socket.",
+                session_character_selection_params, socket_addr
+            );
+        }
+    }
+
+    fn handle_session_map_select_request(
+        session_lobby_resources: &mut SessionLobbyResources<'_>,
+        socket_addr: SocketAddr,
+        session_map_selection_params: &SessionMapSelectionParams,
+    ) {
+        if session_lobby_resources
+            .socket_to_device_id
+            .get(&socket_addr)
+            != Some(&SessionDeviceId::HOST)
+        {
+            debug!(
+                "Received `{:?}` from {:?}, but only the session host may select the map.",
+                session_map_selection_params, socket_addr
+            );
+            return;
+        }
+
+        if let Some((session_code_id, net_session_devices)) =
+            session_lobby_resources.session_code_id_and_devices(socket_addr)
+        {
+            // Every connected player device must have sent its character selections, so that
+            // every device loads the game with the same selections.
+            let all_selected = session_lobby_resources
+                .session_character_selection_statuses
+                .get(&session_code_id)
+                .map(|session_device_ids| {
+                    net_session_devices
+                        .iter()
+                        .filter(|net_session_device| {
+                            !net_session_device.data.player_controllers.is_empty()
+                        })
+                        .all(|net_session_device| {
+                            session_device_ids.contains(&net_session_device.data.id)
+                        })
+                })
+                .unwrap_or(false);
+
+            if !all_selected {
+                debug!(
+                    "Received `{:?}` from {:?}, but not every device has selected characters.",
+                    session_map_selection_params, socket_addr
+                );
+                return;
+            }
+
+            let socket_addrs = net_session_devices
+                .iter()
+                .map(|net_session_device| net_session_device.socket_addr)
+                .collect::<Vec<SocketAddr>>();
+            session_lobby_resources
+                .session_character_selection_statuses
+                .remove(&session_code_id);
+            Self::send_session_lobby_event(
+                &mut session_lobby_resources.transport_resource,
+                socket_addrs.into_iter(),
+                SessionLobbyEvent::SessionMapSelectNotify(session_map_selection_params.clone()),
+            );
+        } else {
+            debug!(
+                "Received `{:?}` from {:?}, but no session code tracked for that socket.",
+                session_map_selection_params, socket_addr
+            );
+        }
+    }
+
     fn send_session_lobby_event(
         transport_resource: &mut TransportResource,
         socket_addrs: impl Iterator<Item = SocketAddr>,
@@ -81,68 +504,54 @@ impl<'s> System<'s> for SessionLobbyResponderSystem {
         &mut self,
         SessionLobbyResponderSystemData {
             session_lobby_nec,
-            session_code_to_id,
-            session_id_to_device_mappings,
-            mut transport_resource,
+            mut session_lobby_resources,
         }: Self::SystemData,
     ) {
-        let session_code_to_id = &*session_code_to_id;
-        let session_id_to_device_mappings = &*session_id_to_device_mappings;
-        let session_device_mappings_read = SessionDeviceMappingsRead {
-            session_code_to_id,
-            session_id_to_device_mappings,
-        };
         session_lobby_nec
             .read(&mut self.session_lobby_event_rid)
-            .filter_map(|session_lobby_event| {
-                if let NetData {
+            .for_each(|session_lobby_event| {
+                let NetData {
                     socket_addr,
-                    data: SessionLobbyEvent::SessionStartRequest(session_start_request_params),
-                } = session_lobby_event
-                {
-                    Some((*socket_addr, session_start_request_params))
-                } else {
-                    None
-                }
-            })
-            .for_each(|(socket_addr, session_start_request_params)| {
-                let SessionStartRequestParams { session_code } = session_start_request_params;
+                    data: session_lobby_event,
+                } = session_lobby_event;
 
-                // Make sure the start request is for the correct `session_code`.
-                if let Some(session_code_tracked) =
-                    session_device_mappings_read.session_code(&socket_addr)
-                {
-                    if session_code_tracked == session_code {
-                        if let Some(net_session_devices) =
-                            session_device_mappings_read.net_session_devices(session_code)
-                        {
-                            debug!(
-                                "Sending `SessionStartNotify` for session: `{}`.",
-                                session_code
-                            );
-
-                            let socket_addrs = net_session_devices
-                                .iter()
-                                .map(|net_session_device| net_session_device.socket_addr);
-                            Self::send_session_lobby_event(
-                                &mut transport_resource,
-                                socket_addrs,
-                                SessionLobbyEvent::SessionStartNotify,
-                            );
-                        }
-                    } else {
-                        debug!(
-                            "Received `{:?}` from {:?}, but session code tracked is `{}`.",
-                            session_start_request_params, socket_addr, session_code_tracked,
-                        );
-                        // TODO: reject because the session code doesn't match
+                match session_lobby_event {
+                    SessionLobbyEvent::SessionStartRequest(session_start_request_params) => {
+                        Self::handle_session_start_request(
+                            &mut session_lobby_resources,
+                            *socket_addr,
+                            session_start_request_params,
+                        )
                     }
-                } else {
-                    debug!(
-                        "Received `{:?}` from {:?}, but no session code tracked for that socket.",
-                        session_start_request_params, socket_addr
-                    );
-                    // TODO: reject
+                    SessionLobbyEvent::SessionChatRequest(session_chat_request_params) => {
+                        Self::handle_session_chat_request(
+                            &mut session_lobby_resources,
+                            *socket_addr,
+                            session_chat_request_params,
+                        )
+                    }
+                    SessionLobbyEvent::SessionReadyRequest(session_ready_request_params) => {
+                        Self::handle_session_ready_request(
+                            &mut session_lobby_resources,
+                            *socket_addr,
+                            *session_ready_request_params,
+                        )
+                    }
+                    SessionLobbyEvent::SessionCharacterSelectRequest(
+                        session_character_selection_params,
+                    ) => Self::handle_session_character_select_request(
+                        &mut session_lobby_resources,
+                        *socket_addr,
+                        session_character_selection_params,
+                    ),
+                    SessionLobbyEvent::SessionMapSelectRequest(session_map_selection_params) => {
+                        Self::handle_session_map_select_request(
+                            &mut session_lobby_resources,
+                            *socket_addr,
+                            session_map_selection_params,
+                        )
+                    }
+                    _ => {}
                 }
             });
     }
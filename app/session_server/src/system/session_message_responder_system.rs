@@ -12,13 +12,17 @@ use derive_new::new;
 use log::{debug, error, warn};
 use net_model::play::{NetData, NetEventChannel, NetMessageEvent};
 use network_session_model::{
-    play::{SessionDeviceId, SessionDeviceName},
+    play::{
+        SessionChecksums, SessionDeviceId, SessionDeviceLatency, SessionDeviceName, SessionPing,
+        SessionPong,
+    },
     SessionMessageEvent,
 };
 
 use crate::model::{
-    GameInputTickStatus, SessionCodeId, SessionCodeToId, SessionDeviceTickStatuses,
-    SessionIdToDeviceMappings, SessionTickStatuses, SocketToDeviceId,
+    GameInputTickStatus, SessionChecksumStatuses, SessionCodeId, SessionCodeToId,
+    SessionDeviceTickStatuses, SessionIdToDeviceMappings, SessionInputHistories, SessionSpectators,
+    SessionTickStatuses, SocketToDeviceId,
 };
 
 /// Notifies game clients when all `GameInputEvent`s have been sent for the current tick.
@@ -26,6 +30,15 @@ use crate::model::{
 /// When `SessionMessageEvent::GameInputTick` has been received from each client, the session server then sends its own
 /// `SessionMessageEvent::GameInputTick` messages to all clients, notifying them that all `GameInputEvent`s have been
 /// sent to them.
+///
+/// Spectators do not send input, so their `GameInputTick` messages are not waited for.
+///
+/// `SessionMessageEvent::SessionPing`s are responded to with a `SessionPong`, and the latency that
+/// the client has measured is sent to all devices in its session.
+///
+/// `SessionMessageEvent::SessionChecksums` are compared once received from all player devices, and
+/// `SessionMessageEvent::SessionDesyncNotify` is sent to all devices for the first tick that
+/// differs.
 #[derive(Debug, SystemDesc, new)]
 #[system_desc(name(SessionMessageResponderSystemDesc))]
 pub struct SessionMessageResponderSystem {
@@ -52,6 +65,15 @@ pub struct SessionMessageResponderSystemData<'s> {
     /// `SessionTickStatuses` resource.
     #[derivative(Debug = "ignore")]
     pub session_tick_statuses: Write<'s, SessionTickStatuses>,
+    /// `SessionSpectators` resource.
+    #[derivative(Debug = "ignore")]
+    pub session_spectators: Read<'s, SessionSpectators>,
+    /// `SessionInputHistories` resource.
+    #[derivative(Debug = "ignore")]
+    pub session_input_histories: Write<'s, SessionInputHistories>,
+    /// `SessionChecksumStatuses` resource.
+    #[derivative(Debug = "ignore")]
+    pub session_checksum_statuses: Write<'s, SessionChecksumStatuses>,
     /// `TransportResource` resource.
     #[derivative(Debug = "ignore")]
     pub transport_resource: Write<'s, TransportResource>,
@@ -167,6 +189,40 @@ impl SessionMessageResponderSystem {
             .all(|game_input_tick_status| *game_input_tick_status == GameInputTickStatus::Received)
     }
 
+    /// Ticks the session when `SessionMessageEvent::GameInputTick` has been received from all
+    /// devices in the session.
+    ///
+    /// `SessionMessageEvent::GameInputTick` is sent to all devices, the session's input history is
+    /// ticked, and the session device tick statuses are reset.
+    pub(crate) fn session_tick_if_all_received(
+        session_id_to_device_mappings: &SessionIdToDeviceMappings,
+        session_code_to_id: &SessionCodeToId,
+        session_input_histories: &mut SessionInputHistories,
+        transport_resource: &mut TransportResource,
+        session_device_tick_statuses: &mut SessionDeviceTickStatuses,
+        session_code_id: SessionCodeId,
+    ) {
+        let session_tick_all_received =
+            Self::session_tick_all_received(session_device_tick_statuses);
+        if session_tick_all_received {
+            Self::session_game_input_tick(
+                session_id_to_device_mappings,
+                session_code_to_id,
+                transport_resource,
+                session_code_id,
+            );
+
+            if let Some(session_input_history) = session_input_histories.get_mut(&session_code_id) {
+                session_input_history.tick();
+            }
+
+            // Reset session device tick statuses.
+            session_device_tick_statuses
+                .values_mut()
+                .for_each(|tick_status| *tick_status = GameInputTickStatus::Pending);
+        }
+    }
+
     /// Sends `SessionMessageEvent::GameInputTick` to all devices in a `Session`.
     fn session_game_input_tick(
         session_id_to_device_mappings: &SessionIdToDeviceMappings,
@@ -212,7 +268,135 @@ impl SessionMessageResponderSystem {
         transport_resource: &mut TransportResource,
         socket_addrs: impl Iterator<Item = SocketAddr>,
     ) {
-        let net_message_event = NetMessageEvent::from(SessionMessageEvent::GameInputTick);
+        Self::send_session_message_event(
+            transport_resource,
+            socket_addrs,
+            SessionMessageEvent::GameInputTick,
+        );
+    }
+
+    /// Responds to a `SessionPing` and sends the client's latency to all devices in its session.
+    fn session_ping_respond(
+        session_id_to_device_mappings: &SessionIdToDeviceMappings,
+        socket_to_device_id: &SocketToDeviceId,
+        transport_resource: &mut TransportResource,
+        socket_addr: SocketAddr,
+        session_ping: SessionPing,
+    ) {
+        let SessionPing {
+            sequence,
+            session_latency,
+        } = session_ping;
+
+        Self::send_session_message_event(
+            transport_resource,
+            std::iter::once(socket_addr),
+            SessionMessageEvent::SessionPong(SessionPong::new(sequence)),
+        );
+
+        let session_latency = if let Some(session_latency) = session_latency {
+            session_latency
+        } else {
+            return;
+        };
+
+        let session_device_id = socket_to_device_id.get(&socket_addr).copied();
+        let net_session_devices = session_id_to_device_mappings
+            .session_code_id(&socket_addr)
+            .and_then(|session_code_id| {
+                session_id_to_device_mappings.net_session_devices(session_code_id)
+            });
+
+        if let (Some(session_device_id), Some(net_session_devices)) =
+            (session_device_id, net_session_devices)
+        {
+            let socket_addrs = net_session_devices
+                .iter()
+                .map(|net_session_device| net_session_device.socket_addr);
+            let session_device_latency =
+                SessionDeviceLatency::new(session_device_id, session_latency);
+
+            Self::send_session_message_event(
+                transport_resource,
+                socket_addrs,
+                SessionMessageEvent::SessionDeviceLatencyNotify(session_device_latency),
+            );
+        } else {
+            warn!(
+                "Received `SessionMessageEvent::SessionPing` from {}, but no session tracked for that socket.",
+                socket_addr
+            );
+        }
+    }
+
+    /// Records a client's `SessionChecksums`, and notifies all devices in the session if the
+    /// checksums for a tick differ.
+    fn session_checksums_compare(
+        session_id_to_device_mappings: &SessionIdToDeviceMappings,
+        socket_to_device_id: &SocketToDeviceId,
+        session_spectators: &SessionSpectators,
+        session_checksum_statuses: &mut SessionChecksumStatuses,
+        transport_resource: &mut TransportResource,
+        socket_addr: SocketAddr,
+        session_checksums: SessionChecksums,
+    ) {
+        let session_device_id = socket_to_device_id.get(&socket_addr).copied();
+        let session_code_id = session_id_to_device_mappings.session_code_id(&socket_addr);
+        let net_session_devices = session_code_id.and_then(|session_code_id| {
+            session_id_to_device_mappings.net_session_devices(session_code_id)
+        });
+
+        if let (Some(session_device_id), Some(session_code_id), Some(net_session_devices)) =
+            (session_device_id, session_code_id, net_session_devices)
+        {
+            let session_device_ids = net_session_devices
+                .iter()
+                .filter(|net_session_device| {
+                    !session_spectators.contains(&net_session_device.socket_addr)
+                })
+                .map(|net_session_device| net_session_device.data.id)
+                .collect::<Vec<SessionDeviceId>>();
+            let session_checksum_status = session_checksum_statuses
+                .entry(session_code_id)
+                .or_default();
+
+            let session_desync = session_checksums
+                .0
+                .into_iter()
+                .find_map(|session_checksum| {
+                    session_checksum_status.insert(
+                        &session_device_ids,
+                        session_device_id,
+                        session_checksum,
+                    )
+                });
+
+            if let Some(session_desync) = session_desync {
+                warn!("{}", session_desync);
+
+                let socket_addrs = net_session_devices
+                    .iter()
+                    .map(|net_session_device| net_session_device.socket_addr);
+                Self::send_session_message_event(
+                    transport_resource,
+                    socket_addrs,
+                    SessionMessageEvent::SessionDesyncNotify(session_desync),
+                );
+            }
+        } else {
+            warn!(
+                "Received `SessionMessageEvent::SessionChecksums` from {}, but no session tracked for that socket.",
+                socket_addr
+            );
+        }
+    }
+
+    fn send_session_message_event(
+        transport_resource: &mut TransportResource,
+        socket_addrs: impl Iterator<Item = SocketAddr>,
+        session_message_event: SessionMessageEvent,
+    ) {
+        let net_message_event = NetMessageEvent::from(session_message_event);
 
         match bincode::serialize(&net_message_event) {
             Ok(payload) => {
@@ -231,8 +415,8 @@ impl SessionMessageResponderSystem {
             }
             Err(e) => {
                 error!(
-                    "Failed to serialize `NetMessageEvent::InputEvent`. Error: `{}`.",
-                    e
+                    "Failed to serialize `{:?}`. Error: `{}`.",
+                    net_message_event, e
                 );
             }
         }
@@ -250,11 +434,16 @@ impl<'s> System<'s> for SessionMessageResponderSystem {
             socket_to_device_id,
             session_code_to_id,
             mut session_tick_statuses,
+            session_spectators,
+            mut session_input_histories,
+            mut session_checksum_statuses,
             mut transport_resource,
         }: Self::SystemData,
     ) {
         // 1. When a client sends `SessionMessageEvent::GameInputTick`, record it.
         // 2. When all clients have sent `GameInputTick`, send `GameInputTick` to all of them.
+        // 3. When a client sends `SessionMessageEvent::SessionPing`, respond with `SessionPong`.
+        // 4. When all players have sent `SessionChecksums` for a tick, compare them.
         //
         // Error cases:
         //
@@ -265,50 +454,71 @@ impl<'s> System<'s> for SessionMessageResponderSystem {
         let session_tick_statuses = &mut session_tick_statuses;
         session_message_nec
             .read(&mut self.session_message_event_rid)
-            .filter(|net_session_message_event| {
-                net_session_message_event.data == SessionMessageEvent::GameInputTick
-            })
             .for_each(|net_session_message_event| {
-                let session_information = Self::select_session_information(
-                    session_id_to_device_mappings,
-                    &socket_to_device_id,
-                    session_tick_statuses,
-                    net_session_message_event,
-                );
-
-                let NetData { socket_addr, .. } = net_session_message_event;
+                let NetData { socket_addr, data } = net_session_message_event;
 
-                match session_information {
-                    Ok((session_device_tick_statuses, session_code_id, session_device_id)) => {
-                        // Record `GameInputTick` for this client.
-                        Self::tick_device(
+                match data {
+                    SessionMessageEvent::GameInputTick
+                        if !session_spectators.contains(socket_addr) =>
+                    {
+                        let session_information = Self::select_session_information(
                             session_id_to_device_mappings,
-                            session_device_tick_statuses,
-                            *socket_addr,
-                            session_code_id,
-                            session_device_id,
+                            &socket_to_device_id,
+                            session_tick_statuses,
+                            net_session_message_event,
                         );
 
-                        // When all devices are ready, send `GameInputTick`
-                        let session_tick_all_received =
-                            Self::session_tick_all_received(session_device_tick_statuses);
-                        if session_tick_all_received {
-                            Self::session_game_input_tick(
-                                session_id_to_device_mappings,
-                                &session_code_to_id,
-                                &mut transport_resource,
+                        match session_information {
+                            Ok((
+                                session_device_tick_statuses,
                                 session_code_id,
-                            );
-
-                            // Reset session device tick statuses.
-                            session_device_tick_statuses
-                                .values_mut()
-                                .for_each(|tick_status| {
-                                    *tick_status = GameInputTickStatus::Pending
-                                });
+                                session_device_id,
+                            )) => {
+                                // Record `GameInputTick` for this client.
+                                Self::tick_device(
+                                    session_id_to_device_mappings,
+                                    session_device_tick_statuses,
+                                    *socket_addr,
+                                    session_code_id,
+                                    session_device_id,
+                                );
+
+                                // When all devices are ready, send `GameInputTick`
+                                Self::session_tick_if_all_received(
+                                    session_id_to_device_mappings,
+                                    &session_code_to_id,
+                                    &mut session_input_histories,
+                                    &mut transport_resource,
+                                    session_device_tick_statuses,
+                                    session_code_id,
+                                );
+                            }
+                            Err(e) => warn!("{}", e),
                         }
                     }
-                    Err(e) => warn!("{}", e),
+                    SessionMessageEvent::SessionPing(session_ping) => {
+                        Self::session_ping_respond(
+                            session_id_to_device_mappings,
+                            &socket_to_device_id,
+                            &mut transport_resource,
+                            *socket_addr,
+                            *session_ping,
+                        );
+                    }
+                    SessionMessageEvent::SessionChecksums(session_checksums)
+                        if !session_spectators.contains(socket_addr) =>
+                    {
+                        Self::session_checksums_compare(
+                            session_id_to_device_mappings,
+                            &socket_to_device_id,
+                            &session_spectators,
+                            &mut session_checksum_statuses,
+                            &mut transport_resource,
+                            *socket_addr,
+                            session_checksums.clone(),
+                        );
+                    }
+                    _ => {}
                 }
             });
     }
@@ -0,0 +1,171 @@
+//! Runs the `session_server` binary, and checks its response to protocol handshakes sent by a
+//! client over loopback.
+
+use std::{
+    any,
+    net::{Ipv4Addr, TcpListener},
+    process::{Child, Stdio},
+    time::{Duration, Instant},
+};
+
+use amethyst::{
+    ecs::WorldExt, network::simulation::web_socket::WebSocketNetworkBundle, shrev::EventChannel,
+    Error, State, StateData, Trans,
+};
+use amethyst_test::{AmethystApplication, GameUpdate};
+use escargot::CargoBuild;
+use net_model::play::{NetHandshakeEvent, NetHandshakeStatus, NetMessageEvent, NetProtocolVersion};
+use net_play::{
+    NetHandshakeResponseSystem, NetHandshakeResponseSystemDesc, NetListenerSystem,
+    NetListenerSystemDesc, NetMessageRequestSystem, NetMessageRequestSystemDesc,
+};
+use network_session_model::config::SessionServerConfig;
+
+/// Maximum time to wait for the session server to respond.
+const RESPONSE_TIMEOUT: Duration = Duration::from_secs(30);
+/// Time to wait before sending the request again, in case the server had not started listening.
+const REQUEST_INTERVAL: Duration = Duration::from_secs(1);
+
+#[test]
+fn accepts_current_protocol_version_and_rejects_different_protocol_version() -> Result<(), Error> {
+    let session_server = SessionServer::spawn()?;
+
+    run_test(
+        session_server.session_server_config.clone(),
+        NetProtocolVersion::CURRENT,
+        NetHandshakeStatus::Accepted,
+    )?;
+    run_test(
+        session_server.session_server_config.clone(),
+        NetProtocolVersion::new(!NetProtocolVersion::CURRENT.0),
+        NetHandshakeStatus::Rejected {
+            server_version: NetProtocolVersion::CURRENT,
+        },
+    )
+}
+
+fn run_test(
+    session_server_config: SessionServerConfig,
+    net_protocol_version: NetProtocolVersion,
+    net_handshake_status_expected: NetHandshakeStatus,
+) -> Result<(), Error> {
+    AmethystApplication::blank()
+        .with_bundle(WebSocketNetworkBundle::new(None))
+        .with_system_desc(
+            NetMessageRequestSystemDesc::default(),
+            any::type_name::<NetMessageRequestSystem>(),
+            &[],
+        )
+        .with_system_desc(
+            NetListenerSystemDesc::default(),
+            any::type_name::<NetListenerSystem>(),
+            &["network_recv"],
+        )
+        .with_system_desc(
+            NetHandshakeResponseSystemDesc::default(),
+            any::type_name::<NetHandshakeResponseSystem>(),
+            &[any::type_name::<NetListenerSystem>()],
+        )
+        .with_setup(move |world| world.insert(session_server_config))
+        .with_state(move || WaitForHandshake::new(net_protocol_version))
+        .with_assertion(move |world| {
+            let net_handshake_status = *world.read_resource::<NetHandshakeStatus>();
+
+            assert_eq!(net_handshake_status_expected, net_handshake_status);
+        })
+        .run()
+}
+
+/// `session_server` process, which is killed when dropped.
+#[derive(Debug)]
+struct SessionServer {
+    /// Child process running the session server.
+    child: Child,
+    /// Address that the session server is listening on.
+    session_server_config: SessionServerConfig,
+}
+
+impl SessionServer {
+    fn spawn() -> Result<Self, Error> {
+        // Find a free port for the session server to listen on.
+        let port = TcpListener::bind((Ipv4Addr::LOCALHOST, 0))?
+            .local_addr()?
+            .port();
+        let session_server_config = SessionServerConfig {
+            address: Ipv4Addr::LOCALHOST.into(),
+            port,
+        };
+
+        let mut command = CargoBuild::new()
+            .bin("session_server")
+            .current_release()
+            .run()
+            .expect("Failed to create `cargo` command")
+            .command();
+        let child = command
+            .args(&["--address", "127.0.0.1", "--port", &port.to_string()])
+            .stdout(Stdio::null())
+            .stderr(Stdio::null())
+            .spawn()?;
+
+        Ok(SessionServer {
+            child,
+            session_server_config,
+        })
+    }
+}
+
+impl Drop for SessionServer {
+    fn drop(&mut self) {
+        let _ = self.child.kill();
+        let _ = self.child.wait();
+    }
+}
+
+#[derive(Debug)]
+struct WaitForHandshake {
+    net_protocol_version: NetProtocolVersion,
+    start: Instant,
+    request_sent: Option<Instant>,
+}
+
+impl WaitForHandshake {
+    fn new(net_protocol_version: NetProtocolVersion) -> Self {
+        WaitForHandshake {
+            net_protocol_version,
+            start: Instant::now(),
+            request_sent: None,
+        }
+    }
+}
+
+impl<T, E> State<T, E> for WaitForHandshake
+where
+    T: GameUpdate,
+    E: Send + Sync + 'static,
+{
+    fn update(&mut self, data: StateData<'_, T>) -> Trans<T, E> {
+        let request_due = self.request_sent.map_or(true, |request_sent| {
+            request_sent.elapsed() >= REQUEST_INTERVAL
+        });
+        if request_due {
+            data.world
+                .write_resource::<EventChannel<NetMessageEvent>>()
+                .single_write(NetMessageEvent::NetHandshakeEvent(
+                    NetHandshakeEvent::Request(self.net_protocol_version),
+                ));
+            self.request_sent = Some(Instant::now());
+        }
+
+        data.data.update(&data.world);
+
+        let net_handshake_status = *data.world.read_resource::<NetHandshakeStatus>();
+        if net_handshake_status == NetHandshakeStatus::Pending
+            && self.start.elapsed() < RESPONSE_TIMEOUT
+        {
+            Trans::None
+        } else {
+            Trans::Pop
+        }
+    }
+}
@@ -3,15 +3,16 @@
 use std::{
     any,
     convert::TryFrom,
-    net::{IpAddr, Ipv4Addr},
+    net::Ipv4Addr,
     path::{Path, PathBuf},
 };
 #[cfg(not(target_arch = "wasm32"))]
 use std::{fs::File, io::BufReader};
 
+use ai_play::AiControllerSystem;
 use amethyst::{
-    assets::{HotReloadBundle, HotReloadStrategy},
-    audio::AudioBundle,
+    assets::{HotReloadBundle, HotReloadStrategy, Processor},
+    audio::{AudioBundle, Source},
     core::transform::TransformBundle,
     input::{Bindings, InputBundle},
     network::simulation::web_socket::WebSocketNetworkBundle,
@@ -19,12 +20,12 @@ use amethyst::{
         plugins::{RenderFlat2D, RenderToWindow},
         rendy::hal::command::ClearColor,
         types::DefaultBackend,
-        RenderingBundle,
+        RenderEmptyBundle, RenderingBundle,
     },
     ui::{RenderUi, UiBundle},
     utils::{application_root_dir, ortho_camera::CameraOrthoSystem},
-    window::EventLoop,
-    CoreApplication, Error, GameDataBuilder,
+    window::{EventLoop, ScreenDimensions},
+    CoreApplication, Error, GameData, GameDataBuilder, State,
 };
 #[cfg(not(target_arch = "wasm32"))]
 use amethyst::{window::DisplayConfig, LoggerConfig};
@@ -36,6 +37,7 @@ use application_robot::RobotState;
 #[cfg(not(target_arch = "wasm32"))]
 use application_ui::FontConfigLoader;
 use application_ui::{ApplicationUiBundle, FontConfig};
+use asset_model::config::AssetSlug;
 use asset_play::{AssetPlayBundle, ItemIdEventSystem};
 #[cfg(not(target_arch = "wasm32"))]
 use asset_selection_stdio::AssetSelectionStdioBundle;
@@ -50,11 +52,15 @@ use camera_play::CameraPlayBundle;
 use character_loading::CharacterLoadingBundle;
 use collision_audio_loading::CollisionAudioLoadingBundle;
 use collision_loading::CollisionLoadingBundle;
+use control_settings_play::{
+    ControlBindingsDisplaySystem, ControlRebindSystem, ControlRebindSystemDesc,
+};
 use energy_loading::EnergyLoadingBundle;
-use frame_rate::strategy::frame_rate_limit_config;
+use frame_rate::strategy::{frame_rate_limit_config, FRAME_RATE_NO_LIMIT};
 use game_input::{
     ControllerInputUpdateSystem, GameInputToControlInputSystem, GameInputToControlInputSystemDesc,
-    InputToGameInputSystem, InputToGameInputSystemDesc, SharedControllerInputUpdateSystem,
+    GamepadAssignmentSystem, GamepadAssignmentSystemDesc, InputToGameInputSystem,
+    InputToGameInputSystemDesc, SharedControllerInputUpdateSystem,
 };
 use game_input_model::{
     config::{ControlBindings, PlayerInputConfigs},
@@ -67,30 +73,55 @@ use game_mode_selection_stdio::GameModeSelectionStdioBundle;
 use game_mode_selection_ui::GameModeSelectionSfxSystem;
 use game_play::GamePlayBundle;
 #[cfg(not(target_arch = "wasm32"))]
+use game_play_model::play::GamePlaySeed;
+#[cfg(not(target_arch = "wasm32"))]
 use game_play_stdio::GamePlayStdioBundle;
 use input_reaction_loading::InputReactionLoadingBundle;
+use item_loading::ItemLoadingBundle;
 use kinematic_loading::KinematicLoadingBundle;
 use loading::{LoadingBundle, LoadingState};
 #[cfg(not(target_arch = "wasm32"))]
 use log::debug;
 use map_loading::MapLoadingBundle;
 use net_play::{
-    NetListenerSystem, NetListenerSystemDesc, NetMessageRequestSystem, NetMessageRequestSystemDesc,
+    NetHandshakeResponseSystem, NetHandshakeResponseSystemDesc, NetListenerSystem,
+    NetListenerSystemDesc, NetMessageRequestSystem, NetMessageRequestSystemDesc,
 };
 use network_input_play::{
     GameInputTickRequestSystem, NetworkInputRequestSystem, NetworkInputRequestSystemDesc,
-    NetworkInputResponseSystem, NetworkInputResponseSystemDesc,
+    NetworkInputResponseSystem, NetworkInputResponseSystemDesc, SessionChecksumRequestSystem,
+    SessionInputCatchUpSystem,
 };
 #[cfg(not(target_arch = "wasm32"))]
 use network_mode_selection_stdio::NetworkModeSelectionStdioBundle;
 use network_session_model::config::SessionServerConfig;
 use network_session_play::{
     SessionInputResourcesSyncSystem, SessionInputResourcesSyncSystemDesc,
-    SessionMessageResponseSystem, SessionMessageResponseSystemDesc, SessionStatusNotifierSystem,
+    SessionMessageResponseSystem, SessionMessageResponseSystemDesc, SessionPingSystem,
+    SessionPingSystemDesc, SessionStatusNotifierSystem,
 };
 use parent_play::ChildEntityDeleteSystem;
+use replay::{
+    ReplayControlInputPlaybackSystem, ReplayControlInputPlaybackSystemDesc,
+    ReplayDivergenceDetectionSystem, ReplayDivergenceDetectionSystemDesc, ReplayPlaybackSystem,
+    ReplayRecordSystem, ReplayRecordSystemDesc, ReplayState,
+};
+#[cfg(not(target_arch = "wasm32"))]
+use replay_model::config::Replay;
+use replay_model::play::{ReplayPlayback, ReplayRecorder};
+use rollback_model::config::SessionSyncMode;
+use rollback_play::{
+    RollbackInputReplaySystem, RollbackInputRequestSystem, RollbackInputRequestSystemDesc,
+    RollbackInputResponseSystem, RollbackInputResponseSystemDesc,
+};
 use sequence_loading::SequenceLoadingBundle;
 use serde::{Deserialize, Serialize};
+use session_browse_play::{
+    SessionBrowseRequestSystem, SessionBrowseRequestSystemDesc, SessionBrowseResponseSystem,
+    SessionBrowseResponseSystemDesc,
+};
+#[cfg(not(target_arch = "wasm32"))]
+use session_browse_stdio::SessionBrowseStdioBundle;
 use session_host_play::{
     SessionHostRequestSystem, SessionHostRequestSystemDesc, SessionHostResponseSystem,
     SessionHostResponseSystemDesc,
@@ -99,17 +130,26 @@ use session_host_play::{
 use session_host_stdio::SessionHostStdioBundle;
 use session_join_play::{
     SessionJoinRequestSystem, SessionJoinRequestSystemDesc, SessionJoinResponseSystem,
-    SessionJoinResponseSystemDesc,
+    SessionJoinResponseSystemDesc, SessionReconnectSystem, SessionReconnectSystemDesc,
 };
 #[cfg(not(target_arch = "wasm32"))]
 use session_join_stdio::SessionJoinStdioBundle;
 use session_lobby_play::{
     SessionLobbyRequestSystem, SessionLobbyRequestSystemDesc, SessionLobbyResponseSystem,
-    SessionLobbyResponseSystemDesc,
+    SessionLobbyResponseSystemDesc, SessionSelectionRequestSystem,
+    SessionSelectionRequestSystemDesc,
 };
+use session_lobby_stdio::SessionLobbyStdioBundle;
 use session_lobby_ui_play::{
-    SessionCodeLabelUpdateSystem, SessionDeviceEntityCreateDeleteSystem,
-    SessionDeviceWidgetUpdateSystem,
+    SessionChatLogLabelUpdateSystem, SessionCodeLabelUpdateSystem,
+    SessionDeviceEntityCreateDeleteSystem, SessionDeviceWidgetUpdateSystem,
+};
+use simulation::{SimulationProgressSystem, SimulationScriptSystem, SimulationState};
+#[cfg(not(target_arch = "wasm32"))]
+use simulation_model::config::{SimulationController, SimulationScript};
+use simulation_model::{
+    config::{Simulation, SimulationCharacter},
+    play::SimulationScripts,
 };
 use spawn_loading::SpawnLoadingBundle;
 use sprite_loading::SpriteLoadingBundle;
@@ -162,26 +202,65 @@ pub struct WillConfig {
     /// Logger configuration file.
     #[structopt(long)]
     logger_config: Option<PathBuf>,
-    /// Address of the session server.
+    /// Replay file to play back in place of device input.
+    ///
+    /// Combine with `--headless` to play back a recorded match without a window.
+    #[structopt(long)]
+    replay: Option<PathBuf>,
+    /// File to record the next match to.
+    #[structopt(long)]
+    replay_record: Option<PathBuf>,
+    /// IP address or hostname of the session server.
     ///
-    /// Currently must be an `IpAddr`, in the future we may accept hostnames.
+    /// Session servers on the local network can also be selected in the network play menu.
     #[serde(default = "WillConfig::session_server_address_default")]
     #[structopt(long, default_value = "127.0.0.1")]
-    session_server_address: IpAddr,
+    session_server_address: String,
     /// Port that the session server is listening on.
     #[serde(default = "WillConfig::session_server_port_default")]
     #[structopt(long, default_value = "1234")]
     session_server_port: u16,
+    /// How clients in a network session keep in sync: `lockstep` or `rollback`.
+    ///
+    /// Every client in a session must use the same mode.
+    #[serde(default)]
+    #[structopt(long, default_value = "lockstep")]
+    session_sync_mode: SessionSyncMode,
+    /// Characters to simulate a match with, instead of showing the menus.
+    ///
+    /// Each character is `<slug>` or `<slug>=<controller>`, where the controller is `ai`,
+    /// `ai:<difficulty>`, or `script:<path>`. The match result is printed as JSON.
+    #[serde(skip)]
+    #[structopt(long)]
+    simulate: Vec<SimulationCharacter>,
+    /// Map to simulate the match on.
+    #[serde(skip)]
+    #[structopt(long)]
+    simulate_map: Option<AssetSlug>,
+    /// Maximum number of ticks to simulate the match for.
+    #[serde(default = "WillConfig::simulate_tick_limit_default")]
+    #[structopt(long, default_value = "3600")]
+    simulate_tick_limit: u32,
+    /// Seed to simulate the match with, to reproduce a previous run.
+    ///
+    /// A random seed is used if this is not specified.
+    #[serde(skip)]
+    #[structopt(long)]
+    simulate_seed: Option<u64>,
 }
 
 impl WillConfig {
-    fn session_server_address_default() -> IpAddr {
-        IpAddr::V4(Ipv4Addr::LOCALHOST)
+    fn session_server_address_default() -> String {
+        Ipv4Addr::LOCALHOST.to_string()
     }
 
     fn session_server_port_default() -> u16 {
         1234
     }
+
+    fn simulate_tick_limit_default() -> u32 {
+        3600
+    }
 }
 
 #[cfg(not(target_arch = "wasm32"))]
@@ -221,11 +300,79 @@ fn logger_setup(logger_config_path: Option<PathBuf>) -> Result<(), Error> {
     Ok(())
 }
 
-fn session_server_config(will_config: &WillConfig) -> SessionServerConfig {
-    SessionServerConfig {
-        address: will_config.session_server_address,
-        port: will_config.session_server_port,
+fn session_server_config(will_config: &WillConfig) -> Result<SessionServerConfig, Error> {
+    SessionServerConfig::resolve(
+        &will_config.session_server_address,
+        will_config.session_server_port,
+    )
+    .map_err(|e| {
+        Error::from_string(format!(
+            "Failed to resolve session server address `{}`: {}",
+            will_config.session_server_address, e
+        ))
+    })
+}
+
+/// Returns the `ReplayPlayback` for the replay file to play back, if any.
+#[cfg(not(target_arch = "wasm32"))]
+fn replay_playback(will_config: &WillConfig) -> Result<Option<ReplayPlayback>, Error> {
+    will_config
+        .replay
+        .as_ref()
+        .map(|replay_path| {
+            IoUtils::read_file(replay_path)
+                .map_err(Error::from)
+                .and_then(|bytes| serde_yaml::from_slice::<Replay>(&bytes).map_err(Error::from))
+                .map(ReplayPlayback::new)
+        })
+        .transpose()
+}
+
+/// Replay files cannot be read from the browser.
+#[cfg(target_arch = "wasm32")]
+fn replay_playback(_will_config: &WillConfig) -> Result<Option<ReplayPlayback>, Error> {
+    Ok(None)
+}
+
+/// Returns the `Simulation` and scripts for the match to simulate, if any.
+#[cfg(not(target_arch = "wasm32"))]
+fn simulation(will_config: &WillConfig) -> Result<Option<(Simulation, SimulationScripts)>, Error> {
+    if will_config.simulate.is_empty() {
+        return Ok(None);
     }
+
+    let map = will_config.simulate_map.clone().ok_or_else(|| {
+        Error::from_string("`--simulate_map` is required when simulating a match.")
+    })?;
+    let simulation = Simulation::new(
+        will_config.simulate.clone(),
+        map,
+        will_config.simulate_tick_limit,
+        will_config.simulate_seed.map(GamePlaySeed),
+    );
+
+    let mut simulation_scripts = SimulationScripts::default();
+    simulation.characters.iter().enumerate().try_for_each(
+        |(controller_id, simulation_character)| {
+            if let SimulationController::Scripted(script_path) = &simulation_character.controller {
+                let simulation_script = IoUtils::read_file(script_path)
+                    .map_err(Error::from)
+                    .and_then(|bytes| {
+                        serde_yaml::from_slice::<SimulationScript>(&bytes).map_err(Error::from)
+                    })?;
+                simulation_scripts.insert(controller_id, simulation_script);
+            }
+            Ok::<(), Error>(())
+        },
+    )?;
+
+    Ok(Some((simulation, simulation_scripts)))
+}
+
+/// Script files cannot be read from the browser.
+#[cfg(target_arch = "wasm32")]
+fn simulation(_will_config: &WillConfig) -> Result<Option<(Simulation, SimulationScripts)>, Error> {
+    Ok(None)
 }
 
 #[cfg(target_arch = "wasm32")]
@@ -453,322 +600,502 @@ where
     let (will_config, player_input_configs, font_config, hot_reload_strategy, rendering_bundle) =
         fn_setup(&app_root, &event_loop)?;
 
-    let session_server_config = session_server_config(&will_config);
+    let session_server_config = session_server_config(&will_config)?;
+    let replay_playback = replay_playback(&will_config)?;
+    let simulation = simulation(&will_config)?;
 
-    let game_mode_selection_state =
-        GameModeSelectionStateBuilder::new(GameModeSelectionStateDelegate::new()).build();
-    let loading_state = LoadingState::<_>::new(game_mode_selection_state);
-    let state = RobotState::new(Box::new(loading_state));
+    let state: Box<dyn State<GameData<'static, 'static>, AppEvent>> = if replay_playback.is_some() {
+        Box::new(LoadingState::<_>::new(ReplayState::new()))
+    } else if simulation.is_some() {
+        Box::new(LoadingState::<_>::new(SimulationState::new()))
+    } else {
+        let game_mode_selection_state =
+            GameModeSelectionStateBuilder::new(GameModeSelectionStateDelegate::new()).build();
+        Box::new(LoadingState::<_>::new(game_mode_selection_state))
+    };
+    let state = RobotState::new(state);
 
     let player_controllers = PlayerControllers::from(&player_input_configs);
 
     let bindings = Bindings::try_from(&player_input_configs)?;
 
+    let headless = will_config.headless;
     let mut game_data = GameDataBuilder::default();
-    if !will_config.headless {
-        // `InputBundle` provides `InputHandler<A, B>`, needed by the `UiBundle` for mouse events.
-        // `UiBundle` registers `Loader<FontAsset>`, needed by `ApplicationUiBundle`.
-        game_data = game_data
-            .with_bundle(AudioBundle::default())?
-            .with_bundle(InputBundle::<ControlBindings>::new().with_bindings(bindings))?;
+    // `InputBundle` provides `InputHandler<A, B>`, needed by the `UiBundle` for mouse events.
+    // `UiBundle` registers `Loader<FontAsset>`, needed by `ApplicationUiBundle`.
+    if headless {
+        // `AudioSystem` needs an output device, so we only include the `Source` processor,
+        // which is needed to load the audio files.
+        game_data = game_data.with(Processor::<Source>::new(), "source_processor", &[]);
+    } else {
+        game_data = game_data.with_bundle(AudioBundle::default())?;
+    }
+    game_data =
+        game_data.with_bundle(InputBundle::<ControlBindings>::new().with_bindings(bindings))?;
 
-        #[cfg(not(target_arch = "wasm32"))]
-        {
-            game_data = game_data.with_bundle(WebSocketNetworkBundle::new(None))?;
-        }
-        #[cfg(target_arch = "wasm32")]
-        {
-            game_data = game_data.with_bundle(WebSocketNetworkBundle::new())?;
-        }
+    #[cfg(not(target_arch = "wasm32"))]
+    {
+        game_data = game_data.with_bundle(WebSocketNetworkBundle::new(None))?;
+    }
+    #[cfg(target_arch = "wasm32")]
+    {
+        game_data = game_data.with_bundle(WebSocketNetworkBundle::new())?;
+    }
 
-        game_data = game_data
-            .with_bundle(HotReloadBundle::new(hot_reload_strategy))?
-            .with_bundle(SpriteLoadingBundle::new())?
-            .with_bundle(SequenceLoadingBundle::new())?
-            .with_bundle(AudioLoadingBundle::new())?
-            .with_bundle(KinematicLoadingBundle::new())?
-            .with_bundle(LoadingBundle::new(assets_dir.clone()))?
-            .with_system_desc(
-                InputToGameInputSystemDesc::default(),
-                any::type_name::<InputToGameInputSystem>(),
-                &["input_system"],
-            )
-            .with_system_desc(
-                GameInputToControlInputSystemDesc::default(),
-                any::type_name::<GameInputToControlInputSystem>(),
-                &[any::type_name::<InputToGameInputSystem>()],
-            )
-            .with(
-                MapperSystem::<ControlInputEventStdinMapper>::new(AppEventVariant::ControlInput),
-                any::type_name::<MapperSystem<ControlInputEventStdinMapper>>(),
-                // Depend on the input handler updated system, so that stdin input takes priority.
-                &[any::type_name::<GameInputToControlInputSystem>()],
-            )
-            .with(
-                ControllerInputUpdateSystem::new(),
-                any::type_name::<ControllerInputUpdateSystem>(),
-                &[any::type_name::<MapperSystem<ControlInputEventStdinMapper>>()],
-            )
-            .with(
-                SharedControllerInputUpdateSystem::new(),
-                any::type_name::<SharedControllerInputUpdateSystem>(),
-                &[any::type_name::<ControllerInputUpdateSystem>()],
-            );
-
-        #[cfg(not(target_arch = "wasm32"))]
-        {
-            game_data = game_data
-                .with_bundle(StdioInputBundle::new())?
-                .with_bundle(StdioCommandStdioBundle::new())?
-                .with_bundle(AssetSelectionStdioBundle::new())?
-                .with_bundle(GamePlayStdioBundle::new())?
-                .with_bundle(GameModeSelectionStdioBundle::new())?
-                .with_bundle(NetworkModeSelectionStdioBundle::new())?
-                .with_bundle(SessionHostStdioBundle::new())?
-                .with_bundle(SessionJoinStdioBundle::new())?;
-        }
+    game_data = game_data
+        .with_bundle(HotReloadBundle::new(hot_reload_strategy))?
+        .with_bundle(SpriteLoadingBundle::new())?
+        .with_bundle(SequenceLoadingBundle::new())?
+        .with_bundle(AudioLoadingBundle::new())?
+        .with_bundle(KinematicLoadingBundle::new())?
+        .with_bundle(LoadingBundle::new(assets_dir.clone()))?;
 
-        #[cfg(not(target_arch = "wasm32"))]
-        let ui_active_widget_deps = [any::type_name::<StdioCommandProcessingSystem>()];
-        #[cfg(target_arch = "wasm32")]
-        let ui_active_widget_deps = [];
+    // When playing back a replay, recorded input is sent in place of device input.
+    let game_input_system_name = if replay_playback.is_some() {
+        game_data = game_data.with(
+            ReplayPlaybackSystem::new(),
+            any::type_name::<ReplayPlaybackSystem>(),
+            &["input_system"],
+        );
+        any::type_name::<ReplayPlaybackSystem>()
+    } else {
+        game_data = game_data.with_system_desc(
+            InputToGameInputSystemDesc::default(),
+            any::type_name::<InputToGameInputSystem>(),
+            &["input_system"],
+        );
+        any::type_name::<InputToGameInputSystem>()
+    };
 
+    game_data = game_data
+        .with_system_desc(
+            GamepadAssignmentSystemDesc::default(),
+            any::type_name::<GamepadAssignmentSystem>(),
+            &["input_system"],
+        )
+        .with_system_desc(
+            RollbackInputRequestSystemDesc::default(),
+            any::type_name::<RollbackInputRequestSystem>(),
+            &["input_system"],
+        )
+        .with(
+            RollbackInputReplaySystem::new(),
+            any::type_name::<RollbackInputReplaySystem>(),
+            // Local input must be recorded before the input for the tick is replayed.
+            &[any::type_name::<RollbackInputRequestSystem>()],
+        )
+        .with_system_desc(
+            GameInputToControlInputSystemDesc::default(),
+            any::type_name::<GameInputToControlInputSystem>(),
+            &[
+                game_input_system_name,
+                any::type_name::<RollbackInputReplaySystem>(),
+            ],
+        )
+        .with(
+            MapperSystem::<ControlInputEventStdinMapper>::new(AppEventVariant::ControlInput),
+            any::type_name::<MapperSystem<ControlInputEventStdinMapper>>(),
+            // Depend on the input handler updated system, so that stdin input takes priority.
+            &[any::type_name::<GameInputToControlInputSystem>()],
+        )
+        .with(
+            AiControllerSystem::new(),
+            any::type_name::<AiControllerSystem>(),
+            &[any::type_name::<MapperSystem<ControlInputEventStdinMapper>>()],
+        )
+        .with(
+            SimulationScriptSystem::new(),
+            any::type_name::<SimulationScriptSystem>(),
+            &[any::type_name::<AiControllerSystem>()],
+        )
+        .with_system_desc(
+            ReplayControlInputPlaybackSystemDesc::default(),
+            any::type_name::<ReplayControlInputPlaybackSystem>(),
+            &[
+                any::type_name::<AiControllerSystem>(),
+                any::type_name::<SimulationScriptSystem>(),
+            ],
+        )
+        .with_system_desc(
+            ReplayRecordSystemDesc::default(),
+            any::type_name::<ReplayRecordSystem>(),
+            &[any::type_name::<ReplayControlInputPlaybackSystem>()],
+        )
+        .with_system_desc(
+            ReplayDivergenceDetectionSystemDesc::default(),
+            any::type_name::<ReplayDivergenceDetectionSystem>(),
+            &[any::type_name::<ReplayControlInputPlaybackSystem>()],
+        )
+        .with(
+            ControllerInputUpdateSystem::new(),
+            any::type_name::<ControllerInputUpdateSystem>(),
+            &[
+                any::type_name::<AiControllerSystem>(),
+                any::type_name::<ReplayRecordSystem>(),
+                any::type_name::<ReplayDivergenceDetectionSystem>(),
+            ],
+        )
+        .with(
+            SharedControllerInputUpdateSystem::new(),
+            any::type_name::<SharedControllerInputUpdateSystem>(),
+            &[any::type_name::<ControllerInputUpdateSystem>()],
+        );
+
+    #[cfg(not(target_arch = "wasm32"))]
+    {
         game_data = game_data
-            .with_bundle(CollisionLoadingBundle::new())?
-            .with_bundle(SpawnLoadingBundle::new())?
-            .with_bundle(BackgroundLoadingBundle::new())?
-            .with_bundle(UiLoadingBundle::new())?
-            .with_bundle(MapLoadingBundle::new())?
-            .with_bundle(CharacterLoadingBundle::new())?
-            .with_bundle(EnergyLoadingBundle::new())?
-            .with_bundle(InputReactionLoadingBundle::new())?
-            .with_bundle(CollisionAudioLoadingBundle::new(assets_dir.clone()))?
-            .with_bundle(UiAudioLoadingBundle::new(assets_dir.clone()))?
-            .with(CameraOrthoSystem::default(), "camera_ortho", &[])
-            .with(
-                UiActiveWidgetUpdateSystem::new(),
-                any::type_name::<UiActiveWidgetUpdateSystem>(),
-                &ui_active_widget_deps,
-            )
-            .with(
-                UiTextColourUpdateSystem::new(),
-                any::type_name::<UiTextColourUpdateSystem>(),
-                &[any::type_name::<UiActiveWidgetUpdateSystem>()],
-            )
-            .with(
-                WidgetSequenceUpdateSystem::new(),
-                any::type_name::<WidgetSequenceUpdateSystem>(),
-                &[any::type_name::<UiActiveWidgetUpdateSystem>()],
-            )
-            .with(
-                StateIdEventSystem::new(),
-                any::type_name::<StateIdEventSystem>(),
-                &[any::type_name::<UiActiveWidgetUpdateSystem>()],
-            )
-            .with(
-                StateCameraResetSystem::new(),
-                any::type_name::<StateCameraResetSystem>(),
-                &[any::type_name::<StateIdEventSystem>()],
-            )
-            .with(
-                StateItemSpawnSystem::new(),
-                any::type_name::<StateItemSpawnSystem>(),
-                &[any::type_name::<StateIdEventSystem>()],
-            )
-            .with(
-                ItemIdEventSystem::new(),
-                any::type_name::<ItemIdEventSystem>(),
-                &[any::type_name::<StateItemSpawnSystem>()],
-            )
-            .with(
-                SessionStatusNotifierSystem::new(),
-                any::type_name::<SessionStatusNotifierSystem>(),
-                &[],
-            )
-            .with_system_desc(
-                SessionInputResourcesSyncSystemDesc::default(),
-                any::type_name::<SessionInputResourcesSyncSystem>(),
-                &[],
-            )
-            .with_bundle(AssetPlayBundle::new())?
-            .with_system_desc(
-                SessionHostRequestSystemDesc::default(),
+            .with_bundle(StdioInputBundle::new())?
+            .with_bundle(StdioCommandStdioBundle::new())?
+            .with_bundle(AssetSelectionStdioBundle::new())?
+            .with_bundle(GamePlayStdioBundle::new())?
+            .with_bundle(GameModeSelectionStdioBundle::new())?
+            .with_bundle(NetworkModeSelectionStdioBundle::new())?
+            .with_bundle(SessionBrowseStdioBundle::new())?
+            .with_bundle(SessionHostStdioBundle::new())?
+            .with_bundle(SessionJoinStdioBundle::new())?
+            .with_bundle(SessionLobbyStdioBundle::new())?;
+    }
+
+    #[cfg(not(target_arch = "wasm32"))]
+    let ui_active_widget_deps = [any::type_name::<StdioCommandProcessingSystem>()];
+    #[cfg(target_arch = "wasm32")]
+    let ui_active_widget_deps = [];
+
+    game_data = game_data
+        .with_bundle(CollisionLoadingBundle::new())?
+        .with_bundle(SpawnLoadingBundle::new())?
+        .with_bundle(BackgroundLoadingBundle::new())?
+        .with_bundle(UiLoadingBundle::new())?
+        .with_bundle(MapLoadingBundle::new())?
+        .with_bundle(CharacterLoadingBundle::new())?
+        .with_bundle(EnergyLoadingBundle::new())?
+        .with_bundle(ItemLoadingBundle::new())?
+        .with_bundle(InputReactionLoadingBundle::new())?
+        .with_bundle(CollisionAudioLoadingBundle::new(assets_dir.clone()))?
+        .with_bundle(UiAudioLoadingBundle::new(assets_dir.clone()))?
+        .with(CameraOrthoSystem::default(), "camera_ortho", &[])
+        .with(
+            UiActiveWidgetUpdateSystem::new(),
+            any::type_name::<UiActiveWidgetUpdateSystem>(),
+            &ui_active_widget_deps,
+        )
+        .with(
+            UiTextColourUpdateSystem::new(),
+            any::type_name::<UiTextColourUpdateSystem>(),
+            &[any::type_name::<UiActiveWidgetUpdateSystem>()],
+        )
+        .with(
+            WidgetSequenceUpdateSystem::new(),
+            any::type_name::<WidgetSequenceUpdateSystem>(),
+            &[any::type_name::<UiActiveWidgetUpdateSystem>()],
+        )
+        .with(
+            StateIdEventSystem::new(),
+            any::type_name::<StateIdEventSystem>(),
+            &[any::type_name::<UiActiveWidgetUpdateSystem>()],
+        )
+        .with(
+            StateCameraResetSystem::new(),
+            any::type_name::<StateCameraResetSystem>(),
+            &[any::type_name::<StateIdEventSystem>()],
+        )
+        .with(
+            StateItemSpawnSystem::new(),
+            any::type_name::<StateItemSpawnSystem>(),
+            &[any::type_name::<StateIdEventSystem>()],
+        )
+        .with(
+            ItemIdEventSystem::new(),
+            any::type_name::<ItemIdEventSystem>(),
+            &[any::type_name::<StateItemSpawnSystem>()],
+        )
+        .with(
+            SessionStatusNotifierSystem::new(),
+            any::type_name::<SessionStatusNotifierSystem>(),
+            &[],
+        )
+        .with_system_desc(
+            SessionInputResourcesSyncSystemDesc::default(),
+            any::type_name::<SessionInputResourcesSyncSystem>(),
+            &[],
+        )
+        .with_bundle(AssetPlayBundle::new())?
+        .with_system_desc(
+            SessionBrowseRequestSystemDesc::default(),
+            any::type_name::<SessionBrowseRequestSystem>(),
+            &[],
+        )
+        .with_system_desc(
+            SessionHostRequestSystemDesc::default(),
+            any::type_name::<SessionHostRequestSystem>(),
+            &[],
+        )
+        .with_system_desc(
+            SessionJoinRequestSystemDesc::default(),
+            any::type_name::<SessionJoinRequestSystem>(),
+            &[],
+        )
+        .with_system_desc(
+            SessionSelectionRequestSystemDesc::default(),
+            any::type_name::<SessionSelectionRequestSystem>(),
+            &[],
+        )
+        .with_system_desc(
+            SessionLobbyRequestSystemDesc::default(),
+            any::type_name::<SessionLobbyRequestSystem>(),
+            &[any::type_name::<SessionSelectionRequestSystem>()],
+        )
+        .with_system_desc(
+            NetworkInputRequestSystemDesc::default(),
+            any::type_name::<NetworkInputRequestSystem>(),
+            &["input_system"],
+        )
+        .with(
+            GameInputTickRequestSystem::new(),
+            any::type_name::<GameInputTickRequestSystem>(),
+            &[any::type_name::<NetworkInputRequestSystem>()],
+        )
+        .with_system_desc(
+            SessionPingSystemDesc::default(),
+            any::type_name::<SessionPingSystem>(),
+            &[],
+        )
+        .with_system_desc(
+            NetMessageRequestSystemDesc::default(),
+            any::type_name::<NetMessageRequestSystem>(),
+            &[
+                any::type_name::<SessionBrowseRequestSystem>(),
                 any::type_name::<SessionHostRequestSystem>(),
-                &[],
-            )
-            .with_system_desc(
-                SessionJoinRequestSystemDesc::default(),
                 any::type_name::<SessionJoinRequestSystem>(),
-                &[],
-            )
-            .with_system_desc(
-                SessionLobbyRequestSystemDesc::default(),
                 any::type_name::<SessionLobbyRequestSystem>(),
-                &[],
-            )
-            .with_system_desc(
-                NetworkInputRequestSystemDesc::default(),
                 any::type_name::<NetworkInputRequestSystem>(),
-                &["input_system"],
-            )
-            .with(
-                GameInputTickRequestSystem::new(),
                 any::type_name::<GameInputTickRequestSystem>(),
-                &[any::type_name::<NetworkInputRequestSystem>()],
-            )
-            .with_system_desc(
-                NetMessageRequestSystemDesc::default(),
-                any::type_name::<NetMessageRequestSystem>(),
-                &[
-                    any::type_name::<SessionHostRequestSystem>(),
-                    any::type_name::<SessionJoinRequestSystem>(),
-                    any::type_name::<SessionLobbyRequestSystem>(),
-                    any::type_name::<NetworkInputRequestSystem>(),
-                    any::type_name::<GameInputTickRequestSystem>(),
-                ],
-            )
-            .with_system_desc(
-                NetListenerSystemDesc::default(),
+                any::type_name::<RollbackInputRequestSystem>(),
+                any::type_name::<SessionPingSystem>(),
+            ],
+        )
+        .with_system_desc(
+            NetListenerSystemDesc::default(),
+            any::type_name::<NetListenerSystem>(),
+            &[],
+        )
+        .with_system_desc(
+            NetHandshakeResponseSystemDesc::default(),
+            any::type_name::<NetHandshakeResponseSystem>(),
+            &[any::type_name::<NetListenerSystem>()],
+        )
+        .with_system_desc(
+            SessionBrowseResponseSystemDesc::default(),
+            any::type_name::<SessionBrowseResponseSystem>(),
+            &[any::type_name::<NetListenerSystem>()],
+        )
+        .with_system_desc(
+            SessionHostResponseSystemDesc::default(),
+            any::type_name::<SessionHostResponseSystem>(),
+            &[any::type_name::<NetListenerSystem>()],
+        )
+        .with_system_desc(
+            SessionJoinResponseSystemDesc::default(),
+            any::type_name::<SessionJoinResponseSystem>(),
+            &[any::type_name::<NetListenerSystem>()],
+        )
+        .with_system_desc(
+            SessionReconnectSystemDesc::default(),
+            any::type_name::<SessionReconnectSystem>(),
+            &[any::type_name::<NetListenerSystem>()],
+        )
+        .with_system_desc(
+            SessionLobbyResponseSystemDesc::default(),
+            any::type_name::<SessionLobbyResponseSystem>(),
+            &[any::type_name::<NetListenerSystem>()],
+        )
+        .with_system_desc(
+            SessionMessageResponseSystemDesc::default(),
+            any::type_name::<SessionMessageResponseSystem>(),
+            &[
+                any::type_name::<NetListenerSystem>(),
+                any::type_name::<GameInputTickRequestSystem>(),
+            ],
+        )
+        .with_system_desc(
+            NetworkInputResponseSystemDesc::default(),
+            any::type_name::<NetworkInputResponseSystem>(),
+            &[
                 any::type_name::<NetListenerSystem>(),
-                &[],
-            )
-            .with_system_desc(
-                SessionHostResponseSystemDesc::default(),
+                any::type_name::<SessionMessageResponseSystem>(),
+            ],
+        )
+        .with_system_desc(
+            RollbackInputResponseSystemDesc::default(),
+            any::type_name::<RollbackInputResponseSystem>(),
+            &[any::type_name::<NetListenerSystem>()],
+        )
+        .with(
+            SessionInputCatchUpSystem::new(),
+            any::type_name::<SessionInputCatchUpSystem>(),
+            &[
+                any::type_name::<GameInputTickRequestSystem>(),
+                any::type_name::<NetworkInputResponseSystem>(),
+                any::type_name::<RollbackInputResponseSystem>(),
+                any::type_name::<SessionReconnectSystem>(),
+            ],
+        )
+        .with(
+            SessionCodeLabelUpdateSystem::new(),
+            any::type_name::<SessionCodeLabelUpdateSystem>(),
+            &[
                 any::type_name::<SessionHostResponseSystem>(),
-                &[any::type_name::<NetListenerSystem>()],
-            )
-            .with_system_desc(
-                SessionJoinResponseSystemDesc::default(),
                 any::type_name::<SessionJoinResponseSystem>(),
-                &[any::type_name::<NetListenerSystem>()],
-            )
-            .with_system_desc(
-                SessionLobbyResponseSystemDesc::default(),
-                any::type_name::<SessionLobbyResponseSystem>(),
-                &[any::type_name::<NetListenerSystem>()],
-            )
-            .with_system_desc(
-                SessionMessageResponseSystemDesc::default(),
                 any::type_name::<SessionMessageResponseSystem>(),
-                &[
-                    any::type_name::<NetListenerSystem>(),
-                    any::type_name::<GameInputTickRequestSystem>(),
-                ],
-            )
-            .with_system_desc(
-                NetworkInputResponseSystemDesc::default(),
-                any::type_name::<NetworkInputResponseSystem>(),
-                &[
-                    any::type_name::<NetListenerSystem>(),
-                    any::type_name::<SessionMessageResponseSystem>(),
-                ],
-            )
-            .with(
-                SessionCodeLabelUpdateSystem::new(),
-                any::type_name::<SessionCodeLabelUpdateSystem>(),
-                &[
-                    any::type_name::<SessionHostResponseSystem>(),
-                    any::type_name::<SessionJoinResponseSystem>(),
-                    any::type_name::<SessionMessageResponseSystem>(),
-                ],
-            )
-            .with(
-                SessionDeviceEntityCreateDeleteSystem::new(),
-                any::type_name::<SessionDeviceEntityCreateDeleteSystem>(),
-                &[
-                    any::type_name::<SessionHostResponseSystem>(),
-                    any::type_name::<SessionJoinResponseSystem>(),
-                    any::type_name::<SessionMessageResponseSystem>(),
-                ],
-            )
-            .with(
-                SessionDeviceWidgetUpdateSystem::new(),
-                any::type_name::<SessionDeviceWidgetUpdateSystem>(),
-                &[any::type_name::<SessionDeviceEntityCreateDeleteSystem>()],
-            )
-            .with_system_desc(
-                StateItemUiInputAugmentSystemDesc::default(),
-                any::type_name::<StateItemUiInputAugmentSystem>(),
-                &[],
-            )
-            .with(
-                PrevTrackerSystem::<StateId>::new(stringify!(StateId)),
-                "state_id_prev_tracker_system",
-                &[],
-            )
-            .with_barrier()
-            .with_bundle(GamePlayBundle::new())?
-            .with(
-                GameModeSelectionSfxSystem::new(),
-                any::type_name::<GameModeSelectionSfxSystem>(),
-                &[],
-            )
-            .with(
-                AssetSelectionSfxSystem::new(),
-                any::type_name::<AssetSelectionSfxSystem>(),
-                &[],
-            )
-            .with(
-                AssetSelectionHighlightUpdateSystem::new(),
-                any::type_name::<AssetSelectionHighlightUpdateSystem>(),
-                &[],
-            )
-            .with(
-                AswPortraitUpdateSystem::new(),
-                any::type_name::<AswPortraitUpdateSystem>(),
-                &[any::type_name::<AssetSelectionHighlightUpdateSystem>()],
-            )
-            .with(
-                ApwPreviewSpawnSystemCharacter::new(),
-                any::type_name::<ApwPreviewSpawnSystemCharacter>(),
-                &[any::type_name::<AssetSelectionHighlightUpdateSystem>()],
-            )
-            .with(
-                ApwPreviewSpawnSystemMap::new(),
-                any::type_name::<ApwPreviewSpawnSystemMap>(),
-                &[any::type_name::<AssetSelectionHighlightUpdateSystem>()],
-            )
-            .with(
-                ChildEntityDeleteSystem::new(),
-                any::type_name::<ChildEntityDeleteSystem>(),
-                &[],
-            )
-            .with_barrier()
-            // To remove the 1 frame of flicker issue, we must run `TransformSystem` near the end,
-            // so that the global matrix is updated even when the local matrix is up to date.
-            //
-            // `UiBundle` has a hardcoded dependency on `"transform_system"`, so we have to shift it
-            // down as well.
-            .with_bundle(TransformBundle::new())?
-            .with_bundle(UiBundle::<ControlBindings>::new())?
-            .with_bundle(ApplicationUiBundle::new(font_config))?
-            .with_bundle(
-                rendering_bundle
-                    .with_plugin(RenderToWindow::new().with_clear(ClearColor {
-                        float32: [0., 0., 0., 1.],
-                    }))
-                    .with_plugin(RenderFlat2D::default())
-                    .with_plugin(RenderUi::default()),
-            )?
-            .with_bundle(CameraPlayBundle::new())?
-            .with_system_desc(
-                UiTransformForFovSystemDesc::default(),
-                any::type_name::<UiTransformForFovSystem>(),
-                &["camera_ortho"],
-            )
-            .with_system_desc(
-                UiTransformInsertionRectifySystemDesc::default(),
-                any::type_name::<UiTransformInsertionRectifySystem>(),
-                &[any::type_name::<UiTransformForFovSystem>()],
-            );
+            ],
+        )
+        .with(
+            SessionChatLogLabelUpdateSystem::new(),
+            any::type_name::<SessionChatLogLabelUpdateSystem>(),
+            &[any::type_name::<SessionLobbyResponseSystem>()],
+        )
+        .with(
+            SessionDeviceEntityCreateDeleteSystem::new(),
+            any::type_name::<SessionDeviceEntityCreateDeleteSystem>(),
+            &[
+                any::type_name::<SessionHostResponseSystem>(),
+                any::type_name::<SessionJoinResponseSystem>(),
+                any::type_name::<SessionMessageResponseSystem>(),
+            ],
+        )
+        .with(
+            SessionDeviceWidgetUpdateSystem::new(),
+            any::type_name::<SessionDeviceWidgetUpdateSystem>(),
+            &[any::type_name::<SessionDeviceEntityCreateDeleteSystem>()],
+        )
+        .with_system_desc(
+            StateItemUiInputAugmentSystemDesc::default(),
+            any::type_name::<StateItemUiInputAugmentSystem>(),
+            &[],
+        )
+        .with(
+            PrevTrackerSystem::<StateId>::new(stringify!(StateId)),
+            "state_id_prev_tracker_system",
+            &[],
+        )
+        .with_barrier()
+        .with_bundle(GamePlayBundle::new())?
+        .with(
+            GameModeSelectionSfxSystem::new(),
+            any::type_name::<GameModeSelectionSfxSystem>(),
+            &[],
+        )
+        .with(
+            AssetSelectionSfxSystem::new(),
+            any::type_name::<AssetSelectionSfxSystem>(),
+            &[],
+        )
+        .with(
+            AssetSelectionHighlightUpdateSystem::new(),
+            any::type_name::<AssetSelectionHighlightUpdateSystem>(),
+            &[],
+        )
+        .with(
+            AswPortraitUpdateSystem::new(),
+            any::type_name::<AswPortraitUpdateSystem>(),
+            &[any::type_name::<AssetSelectionHighlightUpdateSystem>()],
+        )
+        .with(
+            ApwPreviewSpawnSystemCharacter::new(),
+            any::type_name::<ApwPreviewSpawnSystemCharacter>(),
+            &[any::type_name::<AssetSelectionHighlightUpdateSystem>()],
+        )
+        .with(
+            ApwPreviewSpawnSystemMap::new(),
+            any::type_name::<ApwPreviewSpawnSystemMap>(),
+            &[any::type_name::<AssetSelectionHighlightUpdateSystem>()],
+        )
+        .with(
+            ChildEntityDeleteSystem::new(),
+            any::type_name::<ChildEntityDeleteSystem>(),
+            &[],
+        )
+        .with_system_desc(
+            ControlRebindSystemDesc::default(),
+            any::type_name::<ControlRebindSystem>(),
+            &[],
+        )
+        .with(
+            ControlBindingsDisplaySystem::new(),
+            any::type_name::<ControlBindingsDisplaySystem>(),
+            &[any::type_name::<ControlRebindSystem>()],
+        )
+        .with(
+            SimulationProgressSystem::new(),
+            any::type_name::<SimulationProgressSystem>(),
+            &[],
+        )
+        .with_barrier()
+        .with(
+            SessionChecksumRequestSystem::new(),
+            any::type_name::<SessionChecksumRequestSystem>(),
+            &[],
+        )
+        // To remove the 1 frame of flicker issue, we must run `TransformSystem` near the end,
+        // so that the global matrix is updated even when the local matrix is up to date.
+        //
+        // `UiBundle` has a hardcoded dependency on `"transform_system"`, so we have to shift it
+        // down as well.
+        .with_bundle(TransformBundle::new())?
+        .with_bundle(UiBundle::<ControlBindings>::new())?
+        .with_bundle(ApplicationUiBundle::new(font_config))?;
+
+    if headless {
+        game_data = game_data.with_bundle(RenderEmptyBundle::<DefaultBackend>::new(&event_loop))?;
+    } else {
+        game_data = game_data.with_bundle(
+            rendering_bundle
+                .with_plugin(RenderToWindow::new().with_clear(ClearColor {
+                    float32: [0., 0., 0., 1.],
+                }))
+                .with_plugin(RenderFlat2D::default())
+                .with_plugin(RenderUi::default()),
+        )?;
     }
 
-    let app = CoreApplication::<_, AppEvent, AppEventReader>::build(assets_dir, state)?
+    game_data = game_data
+        .with_bundle(CameraPlayBundle::new())?
+        .with_system_desc(
+            UiTransformForFovSystemDesc::default(),
+            any::type_name::<UiTransformForFovSystem>(),
+            &["camera_ortho"],
+        )
+        .with_system_desc(
+            UiTransformInsertionRectifySystemDesc::default(),
+            any::type_name::<UiTransformInsertionRectifySystem>(),
+            &[any::type_name::<UiTransformForFovSystem>()],
+        );
+
+    let mut app_builder = CoreApplication::<_, AppEvent, AppEventReader>::build(assets_dir, state)?
         .with_resource(session_server_config)
+        .with_resource(will_config.session_sync_mode)
+        .with_resource(ReplayRecorder::new(will_config.replay_record.clone()))
         .with_resource(player_controllers)
         .with_resource(player_input_configs)
-        .with_frame_limit_config(frame_rate_limit_config(will_config.frame_rate))
-        .build(game_data)?;
+        .with_frame_limit_config(if simulation.is_some() {
+            // Simulated matches run as fast as possible.
+            FRAME_RATE_NO_LIMIT
+        } else {
+            frame_rate_limit_config(will_config.frame_rate)
+        });
+    if headless {
+        // There is no window to provide the screen dimensions, so we use the default display size.
+        app_builder = app_builder.with_resource(ScreenDimensions::new(800, 600, 1.));
+    }
+    if let Some(replay_playback) = replay_playback {
+        app_builder = app_builder.with_resource(replay_playback);
+    }
+    if let Some((simulation, simulation_scripts)) = simulation {
+        app_builder = app_builder
+            .with_resource(simulation)
+            .with_resource(simulation_scripts);
+    }
+    let app = app_builder.build(game_data)?;
 
     app.run_winit_loop(event_loop);
 }
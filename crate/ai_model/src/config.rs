@@ -0,0 +1,5 @@
+//! Contains data types used for configuration.
+
+pub use self::ai_difficulty::AiDifficulty;
+
+mod ai_difficulty;
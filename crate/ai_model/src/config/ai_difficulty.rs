@@ -0,0 +1,59 @@
+use derivative::Derivative;
+use serde::{Deserialize, Serialize};
+use strum_macros::{Display, EnumIter, EnumString};
+
+/// How well a computer controlled character plays.
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Derivative,
+    Deserialize,
+    Display,
+    EnumIter,
+    EnumString,
+    Hash,
+    PartialEq,
+    Eq,
+    Serialize,
+)]
+#[derivative(Default)]
+#[serde(deny_unknown_fields, rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum AiDifficulty {
+    /// Reacts slowly, and never defends.
+    Easy,
+    /// Reacts moderately, and defends against or dodges attacks.
+    #[derivative(Default)]
+    Normal,
+    /// Reacts quickly, and defends against or dodges attacks.
+    Hard,
+}
+
+impl AiDifficulty {
+    /// Returns the number of ticks between each decision.
+    pub fn decision_interval(self) -> usize {
+        match self {
+            AiDifficulty::Easy => 20,
+            AiDifficulty::Normal => 10,
+            AiDifficulty::Hard => 4,
+        }
+    }
+
+    /// Returns whether the character defends or dodges when threatened.
+    pub fn evades(self) -> bool {
+        match self {
+            AiDifficulty::Easy => false,
+            AiDifficulty::Normal | AiDifficulty::Hard => true,
+        }
+    }
+
+    /// Returns the next difficulty, or `None` if this is the hardest difficulty.
+    pub fn next(self) -> Option<AiDifficulty> {
+        match self {
+            AiDifficulty::Easy => Some(AiDifficulty::Normal),
+            AiDifficulty::Normal => Some(AiDifficulty::Hard),
+            AiDifficulty::Hard => None,
+        }
+    }
+}
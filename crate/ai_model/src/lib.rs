@@ -0,0 +1,6 @@
+#![deny(missing_debug_implementations, missing_docs)] // kcov-ignore
+
+//! Types used for computer controlled characters.
+
+pub mod config;
+pub mod play;
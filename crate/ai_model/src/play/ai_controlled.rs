@@ -0,0 +1,36 @@
+use amethyst::ecs::{storage::HashMapStorage, Component};
+use game_input_model::config::ControllerId;
+use serde::{Deserialize, Serialize};
+
+use crate::{config::AiDifficulty, play::AiDecisionClock};
+
+/// Marks an entity that is controlled by the computer.
+///
+/// This is the AI counterpart of `InputControlled`.
+#[derive(Clone, Component, Copy, Debug, Deserialize, PartialEq, Serialize)]
+#[storage(HashMapStorage)]
+pub struct AiControlled {
+    /// ID of the controller slot that the entity was selected by.
+    pub controller_id: ControllerId,
+    /// How well the computer plays.
+    pub difficulty: AiDifficulty,
+    /// Ticks until the next decision is made.
+    pub decision_clock: AiDecisionClock,
+}
+
+impl AiControlled {
+    /// Returns a new `AiControlled` component.
+    ///
+    /// # Parameters
+    ///
+    /// * `controller_id`: ID of the controller slot that the entity was selected by.
+    /// * `difficulty`: How well the computer plays.
+    pub fn new(controller_id: ControllerId, difficulty: AiDifficulty) -> Self {
+        let decision_clock = AiDecisionClock::new(difficulty.decision_interval());
+        AiControlled {
+            controller_id,
+            difficulty,
+            decision_clock,
+        }
+    }
+}
@@ -0,0 +1,7 @@
+#![deny(missing_debug_implementations, missing_docs)] // kcov-ignore
+
+//! Provides logic for computer controlled characters.
+
+pub use crate::system::{AiControllerSystem, AiControllerSystemData, AiDecisionClockSystem};
+
+mod system;
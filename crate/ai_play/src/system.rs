@@ -0,0 +1,7 @@
+pub use self::{
+    ai_controller_system::{AiControllerSystem, AiControllerSystemData},
+    ai_decision_clock_system::AiDecisionClockSystem,
+};
+
+mod ai_controller_system;
+mod ai_decision_clock_system;
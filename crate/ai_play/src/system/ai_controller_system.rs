@@ -0,0 +1,311 @@
+use ai_model::{config::AiDifficulty, play::AiControlled};
+use amethyst::{
+    ecs::{Entities, Entity, Join, Read, ReadStorage, System, World, Write, WriteStorage},
+    shred::{ResourceId, SystemData},
+    shrev::EventChannel,
+};
+use derivative::Derivative;
+use derive_new::new;
+use game_input_model::{
+    config::{Axis, ControlAction, ControllerId},
+    play::{AxisMoveEventData, ControlActionEventData, ControlInputEvent, ControllerInput},
+};
+use game_play_model::GamePlayStatus;
+use kinematic_model::config::Position;
+use mirrored_model::play::Mirrored;
+use object_model::play::HealthPoints;
+use team_model::play::Team;
+
+/// Horizontal distance within which the character attacks its target.
+const ATTACK_RANGE_X: f32 = 60.;
+/// Depth distance within which the character attacks its target.
+const ATTACK_RANGE_Z: f32 = 10.;
+/// Horizontal distance within which an attacking target is considered a threat.
+const THREAT_RANGE_X: f32 = 90.;
+/// Height difference above which the character jumps to reach its target.
+const JUMP_HEIGHT: f32 = 20.;
+
+/// Sends `ControlInputEvent`s for `AiControlled` entities.
+///
+/// Each computer controlled character approaches the nearest opponent, attacks when in range,
+/// defends when an opponent attacks up close, dodges when an opponent attacks from further away,
+/// and jumps to reach airborne opponents.
+///
+/// Decisions are only made when the `AiDecisionClock` completes. The clock is ticked by the
+/// `AiDecisionClockSystem` each game tick, so that decisions are made at the same tick on every
+/// device in a session.
+#[derive(Debug, Default, new)]
+pub struct AiControllerSystem {
+    /// Pre-allocated vector to track potential targets.
+    #[new(default)]
+    targets: Vec<AiTarget>,
+}
+
+/// `AiControllerSystemData`.
+#[derive(Derivative, SystemData)]
+#[derivative(Debug)]
+pub struct AiControllerSystemData<'s> {
+    /// `Entities`.
+    #[derivative(Debug = "ignore")]
+    pub entities: Entities<'s>,
+    /// `GamePlayStatus` resource.
+    #[derivative(Debug = "ignore")]
+    pub game_play_status: Read<'s, GamePlayStatus>,
+    /// `AiControlled` components.
+    #[derivative(Debug = "ignore")]
+    pub ai_controlleds: WriteStorage<'s, AiControlled>,
+    /// `ControllerInput` components.
+    #[derivative(Debug = "ignore")]
+    pub controller_inputs: ReadStorage<'s, ControllerInput>,
+    /// `Position<f32>` components.
+    #[derivative(Debug = "ignore")]
+    pub positions: ReadStorage<'s, Position<f32>>,
+    /// `Mirrored` components.
+    #[derivative(Debug = "ignore")]
+    pub mirroreds: ReadStorage<'s, Mirrored>,
+    /// `Team` components.
+    #[derivative(Debug = "ignore")]
+    pub teams: ReadStorage<'s, Team>,
+    /// `HealthPoints` components.
+    #[derivative(Debug = "ignore")]
+    pub health_pointses: ReadStorage<'s, HealthPoints>,
+    /// `ControlInputEvent` channel.
+    #[derivative(Debug = "ignore")]
+    pub control_input_ec: Write<'s, EventChannel<ControlInputEvent>>,
+}
+
+/// Character that a computer controlled character may target.
+#[derive(Clone, Copy, Debug)]
+struct AiTarget {
+    /// The target entity.
+    entity: Entity,
+    /// Team the target is on.
+    team: Team,
+    /// Position of the target.
+    position: Position<f32>,
+    /// Input of the target.
+    controller_input: ControllerInput,
+}
+
+impl AiControllerSystem {
+    /// Returns the `ControllerInput` that the computer controlled character should have.
+    fn decide(
+        difficulty: AiDifficulty,
+        controller_input: ControllerInput,
+        position: Position<f32>,
+        mirrored: Mirrored,
+        target: Option<&AiTarget>,
+    ) -> ControllerInput {
+        let mut controller_input_desired = ControllerInput::default();
+        let target = if let Some(target) = target {
+            target
+        } else {
+            return controller_input_desired;
+        };
+
+        let dx = target.position.x - position.x;
+        let dy = target.position.y - position.y;
+        let dz = target.position.z - position.z;
+        let within_z = dz.abs() <= ATTACK_RANGE_Z;
+        let threatened = target.controller_input.attack && dx.abs() <= THREAT_RANGE_X && within_z;
+
+        if threatened && difficulty.evades() {
+            if dx.abs() <= ATTACK_RANGE_X {
+                // Too close to get away, so block the attack.
+                controller_input_desired.defend = true;
+            } else {
+                // Dodge by moving away from the target, and out of its attack depth.
+                controller_input_desired.x_axis_value = -dx.signum();
+                controller_input_desired.z_axis_value = if dz > 0. { -1. } else { 1. };
+            }
+        } else if dx.abs() <= ATTACK_RANGE_X && within_z {
+            // Turn around before attacking, if the target is behind.
+            let facing_target = (dx < 0.) == mirrored.0;
+            if !facing_target {
+                controller_input_desired.x_axis_value = dx.signum();
+            }
+
+            // Alternate pressing and releasing so that each decision is a fresh press.
+            controller_input_desired.attack = !controller_input.attack;
+        } else {
+            if dx.abs() > ATTACK_RANGE_X {
+                controller_input_desired.x_axis_value = dx.signum();
+            }
+            if !within_z {
+                controller_input_desired.z_axis_value = dz.signum();
+            }
+            controller_input_desired.jump = dy > JUMP_HEIGHT && dx.abs() <= THREAT_RANGE_X;
+        }
+
+        controller_input_desired
+    }
+
+    /// Sends `ControlInputEvent`s for every input that differs from the current input.
+    fn send_events(
+        control_input_ec: &mut EventChannel<ControlInputEvent>,
+        entity: Entity,
+        controller_id: ControllerId,
+        controller_input: ControllerInput,
+        controller_input_desired: ControllerInput,
+    ) {
+        let axis_events = [
+            (
+                Axis::X,
+                controller_input.x_axis_value,
+                controller_input_desired.x_axis_value,
+            ),
+            (
+                Axis::Z,
+                controller_input.z_axis_value,
+                controller_input_desired.z_axis_value,
+            ),
+        ];
+        let axis_events = axis_events
+            .iter()
+            .copied()
+            .filter(|(_, value, value_desired)| (value - value_desired).abs() > std::f32::EPSILON)
+            .map(|(axis, _, value)| {
+                ControlInputEvent::AxisMoved(AxisMoveEventData {
+                    controller_id,
+                    entity,
+                    axis,
+                    value,
+                })
+            });
+
+        let action_events = [
+            (
+                ControlAction::Defend,
+                controller_input.defend,
+                controller_input_desired.defend,
+            ),
+            (
+                ControlAction::Jump,
+                controller_input.jump,
+                controller_input_desired.jump,
+            ),
+            (
+                ControlAction::Attack,
+                controller_input.attack,
+                controller_input_desired.attack,
+            ),
+            (
+                ControlAction::Special,
+                controller_input.special,
+                controller_input_desired.special,
+            ),
+        ];
+        let action_events = action_events
+            .iter()
+            .copied()
+            .filter(|(_, pressed, pressed_desired)| pressed != pressed_desired)
+            .map(|(control_action, _, pressed)| {
+                let control_action_event_data = ControlActionEventData {
+                    controller_id,
+                    entity,
+                    control_action,
+                };
+                if pressed {
+                    ControlInputEvent::ControlActionPress(control_action_event_data)
+                } else {
+                    ControlInputEvent::ControlActionRelease(control_action_event_data)
+                }
+            });
+
+        control_input_ec.iter_write(axis_events.chain(action_events));
+    }
+}
+
+impl<'s> System<'s> for AiControllerSystem {
+    type SystemData = AiControllerSystemData<'s>;
+
+    fn run(
+        &mut self,
+        AiControllerSystemData {
+            entities,
+            game_play_status,
+            mut ai_controlleds,
+            controller_inputs,
+            positions,
+            mirroreds,
+            teams,
+            health_pointses,
+            mut control_input_ec,
+        }: Self::SystemData,
+    ) {
+        if *game_play_status != GamePlayStatus::Playing {
+            return;
+        }
+
+        self.targets.clear();
+        self.targets.extend(
+            (
+                &entities,
+                &teams,
+                &positions,
+                &health_pointses,
+                &controller_inputs,
+            )
+                .join()
+                .filter(|(_, _, _, health_points, _)| **health_points > 0)
+                .map(|(entity, team, position, _, controller_input)| AiTarget {
+                    entity,
+                    team: *team,
+                    position: *position,
+                    controller_input: *controller_input,
+                }),
+        );
+
+        let targets = &self.targets;
+        (
+            &entities,
+            &mut ai_controlleds,
+            &controller_inputs,
+            &positions,
+            &teams,
+            &health_pointses,
+        )
+            .join()
+            .for_each(
+                |(entity, ai_controlled, controller_input, position, team, health_points)| {
+                    let controller_input = *controller_input;
+                    let controller_input_desired = if *health_points == 0 {
+                        ControllerInput::default()
+                    } else {
+                        if !ai_controlled.decision_clock.is_complete() {
+                            return;
+                        }
+                        ai_controlled.decision_clock.reset();
+
+                        let target = targets
+                            .iter()
+                            .filter(|target| target.entity != entity && target.team != *team)
+                            .min_by(|target_a, target_b| {
+                                let distance_a = (target_a.position - *position).norm_squared();
+                                let distance_b = (target_b.position - *position).norm_squared();
+                                distance_a
+                                    .partial_cmp(&distance_b)
+                                    .unwrap_or(std::cmp::Ordering::Equal)
+                            });
+                        let mirrored = mirroreds.get(entity).copied().unwrap_or_default();
+
+                        Self::decide(
+                            ai_controlled.difficulty,
+                            controller_input,
+                            *position,
+                            mirrored,
+                            target,
+                        )
+                    };
+
+                    Self::send_events(
+                        &mut control_input_ec,
+                        entity,
+                        ai_controlled.controller_id,
+                        controller_input,
+                        controller_input_desired,
+                    );
+                },
+            );
+    }
+}
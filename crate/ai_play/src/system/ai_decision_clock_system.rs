@@ -0,0 +1,22 @@
+use ai_model::play::AiControlled;
+use amethyst::ecs::{Join, System, WriteStorage};
+use derive_new::new;
+
+/// Ticks each `AiControlled` entity's `AiDecisionClock`.
+///
+/// This runs once per game tick, so computer controlled characters make the same decisions on
+/// every device in a session regardless of frame rate.
+#[derive(Debug, Default, new)]
+pub struct AiDecisionClockSystem;
+
+type AiDecisionClockSystemData<'s> = WriteStorage<'s, AiControlled>;
+
+impl<'s> System<'s> for AiDecisionClockSystem {
+    type SystemData = AiDecisionClockSystemData<'s>;
+
+    fn run(&mut self, mut ai_controlleds: Self::SystemData) {
+        (&mut ai_controlleds)
+            .join()
+            .for_each(|ai_controlled| ai_controlled.decision_clock.tick());
+    } // kcov-ignore
+}
@@ -5,7 +5,8 @@ use std::{
 };
 
 use amethyst::{utils::application_root_dir, Error};
-use serde::Deserialize;
+use ron::ser::PrettyConfig;
+use serde::{Deserialize, Serialize};
 
 use crate::{FindContext, Format, IoUtils};
 
@@ -201,4 +202,72 @@ impl AppFile {
 
         Ok(data)
     }
+
+    /// Serializes and saves the data to the specified file, returning the path written to.
+    ///
+    /// Unlike [`load_in`][load_in], the file does not need to exist beforehand.
+    ///
+    /// # Parameters:
+    ///
+    /// * `conf_dir`: Directory relative to the executable in which to save the file.
+    /// * `file_name`: Name of the file to write relative to the configuration directory.
+    /// * `data`: Data to save.
+    /// * `format`: File [format].
+    ///
+    /// [format]: enum.Format.html
+    /// [load_in]: #method.load_in
+    pub fn save_in<T, P>(
+        conf_dir: P,
+        file_name: &str,
+        data: &T,
+        format: Format,
+    ) -> Result<PathBuf, Error>
+    where
+        T: Serialize,
+        P: AsRef<Path>,
+    {
+        Self::save_in_internal(application_root_dir(), conf_dir, file_name, data, format)
+    } // kcov-ignore
+
+    /// Visible for testing.
+    #[inline]
+    pub fn save_in_internal<T, P>(
+        exe_dir_result: io::Result<PathBuf>,
+        conf_dir: P,
+        file_name: &str,
+        data: &T,
+        format: Format,
+    ) -> Result<PathBuf, Error>
+    where
+        T: Serialize,
+        P: AsRef<Path>,
+    {
+        let mut file_path = exe_dir_result?.join(conf_dir);
+        file_path.push(file_name);
+
+        let bytes = Self::save_bytes(data, format)?;
+        IoUtils::write_file(&file_path, &bytes)?;
+
+        Ok(file_path)
+    }
+
+    /// Serializes data into bytes.
+    ///
+    /// # Parameters:
+    ///
+    /// * `data`: Data to serialize.
+    /// * `format`: File [format].
+    ///
+    /// [format]: enum.Format.html
+    pub fn save_bytes<T>(data: &T, format: Format) -> Result<Vec<u8>, Error>
+    where
+        T: Serialize,
+    {
+        let bytes = match format {
+            Format::Ron => ron::ser::to_string_pretty(data, PrettyConfig::default())?.into_bytes(),
+            Format::Yaml => serde_yaml::to_vec(data)?,
+        };
+
+        Ok(bytes)
+    }
 }
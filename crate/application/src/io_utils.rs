@@ -1,6 +1,6 @@
 use std::{
     fs::File,
-    io::{self, BufReader, Read},
+    io::{self, BufReader, BufWriter, Read, Write},
     path::{Component, Path},
 };
 
@@ -27,6 +27,19 @@ impl IoUtils {
         Ok(buffer)
     }
 
+    /// Writes the given bytes to the specified file, replacing any existing contents.
+    ///
+    /// # Parameters
+    ///
+    /// * `file_path`: `Path` to the file to write.
+    /// * `bytes`: Bytes to write.
+    pub fn write_file(file_path: &Path, bytes: &[u8]) -> io::Result<()> {
+        debug!("Writing file: {}", file_path.display());
+        let mut file = BufWriter::new(File::create(file_path)?);
+        file.write_all(bytes)?;
+        file.flush()
+    }
+
     /// Returns the basename of the path as a String.
     ///
     /// The path must contain at least one textual segment, for example:
@@ -13,6 +13,7 @@ use game_input_model::play::ControlInputEvent;
 use game_mode_selection_model::GameModeSelectionEvent;
 use game_play_model::GamePlayEvent;
 use network_mode_selection_model::NetworkModeSelectionEvent;
+use session_browse_model::SessionBrowseEvent;
 use session_host_model::SessionHostEvent;
 use session_join_model::SessionJoinEvent;
 use session_lobby_model::SessionLobbyEvent;
@@ -42,6 +43,8 @@ pub enum AppEvent {
     GamePlay(GamePlayEvent),
     /// `network_mode_selection` events.
     NetworkModeSelection(NetworkModeSelectionEvent),
+    /// `session_browse` events.
+    SessionBrowse(SessionBrowseEvent),
     /// `session_host` events.
     SessionHost(SessionHostEvent),
     /// `session_join` events.
@@ -26,6 +26,7 @@ use energy_loading::EnergyLoadingBundle;
 use game_input_model::config::ControlBindings;
 use game_loading::GameLoadingState;
 use input_reaction_loading::InputReactionLoadingBundle;
+use item_loading::ItemLoadingBundle;
 use kinematic_loading::KinematicLoadingBundle;
 use loading::{LoadingBundle, LoadingState};
 use map_loading::MapLoadingBundle;
@@ -121,6 +122,7 @@ impl AutexousiousApplication {
             .with_bundle(MapLoadingBundle::new())
             .with_bundle(CharacterLoadingBundle::new())
             .with_bundle(EnergyLoadingBundle::new())
+            .with_bundle(ItemLoadingBundle::new())
             .with_bundle(InputReactionLoadingBundle::new())
             .with_bundle(CollisionAudioLoadingBundle::new(ASSETS_PATH.clone()))
             .with_bundle(UiAudioLoadingBundle::new(ASSETS_PATH.clone()))
@@ -206,6 +208,7 @@ impl AutexousiousApplication {
             .with_bundle(MapLoadingBundle::new())
             .with_bundle(CharacterLoadingBundle::new())
             .with_bundle(EnergyLoadingBundle::new())
+            .with_bundle(ItemLoadingBundle::new())
             .with_bundle(InputReactionLoadingBundle::new())
             .with_bundle(CollisionAudioLoadingBundle::new(ASSETS_PATH.clone()))
             .with_bundle(UiAudioLoadingBundle::new(ASSETS_PATH.clone()))
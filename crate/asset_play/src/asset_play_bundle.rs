@@ -36,7 +36,7 @@ use mirrored_model::play::Mirrored;
 use object_model::play::Grounding;
 use object_type::Character;
 use sequence_model::loaded::{SequenceEndTransitions, SequenceId, WaitSequenceHandles};
-use session_lobby_ui_model::loaded::{SessionCodeLabel, SessionDevicesWidget};
+use session_lobby_ui_model::loaded::{SessionChatLogLabel, SessionCodeLabel, SessionDevicesWidget};
 use spawn_model::loaded::SpawnsSequenceHandles;
 use sprite_model::loaded::{
     ScaleSequenceHandles, SpriteRenderSequenceHandles, TintSequenceHandles,
@@ -110,6 +110,7 @@ impl<'a, 'b> SystemBundle<'a, 'b> for AssetPlayBundle {
         asset_world.register::<Dimensions>();
         asset_world.register::<ChaseModeStick>();
 
+        asset_world.register::<SessionChatLogLabel>();
         asset_world.register::<SessionCodeLabel>();
         asset_world.register::<SessionDevicesWidget>();
 
@@ -380,6 +381,11 @@ impl<'a, 'b> SystemBundle<'a, 'b> for AssetPlayBundle {
         );
 
         // Session Lobby UI
+        builder.add(
+            ItemComponentComponentAugmentSystem::<SessionChatLogLabel>::new(),
+            &any::type_name::<ItemComponentComponentAugmentSystem<SessionChatLogLabel>>(),
+            &[],
+        );
         builder.add(
             ItemComponentComponentAugmentSystem::<SessionCodeLabel>::new(),
             &any::type_name::<ItemComponentComponentAugmentSystem<SessionCodeLabel>>(),
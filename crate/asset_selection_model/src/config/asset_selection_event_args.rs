@@ -12,6 +12,7 @@ use structopt_derive::StructOpt;
 /// * `asset_selection switch -c 0 -s default/heat`
 /// * `asset_selection select -c 0 -s default/heat`
 /// * `asset_selection deselect -c 0`
+/// * `asset_selection cpu_switch -c 1`
 /// * `asset_selection confirm`
 #[derive(Clone, Debug, Deserialize, PartialEq, Serialize, StructOpt)]
 #[serde(deny_unknown_fields, rename_all = "snake_case")]
@@ -65,6 +66,14 @@ pub enum AssetSelectionEventArgs {
         #[structopt(short, long)]
         controller_id: ControllerId,
     },
+    /// Slot has switched between player control and each computer difficulty.
+    CpuSwitch {
+        /// Controller ID.
+        ///
+        /// 0 for the first player, 1 for the second player, etcetera.
+        #[structopt(short, long)]
+        controller_id: ControllerId,
+    },
     /// Asset selections have been confirmed.
     Confirm,
 }
@@ -18,6 +18,8 @@ pub enum AssetSelectionEventCommand {
     Select,
     /// Asset has been deselected.
     Deselect,
+    /// Switches the slot between player control and each computer difficulty.
+    CpuSwitch,
     /// Asset selections have been confirmed.
     Confirm,
 }
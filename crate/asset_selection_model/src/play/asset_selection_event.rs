@@ -57,6 +57,15 @@ pub enum AssetSelectionEvent {
         /// ID of the controller that sent the event.
         controller_id: ControllerId,
     },
+    /// Slot has switched between player control and each computer difficulty.
+    CpuSwitch {
+        /// Entity that the event originated from.
+        ///
+        /// This may be `None` if sent as a CLI command.
+        entity: Option<Entity>,
+        /// ID of the controller that sent the event.
+        controller_id: ControllerId,
+    },
     /// Confirm `AssetSelection`s.
     Confirm,
 }
@@ -112,6 +112,12 @@ impl StdinMapper for AssetSelectionEventStdinMapper {
                 entity: None,
                 controller_id,
             }),
+            AssetSelectionEventArgs::CpuSwitch { controller_id } => {
+                Ok(AssetSelectionEvent::CpuSwitch {
+                    entity: None,
+                    controller_id,
+                })
+            }
             AssetSelectionEventArgs::Confirm => Ok(AssetSelectionEvent::Confirm),
         }
     }
@@ -328,7 +328,9 @@ where
                     }
                 }
                 // No update needed -- preview entities are already correct.
-                AssetSelectionEvent::Select { .. } | AssetSelectionEvent::Deselect { .. } => {}
+                AssetSelectionEvent::Select { .. }
+                | AssetSelectionEvent::Deselect { .. }
+                | AssetSelectionEvent::CpuSwitch { .. } => {}
                 AssetSelectionEvent::Confirm => {}
             });
     }
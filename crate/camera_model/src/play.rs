@@ -1,6 +1,7 @@
 //! Contains data types used during game play.
 
 pub use self::{
+    camera_lock::CameraLock,
     camera_target_coordinates::CameraTargetCoordinates,
     camera_tracked::CameraTracked,
     camera_zoom_dimensions::{
@@ -9,6 +10,7 @@ pub use self::{
     },
 };
 
+mod camera_lock;
 mod camera_target_coordinates;
 mod camera_tracked;
 mod camera_zoom_dimensions;
@@ -0,0 +1,12 @@
+use derivative::Derivative;
+
+/// Whether the game camera follows tracked entities.
+#[derive(Clone, Copy, Debug, Derivative, PartialEq, Eq)]
+#[derivative(Default)]
+pub enum CameraLock {
+    /// The camera follows `CameraTracked` entities.
+    #[derivative(Default)]
+    Unlocked,
+    /// The camera stays where it is.
+    Locked,
+}
@@ -4,7 +4,9 @@ use amethyst::{
     renderer::camera::Camera,
     shred::{ResourceId, SystemData},
 };
-use camera_model::play::{CameraTargetCoordinates, CameraTracked, CameraZoomDimensions};
+use camera_model::play::{
+    CameraLock, CameraTargetCoordinates, CameraTracked, CameraZoomDimensions,
+};
 use derivative::Derivative;
 use derive_new::new;
 use kinematic_model::config::Position;
@@ -16,12 +18,17 @@ use map_selection_model::MapSelection;
 use mirrored_model::play::Mirrored;
 
 /// Focuses the camera at the average position of tracked entities.
+///
+/// The camera target is left unchanged while the `CameraLock` is `Locked`.
 #[derive(Debug, Default, new)]
 pub struct CameraTrackingSystem;
 
 #[derive(Derivative, SystemData)]
 #[derivative(Debug)]
 pub struct CameraTrackingSystemData<'s> {
+    /// `CameraLock` resource.
+    #[derivative(Debug = "ignore")]
+    pub camera_lock: Read<'s, CameraLock>,
     /// `CameraZoomDimensions` resource.
     #[derivative(Debug = "ignore")]
     pub camera_zoom_dimensions: Read<'s, CameraZoomDimensions>,
@@ -141,6 +148,7 @@ impl<'s> System<'s> for CameraTrackingSystem {
     fn run(
         &mut self,
         CameraTrackingSystemData {
+            camera_lock,
             camera_zoom_dimensions,
             map_selection,
             asset_map_bounds,
@@ -152,6 +160,10 @@ impl<'s> System<'s> for CameraTrackingSystem {
             mut camera_target_coordinateses,
         }: Self::SystemData,
     ) {
+        if *camera_lock == CameraLock::Locked {
+            return;
+        }
+
         let map_asset_id = map_selection
             .asset_id()
             .expect("Expected `MapSelection` asset ID to exist.");
@@ -41,6 +41,8 @@ pub enum CharacterSequenceName {
     RunStop,
     /// Dodge while running.
     Dodge,
+    /// Defending against attacks from the front.
+    Defend,
     /// Character is about to jump.
     Jump,
     /// Character has just jumped off the ground.
@@ -111,6 +113,14 @@ pub enum CharacterSequenceName {
     FallForwardLand,
     /// Lying on ground face down.
     LieFaceDown,
+    /// Holding a grabbed character.
+    GrabHold,
+    /// Held by a grabbing character.
+    Grabbed,
+    /// Throwing a grabbed character.
+    Throw,
+    /// Thrown by a grabbing character.
+    Thrown,
 }
 
 impl SequenceName for CharacterSequenceName {}
@@ -17,4 +17,8 @@ pub struct CharacterHitTransitions {
     pub high_stun: SequenceId,
     /// Sequence ID to transition to when falling.
     pub falling: SequenceId,
+    /// Sequence ID of the defend sequence, if the character has one.
+    ///
+    /// Hits are blocked when the character is in this sequence, and the sequence is restarted.
+    pub defend: Option<SequenceId>,
 }
@@ -1,8 +1,9 @@
 use amethyst::ecs::{storage::DenseVecStorage, Component};
 use derivative::Derivative;
+use serde::{Deserialize, Serialize};
 
 /// States used to track X axis input over time to determine when a character should run.
-#[derive(Clone, Copy, Debug, Derivative, PartialEq, Eq)]
+#[derive(Clone, Copy, Debug, Derivative, Deserialize, PartialEq, Eq, Serialize)]
 #[derivative(Default)]
 pub enum RunCounter {
     /// Used when there has not been any X axis input for the number of ticks defined by
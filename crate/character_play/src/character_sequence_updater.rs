@@ -2,11 +2,11 @@ use character_model::config::CharacterSequenceName;
 
 use crate::{
     sequence_handler::{
-        CharacterSequenceHandler, DashAttack, DashBack, DashBackAscend, DashBackDescend,
-        DashDescendLand, DashForward, DashForwardAscend, DashForwardDescend, Dodge,
-        FallForwardAscend, FallForwardDescend, FallForwardLand, Jump, JumpAscend, JumpAttack,
-        JumpDescend, JumpDescendLand, JumpOff, LieFaceDown, Run, RunStop, Stand, StandAttack,
-        StandOnSequenceEnd, Walk,
+        common::SequenceRepeat, CharacterSequenceHandler, DashAttack, DashBack, DashBackAscend,
+        DashBackDescend, DashDescendLand, DashForward, DashForwardAscend, DashForwardDescend,
+        Defend, Dodge, FallForwardAscend, FallForwardDescend, FallForwardLand, Jump, JumpAscend,
+        JumpAttack, JumpDescend, JumpDescendLand, JumpOff, LieFaceDown, Run, RunStop, Stand,
+        StandAttack, StandOnSequenceEnd, Walk,
     },
     CharacterSequenceUpdateComponents,
 };
@@ -35,6 +35,7 @@ impl CharacterSequenceUpdater {
             CharacterSequenceName::Run => &Run::update,
             CharacterSequenceName::RunStop => &RunStop::update,
             CharacterSequenceName::Dodge => &Dodge::update,
+            CharacterSequenceName::Defend => &Defend::update,
             CharacterSequenceName::Jump => &Jump::update,
             CharacterSequenceName::JumpOff => &JumpOff::update,
             CharacterSequenceName::JumpAscend => &JumpAscend::update,
@@ -43,8 +44,11 @@ impl CharacterSequenceUpdater {
             CharacterSequenceName::JumpAttack => &JumpAttack::update,
             CharacterSequenceName::Flinch0
             | CharacterSequenceName::Flinch1
-            | CharacterSequenceName::Dazed => &StandOnSequenceEnd::update,
-            CharacterSequenceName::FallForwardAscend => &FallForwardAscend::update,
+            | CharacterSequenceName::Dazed
+            | CharacterSequenceName::Throw => &StandOnSequenceEnd::update,
+            CharacterSequenceName::FallForwardAscend | CharacterSequenceName::Thrown => {
+                &FallForwardAscend::update
+            }
             CharacterSequenceName::FallForwardDescend => &FallForwardDescend::update,
             CharacterSequenceName::FallForwardLand => &FallForwardLand::update,
             CharacterSequenceName::LieFaceDown => &LieFaceDown::update,
@@ -56,6 +60,10 @@ impl CharacterSequenceUpdater {
             CharacterSequenceName::DashBackDescend => &DashBackDescend::update,
             CharacterSequenceName::DashDescendLand => &DashDescendLand::update,
             CharacterSequenceName::DashAttack => &DashAttack::update,
+            // Grab sequences are ended by the grab being released or thrown.
+            CharacterSequenceName::GrabHold | CharacterSequenceName::Grabbed => {
+                &SequenceRepeat::update
+            }
         };
 
         sequence_handler(components)
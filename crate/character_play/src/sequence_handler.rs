@@ -8,13 +8,13 @@ pub use self::{
     dash_attack::DashAttack, dash_back::DashBack, dash_back_ascend::DashBackAscend,
     dash_back_descend::DashBackDescend, dash_descend_land::DashDescendLand,
     dash_forward::DashForward, dash_forward_ascend::DashForwardAscend,
-    dash_forward_descend::DashForwardDescend, dodge::Dodge, fall_forward_ascend::FallForwardAscend,
-    fall_forward_descend::FallForwardDescend, fall_forward_land::FallForwardLand, jump::Jump,
-    jump_ascend::JumpAscend, jump_attack::JumpAttack, jump_descend::JumpDescend,
-    jump_descend_land::JumpDescendLand, jump_off::JumpOff, lie_face_down::LieFaceDown, run::Run,
-    run_stop::RunStop, sequence_handler_util::SequenceHandlerUtil, stand::Stand,
-    stand_attack::StandAttack, stand_on_sequence_end::StandOnSequenceEnd,
-    switch_sequence_on_descend::SwitchSequenceOnDescend,
+    dash_forward_descend::DashForwardDescend, defend::Defend, dodge::Dodge,
+    fall_forward_ascend::FallForwardAscend, fall_forward_descend::FallForwardDescend,
+    fall_forward_land::FallForwardLand, jump::Jump, jump_ascend::JumpAscend,
+    jump_attack::JumpAttack, jump_descend::JumpDescend, jump_descend_land::JumpDescendLand,
+    jump_off::JumpOff, lie_face_down::LieFaceDown, run::Run, run_stop::RunStop,
+    sequence_handler_util::SequenceHandlerUtil, stand::Stand, stand_attack::StandAttack,
+    stand_on_sequence_end::StandOnSequenceEnd, switch_sequence_on_descend::SwitchSequenceOnDescend,
     switch_sequence_on_end::SwitchSequenceOnEnd,
     switch_sequence_on_end_y_velocity::SwitchSequenceOnEndYVelocity,
     switch_sequence_on_land::SwitchSequenceOnLand, walk::Walk,
@@ -30,6 +30,7 @@ mod dash_descend_land;
 mod dash_forward;
 mod dash_forward_ascend;
 mod dash_forward_descend;
+mod defend;
 mod dodge;
 mod fall_forward_ascend;
 mod fall_forward_descend;
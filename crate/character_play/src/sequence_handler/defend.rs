@@ -0,0 +1,35 @@
+use character_model::config::CharacterSequenceName;
+use sequence_model::play::SequenceStatus;
+
+use crate::{
+    sequence_handler::{
+        common::{grounding::AirborneCheck, status::AliveCheck},
+        CharacterSequenceHandler,
+    },
+    CharacterSequenceUpdateComponents,
+};
+
+/// `Defend` sequence update.
+///
+/// The character keeps defending while the `Defend` button is held.
+#[derive(Debug)]
+pub struct Defend;
+
+impl CharacterSequenceHandler for Defend {
+    fn update(components: CharacterSequenceUpdateComponents<'_>) -> Option<CharacterSequenceName> {
+        [AliveCheck::update, AirborneCheck::update]
+            .iter()
+            .fold(None, |status_update, fn_update| {
+                status_update.or_else(|| fn_update(components))
+            })
+            .or_else(|| {
+                if components.sequence_status != SequenceStatus::End {
+                    None
+                } else if components.controller_input.defend {
+                    Some(CharacterSequenceName::Defend)
+                } else {
+                    Some(CharacterSequenceName::Stand)
+                }
+            })
+    }
+}
@@ -6,9 +6,16 @@ use character_model::{
     config::CharacterSequenceName, loaded::CharacterHitTransitions, play::RunCounter,
 };
 use charge_model::play::{ChargeRetention, ChargeTrackerClock};
+use collision_model::loaded::{
+    GrabReleaseTransition, GrabbedTransition, GrabbingTransition, ThrowingTransition,
+    ThrownTransition,
+};
 use game_input_model::play::ControllerInput;
 use map_model::play::MapBounded;
-use object_model::{config::Mass, play::HealthPoints};
+use object_model::{
+    config::Mass,
+    play::{GuardPoints, HealthPoints},
+};
 use object_status_model::config::StunPoints;
 use sequence_model::{config::SequenceNameString, loaded::SequenceId};
 
@@ -39,6 +46,7 @@ impl CharacterEntityAugmenter {
         CharacterComponentStorages {
             controller_inputs,
             health_pointses,
+            guard_pointses,
             stun_pointses,
             run_counters,
             masses,
@@ -49,6 +57,11 @@ impl CharacterEntityAugmenter {
             charge_use_modes,
             charge_retentions,
             character_hit_transitionses,
+            grabbing_transitions,
+            grabbed_transitions,
+            throwing_transitions,
+            thrown_transitions,
+            grab_release_transitions,
         }: &mut CharacterComponentStorages<'s>,
         asset_id: AssetId,
         entity: Entity,
@@ -73,30 +86,27 @@ impl CharacterEntityAugmenter {
                     asset_id
                 )
             });
-        let low_stun = sequence_id_mappings
-            .id(&SequenceNameString::Name(CharacterSequenceName::Flinch0))
-            .copied()
-            .unwrap_or(SequenceId(0));
-        let mid_stun = sequence_id_mappings
-            .id(&SequenceNameString::Name(CharacterSequenceName::Flinch1))
-            .copied()
-            .unwrap_or(SequenceId(0));
-        let high_stun = sequence_id_mappings
-            .id(&SequenceNameString::Name(CharacterSequenceName::Dazed))
-            .copied()
-            .unwrap_or(SequenceId(0));
-        let falling = sequence_id_mappings
-            .id(&SequenceNameString::Name(
-                CharacterSequenceName::FallForwardAscend,
-            ))
-            .copied()
-            .unwrap_or(SequenceId(0));
+        let sequence_id = |sequence_name: CharacterSequenceName| {
+            sequence_id_mappings
+                .id(&SequenceNameString::Name(sequence_name))
+                .copied()
+                .unwrap_or(SequenceId(0))
+        };
+        let low_stun = sequence_id(CharacterSequenceName::Flinch0);
+        let mid_stun = sequence_id(CharacterSequenceName::Flinch1);
+        let high_stun = sequence_id(CharacterSequenceName::Dazed);
+        let falling = sequence_id(CharacterSequenceName::FallForwardAscend);
+        // Characters without a `defend` sequence cannot block hits, so this does not fall back.
+        let defend = sequence_id_mappings
+            .id(&SequenceNameString::Name(CharacterSequenceName::Defend))
+            .copied();
 
         let character_hit_transitions = CharacterHitTransitions {
             low_stun,
             mid_stun,
             high_stun,
             falling,
+            defend,
         };
 
         // Controller of this entity
@@ -106,6 +116,9 @@ impl CharacterEntityAugmenter {
         health_pointses
             .insert(entity, HealthPoints::default())
             .expect("Failed to insert `HealthPoints` component.");
+        guard_pointses
+            .insert(entity, GuardPoints::default())
+            .expect("Failed to insert `GuardPoints` component.");
         stun_pointses
             .insert(entity, StunPoints::default())
             .expect("Failed to insert `StunPoints` component.");
@@ -146,5 +159,35 @@ impl CharacterEntityAugmenter {
         character_hit_transitionses
             .insert(entity, character_hit_transitions)
             .expect("Failed to insert `CharacterHitTransitions` component.");
+        grabbing_transitions
+            .insert(
+                entity,
+                GrabbingTransition(sequence_id(CharacterSequenceName::GrabHold)),
+            )
+            .expect("Failed to insert `GrabbingTransition` component.");
+        grabbed_transitions
+            .insert(
+                entity,
+                GrabbedTransition(sequence_id(CharacterSequenceName::Grabbed)),
+            )
+            .expect("Failed to insert `GrabbedTransition` component.");
+        throwing_transitions
+            .insert(
+                entity,
+                ThrowingTransition(sequence_id(CharacterSequenceName::Throw)),
+            )
+            .expect("Failed to insert `ThrowingTransition` component.");
+        thrown_transitions
+            .insert(
+                entity,
+                ThrownTransition(sequence_id(CharacterSequenceName::Thrown)),
+            )
+            .expect("Failed to insert `ThrownTransition` component.");
+        grab_release_transitions
+            .insert(
+                entity,
+                GrabReleaseTransition(sequence_id(CharacterSequenceName::Stand)),
+            )
+            .expect("Failed to insert `GrabReleaseTransition` component.");
     }
 }
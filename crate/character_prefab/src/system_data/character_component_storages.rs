@@ -7,10 +7,17 @@ use charge_model::{
     config::{ChargeDelay, ChargeLimit, ChargeUseMode},
     play::{ChargeRetention, ChargeTrackerClock},
 };
+use collision_model::loaded::{
+    GrabReleaseTransition, GrabbedTransition, GrabbingTransition, ThrowingTransition,
+    ThrownTransition,
+};
 use derivative::Derivative;
 use game_input_model::play::ControllerInput;
 use map_model::play::MapBounded;
-use object_model::{config::Mass, play::HealthPoints};
+use object_model::{
+    config::Mass,
+    play::{GuardPoints, HealthPoints},
+};
 use object_status_model::config::StunPoints;
 
 /// Character specific `Component` storages.
@@ -26,6 +33,9 @@ pub struct CharacterComponentStorages<'s> {
     /// `HealthPoints` component storage.
     #[derivative(Debug = "ignore")]
     pub health_pointses: WriteStorage<'s, HealthPoints>,
+    /// `GuardPoints` components.
+    #[derivative(Debug = "ignore")]
+    pub guard_pointses: WriteStorage<'s, GuardPoints>,
     /// `StunPoints` component storage.
     #[derivative(Debug = "ignore")]
     pub stun_pointses: WriteStorage<'s, StunPoints>,
@@ -56,4 +66,19 @@ pub struct CharacterComponentStorages<'s> {
     /// `CharacterHitTransitions` components.
     #[derivative(Debug = "ignore")]
     pub character_hit_transitionses: WriteStorage<'s, CharacterHitTransitions>,
+    /// `GrabbingTransition` components.
+    #[derivative(Debug = "ignore")]
+    pub grabbing_transitions: WriteStorage<'s, GrabbingTransition>,
+    /// `GrabbedTransition` components.
+    #[derivative(Debug = "ignore")]
+    pub grabbed_transitions: WriteStorage<'s, GrabbedTransition>,
+    /// `ThrowingTransition` components.
+    #[derivative(Debug = "ignore")]
+    pub throwing_transitions: WriteStorage<'s, ThrowingTransition>,
+    /// `ThrownTransition` components.
+    #[derivative(Debug = "ignore")]
+    pub thrown_transitions: WriteStorage<'s, ThrownTransition>,
+    /// `GrabReleaseTransition` components.
+    #[derivative(Debug = "ignore")]
+    pub grab_release_transitions: WriteStorage<'s, GrabReleaseTransition>,
 }
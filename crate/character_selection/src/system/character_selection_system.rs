@@ -1,3 +1,4 @@
+use ai_model::config::AiDifficulty;
 use amethyst::{
     ecs::{Read, System, World, Write},
     shred::{ResourceId, SystemData},
@@ -8,7 +9,7 @@ use asset_selection_model::play::{AssetSelection, AssetSelectionEvent};
 use character_selection_model::CharacterSelections;
 use derivative::Derivative;
 use derive_new::new;
-use log::warn;
+use log::{debug, warn};
 use object_type::ObjectType;
 
 /// Populates the `CharacterSelections` based on user input.
@@ -86,6 +87,29 @@ impl<'s> System<'s> for CharacterSelectionSystem {
                 AssetSelectionEvent::Deselect { controller_id, .. } => {
                     character_selections.selections.remove(&controller_id);
                 }
+                AssetSelectionEvent::Leave { controller_id, .. } => {
+                    character_selections.cpu_selections.remove(&controller_id);
+                }
+                AssetSelectionEvent::CpuSwitch { controller_id, .. } => {
+                    // Player -> Easy -> Normal -> Hard -> Player.
+                    let ai_difficulty =
+                        match character_selections.cpu_selections.get(&controller_id) {
+                            None => Some(AiDifficulty::Easy),
+                            Some(ai_difficulty) => ai_difficulty.next(),
+                        };
+
+                    debug!(
+                        "Controller {} CPU difficulty: {:?}",
+                        controller_id, ai_difficulty
+                    );
+                    if let Some(ai_difficulty) = ai_difficulty {
+                        character_selections
+                            .cpu_selections
+                            .insert(controller_id, ai_difficulty);
+                    } else {
+                        character_selections.cpu_selections.remove(&controller_id);
+                    }
+                }
                 _ => {}
             });
     }
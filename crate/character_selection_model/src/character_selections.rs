@@ -1,5 +1,6 @@
 use std::collections::HashMap;
 
+use ai_model::config::AiDifficulty;
 use asset_model::loaded::AssetId;
 use derive_new::new;
 use game_input_model::config::ControllerId;
@@ -11,4 +12,7 @@ use game_input_model::config::ControllerId;
 pub struct CharacterSelections {
     /// Map of controller ID to character asset ID.
     pub selections: HashMap<ControllerId, AssetId>,
+    /// Map of controller ID to difficulty, for slots that are controlled by the computer.
+    #[new(default)]
+    pub cpu_selections: HashMap<ControllerId, AiDifficulty>,
 }
@@ -1,7 +1,8 @@
 use amethyst::ecs::{storage::VecStorage, Component};
+use serde::{Deserialize, Serialize};
 
 /// Whether or not an object is charging.
-#[derive(Clone, Component, Copy, Debug, PartialEq, Eq)]
+#[derive(Clone, Component, Copy, Debug, Deserialize, PartialEq, Eq, Serialize)]
 #[storage(VecStorage)]
 pub enum ChargeStatus {
     /// Object is not charging.
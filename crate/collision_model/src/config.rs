@@ -1,14 +1,18 @@
 //! Types representing collision configuration.
 
 pub use self::{
-    body::Body, hit::Hit, hit_limit::HitLimit, hit_repeat_delay::HitRepeatDelay,
-    interaction::Interaction, interaction_kind::InteractionKind, interactions::Interactions,
+    body::Body, grab::Grab, grab_duration::GrabDuration, hit::Hit, hit_limit::HitLimit,
+    hit_repeat_delay::HitRepeatDelay, interaction::Interaction, interaction_kind::InteractionKind,
+    interactions::Interactions, pickup::Pickup,
 };
 
 mod body;
+mod grab;
+mod grab_duration;
 mod hit;
 mod hit_limit;
 mod hit_repeat_delay;
 mod interaction;
 mod interaction_kind;
 mod interactions;
+mod pickup;
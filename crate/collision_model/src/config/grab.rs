@@ -0,0 +1,20 @@
+use derive_new::new;
+use kinematic_model::config::Velocity;
+use serde::{Deserialize, Serialize};
+
+use crate::config::GrabDuration;
+
+/// Configuration of a grab interaction.
+///
+/// The object with this interaction holds the object whose body it collides with, until the grab
+/// duration elapses or the grabbed object is thrown.
+#[derive(Clone, Copy, Debug, Default, Deserialize, PartialEq, Eq, Serialize, new)]
+#[serde(default, deny_unknown_fields)]
+pub struct Grab {
+    /// Number of ticks that the grabbed object is held for.
+    pub duration: GrabDuration,
+    /// Velocity of the grabbed object when it is thrown.
+    ///
+    /// This is relative to the grabbing object when it is not mirrored.
+    pub throw_velocity: Velocity<i32>,
+}
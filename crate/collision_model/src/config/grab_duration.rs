@@ -0,0 +1,15 @@
+use amethyst::ecs::{storage::VecStorage, Component};
+use derivative::Derivative;
+use derive_more::{Add, AddAssign, Display, From, Sub, SubAssign};
+use numeric_newtype_derive::numeric_newtype;
+use serde::{Deserialize, Serialize};
+
+/// Default number of ticks that a grabbed object is held for.
+const GRAB_DURATION_DEFAULT: u32 = 60;
+
+/// Number of ticks that a grabbed object is held for before it is released.
+#[numeric_newtype]
+#[derive(Component, Debug, Derivative, Deserialize, Hash, Serialize)]
+#[derivative(Default)]
+#[storage(VecStorage)]
+pub struct GrabDuration(#[derivative(Default(value = "GRAB_DURATION_DEFAULT"))] pub u32);
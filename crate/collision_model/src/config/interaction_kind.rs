@@ -1,7 +1,7 @@
 use derivative::Derivative;
 use serde::{Deserialize, Serialize};
 
-use crate::config::Hit;
+use crate::config::{Grab, Hit, Pickup};
 
 /// Type of collision -- hit, picking weapon, grabbing, and so on.
 #[derive(Clone, Copy, Debug, Derivative, Deserialize, PartialEq, Eq, Serialize)]
@@ -12,4 +12,8 @@ pub enum InteractionKind {
     /// other entities.
     #[derivative(Default)]
     Hit(Hit),
+    /// Collision picks up the other object, such as an item.
+    Pickup(Pickup),
+    /// Collision grabs the other object, holding it until it is released or thrown.
+    Grab(Grab),
 }
@@ -0,0 +1,10 @@
+use derive_new::new;
+use serde::{Deserialize, Serialize};
+
+/// Configuration of a pickup interaction.
+///
+/// The object with this interaction picks up the object whose body it collides with, if that
+/// object can be picked up.
+#[derive(Clone, Copy, Debug, Default, Deserialize, PartialEq, Eq, Serialize, new)]
+#[serde(default, deny_unknown_fields)]
+pub struct Pickup {}
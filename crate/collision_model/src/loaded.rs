@@ -6,15 +6,25 @@
 pub use self::{
     body_sequence::{BodySequence, BodySequenceHandle},
     body_sequence_handles::BodySequenceHandles,
+    grab_release_transition::GrabReleaseTransition,
+    grabbed_transition::GrabbedTransition,
+    grabbing_transition::GrabbingTransition,
     hit_transition::HitTransition,
     hitting_transition::HittingTransition,
     interactions_sequence::{InteractionsSequence, InteractionsSequenceHandle},
     interactions_sequence_handles::InteractionsSequenceHandles,
+    throwing_transition::ThrowingTransition,
+    thrown_transition::ThrownTransition,
 };
 
 mod body_sequence;
 mod body_sequence_handles;
+mod grab_release_transition;
+mod grabbed_transition;
+mod grabbing_transition;
 mod hit_transition;
 mod hitting_transition;
 mod interactions_sequence;
 mod interactions_sequence_handles;
+mod throwing_transition;
+mod thrown_transition;
@@ -0,0 +1,9 @@
+use amethyst::ecs::{storage::VecStorage, Component};
+use derive_deref::{Deref, DerefMut};
+use derive_new::new;
+use sequence_model::loaded::SequenceId;
+
+/// Sequence to transition to when a grab ends without a throw.
+#[derive(Clone, Component, Copy, Debug, Deref, DerefMut, PartialEq, new)]
+#[storage(VecStorage)]
+pub struct GrabReleaseTransition(pub SequenceId);
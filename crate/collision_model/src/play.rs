@@ -1,13 +1,17 @@
 //! Types used during game play.
 
 pub use self::{
-    collision_event::CollisionEvent, contact_event::ContactEvent, hit_event::HitEvent,
+    collision_event::CollisionEvent, contact_event::ContactEvent, grab_clock::GrabClock,
+    grab_tracker::GrabTracker, grabbed_by::GrabbedBy, hit_event::HitEvent,
     hit_object_count::HitObjectCount, hit_repeat_clock::HitRepeatClock,
     hit_repeat_tracker::HitRepeatTracker, hit_repeat_trackers::HitRepeatTrackers,
 };
 
 mod collision_event;
 mod contact_event;
+mod grab_clock;
+mod grab_tracker;
+mod grabbed_by;
 mod hit_event;
 mod hit_object_count;
 mod hit_repeat_clock;
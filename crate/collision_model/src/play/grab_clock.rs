@@ -0,0 +1,9 @@
+use amethyst::ecs::{storage::DenseVecStorage, Component};
+use derive_deref::{Deref, DerefMut};
+use derive_more::From;
+use logic_clock::logic_clock;
+use serde::{Deserialize, Serialize};
+
+/// Logic clock to track how long a grabbed object has been held.
+#[logic_clock]
+pub struct GrabClock;
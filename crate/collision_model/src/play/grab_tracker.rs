@@ -0,0 +1,37 @@
+use amethyst::ecs::{storage::DenseVecStorage, world::EntitiesRes, Component, Entity};
+use derive_new::new;
+use snapshot_model::play::{SnapshotComponent, SnapshotEntity};
+
+use crate::{config::Grab, play::GrabClock};
+
+/// Tracks the object grabbed by this entity.
+///
+/// This component should be attached to the grabbing entity. The grabbed entity has a
+/// `GrabbedBy` component linking back to the grabbing entity.
+#[derive(Clone, Component, Copy, Debug, PartialEq, new)]
+#[storage(DenseVecStorage)]
+pub struct GrabTracker {
+    /// Object entity that is grabbed.
+    pub entity: Entity,
+    /// Grab configuration of the interaction.
+    pub grab: Grab,
+    /// Logic clock to track when the grabbed object should be released.
+    pub clock: GrabClock,
+}
+
+impl SnapshotComponent for GrabTracker {
+    type Data = (SnapshotEntity, Grab, GrabClock);
+
+    fn to_snapshot(&self) -> Self::Data {
+        (SnapshotEntity::from(self.entity), self.grab, self.clock)
+    }
+
+    fn from_snapshot(
+        (snapshot_entity, grab, clock): Self::Data,
+        entities: &EntitiesRes,
+    ) -> Option<Self> {
+        snapshot_entity
+            .entity(entities)
+            .map(|entity| GrabTracker::new(entity, grab, clock))
+    }
+}
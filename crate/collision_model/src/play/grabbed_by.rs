@@ -0,0 +1,25 @@
+use amethyst::ecs::{storage::DenseVecStorage, world::EntitiesRes, Component, Entity};
+use derive_new::new;
+use snapshot_model::play::{SnapshotComponent, SnapshotEntity};
+
+/// Links a grabbed entity to the entity grabbing it.
+///
+/// This component should be attached to the grabbed entity. The grabbing entity has a
+/// `GrabTracker` component linking back to the grabbed entity.
+///
+/// Unlike `ParentEntity`, the grabbed entity is not deleted when the grabbing entity is deleted.
+#[derive(Clone, Component, Copy, Debug, PartialEq, new)]
+#[storage(DenseVecStorage)]
+pub struct GrabbedBy(pub Entity);
+
+impl SnapshotComponent for GrabbedBy {
+    type Data = SnapshotEntity;
+
+    fn to_snapshot(&self) -> Self::Data {
+        SnapshotEntity::from(self.0)
+    }
+
+    fn from_snapshot(snapshot_entity: Self::Data, entities: &EntitiesRes) -> Option<Self> {
+        snapshot_entity.entity(entities).map(GrabbedBy)
+    }
+}
@@ -1,11 +1,12 @@
 use std::cmp::PartialEq;
 
-use amethyst::ecs::{storage::DenseVecStorage, Component};
+use amethyst::ecs::{storage::DenseVecStorage, world::EntitiesRes, Component};
 use derive_deref::{Deref, DerefMut};
 use derive_new::new;
 use slotmap::{DefaultKey, SlotMap};
+use snapshot_model::play::{SnapshotComponent, SnapshotEntity};
 
-use crate::play::HitRepeatTracker;
+use crate::play::{HitRepeatClock, HitRepeatTracker};
 
 /// Component that tracks the hit repeat delays for the objects that an entity hits.
 #[derive(Component, Clone, Debug, Deref, DerefMut, new)]
@@ -18,3 +19,38 @@ impl PartialEq for HitRepeatTrackers {
             .all(|(left, right)| left == right)
     }
 }
+
+impl SnapshotComponent for HitRepeatTrackers {
+    type Data = Vec<(SnapshotEntity, HitRepeatClock)>;
+
+    fn to_snapshot(&self) -> Self::Data {
+        self.values()
+            .map(|hit_repeat_tracker| {
+                (
+                    SnapshotEntity::from(hit_repeat_tracker.entity),
+                    hit_repeat_tracker.clock,
+                )
+            })
+            .collect::<Vec<(SnapshotEntity, HitRepeatClock)>>()
+    }
+
+    fn from_snapshot(data: Self::Data, entities: &EntitiesRes) -> Option<Self> {
+        // Trackers for entities that are no longer alive are dropped.
+        let hit_repeat_trackers = data
+            .into_iter()
+            .filter_map(|(snapshot_entity, clock)| {
+                snapshot_entity
+                    .entity(entities)
+                    .map(|entity| HitRepeatTracker::new(entity, clock))
+            })
+            .fold(
+                SlotMap::new(),
+                |mut hit_repeat_trackers, hit_repeat_tracker| {
+                    hit_repeat_trackers.insert(hit_repeat_tracker);
+                    hit_repeat_trackers
+                },
+            );
+
+        Some(HitRepeatTrackers::new(hit_repeat_trackers))
+    }
+}
@@ -2,9 +2,17 @@
 
 //! Provides logic used during game play.
 
-pub use crate::system::{
-    CollisionDetectionSystem, ContactDetectionSystem, HitDetectionSystem, HitEffectSystem,
-    HitRepeatTrackersAugmentSystem, HitRepeatTrackersTickerSystem, HittingEffectSystem,
+pub use crate::{
+    sweep_and_prune::SweepAndPrune,
+    system::{
+        CollisionDetectionSystem, ContactDetectionSystem, GrabEffectSystem, GrabHoldSystem,
+        HitDetectionSystem, HitEffectSystem, HitRepeatTrackersAugmentSystem,
+        HitRepeatTrackersTickerSystem, HittingEffectSystem,
+    },
 };
 
+pub(crate) use crate::volume_extent::VolumeExtent;
+
+mod sweep_and_prune;
 mod system;
+mod volume_extent;
@@ -0,0 +1,113 @@
+use std::cmp::Ordering;
+
+/// Broad phase collision candidate finder.
+///
+/// Items are sorted by their minimum X coordinate, so that only items whose X range overlaps a
+/// query range are visited. Items are further pruned by their Z range.
+///
+/// Usage:
+///
+/// 1. `clear` the items from the previous tick.
+/// 2. `insert` each item with its X and Z ranges.
+/// 3. `prepare` to sort the items.
+/// 4. Query `candidates` for each range to test.
+#[derive(Clone, Debug)]
+pub struct SweepAndPrune<T> {
+    /// Items with their X and Z ranges.
+    entries: Vec<SweepAndPruneEntry<T>>,
+    /// Largest X range length of all items.
+    x_length_max: f32,
+}
+
+#[derive(Clone, Copy, Debug)]
+struct SweepAndPruneEntry<T> {
+    item: T,
+    x_range: [f32; 2],
+    z_range: [f32; 2],
+}
+
+impl<T> Default for SweepAndPrune<T> {
+    fn default() -> Self {
+        SweepAndPrune {
+            entries: Vec::new(),
+            x_length_max: 0.,
+        }
+    }
+}
+
+impl<T> SweepAndPrune<T>
+where
+    T: Copy,
+{
+    /// Returns a new `SweepAndPrune`.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Removes all items.
+    pub fn clear(&mut self) {
+        self.entries.clear();
+        self.x_length_max = 0.;
+    }
+
+    /// Inserts an item with its minimum and maximum X and Z coordinates.
+    pub fn insert(&mut self, item: T, x_range: [f32; 2], z_range: [f32; 2]) {
+        let x_length = x_range[1] - x_range[0];
+        if x_length > self.x_length_max {
+            self.x_length_max = x_length;
+        }
+
+        self.entries.push(SweepAndPruneEntry {
+            item,
+            x_range,
+            z_range,
+        });
+    }
+
+    /// Sorts the items by their minimum X coordinate.
+    ///
+    /// This must be called after inserting items and before querying `candidates`.
+    pub fn prepare(&mut self) {
+        self.entries.sort_by(|entry_a, entry_b| {
+            entry_a.x_range[0]
+                .partial_cmp(&entry_b.x_range[0])
+                .unwrap_or(Ordering::Equal)
+        });
+    }
+
+    /// Returns the number of items.
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Returns whether there are no items.
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Returns the items whose X and Z ranges overlap the given ranges.
+    pub fn candidates(&self, x_range: [f32; 2], z_range: [f32; 2]) -> impl Iterator<Item = T> + '_ {
+        // Items that start before this cannot reach the query range.
+        let x_min_start = x_range[0] - self.x_length_max;
+        let index_start = self
+            .entries
+            .binary_search_by(|entry| {
+                if entry.x_range[0] < x_min_start {
+                    Ordering::Less
+                } else {
+                    Ordering::Greater
+                }
+            })
+            .unwrap_or_else(|index| index);
+
+        self.entries[index_start..]
+            .iter()
+            .take_while(move |entry| entry.x_range[0] <= x_range[1])
+            .filter(move |entry| {
+                entry.x_range[1] >= x_range[0]
+                    && entry.z_range[0] <= z_range[1]
+                    && entry.z_range[1] >= z_range[0]
+            })
+            .map(|entry| entry.item)
+    }
+}
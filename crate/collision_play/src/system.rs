@@ -1,6 +1,7 @@
 pub use self::{
     collision_detection_system::CollisionDetectionSystem,
-    contact_detection_system::ContactDetectionSystem, hit_detection_system::HitDetectionSystem,
+    contact_detection_system::ContactDetectionSystem, grab_effect_system::GrabEffectSystem,
+    grab_hold_system::GrabHoldSystem, hit_detection_system::HitDetectionSystem,
     hit_effect_system::HitEffectSystem,
     hit_repeat_trackers_augment_system::HitRepeatTrackersAugmentSystem,
     hit_repeat_trackers_ticker_system::HitRepeatTrackersTickerSystem,
@@ -9,6 +10,8 @@ pub use self::{
 
 mod collision_detection_system;
 mod contact_detection_system;
+mod grab_effect_system;
+mod grab_hold_system;
 mod hit_detection_system;
 mod hit_effect_system;
 mod hit_repeat_trackers_augment_system;
@@ -1,7 +1,7 @@
 use amethyst::{
     assets::{AssetStorage, Handle},
     core::{math::Vector3, transform::Transform},
-    ecs::{Entities, Join, Read, ReadStorage, System, World, Write},
+    ecs::{Entities, Entity, Join, Read, ReadStorage, System, World, Write},
     renderer::{SpriteRender, SpriteSheet},
     shred::{ResourceId, SystemData},
     shrev::EventChannel,
@@ -16,9 +16,18 @@ use log::debug;
 use mirrored_model::play::Mirrored;
 use shape_model::Volume;
 
+use crate::{SweepAndPrune, VolumeExtent};
+
+/// Padding added to broad phase ranges, as `Box` volumes are truncated to integer coordinates.
+const BROAD_PHASE_PADDING: f32 = 1.;
+
 /// Detects collisions for all objects.
 #[derive(Debug, Default, new)]
-pub struct CollisionDetectionSystem;
+pub struct CollisionDetectionSystem {
+    /// Body entities sorted along the X axis for the broad phase.
+    #[new(default)]
+    body_sweep_and_prune: SweepAndPrune<Entity>,
+}
 
 #[derive(Derivative, SystemData)]
 #[derivative(Debug)]
@@ -56,7 +65,14 @@ pub struct CollisionDetectionSystemData<'s> {
 }
 
 impl CollisionDetectionSystem {
-    fn intersects(
+    /// Returns whether any of the interaction's bounds intersect with the body volume.
+    ///
+    /// # Parameters
+    ///
+    /// * `relative_pos`: Position of the body entity relative to the interaction entity.
+    /// * `interaction`: The `Interaction`, its sprite offsets, and whether it is mirrored.
+    /// * `body`: The body `Volume`, its sprite offsets, and whether it is mirrored.
+    pub fn intersects(
         relative_pos: &Vector3<f32>,
         (interaction, interaction_offsets, interaction_mirrored): (&Interaction, [f32; 2], bool),
         (body, body_offsets, body_mirrored): (&Volume, [f32; 2], bool),
@@ -122,8 +138,18 @@ impl CollisionDetectionSystem {
                 x_intersects && y_intersects && z_intersects
             }
 
-            // Explicitly fail because we haven't finished this.
-            _ => unimplemented!(),
+            (bound, body) => {
+                let interaction_extent = VolumeExtent::new(
+                    bound,
+                    interaction_offsets,
+                    interaction_mirrored,
+                    &Vector3::zeros(),
+                );
+                let body_extent =
+                    VolumeExtent::new(body, body_offsets, body_mirrored, relative_pos);
+
+                interaction_extent.intersects(&body_extent)
+            }
         })
     }
 
@@ -152,6 +178,68 @@ impl CollisionDetectionSystem {
 
         (coord as i32, coord_w as i32)
     }
+
+    /// Returns the X and Z ranges that enclose all of the given volumes.
+    ///
+    /// Returns `None` if there are no volumes.
+    ///
+    /// # Parameters
+    ///
+    /// * `volumes`: Volumes to enclose.
+    /// * `offsets`: Sprite offsets of the object.
+    /// * `mirrored`: Whether the object is mirrored.
+    /// * `position`: Position of the object.
+    pub fn volumes_range<'v>(
+        volumes: impl Iterator<Item = &'v Volume>,
+        offsets: [f32; 2],
+        mirrored: bool,
+        position: &Vector3<f32>,
+    ) -> Option<([f32; 2], [f32; 2])> {
+        volumes
+            .map(|volume| VolumeExtent::new(*volume, offsets, mirrored, position))
+            .map(|volume_extent| (volume_extent.axis_range(0), volume_extent.axis_range(2)))
+            .fold(None, |ranges, (x_range, z_range)| {
+                let (x_range_all, z_range_all) = ranges.unwrap_or((x_range, z_range));
+                Some((
+                    [
+                        x_range_all[0].min(x_range[0]),
+                        x_range_all[1].max(x_range[1]),
+                    ],
+                    [
+                        z_range_all[0].min(z_range[0]),
+                        z_range_all[1].max(z_range[1]),
+                    ],
+                ))
+            })
+            .map(|(x_range, z_range)| {
+                (
+                    [
+                        x_range[0] - BROAD_PHASE_PADDING,
+                        x_range[1] + BROAD_PHASE_PADDING,
+                    ],
+                    [
+                        z_range[0] - BROAD_PHASE_PADDING,
+                        z_range[1] + BROAD_PHASE_PADDING,
+                    ],
+                )
+            })
+    }
+
+    /// Returns the sprite offsets, accounting for the half width and height shift from Amethyst.
+    fn sprite_offsets(
+        sprite_sheet_assets: &AssetStorage<SpriteSheet>,
+        sprite_render: &SpriteRender,
+    ) -> [f32; 2] {
+        let sprite_sheet = sprite_sheet_assets
+            .get(&sprite_render.sprite_sheet)
+            .expect("Expected sprite sheet for sprite_render to exist.");
+        let sprite = &sprite_sheet.sprites[sprite_render.sprite_number];
+
+        [
+            sprite.offsets[0] + sprite.width / 2.,
+            sprite.offsets[1] + sprite.height / 2.,
+        ]
+    }
 }
 
 impl<'s> System<'s> for CollisionDetectionSystem {
@@ -172,8 +260,36 @@ impl<'s> System<'s> for CollisionDetectionSystem {
             mut collision_ec,
         }: Self::SystemData,
     ) {
-        // Naive collision detection.
-        // TODO: Use broad sweep + narrow sweep for optimization.
+        // Broad phase: Only entities whose X and Z ranges overlap are tested for collision.
+        let body_sweep_and_prune = &mut self.body_sweep_and_prune;
+        body_sweep_and_prune.clear();
+        (
+            &entities,
+            &transforms,
+            &body_handles,
+            &sprite_renders,
+            &mirroreds,
+        )
+            .join()
+            .for_each(
+                |(to, to_transform, body_handle, to_sprite_render, to_mirrored)| {
+                    let body_offsets = Self::sprite_offsets(&sprite_sheet_assets, to_sprite_render);
+                    let body = body_assets
+                        .get(body_handle)
+                        .expect("Expected `Body` from handle to exist.");
+
+                    if let Some((x_range, z_range)) = Self::volumes_range(
+                        body.iter(),
+                        body_offsets,
+                        to_mirrored.0,
+                        to_transform.translation(),
+                    ) {
+                        body_sweep_and_prune.insert(to, x_range, z_range);
+                    }
+                },
+            );
+        body_sweep_and_prune.prepare();
+
         for (from, from_transform, interactions_handle, from_sprite_render, from_mirrored) in (
             &entities,
             &transforms,
@@ -183,53 +299,54 @@ impl<'s> System<'s> for CollisionDetectionSystem {
         )
             .join()
         {
-            for (to, to_transform, body_handle, to_sprite_render, to_mirrored) in (
-                &entities,
-                &transforms,
-                &body_handles,
-                &sprite_renders,
-                &mirroreds,
-            )
-                .join()
-            {
+            let interaction_offsets =
+                Self::sprite_offsets(&sprite_sheet_assets, from_sprite_render);
+            let interactions = interactions_assets
+                .get(interactions_handle)
+                .expect("Expected `Interactions` from handle to exist.");
+
+            let (x_range, z_range) = if let Some(ranges) = Self::volumes_range(
+                interactions
+                    .iter()
+                    .flat_map(|interaction| interaction.bounds.iter()),
+                interaction_offsets,
+                from_mirrored.0,
+                from_transform.translation(),
+            ) {
+                ranges
+            } else {
+                continue;
+            };
+
+            // Narrow phase.
+            for to in body_sweep_and_prune.candidates(x_range, z_range) {
                 if from == to {
                     // Skip self
                     continue;
                 }
 
-                let interaction_offsets = {
-                    let sprite_sheet = sprite_sheet_assets
-                        .get(&from_sprite_render.sprite_sheet)
-                        .expect("Expected sprite sheet for from_sprite_render to exist.");
-                    let sprite = &sprite_sheet.sprites[from_sprite_render.sprite_number];
-
-                    // Account for half width and height shift from Amethyst
-                    [
-                        sprite.offsets[0] + sprite.width / 2.,
-                        sprite.offsets[1] + sprite.height / 2.,
-                    ]
+                // Candidates are only inserted for entities with all of these components.
+                let (to_transform, body_handle, to_sprite_render, to_mirrored) = match (
+                    transforms.get(to),
+                    body_handles.get(to),
+                    sprite_renders.get(to),
+                    mirroreds.get(to),
+                ) {
+                    (
+                        Some(to_transform),
+                        Some(body_handle),
+                        Some(to_sprite_render),
+                        Some(to_mirrored),
+                    ) => (to_transform, body_handle, to_sprite_render, to_mirrored),
+                    _ => continue,
                 };
 
-                let body_offsets = {
-                    let sprite_sheet = sprite_sheet_assets
-                        .get(&to_sprite_render.sprite_sheet)
-                        .expect("Expected sprite sheet for to_sprite_render to exist.");
-                    let sprite = &sprite_sheet.sprites[to_sprite_render.sprite_number];
-
-                    // Account for half width and height shift from Amethyst
-                    [
-                        sprite.offsets[0] + sprite.width / 2.,
-                        sprite.offsets[1] + sprite.height / 2.,
-                    ]
-                };
+                let body_offsets = Self::sprite_offsets(&sprite_sheet_assets, to_sprite_render);
 
                 let mut relative_pos = to_transform.translation() - from_transform.translation();
                 // Undo the Z shift from both entities, see `ObjectTransformUpdateSystem`
                 relative_pos[1] += to_transform.translation()[2] - from_transform.translation()[2];
 
-                let interactions = interactions_assets
-                    .get(interactions_handle)
-                    .expect("Expected `Interactions` from handle to exist.");
                 let body = body_assets
                     .get(body_handle)
                     .expect("Expected `Body` from handle to exist.");
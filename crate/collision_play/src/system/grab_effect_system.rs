@@ -0,0 +1,126 @@
+use amethyst::{
+    ecs::{Read, ReadStorage, System, World, WriteStorage},
+    shred::{ResourceId, SystemData},
+    shrev::{EventChannel, ReaderId},
+};
+use collision_model::{
+    config::{Grab, Interaction, InteractionKind},
+    loaded::{GrabbedTransition, GrabbingTransition},
+    play::{ContactEvent, GrabClock, GrabTracker, GrabbedBy},
+};
+use derivative::Derivative;
+use derive_new::new;
+use kinematic_model::config::Velocity;
+use sequence_model::loaded::SequenceId;
+
+/// Grabs objects when a `Grab` interaction contacts an object that can be grabbed.
+///
+/// An object may only grab one object at a time, and an object may only be grabbed by one object.
+#[derive(Debug, Default, new)]
+pub struct GrabEffectSystem {
+    /// Reader ID for the `ContactEvent` channel.
+    #[new(default)]
+    contact_event_rid: Option<ReaderId<ContactEvent>>,
+}
+
+#[derive(Derivative, SystemData)]
+#[derivative(Debug)]
+pub struct GrabEffectSystemData<'s> {
+    /// `ContactEvent` channel.
+    #[derivative(Debug = "ignore")]
+    pub contact_ec: Read<'s, EventChannel<ContactEvent>>,
+    /// `GrabbingTransition` components.
+    #[derivative(Debug = "ignore")]
+    pub grabbing_transitions: ReadStorage<'s, GrabbingTransition>,
+    /// `GrabbedTransition` components.
+    #[derivative(Debug = "ignore")]
+    pub grabbed_transitions: ReadStorage<'s, GrabbedTransition>,
+    /// `GrabTracker` components.
+    #[derivative(Debug = "ignore")]
+    pub grab_trackers: WriteStorage<'s, GrabTracker>,
+    /// `GrabbedBy` components.
+    #[derivative(Debug = "ignore")]
+    pub grabbed_bys: WriteStorage<'s, GrabbedBy>,
+    /// `SequenceId` components.
+    #[derivative(Debug = "ignore")]
+    pub sequence_ids: WriteStorage<'s, SequenceId>,
+    /// `Velocity<f32>` components.
+    #[derivative(Debug = "ignore")]
+    pub velocities: WriteStorage<'s, Velocity<f32>>,
+}
+
+impl<'s> System<'s> for GrabEffectSystem {
+    type SystemData = GrabEffectSystemData<'s>;
+
+    fn run(
+        &mut self,
+        GrabEffectSystemData {
+            contact_ec,
+            grabbing_transitions,
+            grabbed_transitions,
+            mut grab_trackers,
+            mut grabbed_bys,
+            mut sequence_ids,
+            mut velocities,
+        }: Self::SystemData,
+    ) {
+        let contact_event_rid = self
+            .contact_event_rid
+            .as_mut()
+            .expect("Expected `contact_event_rid` field to be set.");
+
+        contact_ec.read(contact_event_rid).for_each(|ev| {
+            let grab = if let Interaction {
+                kind: InteractionKind::Grab(grab),
+                ..
+            } = ev.interaction
+            {
+                grab
+            } else {
+                return;
+            };
+
+            let grabber = ev.from;
+            let grabbed = ev.to;
+
+            // Neither object may already be part of another grab.
+            let grabbed_transition = grabbed_transitions.get(grabbed).copied();
+            let is_free = |entity| !grab_trackers.contains(entity) && !grabbed_bys.contains(entity);
+
+            if let (Some(GrabbedTransition(grabbed_sequence_id)), true, true) =
+                (grabbed_transition, is_free(grabber), is_free(grabbed))
+            {
+                let Grab { duration, .. } = grab;
+                let grab_clock = GrabClock::new(*duration as usize);
+
+                grab_trackers
+                    .insert(grabber, GrabTracker::new(grabbed, grab, grab_clock))
+                    .expect("Failed to insert `GrabTracker` component.");
+                grabbed_bys
+                    .insert(grabbed, GrabbedBy::new(grabber))
+                    .expect("Failed to insert `GrabbedBy` component.");
+
+                if let Some(GrabbingTransition(grabbing_sequence_id)) =
+                    grabbing_transitions.get(grabber).copied()
+                {
+                    let _ = sequence_ids.insert(grabber, grabbing_sequence_id);
+                }
+                let _ = sequence_ids.insert(grabbed, grabbed_sequence_id);
+
+                if let Some(velocity) = velocities.get_mut(grabbed) {
+                    *velocity = Velocity::default();
+                }
+            }
+        });
+    }
+
+    fn setup(&mut self, world: &mut World) {
+        Self::SystemData::setup(world);
+
+        self.contact_event_rid = Some(
+            world
+                .fetch_mut::<EventChannel<ContactEvent>>()
+                .register_reader(),
+        );
+    }
+}
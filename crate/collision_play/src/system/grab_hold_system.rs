@@ -0,0 +1,165 @@
+use amethyst::{
+    ecs::{Entities, Entity, Join, Read, ReadStorage, System, World, WriteStorage},
+    shred::{ResourceId, SystemData},
+    shrev::{EventChannel, ReaderId},
+};
+use collision_model::{
+    loaded::GrabReleaseTransition,
+    play::{GrabTracker, GrabbedBy, HitEvent},
+};
+use derivative::Derivative;
+use derive_new::new;
+use kinematic_model::config::Position;
+use mirrored_model::play::Mirrored;
+use sequence_model::loaded::SequenceId;
+
+/// Ticks each `GrabTracker`'s clock, and keeps grabbed objects in the grabber's hands.
+///
+/// Grabbed objects are released when the `GrabClock` completes, when the grabber is hit, or when
+/// either object is no longer alive. A grabber that is hit does not transition to its release
+/// sequence, as its hit sequence takes precedence.
+#[derive(Debug, Default, new)]
+pub struct GrabHoldSystem {
+    /// Reader ID for the `HitEvent` channel.
+    #[new(default)]
+    hit_event_rid: Option<ReaderId<HitEvent>>,
+}
+
+#[derive(Derivative, SystemData)]
+#[derivative(Debug)]
+pub struct GrabHoldSystemData<'s> {
+    /// `Entities` resource.
+    #[derivative(Debug = "ignore")]
+    pub entities: Entities<'s>,
+    /// `HitEvent` channel.
+    #[derivative(Debug = "ignore")]
+    pub hit_ec: Read<'s, EventChannel<HitEvent>>,
+    /// `GrabReleaseTransition` components.
+    #[derivative(Debug = "ignore")]
+    pub grab_release_transitions: ReadStorage<'s, GrabReleaseTransition>,
+    /// `GrabTracker` components.
+    #[derivative(Debug = "ignore")]
+    pub grab_trackers: WriteStorage<'s, GrabTracker>,
+    /// `GrabbedBy` components.
+    #[derivative(Debug = "ignore")]
+    pub grabbed_bys: WriteStorage<'s, GrabbedBy>,
+    /// `Position<f32>` components.
+    #[derivative(Debug = "ignore")]
+    pub positions: WriteStorage<'s, Position<f32>>,
+    /// `Mirrored` components.
+    #[derivative(Debug = "ignore")]
+    pub mirroreds: WriteStorage<'s, Mirrored>,
+    /// `SequenceId` components.
+    #[derivative(Debug = "ignore")]
+    pub sequence_ids: WriteStorage<'s, SequenceId>,
+}
+
+impl GrabHoldSystem {
+    fn release(
+        grab_release_transitions: &ReadStorage<'_, GrabReleaseTransition>,
+        sequence_ids: &mut WriteStorage<'_, SequenceId>,
+        entity: Entity,
+    ) {
+        if let Some(GrabReleaseTransition(sequence_id)) =
+            grab_release_transitions.get(entity).copied()
+        {
+            let _ = sequence_ids.insert(entity, sequence_id);
+        }
+    }
+}
+
+impl<'s> System<'s> for GrabHoldSystem {
+    type SystemData = GrabHoldSystemData<'s>;
+
+    fn run(
+        &mut self,
+        GrabHoldSystemData {
+            entities,
+            hit_ec,
+            grab_release_transitions,
+            mut grab_trackers,
+            mut grabbed_bys,
+            mut positions,
+            mut mirroreds,
+            mut sequence_ids,
+        }: Self::SystemData,
+    ) {
+        let hit_event_rid = self
+            .hit_event_rid
+            .as_mut()
+            .expect("Expected `hit_event_rid` field to be set.");
+
+        // Grabbers that are hit let go of the grabbed object.
+        let grabbers_hit = hit_ec
+            .read(hit_event_rid)
+            .map(|ev| ev.to)
+            .filter(|entity| grab_trackers.contains(*entity))
+            .collect::<Vec<Entity>>();
+        grabbers_hit.into_iter().for_each(|grabber| {
+            if let Some(grab_tracker) = grab_trackers.remove(grabber) {
+                let grabbed = grab_tracker.entity;
+                if entities.is_alive(grabbed) {
+                    grabbed_bys.remove(grabbed);
+                    Self::release(&grab_release_transitions, &mut sequence_ids, grabbed);
+                }
+            }
+        });
+
+        let released = (&entities, &mut grab_trackers)
+            .join()
+            .filter_map(|(grabber, grab_tracker)| {
+                grab_tracker.clock.tick();
+
+                let grabbed = grab_tracker.entity;
+                if grab_tracker.clock.is_complete() || !entities.is_alive(grabbed) {
+                    return Some((grabber, grabbed));
+                }
+
+                let grabber_position = positions.get(grabber).copied();
+                if let Some(grabber_position) = grabber_position {
+                    if let Some(position) = positions.get_mut(grabbed) {
+                        *position = grabber_position;
+                    }
+                }
+
+                // The grabbed object faces the grabber.
+                let grabber_mirrored = mirroreds.get(grabber).copied().unwrap_or_default();
+                let _ = mirroreds.insert(grabbed, !grabber_mirrored);
+
+                None
+            })
+            .collect::<Vec<(Entity, Entity)>>();
+
+        released.into_iter().for_each(|(grabber, grabbed)| {
+            grab_trackers.remove(grabber);
+            Self::release(&grab_release_transitions, &mut sequence_ids, grabber);
+
+            if entities.is_alive(grabbed) {
+                grabbed_bys.remove(grabbed);
+                Self::release(&grab_release_transitions, &mut sequence_ids, grabbed);
+            }
+        });
+
+        // Release grabbed objects whose grabber is no longer alive.
+        let orphans = (&entities, &grabbed_bys)
+            .join()
+            .filter(|(_, grabbed_by)| !entities.is_alive(grabbed_by.0))
+            .map(|(grabbed, _)| grabbed)
+            .collect::<Vec<Entity>>();
+
+        orphans.into_iter().for_each(|grabbed| {
+            grabbed_bys.remove(grabbed);
+            Self::release(&grab_release_transitions, &mut sequence_ids, grabbed);
+        });
+    }
+
+    fn setup(&mut self, world: &mut World) {
+        Self::SystemData::setup(world);
+
+        self.hit_event_rid = Some(
+            world
+                .fetch_mut::<EventChannel<HitEvent>>()
+                .register_reader(),
+        );
+    }
+}
@@ -0,0 +1,278 @@
+use amethyst::core::math::Vector3;
+use shape_model::{Axis, Volume};
+
+/// `Volume` positioned relative to another entity.
+///
+/// Sprite offsets, mirroring, and the relative position of the entity have been applied.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum VolumeExtent {
+    /// Axis aligned box.
+    Cuboid {
+        /// Minimum coordinates of the box.
+        min: Vector3<f32>,
+        /// Maximum coordinates of the box.
+        max: Vector3<f32>,
+    },
+    /// Axis aligned cylinder.
+    Cylinder {
+        /// Index of the axis the cylinder is aligned with.
+        axis: usize,
+        /// Center of the cylinder.
+        center: Vector3<f32>,
+        /// Radius of the cylinder.
+        r: f32,
+        /// Half of the length of the cylinder.
+        half_l: f32,
+    },
+    /// Sphere.
+    Sphere {
+        /// Center of the sphere.
+        center: Vector3<f32>,
+        /// Radius of the sphere.
+        r: f32,
+    },
+}
+
+impl VolumeExtent {
+    /// Returns the `VolumeExtent` for a `Volume`.
+    pub fn new(
+        volume: Volume,
+        offsets: [f32; 2],
+        mirrored: bool,
+        relative_pos: &Vector3<f32>,
+    ) -> Self {
+        let x_coordinate = |x: f32| {
+            let x = x - offsets[0];
+            let x = if mirrored { -x } else { x };
+            x + relative_pos[0]
+        };
+        let y_coordinate = |y: f32| y - offsets[1] + relative_pos[1];
+        let z_coordinate = |z: f32| z + relative_pos[2];
+
+        match volume {
+            Volume::Box { x, y, z, w, h, d } => {
+                let x1 = x_coordinate(x as f32);
+                let x2 = x_coordinate((x + w as i32) as f32);
+                let min = Vector3::new(x1.min(x2), y_coordinate(y as f32), z_coordinate(z as f32));
+                let max = Vector3::new(
+                    x1.max(x2),
+                    y_coordinate((y + h as i32) as f32),
+                    z_coordinate((z + d as i32) as f32),
+                );
+                VolumeExtent::Cuboid { min, max }
+            }
+            Volume::Cylinder {
+                axis,
+                center: cylinder_center,
+                r,
+                l,
+            } => {
+                // The cross section of the cylinder is centered on the object's position.
+                let mut center = *relative_pos;
+                let axis = Self::axis_index(axis);
+                center[axis] = match axis {
+                    0 => x_coordinate(cylinder_center as f32),
+                    1 => y_coordinate(cylinder_center as f32),
+                    _ => z_coordinate(cylinder_center as f32),
+                };
+                VolumeExtent::Cylinder {
+                    axis,
+                    center,
+                    r: r as f32,
+                    half_l: l as f32 / 2.,
+                }
+            }
+            Volume::Sphere { x, y, z, r } => VolumeExtent::Sphere {
+                center: Vector3::new(
+                    x_coordinate(x as f32),
+                    y_coordinate(y as f32),
+                    z_coordinate(z as f32),
+                ),
+                r: r as f32,
+            },
+        }
+    }
+
+    fn axis_index(axis: Axis) -> usize {
+        match axis {
+            Axis::X => 0,
+            Axis::Y => 1,
+            Axis::Z => 2,
+        }
+    }
+
+    /// Returns the minimum and maximum coordinates of this extent along the given axis.
+    pub fn axis_range(&self, axis: usize) -> [f32; 2] {
+        match *self {
+            VolumeExtent::Cuboid { min, max } => [min[axis], max[axis]],
+            VolumeExtent::Cylinder {
+                axis: cylinder_axis,
+                center,
+                r,
+                half_l,
+            } => {
+                let half_extent = if axis == cylinder_axis { half_l } else { r };
+                [center[axis] - half_extent, center[axis] + half_extent]
+            }
+            VolumeExtent::Sphere { center, r } => [center[axis] - r, center[axis] + r],
+        }
+    }
+
+    /// Returns whether this extent intersects with another.
+    pub fn intersects(&self, other: &VolumeExtent) -> bool {
+        match (*self, *other) {
+            (
+                VolumeExtent::Cuboid {
+                    min: min_a,
+                    max: max_a,
+                },
+                VolumeExtent::Cuboid {
+                    min: min_b,
+                    max: max_b,
+                },
+            ) => (0..3).all(|i| min_a[i] <= max_b[i] && min_b[i] <= max_a[i]),
+            (VolumeExtent::Cuboid { min, max }, VolumeExtent::Sphere { center, r })
+            | (VolumeExtent::Sphere { center, r }, VolumeExtent::Cuboid { min, max }) => {
+                let closest = Vector3::new(
+                    center[0].max(min[0]).min(max[0]),
+                    center[1].max(min[1]).min(max[1]),
+                    center[2].max(min[2]).min(max[2]),
+                );
+                (center - closest).norm_squared() <= r * r
+            }
+            (
+                VolumeExtent::Cuboid { min, max },
+                VolumeExtent::Cylinder {
+                    axis,
+                    center,
+                    r,
+                    half_l,
+                },
+            )
+            | (
+                VolumeExtent::Cylinder {
+                    axis,
+                    center,
+                    r,
+                    half_l,
+                },
+                VolumeExtent::Cuboid { min, max },
+            ) => {
+                let along_axis_intersects =
+                    min[axis] <= center[axis] + half_l && center[axis] - half_l <= max[axis];
+
+                // Circle against rectangle on the plane perpendicular to the axis.
+                let distance_squared = Self::perpendicular_axes(axis)
+                    .iter()
+                    .map(|&i| center[i] - center[i].max(min[i]).min(max[i]))
+                    .map(|delta| delta * delta)
+                    .sum::<f32>();
+
+                along_axis_intersects && distance_squared <= r * r
+            }
+            (
+                VolumeExtent::Sphere {
+                    center: center_a,
+                    r: r_a,
+                },
+                VolumeExtent::Sphere {
+                    center: center_b,
+                    r: r_b,
+                },
+            ) => (center_a - center_b).norm_squared() <= (r_a + r_b) * (r_a + r_b),
+            (
+                VolumeExtent::Sphere {
+                    center: sphere_center,
+                    r: sphere_r,
+                },
+                VolumeExtent::Cylinder {
+                    axis,
+                    center,
+                    r,
+                    half_l,
+                },
+            )
+            | (
+                VolumeExtent::Cylinder {
+                    axis,
+                    center,
+                    r,
+                    half_l,
+                },
+                VolumeExtent::Sphere {
+                    center: sphere_center,
+                    r: sphere_r,
+                },
+            ) => {
+                // Distance from the sphere center to the closest point in the cylinder.
+                let radial_distance = Self::perpendicular_axes(axis)
+                    .iter()
+                    .map(|&i| sphere_center[i] - center[i])
+                    .map(|delta| delta * delta)
+                    .sum::<f32>()
+                    .sqrt();
+                let radial_excess = (radial_distance - r).max(0.);
+                let axial_excess = ((sphere_center[axis] - center[axis]).abs() - half_l).max(0.);
+
+                radial_excess * radial_excess + axial_excess * axial_excess <= sphere_r * sphere_r
+            }
+            (
+                VolumeExtent::Cylinder {
+                    axis: axis_a,
+                    center: center_a,
+                    r: r_a,
+                    half_l: half_l_a,
+                },
+                VolumeExtent::Cylinder {
+                    axis: axis_b,
+                    center: center_b,
+                    r: r_b,
+                    half_l: half_l_b,
+                },
+            ) => {
+                if axis_a == axis_b {
+                    let axis = axis_a;
+                    let along_axis_intersects =
+                        (center_a[axis] - center_b[axis]).abs() <= half_l_a + half_l_b;
+                    let distance_squared = Self::perpendicular_axes(axis)
+                        .iter()
+                        .map(|&i| center_a[i] - center_b[i])
+                        .map(|delta| delta * delta)
+                        .sum::<f32>();
+
+                    along_axis_intersects && distance_squared <= (r_a + r_b) * (r_a + r_b)
+                } else {
+                    // Perpendicular cylinders: each cylinder's length constrains the other
+                    // cylinder's cross section, and the cross sections share the third axis.
+                    let axis_shared = 3 - axis_a - axis_b;
+
+                    // Distance from `b`'s center to `a`'s extent along `a`'s axis.
+                    let distance_a =
+                        ((center_b[axis_a] - center_a[axis_a]).abs() - half_l_a).max(0.);
+                    // Distance from `a`'s center to `b`'s extent along `b`'s axis.
+                    let distance_b =
+                        ((center_a[axis_b] - center_b[axis_b]).abs() - half_l_b).max(0.);
+
+                    if distance_a > r_b || distance_b > r_a {
+                        false
+                    } else {
+                        let half_chord_a = (r_a * r_a - distance_b * distance_b).sqrt();
+                        let half_chord_b = (r_b * r_b - distance_a * distance_a).sqrt();
+
+                        (center_a[axis_shared] - center_b[axis_shared]).abs()
+                            <= half_chord_a + half_chord_b
+                    }
+                }
+            }
+        }
+    }
+
+    /// Returns the indices of the axes perpendicular to the given axis.
+    fn perpendicular_axes(axis: usize) -> [usize; 2] {
+        match axis {
+            0 => [1, 2],
+            1 => [0, 2],
+            _ => [0, 1],
+        }
+    }
+}
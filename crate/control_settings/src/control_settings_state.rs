@@ -4,7 +4,10 @@ use amethyst::{
     GameData, State, StateData, Trans,
 };
 use application_event::AppEvent;
-use control_settings_model::{ControlSettingsEntity, ControlSettingsEvent};
+use control_settings_model::{
+    play::{ControlBindingSelection, ControlRebindStatus},
+    ControlSettingsEntity, ControlSettingsEvent,
+};
 use derivative::Derivative;
 use derive_new::new;
 use log::debug;
@@ -20,6 +23,8 @@ impl<'a, 'b> State<GameData<'a, 'b>, AppEvent> for ControlSettingsState {
     fn on_start(&mut self, data: StateData<'_, GameData<'_, '_>>) {
         data.world.register::<ControlSettingsEntity>();
         data.world.insert(StateId::ControlSettings);
+        data.world.insert(ControlBindingSelection::default());
+        data.world.insert(ControlRebindStatus::default());
     }
 
     fn on_stop(&mut self, mut data: StateData<'_, GameData<'_, '_>>) {
@@ -32,12 +37,16 @@ impl<'a, 'b> State<GameData<'a, 'b>, AppEvent> for ControlSettingsState {
 
     fn handle_event(
         &mut self,
-        _data: StateData<'_, GameData<'_, '_>>,
+        data: StateData<'_, GameData<'_, '_>>,
         event: AppEvent,
     ) -> Trans<GameData<'a, 'b>, AppEvent> {
         match event {
             AppEvent::Window(window_event) => {
-                if is_key_down(&window_event, VirtualKeyCode::Escape) {
+                // `Escape` cancels rebinding a control instead of returning.
+                let control_rebind_status = *data.world.read_resource::<ControlRebindStatus>();
+                if is_key_down(&window_event, VirtualKeyCode::Escape)
+                    && !control_rebind_status.is_awaiting_key()
+                {
                     debug!("Returning from `ControlSettingsState`.");
                     Trans::Pop
                 } else {
@@ -50,10 +59,8 @@ impl<'a, 'b> State<GameData<'a, 'b>, AppEvent> for ControlSettingsState {
                         debug!("Returning from `ControlSettingsState`.");
                         Trans::Pop
                     }
-                    ControlSettingsEvent::ReloadRequest => {
-                        // TODO: Reload control settings from file.
-                        Trans::None
-                    }
+                    // Reloading is handled by `ControlRebindSystem`.
+                    ControlSettingsEvent::ReloadRequest => Trans::None,
                 }
             }
             _ => Trans::None,
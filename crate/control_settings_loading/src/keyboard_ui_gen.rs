@@ -1,6 +1,9 @@
+use std::collections::HashMap;
+
+use amethyst::winit::event::VirtualKeyCode;
 use camera_model::play::CameraZoomDimensions;
 use control_settings_model::{
-    config::{ControlButtonLabel, ControlButtonLabels, KeyboardSettings},
+    config::{ControlButtonLabel, ControlButtonLabels, KeyboardLayout, KeyboardSettings},
     loaded::PlayerControlButtonsLabels,
 };
 use game_input_model::{
@@ -11,6 +14,7 @@ use indexmap::IndexMap;
 use log::error;
 use sprite_model::config::Scale;
 use strum::IntoEnumIterator;
+use ui_label_model::config::UiSpriteLabel;
 use ui_model::config::UiSequences;
 
 use crate::{ButtonToPlayerIndexMapper, ControlButtonToButtonMapper, PcblRepositioner};
@@ -26,28 +30,18 @@ impl KeyboardUiGen {
         player_input_configs: &PlayerInputConfigs,
         sequences: &mut UiSequences,
     ) -> ControlButtonLabels {
-        let layout_positions = keyboard_settings
-            .layout_positions
-            .get(&keyboard_settings.layout);
+        let layout_positions = Self::layout_positions(keyboard_settings);
 
         if let Some(layout_positions) = layout_positions {
             let control_button_to_player_index =
                 ButtonToPlayerIndexMapper::map(player_input_configs);
 
-            let ui_sprite_labels = keyboard_settings
-                .layout
-                .buttons()
-                .iter()
-                .filter_map(|key| {
-                    layout_positions
-                        .get(key)
-                        .cloned()
-                        .map(|ui_sprite_label| (key, ui_sprite_label))
-                })
+            let ui_sprite_labels = layout_positions
+                .into_iter()
                 .map(|(key, mut ui_sprite_label)| {
                     // Sequence to adjust tint.
                     let ui_sequence = sequences.get_mut(&ui_sprite_label.sequence);
-                    let player_index = control_button_to_player_index.get(key).copied();
+                    let player_index = control_button_to_player_index.get(&key).copied();
                     let tint = player_index.and_then(|player_index| {
                         keyboard_settings
                             .controller_tints
@@ -75,11 +69,7 @@ impl KeyboardUiGen {
 
             ControlButtonLabels::new(ui_sprite_labels)
         } else {
-            error!(
-                "Keyboard layout `{layout:?}` specified, but `\"{layout:?}\"` not found in \
-                 `layout_positions`.",
-                layout = keyboard_settings.layout
-            );
+            Self::layout_positions_missing_error(keyboard_settings.layout);
 
             ControlButtonLabels::default()
         }
@@ -92,9 +82,7 @@ impl KeyboardUiGen {
         camera_zoom_dimensions: CameraZoomDimensions,
         sequences: &mut UiSequences,
     ) -> Vec<PlayerControlButtonsLabels> {
-        let layout_positions = keyboard_settings
-            .layout_positions
-            .get(&keyboard_settings.layout);
+        let layout_positions = Self::layout_positions(keyboard_settings);
 
         if let Some(layout_positions) = layout_positions {
             let mut player_control_buttons_labelses =
@@ -163,13 +151,156 @@ impl KeyboardUiGen {
 
             player_control_buttons_labelses
         } else {
+            Self::layout_positions_missing_error(keyboard_settings.layout);
+
+            vec![PlayerControlButtonsLabels::default(); player_input_configs.len()]
+        }
+    }
+
+    /// Returns the `UiSpriteLabel` for each key in the keyboard layout, in layout order.
+    ///
+    /// If `layout_positions` does not contain the layout, the key positions are taken from the
+    /// layout's `physical_layout`, so that layouts which only differ in key legends do not need
+    /// their own positions. In that case, each key uses its own sprite sequence from the physical
+    /// layout or the US layout if present, and otherwise the sequence of the key it replaces.
+    ///
+    /// If `layout_positions` does not contain the physical layout either, its positions are derived
+    /// from the US layout's positions.
+    fn layout_positions(
+        keyboard_settings: &KeyboardSettings,
+    ) -> Option<IndexMap<VirtualKeyCode, UiSpriteLabel>> {
+        let layout = keyboard_settings.layout;
+        if let Some(layout_positions) = keyboard_settings.layout_positions.get(&layout) {
+            let layout_positions = layout
+                .buttons()
+                .into_iter()
+                .filter_map(|key| {
+                    layout_positions
+                        .get(&key)
+                        .cloned()
+                        .map(|ui_sprite_label| (key, ui_sprite_label))
+                })
+                .collect::<IndexMap<VirtualKeyCode, UiSpriteLabel>>();
+
+            return Some(layout_positions);
+        }
+
+        let physical_layout = layout.physical_layout();
+        let us_positions = keyboard_settings.layout_positions.get(&KeyboardLayout::Us);
+        let physical_positions = keyboard_settings
+            .layout_positions
+            .get(&physical_layout)
+            .cloned()
+            .or_else(|| {
+                us_positions.map(|us_positions| {
+                    Self::physical_positions_derive(physical_layout, us_positions)
+                })
+            })?;
+        let layout_positions = layout
+            .buttons()
+            .into_iter()
+            .zip(physical_layout.buttons().into_iter())
+            .filter_map(|(key, physical_key)| {
+                physical_positions
+                    .get(&physical_key)
+                    .map(|physical_sprite_label| {
+                        let sequence = physical_positions
+                            .get(&key)
+                            .or_else(|| {
+                                us_positions.and_then(|us_positions| us_positions.get(&key))
+                            })
+                            .unwrap_or(physical_sprite_label)
+                            .sequence
+                            .clone();
+
+                        let ui_sprite_label =
+                            UiSpriteLabel::new(physical_sprite_label.position, sequence);
+                        (key, ui_sprite_label)
+                    })
+            })
+            .collect::<IndexMap<VirtualKeyCode, UiSpriteLabel>>();
+
+        Some(layout_positions)
+    }
+
+    /// Returns key positions for a physical layout, derived from the US layout's key positions.
+    ///
+    /// Keys in the same position as on the US keyboard use the US key's position. On ISO keyboards,
+    /// the extra key is placed left of `Z`, and the hash key right of the apostrophe key. On laptop
+    /// keyboards, `Insert` and `Delete` take the place of `Sysrq` and `Scroll` in the top row.
+    fn physical_positions_derive(
+        physical_layout: KeyboardLayout,
+        us_positions: &HashMap<VirtualKeyCode, UiSpriteLabel>,
+    ) -> HashMap<VirtualKeyCode, UiSpriteLabel> {
+        // Horizontal distance between adjacent keys.
+        let key_width = us_positions
+            .get(&VirtualKeyCode::X)
+            .and_then(|key_x| {
+                us_positions
+                    .get(&VirtualKeyCode::Z)
+                    .map(|key_z| key_x.position.x - key_z.position.x)
+            })
+            .unwrap_or(0);
+        let position_offset = |anchor: VirtualKeyCode, keys: i32, sequence_key: VirtualKeyCode| {
+            us_positions.get(&anchor).and_then(|anchor_sprite_label| {
+                us_positions
+                    .get(&sequence_key)
+                    .map(|sequence_sprite_label| {
+                        let mut position = anchor_sprite_label.position;
+                        position.x += key_width * keys;
+                        UiSpriteLabel::new(position, sequence_sprite_label.sequence.clone())
+                    })
+            })
+        };
+
+        physical_layout
+            .buttons()
+            .into_iter()
+            .filter_map(|key| {
+                let ui_sprite_label = match (physical_layout, key) {
+                    (KeyboardLayout::Uk, VirtualKeyCode::OEM102) => {
+                        position_offset(VirtualKeyCode::Z, -1, VirtualKeyCode::Backslash)
+                    }
+                    (KeyboardLayout::Uk, VirtualKeyCode::Backslash) => {
+                        position_offset(VirtualKeyCode::Apostrophe, 1, VirtualKeyCode::Backslash)
+                    }
+                    (KeyboardLayout::UsLaptop, VirtualKeyCode::Insert) => {
+                        position_offset(VirtualKeyCode::Sysrq, 0, VirtualKeyCode::Insert)
+                    }
+                    (KeyboardLayout::UsLaptop, VirtualKeyCode::Delete) => {
+                        position_offset(VirtualKeyCode::Scroll, 0, VirtualKeyCode::Delete)
+                    }
+                    _ => us_positions.get(&key).cloned(),
+                };
+
+                ui_sprite_label.map(|ui_sprite_label| (key, ui_sprite_label))
+            })
+            .collect::<HashMap<VirtualKeyCode, UiSpriteLabel>>()
+    }
+
+    fn layout_positions_missing_error(layout: KeyboardLayout) {
+        let physical_layout = layout.physical_layout();
+        if layout == KeyboardLayout::Us {
             error!(
                 "Keyboard layout `{layout:?}` specified, but `\"{layout:?}\"` not found in \
                  `layout_positions`.",
-                layout = keyboard_settings.layout
+                layout = layout
+            );
+        } else if layout == physical_layout {
+            error!(
+                "Keyboard layout `{layout:?}` specified, but neither `\"{layout:?}\"` nor \
+                 `\"{us:?}\"` found in `layout_positions`.",
+                layout = layout,
+                us = KeyboardLayout::Us
+            );
+        } else {
+            error!(
+                "Keyboard layout `{layout:?}` specified, but none of `\"{layout:?}\"`, \
+                 `\"{physical_layout:?}\"`, or `\"{us:?}\"` found in `layout_positions`.",
+                layout = layout,
+                physical_layout = physical_layout,
+                us = KeyboardLayout::Us
             );
-
-            vec![PlayerControlButtonsLabels::default(); player_input_configs.len()]
         }
     }
 }
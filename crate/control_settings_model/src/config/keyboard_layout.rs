@@ -3,6 +3,10 @@ use derivative::Derivative;
 use serde::{Deserialize, Serialize};
 
 /// Keyboard layout variants.
+///
+/// Letter and digit keys are identified by their legend. Other keys are identified by the
+/// `VirtualKeyCode` of the US key in the same physical position where possible, as their key codes
+/// differ between platforms.
 #[derive(Clone, Copy, Debug, Derivative, Deserialize, Hash, PartialEq, Eq, Serialize)]
 #[derivative(Default)]
 #[serde(deny_unknown_fields, rename_all = "snake_case")]
@@ -10,129 +14,600 @@ pub enum KeyboardLayout {
     /// US keyboard layout
     #[derivative(Default)]
     Us,
+    /// US keyboard layout for compact laptop keyboards, without the navigation keys and number pad.
+    UsLaptop,
+    /// UK ISO keyboard layout.
+    Uk,
+    /// German QWERTZ keyboard layout.
+    De,
+    /// French AZERTY keyboard layout.
+    Fr,
 }
 
 impl KeyboardLayout {
     /// Returns the keyboard buttons present for this keyboard layout.
     ///
+    /// Buttons are listed in the same order for layouts that share a `physical_layout`, so the
+    /// button at each index is in the same position on the keyboard.
+    ///
     /// Development note: This is not called `keys()` as it is ambiguous with [`HashMap::keys`].
     pub fn buttons(self) -> Vec<VirtualKeyCode> {
         match self {
-            KeyboardLayout::Us => {
-                vec![
-                    // Bottom row.
-                    VirtualKeyCode::LControl,
-                    VirtualKeyCode::LWin,
-                    VirtualKeyCode::LAlt,
-                    VirtualKeyCode::Space,
-                    VirtualKeyCode::RAlt,
-                    VirtualKeyCode::RWin,
-                    VirtualKeyCode::Apps, // Context menu
-                    VirtualKeyCode::RControl,
-                    VirtualKeyCode::Left,
-                    VirtualKeyCode::Down,
-                    VirtualKeyCode::Right,
-                    VirtualKeyCode::Numpad0,
-                    VirtualKeyCode::NumpadComma,
-                    VirtualKeyCode::NumpadEnter,
-                    // Second row.
-                    VirtualKeyCode::LShift,
-                    VirtualKeyCode::Z,
-                    VirtualKeyCode::X,
-                    VirtualKeyCode::C,
-                    VirtualKeyCode::V,
-                    VirtualKeyCode::B,
-                    VirtualKeyCode::N,
-                    VirtualKeyCode::M,
-                    VirtualKeyCode::Comma,
-                    VirtualKeyCode::Period,
-                    VirtualKeyCode::Slash,
-                    VirtualKeyCode::RShift,
-                    VirtualKeyCode::Up,
-                    VirtualKeyCode::Numpad1,
-                    VirtualKeyCode::Numpad2,
-                    VirtualKeyCode::Numpad3,
-                    // Third row.
-                    VirtualKeyCode::Capital,
-                    VirtualKeyCode::A,
-                    VirtualKeyCode::S,
-                    VirtualKeyCode::D,
-                    VirtualKeyCode::F,
-                    VirtualKeyCode::G,
-                    VirtualKeyCode::H,
-                    VirtualKeyCode::J,
-                    VirtualKeyCode::K,
-                    VirtualKeyCode::L,
-                    VirtualKeyCode::Semicolon,  // Colon
-                    VirtualKeyCode::Apostrophe, // Quote / double quote.
-                    VirtualKeyCode::Return,     // Enter
-                    VirtualKeyCode::Numpad4,
-                    VirtualKeyCode::Numpad5,
-                    VirtualKeyCode::Numpad6,
-                    VirtualKeyCode::Add,
-                    // Fourth row.
-                    VirtualKeyCode::Tab,
-                    VirtualKeyCode::Q,
-                    VirtualKeyCode::W,
-                    VirtualKeyCode::E,
-                    VirtualKeyCode::R,
-                    VirtualKeyCode::T,
-                    VirtualKeyCode::Y,
-                    VirtualKeyCode::U,
-                    VirtualKeyCode::I,
-                    VirtualKeyCode::O,
-                    VirtualKeyCode::P,
-                    VirtualKeyCode::LBracket,
-                    VirtualKeyCode::RBracket,
-                    VirtualKeyCode::Backslash,
-                    VirtualKeyCode::End,
-                    VirtualKeyCode::Delete,
-                    VirtualKeyCode::PageDown,
-                    VirtualKeyCode::Numpad7,
-                    VirtualKeyCode::Numpad8,
-                    VirtualKeyCode::Numpad9,
-                    // Fifth row.
-                    VirtualKeyCode::Grave,
-                    VirtualKeyCode::Key1,
-                    VirtualKeyCode::Key2,
-                    VirtualKeyCode::Key3,
-                    VirtualKeyCode::Key4,
-                    VirtualKeyCode::Key5,
-                    VirtualKeyCode::Key6,
-                    VirtualKeyCode::Key7,
-                    VirtualKeyCode::Key8,
-                    VirtualKeyCode::Key9,
-                    VirtualKeyCode::Key0,
-                    VirtualKeyCode::Minus, // Underline
-                    VirtualKeyCode::Equals,
-                    VirtualKeyCode::Back,
-                    VirtualKeyCode::Insert,
-                    VirtualKeyCode::Home,
-                    VirtualKeyCode::PageUp,
-                    VirtualKeyCode::Numlock,
-                    VirtualKeyCode::Divide,
-                    VirtualKeyCode::Multiply,
-                    VirtualKeyCode::Subtract, // NumpadSubtract
-                    // Top row.
-                    VirtualKeyCode::Escape,
-                    VirtualKeyCode::F1,
-                    VirtualKeyCode::F2,
-                    VirtualKeyCode::F3,
-                    VirtualKeyCode::F4,
-                    VirtualKeyCode::F5,
-                    VirtualKeyCode::F6,
-                    VirtualKeyCode::F7,
-                    VirtualKeyCode::F8,
-                    VirtualKeyCode::F9,
-                    VirtualKeyCode::F10,
-                    VirtualKeyCode::F11,
-                    VirtualKeyCode::F12,
-                    VirtualKeyCode::Snapshot, // Print Screen
-                    VirtualKeyCode::Sysrq,
-                    VirtualKeyCode::Scroll,
-                    VirtualKeyCode::Pause,
-                ]
-            }
+            KeyboardLayout::Us => Self::buttons_us(),
+            KeyboardLayout::UsLaptop => Self::buttons_us_laptop(),
+            KeyboardLayout::Uk => Self::buttons_uk(),
+            KeyboardLayout::De => Self::buttons_de(),
+            KeyboardLayout::Fr => Self::buttons_fr(),
+        }
+    }
+
+    /// Returns the layout whose key positions are shared by this layout.
+    ///
+    /// The UK, German, and French layouts are all ISO keyboards, so they share the UK layout's key
+    /// positions.
+    pub fn physical_layout(self) -> KeyboardLayout {
+        match self {
+            KeyboardLayout::Us => KeyboardLayout::Us,
+            KeyboardLayout::UsLaptop => KeyboardLayout::UsLaptop,
+            KeyboardLayout::Uk | KeyboardLayout::De | KeyboardLayout::Fr => KeyboardLayout::Uk,
         }
     }
+
+    fn buttons_us() -> Vec<VirtualKeyCode> {
+        vec![
+            // Bottom row.
+            VirtualKeyCode::LControl,
+            VirtualKeyCode::LWin,
+            VirtualKeyCode::LAlt,
+            VirtualKeyCode::Space,
+            VirtualKeyCode::RAlt,
+            VirtualKeyCode::RWin,
+            VirtualKeyCode::Apps, // Context menu
+            VirtualKeyCode::RControl,
+            VirtualKeyCode::Left,
+            VirtualKeyCode::Down,
+            VirtualKeyCode::Right,
+            VirtualKeyCode::Numpad0,
+            VirtualKeyCode::NumpadComma,
+            VirtualKeyCode::NumpadEnter,
+            // Second row.
+            VirtualKeyCode::LShift,
+            VirtualKeyCode::Z,
+            VirtualKeyCode::X,
+            VirtualKeyCode::C,
+            VirtualKeyCode::V,
+            VirtualKeyCode::B,
+            VirtualKeyCode::N,
+            VirtualKeyCode::M,
+            VirtualKeyCode::Comma,
+            VirtualKeyCode::Period,
+            VirtualKeyCode::Slash,
+            VirtualKeyCode::RShift,
+            VirtualKeyCode::Up,
+            VirtualKeyCode::Numpad1,
+            VirtualKeyCode::Numpad2,
+            VirtualKeyCode::Numpad3,
+            // Third row.
+            VirtualKeyCode::Capital,
+            VirtualKeyCode::A,
+            VirtualKeyCode::S,
+            VirtualKeyCode::D,
+            VirtualKeyCode::F,
+            VirtualKeyCode::G,
+            VirtualKeyCode::H,
+            VirtualKeyCode::J,
+            VirtualKeyCode::K,
+            VirtualKeyCode::L,
+            VirtualKeyCode::Semicolon,  // Colon
+            VirtualKeyCode::Apostrophe, // Quote / double quote.
+            VirtualKeyCode::Return,     // Enter
+            VirtualKeyCode::Numpad4,
+            VirtualKeyCode::Numpad5,
+            VirtualKeyCode::Numpad6,
+            VirtualKeyCode::Add,
+            // Fourth row.
+            VirtualKeyCode::Tab,
+            VirtualKeyCode::Q,
+            VirtualKeyCode::W,
+            VirtualKeyCode::E,
+            VirtualKeyCode::R,
+            VirtualKeyCode::T,
+            VirtualKeyCode::Y,
+            VirtualKeyCode::U,
+            VirtualKeyCode::I,
+            VirtualKeyCode::O,
+            VirtualKeyCode::P,
+            VirtualKeyCode::LBracket,
+            VirtualKeyCode::RBracket,
+            VirtualKeyCode::Backslash,
+            VirtualKeyCode::End,
+            VirtualKeyCode::Delete,
+            VirtualKeyCode::PageDown,
+            VirtualKeyCode::Numpad7,
+            VirtualKeyCode::Numpad8,
+            VirtualKeyCode::Numpad9,
+            // Fifth row.
+            VirtualKeyCode::Grave,
+            VirtualKeyCode::Key1,
+            VirtualKeyCode::Key2,
+            VirtualKeyCode::Key3,
+            VirtualKeyCode::Key4,
+            VirtualKeyCode::Key5,
+            VirtualKeyCode::Key6,
+            VirtualKeyCode::Key7,
+            VirtualKeyCode::Key8,
+            VirtualKeyCode::Key9,
+            VirtualKeyCode::Key0,
+            VirtualKeyCode::Minus, // Underline
+            VirtualKeyCode::Equals,
+            VirtualKeyCode::Back,
+            VirtualKeyCode::Insert,
+            VirtualKeyCode::Home,
+            VirtualKeyCode::PageUp,
+            VirtualKeyCode::Numlock,
+            VirtualKeyCode::Divide,
+            VirtualKeyCode::Multiply,
+            VirtualKeyCode::Subtract, // NumpadSubtract
+            // Top row.
+            VirtualKeyCode::Escape,
+            VirtualKeyCode::F1,
+            VirtualKeyCode::F2,
+            VirtualKeyCode::F3,
+            VirtualKeyCode::F4,
+            VirtualKeyCode::F5,
+            VirtualKeyCode::F6,
+            VirtualKeyCode::F7,
+            VirtualKeyCode::F8,
+            VirtualKeyCode::F9,
+            VirtualKeyCode::F10,
+            VirtualKeyCode::F11,
+            VirtualKeyCode::F12,
+            VirtualKeyCode::Snapshot, // Print Screen
+            VirtualKeyCode::Sysrq,
+            VirtualKeyCode::Scroll,
+            VirtualKeyCode::Pause,
+        ]
+    }
+
+    fn buttons_us_laptop() -> Vec<VirtualKeyCode> {
+        vec![
+            // Bottom row.
+            VirtualKeyCode::LControl,
+            VirtualKeyCode::LWin,
+            VirtualKeyCode::LAlt,
+            VirtualKeyCode::Space,
+            VirtualKeyCode::RAlt,
+            VirtualKeyCode::Apps, // Context menu
+            VirtualKeyCode::RControl,
+            VirtualKeyCode::Left,
+            VirtualKeyCode::Down,
+            VirtualKeyCode::Right,
+            // Second row.
+            VirtualKeyCode::LShift,
+            VirtualKeyCode::Z,
+            VirtualKeyCode::X,
+            VirtualKeyCode::C,
+            VirtualKeyCode::V,
+            VirtualKeyCode::B,
+            VirtualKeyCode::N,
+            VirtualKeyCode::M,
+            VirtualKeyCode::Comma,
+            VirtualKeyCode::Period,
+            VirtualKeyCode::Slash,
+            VirtualKeyCode::RShift,
+            VirtualKeyCode::Up,
+            // Third row.
+            VirtualKeyCode::Capital,
+            VirtualKeyCode::A,
+            VirtualKeyCode::S,
+            VirtualKeyCode::D,
+            VirtualKeyCode::F,
+            VirtualKeyCode::G,
+            VirtualKeyCode::H,
+            VirtualKeyCode::J,
+            VirtualKeyCode::K,
+            VirtualKeyCode::L,
+            VirtualKeyCode::Semicolon,  // Colon
+            VirtualKeyCode::Apostrophe, // Quote / double quote.
+            VirtualKeyCode::Return,     // Enter
+            // Fourth row.
+            VirtualKeyCode::Tab,
+            VirtualKeyCode::Q,
+            VirtualKeyCode::W,
+            VirtualKeyCode::E,
+            VirtualKeyCode::R,
+            VirtualKeyCode::T,
+            VirtualKeyCode::Y,
+            VirtualKeyCode::U,
+            VirtualKeyCode::I,
+            VirtualKeyCode::O,
+            VirtualKeyCode::P,
+            VirtualKeyCode::LBracket,
+            VirtualKeyCode::RBracket,
+            VirtualKeyCode::Backslash,
+            // Fifth row.
+            VirtualKeyCode::Grave,
+            VirtualKeyCode::Key1,
+            VirtualKeyCode::Key2,
+            VirtualKeyCode::Key3,
+            VirtualKeyCode::Key4,
+            VirtualKeyCode::Key5,
+            VirtualKeyCode::Key6,
+            VirtualKeyCode::Key7,
+            VirtualKeyCode::Key8,
+            VirtualKeyCode::Key9,
+            VirtualKeyCode::Key0,
+            VirtualKeyCode::Minus, // Underline
+            VirtualKeyCode::Equals,
+            VirtualKeyCode::Back,
+            // Top row.
+            VirtualKeyCode::Escape,
+            VirtualKeyCode::F1,
+            VirtualKeyCode::F2,
+            VirtualKeyCode::F3,
+            VirtualKeyCode::F4,
+            VirtualKeyCode::F5,
+            VirtualKeyCode::F6,
+            VirtualKeyCode::F7,
+            VirtualKeyCode::F8,
+            VirtualKeyCode::F9,
+            VirtualKeyCode::F10,
+            VirtualKeyCode::F11,
+            VirtualKeyCode::F12,
+            VirtualKeyCode::Snapshot, // Print Screen
+            VirtualKeyCode::Insert,
+            VirtualKeyCode::Delete,
+        ]
+    }
+
+    fn buttons_uk() -> Vec<VirtualKeyCode> {
+        vec![
+            // Bottom row.
+            VirtualKeyCode::LControl,
+            VirtualKeyCode::LWin,
+            VirtualKeyCode::LAlt,
+            VirtualKeyCode::Space,
+            VirtualKeyCode::RAlt,
+            VirtualKeyCode::RWin,
+            VirtualKeyCode::Apps, // Context menu
+            VirtualKeyCode::RControl,
+            VirtualKeyCode::Left,
+            VirtualKeyCode::Down,
+            VirtualKeyCode::Right,
+            VirtualKeyCode::Numpad0,
+            VirtualKeyCode::NumpadComma,
+            VirtualKeyCode::NumpadEnter,
+            // Second row.
+            VirtualKeyCode::LShift,
+            VirtualKeyCode::OEM102, // Backslash / pipe
+            VirtualKeyCode::Z,
+            VirtualKeyCode::X,
+            VirtualKeyCode::C,
+            VirtualKeyCode::V,
+            VirtualKeyCode::B,
+            VirtualKeyCode::N,
+            VirtualKeyCode::M,
+            VirtualKeyCode::Comma,
+            VirtualKeyCode::Period,
+            VirtualKeyCode::Slash,
+            VirtualKeyCode::RShift,
+            VirtualKeyCode::Up,
+            VirtualKeyCode::Numpad1,
+            VirtualKeyCode::Numpad2,
+            VirtualKeyCode::Numpad3,
+            // Third row.
+            VirtualKeyCode::Capital,
+            VirtualKeyCode::A,
+            VirtualKeyCode::S,
+            VirtualKeyCode::D,
+            VirtualKeyCode::F,
+            VirtualKeyCode::G,
+            VirtualKeyCode::H,
+            VirtualKeyCode::J,
+            VirtualKeyCode::K,
+            VirtualKeyCode::L,
+            VirtualKeyCode::Semicolon,  // Colon
+            VirtualKeyCode::Apostrophe, // Quote / at
+            VirtualKeyCode::Backslash,  // Hash / tilde
+            VirtualKeyCode::Return,     // Enter
+            VirtualKeyCode::Numpad4,
+            VirtualKeyCode::Numpad5,
+            VirtualKeyCode::Numpad6,
+            VirtualKeyCode::Add,
+            // Fourth row.
+            VirtualKeyCode::Tab,
+            VirtualKeyCode::Q,
+            VirtualKeyCode::W,
+            VirtualKeyCode::E,
+            VirtualKeyCode::R,
+            VirtualKeyCode::T,
+            VirtualKeyCode::Y,
+            VirtualKeyCode::U,
+            VirtualKeyCode::I,
+            VirtualKeyCode::O,
+            VirtualKeyCode::P,
+            VirtualKeyCode::LBracket,
+            VirtualKeyCode::RBracket,
+            VirtualKeyCode::End,
+            VirtualKeyCode::Delete,
+            VirtualKeyCode::PageDown,
+            VirtualKeyCode::Numpad7,
+            VirtualKeyCode::Numpad8,
+            VirtualKeyCode::Numpad9,
+            // Fifth row.
+            VirtualKeyCode::Grave, // Backtick / not sign
+            VirtualKeyCode::Key1,
+            VirtualKeyCode::Key2,
+            VirtualKeyCode::Key3,
+            VirtualKeyCode::Key4,
+            VirtualKeyCode::Key5,
+            VirtualKeyCode::Key6,
+            VirtualKeyCode::Key7,
+            VirtualKeyCode::Key8,
+            VirtualKeyCode::Key9,
+            VirtualKeyCode::Key0,
+            VirtualKeyCode::Minus, // Underline
+            VirtualKeyCode::Equals,
+            VirtualKeyCode::Back,
+            VirtualKeyCode::Insert,
+            VirtualKeyCode::Home,
+            VirtualKeyCode::PageUp,
+            VirtualKeyCode::Numlock,
+            VirtualKeyCode::Divide,
+            VirtualKeyCode::Multiply,
+            VirtualKeyCode::Subtract, // NumpadSubtract
+            // Top row.
+            VirtualKeyCode::Escape,
+            VirtualKeyCode::F1,
+            VirtualKeyCode::F2,
+            VirtualKeyCode::F3,
+            VirtualKeyCode::F4,
+            VirtualKeyCode::F5,
+            VirtualKeyCode::F6,
+            VirtualKeyCode::F7,
+            VirtualKeyCode::F8,
+            VirtualKeyCode::F9,
+            VirtualKeyCode::F10,
+            VirtualKeyCode::F11,
+            VirtualKeyCode::F12,
+            VirtualKeyCode::Snapshot, // Print Screen
+            VirtualKeyCode::Sysrq,
+            VirtualKeyCode::Scroll,
+            VirtualKeyCode::Pause,
+        ]
+    }
+
+    fn buttons_de() -> Vec<VirtualKeyCode> {
+        vec![
+            // Bottom row.
+            VirtualKeyCode::LControl,
+            VirtualKeyCode::LWin,
+            VirtualKeyCode::LAlt,
+            VirtualKeyCode::Space,
+            VirtualKeyCode::RAlt,
+            VirtualKeyCode::RWin,
+            VirtualKeyCode::Apps, // Context menu
+            VirtualKeyCode::RControl,
+            VirtualKeyCode::Left,
+            VirtualKeyCode::Down,
+            VirtualKeyCode::Right,
+            VirtualKeyCode::Numpad0,
+            VirtualKeyCode::NumpadComma,
+            VirtualKeyCode::NumpadEnter,
+            // Second row.
+            VirtualKeyCode::LShift,
+            VirtualKeyCode::OEM102, // Less than / greater than
+            VirtualKeyCode::Y,
+            VirtualKeyCode::X,
+            VirtualKeyCode::C,
+            VirtualKeyCode::V,
+            VirtualKeyCode::B,
+            VirtualKeyCode::N,
+            VirtualKeyCode::M,
+            VirtualKeyCode::Comma,
+            VirtualKeyCode::Period,
+            VirtualKeyCode::Slash, // Minus / underline
+            VirtualKeyCode::RShift,
+            VirtualKeyCode::Up,
+            VirtualKeyCode::Numpad1,
+            VirtualKeyCode::Numpad2,
+            VirtualKeyCode::Numpad3,
+            // Third row.
+            VirtualKeyCode::Capital,
+            VirtualKeyCode::A,
+            VirtualKeyCode::S,
+            VirtualKeyCode::D,
+            VirtualKeyCode::F,
+            VirtualKeyCode::G,
+            VirtualKeyCode::H,
+            VirtualKeyCode::J,
+            VirtualKeyCode::K,
+            VirtualKeyCode::L,
+            VirtualKeyCode::Semicolon,  // Ö
+            VirtualKeyCode::Apostrophe, // Ä
+            VirtualKeyCode::Backslash,  // Hash / quote
+            VirtualKeyCode::Return,     // Enter
+            VirtualKeyCode::Numpad4,
+            VirtualKeyCode::Numpad5,
+            VirtualKeyCode::Numpad6,
+            VirtualKeyCode::Add,
+            // Fourth row.
+            VirtualKeyCode::Tab,
+            VirtualKeyCode::Q,
+            VirtualKeyCode::W,
+            VirtualKeyCode::E,
+            VirtualKeyCode::R,
+            VirtualKeyCode::T,
+            VirtualKeyCode::Z,
+            VirtualKeyCode::U,
+            VirtualKeyCode::I,
+            VirtualKeyCode::O,
+            VirtualKeyCode::P,
+            VirtualKeyCode::LBracket, // Ü
+            VirtualKeyCode::RBracket, // Plus / asterisk / tilde
+            VirtualKeyCode::End,
+            VirtualKeyCode::Delete,
+            VirtualKeyCode::PageDown,
+            VirtualKeyCode::Numpad7,
+            VirtualKeyCode::Numpad8,
+            VirtualKeyCode::Numpad9,
+            // Fifth row.
+            VirtualKeyCode::Grave, // Circumflex / degree
+            VirtualKeyCode::Key1,
+            VirtualKeyCode::Key2,
+            VirtualKeyCode::Key3,
+            VirtualKeyCode::Key4,
+            VirtualKeyCode::Key5,
+            VirtualKeyCode::Key6,
+            VirtualKeyCode::Key7,
+            VirtualKeyCode::Key8,
+            VirtualKeyCode::Key9,
+            VirtualKeyCode::Key0,
+            VirtualKeyCode::Minus,  // ß / question mark
+            VirtualKeyCode::Equals, // Acute / grave accent
+            VirtualKeyCode::Back,
+            VirtualKeyCode::Insert,
+            VirtualKeyCode::Home,
+            VirtualKeyCode::PageUp,
+            VirtualKeyCode::Numlock,
+            VirtualKeyCode::Divide,
+            VirtualKeyCode::Multiply,
+            VirtualKeyCode::Subtract, // NumpadSubtract
+            // Top row.
+            VirtualKeyCode::Escape,
+            VirtualKeyCode::F1,
+            VirtualKeyCode::F2,
+            VirtualKeyCode::F3,
+            VirtualKeyCode::F4,
+            VirtualKeyCode::F5,
+            VirtualKeyCode::F6,
+            VirtualKeyCode::F7,
+            VirtualKeyCode::F8,
+            VirtualKeyCode::F9,
+            VirtualKeyCode::F10,
+            VirtualKeyCode::F11,
+            VirtualKeyCode::F12,
+            VirtualKeyCode::Snapshot, // Print Screen
+            VirtualKeyCode::Sysrq,
+            VirtualKeyCode::Scroll,
+            VirtualKeyCode::Pause,
+        ]
+    }
+
+    fn buttons_fr() -> Vec<VirtualKeyCode> {
+        vec![
+            // Bottom row.
+            VirtualKeyCode::LControl,
+            VirtualKeyCode::LWin,
+            VirtualKeyCode::LAlt,
+            VirtualKeyCode::Space,
+            VirtualKeyCode::RAlt,
+            VirtualKeyCode::RWin,
+            VirtualKeyCode::Apps, // Context menu
+            VirtualKeyCode::RControl,
+            VirtualKeyCode::Left,
+            VirtualKeyCode::Down,
+            VirtualKeyCode::Right,
+            VirtualKeyCode::Numpad0,
+            VirtualKeyCode::NumpadComma,
+            VirtualKeyCode::NumpadEnter,
+            // Second row.
+            VirtualKeyCode::LShift,
+            VirtualKeyCode::OEM102, // Less than / greater than
+            VirtualKeyCode::W,
+            VirtualKeyCode::X,
+            VirtualKeyCode::C,
+            VirtualKeyCode::V,
+            VirtualKeyCode::B,
+            VirtualKeyCode::N,
+            VirtualKeyCode::Comma,
+            VirtualKeyCode::Semicolon, // Semicolon / period
+            VirtualKeyCode::Period,    // Colon / slash
+            VirtualKeyCode::Slash,     // Exclamation mark / section
+            VirtualKeyCode::RShift,
+            VirtualKeyCode::Up,
+            VirtualKeyCode::Numpad1,
+            VirtualKeyCode::Numpad2,
+            VirtualKeyCode::Numpad3,
+            // Third row.
+            VirtualKeyCode::Capital,
+            VirtualKeyCode::Q,
+            VirtualKeyCode::S,
+            VirtualKeyCode::D,
+            VirtualKeyCode::F,
+            VirtualKeyCode::G,
+            VirtualKeyCode::H,
+            VirtualKeyCode::J,
+            VirtualKeyCode::K,
+            VirtualKeyCode::L,
+            VirtualKeyCode::M,
+            VirtualKeyCode::Apostrophe, // Ù / percent
+            VirtualKeyCode::Backslash,  // Asterisk / mu
+            VirtualKeyCode::Return,     // Enter
+            VirtualKeyCode::Numpad4,
+            VirtualKeyCode::Numpad5,
+            VirtualKeyCode::Numpad6,
+            VirtualKeyCode::Add,
+            // Fourth row.
+            VirtualKeyCode::Tab,
+            VirtualKeyCode::A,
+            VirtualKeyCode::Z,
+            VirtualKeyCode::E,
+            VirtualKeyCode::R,
+            VirtualKeyCode::T,
+            VirtualKeyCode::Y,
+            VirtualKeyCode::U,
+            VirtualKeyCode::I,
+            VirtualKeyCode::O,
+            VirtualKeyCode::P,
+            VirtualKeyCode::LBracket, // Circumflex / diaeresis
+            VirtualKeyCode::RBracket, // Dollar / pound
+            VirtualKeyCode::End,
+            VirtualKeyCode::Delete,
+            VirtualKeyCode::PageDown,
+            VirtualKeyCode::Numpad7,
+            VirtualKeyCode::Numpad8,
+            VirtualKeyCode::Numpad9,
+            // Fifth row.
+            VirtualKeyCode::Grave, // Superscript two
+            VirtualKeyCode::Key1,
+            VirtualKeyCode::Key2,
+            VirtualKeyCode::Key3,
+            VirtualKeyCode::Key4,
+            VirtualKeyCode::Key5,
+            VirtualKeyCode::Key6,
+            VirtualKeyCode::Key7,
+            VirtualKeyCode::Key8,
+            VirtualKeyCode::Key9,
+            VirtualKeyCode::Key0,
+            VirtualKeyCode::Minus,  // Right parenthesis / degree
+            VirtualKeyCode::Equals, // Equals / plus
+            VirtualKeyCode::Back,
+            VirtualKeyCode::Insert,
+            VirtualKeyCode::Home,
+            VirtualKeyCode::PageUp,
+            VirtualKeyCode::Numlock,
+            VirtualKeyCode::Divide,
+            VirtualKeyCode::Multiply,
+            VirtualKeyCode::Subtract, // NumpadSubtract
+            // Top row.
+            VirtualKeyCode::Escape,
+            VirtualKeyCode::F1,
+            VirtualKeyCode::F2,
+            VirtualKeyCode::F3,
+            VirtualKeyCode::F4,
+            VirtualKeyCode::F5,
+            VirtualKeyCode::F6,
+            VirtualKeyCode::F7,
+            VirtualKeyCode::F8,
+            VirtualKeyCode::F9,
+            VirtualKeyCode::F10,
+            VirtualKeyCode::F11,
+            VirtualKeyCode::F12,
+            VirtualKeyCode::Snapshot, // Print Screen
+            VirtualKeyCode::Sysrq,
+            VirtualKeyCode::Scroll,
+            VirtualKeyCode::Pause,
+        ]
+    }
 }
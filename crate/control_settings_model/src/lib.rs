@@ -11,3 +11,4 @@ mod control_settings_event;
 
 pub mod config;
 pub mod loaded;
+pub mod play;
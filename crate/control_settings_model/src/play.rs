@@ -0,0 +1,8 @@
+//! Data types used at runtime.
+
+pub use self::{
+    control_binding_selection::ControlBindingSelection, control_rebind_status::ControlRebindStatus,
+};
+
+mod control_binding_selection;
+mod control_rebind_status;
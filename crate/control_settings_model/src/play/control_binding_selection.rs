@@ -0,0 +1,20 @@
+use derive_new::new;
+use game_input_model::config::{ControlBindingTarget, ControllerId};
+
+/// Control binding that is currently selected in the control settings screen.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, new)]
+pub struct ControlBindingSelection {
+    /// Index of the player's `PlayerInputConfig`.
+    pub player: ControllerId,
+    /// Index of the control within `ControlBindingTarget::player_targets`.
+    pub target_index: usize,
+}
+
+impl ControlBindingSelection {
+    /// Returns the `ControlBindingTarget` that is selected.
+    pub fn target(self) -> Option<ControlBindingTarget> {
+        ControlBindingTarget::player_targets(self.player)
+            .get(self.target_index)
+            .copied()
+    }
+}
@@ -0,0 +1,27 @@
+use amethyst::input::Button;
+use derivative::Derivative;
+use game_input_model::{config::ControlBindingTarget, play::GameInputModelError};
+
+/// Progress of rebinding a control in the control settings screen.
+#[derive(Clone, Copy, Debug, Derivative, PartialEq)]
+#[derivative(Default)]
+pub enum ControlRebindStatus {
+    /// No control is being rebound.
+    #[derivative(Default)]
+    Idle,
+    /// Waiting for the player to press the key to bind to the control.
+    AwaitingKey(ControlBindingTarget),
+    /// The control was bound to the button.
+    Rebound(ControlBindingTarget, Button),
+    /// Cancelled rebinding the control.
+    Cancelled(ControlBindingTarget),
+    /// Failed to bind the control.
+    Failed(GameInputModelError),
+}
+
+impl ControlRebindStatus {
+    /// Returns whether a key press is awaited for a control.
+    pub fn is_awaiting_key(self) -> bool {
+        matches!(self, ControlRebindStatus::AwaitingKey(..))
+    }
+}
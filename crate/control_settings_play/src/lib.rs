@@ -0,0 +1,9 @@
+#![deny(missing_debug_implementations, missing_docs)] // kcov-ignore
+
+//! Provides logic for rebinding controls in the control settings screen.
+
+pub use crate::system::{
+    ControlBindingsDisplaySystem, ControlRebindSystem, ControlRebindSystemDesc,
+};
+
+mod system;
@@ -0,0 +1,7 @@
+pub use self::{
+    control_bindings_display_system::ControlBindingsDisplaySystem,
+    control_rebind_system::{ControlRebindSystem, ControlRebindSystemDesc},
+};
+
+mod control_bindings_display_system;
+mod control_rebind_system;
@@ -0,0 +1,298 @@
+use std::fmt::Write as _;
+
+use amethyst::{
+    ecs::{Entities, Join, Read, ReadExpect, System, World, WriteStorage},
+    input::{Button, ControllerAxis, ControllerButton},
+    shred::{ResourceId, SystemData},
+    ui::{Anchor, UiText, UiTransform},
+};
+use application_ui::{FontVariant, Theme};
+use control_settings_model::{
+    play::{ControlBindingSelection, ControlRebindStatus},
+    ControlSettingsEntity,
+};
+use derivative::Derivative;
+use derive_new::new;
+use game_input_model::{
+    config::{Axis, ControlBindingTarget, GamepadConfig, PlayerInputConfigs},
+    play::GamepadAssignments,
+};
+use state_registry::StateId;
+
+const FONT_COLOUR: [f32; 4] = [0.8, 0.8, 0.8, 1.];
+const FONT_SIZE_WIDGET: f32 = 16.;
+const LABEL_WIDTH: f32 = 500.;
+const LABEL_HEIGHT: f32 = 280.;
+const LABEL_MARGIN: f32 = 20.;
+
+/// Displays the selected player's control bindings and the rebinding progress.
+#[derive(Debug, Default, new)]
+pub struct ControlBindingsDisplaySystem;
+
+/// `ControlBindingsDisplaySystemData`.
+#[derive(Derivative, SystemData)]
+#[derivative(Debug)]
+pub struct ControlBindingsDisplaySystemData<'s> {
+    /// `StateId` resource.
+    #[derivative(Debug = "ignore")]
+    pub state_id: Read<'s, StateId>,
+    /// `Entities`.
+    #[derivative(Debug = "ignore")]
+    pub entities: Entities<'s>,
+    /// `PlayerInputConfigs` resource.
+    #[derivative(Debug = "ignore")]
+    pub player_input_configs: Read<'s, PlayerInputConfigs>,
+    /// `ControlBindingSelection` resource.
+    #[derivative(Debug = "ignore")]
+    pub control_binding_selection: Read<'s, ControlBindingSelection>,
+    /// `ControlRebindStatus` resource.
+    #[derivative(Debug = "ignore")]
+    pub control_rebind_status: Read<'s, ControlRebindStatus>,
+    /// `GamepadAssignments` resource.
+    #[derivative(Debug = "ignore")]
+    pub gamepad_assignments: Read<'s, GamepadAssignments>,
+    /// `ControlSettingsEntity` components.
+    #[derivative(Debug = "ignore")]
+    pub control_settings_entities: WriteStorage<'s, ControlSettingsEntity>,
+
+    // Resources needed to display text.
+    /// `Theme` resource.
+    #[derivative(Debug = "ignore")]
+    pub theme: ReadExpect<'s, Theme>,
+    /// `UiTransform` components.
+    #[derivative(Debug = "ignore")]
+    pub ui_transforms: WriteStorage<'s, UiTransform>,
+    /// `UiText` components.
+    #[derivative(Debug = "ignore")]
+    pub ui_texts: WriteStorage<'s, UiText>,
+}
+
+impl ControlBindingsDisplaySystem {
+    /// Returns the text for a button.
+    fn button_text(button: Button) -> String {
+        match button {
+            Button::Key(key_code) => format!("{:?}", key_code),
+            _ => format!("{:?}", button),
+        }
+    }
+
+    /// Returns the glyph for a gamepad button.
+    fn gamepad_button_glyph(controller_button: ControllerButton) -> String {
+        let glyph = match controller_button {
+            ControllerButton::A => "(A)",
+            ControllerButton::B => "(B)",
+            ControllerButton::X => "(X)",
+            ControllerButton::Y => "(Y)",
+            ControllerButton::LeftShoulder => "[LB]",
+            ControllerButton::RightShoulder => "[RB]",
+            ControllerButton::LeftStick => "(L3)",
+            ControllerButton::RightStick => "(R3)",
+            ControllerButton::DPadUp => "D-Up",
+            ControllerButton::DPadDown => "D-Down",
+            ControllerButton::DPadLeft => "D-Left",
+            ControllerButton::DPadRight => "D-Right",
+            _ => return format!("[{:?}]", controller_button),
+        };
+        String::from(glyph)
+    }
+
+    /// Returns the glyph for a gamepad control, if the control has one.
+    fn gamepad_glyph(
+        gamepad_config: &GamepadConfig,
+        target: ControlBindingTarget,
+    ) -> Option<String> {
+        let (player_axis_control, positive) = match target {
+            ControlBindingTarget::AxisNegative(player_axis_control) => (player_axis_control, false),
+            ControlBindingTarget::AxisPositive(player_axis_control) => (player_axis_control, true),
+            ControlBindingTarget::Action(player_action_control) => {
+                return gamepad_config
+                    .actions
+                    .get(&player_action_control.action)
+                    .copied()
+                    .map(Self::gamepad_button_glyph);
+            }
+        };
+
+        let gamepad_axis = gamepad_config.axes.get(&player_axis_control.axis)?;
+        let stick = match gamepad_axis.axis {
+            ControllerAxis::LeftX | ControllerAxis::LeftY => "LS",
+            ControllerAxis::RightX | ControllerAxis::RightY => "RS",
+            ControllerAxis::LeftTrigger => "LT",
+            ControllerAxis::RightTrigger => "RT",
+        };
+        // Stick directions follow the game axis, as positive Z and positive stick Y are both
+        // downwards.
+        let direction = match (player_axis_control.axis, positive != gamepad_axis.invert) {
+            (Axis::X, false) => "Left",
+            (Axis::X, true) => "Right",
+            (Axis::Z, false) => "Up",
+            (Axis::Z, true) => "Down",
+        };
+
+        Some(format!("{} {}", stick, direction))
+    }
+
+    /// Returns the text listing the selected player's control bindings.
+    fn control_bindings_text(
+        player_input_configs: &PlayerInputConfigs,
+        gamepad_assignments: &GamepadAssignments,
+        control_binding_selection: ControlBindingSelection,
+        control_rebind_status: ControlRebindStatus,
+    ) -> String {
+        let player = control_binding_selection.player;
+        let player_input_config = player_input_configs.get(player);
+        let player_name = player_input_config
+            .map(|player_input_config| player_input_config.name.as_str())
+            .unwrap_or("No player");
+        let gamepad_text = gamepad_assignments
+            .gamepad(player)
+            .map(|gamepad_id| format!("gamepad {}", gamepad_id))
+            .unwrap_or_else(|| String::from("no gamepad"));
+
+        let mut text = format!(
+            "{} - {} (Left / Right: change player)\n\
+             Attack: rebind, Special: reload, Jump: back\n\
+             \n  Control  Key        Gamepad",
+            player_name, gamepad_text
+        );
+
+        ControlBindingTarget::player_targets(player)
+            .into_iter()
+            .enumerate()
+            .for_each(|(index, target)| {
+                let marker = if index == control_binding_selection.target_index {
+                    '>'
+                } else {
+                    ' '
+                };
+                let button_text = player_input_configs
+                    .button(target)
+                    .map(Self::button_text)
+                    .unwrap_or_else(|| String::from("-"));
+                let gamepad_glyph = player_input_config
+                    .and_then(|player_input_config| {
+                        Self::gamepad_glyph(&player_input_config.controller_config.gamepad, target)
+                    })
+                    .unwrap_or_else(|| String::from("-"));
+                let _ = write!(
+                    text,
+                    "\n{} {:<8} {:<10} {}",
+                    marker,
+                    target.to_string(),
+                    button_text,
+                    gamepad_glyph
+                );
+            });
+
+        text.push_str("\n\n");
+        match control_rebind_status {
+            ControlRebindStatus::Idle => {}
+            ControlRebindStatus::AwaitingKey(target) => {
+                let _ = write!(
+                    text,
+                    "Press a key for player {} {} (Escape: cancel).",
+                    target.player(),
+                    target
+                );
+            }
+            ControlRebindStatus::Rebound(target, button) => {
+                let _ = write!(
+                    text,
+                    "Bound player {} {} to {}.",
+                    target.player(),
+                    target,
+                    Self::button_text(button)
+                );
+            }
+            ControlRebindStatus::Cancelled(target) => {
+                let _ = write!(
+                    text,
+                    "Cancelled rebinding player {} {}.",
+                    target.player(),
+                    target
+                );
+            }
+            ControlRebindStatus::Failed(e) => {
+                let _ = write!(text, "{}", e);
+            }
+        }
+
+        text
+    }
+}
+
+impl<'s> System<'s> for ControlBindingsDisplaySystem {
+    type SystemData = ControlBindingsDisplaySystemData<'s>;
+
+    fn run(
+        &mut self,
+        ControlBindingsDisplaySystemData {
+            state_id,
+            entities,
+            player_input_configs,
+            control_binding_selection,
+            control_rebind_status,
+            gamepad_assignments,
+            mut control_settings_entities,
+            theme,
+            mut ui_transforms,
+            mut ui_texts,
+        }: Self::SystemData,
+    ) {
+        if *state_id != StateId::ControlSettings {
+            return;
+        }
+
+        let text = Self::control_bindings_text(
+            &player_input_configs,
+            &gamepad_assignments,
+            *control_binding_selection,
+            *control_rebind_status,
+        );
+
+        // This system is the only one that creates `ControlSettingsEntity`s with `UiText`s.
+        let ui_text_existing = (&control_settings_entities, &mut ui_texts)
+            .join()
+            .next()
+            .map(|(_, ui_text)| ui_text);
+
+        if let Some(ui_text) = ui_text_existing {
+            if ui_text.text != text {
+                ui_text.text = text;
+            }
+        } else {
+            let font = theme
+                .fonts
+                .get(&FontVariant::Regular)
+                .expect("Failed to get regular font handle.");
+
+            let x = LABEL_MARGIN;
+            let y = LABEL_MARGIN + LABEL_HEIGHT / 2.;
+            let z = 1.;
+
+            let ui_transform = UiTransform::new(
+                String::from("control_bindings_text"),
+                Anchor::BottomLeft,
+                Anchor::MiddleLeft,
+                x,
+                y,
+                z,
+                LABEL_WIDTH,
+                LABEL_HEIGHT,
+            );
+
+            let ui_text = UiText::new(font.clone(), text, FONT_COLOUR, FONT_SIZE_WIDGET);
+
+            entities
+                .build_entity()
+                .with(ControlSettingsEntity, &mut control_settings_entities)
+                .with(ui_transform, &mut ui_transforms)
+                .with(ui_text, &mut ui_texts)
+                .build();
+        }
+    }
+
+    fn setup(&mut self, world: &mut World) {
+        Self::SystemData::setup(world);
+    }
+}
@@ -0,0 +1,275 @@
+use amethyst::{
+    derive::SystemDesc,
+    ecs::{Read, System, World, Write},
+    input::{Button, InputEvent, InputHandler, VirtualKeyCode},
+    shred::{ResourceId, SystemData},
+    shrev::{EventChannel, ReaderId},
+};
+use application::{AppDir, AppFile, Format};
+use control_settings_model::{
+    play::{ControlBindingSelection, ControlRebindStatus},
+    ControlSettingsEvent,
+};
+use derivative::Derivative;
+use derive_new::new;
+use game_input_model::{
+    config::{
+        Axis, ControlAction, ControlBindingTarget, ControlBindings, PlayerActionControl,
+        PlayerAxisControl, PlayerInputConfigs,
+    },
+    play::{ControllerIdOffset, GamepadAssignments},
+    GameInputEvent,
+};
+use log::{debug, error};
+use state_registry::StateId;
+
+/// File that `PlayerInputConfigs` are loaded from and saved to.
+const PLAYER_INPUT_CONFIGS_FILE: &str = "player_input_configs.yaml";
+
+/// Selects and rebinds controls in the control settings screen.
+///
+/// * Up and down select the control.
+/// * Left and right select the player.
+/// * `Attack` waits for a key press to bind to the selected control.
+/// * `Special` reloads the controls from file.
+/// * `Jump` returns to the previous menu.
+///
+/// While waiting for a key press, `Escape` cancels rebinding the control. When a control is
+/// rebound, the input `Bindings` are updated and the `PlayerInputConfigs` are saved to file.
+#[derive(Debug, SystemDesc, new)]
+#[system_desc(name(ControlRebindSystemDesc))]
+pub struct ControlRebindSystem {
+    /// Reader ID for the `GameInputEvent` channel.
+    #[system_desc(event_channel_reader)]
+    game_input_event_rid: ReaderId<GameInputEvent>,
+    /// Reader ID for the `InputEvent<ControlBindings>` channel.
+    #[system_desc(event_channel_reader)]
+    input_event_rid: ReaderId<InputEvent<ControlBindings>>,
+    /// Reader ID for the `ControlSettingsEvent` channel.
+    #[system_desc(event_channel_reader)]
+    control_settings_event_rid: ReaderId<ControlSettingsEvent>,
+}
+
+/// `ControlRebindSystemData`.
+#[derive(Derivative, SystemData)]
+#[derivative(Debug)]
+pub struct ControlRebindSystemData<'s> {
+    /// `GameInputEvent` channel.
+    #[derivative(Debug = "ignore")]
+    pub game_input_ec: Read<'s, EventChannel<GameInputEvent>>,
+    /// `InputEvent<ControlBindings>` channel.
+    #[derivative(Debug = "ignore")]
+    pub input_ec: Read<'s, EventChannel<InputEvent<ControlBindings>>>,
+    /// `ControlSettingsEvent` channel.
+    #[derivative(Debug = "ignore")]
+    pub control_settings_ec: Write<'s, EventChannel<ControlSettingsEvent>>,
+    /// `StateId` resource.
+    #[derivative(Debug = "ignore")]
+    pub state_id: Read<'s, StateId>,
+    /// `ControllerIdOffset` resource.
+    #[derivative(Debug = "ignore")]
+    pub controller_id_offset: Read<'s, ControllerIdOffset>,
+    /// `GamepadAssignments` resource.
+    #[derivative(Debug = "ignore")]
+    pub gamepad_assignments: Read<'s, GamepadAssignments>,
+    /// `PlayerInputConfigs` resource.
+    #[derivative(Debug = "ignore")]
+    pub player_input_configs: Write<'s, PlayerInputConfigs>,
+    /// `ControlBindingSelection` resource.
+    #[derivative(Debug = "ignore")]
+    pub control_binding_selection: Write<'s, ControlBindingSelection>,
+    /// `ControlRebindStatus` resource.
+    #[derivative(Debug = "ignore")]
+    pub control_rebind_status: Write<'s, ControlRebindStatus>,
+    /// `InputHandler<ControlBindings>` resource.
+    #[derivative(Debug = "ignore")]
+    pub input_handler: Write<'s, InputHandler<ControlBindings>>,
+}
+
+impl ControlRebindSystem {
+    fn update_input_bindings(
+        player_input_configs: &PlayerInputConfigs,
+        input_handler: &mut InputHandler<ControlBindings>,
+        controller_id_offset: ControllerIdOffset,
+        gamepad_assignments: &GamepadAssignments,
+    ) {
+        match player_input_configs.generate_bindings(controller_id_offset, gamepad_assignments) {
+            Ok(bindings) => input_handler.bindings = bindings,
+            Err(e) => error!("Failed to update input `Bindings`. Error: {}", e),
+        }
+    }
+
+    fn player_input_configs_save(player_input_configs: &PlayerInputConfigs) {
+        let save_result = AppFile::save_in(
+            AppDir::RESOURCES,
+            PLAYER_INPUT_CONFIGS_FILE,
+            player_input_configs,
+            Format::Yaml,
+        );
+        match save_result {
+            Ok(file_path) => debug!("Saved controls to `{}`.", file_path.display()),
+            Err(e) => error!("Failed to save controls. Error: {}", e),
+        }
+    }
+
+    fn player_input_configs_reload(
+        player_input_configs: &mut PlayerInputConfigs,
+        input_handler: &mut InputHandler<ControlBindings>,
+        controller_id_offset: ControllerIdOffset,
+        gamepad_assignments: &GamepadAssignments,
+    ) {
+        let load_result = AppFile::load_in::<PlayerInputConfigs, _>(
+            AppDir::RESOURCES,
+            PLAYER_INPUT_CONFIGS_FILE,
+            Format::Yaml,
+        );
+        match load_result {
+            Ok(player_input_configs_loaded) => {
+                *player_input_configs = player_input_configs_loaded;
+                Self::update_input_bindings(
+                    player_input_configs,
+                    input_handler,
+                    controller_id_offset,
+                    gamepad_assignments,
+                );
+            }
+            Err(e) => error!("Failed to reload controls. Error: {}", e),
+        }
+    }
+
+    fn rebind(
+        player_input_configs: &mut PlayerInputConfigs,
+        input_handler: &mut InputHandler<ControlBindings>,
+        controller_id_offset: ControllerIdOffset,
+        gamepad_assignments: &GamepadAssignments,
+        target: ControlBindingTarget,
+        key_code: VirtualKeyCode,
+    ) -> ControlRebindStatus {
+        if key_code == VirtualKeyCode::Escape {
+            return ControlRebindStatus::Cancelled(target);
+        }
+
+        let button = Button::Key(key_code);
+        match player_input_configs.rebind(target, button) {
+            Ok(()) => {
+                Self::update_input_bindings(
+                    player_input_configs,
+                    input_handler,
+                    controller_id_offset,
+                    gamepad_assignments,
+                );
+                Self::player_input_configs_save(player_input_configs);
+
+                ControlRebindStatus::Rebound(target, button)
+            }
+            Err(e) => ControlRebindStatus::Failed(e),
+        }
+    }
+}
+
+impl<'s> System<'s> for ControlRebindSystem {
+    type SystemData = ControlRebindSystemData<'s>;
+
+    fn run(
+        &mut self,
+        ControlRebindSystemData {
+            game_input_ec,
+            input_ec,
+            mut control_settings_ec,
+            state_id,
+            controller_id_offset,
+            gamepad_assignments,
+            mut player_input_configs,
+            mut control_binding_selection,
+            mut control_rebind_status,
+            mut input_handler,
+        }: Self::SystemData,
+    ) {
+        // Always read events so they don't accumulate while in other states.
+        let game_input_events = game_input_ec.read(&mut self.game_input_event_rid);
+        let input_events = input_ec.read(&mut self.input_event_rid);
+        let reload_requested = control_settings_ec
+            .read(&mut self.control_settings_event_rid)
+            .any(|ev| *ev == ControlSettingsEvent::ReloadRequest);
+
+        if *state_id != StateId::ControlSettings {
+            return;
+        }
+
+        if reload_requested {
+            Self::player_input_configs_reload(
+                &mut player_input_configs,
+                &mut input_handler,
+                *controller_id_offset,
+                &gamepad_assignments,
+            );
+            *control_rebind_status = ControlRebindStatus::Idle;
+        }
+
+        // `GameInputEvent`s are ignored while waiting for a key press, and key presses are ignored
+        // on the frame the control is selected, so the selecting key is not bound to the control.
+        if let ControlRebindStatus::AwaitingKey(target) = *control_rebind_status {
+            let key_code = input_events
+                .filter_map(|ev| {
+                    if let InputEvent::KeyPressed { key_code, .. } = ev {
+                        Some(*key_code)
+                    } else {
+                        None
+                    }
+                })
+                .next();
+            if let Some(key_code) = key_code {
+                *control_rebind_status = Self::rebind(
+                    &mut player_input_configs,
+                    &mut input_handler,
+                    *controller_id_offset,
+                    &gamepad_assignments,
+                    target,
+                    key_code,
+                );
+            }
+            return;
+        }
+
+        let player_count = player_input_configs.len();
+        let target_count =
+            ControlBindingTarget::player_targets(control_binding_selection.player).len();
+        if player_count == 0 {
+            return;
+        }
+
+        let mut control_settings_events = Vec::new();
+        game_input_events.for_each(|ev| match *ev {
+            GameInputEvent::AxisMoved {
+                axis: PlayerAxisControl { axis, .. },
+                value,
+            } => {
+                // Positive Z is downwards.
+                let (index, count) = match axis {
+                    Axis::X => (&mut control_binding_selection.player, player_count),
+                    Axis::Z => (&mut control_binding_selection.target_index, target_count),
+                };
+                if value > 0. {
+                    *index = (*index + 1) % count;
+                } else if value < 0. {
+                    *index = (*index + count - 1) % count;
+                }
+            }
+            GameInputEvent::ActionPressed(PlayerActionControl { action, .. }) => match action {
+                ControlAction::Attack => {
+                    if let Some(target) = control_binding_selection.target() {
+                        *control_rebind_status = ControlRebindStatus::AwaitingKey(target);
+                    }
+                }
+                ControlAction::Special => {
+                    control_settings_events.push(ControlSettingsEvent::ReloadRequest)
+                }
+                ControlAction::Jump => control_settings_events.push(ControlSettingsEvent::Return),
+                ControlAction::Defend => {}
+            },
+            _ => {}
+        });
+
+        control_settings_ec.iter_write(control_settings_events);
+    }
+}
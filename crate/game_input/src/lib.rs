@@ -3,8 +3,10 @@
 //! Provides types to link control input into Amethyst.
 
 pub use crate::system::{
+    ControlInputHistoryUpdateSystem, ControlInputHistoryUpdateSystemDesc,
     ControllerInputUpdateSystem, GameInputToControlInputSystem, GameInputToControlInputSystemDesc,
-    InputToGameInputSystem, InputToGameInputSystemDesc, SharedControllerInputUpdateSystem,
+    GamepadAssignmentSystem, GamepadAssignmentSystemDesc, InputToGameInputSystem,
+    InputToGameInputSystemDesc, SharedControllerInputUpdateSystem,
 };
 
 mod system;
@@ -1,13 +1,19 @@
 pub use self::{
+    control_input_history_update_system::{
+        ControlInputHistoryUpdateSystem, ControlInputHistoryUpdateSystemDesc,
+    },
     controller_input_update_system::ControllerInputUpdateSystem,
     game_input_to_control_input_system::{
         GameInputToControlInputSystem, GameInputToControlInputSystemDesc,
     },
+    gamepad_assignment_system::{GamepadAssignmentSystem, GamepadAssignmentSystemDesc},
     input_to_game_input_system::{InputToGameInputSystem, InputToGameInputSystemDesc},
     shared_controller_input_update_system::SharedControllerInputUpdateSystem,
 };
 
+mod control_input_history_update_system;
 mod controller_input_update_system;
 mod game_input_to_control_input_system;
+mod gamepad_assignment_system;
 mod input_to_game_input_system;
 mod shared_controller_input_update_system;
@@ -0,0 +1,90 @@
+use amethyst::{
+    derive::SystemDesc,
+    ecs::{Join, Read, ReadStorage, System, World, WriteStorage},
+    shred::{ResourceId, SystemData},
+    shrev::{EventChannel, ReaderId},
+};
+use derivative::Derivative;
+use derive_new::new;
+use game_input_model::{
+    config::CommandInput,
+    play::{AxisMoveEventData, ControlActionEventData, ControlInputEvent, ControlInputHistory},
+};
+use mirrored_model::play::Mirrored;
+
+/// Records entered inputs in each entity's `ControlInputHistory`.
+///
+/// X axis input is recorded relative to the direction the entity is facing when the input is
+/// entered. Axis input is only recorded when the axis direction changes, so repeated events from
+/// analog sticks do not fill the history.
+///
+/// This must only run on session ticks, as each run ages the recorded inputs.
+#[derive(Debug, SystemDesc, new)]
+#[system_desc(name(ControlInputHistoryUpdateSystemDesc))]
+pub struct ControlInputHistoryUpdateSystem {
+    /// Reader ID for the `ControlInputEvent` channel.
+    #[system_desc(event_channel_reader)]
+    control_input_event_rid: ReaderId<ControlInputEvent>,
+}
+
+/// `ControlInputHistoryUpdateSystemData`.
+#[derive(Derivative, SystemData)]
+#[derivative(Debug)]
+pub struct ControlInputHistoryUpdateSystemData<'s> {
+    /// `ControlInputEvent` channel.
+    #[derivative(Debug = "ignore")]
+    pub control_input_ec: Read<'s, EventChannel<ControlInputEvent>>,
+    /// `Mirrored` components.
+    #[derivative(Debug = "ignore")]
+    pub mirroreds: ReadStorage<'s, Mirrored>,
+    /// `ControlInputHistory` components.
+    #[derivative(Debug = "ignore")]
+    pub control_input_histories: WriteStorage<'s, ControlInputHistory>,
+}
+
+impl<'s> System<'s> for ControlInputHistoryUpdateSystem {
+    type SystemData = ControlInputHistoryUpdateSystemData<'s>;
+
+    fn run(
+        &mut self,
+        ControlInputHistoryUpdateSystemData {
+            control_input_ec,
+            mirroreds,
+            mut control_input_histories,
+        }: Self::SystemData,
+    ) {
+        (&mut control_input_histories)
+            .join()
+            .for_each(ControlInputHistory::advance);
+
+        control_input_ec
+            .read(&mut self.control_input_event_rid)
+            .for_each(|ev| match *ev {
+                ControlInputEvent::AxisMoved(AxisMoveEventData {
+                    entity,
+                    axis,
+                    value,
+                    ..
+                }) => {
+                    let mirrored = mirroreds.get(entity).copied().unwrap_or_default();
+                    if let Ok(entry) = control_input_histories.entry(entity) {
+                        entry
+                            .or_insert_with(ControlInputHistory::new)
+                            .push_axis(axis, value, *mirrored);
+                    }
+                }
+                ControlInputEvent::ControlActionPress(ControlActionEventData {
+                    entity,
+                    control_action,
+                    ..
+                }) => {
+                    if let Ok(entry) = control_input_histories.entry(entity) {
+                        entry
+                            .or_insert_with(ControlInputHistory::new)
+                            .push(CommandInput::from(control_action));
+                    }
+                }
+                ControlInputEvent::ControlActionRelease(..) => {}
+            });
+    }
+}
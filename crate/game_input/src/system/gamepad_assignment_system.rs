@@ -0,0 +1,102 @@
+use amethyst::{
+    derive::SystemDesc,
+    ecs::{Read, System, World, Write},
+    input::{InputEvent, InputHandler},
+    shred::{ResourceId, SystemData},
+    shrev::{EventChannel, ReaderId},
+};
+use derivative::Derivative;
+use derive_new::new;
+use game_input_model::{
+    config::{ControlBindings, PlayerInputConfigs},
+    play::{ControllerIdOffset, GamepadAssignments},
+};
+use log::{error, info, warn};
+
+/// Assigns connected gamepads to players without a gamepad, and updates input `Bindings`.
+///
+/// Gamepads are assigned to players in order of their `PlayerInputConfig`. When a gamepad is
+/// disconnected, its player becomes free for the next gamepad that is connected.
+#[derive(Debug, SystemDesc, new)]
+#[system_desc(name(GamepadAssignmentSystemDesc))]
+pub struct GamepadAssignmentSystem {
+    /// Reader ID for the `InputEvent` channel.
+    #[system_desc(event_channel_reader)]
+    input_event_rid: ReaderId<InputEvent<ControlBindings>>,
+}
+
+/// `GamepadAssignmentSystemData`.
+#[derive(Derivative, SystemData)]
+#[derivative(Debug)]
+pub struct GamepadAssignmentSystemData<'s> {
+    /// `InputEvent<ControlBindings>` channel.
+    #[derivative(Debug = "ignore")]
+    pub input_ec: Read<'s, EventChannel<InputEvent<ControlBindings>>>,
+    /// `PlayerInputConfigs` resource.
+    #[derivative(Debug = "ignore")]
+    pub player_input_configs: Read<'s, PlayerInputConfigs>,
+    /// `ControllerIdOffset` resource.
+    #[derivative(Debug = "ignore")]
+    pub controller_id_offset: Read<'s, ControllerIdOffset>,
+    /// `GamepadAssignments` resource.
+    #[derivative(Debug = "ignore")]
+    pub gamepad_assignments: Write<'s, GamepadAssignments>,
+    /// `InputHandler<ControlBindings>` resource.
+    #[derivative(Debug = "ignore")]
+    pub input_handler: Write<'s, InputHandler<ControlBindings>>,
+}
+
+impl<'s> System<'s> for GamepadAssignmentSystem {
+    type SystemData = GamepadAssignmentSystemData<'s>;
+
+    fn run(
+        &mut self,
+        GamepadAssignmentSystemData {
+            input_ec,
+            player_input_configs,
+            controller_id_offset,
+            mut gamepad_assignments,
+            mut input_handler,
+        }: Self::SystemData,
+    ) {
+        let player_count = player_input_configs.len();
+        let gamepad_assignments_changed =
+            input_ec
+                .read(&mut self.input_event_rid)
+                .fold(false, |changed, ev| match *ev {
+                    InputEvent::ControllerConnected { which } => {
+                        match gamepad_assignments.assign(which, player_count) {
+                            Some(player) => {
+                                info!("Gamepad {} assigned to player {}.", which, player);
+                                true
+                            }
+                            None => {
+                                warn!(
+                                    "Gamepad {} connected, but every player has a gamepad.",
+                                    which
+                                );
+                                changed
+                            }
+                        }
+                    }
+                    InputEvent::ControllerDisconnected { which } => {
+                        if let Some(player) = gamepad_assignments.unassign(which) {
+                            info!("Gamepad {} unassigned from player {}.", which, player);
+                            true
+                        } else {
+                            changed
+                        }
+                    }
+                    _ => changed,
+                });
+
+        if gamepad_assignments_changed {
+            match player_input_configs
+                .generate_bindings(*controller_id_offset, &gamepad_assignments)
+            {
+                Ok(bindings) => input_handler.bindings = bindings,
+                Err(e) => error!("Failed to update input `Bindings`. Error: {}", e),
+            }
+        }
+    }
+}
@@ -9,7 +9,7 @@ use amethyst::{
 };
 use derivative::Derivative;
 use derive_new::new;
-use game_input_model::{config::ControlBindings, GameInputEvent};
+use game_input_model::{config::ControlBindings, play::AxisInputQuantizer, GameInputEvent};
 use network_session_model::play::SessionStatus;
 
 /// Sends `GameInputEvent`s based on a subset of `InputEvent`s.
@@ -19,6 +19,10 @@ pub struct InputToGameInputSystem {
     /// Reader ID for the `InputEvent` channel.
     #[system_desc(event_channel_reader)]
     input_event_rid: ReaderId<InputEvent<ControlBindings>>,
+    /// Quantizes analog axis values into the same values as keyboard axes.
+    #[system_desc(skip)]
+    #[new(default)]
+    axis_input_quantizer: AxisInputQuantizer,
 }
 
 #[derive(Derivative, SystemData)]
@@ -46,6 +50,7 @@ impl<'s> System<'s> for InputToGameInputSystem {
             mut game_input_ec,
         }: Self::SystemData,
     ) {
+        let axis_input_quantizer = &mut self.axis_input_quantizer;
         let input_events = input_ec.read(&mut self.input_event_rid);
 
         let session_status = &*session_status;
@@ -55,6 +60,7 @@ impl<'s> System<'s> for InputToGameInputSystem {
         {
             input_events
                 .filter_map(|input_event| GameInputEvent::try_from(input_event).ok())
+                .filter_map(|game_input_event| axis_input_quantizer.quantize(game_input_event))
                 .for_each(|game_input_event| game_input_ec.single_write(game_input_event));
         }
     }
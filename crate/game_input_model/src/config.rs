@@ -1,21 +1,29 @@
 //! Contains the types that represent the configuration on disk.
 
 pub use self::{
-    axis::Axis, control_action::ControlAction, control_args::ControlArgs,
+    axis::Axis, command_input::CommandInput, control_action::ControlAction,
+    control_args::ControlArgs, control_binding_target::ControlBindingTarget,
     control_bindings::ControlBindings, control_input_event_args::ControlInputEventArgs,
-    controller_config::ControllerConfig, controller_id::ControllerId,
+    controller_config::ControllerConfig, controller_id::ControllerId, gamepad_axis::GamepadAxis,
+    gamepad_config::GamepadConfig, gamepad_id::GamepadId, input_command::InputCommand,
     input_direction::InputDirection, input_direction_z::InputDirectionZ,
     player_action_control::PlayerActionControl, player_axis_control::PlayerAxisControl,
     player_input_config::PlayerInputConfig, player_input_configs::PlayerInputConfigs,
 };
 
 mod axis;
+mod command_input;
 mod control_action;
 mod control_args;
+mod control_binding_target;
 mod control_bindings;
 mod control_input_event_args;
 mod controller_config;
 mod controller_id;
+mod gamepad_axis;
+mod gamepad_config;
+mod gamepad_id;
+mod input_command;
 mod input_direction;
 mod input_direction_z;
 mod player_action_control;
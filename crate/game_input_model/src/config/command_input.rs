@@ -0,0 +1,83 @@
+use serde::{Deserialize, Serialize};
+
+use crate::config::{Axis, ControlAction, InputDirection};
+
+/// Input that is part of an `InputCommand`.
+///
+/// X axis input is relative to the direction the object is facing, so that the same command
+/// works whether or not the object is mirrored.
+#[derive(Clone, Copy, Debug, Deserialize, Hash, PartialEq, Eq, Serialize)]
+#[serde(deny_unknown_fields, rename_all = "snake_case")]
+pub enum CommandInput {
+    /// X axis input in the direction the object is facing.
+    Forward,
+    /// X axis input opposite to the direction the object is facing.
+    Back,
+    /// Z axis input upwards.
+    Up,
+    /// Z axis input downwards.
+    Down,
+    /// `Defend` button press.
+    Defend,
+    /// `Jump` button press.
+    Jump,
+    /// `Attack` button press.
+    Attack,
+    /// `Special` button press.
+    Special,
+}
+
+impl CommandInput {
+    /// Returns the `CommandInput` for an axis input, or `None` if the axis value is zero.
+    ///
+    /// # Parameters
+    ///
+    /// * `axis`: Axis that was moved.
+    /// * `axis_value`: Input value of the axis.
+    /// * `mirrored`: Whether the object is facing the axis negative direction.
+    pub fn from_axis(axis: Axis, axis_value: f32, mirrored: bool) -> Option<Self> {
+        match axis {
+            Axis::X => {
+                if InputDirection::input_matches_direction(axis_value, mirrored) {
+                    Some(CommandInput::Forward)
+                } else if InputDirection::input_opposes_direction(axis_value, mirrored) {
+                    Some(CommandInput::Back)
+                } else {
+                    None
+                }
+            }
+            Axis::Z => {
+                if axis_value < 0. {
+                    Some(CommandInput::Up)
+                } else if axis_value > 0. {
+                    Some(CommandInput::Down)
+                } else {
+                    None
+                }
+            }
+        }
+    }
+
+    /// Returns the axis of this input, if it is an axis input.
+    pub fn axis(self) -> Option<Axis> {
+        match self {
+            CommandInput::Forward | CommandInput::Back => Some(Axis::X),
+            CommandInput::Up | CommandInput::Down => Some(Axis::Z),
+            CommandInput::Defend
+            | CommandInput::Jump
+            | CommandInput::Attack
+            | CommandInput::Special => None,
+        }
+    }
+}
+
+impl From<ControlAction> for CommandInput {
+    fn from(control_action: ControlAction) -> Self {
+        match control_action {
+            ControlAction::Defend => CommandInput::Defend,
+            ControlAction::Jump => CommandInput::Jump,
+            ControlAction::Attack => CommandInput::Attack,
+            ControlAction::Special => CommandInput::Special,
+        }
+    }
+}
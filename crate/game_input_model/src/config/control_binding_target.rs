@@ -0,0 +1,78 @@
+use std::fmt;
+
+use serde::{Deserialize, Serialize};
+use strum::IntoEnumIterator;
+
+use crate::config::{Axis, ControlAction, ControllerId, PlayerActionControl, PlayerAxisControl};
+
+/// Control that a single button may be bound to.
+///
+/// The `player` in each control is the index of the player's `PlayerInputConfig`, which is not
+/// offset by the `ControllerIdOffset`.
+#[derive(Clone, Copy, Debug, Deserialize, Hash, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ControlBindingTarget {
+    /// Negative direction of an axis -- left for `X`, up for `Z`.
+    AxisNegative(PlayerAxisControl),
+    /// Positive direction of an axis -- right for `X`, down for `Z`.
+    AxisPositive(PlayerAxisControl),
+    /// Action button.
+    Action(PlayerActionControl),
+}
+
+impl ControlBindingTarget {
+    /// Returns the controls that may be bound for a player, in display order.
+    ///
+    /// # Parameters
+    ///
+    /// * `player`: Index of the player's `PlayerInputConfig`.
+    pub fn player_targets(player: ControllerId) -> Vec<ControlBindingTarget> {
+        let player_axis_control_x = PlayerAxisControl::new(player, Axis::X);
+        let player_axis_control_z = PlayerAxisControl::new(player, Axis::Z);
+
+        vec![
+            ControlBindingTarget::AxisNegative(player_axis_control_z),
+            ControlBindingTarget::AxisPositive(player_axis_control_z),
+            ControlBindingTarget::AxisNegative(player_axis_control_x),
+            ControlBindingTarget::AxisPositive(player_axis_control_x),
+        ]
+        .into_iter()
+        .chain(
+            ControlAction::iter().map(|action| {
+                ControlBindingTarget::Action(PlayerActionControl::new(player, action))
+            }),
+        )
+        .collect::<Vec<ControlBindingTarget>>()
+    }
+
+    /// Returns the index of the player's `PlayerInputConfig` for this control.
+    pub fn player(self) -> ControllerId {
+        match self {
+            ControlBindingTarget::AxisNegative(player_axis_control)
+            | ControlBindingTarget::AxisPositive(player_axis_control) => player_axis_control.player,
+            ControlBindingTarget::Action(player_action_control) => player_action_control.player,
+        }
+    }
+}
+
+impl fmt::Display for ControlBindingTarget {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ControlBindingTarget::AxisNegative(PlayerAxisControl { axis: Axis::X, .. }) => {
+                write!(f, "left")
+            }
+            ControlBindingTarget::AxisPositive(PlayerAxisControl { axis: Axis::X, .. }) => {
+                write!(f, "right")
+            }
+            ControlBindingTarget::AxisNegative(PlayerAxisControl { axis: Axis::Z, .. }) => {
+                write!(f, "up")
+            }
+            ControlBindingTarget::AxisPositive(PlayerAxisControl { axis: Axis::Z, .. }) => {
+                write!(f, "down")
+            }
+            ControlBindingTarget::Action(PlayerActionControl { action, .. }) => {
+                write!(f, "{}", action)
+            }
+        }
+    }
+}
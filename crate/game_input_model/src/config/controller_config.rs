@@ -5,7 +5,7 @@ use derivative::Derivative;
 use derive_new::new;
 use serde::{Deserialize, Serialize};
 
-use crate::config::{Axis, ControlAction};
+use crate::config::{Axis, ControlAction, GamepadConfig};
 
 /// Structure for each controller's configuration.
 #[derive(Clone, Derivative, Default, PartialEq, Serialize, Deserialize, new)]
@@ -15,4 +15,8 @@ pub struct ControllerConfig {
     pub axes: HashMap<Axis, InputAxis>, // kcov-ignore
     /// Action control configuration.
     pub actions: HashMap<ControlAction, Button>, // kcov-ignore
+    /// Gamepad control configuration, used when a gamepad is assigned to the player.
+    #[new(default)]
+    #[serde(default)]
+    pub gamepad: GamepadConfig,
 }
@@ -0,0 +1,42 @@
+use amethyst::input::{Axis as InputAxis, ControllerAxis};
+use derive_new::new;
+use serde::{Deserialize, Serialize};
+
+use crate::config::GamepadId;
+
+/// Gamepad analog axis that controls an `Axis`.
+#[derive(Clone, Copy, Debug, Deserialize, PartialEq, Serialize, new)]
+#[serde(deny_unknown_fields)]
+pub struct GamepadAxis {
+    /// Analog axis on the gamepad.
+    pub axis: ControllerAxis,
+    /// Whether the axis value is inverted.
+    #[serde(default)]
+    pub invert: bool,
+    /// Absolute axis value the stick must pass before the axis is considered moved.
+    #[serde(default = "GamepadAxis::threshold_default")]
+    pub threshold: f32,
+}
+
+impl GamepadAxis {
+    /// Default threshold for analog axes.
+    pub const THRESHOLD_DEFAULT: f32 = 0.5;
+
+    fn threshold_default() -> f32 {
+        Self::THRESHOLD_DEFAULT
+    }
+
+    /// Returns the amethyst `InputAxis` for this axis on the given gamepad.
+    ///
+    /// # Parameters
+    ///
+    /// * `gamepad_id`: ID of the connected gamepad.
+    pub fn input_axis(self, gamepad_id: GamepadId) -> InputAxis {
+        InputAxis::Controller {
+            controller_id: gamepad_id,
+            axis: self.axis,
+            invert: self.invert,
+            dead_zone: f64::from(self.threshold),
+        }
+    }
+}
@@ -0,0 +1,45 @@
+use std::collections::HashMap;
+
+use amethyst::input::{ControllerAxis, ControllerButton};
+use derive_new::new;
+use serde::{Deserialize, Serialize};
+
+use crate::config::{Axis, ControlAction, GamepadAxis};
+
+/// Gamepad controls for a player.
+///
+/// These are only bound when a gamepad is assigned to the player.
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize, new)]
+#[serde(deny_unknown_fields)]
+pub struct GamepadConfig {
+    /// Analog axes for each `Axis`.
+    #[serde(default)]
+    pub axes: HashMap<Axis, GamepadAxis>,
+    /// Gamepad buttons for each `ControlAction`.
+    #[serde(default)]
+    pub actions: HashMap<ControlAction, ControllerButton>,
+}
+
+impl Default for GamepadConfig {
+    /// Left stick to move, and face buttons for actions.
+    fn default() -> Self {
+        let mut axes = HashMap::new();
+        axes.insert(
+            Axis::X,
+            GamepadAxis::new(ControllerAxis::LeftX, false, GamepadAxis::THRESHOLD_DEFAULT),
+        );
+        // Positive Z is downwards, which matches the stick's Y axis.
+        axes.insert(
+            Axis::Z,
+            GamepadAxis::new(ControllerAxis::LeftY, false, GamepadAxis::THRESHOLD_DEFAULT),
+        );
+
+        let mut actions = HashMap::new();
+        actions.insert(ControlAction::Defend, ControllerButton::B);
+        actions.insert(ControlAction::Jump, ControllerButton::A);
+        actions.insert(ControlAction::Attack, ControllerButton::X);
+        actions.insert(ControlAction::Special, ControllerButton::Y);
+
+        GamepadConfig::new(axes, actions)
+    }
+}
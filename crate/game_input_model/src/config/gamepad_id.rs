@@ -0,0 +1,4 @@
+/// Type for the ID of a connected gamepad.
+///
+/// This is the ID that Amethyst assigns to a game controller when it is connected.
+pub type GamepadId = u32;
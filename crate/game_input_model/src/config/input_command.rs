@@ -0,0 +1,24 @@
+use derive_new::new;
+use serde::{Deserialize, Serialize};
+
+use crate::config::CommandInput;
+
+/// Sequence of inputs that must be entered within a number of ticks, such as "defend, forward,
+/// attack".
+#[derive(Clone, Debug, Deserialize, Hash, PartialEq, Eq, Serialize, new)]
+pub struct InputCommand {
+    /// Inputs to enter, in order.
+    pub inputs: Vec<CommandInput>,
+    /// Maximum number of ticks between the first and last input.
+    #[serde(default = "InputCommand::window_default")]
+    pub window: u32,
+}
+
+impl InputCommand {
+    /// Default number of ticks that a command must be entered within.
+    pub const WINDOW_DEFAULT: u32 = 30;
+
+    fn window_default() -> u32 {
+        Self::WINDOW_DEFAULT
+    }
+}
@@ -1,4 +1,4 @@
-use std::{convert::TryFrom, iter};
+use std::{collections::HashSet, convert::TryFrom, iter};
 
 use amethyst::{
     error::{format_err, ResultExt},
@@ -11,8 +11,11 @@ use log::error;
 use serde::{Deserialize, Serialize};
 
 use crate::{
-    config::{ControlBindings, PlayerActionControl, PlayerAxisControl, PlayerInputConfig},
-    play::ControllerIdOffset,
+    config::{
+        Axis, ControlBindingTarget, ControlBindings, PlayerActionControl, PlayerAxisControl,
+        PlayerInputConfig,
+    },
+    play::{ControllerIdOffset, GameInputModelError, GamepadAssignments},
 };
 
 /// Structure for holding the input configuration.
@@ -25,12 +28,17 @@ impl PlayerInputConfigs {
     /// The `ControllerIdOffset` is used when local controllers should start with a higher index as
     /// remote controllers may use the lower indices.
     ///
+    /// Players with an assigned gamepad have their gamepad controls bound in addition to their
+    /// keyboard controls.
+    ///
     /// # Parameters
     ///
     /// * `controller_id_offset`: The offset for controller IDs.
+    /// * `gamepad_assignments`: Gamepads assigned to players.
     pub fn generate_bindings(
         &self,
         controller_id_offset: ControllerIdOffset,
+        gamepad_assignments: &GamepadAssignments,
     ) -> Result<Bindings<ControlBindings>, Error> {
         let mut bindings = Bindings::new();
 
@@ -41,15 +49,35 @@ impl PlayerInputConfigs {
             // The enumeration index is used as the controller ID
             .flat_map(|(index, player_input_config)| {
                 let controller_id = index + controller_id_offset.0;
-                player_input_config
-                    .controller_config
+                let controller_config = &player_input_config.controller_config;
+                let gamepad_id = gamepad_assignments.gamepad(index);
+                controller_config
                     .axes
-                    .iter()
-                    .map(|(&axis, input_axis)| {
-                        (
-                            PlayerAxisControl::new(controller_id, axis),
-                            input_axis.clone(),
-                        )
+                    .keys()
+                    .chain(controller_config.gamepad.axes.keys())
+                    .copied()
+                    .collect::<HashSet<Axis>>()
+                    .into_iter()
+                    .filter_map(|axis| {
+                        let input_axis = controller_config.axes.get(&axis).cloned();
+                        let input_axis_gamepad = gamepad_id.and_then(|gamepad_id| {
+                            controller_config
+                                .gamepad
+                                .axes
+                                .get(&axis)
+                                .map(|gamepad_axis| gamepad_axis.input_axis(gamepad_id))
+                        });
+                        let input_axis = match (input_axis, input_axis_gamepad) {
+                            (Some(input_axis), Some(input_axis_gamepad)) => {
+                                Some(InputAxis::Multiple(vec![input_axis, input_axis_gamepad]))
+                            }
+                            (Some(input_axis), None) | (None, Some(input_axis)) => Some(input_axis),
+                            (None, None) => None,
+                        };
+
+                        input_axis.map(|input_axis| {
+                            (PlayerAxisControl::new(controller_id, axis), input_axis)
+                        })
                     })
                     .collect::<Vec<(PlayerAxisControl, InputAxis)>>()
             })
@@ -75,12 +103,30 @@ impl PlayerInputConfigs {
             // The enumeration index is used as the controller ID
             .flat_map(|(index, player_input_config)| {
                 let controller_id = index + controller_id_offset.0;
-                player_input_config
-                    .controller_config
+                let controller_config = &player_input_config.controller_config;
+                let gamepad_actions = gamepad_assignments
+                    .gamepad(index)
+                    .map(|gamepad_id| {
+                        controller_config
+                            .gamepad
+                            .actions
+                            .iter()
+                            .map(|(&action, controller_button)| {
+                                (action, Button::Controller(gamepad_id, *controller_button))
+                            })
+                            .collect::<Vec<_>>()
+                    })
+                    .unwrap_or_default();
+                controller_config
                     .actions
                     .iter()
-                    .map(|(&axis, input_button)| {
-                        (PlayerActionControl::new(controller_id, axis), *input_button)
+                    .map(|(&action, input_button)| (action, *input_button))
+                    .chain(gamepad_actions)
+                    .map(|(action, input_button)| {
+                        (
+                            PlayerActionControl::new(controller_id, action),
+                            input_button,
+                        )
                     })
                     .collect::<Vec<(PlayerActionControl, Button)>>()
             })
@@ -108,6 +154,97 @@ impl PlayerInputConfigs {
         }
         axis_result.and(action_result).map(|_| bindings)
     }
+    /// Returns the button bound to the given control, if any.
+    ///
+    /// `None` is returned if the player has no input configuration, the control is not bound, or
+    /// the axis is not an emulated axis.
+    ///
+    /// # Parameters
+    ///
+    /// * `target`: Control to look up the button for.
+    pub fn button(&self, target: ControlBindingTarget) -> Option<Button> {
+        let controller_config = &self.get(target.player())?.controller_config;
+        match target {
+            ControlBindingTarget::AxisNegative(player_axis_control) => {
+                match controller_config.axes.get(&player_axis_control.axis)? {
+                    InputAxis::Emulated { neg, .. } => Some(*neg),
+                    _ => None,
+                }
+            }
+            ControlBindingTarget::AxisPositive(player_axis_control) => {
+                match controller_config.axes.get(&player_axis_control.axis)? {
+                    InputAxis::Emulated { pos, .. } => Some(*pos),
+                    _ => None,
+                }
+            }
+            ControlBindingTarget::Action(player_action_control) => controller_config
+                .actions
+                .get(&player_action_control.action)
+                .copied(),
+        }
+    }
+
+    /// Returns the control that the given button is bound to, if any.
+    ///
+    /// # Parameters
+    ///
+    /// * `button`: Button to look up the control for.
+    pub fn binding_target(&self, button: Button) -> Option<ControlBindingTarget> {
+        (0..self.len())
+            .flat_map(ControlBindingTarget::player_targets)
+            .find(|target| self.button(*target) == Some(button))
+    }
+
+    /// Binds a button to the given control, replacing the previously bound button.
+    ///
+    /// Rebinding a control to the button it is already bound to succeeds without any change.
+    ///
+    /// # Parameters
+    ///
+    /// * `target`: Control to bind the button to.
+    /// * `button`: Button to bind.
+    pub fn rebind(
+        &mut self,
+        target: ControlBindingTarget,
+        button: Button,
+    ) -> Result<(), GameInputModelError> {
+        if let Some(existing) = self.binding_target(button) {
+            if existing != target {
+                return Err(GameInputModelError::ControlBindingConflict {
+                    target,
+                    button,
+                    existing,
+                });
+            }
+        }
+
+        let controller_config = &mut self
+            .get_mut(target.player())
+            .ok_or(GameInputModelError::ControlBindingPlayerNotFound { target })?
+            .controller_config;
+        match target {
+            ControlBindingTarget::AxisNegative(PlayerAxisControl { axis, .. })
+            | ControlBindingTarget::AxisPositive(PlayerAxisControl { axis, .. }) => {
+                let input_axis = controller_config.axes.get_mut(&axis);
+                match (target, input_axis) {
+                    (
+                        ControlBindingTarget::AxisNegative(..),
+                        Some(InputAxis::Emulated { neg, .. }),
+                    ) => *neg = button,
+                    (
+                        ControlBindingTarget::AxisPositive(..),
+                        Some(InputAxis::Emulated { pos, .. }),
+                    ) => *pos = button,
+                    _ => return Err(GameInputModelError::ControlBindingAxisNotEmulated { target }),
+                }
+            }
+            ControlBindingTarget::Action(PlayerActionControl { action, .. }) => {
+                controller_config.actions.insert(action, button);
+            }
+        }
+
+        Ok(())
+    }
 }
 
 impl<'config> TryFrom<&'config PlayerInputConfigs> for Bindings<ControlBindings> {
@@ -116,6 +253,7 @@ impl<'config> TryFrom<&'config PlayerInputConfigs> for Bindings<ControlBindings>
     fn try_from(
         player_input_configs: &'config PlayerInputConfigs,
     ) -> Result<Bindings<ControlBindings>, Error> {
-        player_input_configs.generate_bindings(ControllerIdOffset::new(0))
+        player_input_configs
+            .generate_bindings(ControllerIdOffset::new(0), &GamepadAssignments::default())
     }
 }
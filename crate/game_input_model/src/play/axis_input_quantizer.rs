@@ -0,0 +1,50 @@
+use std::collections::HashMap;
+
+use crate::{config::PlayerAxisControl, GameInputEvent};
+
+/// Reduces axis values to `-1.`, `0.`, or `1.`, and drops events that do not change the value.
+///
+/// Analog sticks send many `AxisMoved` events with fractional values. Quantizing them makes
+/// gamepads behave the same as keys, and keeps the number of events sent over the network and
+/// recorded in replays the same as for keyboard input.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct AxisInputQuantizer {
+    /// Last quantized value of each axis.
+    axis_values: HashMap<PlayerAxisControl, i8>,
+}
+
+impl AxisInputQuantizer {
+    /// Returns the event with its axis value quantized, or `None` if the value is unchanged.
+    ///
+    /// Non-axis events are returned as is.
+    ///
+    /// # Parameters
+    ///
+    /// * `game_input_event`: Event to quantize.
+    pub fn quantize(&mut self, game_input_event: GameInputEvent) -> Option<GameInputEvent> {
+        match game_input_event {
+            GameInputEvent::AxisMoved { axis, value } => {
+                let value = if value > 0. {
+                    1
+                } else if value < 0. {
+                    -1
+                } else {
+                    0
+                };
+
+                let value_previous = self.axis_values.insert(axis, value);
+                if value_previous == Some(value) {
+                    None
+                } else {
+                    Some(GameInputEvent::AxisMoved {
+                        axis,
+                        value: f32::from(value),
+                    })
+                }
+            }
+            GameInputEvent::ActionPressed(..) | GameInputEvent::ActionReleased(..) => {
+                Some(game_input_event)
+            }
+        }
+    }
+}
@@ -0,0 +1,122 @@
+use std::collections::VecDeque;
+
+use amethyst::ecs::{storage::DenseVecStorage, Component};
+use derive_new::new;
+use serde::{Deserialize, Serialize};
+
+use crate::config::{Axis, CommandInput, InputCommand};
+
+/// Input entered at a particular tick.
+#[derive(Clone, Copy, Debug, Deserialize, PartialEq, Eq, Serialize, new)]
+pub struct ControlInputHistoryEntry {
+    /// Input that was entered.
+    pub input: CommandInput,
+    /// Tick the input was entered.
+    pub tick: u32,
+}
+
+/// Recent inputs entered for an entity, used to detect `InputCommand`s.
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Eq, Serialize)]
+pub struct ControlInputHistory {
+    /// Number of ticks that this history has been tracked for.
+    pub tick: u32,
+    /// Entered inputs, oldest first.
+    pub entries: VecDeque<ControlInputHistoryEntry>,
+    /// Direction of the last X axis input, as if the entity is not mirrored.
+    #[serde(default)]
+    pub x_axis_direction: Option<CommandInput>,
+    /// Direction of the last Z axis input.
+    #[serde(default)]
+    pub z_axis_direction: Option<CommandInput>,
+}
+
+impl ControlInputHistory {
+    /// Maximum number of inputs that are remembered.
+    pub const CAPACITY: usize = 16;
+
+    /// Returns a new `ControlInputHistory`.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Advances the history by one tick.
+    pub fn advance(&mut self) {
+        self.tick = self.tick.wrapping_add(1);
+    }
+
+    /// Records an input entered on the current tick.
+    ///
+    /// The oldest input is forgotten when there are more than `CAPACITY` inputs.
+    pub fn push(&mut self, input: CommandInput) {
+        if self.entries.len() == Self::CAPACITY {
+            self.entries.pop_front();
+        }
+        self.entries
+            .push_back(ControlInputHistoryEntry::new(input, self.tick));
+    }
+
+    /// Records an axis input if the axis direction differs from the last input on that axis.
+    ///
+    /// Analog sticks send many axis events while held in one direction, so only changes in
+    /// direction are recorded. Returning the axis to neutral allows the same direction to be
+    /// recorded again.
+    ///
+    /// # Parameters
+    ///
+    /// * `axis`: Axis that was moved.
+    /// * `axis_value`: Input value of the axis.
+    /// * `mirrored`: Whether the entity is facing the axis negative direction.
+    pub fn push_axis(&mut self, axis: Axis, axis_value: f32, mirrored: bool) {
+        let direction = CommandInput::from_axis(axis, axis_value, false);
+        let direction_last = match axis {
+            Axis::X => &mut self.x_axis_direction,
+            Axis::Z => &mut self.z_axis_direction,
+        };
+        if *direction_last == direction {
+            return;
+        }
+        *direction_last = direction;
+
+        if let Some(command_input) = CommandInput::from_axis(axis, axis_value, mirrored) {
+            self.push(command_input);
+        }
+    }
+
+    /// Returns whether the command's inputs are the most recent inputs, and were entered within
+    /// the command's window ending on the current tick.
+    ///
+    /// # Parameters
+    ///
+    /// * `input_command`: Command to check.
+    pub fn matches(&self, input_command: &InputCommand) -> bool {
+        let input_count = input_command.inputs.len();
+        if input_count == 0 || input_count > self.entries.len() {
+            return false;
+        }
+
+        let entries_recent = self.entries.iter().skip(self.entries.len() - input_count);
+        let inputs_match = entries_recent
+            .clone()
+            .zip(input_command.inputs.iter())
+            .all(|(entry, input)| entry.input == *input);
+        if !inputs_match {
+            return false;
+        }
+
+        let tick_first = entries_recent
+            .clone()
+            .next()
+            .map(|entry| entry.tick)
+            .expect("Unreachable: `input_count` is non-zero.");
+        let tick_last = entries_recent
+            .last()
+            .map(|entry| entry.tick)
+            .expect("Unreachable: `input_count` is non-zero.");
+
+        tick_last == self.tick && tick_last.wrapping_sub(tick_first) <= input_command.window
+    }
+}
+
+impl Component for ControlInputHistory {
+    type Storage = DenseVecStorage<Self>;
+}
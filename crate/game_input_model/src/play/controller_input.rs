@@ -1,9 +1,10 @@
 use amethyst::ecs::{storage::DenseVecStorage, Component};
 use derive_new::new;
+use serde::{Deserialize, Serialize};
 
 /// Input for a character entity.
 // TODO: Map of actions and axes <https://gitlab.com/azriel91/autexousious/issues/73>.
-#[derive(Clone, Copy, Debug, Default, PartialEq, new)]
+#[derive(Clone, Copy, Debug, Default, Deserialize, PartialEq, Serialize, new)]
 pub struct ControllerInput {
     /// X axis input value.
     ///
@@ -3,6 +3,10 @@ use std::{
     fmt::{self, Display, Formatter},
 };
 
+use amethyst::input::Button;
+
+use crate::config::ControlBindingTarget;
+
 /// Errors when using `game_input_model` types.
 #[derive(Clone, Copy, Debug, PartialEq)]
 pub enum GameInputModelError {
@@ -10,6 +14,25 @@ pub enum GameInputModelError {
     PlayerControllerParseError,
     /// Failed to parse `PlayerControllers` from string.
     PlayerControllersParseError,
+    /// There is no `PlayerInputConfig` for the player of a control binding.
+    ControlBindingPlayerNotFound {
+        /// Control that was to be bound.
+        target: ControlBindingTarget,
+    },
+    /// The axis to bind is not an emulated axis, so it cannot be bound to a button.
+    ControlBindingAxisNotEmulated {
+        /// Control that was to be bound.
+        target: ControlBindingTarget,
+    },
+    /// The button is already bound to a different control.
+    ControlBindingConflict {
+        /// Control that was to be bound.
+        target: ControlBindingTarget,
+        /// Button that was to be bound.
+        button: Button,
+        /// Control that the button is already bound to.
+        existing: ControlBindingTarget,
+    },
 }
 
 impl Display for GameInputModelError {
@@ -24,6 +47,30 @@ impl Display for GameInputModelError {
                 "Player controllers must be space separated in the form \
                     `<id>:<name> <id>:<name>`. Example: `0:azriel 1:friend`"
             ),
+            Self::ControlBindingPlayerNotFound { target } => write!(
+                f,
+                "Player {} does not have an input configuration.",
+                target.player()
+            ),
+            Self::ControlBindingAxisNotEmulated { target } => write!(
+                f,
+                "Player {} {} cannot be bound to a button, as the axis is not emulated.",
+                target.player(),
+                target
+            ),
+            Self::ControlBindingConflict {
+                target,
+                button,
+                existing,
+            } => write!(
+                f,
+                "Cannot bind `{:?}` to player {} {}, as it is already bound to player {} {}.",
+                button,
+                target.player(),
+                target,
+                existing.player(),
+                existing
+            ),
         }
     }
 }
@@ -0,0 +1,63 @@
+use std::collections::HashMap;
+
+use derive_deref::{Deref, DerefMut};
+use derive_new::new;
+
+use crate::config::{ControllerId, GamepadId};
+
+/// Gamepads assigned to local players (`HashMap<ControllerId, GamepadId>` newtype).
+///
+/// The `ControllerId` key is the index of the player's `PlayerInputConfig`, which is not offset
+/// by the `ControllerIdOffset`.
+#[derive(Clone, Debug, Default, Deref, DerefMut, PartialEq, new)]
+pub struct GamepadAssignments(pub HashMap<ControllerId, GamepadId>);
+
+impl GamepadAssignments {
+    /// Returns the gamepad assigned to the player, if any.
+    ///
+    /// # Parameters
+    ///
+    /// * `player`: Index of the player's `PlayerInputConfig`.
+    pub fn gamepad(&self, player: ControllerId) -> Option<GamepadId> {
+        self.get(&player).copied()
+    }
+
+    /// Assigns the gamepad to the first player without a gamepad, returning the player index.
+    ///
+    /// If the gamepad is already assigned, its existing player index is returned. `None` is
+    /// returned if every player already has a gamepad.
+    ///
+    /// # Parameters
+    ///
+    /// * `gamepad_id`: ID of the connected gamepad.
+    /// * `player_count`: Number of local players.
+    pub fn assign(&mut self, gamepad_id: GamepadId, player_count: usize) -> Option<ControllerId> {
+        if let Some(player) = self.player(gamepad_id) {
+            return Some(player);
+        }
+
+        let player = (0..player_count).find(|player| !self.contains_key(player))?;
+        self.insert(player, gamepad_id);
+
+        Some(player)
+    }
+
+    /// Removes the gamepad's assignment, returning the player index it was assigned to.
+    ///
+    /// # Parameters
+    ///
+    /// * `gamepad_id`: ID of the disconnected gamepad.
+    pub fn unassign(&mut self, gamepad_id: GamepadId) -> Option<ControllerId> {
+        let player = self.player(gamepad_id)?;
+        self.remove(&player);
+
+        Some(player)
+    }
+
+    /// Returns the player index that the gamepad is assigned to, if any.
+    fn player(&self, gamepad_id: GamepadId) -> Option<ControllerId> {
+        self.iter()
+            .find(|(_, gamepad_id_assigned)| **gamepad_id_assigned == gamepad_id)
+            .map(|(player, _)| *player)
+    }
+}
@@ -1,3 +1,4 @@
+use ai_model::play::AiControlled;
 use amethyst::{
     assets::PrefabData,
     ecs::{
@@ -40,6 +41,9 @@ pub struct CharacterAugmentRectifySystemData<'s> {
     /// `InputControlled` components.
     #[derivative(Debug = "ignore")]
     pub input_controlleds: ReadStorage<'s, InputControlled>,
+    /// `AiControlled` components.
+    #[derivative(Debug = "ignore")]
+    pub ai_controlleds: ReadStorage<'s, AiControlled>,
     /// `CameraTracked` components.
     #[derivative(Debug = "ignore")]
     pub camera_trackeds: WriteStorage<'s, CameraTracked>,
@@ -103,6 +107,7 @@ impl<'s> System<'s> for CharacterAugmentRectifySystem {
             map_selection,
             asset_map_bounds,
             input_controlleds,
+            ai_controlleds,
             mut camera_trackeds,
             mut positions,
             lazy_update,
@@ -138,9 +143,12 @@ impl<'s> System<'s> for CharacterAugmentRectifySystem {
         // This `Position` moves the entity to the middle of a screen wide map.
         let position = Position::<f32>::new(width / 2., height / 2., depth / 2.);
 
-        (&entities, &input_controlleds)
+        (&entities, input_controlleds.maybe(), ai_controlleds.maybe())
             .join()
-            .for_each(|(entity, _)| {
+            .filter(|(_, input_controlled, ai_controlled)| {
+                input_controlled.is_some() || ai_controlled.is_some()
+            })
+            .for_each(|(entity, _, _)| {
                 // Set character `position` based on the map.
                 positions
                     .insert(entity, position)
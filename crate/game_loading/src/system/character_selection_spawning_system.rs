@@ -1,3 +1,4 @@
+use ai_model::play::AiControlled;
 use amethyst::{
     ecs::{Entities, Entity, Read, System, World, Write, WriteStorage},
     shred::{ResourceId, SystemData},
@@ -11,6 +12,7 @@ use derivative::Derivative;
 use derive_new::new;
 use game_input_model::play::InputControlled;
 use game_model::play::GameEntities;
+use game_play_model::{play::STAGE_PLAYER_TEAM, GameMode};
 use object_type::ObjectType;
 use team_model::play::{IndependentCounter, Team};
 
@@ -30,6 +32,9 @@ pub struct CharacterSelectionSpawningSystemData<'s> {
     /// `CharacterSelections` resource.
     #[derivative(Debug = "ignore")]
     pub character_selections: Read<'s, CharacterSelections>,
+    /// `GameMode` resource.
+    #[derivative(Debug = "ignore")]
+    pub game_mode: Read<'s, GameMode>,
     /// `GameLoadingStatus` resource.
     #[derivative(Debug = "ignore")]
     pub game_loading_status: Write<'s, GameLoadingStatus>,
@@ -57,6 +62,9 @@ pub struct CharacterSelectionSpawningSystemData<'s> {
     /// `InputControlled` components.
     #[derivative(Debug = "ignore")]
     pub input_controlleds: WriteStorage<'s, InputControlled>,
+    /// `AiControlled` components.
+    #[derivative(Debug = "ignore")]
+    pub ai_controlleds: WriteStorage<'s, AiControlled>,
     /// `Team` components.
     #[derivative(Debug = "ignore")]
     pub teams: WriteStorage<'s, Team>,
@@ -73,6 +81,7 @@ impl<'s> System<'s> for CharacterSelectionSpawningSystem {
         CharacterSelectionSpawningSystemData {
             entities,
             character_selections,
+            game_mode,
             mut game_loading_status,
             mut independent_counter,
             asset_item_ids,
@@ -82,6 +91,7 @@ impl<'s> System<'s> for CharacterSelectionSpawningSystem {
             character_spawning_resources,
             mut character_component_storages,
             mut input_controlleds,
+            mut ai_controlleds,
             mut teams,
             mut game_entities,
         }: Self::SystemData,
@@ -123,14 +133,25 @@ impl<'s> System<'s> for CharacterSelectionSpawningSystem {
                 item_ids
                     .insert(entity, item_id)
                     .expect("Failed to insert `ItemId` for character.");
-                input_controlleds
-                    .insert(entity, InputControlled::new(*controller_id))
-                    .expect("Failed to insert `InputControlled` for character.");
+                if let Some(ai_difficulty) = character_selections
+                    .cpu_selections
+                    .get(controller_id)
+                    .copied()
+                {
+                    ai_controlleds
+                        .insert(entity, AiControlled::new(*controller_id, ai_difficulty))
+                        .expect("Failed to insert `AiControlled` for character.");
+                } else {
+                    input_controlleds
+                        .insert(entity, InputControlled::new(*controller_id))
+                        .expect("Failed to insert `InputControlled` for character.");
+                }
+                let team = match *game_mode {
+                    GameMode::Versus => Team::Independent(independent_counter.get_and_increment()),
+                    GameMode::Stage => STAGE_PLAYER_TEAM,
+                };
                 teams
-                    .insert(
-                        entity,
-                        Team::Independent(independent_counter.get_and_increment()),
-                    )
+                    .insert(entity, team)
                     .expect("Failed to insert `Team` for character.");
 
                 entity
@@ -3,7 +3,8 @@ use application_event::AppEvent;
 use application_state::{AppState, AppStateBuilder};
 use derivative::Derivative;
 use derive_new::new;
-use game_mode_selection_model::GameModeSelectionEntity;
+use game_mode_selection_model::{GameModeIndex, GameModeSelectionEntity};
+use game_play_model::GameMode;
 use log::debug;
 use menu_model::MenuEvent;
 use state_registry::StateId;
@@ -38,6 +39,15 @@ impl GameModeSelectionStateDelegate {
     fn initialize_state(data: StateData<'_, GameData<'static, 'static>>) {
         data.world.insert(StateId::GameModeSelection);
     }
+
+    /// Returns the `GameMode` to play for the selected `GameModeIndex`, if any.
+    fn game_mode(game_mode_index: GameModeIndex) -> Option<GameMode> {
+        match game_mode_index {
+            GameModeIndex::StartGame | GameModeIndex::NetworkPlay => Some(GameMode::Versus),
+            GameModeIndex::Stage => Some(GameMode::Stage),
+            GameModeIndex::ControlSettings | GameModeIndex::Exit => None,
+        }
+    }
 }
 
 impl State<GameData<'static, 'static>, AppEvent> for GameModeSelectionStateDelegate {
@@ -51,7 +61,7 @@ impl State<GameData<'static, 'static>, AppEvent> for GameModeSelectionStateDeleg
 
     fn handle_event(
         &mut self,
-        _data: StateData<'_, GameData<'static, 'static>>,
+        data: StateData<'_, GameData<'static, 'static>>,
         event: AppEvent,
     ) -> Trans<GameData<'static, 'static>, AppEvent> {
         if let AppEvent::GameModeSelection(game_mode_selection_event) = event {
@@ -60,7 +70,13 @@ impl State<GameData<'static, 'static>, AppEvent> for GameModeSelectionStateDeleg
                 game_mode_selection_event
             );
             match game_mode_selection_event {
-                MenuEvent::Select(idx) => GameModeSelectionTrans::trans(idx),
+                MenuEvent::Select(idx) => {
+                    if let Some(game_mode) = Self::game_mode(idx) {
+                        data.world.insert(game_mode);
+                    }
+
+                    GameModeSelectionTrans::trans(idx)
+                }
                 MenuEvent::Close => Trans::Pop,
             }
         } else {
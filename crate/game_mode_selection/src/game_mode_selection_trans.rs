@@ -10,7 +10,9 @@ use game_loading::GameLoadingState;
 use game_mode_selection_model::GameModeIndex;
 use game_play::GamePlayState;
 use map_selection::{MapSelectionBundle, MapSelectionStateBuilder, MapSelectionStateDelegate};
+use net_play::NetDiscoveryRequestSystem;
 use network_mode_selection::{NetworkModeSelectionStateBuilder, NetworkModeSelectionStateDelegate};
+use network_mode_selection_play::{NetServerListDisplaySystem, NetServerSelectionSystem};
 
 /// Returns the `Trans` for a given `GameModeIndex`.
 #[derive(Debug)]
@@ -24,7 +26,7 @@ impl GameModeSelectionTrans {
     /// * `game_mode_index`: The selected index.
     pub fn trans(game_mode_index: GameModeIndex) -> Trans<GameData<'static, 'static>, AppEvent> {
         match game_mode_index {
-            GameModeIndex::StartGame => {
+            GameModeIndex::StartGame | GameModeIndex::Stage => {
                 let character_selection_state = Self::character_selection_state();
                 Trans::Push(character_selection_state)
             }
@@ -64,8 +66,23 @@ impl GameModeSelectionTrans {
     }
 
     fn network_mode_selection_state() -> Box<dyn State<GameData<'static, 'static>, AppEvent>> {
-        let state =
-            NetworkModeSelectionStateBuilder::new(NetworkModeSelectionStateDelegate::new()).build();
+        let state = NetworkModeSelectionStateBuilder::new(NetworkModeSelectionStateDelegate::new())
+            .with_system(
+                NetDiscoveryRequestSystem::new(),
+                any::type_name::<NetDiscoveryRequestSystem>(),
+                &[],
+            )
+            .with_system(
+                NetServerSelectionSystem::new(),
+                any::type_name::<NetServerSelectionSystem>(),
+                &[any::type_name::<NetDiscoveryRequestSystem>()],
+            )
+            .with_system(
+                NetServerListDisplaySystem::new(),
+                any::type_name::<NetServerListDisplaySystem>(),
+                &[any::type_name::<NetServerSelectionSystem>()],
+            )
+            .build();
 
         Box::new(state)
     }
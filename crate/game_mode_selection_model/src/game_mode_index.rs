@@ -10,6 +10,8 @@ use strum_macros::{Display, EnumIter, EnumString};
 pub enum GameModeIndex {
     /// Starts a local game.
     StartGame,
+    /// Starts a stage with waves of enemies.
+    Stage,
     /// Goes to the network mode selection menu.
     NetworkPlay,
     /// Opens control settings.
@@ -1,7 +1,9 @@
 use std::any;
 
+use ai_model::play::AiControlled;
+use ai_play::{AiControllerSystem, AiDecisionClockSystem};
 use amethyst::{
-    core::{bundle::SystemBundle, SystemDesc, SystemExt},
+    core::{bundle::SystemBundle, transform::Transform, SystemDesc, SystemExt},
     ecs::{DispatcherBuilder, World},
     Error,
 };
@@ -11,6 +13,11 @@ use camera_play::{CameraTrackingSystem, CameraVelocitySystem};
 use character_model::{
     config::CharacterIrr,
     loaded::{CharacterIrs, CharacterIrsHandles},
+    play::RunCounter,
+};
+use charge_model::play::{
+    ChargeBeginDelayClock, ChargeDelayClock, ChargeRetention, ChargeRetentionClock, ChargeStatus,
+    ChargeTrackerClock, ChargeUseEvent,
 };
 use charge_play::{
     ChargeIncrementSystem, ChargeInitializeDelaySystem, ChargeInitializeDetectionSystem,
@@ -18,16 +25,33 @@ use charge_play::{
 };
 use chase_play::StickToTargetObjectSystem;
 use collision_audio_play::HitSfxSystem;
-use collision_model::loaded::{
-    BodySequence, BodySequenceHandles, InteractionsSequence, InteractionsSequenceHandles,
+use collision_model::{
+    loaded::{
+        BodySequence, BodySequenceHandles, InteractionsSequence, InteractionsSequenceHandles,
+    },
+    play::{
+        CollisionEvent, ContactEvent, GrabTracker, GrabbedBy, HitEvent, HitObjectCount,
+        HitRepeatTrackers,
+    },
 };
 use collision_play::{
-    CollisionDetectionSystem, ContactDetectionSystem, HitDetectionSystem, HitEffectSystem,
-    HitRepeatTrackersAugmentSystem, HitRepeatTrackersTickerSystem, HittingEffectSystem,
+    CollisionDetectionSystem, ContactDetectionSystem, GrabEffectSystem, GrabHoldSystem,
+    HitDetectionSystem, HitEffectSystem, HitRepeatTrackersAugmentSystem,
+    HitRepeatTrackersTickerSystem, HittingEffectSystem,
 };
 use derive_new::new;
-use game_input_model::play::ControllerInput;
+use game_input::{
+    ControlInputHistoryUpdateSystem, ControlInputHistoryUpdateSystemDesc,
+    ControllerInputUpdateSystem, GameInputToControlInputSystem, GameInputToControlInputSystemDesc,
+    SharedControllerInputUpdateSystem,
+};
+use game_input_model::{
+    play::{ControlInputEvent, ControlInputHistory, ControllerInput},
+    GameInputEvent,
+};
 use game_play_hud::{CpBarUpdateSystem, HpBarUpdateSystem};
+use game_play_model::GamePlayEntity;
+use game_stats_model::play::DamageDealt;
 use input_reaction_model::{
     config::BasicIrr,
     loaded::{InputReactionsSequence, InputReactionsSequenceHandles},
@@ -36,25 +60,42 @@ use input_reaction_play::{
     ButtonInputReactionsTransitionSystem, ButtonInputReactionsTransitionSystemDesc,
     InputReactionsTransitionSystem, InteractableObjectSyncSystem,
 };
+use item_model::play::{HeldBy, HeldItem};
+use item_play::{ItemFollowHolderSystem, ItemLandSystem, ItemPickupSystem, ItemThrowSystem};
 use kinematic_model::{
-    config::Position,
+    config::{Position, Velocity},
     loaded::{ObjectAccelerationSequence, ObjectAccelerationSequenceHandles},
 };
+use map_model::play::{MapBoundaryEvent, OutOfBoundsDeleteClock};
 use map_play::{
     KeepWithinMapBoundsSystem, MapEnterExitDetectionSystem, MapOutOfBoundsClockAugmentSystem,
     MapOutOfBoundsDeletionSystem, MapSpawnOutOfBoundsDetectionSystem,
 };
+use mirrored_model::play::Mirrored;
 use network_session_model::play::SessionCondition;
+use object_model::play::{Grounding, GuardPoints, HealthPoints, SkillPoints};
 use object_play::{
     ObjectAccelerationSystem, ObjectGravitySystem, ObjectGroundingSystem, ObjectMirroringSystem,
 };
-use object_status_play::StunPointsReductionSystem;
-use sequence_model::loaded::{SequenceEndTransitions, WaitSequence, WaitSequenceHandles};
+use object_status_model::config::StunPoints;
+use object_status_play::{GuardPointsRecoverySystem, StunPointsReductionSystem};
+use parent_model::play::ParentEntity;
+use rollback_play::{RollbackEventChannels, RollbackInputReplaySystem};
+use sequence_model::{
+    loaded::{SequenceEndTransitions, SequenceId, WaitSequence, WaitSequenceHandles},
+    play::{
+        FrameFreezeClock, FrameIndexClock, FrameWaitClock, SequenceStatus, SequenceUpdateEvent,
+    },
+};
 use sequence_play::{
     FrameComponentUpdateSystem, SequenceComponentUpdateSystem, SequenceEndTransitionSystem,
     SequenceStatusUpdateSystem, SequenceUpdateSystem,
 };
-use spawn_model::loaded::{SpawnsSequence, SpawnsSequenceHandles};
+use snapshot_model::play::{DeferredDelete, SnapshotRegistry};
+use spawn_model::{
+    loaded::{SpawnsSequence, SpawnsSequenceHandles},
+    play::SpawnEvent,
+};
 use spawn_play::{SpawnGameObjectRectifySystem, SpawnGameObjectSystem};
 use sprite_model::loaded::{
     ScaleSequence, ScaleSequenceHandles, SpriteRenderSequence, SpriteRenderSequenceHandles,
@@ -62,18 +103,35 @@ use sprite_model::loaded::{
 };
 use sprite_play::SpriteScaleUpdateSystem;
 use state_registry::StateId;
-use tracker::LastTrackerSystem;
+use tracker::{Last, LastTrackerSystem};
 
 use crate::{
     CharacterHitEffectSystem, CharacterSequenceUpdateSystem, FrameFreezeClockAugmentSystem,
     GamePlayEndDetectionSystem, GamePlayEndTransitionDelaySystem, GamePlayEndTransitionSystem,
-    GamePlayRemovalAugmentSystem, GamePlayStatusDisplaySystem, GroundingFrictionSystem,
-    ObjectKinematicsUpdateSystem, ObjectTransformUpdateSystem,
+    GamePlayRemovalAugmentSystem, GamePlayStatusDisplaySystem, GrabThrowSystem,
+    GroundingFrictionSystem, ObjectKinematicsUpdateSystem, ObjectTransformUpdateSystem,
+    StageWaveSystem,
 };
 
 /// Adds the object type update systems to the provided dispatcher.
 #[derive(Debug, new)]
-pub struct GamePlayBundle;
+pub struct GamePlayBundle {
+    /// Whether to only add the systems that simulate game play.
+    #[new(default)]
+    simulation_only: bool,
+}
+
+impl GamePlayBundle {
+    /// Returns a `GamePlayBundle` that only adds the systems that simulate game play.
+    ///
+    /// This is used to resimulate ticks when rolling back. Input is replayed from the
+    /// `InputHistory`, and audio, camera, HUD, and game play end systems are not added.
+    pub fn new_simulation() -> Self {
+        GamePlayBundle {
+            simulation_only: true,
+        }
+    }
+}
 
 impl<'a, 'b> SystemBundle<'a, 'b> for GamePlayBundle {
     fn build(
@@ -81,8 +139,58 @@ impl<'a, 'b> SystemBundle<'a, 'b> for GamePlayBundle {
         world: &mut World,
         builder: &mut DispatcherBuilder<'a, 'b>,
     ) -> Result<(), Error> {
+        // === Input replay === //
+
+        // When resimulating, input for each tick is replayed from the `InputHistory`. Otherwise
+        // these systems are run in the main dispatcher.
+        if self.simulation_only {
+            builder.add(
+                RollbackInputReplaySystem::new(),
+                any::type_name::<RollbackInputReplaySystem>(),
+                &[],
+            ); // kcov-ignore
+            builder.add(
+                <GameInputToControlInputSystemDesc as SystemDesc<
+                    'a,
+                    'b,
+                    GameInputToControlInputSystem,
+                >>::build(GameInputToControlInputSystemDesc::default(), world),
+                any::type_name::<GameInputToControlInputSystem>(),
+                &[any::type_name::<RollbackInputReplaySystem>()],
+            ); // kcov-ignore
+            builder.add(
+                AiControllerSystem::new(),
+                any::type_name::<AiControllerSystem>(),
+                &[any::type_name::<GameInputToControlInputSystem>()],
+            ); // kcov-ignore
+            builder.add(
+                ControllerInputUpdateSystem::new(),
+                any::type_name::<ControllerInputUpdateSystem>(),
+                &[any::type_name::<AiControllerSystem>()],
+            ); // kcov-ignore
+            builder.add(
+                SharedControllerInputUpdateSystem::new(),
+                any::type_name::<SharedControllerInputUpdateSystem>(),
+                &[any::type_name::<ControllerInputUpdateSystem>()],
+            ); // kcov-ignore
+            builder.add_barrier();
+        }
+
         // === Component augmentation === //
 
+        // Records inputs per session tick, before the `InputReactionsTransitionSystem`s match
+        // commands against them.
+        builder.add(
+            <ControlInputHistoryUpdateSystemDesc as SystemDesc<
+                'a,
+                'b,
+                ControlInputHistoryUpdateSystem,
+            >>::build(ControlInputHistoryUpdateSystemDesc::default(), world)
+            .pausable(SessionCondition::Ready),
+            any::type_name::<ControlInputHistoryUpdateSystem>(),
+            &[],
+        ); // kcov-ignore
+
         builder.add(
             SequenceStatusUpdateSystem::new(),
             any::type_name::<SequenceStatusUpdateSystem>(),
@@ -169,14 +277,16 @@ impl<'a, 'b> SystemBundle<'a, 'b> for GamePlayBundle {
             &[],
         ); // kcov-ignore
 
-        builder.add(HitSfxSystem::new(), any::type_name::<HitSfxSystem>(), &[]);
+        if !self.simulation_only {
+            builder.add(HitSfxSystem::new(), any::type_name::<HitSfxSystem>(), &[]);
 
-        // Play sounds from sequence updates.
-        builder.add(
-            SequenceAudioPlaySystem::new(),
-            any::type_name::<SequenceAudioPlaySystem>(),
-            &[any::type_name::<FrameComponentUpdateSystem<SourceSequence>>()],
-        ); // kcov-ignore
+            // Play sounds from sequence updates.
+            builder.add(
+                SequenceAudioPlaySystem::new(),
+                any::type_name::<SequenceAudioPlaySystem>(),
+                &[any::type_name::<FrameComponentUpdateSystem<SourceSequence>>()],
+            ); // kcov-ignore
+        }
 
         // Spawn objects
         builder.add(
@@ -256,6 +366,12 @@ impl<'a, 'b> SystemBundle<'a, 'b> for GamePlayBundle {
             any::type_name::<GroundingFrictionSystem>(),
             &[any::type_name::<ObjectGroundingSystem>()],
         ); // kcov-ignore
+           // Returns thrown items to rest when they land.
+        builder.add(
+            ItemLandSystem::new().pausable(SessionCondition::Ready),
+            any::type_name::<ItemLandSystem>(),
+            &[any::type_name::<ObjectGroundingSystem>()],
+        ); // kcov-ignore
 
         builder.add(
             MapOutOfBoundsDeletionSystem::new().pausable(SessionCondition::Ready),
@@ -271,12 +387,23 @@ impl<'a, 'b> SystemBundle<'a, 'b> for GamePlayBundle {
             &[any::type_name::<MapOutOfBoundsDeletionSystem>()],
         ); // kcov-ignore
 
+        // Keeps grabbed objects with their grabber, and releases them when the grab ends.
+        builder.add(
+            GrabHoldSystem::new().pausable(SessionCondition::Ready),
+            any::type_name::<GrabHoldSystem>(),
+            &[
+                any::type_name::<ObjectKinematicsUpdateSystem>(),
+                any::type_name::<KeepWithinMapBoundsSystem>(),
+            ],
+        ); // kcov-ignore
+
         builder.add(
             ObjectTransformUpdateSystem::new(),
             any::type_name::<ObjectTransformUpdateSystem>(),
             &[
                 any::type_name::<ObjectKinematicsUpdateSystem>(),
                 any::type_name::<KeepWithinMapBoundsSystem>(),
+                any::type_name::<GrabHoldSystem>(),
             ],
         ); // kcov-ignore
         builder.add(
@@ -294,6 +421,14 @@ impl<'a, 'b> SystemBundle<'a, 'b> for GamePlayBundle {
             any::type_name::<StickToTargetObjectSystem>(),
             &[any::type_name::<ObjectTransformUpdateSystem>()],
         ); // kcov-ignore
+        builder.add(
+            ItemFollowHolderSystem::new(),
+            any::type_name::<ItemFollowHolderSystem>(),
+            &[
+                any::type_name::<ObjectTransformUpdateSystem>(),
+                any::type_name::<ObjectMirroringSystem>(),
+            ],
+        ); // kcov-ignore
 
         // Reduces charge when not charging.
         builder.add(
@@ -302,6 +437,13 @@ impl<'a, 'b> SystemBundle<'a, 'b> for GamePlayBundle {
             &[],
         ); // kcov-ignore
 
+        // Ticks computer controlled characters' decision clocks each tick.
+        builder.add(
+            AiDecisionClockSystem::new().pausable(SessionCondition::Ready),
+            any::type_name::<AiDecisionClockSystem>(),
+            &[],
+        ); // kcov-ignore
+
         // Reduces `StunPoints` each tick.
         builder.add(
             StunPointsReductionSystem::new().pausable(SessionCondition::Ready),
@@ -309,6 +451,13 @@ impl<'a, 'b> SystemBundle<'a, 'b> for GamePlayBundle {
             &[],
         ); // kcov-ignore
 
+        // Recovers `GuardPoints` each tick.
+        builder.add(
+            GuardPointsRecoverySystem::new().pausable(SessionCondition::Ready),
+            any::type_name::<GuardPointsRecoverySystem>(),
+            &[],
+        ); // kcov-ignore
+
         builder.add(
             HitRepeatTrackersTickerSystem::new().pausable(SessionCondition::Ready),
             any::type_name::<HitRepeatTrackersTickerSystem>(),
@@ -324,6 +473,7 @@ impl<'a, 'b> SystemBundle<'a, 'b> for GamePlayBundle {
             any::type_name::<CollisionDetectionSystem>(),
             &[
                 any::type_name::<StunPointsReductionSystem>(),
+                any::type_name::<GuardPointsRecoverySystem>(),
                 any::type_name::<HitRepeatTrackersTickerSystem>(),
             ],
         ); // kcov-ignore
@@ -353,15 +503,19 @@ impl<'a, 'b> SystemBundle<'a, 'b> for GamePlayBundle {
             &any::type_name::<InputReactionsTransitionSystem<BasicIrr>>(),
             &[any::type_name::<SequenceEndTransitionSystem>()],
         ); // kcov-ignore
-        builder.add(
-            <ButtonInputReactionsTransitionSystemDesc as SystemDesc<
-                'a,
-                'b,
-                ButtonInputReactionsTransitionSystem<BasicIrr>,
-            >>::build(ButtonInputReactionsTransitionSystemDesc::default(), world),
-            &any::type_name::<ButtonInputReactionsTransitionSystem<BasicIrr>>(),
-            &[any::type_name::<SequenceEndTransitionSystem>()],
-        ); // kcov-ignore
+        if !self.simulation_only {
+            builder.add(
+                <ButtonInputReactionsTransitionSystemDesc as SystemDesc<
+                    'a,
+                    'b,
+                    ButtonInputReactionsTransitionSystem<BasicIrr>,
+                >>::build(
+                    ButtonInputReactionsTransitionSystemDesc::default(), world
+                ),
+                &any::type_name::<ButtonInputReactionsTransitionSystem<BasicIrr>>(),
+                &[any::type_name::<SequenceEndTransitionSystem>()],
+            ); // kcov-ignore
+        }
 
         // Note: The `CharacterSequenceUpdateSystem` depends on
         // `game_input::ControllerInputUpdateSystem`. We rely on the main dispatcher to be run
@@ -412,6 +566,44 @@ impl<'a, 'b> SystemBundle<'a, 'b> for GamePlayBundle {
             &[any::type_name::<ChargeIncrementSystem>()],
         ); // kcov-ignore
 
+        // Items
+        //
+        // The `ItemPickupSystem` depends on the `ItemThrowSystem` so that an item picked up this
+        // tick is not thrown by the same `Attack` press.
+        builder.add(
+            ItemThrowSystem::new().pausable(SessionCondition::Ready),
+            any::type_name::<ItemThrowSystem>(),
+            &[any::type_name::<CharacterHitEffectSystem>()],
+        ); // kcov-ignore
+        builder.add(
+            ItemPickupSystem::new().pausable(SessionCondition::Ready),
+            any::type_name::<ItemPickupSystem>(),
+            &[any::type_name::<ItemThrowSystem>()],
+        ); // kcov-ignore
+
+        // Grabs
+        //
+        // The `GrabEffectSystem` depends on the `GrabThrowSystem` so that an object grabbed this
+        // tick is not thrown by the same `Attack` press. Both depend on the
+        // `CharacterHitEffectSystem` so that grab sequences overwrite hit sequences.
+        //
+        // Throwing a grabbed object takes priority over throwing a held item. The
+        // `ItemThrowSystem` skips objects that are grabbing, so it must run before the
+        // `GrabThrowSystem` removes the `GrabTracker`.
+        builder.add(
+            GrabThrowSystem::new().pausable(SessionCondition::Ready),
+            any::type_name::<GrabThrowSystem>(),
+            &[
+                any::type_name::<CharacterHitEffectSystem>(),
+                any::type_name::<ItemThrowSystem>(),
+            ],
+        ); // kcov-ignore
+        builder.add(
+            GrabEffectSystem::new().pausable(SessionCondition::Ready),
+            any::type_name::<GrabEffectSystem>(),
+            &[any::type_name::<GrabThrowSystem>()],
+        ); // kcov-ignore
+
         // Hit / Hitting effects.
         //
         // There are only two currently, but if there is a timer system, perhaps that should go
@@ -432,58 +624,69 @@ impl<'a, 'b> SystemBundle<'a, 'b> for GamePlayBundle {
         // Perhaps this should be straight after the `StickToTargetObjectSystem`, but we put it here
         // so that the renderer will show the HP including the damage dealt this frame, instead of
         // one frame later.
-        builder.add(
-            HpBarUpdateSystem::new(),
-            any::type_name::<HpBarUpdateSystem>(),
-            &[any::type_name::<CharacterHitEffectSystem>()],
-        ); // kcov-ignore
-        builder.add(
-            CpBarUpdateSystem::new(),
-            any::type_name::<CpBarUpdateSystem>(),
-            &[any::type_name::<CharacterHitEffectSystem>()],
-        ); // kcov-ignore
+        if !self.simulation_only {
+            builder.add(
+                HpBarUpdateSystem::new(),
+                any::type_name::<HpBarUpdateSystem>(),
+                &[any::type_name::<CharacterHitEffectSystem>()],
+            ); // kcov-ignore
+            builder.add(
+                CpBarUpdateSystem::new(),
+                any::type_name::<CpBarUpdateSystem>(),
+                &[any::type_name::<CharacterHitEffectSystem>()],
+            ); // kcov-ignore
+        }
 
         builder.add_barrier();
 
         // === Helper Systems === //
 
-        // Detects when the winning condition has been met.
+        // Spawns the next wave of enemies when playing a stage.
         builder.add(
-            GamePlayEndDetectionSystem::new(),
-            any::type_name::<GamePlayEndDetectionSystem>(),
+            StageWaveSystem::new().pausable(SessionCondition::Ready),
+            any::type_name::<StageWaveSystem>(),
             &[],
         ); // kcov-ignore
 
-        builder.add(
-            GamePlayStatusDisplaySystem::new(),
-            any::type_name::<GamePlayStatusDisplaySystem>(),
-            &[any::type_name::<GamePlayEndDetectionSystem>()],
-        ); // kcov-ignore
-
-        // Delay before game play end transition is accepted.
-        builder.add(
-            GamePlayEndTransitionDelaySystem::new(),
-            any::type_name::<GamePlayEndTransitionDelaySystem>(),
-            &[any::type_name::<GamePlayEndDetectionSystem>()],
-        ); // kcov-ignore
-
-        // Sends a state transition when game play ends, and `Attack` is pressed.
-        builder.add(
-            GamePlayEndTransitionSystem::new(),
-            any::type_name::<GamePlayEndTransitionSystem>(),
-            &[any::type_name::<GamePlayEndTransitionDelaySystem>()],
-        ); // kcov-ignore
-
-        builder.add(
-            CameraTrackingSystem::default().pausable(StateId::GamePlay),
-            any::type_name::<CameraTrackingSystem>(),
-            &[],
-        ); // kcov-ignore
-        builder.add(
-            CameraVelocitySystem::default().pausable(SessionCondition::Ready),
-            any::type_name::<CameraVelocitySystem>(),
-            &[any::type_name::<CameraTrackingSystem>()],
-        ); // kcov-ignore
+        if !self.simulation_only {
+            // Detects when the winning condition has been met.
+            builder.add(
+                GamePlayEndDetectionSystem::new(),
+                any::type_name::<GamePlayEndDetectionSystem>(),
+                &[any::type_name::<StageWaveSystem>()],
+            ); // kcov-ignore
+
+            builder.add(
+                GamePlayStatusDisplaySystem::new(),
+                any::type_name::<GamePlayStatusDisplaySystem>(),
+                &[any::type_name::<GamePlayEndDetectionSystem>()],
+            ); // kcov-ignore
+
+            // Delay before game play end transition is accepted.
+            builder.add(
+                GamePlayEndTransitionDelaySystem::new(),
+                any::type_name::<GamePlayEndTransitionDelaySystem>(),
+                &[any::type_name::<GamePlayEndDetectionSystem>()],
+            ); // kcov-ignore
+
+            // Sends a state transition when game play ends, and `Attack` is pressed.
+            builder.add(
+                GamePlayEndTransitionSystem::new(),
+                any::type_name::<GamePlayEndTransitionSystem>(),
+                &[any::type_name::<GamePlayEndTransitionDelaySystem>()],
+            ); // kcov-ignore
+
+            builder.add(
+                CameraTrackingSystem::default().pausable(StateId::GamePlay),
+                any::type_name::<CameraTrackingSystem>(),
+                &[any::type_name::<StageWaveSystem>()],
+            ); // kcov-ignore
+            builder.add(
+                CameraVelocitySystem::default().pausable(SessionCondition::Ready),
+                any::type_name::<CameraVelocitySystem>(),
+                &[any::type_name::<CameraTrackingSystem>()],
+            ); // kcov-ignore
+        }
 
         let position_tracker_system =
             LastTrackerSystem::<Position<f32>>::new(stringify!(Position<f32>));
@@ -493,12 +696,87 @@ impl<'a, 'b> SystemBundle<'a, 'b> for GamePlayBundle {
         let controller_input_tracker_system =
             LastTrackerSystem::<ControllerInput>::new(stringify!(game_input::ControllerInput));
         let controller_input_tracker_system_name = controller_input_tracker_system.system_name();
+        let controller_input_tracker_system_dependencies = if self.simulation_only {
+            vec![]
+        } else {
+            vec![any::type_name::<GamePlayEndTransitionSystem>()]
+        };
         builder.add(
             controller_input_tracker_system,
             &controller_input_tracker_system_name,
-            &[any::type_name::<GamePlayEndTransitionSystem>()],
+            &controller_input_tracker_system_dependencies,
         ); // kcov-ignore
 
+        // The snapshot components and event channels are registered once, by the bundle in the
+        // main dispatcher.
+        if self.simulation_only {
+            return Ok(());
+        }
+
+        // === Snapshot === //
+
+        // Components that change during game play, which are captured in world snapshots.
+        //
+        // Only `GamePlayEntity`s are captured and restored, so entities outside game play, such as
+        // UI widgets, are left untouched. Keys identify each storage in serialized snapshots, so they
+        // must not change between builds.
+        let mut snapshot_registry = SnapshotRegistry::default();
+        snapshot_registry
+            .scope::<GamePlayEntity>()
+            .register::<Transform>("transform")
+            .register::<Position<f32>>("position")
+            .register::<Velocity<f32>>("velocity")
+            .register::<Last<Position<f32>>>("last_position")
+            .register::<Mirrored>("mirrored")
+            .register::<Grounding>("grounding")
+            .register::<ControllerInput>("controller_input")
+            .register::<Last<ControllerInput>>("last_controller_input")
+            .register::<ControlInputHistory>("control_input_history")
+            .register::<AiControlled>("ai_controlled")
+            .register::<HealthPoints>("health_points")
+            .register::<SkillPoints>("skill_points")
+            .register::<GuardPoints>("guard_points")
+            .register::<StunPoints>("stun_points")
+            .register::<DamageDealt>("damage_dealt")
+            .register::<SequenceId>("sequence_id")
+            .register::<SequenceStatus>("sequence_status")
+            .register::<FrameIndexClock>("frame_index_clock")
+            .register::<FrameWaitClock>("frame_wait_clock")
+            .register::<FrameFreezeClock>("frame_freeze_clock")
+            .register::<RunCounter>("run_counter")
+            .register::<ChargeStatus>("charge_status")
+            .register::<ChargeTrackerClock>("charge_tracker_clock")
+            .register::<ChargeBeginDelayClock>("charge_begin_delay_clock")
+            .register::<ChargeDelayClock>("charge_delay_clock")
+            .register::<ChargeRetention>("charge_retention")
+            .register::<ChargeRetentionClock>("charge_retention_clock")
+            .register::<HitObjectCount>("hit_object_count")
+            .register::<OutOfBoundsDeleteClock>("out_of_bounds_delete_clock")
+            .register::<DeferredDelete>("deferred_delete")
+            .register_mapped::<HitRepeatTrackers>("hit_repeat_trackers")
+            .register_mapped::<GrabTracker>("grab_tracker")
+            .register_mapped::<GrabbedBy>("grabbed_by")
+            .register_mapped::<HeldItem>("held_item")
+            .register_mapped::<HeldBy>("held_by")
+            .register_mapped::<ParentEntity>("parent_entity");
+        snapshot_registry.setup(world);
+        world.insert(snapshot_registry);
+
+        // Event channels that game play systems read and write, which are swapped out while ticks
+        // are resimulated.
+        let mut rollback_event_channels = RollbackEventChannels::default();
+        rollback_event_channels
+            .register::<GameInputEvent>()
+            .register::<ControlInputEvent>()
+            .register::<SequenceUpdateEvent>()
+            .register::<SpawnEvent>()
+            .register::<MapBoundaryEvent>()
+            .register::<CollisionEvent>()
+            .register::<ContactEvent>()
+            .register::<HitEvent>()
+            .register::<ChargeUseEvent>();
+        world.insert(rollback_event_channels);
+
         Ok(())
     }
 }
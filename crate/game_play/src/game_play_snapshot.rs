@@ -0,0 +1,34 @@
+use amethyst::ecs::{World, WorldExt};
+use derive_new::new;
+use log::error;
+use rollback_play::WorldSnapshot;
+use snapshot_model::play::SnapshotRegistry;
+
+/// Game play state in binary form, which is restored when rolling back or loading a save state.
+///
+/// The components captured are those registered in the `SnapshotRegistry` by the
+/// `GamePlayBundle`.
+#[derive(Clone, Debug, Default, PartialEq, new)]
+pub struct GamePlaySnapshot {
+    /// Serialized `WorldSnapshotData`.
+    pub bytes: Vec<u8>,
+}
+
+impl WorldSnapshot for GamePlaySnapshot {
+    fn capture(world: &World) -> Self {
+        let snapshot_registry = world.read_resource::<SnapshotRegistry>();
+        let bytes = snapshot_registry.capture(world).unwrap_or_else(|e| {
+            error!("Failed to capture game play snapshot: {}", e);
+            Vec::new()
+        });
+
+        GamePlaySnapshot::new(bytes)
+    }
+
+    fn restore(&self, world: &World) {
+        let snapshot_registry = world.read_resource::<SnapshotRegistry>();
+        if let Err(e) = snapshot_registry.restore(world, &self.bytes) {
+            error!("Failed to restore game play snapshot: {}", e);
+        }
+    }
+}
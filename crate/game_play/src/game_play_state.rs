@@ -1,23 +1,63 @@
 use amethyst::{
-    ecs::{Entity, World, WorldExt},
+    core::SystemBundle,
+    ecs::{Dispatcher, DispatcherBuilder, Entity, World, WorldExt},
     input::{is_key_down, VirtualKeyCode},
     GameData, State, StateData, Trans,
 };
 use application_event::AppEvent;
+use camera_model::play::CameraLock;
 use derivative::Derivative;
 use derive_new::new;
 use game_model::play::GameEntities;
-use game_play_model::{GamePlayEntity, GamePlayEvent, GamePlayStatus};
-use log::debug;
+use game_play_model::{
+    play::{GamePlaySeed, StageProgress},
+    GamePlayEntity, GamePlayEvent, GamePlayStatus,
+};
+use log::{debug, warn};
+use network_session_model::play::SessionStatus;
+use rollback_model::{
+    config::SessionSyncMode,
+    play::{InputHistory, RollbackStatus},
+};
+use rollback_play::{RollbackEventChannels, RollbackSimulator, WorldSnapshot};
+use snapshot_model::play::DeleteMode;
 use state_registry::StateId;
 use state_support::StateEntityUtils;
 
+use crate::{GamePlayBundle, GamePlaySnapshot};
+
 /// `State` where game play takes place.
 #[derive(Derivative, Default, new)]
 #[derivative(Debug)]
-pub struct GamePlayState;
+pub struct GamePlayState {
+    /// Rewinds and resimulates game play when late input arrives in a rollback session.
+    #[new(default)]
+    rollback_simulator: RollbackSimulator<GamePlaySnapshot>,
+    /// Dispatcher with the game play systems, used to resimulate ticks.
+    ///
+    /// This is built when rollback is first active.
+    #[derivative(Debug = "ignore")]
+    #[new(default)]
+    rollback_dispatcher: Option<Dispatcher<'static, 'static>>,
+    /// Game play state captured by the last `GamePlayEvent::SaveState`.
+    #[new(default)]
+    save_state: Option<GamePlaySnapshot>,
+    /// Seed to play the match with, or `None` to generate a random seed.
+    #[new(default)]
+    game_play_seed: Option<GamePlaySeed>,
+}
 
 impl GamePlayState {
+    /// Returns a `GamePlayState` that plays the match with the given seed.
+    ///
+    /// This is used to play back a recorded match.
+    pub fn new_seeded(game_play_seed: GamePlaySeed) -> Self {
+        GamePlayState {
+            game_play_seed: Some(game_play_seed),
+            ..Self::new()
+        }
+    }
+
     fn terminate_entities(&mut self, world: &mut World) {
         // This `allow` is needed because rustc evaluates that `game_entities` does not live long
         // enough when entities is constructed, so we need to bind entities to a variable.
@@ -41,16 +81,90 @@ impl GamePlayState {
 
         StateEntityUtils::clear::<GamePlayEntity>(world);
     }
+
+    fn reset_rollback(&mut self, world: &mut World) {
+        self.rollback_simulator.reset();
+        world.insert(RollbackStatus::default());
+        world.insert(InputHistory::default());
+        world.insert(DeleteMode::default());
+    }
+
+    /// Returns a dispatcher with the game play systems, which resimulates ticks.
+    ///
+    /// The systems are set up while the `RollbackEventChannels` are swapped in, so that they read
+    /// events from the resimulation channels.
+    fn rollback_dispatcher(world: &mut World) -> Dispatcher<'static, 'static> {
+        let rollback_event_channels = world
+            .try_fetch::<RollbackEventChannels>()
+            .map(|rollback_event_channels| (*rollback_event_channels).clone())
+            .unwrap_or_default();
+        rollback_event_channels.swap(world);
+
+        let mut dispatcher_builder = DispatcherBuilder::new();
+        GamePlayBundle::new_simulation()
+            .build(world, &mut dispatcher_builder)
+            .expect("Failed to register simulation `GamePlayBundle`.");
+
+        let mut dispatcher = dispatcher_builder.build();
+        dispatcher.setup(world);
+
+        rollback_event_channels.swap(world);
+
+        dispatcher
+    }
+
+    fn save_state(&mut self, world: &World) {
+        debug!("Saving game play state.");
+        self.save_state = Some(GamePlaySnapshot::capture(world));
+    }
+
+    fn load_state(&mut self, world: &mut World) {
+        if let Some(save_state) = self.save_state.as_ref() {
+            debug!("Loading game play state.");
+            save_state.restore(world);
+            world.maintain();
+        } else {
+            warn!("No game play state has been saved.");
+        }
+    }
+
+    fn is_rollback_active(world: &World) -> bool {
+        let session_sync_mode = world
+            .try_fetch::<SessionSyncMode>()
+            .map(|session_sync_mode| *session_sync_mode)
+            .unwrap_or_default();
+        let session_status = world
+            .try_fetch::<SessionStatus>()
+            .map(|session_status| session_status.clone())
+            .unwrap_or_default();
+        let game_play_status = world
+            .try_fetch::<GamePlayStatus>()
+            .map(|game_play_status| *game_play_status)
+            .unwrap_or_default();
+
+        session_sync_mode.is_rollback_active(&session_status, game_play_status)
+    }
 }
 
 impl<'a, 'b> State<GameData<'a, 'b>, AppEvent> for GamePlayState {
     fn on_start(&mut self, data: StateData<'_, GameData<'_, '_>>) {
+        self.reset_rollback(data.world);
         data.world.insert(StateId::GamePlay);
         data.world.insert(GamePlayStatus::Playing);
+        data.world.insert(StageProgress::default());
+        data.world.insert(CameraLock::Unlocked);
+
+        let game_play_seed = self.game_play_seed.unwrap_or_else(GamePlaySeed::random);
+        data.world.insert(game_play_seed);
     }
 
     fn on_stop(&mut self, mut data: StateData<'_, GameData<'_, '_>>) {
         self.terminate_entities(&mut data.world);
+        self.reset_rollback(data.world);
+        self.rollback_dispatcher = None;
+        self.save_state = None;
+        data.world.insert(StageProgress::default());
+        data.world.insert(CameraLock::Unlocked);
     }
 
     fn on_resume(&mut self, data: StateData<'_, GameData<'a, 'b>>) {
@@ -96,6 +210,14 @@ impl<'a, 'b> State<GameData<'a, 'b>, AppEvent> for GamePlayState {
                         // TODO: `GamePlayStats` state.
                         Trans::Pop
                     }
+                    GamePlayEvent::SaveState => {
+                        self.save_state(data.world);
+                        Trans::None
+                    }
+                    GamePlayEvent::LoadState => {
+                        self.load_state(data.world);
+                        Trans::None
+                    }
                 }
             }
             _ => Trans::None,
@@ -109,7 +231,18 @@ impl<'a, 'b> State<GameData<'a, 'b>, AppEvent> for GamePlayState {
         // Note: The built-in dispatcher must be run before the state specific dispatcher as the
         // `"input_system"` is registered in the main dispatcher, and is a dependency of the
         // `ControllerInputUpdateSystem`.
-        data.data.update(&data.world);
+        if Self::is_rollback_active(data.world) {
+            let game_data = data.data;
+            let world = data.world;
+            let rollback_dispatcher = self
+                .rollback_dispatcher
+                .get_or_insert_with(|| Self::rollback_dispatcher(world));
+            self.rollback_simulator
+                .update(world, rollback_dispatcher, |world| game_data.update(world));
+        } else {
+            data.world.insert(DeleteMode::Immediate);
+            data.data.update(&data.world);
+        }
         Trans::None
     }
 }
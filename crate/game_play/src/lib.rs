@@ -7,6 +7,7 @@
 
 pub use crate::{
     game_play_bundle::GamePlayBundle,
+    game_play_snapshot::GamePlaySnapshot,
     game_play_state::GamePlayState,
     system::{
         CharacterHitEffectSystem, CharacterHitEffectSystemData, CharacterSequenceUpdateSystem,
@@ -16,13 +17,15 @@ pub use crate::{
         GamePlayEndTransitionDelaySystemData, GamePlayEndTransitionSystem,
         GamePlayEndTransitionSystemData, GamePlayRemovalAugmentSystem,
         GamePlayRemovalAugmentSystemData, GamePlayStatusDisplaySystem,
-        GamePlayStatusDisplaySystemData, GroundingFrictionSystem, GroundingFrictionSystemData,
-        ObjectKinematicsUpdateSystem, ObjectKinematicsUpdateSystemData,
-        ObjectTransformUpdateSystem, ObjectTransformUpdateSystemData,
-        GAME_PLAY_END_TRANSITION_DELAY_DEFAULT,
+        GamePlayStatusDisplaySystemData, GrabThrowSystem, GrabThrowSystemData,
+        GroundingFrictionSystem, GroundingFrictionSystemData, ObjectKinematicsUpdateSystem,
+        ObjectKinematicsUpdateSystemData, ObjectTransformUpdateSystem,
+        ObjectTransformUpdateSystemData, StageWaveSystem, StageWaveSystemData,
+        BLOCK_CHIP_DAMAGE_DIVISOR, GAME_PLAY_END_TRANSITION_DELAY_DEFAULT,
     },
 };
 
 mod game_play_bundle;
+mod game_play_snapshot;
 mod game_play_state;
 mod system;
@@ -1,5 +1,7 @@
 pub use self::{
-    character_hit_effect_system::{CharacterHitEffectSystem, CharacterHitEffectSystemData},
+    character_hit_effect_system::{
+        CharacterHitEffectSystem, CharacterHitEffectSystemData, BLOCK_CHIP_DAMAGE_DIVISOR,
+    },
     character_sequence_update_system::{
         CharacterSequenceUpdateSystem, CharacterSequenceUpdateSystemData,
     },
@@ -17,6 +19,7 @@ pub use self::{
     game_play_status_display_system::{
         GamePlayStatusDisplaySystem, GamePlayStatusDisplaySystemData,
     },
+    grab_throw_system::{GrabThrowSystem, GrabThrowSystemData},
     grounding_friction_system::{GroundingFrictionSystem, GroundingFrictionSystemData},
     object_kinematics_update_system::{
         ObjectKinematicsUpdateSystem, ObjectKinematicsUpdateSystemData,
@@ -25,6 +28,7 @@ pub use self::{
         ObjectTransformUpdateSystem, ObjectTransformUpdateSystemData,
     },
     sequence::{FrameFreezeClockAugmentSystem, FrameFreezeClockAugmentSystemData},
+    stage_wave_system::{StageWaveSystem, StageWaveSystemData},
 };
 
 mod character_hit_effect_system;
@@ -34,7 +38,9 @@ mod game_play_end_transition_delay_system;
 mod game_play_end_transition_system;
 mod game_play_removal_augment_system;
 mod game_play_status_display_system;
+mod grab_throw_system;
 mod grounding_friction_system;
 mod object_kinematics_update_system;
 mod object_transform_update_system;
 mod sequence;
+mod stage_wave_system;
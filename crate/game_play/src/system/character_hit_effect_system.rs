@@ -10,16 +10,23 @@ use collision_model::{
 };
 use derivative::Derivative;
 use derive_new::new;
+use game_stats_model::play::DamageDealt;
 use kinematic_model::config::Velocity;
 use mirrored_model::play::Mirrored;
-use object_model::play::HealthPoints;
+use object_model::play::{GuardPoints, HealthPoints};
 use object_status_model::config::StunPoints;
 use sequence_model::loaded::SequenceId;
+use spawn_model::play::SpawnParent;
 
 const STUN_THRESHOLD_LOW: StunPoints = StunPoints(40);
 const STUN_THRESHOLD_MID: StunPoints = StunPoints(80);
 const STUN_THRESHOLD_HIGH: StunPoints = StunPoints(120);
 
+/// Divisor applied to `hp_damage` when a hit is blocked.
+///
+/// The remaining damage is the chip damage dealt through the guard.
+pub const BLOCK_CHIP_DAMAGE_DIVISOR: u32 = 10;
+
 /// Determines collision effects for characters.
 #[derive(Debug, Default, new)]
 pub struct CharacterHitEffectSystem {
@@ -44,6 +51,9 @@ pub struct CharacterHitEffectSystemData<'s> {
     /// `HealthPoints` components.
     #[derivative(Debug = "ignore")]
     pub health_pointses: WriteStorage<'s, HealthPoints>,
+    /// `GuardPoints` components.
+    #[derivative(Debug = "ignore")]
+    pub guard_pointses: WriteStorage<'s, GuardPoints>,
     /// `StunPoints` components.
     #[derivative(Debug = "ignore")]
     pub stun_pointses: WriteStorage<'s, StunPoints>,
@@ -53,6 +63,12 @@ pub struct CharacterHitEffectSystemData<'s> {
     /// `SequenceId` components.
     #[derivative(Debug = "ignore")]
     pub sequence_ids: WriteStorage<'s, SequenceId>,
+    /// `SpawnParent` components.
+    #[derivative(Debug = "ignore")]
+    pub spawn_parents: ReadStorage<'s, SpawnParent>,
+    /// `DamageDealt` components.
+    #[derivative(Debug = "ignore")]
+    pub damage_dealts: WriteStorage<'s, DamageDealt>,
 }
 
 impl<'s> System<'s> for CharacterHitEffectSystem {
@@ -65,9 +81,12 @@ impl<'s> System<'s> for CharacterHitEffectSystem {
             mirroreds,
             character_hit_transitionses,
             mut health_pointses,
+            mut guard_pointses,
             mut stun_pointses,
             mut velocities,
             mut sequence_ids,
+            spawn_parents,
+            mut damage_dealts,
         }: Self::SystemData,
     ) {
         // Read from channel
@@ -82,9 +101,14 @@ impl<'s> System<'s> for CharacterHitEffectSystem {
                     .get(ev.from)
                     .map(|mirrored| **mirrored)
                     .unwrap_or(false);
+                let mirrored_to = mirroreds
+                    .get(ev.to)
+                    .map(|mirrored| **mirrored)
+                    .unwrap_or(false);
 
                 let character_hit_transitions = character_hit_transitionses.get(ev.to);
                 let health_points = health_pointses.get_mut(ev.to);
+                let guard_points = guard_pointses.get_mut(ev.to);
                 let stun_points = stun_pointses.get_mut(ev.to);
                 let velocity = velocities.get_mut(ev.to);
                 let sequence_id = sequence_ids.get_mut(ev.to);
@@ -92,18 +116,20 @@ impl<'s> System<'s> for CharacterHitEffectSystem {
                 if let (
                     Some(character_hit_transitions),
                     Some(health_points),
+                    Some(guard_points),
                     Some(stun_points),
                     Some(velocity),
                     Some(sequence_id),
                 ) = (
                     character_hit_transitions,
                     health_points,
+                    guard_points,
                     stun_points,
                     velocity,
                     sequence_id,
                 ) {
                     // TODO: Split this system with health check system.
-                    let Interaction {
+                    let (hp_damage, stun, acceleration) = if let Interaction {
                         kind:
                             InteractionKind::Hit(Hit {
                                 hp_damage,
@@ -112,33 +138,76 @@ impl<'s> System<'s> for CharacterHitEffectSystem {
                                 ..
                             }),
                         ..
-                    } = ev.interaction;
-                    if health_points.0 < hp_damage {
-                        *health_points = HealthPoints(0);
+                    } = ev.interaction
+                    {
+                        (hp_damage, stun, acceleration)
                     } else {
-                        (*health_points) -= hp_damage;
-                    }
+                        return;
+                    };
 
-                    *stun_points += stun;
+                    // Hits are only blocked when the defending character faces the hitter.
+                    let defend_sequence_id = character_hit_transitions
+                        .defend
+                        .filter(|defend| *defend == *sequence_id && mirrored != mirrored_to);
+                    let hp_damage = if defend_sequence_id.is_some() {
+                        hp_damage / BLOCK_CHIP_DAMAGE_DIVISOR
+                    } else {
+                        hp_damage
+                    };
+
+                    let hp_damage = std::cmp::min(health_points.0, hp_damage);
+                    (*health_points) -= hp_damage;
+
+                    // Damage from spawned objects is credited to the object that spawned them.
+                    let attacker = spawn_parents
+                        .get(ev.from)
+                        .map(|spawn_parent| spawn_parent.entity)
+                        .unwrap_or(ev.from);
+                    if let Ok(damage_dealt) = damage_dealts.entry(attacker) {
+                        *damage_dealt.or_insert_with(DamageDealt::default) += hp_damage;
+                    }
 
                     if mirrored {
                         velocity.x -= (*acceleration).x as f32;
                     } else {
                         velocity.x += (*acceleration).x as f32;
                     }
-                    velocity.y += (*acceleration).y as f32;
-                    velocity.z += (*acceleration).z as f32;
-
-                    let next_sequence_id = if *health_points == 0 {
-                        character_hit_transitions.falling
-                    } else if *stun_points < STUN_THRESHOLD_LOW {
-                        character_hit_transitions.low_stun
-                    } else if *stun_points < STUN_THRESHOLD_MID {
-                        character_hit_transitions.mid_stun
-                    } else if *stun_points < STUN_THRESHOLD_HIGH {
-                        character_hit_transitions.high_stun
+
+                    let next_sequence_id = if let Some(defend_sequence_id) = defend_sequence_id {
+                        // Blocked hits wear down the guard instead of stunning the character.
+                        //
+                        // A broken guard is recovered by the `GuardPointsRecoverySystem`.
+                        let guard_broken = guard_points.0 <= stun.0;
+                        if guard_broken {
+                            *guard_points = GuardPoints(0);
+                        } else {
+                            (*guard_points) -= stun.0;
+                        }
+
+                        if *health_points == 0 {
+                            character_hit_transitions.falling
+                        } else if guard_broken {
+                            character_hit_transitions.high_stun
+                        } else {
+                            defend_sequence_id
+                        }
                     } else {
-                        character_hit_transitions.falling
+                        *stun_points += stun;
+
+                        velocity.y += (*acceleration).y as f32;
+                        velocity.z += (*acceleration).z as f32;
+
+                        if *health_points == 0 {
+                            character_hit_transitions.falling
+                        } else if *stun_points < STUN_THRESHOLD_LOW {
+                            character_hit_transitions.low_stun
+                        } else if *stun_points < STUN_THRESHOLD_MID {
+                            character_hit_transitions.mid_stun
+                        } else if *stun_points < STUN_THRESHOLD_HIGH {
+                            character_hit_transitions.high_stun
+                        } else {
+                            character_hit_transitions.falling
+                        }
                     };
 
                     // Set sequence id
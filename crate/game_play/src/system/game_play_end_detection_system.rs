@@ -1,13 +1,16 @@
 use std::collections::HashMap;
 
 use amethyst::{
-    ecs::{Join, ReadStorage, System, World, Write},
+    ecs::{Join, Read, ReadStorage, System, World, Write},
     shred::{ResourceId, SystemData},
     shrev::EventChannel,
 };
 use derivative::Derivative;
 use derive_new::new;
-use game_play_model::{GamePlayEvent, GamePlayStatus};
+use game_play_model::{
+    play::{StageProgress, STAGE_PLAYER_TEAM},
+    GameMode, GamePlayEvent, GamePlayStatus,
+};
 use game_stats_model::play::{WinOutcome, WinStatus};
 use object_model::play::HealthPoints;
 use team_model::play::Team;
@@ -41,6 +44,12 @@ pub struct GamePlayEndDetectionSystemData<'s> {
     /// `WinStatus` resource.
     #[derivative(Debug = "ignore")]
     pub win_status: Write<'s, WinStatus>,
+    /// `GameMode` resource.
+    #[derivative(Debug = "ignore")]
+    pub game_mode: Read<'s, GameMode>,
+    /// `StageProgress` resource.
+    #[derivative(Debug = "ignore")]
+    pub stage_progress: Read<'s, StageProgress>,
 }
 
 impl GamePlayEndDetectionSystem {
@@ -50,6 +59,8 @@ impl GamePlayEndDetectionSystem {
         GamePlayEndDetectionSystemData {
             teams,
             health_pointses,
+            game_mode,
+            stage_progress,
             ..
         }: &mut GamePlayEndDetectionSystemData,
     ) -> Option<WinStatus> {
@@ -65,6 +76,15 @@ impl GamePlayEndDetectionSystem {
                 .next()
                 .copied()
                 .expect("Expected `Team` entry to exist.");
+
+            // In stage mode, players only win once every wave has been cleared.
+            if **game_mode == GameMode::Stage
+                && winning_team == STAGE_PLAYER_TEAM
+                && !stage_progress.is_complete()
+            {
+                return None;
+            }
+
             let win_outcome = WinOutcome::WinLoss { winning_team };
             let win_status = WinStatus::new(win_outcome);
             Some(win_status)
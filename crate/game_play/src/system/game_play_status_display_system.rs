@@ -1,5 +1,6 @@
 use std::convert::TryInto;
 
+use ai_model::play::AiControlled;
 use amethyst::{
     ecs::{Entities, Join, Read, ReadExpect, ReadStorage, System, World, WriteStorage},
     shred::{ResourceId, SystemData},
@@ -58,6 +59,9 @@ pub struct GamePlayStatusDisplaySystemData<'s> {
     /// `InputControlled` components.
     #[derivative(Debug = "ignore")]
     pub input_controlleds: ReadStorage<'s, InputControlled>,
+    /// `AiControlled` components.
+    #[derivative(Debug = "ignore")]
+    pub ai_controlleds: ReadStorage<'s, AiControlled>,
     /// `PlayerControllers` resource.
     #[derivative(Debug = "ignore")]
     pub player_controllers: Read<'s, PlayerControllers>,
@@ -93,16 +97,17 @@ impl GamePlayStatusDisplaySystem {
         win_status: WinStatus,
         teams: &ReadStorage<'_, Team>,
         input_controlleds: &ReadStorage<'_, InputControlled>,
+        ai_controlleds: &ReadStorage<'_, AiControlled>,
         player_controllers: &PlayerControllers,
     ) -> String {
         match win_status.outcome {
             WinOutcome::None => String::from("Ongoing Match"),
             WinOutcome::WinLoss { winning_team } => {
-                let winner = (teams, input_controlleds)
-                    .join()
-                    .find(|(team, _)| **team == winning_team)
-                    .map(|(team, input_controlled)| match team {
-                        Team::Independent(..) => {
+                let winner = match winning_team {
+                    Team::Independent(..) => (teams, input_controlleds)
+                        .join()
+                        .find(|(team, _)| **team == winning_team)
+                        .map(|(_, input_controlled)| {
                             let controller_id = input_controlled.controller_id;
                             player_controllers
                                 .get(
@@ -121,10 +126,16 @@ impl GamePlayStatusDisplaySystem {
 
                                     String::from("")
                                 })
-                        }
-                        Team::Number(team_counter) => format!("Team {}", team_counter),
-                    })
-                    .expect("Expected entity for winning team to exist.");
+                        })
+                        .or_else(|| {
+                            (teams, ai_controlleds)
+                                .join()
+                                .find(|(team, _)| **team == winning_team)
+                                .map(|_| String::from("CPU"))
+                        })
+                        .expect("Expected entity for winning team to exist."),
+                    Team::Number(team_counter) => format!("Team {}", team_counter),
+                };
                 format!("Winner: {}", winner)
             }
             WinOutcome::Draw => String::from("Draw"),
@@ -143,6 +154,7 @@ impl<'s> System<'s> for GamePlayStatusDisplaySystem {
             win_status,
             teams,
             input_controlleds,
+            ai_controlleds,
             player_controllers,
             mut game_play_status_entities,
             mut game_play_entities,
@@ -187,6 +199,7 @@ impl<'s> System<'s> for GamePlayStatusDisplaySystem {
                         *win_status,
                         &teams,
                         &input_controlleds,
+                        &ai_controlleds,
                         &player_controllers,
                     );
 
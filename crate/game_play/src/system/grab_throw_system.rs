@@ -0,0 +1,141 @@
+use amethyst::{
+    ecs::{Entity, Read, ReadStorage, System, World, WriteStorage},
+    shred::{ResourceId, SystemData},
+    shrev::{EventChannel, ReaderId},
+};
+use collision_model::{
+    config::Grab,
+    loaded::{ThrowingTransition, ThrownTransition},
+    play::{GrabTracker, GrabbedBy},
+};
+use derivative::Derivative;
+use derive_new::new;
+use game_input_model::{
+    config::ControlAction,
+    play::{ControlActionEventData, ControlInputEvent},
+};
+use kinematic_model::config::Velocity;
+use mirrored_model::play::Mirrored;
+use sequence_model::loaded::SequenceId;
+
+/// Throws the grabbed object when the grabber presses `Attack`.
+#[derive(Debug, Default, new)]
+pub struct GrabThrowSystem {
+    /// Reader ID for the `ControlInputEvent` channel.
+    #[new(default)]
+    control_input_event_rid: Option<ReaderId<ControlInputEvent>>,
+}
+
+/// `GrabThrowSystemData`.
+#[derive(Derivative, SystemData)]
+#[derivative(Debug)]
+pub struct GrabThrowSystemData<'s> {
+    /// `ControlInputEvent` channel.
+    #[derivative(Debug = "ignore")]
+    pub control_input_ec: Read<'s, EventChannel<ControlInputEvent>>,
+    /// `ThrowingTransition` components.
+    #[derivative(Debug = "ignore")]
+    pub throwing_transitions: ReadStorage<'s, ThrowingTransition>,
+    /// `ThrownTransition` components.
+    #[derivative(Debug = "ignore")]
+    pub thrown_transitions: ReadStorage<'s, ThrownTransition>,
+    /// `Mirrored` components.
+    #[derivative(Debug = "ignore")]
+    pub mirroreds: ReadStorage<'s, Mirrored>,
+    /// `GrabTracker` components.
+    #[derivative(Debug = "ignore")]
+    pub grab_trackers: WriteStorage<'s, GrabTracker>,
+    /// `GrabbedBy` components.
+    #[derivative(Debug = "ignore")]
+    pub grabbed_bys: WriteStorage<'s, GrabbedBy>,
+    /// `SequenceId` components.
+    #[derivative(Debug = "ignore")]
+    pub sequence_ids: WriteStorage<'s, SequenceId>,
+    /// `Velocity<f32>` components.
+    #[derivative(Debug = "ignore")]
+    pub velocities: WriteStorage<'s, Velocity<f32>>,
+}
+
+impl GrabThrowSystem {
+    fn throw(
+        GrabThrowSystemData {
+            throwing_transitions,
+            thrown_transitions,
+            mirroreds,
+            grab_trackers,
+            grabbed_bys,
+            sequence_ids,
+            velocities,
+            ..
+        }: &mut GrabThrowSystemData<'_>,
+        grabber: Entity,
+    ) {
+        let (grabbed, throw_velocity) = if let Some(GrabTracker {
+            entity,
+            grab: Grab { throw_velocity, .. },
+            ..
+        }) = grab_trackers.remove(grabber)
+        {
+            (entity, throw_velocity)
+        } else {
+            return;
+        };
+        grabbed_bys.remove(grabbed);
+
+        if let Some(ThrowingTransition(sequence_id)) = throwing_transitions.get(grabber).copied() {
+            let _ = sequence_ids.insert(grabber, sequence_id);
+        }
+        if let Some(ThrownTransition(sequence_id)) = thrown_transitions.get(grabbed).copied() {
+            let _ = sequence_ids.insert(grabbed, sequence_id);
+        }
+
+        let mirrored = mirroreds.get(grabber).copied().unwrap_or_default();
+        let mut velocity = Velocity::<f32>::new(
+            throw_velocity.x as f32,
+            throw_velocity.y as f32,
+            throw_velocity.z as f32,
+        );
+        if mirrored.0 {
+            velocity.x = -velocity.x;
+        }
+        let _ = velocities.insert(grabbed, velocity);
+    }
+}
+
+impl<'s> System<'s> for GrabThrowSystem {
+    type SystemData = GrabThrowSystemData<'s>;
+
+    fn run(&mut self, mut grab_throw_system_data: Self::SystemData) {
+        let control_input_event_rid = self
+            .control_input_event_rid
+            .as_mut()
+            .expect("Expected `control_input_event_rid` field to be set.");
+
+        let grabbers = grab_throw_system_data
+            .control_input_ec
+            .read(control_input_event_rid)
+            .filter_map(|ev| match ev {
+                ControlInputEvent::ControlActionPress(ControlActionEventData {
+                    entity,
+                    control_action: ControlAction::Attack,
+                    ..
+                }) => Some(*entity),
+                _ => None,
+            })
+            .collect::<Vec<Entity>>();
+
+        grabbers
+            .into_iter()
+            .for_each(|grabber| Self::throw(&mut grab_throw_system_data, grabber));
+    }
+
+    fn setup(&mut self, world: &mut World) {
+        Self::SystemData::setup(world);
+
+        self.control_input_event_rid = Some(
+            world
+                .fetch_mut::<EventChannel<ControlInputEvent>>()
+                .register_reader(),
+        );
+    }
+}
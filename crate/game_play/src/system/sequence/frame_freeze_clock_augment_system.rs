@@ -51,15 +51,16 @@ impl<'s> System<'s> for FrameFreezeClockAugmentSystem {
             )
             .for_each(|ev| {
                 // Only add `FrameFreezeClock` for `Hit` interactions.
-                let Interaction {
+                if let Interaction {
                     kind: InteractionKind::Hit(_),
                     ..
-                } = ev.interaction;
-
-                let frame_freeze_clock = FrameFreezeClock::new(3);
-                frame_freeze_clocks
-                    .insert(ev.from, frame_freeze_clock)
-                    .expect("Failed to insert `FrameFreezeClock`.");
+                } = ev.interaction
+                {
+                    let frame_freeze_clock = FrameFreezeClock::new(3);
+                    frame_freeze_clocks
+                        .insert(ev.from, frame_freeze_clock)
+                        .expect("Failed to insert `FrameFreezeClock`.");
+                }
             });
     }
 
@@ -0,0 +1,253 @@
+use std::str::FromStr;
+
+use amethyst::{
+    assets::AssetStorage,
+    ecs::{Entity, Read, ReadStorage, System, World, Write, WriteStorage},
+    shred::{ResourceId, SystemData},
+};
+use camera_model::play::CameraLock;
+use character_model::config::CharacterSequenceName;
+use derivative::Derivative;
+use derive_new::new;
+use game_play_model::{
+    play::{StageProgress, STAGE_ENEMY_TEAM},
+    GameMode, GamePlayEntity, GamePlayStatus,
+};
+use kinematic_model::config::{Position, Velocity};
+use log::error;
+use map_model::{config::MapDefinition, loaded::AssetMapDefinitionHandle};
+use map_selection_model::MapSelection;
+use object_model::play::HealthPoints;
+use sequence_model::{
+    config::SequenceNameString,
+    loaded::{AssetSequenceIdMappings, SequenceId},
+};
+use spawn_model::{config, loaded::Spawn};
+use spawn_play::{GameObjectSpawner, SpawnGameObjectResources};
+use team_model::play::Team;
+
+/// Spawns the waves of enemies declared by the map when playing a stage.
+///
+/// The next wave is spawned once every enemy in the current wave has been defeated. The camera
+/// is locked while enemies remain.
+#[derive(Debug, Default, new)]
+pub struct StageWaveSystem;
+
+/// `StageWaveSystemData`.
+#[derive(Derivative, SystemData)]
+#[derivative(Debug)]
+pub struct StageWaveSystemData<'s> {
+    /// `GameMode` resource.
+    #[derivative(Debug = "ignore")]
+    pub game_mode: Read<'s, GameMode>,
+    /// `GamePlayStatus` resource.
+    #[derivative(Debug = "ignore")]
+    pub game_play_status: Read<'s, GamePlayStatus>,
+    /// `MapSelection` resource.
+    #[derivative(Debug = "ignore")]
+    pub map_selection: Read<'s, MapSelection>,
+    /// `AssetMapDefinitionHandle` resource.
+    #[derivative(Debug = "ignore")]
+    pub asset_map_definition_handle: Read<'s, AssetMapDefinitionHandle>,
+    /// `MapDefinition` assets.
+    #[derivative(Debug = "ignore")]
+    pub map_definition_assets: Read<'s, AssetStorage<MapDefinition>>,
+    /// `AssetSequenceIdMappings<CharacterSequenceName>` resource.
+    #[derivative(Debug = "ignore")]
+    pub asset_sequence_id_mappings_character:
+        Read<'s, AssetSequenceIdMappings<CharacterSequenceName>>,
+    /// `StageProgress` resource.
+    #[derivative(Debug = "ignore")]
+    pub stage_progress: Write<'s, StageProgress>,
+    /// `CameraLock` resource.
+    #[derivative(Debug = "ignore")]
+    pub camera_lock: Write<'s, CameraLock>,
+    /// `HealthPoints` components.
+    #[derivative(Debug = "ignore")]
+    pub health_pointses: ReadStorage<'s, HealthPoints>,
+    /// `Position<f32>` components.
+    #[derivative(Debug = "ignore")]
+    pub positions: WriteStorage<'s, Position<f32>>,
+    /// `Team` components.
+    #[derivative(Debug = "ignore")]
+    pub teams: WriteStorage<'s, Team>,
+    /// `GamePlayEntity` components.
+    #[derivative(Debug = "ignore")]
+    pub game_play_entities: WriteStorage<'s, GamePlayEntity>,
+    /// `SpawnGameObjectResources`.
+    pub spawn_game_object_resources: SpawnGameObjectResources<'s>,
+}
+
+impl StageWaveSystem {
+    /// Returns the stage entity, creating it if it does not exist.
+    fn stage_entity(
+        StageWaveSystemData {
+            stage_progress,
+            positions,
+            teams,
+            game_play_entities,
+            spawn_game_object_resources,
+            ..
+        }: &mut StageWaveSystemData,
+    ) -> Entity {
+        if let Some(stage_entity) = stage_progress.stage_entity {
+            return stage_entity;
+        }
+
+        let stage_entity = spawn_game_object_resources.entities.create();
+        positions
+            .insert(stage_entity, Position::default())
+            .expect("Failed to insert `Position<f32>` component.");
+        teams
+            .insert(stage_entity, STAGE_ENEMY_TEAM)
+            .expect("Failed to insert `Team` component.");
+        game_play_entities
+            .insert(stage_entity, GamePlayEntity)
+            .expect("Failed to insert `GamePlayEntity` component.");
+
+        stage_progress.stage_entity = Some(stage_entity);
+        stage_entity
+    }
+
+    /// Returns the loaded `Spawn` for a spawn declared in a `MapPhase`.
+    fn spawn_loaded(
+        asset_sequence_id_mappings_character: &AssetSequenceIdMappings<CharacterSequenceName>,
+        spawn_game_object_resources: &SpawnGameObjectResources<'_>,
+        spawn_config: &config::Spawn,
+    ) -> Option<Spawn> {
+        let spawn_asset_slug = &spawn_config.object;
+        let object = if let Some(asset_id) = spawn_game_object_resources
+            .asset_id_mappings
+            .id(spawn_asset_slug)
+            .copied()
+        {
+            asset_id
+        } else {
+            error!("Asset ID not found for `{}`.", spawn_asset_slug);
+            return None;
+        };
+
+        let position = {
+            let position_config = spawn_config.position;
+            Position::<f32>::new(
+                position_config.x as f32,
+                position_config.y as f32,
+                position_config.z as f32,
+            )
+        };
+        let velocity = {
+            let velocity_config = spawn_config.velocity;
+            Velocity::<f32>::new(
+                velocity_config.x as f32,
+                velocity_config.y as f32,
+                velocity_config.z as f32,
+            )
+        };
+
+        let sequence_id = asset_sequence_id_mappings_character
+            .get(object)
+            .and_then(|sequence_id_mappings| {
+                let sequence_name_string = spawn_config
+                    .sequence
+                    .as_ref()
+                    .map(|sequence_string| {
+                        SequenceNameString::from_str(sequence_string)
+                            .expect("Expected `SequenceNameString::from_str` to succeed.")
+                    })
+                    .unwrap_or_else(|| SequenceNameString::from(CharacterSequenceName::default()));
+
+                sequence_id_mappings.id(&sequence_name_string).copied()
+            })
+            .unwrap_or_else(|| SequenceId::new(0));
+
+        Some(Spawn {
+            object,
+            position,
+            velocity,
+            sequence_id,
+        })
+    }
+}
+
+impl<'s> System<'s> for StageWaveSystem {
+    type SystemData = StageWaveSystemData<'s>;
+
+    fn run(&mut self, mut stage_wave_system_data: Self::SystemData) {
+        if *stage_wave_system_data.game_mode != GameMode::Stage
+            || *stage_wave_system_data.game_play_status != GamePlayStatus::Playing
+        {
+            return;
+        }
+
+        let map_phase = {
+            let StageWaveSystemData {
+                map_selection,
+                asset_map_definition_handle,
+                map_definition_assets,
+                stage_progress,
+                health_pointses,
+                spawn_game_object_resources,
+                ..
+            } = &mut stage_wave_system_data;
+
+            let map_definition = map_selection
+                .asset_id()
+                .and_then(|asset_id| asset_map_definition_handle.get(asset_id))
+                .and_then(|map_definition_handle| map_definition_assets.get(map_definition_handle));
+            let map_definition = if let Some(map_definition) = map_definition {
+                map_definition
+            } else {
+                return;
+            };
+            stage_progress.phase_count = map_definition.phases.len();
+
+            let entities = &spawn_game_object_resources.entities;
+            stage_progress.enemies.retain(|enemy| {
+                entities.is_alive(*enemy)
+                    && health_pointses
+                        .get(*enemy)
+                        .map(|health_points| *health_points > 0)
+                        .unwrap_or(false)
+            });
+
+            if stage_progress.is_wave_cleared() {
+                map_definition
+                    .phases
+                    .get(stage_progress.phase_index)
+                    .cloned()
+            } else {
+                None
+            }
+        };
+
+        if let Some(map_phase) = map_phase {
+            let stage_entity = Self::stage_entity(&mut stage_wave_system_data);
+
+            let StageWaveSystemData {
+                asset_sequence_id_mappings_character,
+                stage_progress,
+                spawn_game_object_resources,
+                ..
+            } = &mut stage_wave_system_data;
+            map_phase.spawns.iter().for_each(|spawn_config| {
+                if let Some(spawn) = Self::spawn_loaded(
+                    asset_sequence_id_mappings_character,
+                    spawn_game_object_resources,
+                    spawn_config,
+                ) {
+                    let entity_spawned =
+                        GameObjectSpawner::spawn(spawn_game_object_resources, stage_entity, &spawn);
+                    stage_progress.enemies.push(entity_spawned);
+                }
+            });
+            stage_progress.phase_index += 1;
+        }
+
+        *stage_wave_system_data.camera_lock =
+            if stage_wave_system_data.stage_progress.is_wave_cleared() {
+                CameraLock::Unlocked
+            } else {
+                CameraLock::Locked
+            };
+    }
+}
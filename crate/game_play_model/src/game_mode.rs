@@ -0,0 +1,14 @@
+use derivative::Derivative;
+use serde::{Deserialize, Serialize};
+
+/// Mode of play for a game.
+#[derive(Clone, Copy, Debug, Derivative, Deserialize, PartialEq, Eq, Serialize)]
+#[derivative(Default)]
+#[serde(rename_all = "snake_case")]
+pub enum GameMode {
+    /// Characters fight until at most one team remains.
+    #[derivative(Default)]
+    Versus,
+    /// Players cooperate to defeat the waves of enemies declared by the map.
+    Stage,
+}
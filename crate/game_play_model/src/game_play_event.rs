@@ -13,4 +13,8 @@ pub enum GamePlayEvent {
     End,
     /// Signals to go to the round statistics.
     EndStats,
+    /// Captures the state of the round.
+    SaveState,
+    /// Restores the state of the round captured by the last `SaveState`.
+    LoadState,
 }
@@ -11,6 +11,8 @@ use structopt_derive::StructOpt;
 /// * `game_play resume`
 /// * `game_play end`
 /// * `game_play end_stats`
+/// * `game_play save_state`
+/// * `game_play load_state`
 #[derive(Clone, Copy, Debug, Deserialize, PartialEq, Serialize, StructOpt)]
 #[serde(deny_unknown_fields, rename_all = "snake_case")]
 #[structopt(rename_all = "snake_case")]
@@ -27,4 +29,8 @@ pub enum GamePlayEventArgs {
     End,
     /// Signals to go to the round statistics.
     EndStats,
+    /// Captures the state of the round.
+    SaveState,
+    /// Restores the state of the round captured by the last `SaveState`.
+    LoadState,
 }
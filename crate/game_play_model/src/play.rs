@@ -2,8 +2,12 @@
 
 pub use self::{
     game_play_end_transition_delay_clock::GamePlayEndTransitionDelayClock,
+    game_play_seed::GamePlaySeed,
     game_play_status_entity::GamePlayStatusEntity,
+    stage_progress::{StageProgress, STAGE_ENEMY_TEAM, STAGE_PLAYER_TEAM},
 };
 
 mod game_play_end_transition_delay_clock;
+mod game_play_seed;
 mod game_play_status_entity;
+mod stage_progress;
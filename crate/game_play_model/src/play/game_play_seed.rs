@@ -0,0 +1,14 @@
+use serde::{Deserialize, Serialize};
+
+/// Seed for random number generation during game play.
+///
+/// This is recorded in replays, so that a match can be played back deterministically.
+#[derive(Clone, Copy, Debug, Default, Deserialize, Hash, PartialEq, Eq, Serialize)]
+pub struct GamePlaySeed(pub u64);
+
+impl GamePlaySeed {
+    /// Returns a seed generated from system entropy.
+    pub fn random() -> Self {
+        GamePlaySeed(rand::random())
+    }
+}
@@ -0,0 +1,33 @@
+use amethyst::ecs::Entity;
+use team_model::play::{Team, TeamCounter};
+
+/// Team that players are on when playing a stage.
+pub const STAGE_PLAYER_TEAM: Team = Team::Number(TeamCounter(0));
+
+/// Team that stage enemies are on.
+pub const STAGE_ENEMY_TEAM: Team = Team::Number(TeamCounter(1));
+
+/// Tracks the waves of enemies spawned when playing a stage.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct StageProgress {
+    /// Entity that stage enemies are spawned from.
+    pub stage_entity: Option<Entity>,
+    /// Index of the next `MapPhase` to spawn.
+    pub phase_index: usize,
+    /// Number of `MapPhase`s in the stage.
+    pub phase_count: usize,
+    /// Enemies in the current wave that have not been defeated.
+    pub enemies: Vec<Entity>,
+}
+
+impl StageProgress {
+    /// Returns whether the current wave has been cleared.
+    pub fn is_wave_cleared(&self) -> bool {
+        self.enemies.is_empty()
+    }
+
+    /// Returns whether every wave in the stage has been spawned and cleared.
+    pub fn is_complete(&self) -> bool {
+        self.phase_index >= self.phase_count && self.is_wave_cleared()
+    }
+}
@@ -19,6 +19,8 @@ impl StdinMapper for GamePlayEventStdinMapper {
             GamePlayEventArgs::Resume => Ok(GamePlayEvent::Resume),
             GamePlayEventArgs::End => Ok(GamePlayEvent::End),
             GamePlayEventArgs::EndStats => Ok(GamePlayEvent::EndStats),
+            GamePlayEventArgs::SaveState => Ok(GamePlayEvent::SaveState),
+            GamePlayEventArgs::LoadState => Ok(GamePlayEvent::LoadState),
         }
     }
 }
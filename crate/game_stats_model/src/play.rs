@@ -1,6 +1,7 @@
 //! Contains data types used at runtime.
 
-pub use self::{win_outcome::WinOutcome, win_status::WinStatus};
+pub use self::{damage_dealt::DamageDealt, win_outcome::WinOutcome, win_status::WinStatus};
 
+mod damage_dealt;
 mod win_outcome;
 mod win_status;
@@ -0,0 +1,10 @@
+use amethyst::ecs::{storage::VecStorage, Component};
+use derive_more::{Add, AddAssign, Display, From, Sub, SubAssign};
+use numeric_newtype_derive::numeric_newtype;
+use serde::{Deserialize, Serialize};
+
+/// Health points of damage that an object has dealt to others.
+#[numeric_newtype]
+#[derive(Component, Debug, Default, Deserialize, Hash, Serialize)]
+#[storage(VecStorage)]
+pub struct DamageDealt(pub u32);
@@ -1,9 +1,11 @@
 use derivative::Derivative;
+use serde::{Deserialize, Serialize};
 use team_model::play::Team;
 
 /// Outcome of a round.
-#[derive(Clone, Copy, Debug, Derivative, PartialEq)]
+#[derive(Clone, Copy, Debug, Derivative, Deserialize, PartialEq, Serialize)]
 #[derivative(Default)]
+#[serde(rename_all = "snake_case")]
 pub enum WinOutcome {
     /// There is currently no outcome.
     #[derivative(Default)]
@@ -4,11 +4,11 @@ use amethyst::assets::{AssetStorage, Loader};
 use game_input_model::config::{Axis, ControlAction};
 use input_reaction_model::{
     config::{
-        self, ButtonInputReaction, ButtonInputReactionN, InputReactionAppEvents,
-        InputReactionSingle,
+        self, ButtonInputReaction, ButtonInputReactionN, CommandInputReaction,
+        CommandInputReactionN, InputReactionAppEvents, InputReactionSingle,
     },
     loaded::{
-        self, AxisTransition, FallbackTransition, InputReaction, InputReactions,
+        self, AxisTransition, CommandTransition, FallbackTransition, InputReaction, InputReactions,
         InputReactionsSequence, InputReactionsSequenceHandle, ReactionEffect, ReactionEffectButton,
         ReactionEffectData,
     },
@@ -141,6 +141,16 @@ where
             };
         }
 
+        // Command transitions are pushed first, so that they take priority over the transition
+        // for the last input of the command.
+        Self::push_command_reactions(
+            input_reactions_default,
+            input_reactions_sequence,
+            input_reactions_frame,
+            sequence_id_mappings,
+            &mut input_reactions_loaded,
+        );
+
         push_action_reactions!(press_defend, ActionPress, Defend);
         push_action_reactions!(press_jump, ActionPress, Jump);
         push_action_reactions!(press_attack, ActionPress, Attack);
@@ -187,6 +197,68 @@ where
         loader.load_from_data(input_reactions, (), input_reactions_assets)
     }
 
+    fn push_command_reactions(
+        input_reactions_default: Option<&config::InputReactions<SeqName, IRR>>,
+        input_reactions_sequence: Option<&config::InputReactions<SeqName, IRR>>,
+        input_reactions_frame: &config::InputReactions<SeqName, IRR>,
+        sequence_id_mappings: &SequenceIdMappings<SeqName>,
+        input_reactions_loaded: &mut Vec<InputReaction<IRR>>,
+    ) {
+        let command_input_reaction_n = input_reactions_frame.command.as_ref().or_else(|| {
+            // We want to make sure that, if `input_reactions_sequence.is_some()`, but
+            // the transition inside is `None`, we still fallback to `None`. This allows
+            // a sequence transition `None` value to override the default transition.
+            input_reactions_sequence
+                .or(input_reactions_default)
+                .and_then(|input_reactions_fallback| input_reactions_fallback.command.as_ref())
+        });
+        if let Some(command_input_reaction_n) = command_input_reaction_n {
+            match command_input_reaction_n {
+                CommandInputReactionN::One(command_input_reaction) => {
+                    Self::push_command_reaction(
+                        sequence_id_mappings,
+                        input_reactions_loaded,
+                        command_input_reaction,
+                    );
+                }
+                CommandInputReactionN::Many(command_input_reactions) => {
+                    command_input_reactions
+                        .iter()
+                        .for_each(|command_input_reaction| {
+                            Self::push_command_reaction(
+                                sequence_id_mappings,
+                                input_reactions_loaded,
+                                command_input_reaction,
+                            );
+                        });
+                }
+            };
+        }
+    }
+
+    fn push_command_reaction(
+        sequence_id_mappings: &SequenceIdMappings<SeqName>,
+        input_reactions_loaded: &mut Vec<InputReaction<IRR>>,
+        command_input_reaction: &CommandInputReaction<SeqName, IRR>,
+    ) {
+        let command = &command_input_reaction.command;
+        Self::load_input_reactions(
+            sequence_id_mappings,
+            input_reactions_loaded,
+            Some(command_input_reaction),
+            |sequence_id, events, requirement| {
+                InputReaction::<IRR>::new(
+                    ReactionEffect::Command(CommandTransition {
+                        command: command.clone(),
+                        sequence_id,
+                        events,
+                    }),
+                    requirement,
+                )
+            },
+        );
+    }
+
     fn push_fallback_reaction(
         input_reactions_default: Option<&config::InputReactions<SeqName, IRR>>,
         input_reactions_sequence: Option<&config::InputReactions<SeqName, IRR>>,
@@ -3,7 +3,9 @@
 pub use self::{
     basic_irr::BasicIrr, basic_irr_params::BasicIrrParams, basic_irr_part::BasicIrrPart,
     button_input_reaction::ButtonInputReaction, button_input_reaction_n::ButtonInputReactionN,
-    button_input_reactions::ButtonInputReactions, input_reaction::InputReaction,
+    button_input_reactions::ButtonInputReactions, command_input_reaction::CommandInputReaction,
+    command_input_reaction_n::CommandInputReactionN,
+    command_input_reactions::CommandInputReactions, input_reaction::InputReaction,
     input_reaction_app_event::InputReactionAppEvent,
     input_reaction_app_events::InputReactionAppEvents,
     input_reaction_multiple::InputReactionMultiple,
@@ -17,6 +19,9 @@ mod basic_irr_part;
 mod button_input_reaction;
 mod button_input_reaction_n;
 mod button_input_reactions;
+mod command_input_reaction;
+mod command_input_reaction_n;
+mod command_input_reactions;
 mod input_reaction;
 mod input_reaction_app_event;
 mod input_reaction_app_events;
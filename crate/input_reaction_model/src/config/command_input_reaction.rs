@@ -0,0 +1,39 @@
+use derive_new::new;
+use game_input_model::config::InputCommand;
+use sequence_model::config::SequenceName;
+use serde::{Deserialize, Serialize};
+
+use crate::config::InputReaction;
+
+/// Reaction when a command input sequence is entered.
+///
+/// ```yaml
+/// command:
+///   inputs: [defend, forward, attack]
+///   window: 20
+///   next: "sequence_name"
+/// ```
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize, new)]
+#[serde(rename_all = "snake_case")]
+pub struct CommandInputReaction<SeqName, IRR>
+where
+    SeqName: SequenceName,
+    IRR: Default,
+{
+    /// Inputs of the command, and the number of ticks they must be entered within.
+    #[serde(flatten)]
+    pub command: InputCommand,
+    /// Variants of how an `InputReaction` may be specified.
+    #[serde(flatten)]
+    pub reaction: InputReaction<SeqName, IRR>,
+}
+
+impl<SeqName, IRR> AsRef<InputReaction<SeqName, IRR>> for CommandInputReaction<SeqName, IRR>
+where
+    SeqName: SequenceName,
+    IRR: Default,
+{
+    fn as_ref(&self) -> &InputReaction<SeqName, IRR> {
+        &self.reaction
+    }
+}
@@ -0,0 +1,22 @@
+use derive_new::new;
+use sequence_model::config::SequenceName;
+use serde::{Deserialize, Serialize};
+
+use crate::config::{CommandInputReaction, CommandInputReactions};
+
+/// Variants of how `CommandInputReaction`s may be specified.
+///
+/// This is primarily to make it more ergonomic for users to specify different kinds of values in
+/// configuration.
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize, new)]
+#[serde(deny_unknown_fields, rename_all = "snake_case", untagged)]
+pub enum CommandInputReactionN<SeqName, IRR>
+where
+    SeqName: SequenceName,
+    IRR: Default,
+{
+    /// Single command input reaction.
+    One(CommandInputReaction<SeqName, IRR>),
+    /// List of command input reactions in decreasing priority order.
+    Many(CommandInputReactions<SeqName, IRR>),
+}
@@ -0,0 +1,15 @@
+use derive_deref::{Deref, DerefMut};
+use derive_new::new;
+use sequence_model::config::SequenceName;
+use serde::{Deserialize, Serialize};
+
+use crate::config::CommandInputReaction;
+
+/// Reactions when command input sequences are entered (`Vec<CommandInputReaction<SeqName, IRR>>`
+/// newtype).
+#[derive(Clone, Debug, Deref, DerefMut, Deserialize, PartialEq, Serialize, new)]
+#[serde(deny_unknown_fields)]
+pub struct CommandInputReactions<SeqName, IRR>(pub Vec<CommandInputReaction<SeqName, IRR>>)
+where
+    SeqName: SequenceName,
+    IRR: Default;
@@ -3,7 +3,7 @@ use derive_new::new;
 use sequence_model::config::SequenceName;
 use serde::{Deserialize, Serialize};
 
-use crate::config::{BasicIrr, ButtonInputReactionN, InputReaction};
+use crate::config::{BasicIrr, ButtonInputReactionN, CommandInputReactionN, InputReaction};
 
 /// Sequence to transition to when a `ControlAction` is pressed, held, or released.
 #[derive(Clone, Debug, Derivative, Deserialize, PartialEq, Serialize, new)]
@@ -86,6 +86,12 @@ where
     #[new(default)]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub release_z: Option<InputReaction<SeqName, IRR>>,
+    /// Sequence to transition to when a command input sequence is entered.
+    ///
+    /// Command reactions take priority over reactions to the last input of the command.
+    #[new(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub command: Option<CommandInputReactionN<SeqName, IRR>>,
     /// Fallback sequence to transition to.
     #[new(default)]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -2,6 +2,7 @@
 
 pub use self::{
     axis_transition::AxisTransition,
+    command_transition::CommandTransition,
     fallback_transition::FallbackTransition,
     input_reaction::InputReaction,
     input_reactions::{InputReactions, InputReactionsHandle},
@@ -13,6 +14,7 @@ pub use self::{
 };
 
 mod axis_transition;
+mod command_transition;
 mod fallback_transition;
 mod input_reaction;
 mod input_reactions;
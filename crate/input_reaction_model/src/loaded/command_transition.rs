@@ -0,0 +1,16 @@
+use derive_new::new;
+use game_input_model::config::InputCommand;
+use sequence_model::loaded::SequenceId;
+
+use crate::config::InputReactionAppEvents;
+
+/// Transition to a specified sequence when a command input sequence is entered.
+#[derive(Clone, Debug, PartialEq, new)]
+pub struct CommandTransition {
+    /// Command that this transition applies to.
+    pub command: InputCommand,
+    /// ID of the sequence to switch to.
+    pub sequence_id: SequenceId,
+    /// Events to send.
+    pub events: InputReactionAppEvents,
+}
@@ -1,7 +1,9 @@
 use amethyst::ecs::{storage::VecStorage, Component};
 use derive_new::new;
 
-use crate::loaded::{AxisTransition, FallbackTransition, ReactionEffectButton, ReactionEffectData};
+use crate::loaded::{
+    AxisTransition, CommandTransition, FallbackTransition, ReactionEffectButton, ReactionEffectData,
+};
 
 /// Sequence to transition to on control input.
 #[derive(Clone, Component, Debug, PartialEq, new)]
@@ -19,6 +21,8 @@ pub enum ReactionEffect {
     AxisHold(AxisTransition),
     /// Transition to a specified sequence on axis input press event.
     AxisRelease(AxisTransition),
+    /// Transition to a specified sequence when a command input sequence is entered.
+    Command(CommandTransition),
     /// Transition to a specified fallback sequence.
     Fallback(FallbackTransition),
     /// Transition to a specified sequence on device button press event.
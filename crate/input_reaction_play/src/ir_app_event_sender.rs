@@ -134,6 +134,8 @@ impl IrAppEventSender {
             GamePlayEventArgs::Resume => GamePlayEvent::Resume,
             GamePlayEventArgs::End => GamePlayEvent::End,
             GamePlayEventArgs::EndStats => GamePlayEvent::EndStats,
+            GamePlayEventArgs::SaveState => GamePlayEvent::SaveState,
+            GamePlayEventArgs::LoadState => GamePlayEvent::LoadState,
         };
 
         ir_app_event_sender_system_data
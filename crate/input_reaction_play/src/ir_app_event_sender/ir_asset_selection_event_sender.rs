@@ -109,6 +109,10 @@ impl IrAssetSelectionEventSender {
                     controller_id,
                 })
             }
+            AssetSelectionEventCommand::CpuSwitch => Some(AssetSelectionEvent::CpuSwitch {
+                entity: Some(ash_entity),
+                controller_id,
+            }),
             AssetSelectionEventCommand::Confirm => {
                 if Self::asset_selection_confirm_preconditions_met(
                     ir_app_event_sender_system_data,
@@ -17,10 +17,14 @@ impl IrSessionHostEventSender {
         host_event_command: SessionHostEventCommand,
     ) {
         let host_event = match host_event_command {
-            SessionHostEventCommand::SessionHostRequest => {
-                if let Some(host_request_params) =
-                    Self::host_request_params_discover(ir_app_event_sender_system_data)
-                {
+            SessionHostEventCommand::SessionHostRequest
+            | SessionHostEventCommand::SessionHostPublicRequest => {
+                let session_public =
+                    host_event_command == SessionHostEventCommand::SessionHostPublicRequest;
+                if let Some(host_request_params) = Self::host_request_params_discover(
+                    ir_app_event_sender_system_data,
+                    session_public,
+                ) {
                     Some(SessionHostEvent::SessionHostRequest(host_request_params))
                 } else {
                     // TODO: Feedback that the form needs to be filled.
@@ -40,6 +44,7 @@ impl IrSessionHostEventSender {
 
     fn host_request_params_discover(
         ir_app_event_sender_system_data: &IrAppEventSenderSystemData,
+        session_public: bool,
     ) -> Option<SessionHostRequestParams> {
         let IrAppEventSenderSystemData {
             player_controllers,
@@ -61,6 +66,7 @@ impl IrSessionHostEventSender {
             Some(SessionHostRequestParams::new(
                 session_device_name,
                 player_controllers,
+                session_public,
             ))
         } else {
             None
@@ -1,7 +1,9 @@
 use amethyst::ecs::Entity;
 use network_session_model::play::{SessionCode, SessionDeviceName};
 use session_join_model::{
-    config::SessionJoinEventCommand, play::SessionJoinRequestParams, SessionJoinEvent,
+    config::SessionJoinEventCommand,
+    play::{SessionJoinRequestParams, SessionSpectateRequestParams},
+    SessionJoinEvent,
 };
 
 use crate::IrAppEventSenderSystemData;
@@ -29,6 +31,18 @@ impl IrSessionJoinEventSender {
                     None
                 }
             }
+            SessionJoinEventCommand::SessionSpectateRequest => {
+                if let Some((session_code, session_device_name)) =
+                    Self::session_code_and_device_name_discover(ir_app_event_sender_system_data)
+                {
+                    Some(SessionJoinEvent::SessionSpectateRequest(
+                        SessionSpectateRequestParams::new(session_code, session_device_name),
+                    ))
+                } else {
+                    // TODO: Feedback that the form needs to be filled.
+                    None
+                }
+            }
             SessionJoinEventCommand::JoinCancel => Some(SessionJoinEvent::JoinCancel),
             SessionJoinEventCommand::Back => Some(SessionJoinEvent::Back),
         };
@@ -43,8 +57,19 @@ impl IrSessionJoinEventSender {
     fn session_join_request_params_discover(
         ir_app_event_sender_system_data: &IrAppEventSenderSystemData,
     ) -> Option<SessionJoinRequestParams> {
+        let player_controllers = (*ir_app_event_sender_system_data.player_controllers).clone();
+
+        Self::session_code_and_device_name_discover(ir_app_event_sender_system_data).map(
+            |(session_code, session_device_name)| {
+                SessionJoinRequestParams::new(session_code, session_device_name, player_controllers)
+            },
+        )
+    }
+
+    fn session_code_and_device_name_discover(
+        ir_app_event_sender_system_data: &IrAppEventSenderSystemData,
+    ) -> Option<(SessionCode, SessionDeviceName)> {
         let IrAppEventSenderSystemData {
-            player_controllers,
             ui_form_input_entities,
             ui_texts,
             ..
@@ -62,15 +87,9 @@ impl IrSessionJoinEventSender {
             .map(|ui_text| ui_text.text.clone())
             .map(SessionCode::new);
 
-        let player_controllers = (*player_controllers).clone();
-
         if let (Some(session_device_name), Some(session_code)) = (session_device_name, session_code)
         {
-            Some(SessionJoinRequestParams::new(
-                session_code,
-                session_device_name,
-                player_controllers,
-            ))
+            Some((session_code, session_device_name))
         } else {
             None
         }
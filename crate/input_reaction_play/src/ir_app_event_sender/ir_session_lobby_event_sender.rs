@@ -1,5 +1,7 @@
 use session_lobby_model::{
-    config::SessionLobbyEventCommand, play::SessionStartRequestParams, SessionLobbyEvent,
+    config::SessionLobbyEventCommand,
+    play::{SessionReadyRequestParams, SessionStartRequestParams},
+    SessionLobbyEvent,
 };
 
 use crate::IrAppEventSenderSystemData;
@@ -19,6 +21,14 @@ impl IrSessionLobbyEventSender {
                 let session_start_request_params = SessionStartRequestParams::new(session_code);
                 SessionLobbyEvent::SessionStartRequest(session_start_request_params)
             }
+            SessionLobbyEventCommand::SessionReadyToggle => {
+                let session_device_id = *ir_app_event_sender_system_data.session_device_id;
+                let ready = !ir_app_event_sender_system_data
+                    .session_device_readies
+                    .contains(&session_device_id);
+                let session_ready_request_params = SessionReadyRequestParams::new(ready);
+                SessionLobbyEvent::SessionReadyRequest(session_ready_request_params)
+            }
             SessionLobbyEventCommand::Back => SessionLobbyEvent::Back,
         };
 
@@ -10,17 +10,17 @@ use approx::{relative_eq, relative_ne};
 use derivative::Derivative;
 use derive_new::new;
 use game_input_model::{
-    config::{Axis, ControlAction},
+    config::{Axis, CommandInput, ControlAction},
     play::{
-        AxisMoveEventData, ControlActionEventData, ControlInputEvent, ControllerInput,
-        InputControlled,
+        AxisMoveEventData, ControlActionEventData, ControlInputEvent, ControlInputHistory,
+        ControllerInput, InputControlled,
     },
 };
 use input_reaction_model::{
     config::{InputReactionAppEvents, InputReactionRequirement},
     loaded::{
-        AxisTransition, FallbackTransition, InputReaction, InputReactions, InputReactionsHandle,
-        ReactionEffect, ReactionEffectData,
+        AxisTransition, CommandTransition, FallbackTransition, InputReaction, InputReactions,
+        InputReactionsHandle, ReactionEffect, ReactionEffectData,
     },
 };
 use sequence_model::loaded::SequenceId;
@@ -74,6 +74,9 @@ where
     /// `ControllerInput` components.
     #[derivative(Debug = "ignore")]
     pub controller_inputs: ReadStorage<'s, ControllerInput>,
+    /// `ControlInputHistory` components.
+    #[derivative(Debug = "ignore")]
+    pub control_input_histories: ReadStorage<'s, ControlInputHistory>,
     /// `InputControlled` components.
     #[derivative(Debug = "ignore")]
     pub input_controlleds: ReadStorage<'s, InputControlled>,
@@ -100,6 +103,7 @@ where
         InputReactionsTransitionResources {
             entities: ref _entities,
             ref controller_inputs,
+            ref control_input_histories,
             ref input_reactions_handles,
             ref input_reactions_assets,
             ref mut sequence_ids,
@@ -123,6 +127,7 @@ where
             let input_reactions = input_reactions_assets
                 .get(input_reactions_handle)
                 .expect("Expected `InputReactions` to be loaded.");
+            let control_input_history = control_input_histories.get(entity);
 
             let transition_sequence_id = input_reactions
                 .iter()
@@ -130,6 +135,20 @@ where
                     let input_reaction_requirement = &input_reaction.requirement;
 
                     match &input_reaction.effect {
+                        ReactionEffect::Command(command_transition) => {
+                            if value {
+                                Self::command_transition(
+                                    command_transition,
+                                    control_input_history,
+                                    |input| input == CommandInput::from(control_action),
+                                )
+                                .map(|(transition, events)| {
+                                    (transition, events, input_reaction_requirement)
+                                })
+                            } else {
+                                None
+                            }
+                        }
                         ReactionEffect::ActionPress(ReactionEffectData {
                             action,
                             sequence_id,
@@ -194,6 +213,7 @@ where
         InputReactionsTransitionResources {
             entities: ref _entities,
             ref controller_inputs,
+            ref control_input_histories,
             ref input_reactions_handles,
             ref input_reactions_assets,
             ref mut sequence_ids,
@@ -217,6 +237,7 @@ where
             let input_reactions = input_reactions_assets
                 .get(input_reactions_handle)
                 .expect("Expected `InputReactions` to be loaded.");
+            let control_input_history = control_input_histories.get(entity);
 
             let transition_sequence_id = input_reactions
                 .iter()
@@ -224,6 +245,20 @@ where
                     let input_reaction_requirement = &input_reaction.requirement;
 
                     match &input_reaction.effect {
+                        ReactionEffect::Command(command_transition) => {
+                            if relative_ne!(0., value) {
+                                Self::command_transition(
+                                    command_transition,
+                                    control_input_history,
+                                    |input| input.axis() == Some(control_axis),
+                                )
+                                .map(|(transition, events)| {
+                                    (transition, events, input_reaction_requirement)
+                                })
+                            } else {
+                                None
+                            }
+                        }
                         ReactionEffect::AxisPress(AxisTransition {
                             axis,
                             sequence_id,
@@ -292,6 +327,7 @@ where
         InputReactionsTransitionResources {
             ref entities,
             ref controller_inputs,
+            control_input_histories: ref _control_input_histories,
             ref input_controlleds,
             ref input_reactions_handles,
             ref input_reactions_assets,
@@ -460,6 +496,42 @@ where
         }
     } // kcov-ignore
 
+    /// Returns the transition sequence ID if the command was entered, ending on the current
+    /// input.
+    ///
+    /// # Parameters
+    ///
+    /// * `command_transition`: `InputCommand` and sequence ID the command transition applies to.
+    /// * `control_input_history`: Inputs recently entered for the entity.
+    /// * `input_matches_fn`: Whether the last input of the command is the current input.
+    fn command_transition<'f, F>(
+        CommandTransition {
+            command,
+            sequence_id,
+            events,
+        }: &'f CommandTransition,
+        control_input_history: Option<&ControlInputHistory>,
+        input_matches_fn: F,
+    ) -> Option<(SequenceId, &'f InputReactionAppEvents)>
+    where
+        F: Fn(CommandInput) -> bool,
+    {
+        let input_last_matches = command
+            .inputs
+            .last()
+            .copied()
+            .map_or(false, input_matches_fn);
+        let command_entered = control_input_history.map_or(false, |control_input_history| {
+            control_input_history.matches(command)
+        });
+
+        if input_last_matches && command_entered {
+            Some((*sequence_id, events))
+        } else {
+            None
+        }
+    }
+
     fn process_transition<'f>(
         requirement_system_data: &mut IRR::SystemData,
         entity: Entity,
@@ -14,10 +14,10 @@ use game_input_model::{loaded::PlayerControllers, play::InputControlled};
 use game_mode_selection_model::GameModeSelectionEvent;
 use game_play_model::GamePlayEvent;
 use network_mode_selection_model::NetworkModeSelectionEvent;
-use network_session_model::play::SessionCode;
+use network_session_model::play::{SessionCode, SessionDeviceId};
 use session_host_model::SessionHostEvent;
 use session_join_model::SessionJoinEvent;
-use session_lobby_model::SessionLobbyEvent;
+use session_lobby_model::{play::SessionDeviceReadies, SessionLobbyEvent};
 use state_registry::StateId;
 use ui_form_model::play::UiFormInputEntities;
 
@@ -49,6 +49,12 @@ pub struct IrAppEventSenderSystemData<'s> {
     /// `SessionCode` resource.
     #[derivative(Debug = "ignore")]
     pub session_code: Read<'s, SessionCode>,
+    /// `SessionDeviceId` resource.
+    #[derivative(Debug = "ignore")]
+    pub session_device_id: Read<'s, SessionDeviceId>,
+    /// `SessionDeviceReadies` resource.
+    #[derivative(Debug = "ignore")]
+    pub session_device_readies: Read<'s, SessionDeviceReadies>,
 
     /// `UiFormInputEntities` resource.
     #[derivative(Debug = "ignore")]
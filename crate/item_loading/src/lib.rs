@@ -0,0 +1,7 @@
+#![deny(missing_debug_implementations, missing_docs)] // kcov-ignore
+
+//! Processes item configuration into the loaded item model.
+
+pub use crate::item_loading_bundle::ItemLoadingBundle;
+
+mod item_loading_bundle;
@@ -0,0 +1,14 @@
+use asset_derive::Asset;
+use derive_new::new;
+use object_model::config::ObjectDefinition;
+use serde::{Deserialize, Serialize};
+
+use crate::config::ItemSequence;
+
+/// Contains all of the sequences for an `Item`.
+#[derive(Asset, Clone, Debug, Default, Deserialize, PartialEq, Serialize, new)]
+pub struct ItemDefinition {
+    /// Sequences of actions this object can perform.
+    #[serde(flatten)]
+    pub object_definition: ObjectDefinition<ItemSequence>,
+}
@@ -0,0 +1,11 @@
+//! Contains the types that represent processed configuration.
+//!
+//! This differs from the plain configuration types as they would have been processed into the form
+//! that will be used in game.
+
+pub use self::{
+    asset_item_definition_handle::AssetItemDefinitionHandle, item_transitions::ItemTransitions,
+};
+
+mod asset_item_definition_handle;
+mod item_transitions;
@@ -0,0 +1,17 @@
+use amethyst::ecs::{storage::DenseVecStorage, Component};
+use derive_new::new;
+use sequence_model::loaded::SequenceId;
+
+/// Sequences to transition to when an item is picked up, thrown, or lands.
+///
+/// This component also marks the entity as an item that may be picked up.
+#[derive(Clone, Component, Copy, Debug, PartialEq, new)]
+#[storage(DenseVecStorage)]
+pub struct ItemTransitions {
+    /// Sequence ID to transition to when the item is resting on the ground.
+    pub rest: SequenceId,
+    /// Sequence ID to transition to when the item is picked up.
+    pub held: SequenceId,
+    /// Sequence ID to transition to when the item is thrown.
+    pub thrown: SequenceId,
+}
@@ -0,0 +1,6 @@
+//! Types used during game play.
+
+pub use self::{held_by::HeldBy, held_item::HeldItem};
+
+mod held_by;
+mod held_item;
@@ -0,0 +1,25 @@
+use amethyst::ecs::{storage::DenseVecStorage, world::EntitiesRes, Component, Entity};
+use derive_new::new;
+use snapshot_model::play::{SnapshotComponent, SnapshotEntity};
+
+/// Links an item entity to the entity holding it.
+///
+/// This component should be attached to the item entity. The holder entity has a `HeldItem`
+/// component linking back to the item.
+///
+/// Unlike `ParentEntity`, this does not cause the item to be deleted with its holder, and does not
+/// conflict with the `ParentEntity` of spawned items.
+#[derive(Clone, Component, Copy, Debug, PartialEq, new)]
+pub struct HeldBy(pub Entity);
+
+impl SnapshotComponent for HeldBy {
+    type Data = SnapshotEntity;
+
+    fn to_snapshot(&self) -> Self::Data {
+        SnapshotEntity::from(self.0)
+    }
+
+    fn from_snapshot(snapshot_entity: Self::Data, entities: &EntitiesRes) -> Option<Self> {
+        snapshot_entity.entity(entities).map(HeldBy)
+    }
+}
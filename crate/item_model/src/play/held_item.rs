@@ -0,0 +1,22 @@
+use amethyst::ecs::{storage::DenseVecStorage, world::EntitiesRes, Component, Entity};
+use derive_new::new;
+use snapshot_model::play::{SnapshotComponent, SnapshotEntity};
+
+/// Links a holder entity to the item entity it holds.
+///
+/// This component should be attached to the holder entity. The item entity has a `HeldBy`
+/// component linking back to the holder.
+#[derive(Clone, Component, Copy, Debug, PartialEq, new)]
+pub struct HeldItem(pub Entity);
+
+impl SnapshotComponent for HeldItem {
+    type Data = SnapshotEntity;
+
+    fn to_snapshot(&self) -> Self::Data {
+        SnapshotEntity::from(self.0)
+    }
+
+    fn from_snapshot(snapshot_entity: Self::Data, entities: &EntitiesRes) -> Option<Self> {
+        snapshot_entity.entity(entities).map(HeldItem)
+    }
+}
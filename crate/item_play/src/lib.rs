@@ -0,0 +1,10 @@
+#![deny(missing_debug_implementations, missing_docs)] // kcov-ignore
+
+//! Provides logic for items used during game play.
+
+pub use crate::system::{
+    ItemFollowHolderSystem, ItemLandSystem, ItemPickupSystem, ItemThrowSystem,
+    ITEM_THROW_HIT_REPEAT_DELAY, ITEM_THROW_VELOCITY,
+};
+
+mod system;
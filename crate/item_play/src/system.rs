@@ -0,0 +1,11 @@
+pub use self::{
+    item_follow_holder_system::ItemFollowHolderSystem,
+    item_land_system::ItemLandSystem,
+    item_pickup_system::ItemPickupSystem,
+    item_throw_system::{ItemThrowSystem, ITEM_THROW_HIT_REPEAT_DELAY, ITEM_THROW_VELOCITY},
+};
+
+mod item_follow_holder_system;
+mod item_land_system;
+mod item_pickup_system;
+mod item_throw_system;
@@ -0,0 +1,99 @@
+use amethyst::{
+    core::Transform,
+    ecs::{Entities, Join, ReadStorage, System, World, WriteStorage},
+    shred::{ResourceId, SystemData},
+};
+use derivative::Derivative;
+use derive_new::new;
+use item_model::{loaded::ItemTransitions, play::HeldBy};
+use kinematic_model::config::Position;
+use mirrored_model::play::Mirrored;
+use sequence_model::loaded::SequenceId;
+
+/// Updates a held item's `Position`, `Translation`, and `Mirrored` to match its holder.
+///
+/// Items whose holder has been deleted are dropped.
+#[derive(Debug, Default, new)]
+pub struct ItemFollowHolderSystem;
+
+#[derive(Derivative, SystemData)]
+#[derivative(Debug)]
+pub struct ItemFollowHolderSystemData<'s> {
+    /// `Entities` resource.
+    #[derivative(Debug = "ignore")]
+    pub entities: Entities<'s>,
+    /// `ItemTransitions` components.
+    #[derivative(Debug = "ignore")]
+    pub item_transitionses: ReadStorage<'s, ItemTransitions>,
+    /// `HeldBy` components.
+    #[derivative(Debug = "ignore")]
+    pub held_bys: WriteStorage<'s, HeldBy>,
+    /// `SequenceId` components.
+    #[derivative(Debug = "ignore")]
+    pub sequence_ids: WriteStorage<'s, SequenceId>,
+    /// `Position<f32>` components.
+    #[derivative(Debug = "ignore")]
+    pub positions: WriteStorage<'s, Position<f32>>,
+    /// `Transform` components.
+    #[derivative(Debug = "ignore")]
+    pub transforms: WriteStorage<'s, Transform>,
+    /// `Mirrored` components.
+    #[derivative(Debug = "ignore")]
+    pub mirroreds: WriteStorage<'s, Mirrored>,
+}
+
+impl<'s> System<'s> for ItemFollowHolderSystem {
+    type SystemData = ItemFollowHolderSystemData<'s>;
+
+    fn run(
+        &mut self,
+        ItemFollowHolderSystemData {
+            entities,
+            item_transitionses,
+            mut held_bys,
+            mut sequence_ids,
+            mut positions,
+            mut transforms,
+            mut mirroreds,
+        }: Self::SystemData,
+    ) {
+        // Drop items whose holder no longer exists, so that they fall and may be picked up again.
+        let items_dropped = (&entities, &item_transitionses, &held_bys)
+            .join()
+            .filter(|(_, _, held_by)| !entities.is_alive(held_by.0))
+            .map(|(item_entity, item_transitions, _)| (item_entity, item_transitions.thrown))
+            .collect::<Vec<_>>();
+        items_dropped
+            .into_iter()
+            .for_each(|(item_entity, sequence_id_thrown)| {
+                held_bys.remove(item_entity);
+                let _ = sequence_ids.insert(item_entity, sequence_id_thrown);
+            });
+
+        (&entities, &item_transitionses, &held_bys)
+            .join()
+            .for_each(|(item_entity, _, held_by)| {
+                let holder = held_by.0;
+
+                let holder_position = positions.get(holder).copied();
+                if let Some(holder_position) = holder_position {
+                    if let Some(position) = positions.get_mut(item_entity) {
+                        *position = holder_position;
+                    }
+                }
+
+                let holder_translation =
+                    transforms.get(holder).map(Transform::translation).copied();
+                if let Some(translation) = holder_translation {
+                    if let Some(transform) = transforms.get_mut(item_entity) {
+                        *transform.translation_mut() = translation;
+                    }
+                }
+
+                let holder_mirrored = mirroreds.get(holder).copied();
+                if let Some(holder_mirrored) = holder_mirrored {
+                    let _ = mirroreds.insert(item_entity, holder_mirrored);
+                }
+            });
+    } // kcov-ignore
+}
@@ -0,0 +1,64 @@
+use amethyst::{
+    ecs::{Join, ReadStorage, System, World, WriteStorage},
+    shred::{ResourceId, SystemData},
+};
+use derivative::Derivative;
+use derive_new::new;
+use item_model::loaded::ItemTransitions;
+use kinematic_model::config::Velocity;
+use object_model::play::Grounding;
+use sequence_model::loaded::SequenceId;
+
+/// Transitions thrown items to their `rest` sequence when they land.
+///
+/// Items that are still moving upwards are not considered landed, so an item thrown from the
+/// ground is not transitioned on the tick it is thrown.
+#[derive(Debug, Default, new)]
+pub struct ItemLandSystem;
+
+#[derive(Derivative, SystemData)]
+#[derivative(Debug)]
+pub struct ItemLandSystemData<'s> {
+    /// `ItemTransitions` components.
+    #[derivative(Debug = "ignore")]
+    pub item_transitionses: ReadStorage<'s, ItemTransitions>,
+    /// `Grounding` components.
+    #[derivative(Debug = "ignore")]
+    pub groundings: ReadStorage<'s, Grounding>,
+    /// `Velocity<f32>` components.
+    #[derivative(Debug = "ignore")]
+    pub velocities: ReadStorage<'s, Velocity<f32>>,
+    /// `SequenceId` components.
+    #[derivative(Debug = "ignore")]
+    pub sequence_ids: WriteStorage<'s, SequenceId>,
+}
+
+impl<'s> System<'s> for ItemLandSystem {
+    type SystemData = ItemLandSystemData<'s>;
+
+    fn run(
+        &mut self,
+        ItemLandSystemData {
+            item_transitionses,
+            groundings,
+            velocities,
+            mut sequence_ids,
+        }: Self::SystemData,
+    ) {
+        (
+            &item_transitionses,
+            &groundings,
+            &velocities,
+            &mut sequence_ids,
+        )
+            .join()
+            .filter(|(item_transitions, grounding, velocity, sequence_id)| {
+                **sequence_id == item_transitions.thrown
+                    && **grounding == Grounding::OnGround
+                    && velocity[1] <= 0.
+            })
+            .for_each(|(item_transitions, _, _, sequence_id)| {
+                *sequence_id = item_transitions.rest;
+            });
+    }
+}
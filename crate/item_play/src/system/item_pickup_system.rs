@@ -0,0 +1,119 @@
+use amethyst::{
+    ecs::{Read, ReadStorage, System, World, WriteStorage},
+    shred::{ResourceId, SystemData},
+    shrev::{EventChannel, ReaderId},
+};
+use collision_model::{
+    config::{Interaction, InteractionKind},
+    play::ContactEvent,
+};
+use derivative::Derivative;
+use derive_new::new;
+use item_model::{
+    loaded::ItemTransitions,
+    play::{HeldBy, HeldItem},
+};
+use kinematic_model::config::Velocity;
+use sequence_model::loaded::SequenceId;
+
+/// Picks up items when a `Pickup` interaction contacts an item.
+///
+/// An object may only hold one item at a time, and an item may only be held by one object.
+#[derive(Debug, Default, new)]
+pub struct ItemPickupSystem {
+    /// Reader ID for the `ContactEvent` channel.
+    #[new(default)]
+    contact_event_rid: Option<ReaderId<ContactEvent>>,
+}
+
+#[derive(Derivative, SystemData)]
+#[derivative(Debug)]
+pub struct ItemPickupSystemData<'s> {
+    /// `ContactEvent` channel.
+    #[derivative(Debug = "ignore")]
+    pub contact_ec: Read<'s, EventChannel<ContactEvent>>,
+    /// `ItemTransitions` components.
+    #[derivative(Debug = "ignore")]
+    pub item_transitionses: ReadStorage<'s, ItemTransitions>,
+    /// `HeldItem` components.
+    #[derivative(Debug = "ignore")]
+    pub held_items: WriteStorage<'s, HeldItem>,
+    /// `HeldBy` components.
+    #[derivative(Debug = "ignore")]
+    pub held_bys: WriteStorage<'s, HeldBy>,
+    /// `SequenceId` components.
+    #[derivative(Debug = "ignore")]
+    pub sequence_ids: WriteStorage<'s, SequenceId>,
+    /// `Velocity<f32>` components.
+    #[derivative(Debug = "ignore")]
+    pub velocities: WriteStorage<'s, Velocity<f32>>,
+}
+
+impl<'s> System<'s> for ItemPickupSystem {
+    type SystemData = ItemPickupSystemData<'s>;
+
+    fn run(
+        &mut self,
+        ItemPickupSystemData {
+            contact_ec,
+            item_transitionses,
+            mut held_items,
+            mut held_bys,
+            mut sequence_ids,
+            mut velocities,
+        }: Self::SystemData,
+    ) {
+        let contact_event_rid = self
+            .contact_event_rid
+            .as_mut()
+            .expect("Expected `contact_event_rid` field to be set.");
+
+        contact_ec
+            .read(contact_event_rid)
+            .filter(|ev| {
+                if let Interaction {
+                    kind: InteractionKind::Pickup(..),
+                    ..
+                } = ev.interaction
+                {
+                    true
+                } else {
+                    false
+                }
+            })
+            .for_each(|ev| {
+                let holder = ev.from;
+                let item = ev.to;
+
+                let item_transitions = item_transitionses.get(item).copied();
+                let holder_has_item = held_items.contains(holder);
+                let item_is_held = held_bys.contains(item);
+
+                if let (Some(item_transitions), false, false) =
+                    (item_transitions, holder_has_item, item_is_held)
+                {
+                    held_items
+                        .insert(holder, HeldItem::new(item))
+                        .expect("Failed to insert `HeldItem` component.");
+                    held_bys
+                        .insert(item, HeldBy::new(holder))
+                        .expect("Failed to insert `HeldBy` component.");
+                    let _ = sequence_ids.insert(item, item_transitions.held);
+
+                    if let Some(velocity) = velocities.get_mut(item) {
+                        *velocity = Velocity::default();
+                    }
+                }
+            });
+    }
+
+    fn setup(&mut self, world: &mut World) {
+        Self::SystemData::setup(world);
+
+        self.contact_event_rid = Some(
+            world
+                .fetch_mut::<EventChannel<ContactEvent>>()
+                .register_reader(),
+        );
+    }
+}
@@ -0,0 +1,163 @@
+use amethyst::{
+    ecs::{Entities, Entity, Join, ReadStorage, System, World, WriteStorage},
+    shred::{ResourceId, SystemData},
+};
+use collision_model::play::{GrabTracker, HitRepeatClock, HitRepeatTracker, HitRepeatTrackers};
+use derivative::Derivative;
+use derive_new::new;
+use game_input_model::{
+    config::{CommandInput, InputCommand},
+    play::ControlInputHistory,
+};
+use item_model::{
+    loaded::ItemTransitions,
+    play::{HeldBy, HeldItem},
+};
+use kinematic_model::config::Velocity;
+use mirrored_model::play::Mirrored;
+use sequence_model::loaded::SequenceId;
+use slotmap::SlotMap;
+
+/// Velocity of an item when it is thrown, when the thrower is not mirrored.
+pub const ITEM_THROW_VELOCITY: [f32; 3] = [12., 6., 0.];
+
+/// Number of ticks before a thrown item may hit its thrower.
+pub const ITEM_THROW_HIT_REPEAT_DELAY: usize = 20;
+
+/// Throws the held item when the holder enters the throw `InputCommand`.
+///
+/// Objects that are grabbing another object throw the grabbed object instead, so their held item
+/// is not thrown.
+///
+/// This must run after the `ControlInputHistoryUpdateSystem`, so that the command is matched
+/// against the current tick's input.
+#[derive(Debug, new)]
+pub struct ItemThrowSystem {
+    /// Command that throws the held item.
+    #[new(value = "ItemThrowSystem::input_command_default()")]
+    input_command: InputCommand,
+}
+
+impl Default for ItemThrowSystem {
+    fn default() -> Self {
+        ItemThrowSystem::new()
+    }
+}
+
+#[derive(Derivative, SystemData)]
+#[derivative(Debug)]
+pub struct ItemThrowSystemData<'s> {
+    /// `Entities` resource.
+    #[derivative(Debug = "ignore")]
+    pub entities: Entities<'s>,
+    /// `ControlInputHistory` components.
+    #[derivative(Debug = "ignore")]
+    pub control_input_histories: ReadStorage<'s, ControlInputHistory>,
+    /// `ItemTransitions` components.
+    #[derivative(Debug = "ignore")]
+    pub item_transitionses: ReadStorage<'s, ItemTransitions>,
+    /// `Mirrored` components.
+    #[derivative(Debug = "ignore")]
+    pub mirroreds: ReadStorage<'s, Mirrored>,
+    /// `GrabTracker` components.
+    #[derivative(Debug = "ignore")]
+    pub grab_trackers: ReadStorage<'s, GrabTracker>,
+    /// `HeldItem` components.
+    #[derivative(Debug = "ignore")]
+    pub held_items: WriteStorage<'s, HeldItem>,
+    /// `HeldBy` components.
+    #[derivative(Debug = "ignore")]
+    pub held_bys: WriteStorage<'s, HeldBy>,
+    /// `SequenceId` components.
+    #[derivative(Debug = "ignore")]
+    pub sequence_ids: WriteStorage<'s, SequenceId>,
+    /// `Velocity<f32>` components.
+    #[derivative(Debug = "ignore")]
+    pub velocities: WriteStorage<'s, Velocity<f32>>,
+    /// `HitRepeatTrackers` components.
+    #[derivative(Debug = "ignore")]
+    pub hit_repeat_trackerses: WriteStorage<'s, HitRepeatTrackers>,
+}
+
+impl ItemThrowSystem {
+    /// Returns the default command that throws the held item, which is pressing `Attack`.
+    pub fn input_command_default() -> InputCommand {
+        InputCommand::new(vec![CommandInput::Attack], InputCommand::WINDOW_DEFAULT)
+    }
+
+    fn throw(
+        ItemThrowSystemData {
+            item_transitionses,
+            mirroreds,
+            grab_trackers,
+            held_items,
+            held_bys,
+            sequence_ids,
+            velocities,
+            hit_repeat_trackerses,
+            ..
+        }: &mut ItemThrowSystemData<'_>,
+        holder: Entity,
+    ) {
+        if grab_trackers.contains(holder) {
+            return;
+        }
+
+        let item = if let Some(HeldItem(item)) = held_items.remove(holder) {
+            item
+        } else {
+            return;
+        };
+        held_bys.remove(item);
+
+        if let Some(item_transitions) = item_transitionses.get(item) {
+            let _ = sequence_ids.insert(item, item_transitions.thrown);
+        }
+
+        let mirrored = mirroreds.get(holder).copied().unwrap_or_default();
+        let mut velocity = Velocity::<f32>::from(ITEM_THROW_VELOCITY);
+        if mirrored.0 {
+            velocity.x = -velocity.x;
+        }
+        let _ = velocities.insert(item, velocity);
+
+        // Prevent the item from hitting the thrower as it leaves the thrower's hands.
+        let hit_repeat_tracker =
+            HitRepeatTracker::new(holder, HitRepeatClock::new(ITEM_THROW_HIT_REPEAT_DELAY));
+        match hit_repeat_trackerses.get_mut(item) {
+            Some(hit_repeat_trackers) => {
+                hit_repeat_trackers
+                    .retain(|_, hit_repeat_tracker| hit_repeat_tracker.entity != holder);
+                hit_repeat_trackers.insert(hit_repeat_tracker);
+            }
+            None => {
+                let mut slot_map = SlotMap::new();
+                slot_map.insert(hit_repeat_tracker);
+                hit_repeat_trackerses
+                    .insert(item, HitRepeatTrackers::new(slot_map))
+                    .expect("Failed to insert `HitRepeatTrackers`.");
+            }
+        }
+    }
+}
+
+impl<'s> System<'s> for ItemThrowSystem {
+    type SystemData = ItemThrowSystemData<'s>;
+
+    fn run(&mut self, mut item_throw_system_data: Self::SystemData) {
+        let input_command = &self.input_command;
+        let holders = (
+            &item_throw_system_data.entities,
+            &item_throw_system_data.held_items,
+            &item_throw_system_data.control_input_histories,
+        )
+            .join()
+            .filter(|(_, _, control_input_history)| control_input_history.matches(input_command))
+            .map(|(holder, _, _)| holder)
+            .collect::<Vec<Entity>>();
+
+        holders
+            .into_iter()
+            .for_each(|holder| Self::throw(&mut item_throw_system_data, holder));
+    }
+}
@@ -0,0 +1,85 @@
+use amethyst::ecs::Entity;
+use asset_model::loaded::AssetId;
+use collision_model::loaded::{HitTransition, HittingTransition};
+use item_model::{config::ItemSequenceName, loaded::ItemTransitions};
+use log::error;
+use map_model::play::MapBounded;
+use sequence_model::config::SequenceNameString;
+
+use crate::{ItemComponentStorages, ItemSpawningResources};
+
+/// Augments an entity with `Item` components.
+#[derive(Debug)]
+pub struct ItemEntityAugmenter;
+
+impl ItemEntityAugmenter {
+    /// Augments an entity with `Item` components.
+    ///
+    /// # Parameters
+    ///
+    /// * `item_spawning_resources`: Resources needed to spawn the item.
+    /// * `item_component_storages`: Item specific `Component` storages.
+    /// * `asset_id`: Asset ID of the item.
+    /// * `entity`: The entity to augment.
+    pub fn augment<'s>(
+        ItemSpawningResources {
+            asset_sequence_id_mappings_item,
+        }: &ItemSpawningResources<'s>,
+        ItemComponentStorages {
+            map_boundeds,
+            hit_transitions,
+            hitting_transitions,
+            item_transitionses,
+        }: &mut ItemComponentStorages<'s>,
+        asset_id: AssetId,
+        entity: Entity,
+    ) {
+        let sequence_id_mappings = asset_sequence_id_mappings_item
+            .get(asset_id)
+            .unwrap_or_else(|| {
+                panic!(
+                    "Expected `SequenceIdMappings<ItemSequenceName>` to exist for `{:?}`.",
+                    asset_id
+                )
+            });
+        let sequence_id = |sequence_name: ItemSequenceName| {
+            sequence_id_mappings
+                .id(&SequenceNameString::Name(sequence_name))
+                .copied()
+                .unwrap_or_else(|| {
+                    let sequence_default = ItemSequenceName::default();
+                    error!(
+                        "`{}` sequence not found for `{:?}`. Falling back to `{}`.",
+                        sequence_name, asset_id, sequence_default
+                    );
+
+                    sequence_id_mappings
+                        .id(&SequenceNameString::Name(sequence_default))
+                        .copied()
+                        .unwrap_or_else(|| {
+                            panic!(
+                                "`{}` sequence not found for `{:?}`",
+                                sequence_default, asset_id
+                            )
+                        })
+                })
+        };
+        let rest = sequence_id(ItemSequenceName::Rest);
+        let held = sequence_id(ItemSequenceName::Held);
+        let thrown = sequence_id(ItemSequenceName::Thrown);
+        let hit = sequence_id(ItemSequenceName::Hit);
+
+        map_boundeds
+            .insert(entity, MapBounded::default())
+            .expect("Failed to insert `MapBounded` component.");
+        hit_transitions
+            .insert(entity, HitTransition::new(hit))
+            .expect("Failed to insert `HitTransition` component.");
+        hitting_transitions
+            .insert(entity, HittingTransition::new(hit))
+            .expect("Failed to insert `HittingTransition` component.");
+        item_transitionses
+            .insert(entity, ItemTransitions::new(rest, held, thrown))
+            .expect("Failed to insert `ItemTransitions` component.");
+    }
+}
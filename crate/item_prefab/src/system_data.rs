@@ -0,0 +1,6 @@
+pub use self::{
+    item_component_storages::ItemComponentStorages, item_spawning_resources::ItemSpawningResources,
+};
+
+mod item_component_storages;
+mod item_spawning_resources;
@@ -0,0 +1,29 @@
+use amethyst::{
+    ecs::{World, WriteStorage},
+    shred::{ResourceId, SystemData},
+};
+use collision_model::loaded::{HitTransition, HittingTransition};
+use derivative::Derivative;
+use item_model::loaded::ItemTransitions;
+use map_model::play::MapBounded;
+
+/// Item specific `Component` storages.
+///
+/// These are the storages for the components specific to item objects. See also
+/// `ObjectComponentStorages`.
+#[derive(Derivative, SystemData)]
+#[derivative(Debug)]
+pub struct ItemComponentStorages<'s> {
+    /// `MapBounded` component storage.
+    #[derivative(Debug = "ignore")]
+    pub map_boundeds: WriteStorage<'s, MapBounded>,
+    /// `HitTransition` components.
+    #[derivative(Debug = "ignore")]
+    pub hit_transitions: WriteStorage<'s, HitTransition>,
+    /// `HittingTransition` components.
+    #[derivative(Debug = "ignore")]
+    pub hitting_transitions: WriteStorage<'s, HittingTransition>,
+    /// `ItemTransitions` components.
+    #[derivative(Debug = "ignore")]
+    pub item_transitionses: WriteStorage<'s, ItemTransitions>,
+}
@@ -154,6 +154,7 @@ where
                          * `SpriteLoadingBundle`\n\
                          * `CharacterLoadingBundle`\n\
                          * `EnergyLoadingBundle`\n\
+                         * `ItemLoadingBundle`\n\
                          * `BackgroundLoadingBundle`\n\
                          * `MapLoadingBundle`\n\
                          * `amethyst::audio::AudioBundle`\n\
@@ -32,11 +32,13 @@ impl<'s> AssetPartLoader<'s> for AssetDefinitionLoader {
         DefinitionLoadingResources {
             character_definition_assets,
             energy_definition_assets,
+            item_definition_assets,
             map_definition_assets,
             background_definition_assets,
             ui_definition_assets,
             asset_character_definition_handle,
             asset_energy_definition_handle,
+            asset_item_definition_handle,
             asset_map_definition_handle,
             asset_background_definition_handle,
             asset_ui_definition_handle,
@@ -94,6 +96,16 @@ impl<'s> AssetPartLoader<'s> for AssetDefinitionLoader {
 
                         asset_energy_definition_handle.insert(asset_id, energy_definition_handle);
                     }
+                    ObjectType::Item => {
+                        let item_definition_handle = loader.load(
+                            object_definition_path,
+                            YamlFormat,
+                            &mut *progress_counter,
+                            item_definition_assets,
+                        );
+
+                        asset_item_definition_handle.insert(asset_id, item_definition_handle);
+                    }
                     ObjectType::TestObject => panic!("`TestObject` loading is not supported."),
                 }
             }
@@ -166,11 +178,13 @@ impl<'s> AssetPartLoader<'s> for AssetDefinitionLoader {
         DefinitionLoadingResources {
             character_definition_assets,
             energy_definition_assets,
+            item_definition_assets,
             map_definition_assets,
             background_definition_assets,
             ui_definition_assets,
             asset_character_definition_handle,
             asset_energy_definition_handle,
+            asset_item_definition_handle,
             asset_map_definition_handle,
             asset_background_definition_handle,
             asset_ui_definition_handle,
@@ -195,6 +209,12 @@ impl<'s> AssetPartLoader<'s> for AssetDefinitionLoader {
                         energy_definition_assets.get(character_definition_handle)
                     })
                     .is_some(),
+                ObjectType::Item => asset_item_definition_handle
+                    .get(asset_id)
+                    .and_then(|item_definition_handle| {
+                        item_definition_assets.get(item_definition_handle)
+                    })
+                    .is_some(),
                 ObjectType::TestObject => panic!("`TestObject` loading is not supported."),
             },
             AssetType::Map => asset_map_definition_handle
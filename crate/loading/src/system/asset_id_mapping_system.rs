@@ -37,12 +37,14 @@ impl<'s> AssetPartLoader<'s> for AssetIdMapper {
         IdMappingResources {
             asset_sequence_id_mappings_character,
             asset_sequence_id_mappings_energy,
+            asset_sequence_id_mappings_item,
             ..
         }: &mut IdMappingResources<'_>,
     ) {
         let capacity = asset_id_mappings.capacity();
         asset_sequence_id_mappings_character.set_capacity(capacity);
         asset_sequence_id_mappings_energy.set_capacity(capacity);
+        asset_sequence_id_mappings_item.set_capacity(capacity);
     }
 
     /// Map's an asset's sequence IDs.
@@ -58,10 +60,12 @@ impl<'s> AssetPartLoader<'s> for AssetIdMapper {
                 DefinitionLoadingResourcesRead {
                     character_definition_assets,
                     energy_definition_assets,
+                    item_definition_assets,
                     map_definition_assets,
                     ui_definition_assets,
                     asset_character_definition_handle,
                     asset_energy_definition_handle,
+                    asset_item_definition_handle,
                     asset_map_definition_handle,
                     asset_ui_definition_handle,
                     ..
@@ -69,6 +73,7 @@ impl<'s> AssetPartLoader<'s> for AssetIdMapper {
             asset_sequence_id_mappings_sprite,
             asset_sequence_id_mappings_character,
             asset_sequence_id_mappings_energy,
+            asset_sequence_id_mappings_item,
         }: &mut IdMappingResources<'_>,
         asset_id: AssetId,
     ) {
@@ -114,6 +119,19 @@ impl<'s> AssetPartLoader<'s> for AssetIdMapper {
                     );
                     asset_sequence_id_mappings_energy.insert(asset_id, sequence_id_mappings);
                 }
+                ObjectType::Item => {
+                    let item_definition = asset_item_definition_handle
+                        .get(asset_id)
+                        .and_then(|item_definition_handle| {
+                            item_definition_assets.get(item_definition_handle)
+                        })
+                        .expect("Expected `ItemDefinition` to be loaded.");
+
+                    let sequence_id_mappings = SequenceIdMappings::from_iter(
+                        item_definition.object_definition.sequences.keys(),
+                    );
+                    asset_sequence_id_mappings_item.insert(asset_id, sequence_id_mappings);
+                }
                 ObjectType::TestObject => panic!("`TestObject` loading is not supported."),
             },
             AssetType::Map => {
@@ -194,15 +212,18 @@ impl<'s> AssetPartLoader<'s> for AssetIdMapper {
                 DefinitionLoadingResourcesRead {
                     character_definition_assets,
                     energy_definition_assets,
+                    item_definition_assets,
                     ui_definition_assets,
                     asset_character_definition_handle,
                     asset_energy_definition_handle,
+                    asset_item_definition_handle,
                     asset_ui_definition_handle,
                     ..
                 },
             asset_sequence_id_mappings_sprite,
             asset_sequence_id_mappings_character,
             asset_sequence_id_mappings_energy,
+            asset_sequence_id_mappings_item,
         } = id_mapping_resources;
 
         let asset_type = asset_type_mappings
@@ -251,6 +272,25 @@ impl<'s> AssetPartLoader<'s> for AssetIdMapper {
 
                     id_mappings_self && spawn_id_mappings_exist
                 }
+                ObjectType::Item => {
+                    let id_mappings_self = asset_sequence_id_mappings_item.get(asset_id).is_some();
+                    let spawn_id_mappings_exist = {
+                        let item_definition = asset_item_definition_handle
+                            .get(asset_id)
+                            .and_then(|item_definition_handle| {
+                                item_definition_assets.get(item_definition_handle)
+                            })
+                            .expect("Expected `ItemDefinition` to be loaded.");
+
+                        Self::spawn_object_sequence_id_mappings_loaded(
+                            asset_loading_resources,
+                            id_mapping_resources,
+                            &item_definition.object_definition,
+                        )
+                    };
+
+                    id_mappings_self && spawn_id_mappings_exist
+                }
                 ObjectType::TestObject => panic!("`TestObject` loading is not supported."),
             },
             AssetType::Map => asset_sequence_id_mappings_sprite.get(asset_id).is_some(),
@@ -321,6 +361,7 @@ impl AssetIdMapper {
         IdMappingResources {
             asset_sequence_id_mappings_character,
             asset_sequence_id_mappings_energy,
+            asset_sequence_id_mappings_item,
             ..
         }: &IdMappingResources<'_>,
         object_definition: &ObjectDefinition<ObjSeq>,
@@ -353,6 +394,9 @@ impl AssetIdMapper {
                         ObjectType::Energy => asset_sequence_id_mappings_energy
                             .get(spawn_asset_id)
                             .is_some(),
+                        ObjectType::Item => asset_sequence_id_mappings_item
+                            .get(spawn_asset_id)
+                            .is_some(),
                         ObjectType::TestObject => {
                             panic!("Spawning `TestObject`s is not supported.")
                         }
@@ -4,8 +4,10 @@ pub use self::{
     map_bounds::MapBounds,
     map_definition::{MapDefinition, MapDefinitionHandle},
     map_header::MapHeader,
+    map_phase::MapPhase,
 };
 
 mod map_bounds;
 mod map_definition;
 mod map_header;
+mod map_phase;
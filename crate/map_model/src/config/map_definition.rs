@@ -3,7 +3,7 @@ use background_model::config::BackgroundDefinition;
 use derive_new::new;
 use serde::{Deserialize, Serialize};
 
-use crate::config::MapHeader;
+use crate::config::{MapHeader, MapPhase};
 
 /// Defines a playable area that objects can reside in.
 #[derive(Asset, Clone, Debug, Deserialize, Serialize, PartialEq, new)]
@@ -13,4 +13,8 @@ pub struct MapDefinition {
     /// Background to draw.
     #[serde(flatten)]
     pub background: BackgroundDefinition,
+    /// Waves of objects to spawn when the map is played as a stage.
+    #[new(default)]
+    #[serde(default)]
+    pub phases: Vec<MapPhase>,
 }
@@ -0,0 +1,15 @@
+use derive_new::new;
+use serde::{Deserialize, Serialize};
+use spawn_model::config::Spawns;
+
+/// Wave of objects to spawn when playing the map as a stage.
+///
+/// The next phase begins when all objects spawned in the current phase have been defeated.
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Serialize, new)]
+#[serde(default, deny_unknown_fields)]
+pub struct MapPhase {
+    /// Objects to spawn in this phase.
+    ///
+    /// Positions are relative to the map's origin.
+    pub spawns: Spawns,
+}
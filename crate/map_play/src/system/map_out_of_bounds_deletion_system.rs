@@ -1,10 +1,11 @@
 use amethyst::{
-    ecs::{Entities, Join, System, World, WriteStorage},
+    ecs::{Entities, Join, Read, System, World, WriteStorage},
     shred::{ResourceId, SystemData},
 };
 use derivative::Derivative;
 use derive_new::new;
 use map_model::play::OutOfBoundsDeleteClock;
+use snapshot_model::play::{DeferredDelete, DeleteMode};
 
 /// Ticks each `HitRepeatTracker`'s clock.
 #[derive(Debug, Default, new)]
@@ -16,9 +17,15 @@ pub struct MapOutOfBoundsDeletionSystemData<'s> {
     /// `Entities`.
     #[derivative(Debug = "ignore")]
     pub entities: Entities<'s>,
+    /// `DeleteMode` resource.
+    #[derivative(Debug = "ignore")]
+    pub delete_mode: Read<'s, DeleteMode>,
     /// `OutOfBoundsDeleteClock` components.
     #[derivative(Debug = "ignore")]
     pub out_of_bounds_delete_clocks: WriteStorage<'s, OutOfBoundsDeleteClock>,
+    /// `DeferredDelete` components.
+    #[derivative(Debug = "ignore")]
+    pub deferred_deletes: WriteStorage<'s, DeferredDelete>,
 }
 
 impl<'s> System<'s> for MapOutOfBoundsDeletionSystem {
@@ -28,7 +35,9 @@ impl<'s> System<'s> for MapOutOfBoundsDeletionSystem {
         &mut self,
         MapOutOfBoundsDeletionSystemData {
             entities,
+            delete_mode,
             mut out_of_bounds_delete_clocks,
+            mut deferred_deletes,
         }: Self::SystemData,
     ) {
         (&entities, &mut out_of_bounds_delete_clocks)
@@ -36,7 +45,9 @@ impl<'s> System<'s> for MapOutOfBoundsDeletionSystem {
             .for_each(|(entity, out_of_bounds_delete_clock)| {
                 out_of_bounds_delete_clock.tick();
                 if out_of_bounds_delete_clock.is_complete() {
-                    entities.delete(entity).expect("Failed to delete entity.");
+                    delete_mode
+                        .delete(&entities, &mut deferred_deletes, entity)
+                        .expect("Failed to delete entity.");
                 }
             });
     } // kcov-ignore
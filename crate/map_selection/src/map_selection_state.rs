@@ -11,6 +11,8 @@ use derivative::Derivative;
 use derive_new::new;
 use log::debug;
 use map_selection_model::{MapSelection, MapSelectionEntity};
+use network_session_model::play::SessionStatus;
+use session_lobby_model::SessionLobbyEvent;
 use state_registry::StateId;
 
 use crate::MapSelectionStatus;
@@ -72,6 +74,20 @@ where
     fn reset_map_selection_state(&self, world: &mut World) {
         world.insert(MapSelectionStatus::Pending);
     }
+
+    /// Returns whether the application is in a network session.
+    ///
+    /// In a session, the map is chosen by the session host, and every device waits for the
+    /// session server's notification before proceeding.
+    fn session_established(world: &World) -> bool {
+        world
+            .try_fetch::<SessionStatus>()
+            .map(|session_status| {
+                *session_status == SessionStatus::JoinEstablished
+                    || *session_status == SessionStatus::HostEstablished
+            })
+            .unwrap_or(false)
+    }
 }
 
 impl<'a, 'b, F, S> State<GameData<'a, 'b>, AppEvent> for MapSelectionStateDelegate<'a, 'b, F, S>
@@ -96,32 +112,43 @@ where
         data: StateData<'_, GameData<'a, 'b>>,
         event: AppEvent,
     ) -> Trans<GameData<'a, 'b>, AppEvent> {
-        if let AppEvent::AssetSelection(asset_selection_event) = event {
-            debug!(
-                "Received asset_selection_event: {:?}",
-                asset_selection_event
-            );
-
-            match asset_selection_event {
-                AssetSelectionEvent::Return => Trans::Pop,
-                AssetSelectionEvent::Confirm => {
-                    let map_selection = data.world.read_resource::<MapSelection>();
-
-                    // Hack: `AssetSelectionEvent`s are carried through from the previous state
-                    // when multiple controllers send input at the same time.
-                    if map_selection.asset_id().is_some() {
-                        debug!("map_selection: `{:?}`", &*map_selection);
-
-                        Trans::Switch((self.next_state_fn)())
-                    } else {
-                        debug!("Ignoring asset selection event: {:?}", event);
+        match event {
+            AppEvent::AssetSelection(asset_selection_event) => {
+                debug!(
+                    "Received asset_selection_event: {:?}",
+                    asset_selection_event
+                );
+
+                match asset_selection_event {
+                    AssetSelectionEvent::Return => Trans::Pop,
+                    AssetSelectionEvent::Confirm if Self::session_established(data.world) => {
+                        debug!("Waiting for map selection from session server.");
                         Trans::None
                     }
+                    AssetSelectionEvent::Confirm => {
+                        let map_selection = data.world.read_resource::<MapSelection>();
+
+                        // Hack: `AssetSelectionEvent`s are carried through from the previous state
+                        // when multiple controllers send input at the same time.
+                        if map_selection.asset_id().is_some() {
+                            debug!("map_selection: `{:?}`", &*map_selection);
+
+                            Trans::Switch((self.next_state_fn)())
+                        } else {
+                            debug!("Ignoring asset selection event: {:?}", event);
+                            Trans::None
+                        }
+                    }
+                    _ => Trans::None,
                 }
-                _ => Trans::None,
             }
-        } else {
-            Trans::None
+            AppEvent::SessionLobby(SessionLobbyEvent::SessionMapSelectNotify(_)) => {
+                let map_selection = data.world.read_resource::<MapSelection>();
+                debug!("map_selection from session server: `{:?}`", &*map_selection);
+
+                Trans::Switch((self.next_state_fn)())
+            }
+            _ => Trans::None,
         }
     }
 }
@@ -85,7 +85,9 @@ impl<'s> System<'s> for MapSelectionSystem {
                 AssetSelectionEvent::Confirm => {
                     *map_selection_status = MapSelectionStatus::Confirmed;
                 }
-                AssetSelectionEvent::Join { .. } | AssetSelectionEvent::Leave { .. } => {
+                AssetSelectionEvent::Join { .. }
+                | AssetSelectionEvent::Leave { .. }
+                | AssetSelectionEvent::CpuSwitch { .. } => {
                     warn!("Received `{:?}` in `MapSelectionSystem`.", ev);
                 }
             });
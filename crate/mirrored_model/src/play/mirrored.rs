@@ -10,6 +10,7 @@ use derive_more::{
     BitAnd, BitAndAssign, BitOr, BitOrAssign, BitXor, BitXorAssign, Display, From, Not,
 };
 use derive_new::new;
+use serde::{Deserialize, Serialize};
 
 /// Whether the object is mirrored.
 #[derive(
@@ -26,11 +27,13 @@ use derive_new::new;
     Deref,
     DerefMut,
     Default,
+    Deserialize,
     Display,
     From,
     PartialEq,
     Eq,
     Not,
+    Serialize,
     new,
 )]
 #[storage(VecStorage)]
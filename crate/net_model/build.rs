@@ -0,0 +1,223 @@
+//! Computes the network protocol version from the source of the crates whose types are sent over
+//! the network.
+//!
+//! Any change to the code of those crates changes the version, so that clients and servers built
+//! from different sources do not attempt to parse each other's messages. Comments, formatting, and
+//! line endings are not part of the version, so documentation changes and checkouts with different
+//! line endings remain compatible.
+
+use std::{
+    collections::BTreeSet,
+    env,
+    fs::{self, File},
+    io::{self, Write},
+    path::{Component, Path, PathBuf},
+};
+
+/// FNV-1a offset basis.
+const FNV_OFFSET_BASIS: u64 = 0xcbf2_9ce4_8422_2325;
+/// FNV-1a prime.
+const FNV_PRIME: u64 = 0x0000_0100_0000_01b3;
+
+fn main() -> io::Result<()> {
+    let manifest_dir = PathBuf::from(env::var("CARGO_MANIFEST_DIR").expect("`CARGO_MANIFEST_DIR`"));
+    let manifest_dir = path_normalize(&manifest_dir);
+    let crates_dir = manifest_dir.parent().unwrap_or(&manifest_dir).to_path_buf();
+
+    // Types in `NetMessageEvent` may come from this crate, or any crate it depends on by path.
+    let mut schema_crates = BTreeSet::new();
+    schema_crates_collect(&manifest_dir, &mut schema_crates)?;
+
+    let mut source_paths = Vec::new();
+    schema_crates.iter().try_for_each(|schema_crate| {
+        let src_dir = schema_crate.join("src");
+        println!(
+            "cargo:rerun-if-changed={}",
+            schema_crate.join("Cargo.toml").display()
+        );
+        println!("cargo:rerun-if-changed={}", src_dir.display());
+
+        rust_files_collect(&src_dir, &mut source_paths)
+    })?;
+    source_paths.sort();
+
+    let version = source_paths
+        .iter()
+        .try_fold(FNV_OFFSET_BASIS, |hash, source_path| {
+            let relative_path = source_path
+                .strip_prefix(&crates_dir)
+                .unwrap_or(source_path)
+                .to_string_lossy()
+                .replace('\\', "/");
+            let contents = fs::read_to_string(source_path)?;
+            let tokens = source_tokens(&contents);
+
+            let hash = fnv_1a(hash, relative_path.as_bytes());
+            Ok::<_, io::Error>(fnv_1a(hash, tokens.as_bytes()))
+        })?;
+
+    let out_path =
+        PathBuf::from(env::var("OUT_DIR").expect("`OUT_DIR`")).join("net_protocol_version.rs");
+    let mut out_file = File::create(out_path)?;
+    writeln!(out_file, "0x{:016x}", version)
+}
+
+/// Collects the crate directory and its transitive path dependencies.
+///
+/// Only `[dependencies]` sections are read, as dev and build dependencies are not part of the
+/// crate's types.
+fn schema_crates_collect(
+    crate_dir: &Path,
+    schema_crates: &mut BTreeSet<PathBuf>,
+) -> io::Result<()> {
+    if !schema_crates.insert(crate_dir.to_path_buf()) {
+        return Ok(());
+    }
+
+    let manifest = fs::read_to_string(crate_dir.join("Cargo.toml"))?;
+    let mut in_dependencies = false;
+    manifest.lines().map(str::trim).try_for_each(|line| {
+        if line.starts_with('[') {
+            in_dependencies = line.ends_with("dependencies]")
+                && !line.ends_with("dev-dependencies]")
+                && !line.ends_with("build-dependencies]");
+            return Ok(());
+        }
+        if !in_dependencies {
+            return Ok(());
+        }
+
+        let dependency_path = line.find("path").and_then(|path_index| {
+            let path_value = &line[path_index + "path".len()..];
+            let path_value = path_value.trim_start().strip_prefix('=')?.trim_start();
+            let path_value = path_value.strip_prefix('"')?;
+            path_value.find('"').map(|end| &path_value[..end])
+        });
+        if let Some(dependency_path) = dependency_path {
+            let dependency_dir = path_normalize(&crate_dir.join(dependency_path));
+            schema_crates_collect(&dependency_dir, schema_crates)
+        } else {
+            Ok(())
+        }
+    })
+}
+
+/// Resolves `.` and `..` components without accessing the file system.
+fn path_normalize(path: &Path) -> PathBuf {
+    path.components()
+        .fold(PathBuf::new(), |mut path_normalized, component| {
+            match component {
+                Component::CurDir => {}
+                Component::ParentDir => {
+                    path_normalized.pop();
+                }
+                component => path_normalized.push(component.as_os_str()),
+            }
+            path_normalized
+        })
+}
+
+fn rust_files_collect(dir: &Path, source_paths: &mut Vec<PathBuf>) -> io::Result<()> {
+    fs::read_dir(dir)?.try_for_each(|entry| {
+        let path = entry?.path();
+        if path.is_dir() {
+            rust_files_collect(&path, source_paths)
+        } else {
+            if path
+                .extension()
+                .map_or(false, |extension| extension == "rs")
+            {
+                source_paths.push(path);
+            }
+            Ok(())
+        }
+    })
+}
+
+/// Returns the source code with comments removed, and whitespace between tokens collapsed to a
+/// single space.
+///
+/// String and character literals are kept as is, apart from `\r\n` line endings which are
+/// normalized to `\n`.
+fn source_tokens(source: &str) -> String {
+    let source = source.replace("\r\n", "\n").chars().collect::<Vec<char>>();
+    let mut tokens = String::with_capacity(source.len());
+    let mut separator_pending = false;
+
+    let mut index = 0;
+    while index < source.len() {
+        let c = source[index];
+        let c_next = source.get(index + 1).copied();
+        match (c, c_next) {
+            ('/', Some('/')) => {
+                // Line comments, including doc comments.
+                while index < source.len() && source[index] != '\n' {
+                    index += 1;
+                }
+                separator_pending = true;
+            }
+            ('/', Some('*')) => {
+                // Block comments, which may be nested.
+                let mut depth = 0;
+                while index < source.len() {
+                    match (source[index], source.get(index + 1).copied()) {
+                        ('/', Some('*')) => {
+                            depth += 1;
+                            index += 2;
+                        }
+                        ('*', Some('/')) => {
+                            depth -= 1;
+                            index += 2;
+                            if depth == 0 {
+                                break;
+                            }
+                        }
+                        _ => index += 1,
+                    }
+                }
+                separator_pending = true;
+            }
+            (c, _) if c.is_whitespace() => {
+                index += 1;
+                separator_pending = true;
+            }
+            _ => {
+                if separator_pending && !tokens.is_empty() {
+                    tokens.push(' ');
+                }
+                separator_pending = false;
+
+                let literal_end = match (c, c_next) {
+                    ('"', _) => literal_end(&source, index, '"'),
+                    // Character literals, as opposed to lifetimes.
+                    ('\'', Some('\\')) => literal_end(&source, index, '\''),
+                    ('\'', Some(_)) if source.get(index + 2) == Some(&'\'') => index + 3,
+                    _ => index + 1,
+                };
+                tokens.extend(&source[index..literal_end]);
+                index = literal_end;
+            }
+        }
+    }
+
+    tokens
+}
+
+/// Returns the index after the closing delimiter of the literal that starts at `start`.
+fn literal_end(source: &[char], start: usize, delimiter: char) -> usize {
+    let mut index = start + 1;
+    while index < source.len() {
+        match source[index] {
+            '\\' => index += 2,
+            c if c == delimiter => return index + 1,
+            _ => index += 1,
+        }
+    }
+    source.len()
+}
+
+fn fnv_1a(hash: u64, bytes: &[u8]) -> u64 {
+    bytes.iter().fold(hash, |hash, byte| {
+        (hash ^ u64::from(*byte)).wrapping_mul(FNV_PRIME)
+    })
+}
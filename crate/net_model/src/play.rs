@@ -1,12 +1,24 @@
 //! Data types used at runtime.
 
 pub use self::{
-    net_data::NetData, net_event_channel::NetEventChannel, net_message_event::NetMessageEvent,
+    net_data::NetData, net_discovery_event::NetDiscoveryEvent, net_event_channel::NetEventChannel,
+    net_handshake_event::NetHandshakeEvent, net_handshake_status::NetHandshakeStatus,
+    net_message_event::NetMessageEvent, net_peer_versions::NetPeerVersions,
+    net_protocol_version::NetProtocolVersion, net_server_announcement::NetServerAnnouncement,
+    net_server_discoveries::NetServerDiscoveries, net_server_discovery::NetServerDiscovery,
     net_session_device::NetSessionDevice, net_session_devices::NetSessionDevices,
 };
 
 mod net_data;
+mod net_discovery_event;
 mod net_event_channel;
+mod net_handshake_event;
+mod net_handshake_status;
 mod net_message_event;
+mod net_peer_versions;
+mod net_protocol_version;
+mod net_server_announcement;
+mod net_server_discoveries;
+mod net_server_discovery;
 mod net_session_device;
 mod net_session_devices;
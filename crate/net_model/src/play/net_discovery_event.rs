@@ -0,0 +1,19 @@
+use serde::{Deserialize, Serialize};
+
+use crate::play::NetServerAnnouncement;
+
+/// Exchanged over UDP to discover session servers on the local network.
+///
+/// This type must not change, so that it can be read by builds with a different protocol version.
+#[derive(Clone, Copy, Debug, Deserialize, PartialEq, Serialize)]
+pub enum NetDiscoveryEvent {
+    /// Broadcast by clients looking for session servers.
+    Probe,
+    /// Session server's response to a `Probe`.
+    Announce(NetServerAnnouncement),
+}
+
+impl NetDiscoveryEvent {
+    /// UDP port that session servers listen on for `Probe`s.
+    pub const PORT: u16 = 1235;
+}
@@ -0,0 +1,16 @@
+use serde::{Deserialize, Serialize};
+
+use crate::play::NetProtocolVersion;
+
+/// Exchanged between a client and the session server before any session requests.
+///
+/// This type must not change, so that it can be read by builds with a different protocol version.
+#[derive(Clone, Copy, Debug, Deserialize, PartialEq, Serialize)]
+pub enum NetHandshakeEvent {
+    /// Client's protocol version, sent before a session host or join request.
+    Request(NetProtocolVersion),
+    /// Server accepted the client's protocol version.
+    Accept,
+    /// Server rejected the client's protocol version, and includes the server's version.
+    Reject(NetProtocolVersion),
+}
@@ -0,0 +1,21 @@
+use crate::play::NetProtocolVersion;
+
+/// Result of the most recent handshake with the session server.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum NetHandshakeStatus {
+    /// No response has been received from the session server.
+    Pending,
+    /// The session server accepted this client's protocol version.
+    Accepted,
+    /// The session server rejected this client's protocol version.
+    Rejected {
+        /// Protocol version of the session server.
+        server_version: NetProtocolVersion,
+    },
+}
+
+impl Default for NetHandshakeStatus {
+    fn default() -> Self {
+        NetHandshakeStatus::Pending
+    }
+}
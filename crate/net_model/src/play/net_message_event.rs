@@ -1,16 +1,27 @@
 use derive_more::From;
 use game_input_model::GameInputEvent;
 use network_session_model::SessionMessageEvent;
+use rollback_model::play::{RollbackTickConfirm, TickedGameInputEvent};
 use serde::{Deserialize, Serialize};
+use session_browse_model::SessionBrowseEvent;
 use session_host_model::SessionHostEvent;
 use session_join_model::SessionJoinEvent;
 use session_lobby_model::SessionLobbyEvent;
 
+use crate::play::NetHandshakeEvent;
+
 /// All variants of messages that can be sent over the network.
 #[derive(Clone, Debug, Deserialize, From, PartialEq, Serialize)]
 pub enum NetMessageEvent {
+    /// `NetHandshakeEvent` messages.
+    ///
+    /// This must remain the first variant, so that it is parsed the same way across protocol
+    /// versions.
+    NetHandshakeEvent(NetHandshakeEvent),
     /// `GameInputEvent` messages.
     GameInputEvent(GameInputEvent),
+    /// `SessionBrowseEvent` messages.
+    SessionBrowseEvent(SessionBrowseEvent),
     /// `SessionHostEvent` messages.
     SessionHostEvent(SessionHostEvent),
     /// `SessionJoinEvent` messages.
@@ -19,4 +30,8 @@ pub enum NetMessageEvent {
     SessionLobbyEvent(SessionLobbyEvent),
     /// `SessionMessageEvent` messages.
     SessionMessageEvent(SessionMessageEvent),
+    /// `TickedGameInputEvent` messages.
+    TickedGameInputEvent(TickedGameInputEvent),
+    /// `RollbackTickConfirm` messages.
+    RollbackTickConfirm(RollbackTickConfirm),
 }
@@ -0,0 +1,21 @@
+use std::{collections::HashMap, net::SocketAddr};
+
+use derive_deref::{Deref, DerefMut};
+use derive_new::new;
+
+use crate::play::NetProtocolVersion;
+
+/// Protocol versions that peers sent in their handshake.
+///
+/// `HashMap<SocketAddr, NetProtocolVersion>` newtype.
+#[derive(Clone, Debug, Default, Deref, DerefMut, PartialEq, new)]
+pub struct NetPeerVersions(pub HashMap<SocketAddr, NetProtocolVersion>);
+
+impl NetPeerVersions {
+    /// Returns whether the peer completed a handshake with a compatible protocol version.
+    pub fn is_compatible(&self, socket_addr: &SocketAddr) -> bool {
+        self.get(socket_addr).map_or(false, |net_protocol_version| {
+            net_protocol_version.is_current()
+        })
+    }
+}
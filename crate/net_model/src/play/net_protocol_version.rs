@@ -0,0 +1,31 @@
+use std::fmt::{self, Display, Formatter};
+
+use derive_new::new;
+use serde::{Deserialize, Serialize};
+
+/// Version of the network protocol, exchanged in the `NetHandshakeEvent`.
+///
+/// The current version is computed by `build.rs` from the source of the crates whose types are
+/// sent in a `NetMessageEvent`, so builds that may serialize messages differently have different
+/// versions. Comments and formatting are ignored.
+#[derive(Clone, Copy, Debug, Deserialize, Hash, PartialEq, Eq, Serialize, new)]
+pub struct NetProtocolVersion(pub u64);
+
+impl NetProtocolVersion {
+    /// Protocol version of this build.
+    pub const CURRENT: NetProtocolVersion = NetProtocolVersion(include!(concat!(
+        env!("OUT_DIR"),
+        "/net_protocol_version.rs"
+    )));
+
+    /// Returns whether this version is compatible with this build's protocol version.
+    pub fn is_current(self) -> bool {
+        self == Self::CURRENT
+    }
+}
+
+impl Display for NetProtocolVersion {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{:016x}", self.0)
+    }
+}
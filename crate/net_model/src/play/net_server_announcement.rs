@@ -0,0 +1,13 @@
+use derive_new::new;
+use serde::{Deserialize, Serialize};
+
+use crate::play::NetProtocolVersion;
+
+/// Information a session server announces about itself when probed.
+#[derive(Clone, Copy, Debug, Deserialize, PartialEq, Serialize, new)]
+pub struct NetServerAnnouncement {
+    /// Port that the session server accepts connections on.
+    pub port: u16,
+    /// Protocol version of the session server.
+    pub net_protocol_version: NetProtocolVersion,
+}
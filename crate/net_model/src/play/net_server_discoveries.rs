@@ -0,0 +1,44 @@
+use std::{
+    net::SocketAddr,
+    time::{Duration, Instant},
+};
+
+use derive_deref::{Deref, DerefMut};
+use derive_new::new;
+
+use crate::play::NetServerDiscovery;
+
+/// Session servers found on the local network, in the order they were found.
+///
+/// Newtype for `Vec<NetServerDiscovery>`.
+#[derive(Clone, Debug, Default, Deref, DerefMut, PartialEq, new)]
+pub struct NetServerDiscoveries(pub Vec<NetServerDiscovery>);
+
+impl NetServerDiscoveries {
+    /// Records a session server's announcement, replacing the earlier record for the same address.
+    pub fn insert(&mut self, net_server_discovery: NetServerDiscovery) {
+        let existing = self.0.iter_mut().find(|net_server_discovery_existing| {
+            net_server_discovery_existing.socket_addr == net_server_discovery.socket_addr
+        });
+
+        if let Some(existing) = existing {
+            *existing = net_server_discovery;
+        } else {
+            self.0.push(net_server_discovery);
+        }
+    }
+
+    /// Removes session servers that have not responded since `expiry` before `now`.
+    pub fn retain_recent(&mut self, now: Instant, expiry: Duration) {
+        self.0.retain(|net_server_discovery| {
+            now.duration_since(net_server_discovery.seen_at) < expiry
+        });
+    }
+
+    /// Returns the position of the session server with the given address.
+    pub fn position(&self, socket_addr: SocketAddr) -> Option<usize> {
+        self.0
+            .iter()
+            .position(|net_server_discovery| net_server_discovery.socket_addr == socket_addr)
+    }
+}
@@ -0,0 +1,16 @@
+use std::{net::SocketAddr, time::Instant};
+
+use derive_new::new;
+
+use crate::play::NetProtocolVersion;
+
+/// A session server found on the local network.
+#[derive(Clone, Copy, Debug, PartialEq, new)]
+pub struct NetServerDiscovery {
+    /// Address that the session server accepts connections on.
+    pub socket_addr: SocketAddr,
+    /// Protocol version of the session server.
+    pub net_protocol_version: NetProtocolVersion,
+    /// When the session server last responded to a probe.
+    pub seen_at: Instant,
+}
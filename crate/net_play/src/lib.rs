@@ -3,6 +3,8 @@
 //! Provides logic for the network.
 
 pub use crate::system::{
+    NetDiscoveryRequestSystem, NetDiscoveryResponderSystem, NetHandshakeResponderSystem,
+    NetHandshakeResponderSystemDesc, NetHandshakeResponseSystem, NetHandshakeResponseSystemDesc,
     NetListenerSystem, NetListenerSystemDesc, NetMessageRequestSystem, NetMessageRequestSystemDesc,
 };
 
@@ -1,7 +1,17 @@
 pub use self::{
+    net_discovery_request_system::NetDiscoveryRequestSystem,
+    net_discovery_responder_system::NetDiscoveryResponderSystem,
+    net_handshake_responder_system::{
+        NetHandshakeResponderSystem, NetHandshakeResponderSystemDesc,
+    },
+    net_handshake_response_system::{NetHandshakeResponseSystem, NetHandshakeResponseSystemDesc},
     net_listener_system::{NetListenerSystem, NetListenerSystemDesc},
     net_message_request_system::{NetMessageRequestSystem, NetMessageRequestSystemDesc},
 };
 
+mod net_discovery_request_system;
+mod net_discovery_responder_system;
+mod net_handshake_responder_system;
+mod net_handshake_response_system;
 mod net_listener_system;
 mod net_message_request_system;
@@ -0,0 +1,160 @@
+use std::{
+    io,
+    net::{Ipv4Addr, SocketAddr, UdpSocket},
+    time::{Duration, Instant},
+};
+
+use amethyst::{
+    ecs::{System, World, Write},
+    shred::{ResourceId, SystemData},
+};
+use derivative::Derivative;
+use derive_new::new;
+use log::{debug, error, warn};
+use net_model::play::{
+    NetDiscoveryEvent, NetServerAnnouncement, NetServerDiscoveries, NetServerDiscovery,
+};
+
+/// Maximum size of a `NetDiscoveryEvent` datagram.
+const DATAGRAM_SIZE_MAX: usize = 512;
+/// Interval between `NetDiscoveryEvent::Probe`s.
+const PROBE_INTERVAL: Duration = Duration::from_secs(1);
+/// Duration after which a session server that has not responded is forgotten.
+const DISCOVERY_EXPIRY: Duration = Duration::from_secs(5);
+
+/// Periodically probes for session servers on the local network, and records their announcements.
+///
+/// Probes are broadcast on the local network, and also sent to the loopback address so that a
+/// session server on the same machine is found.
+#[derive(Debug, Default, new)]
+pub struct NetDiscoveryRequestSystem {
+    /// Socket that probes are sent from, bound on the first run.
+    #[new(default)]
+    udp_socket: Option<UdpSocket>,
+    /// Whether binding the socket failed, in which case discovery is disabled.
+    #[new(default)]
+    udp_socket_failed: bool,
+    /// When the last probe was sent.
+    #[new(default)]
+    probe_sent_at: Option<Instant>,
+}
+
+/// `NetDiscoveryRequestSystemData`.
+#[derive(Derivative, SystemData)]
+#[derivative(Debug)]
+pub struct NetDiscoveryRequestSystemData<'s> {
+    /// `NetServerDiscoveries` resource.
+    #[derivative(Debug = "ignore")]
+    pub net_server_discoveries: Write<'s, NetServerDiscoveries>,
+}
+
+impl NetDiscoveryRequestSystem {
+    /// Returns a non-blocking UDP socket that may send broadcasts.
+    fn udp_socket_bind() -> io::Result<UdpSocket> {
+        let udp_socket = UdpSocket::bind((Ipv4Addr::UNSPECIFIED, 0))?;
+        udp_socket.set_broadcast(true)?;
+        udp_socket.set_nonblocking(true)?;
+        Ok(udp_socket)
+    }
+
+    /// Sends a `NetDiscoveryEvent::Probe` to the broadcast and loopback addresses.
+    fn probe_send(udp_socket: &UdpSocket) {
+        match bincode::serialize(&NetDiscoveryEvent::Probe) {
+            Ok(payload) => [Ipv4Addr::BROADCAST, Ipv4Addr::LOCALHOST]
+                .iter()
+                .for_each(|address| {
+                    let socket_addr = SocketAddr::from((*address, NetDiscoveryEvent::PORT));
+                    if let Err(e) = udp_socket.send_to(&payload, socket_addr) {
+                        debug!(
+                            "Failed to send discovery probe to {}. Error: `{}`.",
+                            socket_addr, e
+                        );
+                    }
+                }),
+            Err(e) => error!(
+                "Failed to serialize `NetDiscoveryEvent::Probe`. Error: `{}`.",
+                e
+            ),
+        }
+    }
+
+    /// Records announcements received since the last run.
+    fn announcements_receive(
+        udp_socket: &UdpSocket,
+        net_server_discoveries: &mut NetServerDiscoveries,
+        now: Instant,
+    ) {
+        let mut buffer = [0u8; DATAGRAM_SIZE_MAX];
+        loop {
+            let (len, socket_addr) = match udp_socket.recv_from(&mut buffer) {
+                Ok(received) => received,
+                Err(e) if e.kind() == io::ErrorKind::WouldBlock => break,
+                Err(e) => {
+                    debug!("Failed to receive discovery announcement. Error: `{}`.", e);
+                    break;
+                }
+            };
+
+            if let Ok(NetDiscoveryEvent::Announce(net_server_announcement)) =
+                bincode::deserialize::<NetDiscoveryEvent>(&buffer[..len])
+            {
+                let NetServerAnnouncement {
+                    port,
+                    net_protocol_version,
+                } = net_server_announcement;
+
+                // The server accepts connections on the address it responded from.
+                let socket_addr = SocketAddr::new(socket_addr.ip(), port);
+                net_server_discoveries.insert(NetServerDiscovery::new(
+                    socket_addr,
+                    net_protocol_version,
+                    now,
+                ));
+            }
+        }
+    }
+}
+
+impl<'s> System<'s> for NetDiscoveryRequestSystem {
+    type SystemData = NetDiscoveryRequestSystemData<'s>;
+
+    fn run(
+        &mut self,
+        NetDiscoveryRequestSystemData {
+            mut net_server_discoveries,
+        }: Self::SystemData,
+    ) {
+        if self.udp_socket_failed {
+            return;
+        }
+        if self.udp_socket.is_none() {
+            match Self::udp_socket_bind() {
+                Ok(udp_socket) => self.udp_socket = Some(udp_socket),
+                Err(e) => {
+                    warn!(
+                        "Failed to bind socket for session server discovery. Error: `{}`.",
+                        e
+                    );
+                    self.udp_socket_failed = true;
+                    return;
+                }
+            }
+        }
+
+        if let Some(udp_socket) = self.udp_socket.as_ref() {
+            let now = Instant::now();
+
+            Self::announcements_receive(udp_socket, &mut net_server_discoveries, now);
+            net_server_discoveries.retain_recent(now, DISCOVERY_EXPIRY);
+
+            let probe_due = self
+                .probe_sent_at
+                .map(|probe_sent_at| now.duration_since(probe_sent_at) >= PROBE_INTERVAL)
+                .unwrap_or(true);
+            if probe_due {
+                Self::probe_send(udp_socket);
+                self.probe_sent_at = Some(now);
+            }
+        }
+    }
+}
@@ -0,0 +1,67 @@
+use std::{io, net::UdpSocket};
+
+use amethyst::ecs::System;
+use derive_new::new;
+use log::{debug, error, warn};
+use net_model::play::{NetDiscoveryEvent, NetServerAnnouncement};
+
+/// Maximum size of a `NetDiscoveryEvent` datagram.
+const DATAGRAM_SIZE_MAX: usize = 512;
+
+/// Responds to `NetDiscoveryEvent::Probe`s with the session server's `NetServerAnnouncement`.
+///
+/// The UDP socket should be non-blocking, and bound to the unspecified address to receive
+/// broadcast probes from the local network.
+#[derive(Debug, new)]
+pub struct NetDiscoveryResponderSystem {
+    /// Socket that probes are received on.
+    udp_socket: UdpSocket,
+    /// Announcement to respond to probes with.
+    net_server_announcement: NetServerAnnouncement,
+}
+
+impl<'s> System<'s> for NetDiscoveryResponderSystem {
+    type SystemData = ();
+
+    fn run(&mut self, _: Self::SystemData) {
+        let mut buffer = [0u8; DATAGRAM_SIZE_MAX];
+        loop {
+            let (len, socket_addr) = match self.udp_socket.recv_from(&mut buffer) {
+                Ok(received) => received,
+                Err(e) if e.kind() == io::ErrorKind::WouldBlock => break,
+                Err(e) => {
+                    warn!("Failed to receive discovery probe. Error: `{}`.", e);
+                    break;
+                }
+            };
+
+            match bincode::deserialize::<NetDiscoveryEvent>(&buffer[..len]) {
+                Ok(NetDiscoveryEvent::Probe) => {
+                    debug!("Received discovery probe from {}.", socket_addr);
+
+                    let net_discovery_event =
+                        NetDiscoveryEvent::Announce(self.net_server_announcement);
+                    match bincode::serialize(&net_discovery_event) {
+                        Ok(payload) => {
+                            if let Err(e) = self.udp_socket.send_to(&payload, socket_addr) {
+                                warn!(
+                                    "Failed to send discovery announcement to {}. Error: `{}`.",
+                                    socket_addr, e
+                                );
+                            }
+                        }
+                        Err(e) => error!(
+                            "Failed to serialize `{:?}`. Error: `{}`.",
+                            net_discovery_event, e
+                        ),
+                    }
+                }
+                Ok(NetDiscoveryEvent::Announce(_)) => {}
+                Err(e) => debug!(
+                    "Ignoring unrecognized datagram from {}. Error: `{}`.",
+                    socket_addr, e
+                ),
+            }
+        }
+    }
+}
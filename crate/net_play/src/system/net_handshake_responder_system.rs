@@ -0,0 +1,100 @@
+use amethyst::{
+    derive::SystemDesc,
+    ecs::{Read, System, World, Write},
+    network::simulation::{DeliveryRequirement, TransportResource, UrgencyRequirement},
+    shred::{ResourceId, SystemData},
+    shrev::ReaderId,
+};
+use derivative::Derivative;
+use derive_new::new;
+use log::{debug, error, warn};
+use net_model::play::{
+    NetData, NetEventChannel, NetHandshakeEvent, NetMessageEvent, NetPeerVersions,
+    NetProtocolVersion,
+};
+
+/// Records the protocol version of each client, and responds whether it is compatible.
+///
+/// This is used by the session server.
+#[derive(Debug, SystemDesc, new)]
+#[system_desc(name(NetHandshakeResponderSystemDesc))]
+pub struct NetHandshakeResponderSystem {
+    /// Reader ID for the `NetHandshakeEvent` channel.
+    #[system_desc(event_channel_reader)]
+    net_handshake_event_rid: ReaderId<NetData<NetHandshakeEvent>>,
+}
+
+#[derive(Derivative, SystemData)]
+#[derivative(Debug)]
+pub struct NetHandshakeResponderSystemData<'s> {
+    /// `NetHandshakeEvent` channel.
+    #[derivative(Debug = "ignore")]
+    pub net_handshake_nec: Read<'s, NetEventChannel<NetHandshakeEvent>>,
+    /// `NetPeerVersions` resource.
+    #[derivative(Debug = "ignore")]
+    pub net_peer_versions: Write<'s, NetPeerVersions>,
+    /// `TransportResource` resource.
+    #[derivative(Debug = "ignore")]
+    pub transport_resource: Write<'s, TransportResource>,
+}
+
+impl<'s> System<'s> for NetHandshakeResponderSystem {
+    type SystemData = NetHandshakeResponderSystemData<'s>;
+
+    fn run(
+        &mut self,
+        NetHandshakeResponderSystemData {
+            net_handshake_nec,
+            mut net_peer_versions,
+            mut transport_resource,
+        }: Self::SystemData,
+    ) {
+        net_handshake_nec
+            .read(&mut self.net_handshake_event_rid)
+            .for_each(|net_handshake_event| {
+                let NetData {
+                    socket_addr,
+                    data: net_handshake_event,
+                } = net_handshake_event;
+
+                if let NetHandshakeEvent::Request(net_protocol_version) = net_handshake_event {
+                    net_peer_versions.insert(*socket_addr, *net_protocol_version);
+
+                    let net_handshake_event = if net_protocol_version.is_current() {
+                        debug!("Accepted handshake from `{}`.", socket_addr);
+
+                        NetHandshakeEvent::Accept
+                    } else {
+                        warn!(
+                            "Rejecting handshake from `{}`. Client protocol version: `{}`, \
+                             server protocol version: `{}`.",
+                            socket_addr,
+                            net_protocol_version,
+                            NetProtocolVersion::CURRENT
+                        );
+
+                        NetHandshakeEvent::Reject(NetProtocolVersion::CURRENT)
+                    };
+
+                    let net_message_event = NetMessageEvent::from(net_handshake_event);
+                    match bincode::serialize(&net_message_event) {
+                        Ok(payload) => {
+                            transport_resource.send_with_requirements(
+                                *socket_addr,
+                                &payload,
+                                DeliveryRequirement::ReliableOrdered(None),
+                                UrgencyRequirement::OnTick,
+                            );
+                        }
+                        Err(e) => {
+                            error!(
+                                "Failed to serialize `NetMessageEvent::NetHandshakeEvent`. \
+                                 Error: `{}`.",
+                                e
+                            );
+                        }
+                    }
+                }
+            });
+    }
+}
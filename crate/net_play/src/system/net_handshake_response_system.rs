@@ -0,0 +1,64 @@
+use amethyst::{
+    derive::SystemDesc,
+    ecs::{Read, System, World, Write},
+    shred::{ResourceId, SystemData},
+    shrev::ReaderId,
+};
+use derivative::Derivative;
+use derive_new::new;
+use log::error;
+use net_model::play::{
+    NetData, NetEventChannel, NetHandshakeEvent, NetHandshakeStatus, NetProtocolVersion,
+};
+
+/// Records the session server's response to this client's handshake.
+#[derive(Debug, SystemDesc, new)]
+#[system_desc(name(NetHandshakeResponseSystemDesc))]
+pub struct NetHandshakeResponseSystem {
+    /// Reader ID for the `NetHandshakeEvent` channel.
+    #[system_desc(event_channel_reader)]
+    net_handshake_event_rid: ReaderId<NetData<NetHandshakeEvent>>,
+}
+
+#[derive(Derivative, SystemData)]
+#[derivative(Debug)]
+pub struct NetHandshakeResponseSystemData<'s> {
+    /// `NetHandshakeEvent` channel.
+    #[derivative(Debug = "ignore")]
+    pub net_handshake_nec: Read<'s, NetEventChannel<NetHandshakeEvent>>,
+    /// `NetHandshakeStatus` resource.
+    #[derivative(Debug = "ignore")]
+    pub net_handshake_status: Write<'s, NetHandshakeStatus>,
+}
+
+impl<'s> System<'s> for NetHandshakeResponseSystem {
+    type SystemData = NetHandshakeResponseSystemData<'s>;
+
+    fn run(
+        &mut self,
+        NetHandshakeResponseSystemData {
+            net_handshake_nec,
+            mut net_handshake_status,
+        }: Self::SystemData,
+    ) {
+        net_handshake_nec
+            .read(&mut self.net_handshake_event_rid)
+            .for_each(|net_handshake_event| match net_handshake_event.data {
+                NetHandshakeEvent::Accept => {
+                    *net_handshake_status = NetHandshakeStatus::Accepted;
+                }
+                NetHandshakeEvent::Reject(server_version) => {
+                    error!(
+                        "Session server at `{}` is running an incompatible version. \
+                         Client protocol version: `{}`, server protocol version: `{}`.",
+                        net_handshake_event.socket_addr,
+                        NetProtocolVersion::CURRENT,
+                        server_version
+                    );
+
+                    *net_handshake_status = NetHandshakeStatus::Rejected { server_version };
+                }
+                NetHandshakeEvent::Request(_) => {}
+            });
+    }
+}
@@ -9,8 +9,10 @@ use derivative::Derivative;
 use derive_new::new;
 use game_input_model::GameInputEvent;
 use log::{debug, error};
-use net_model::play::{NetData, NetEventChannel, NetMessageEvent};
+use net_model::play::{NetData, NetEventChannel, NetHandshakeEvent, NetMessageEvent};
 use network_session_model::SessionMessageEvent;
+use rollback_model::play::{RollbackTickConfirm, TickedGameInputEvent};
+use session_browse_model::SessionBrowseEvent;
 use session_host_model::SessionHostEvent;
 use session_join_model::SessionJoinEvent;
 use session_lobby_model::SessionLobbyEvent;
@@ -30,9 +32,15 @@ pub struct NetListenerSystemData<'s> {
     /// `NetworkSimulationEvent` channel.
     #[derivative(Debug = "ignore")]
     pub network_simulation_ec: Read<'s, EventChannel<NetworkSimulationEvent>>,
+    /// Net `NetHandshakeEvent` channel.
+    #[derivative(Debug = "ignore")]
+    pub net_handshake_nec: Write<'s, NetEventChannel<NetHandshakeEvent>>,
     /// Net `GameInputEvent` channel.
     #[derivative(Debug = "ignore")]
     pub game_input_nec: Write<'s, NetEventChannel<GameInputEvent>>,
+    /// Net `SessionBrowseEvent` channel.
+    #[derivative(Debug = "ignore")]
+    pub session_browse_nec: Write<'s, NetEventChannel<SessionBrowseEvent>>,
     /// Net `SessionHostEvent` channel.
     #[derivative(Debug = "ignore")]
     pub session_host_nec: Write<'s, NetEventChannel<SessionHostEvent>>,
@@ -45,6 +53,12 @@ pub struct NetListenerSystemData<'s> {
     /// Net `SessionMessageEvent` channel.
     #[derivative(Debug = "ignore")]
     pub session_message_nec: Write<'s, NetEventChannel<SessionMessageEvent>>,
+    /// Net `TickedGameInputEvent` channel.
+    #[derivative(Debug = "ignore")]
+    pub ticked_game_input_nec: Write<'s, NetEventChannel<TickedGameInputEvent>>,
+    /// Net `RollbackTickConfirm` channel.
+    #[derivative(Debug = "ignore")]
+    pub rollback_tick_confirm_nec: Write<'s, NetEventChannel<RollbackTickConfirm>>,
 }
 
 impl<'s> System<'s> for NetListenerSystem {
@@ -54,11 +68,15 @@ impl<'s> System<'s> for NetListenerSystem {
         &mut self,
         NetListenerSystemData {
             network_simulation_ec,
+            mut net_handshake_nec,
             mut game_input_nec,
+            mut session_browse_nec,
             mut session_host_nec,
             mut session_join_nec,
             mut session_lobby_nec,
             mut session_message_nec,
+            mut ticked_game_input_nec,
+            mut rollback_tick_confirm_nec,
         }: Self::SystemData,
     ) {
         network_simulation_ec
@@ -71,10 +89,22 @@ impl<'s> System<'s> for NetListenerSystem {
                         Ok(net_message_event) => {
                             debug!("{:?}", net_message_event);
                             match net_message_event {
+                                NetMessageEvent::NetHandshakeEvent(net_handshake_event) => {
+                                    net_handshake_nec.single_write(NetData::new(
+                                        *socket_addr,
+                                        net_handshake_event,
+                                    ));
+                                }
                                 NetMessageEvent::GameInputEvent(game_input_event) => {
                                     game_input_nec
                                         .single_write(NetData::new(*socket_addr, game_input_event));
                                 }
+                                NetMessageEvent::SessionBrowseEvent(session_browse_event) => {
+                                    session_browse_nec.single_write(NetData::new(
+                                        *socket_addr,
+                                        session_browse_event,
+                                    ));
+                                }
                                 NetMessageEvent::SessionHostEvent(session_host_event) => {
                                     session_host_nec.single_write(NetData::new(
                                         *socket_addr,
@@ -99,6 +129,18 @@ impl<'s> System<'s> for NetListenerSystem {
                                         session_message_event,
                                     ));
                                 }
+                                NetMessageEvent::TickedGameInputEvent(ticked_game_input_event) => {
+                                    ticked_game_input_nec.single_write(NetData::new(
+                                        *socket_addr,
+                                        ticked_game_input_event,
+                                    ));
+                                }
+                                NetMessageEvent::RollbackTickConfirm(rollback_tick_confirm) => {
+                                    rollback_tick_confirm_nec.single_write(NetData::new(
+                                        *socket_addr,
+                                        rollback_tick_confirm,
+                                    ));
+                                }
                             }
                         }
                         Err(e) => error!("Failed to parse `NetMessageEvent`: `{}`", e),
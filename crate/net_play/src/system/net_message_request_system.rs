@@ -10,10 +10,16 @@ use amethyst::{
 use derivative::Derivative;
 use derive_new::new;
 use log::{debug, error};
-use net_model::play::NetMessageEvent;
+use net_model::play::{NetHandshakeEvent, NetMessageEvent, NetProtocolVersion};
 use network_session_model::config::SessionServerConfig;
+use session_browse_model::SessionBrowseEvent;
+use session_host_model::SessionHostEvent;
+use session_join_model::SessionJoinEvent;
 
 /// Sends requests to the session server.
+///
+/// A `NetHandshakeEvent` is sent before each session list, host, or join request, so that the
+/// session server can check that this client's protocol version is compatible.
 #[derive(Debug, SystemDesc, new)]
 #[system_desc(name(NetMessageRequestSystemDesc))]
 pub struct NetMessageRequestSystem {
@@ -36,6 +42,45 @@ pub struct NetMessageRequestSystemData<'s> {
     pub transport_resource: Write<'s, TransportResource>,
 }
 
+impl NetMessageRequestSystem {
+    /// Returns whether the message requests to list, host, join, spectate, or reconnect to a
+    /// session.
+    fn is_session_request(net_message_event: &NetMessageEvent) -> bool {
+        matches!(
+            net_message_event,
+            NetMessageEvent::SessionBrowseEvent(SessionBrowseEvent::SessionListRequest)
+                | NetMessageEvent::SessionHostEvent(SessionHostEvent::SessionHostRequest(_))
+                | NetMessageEvent::SessionJoinEvent(SessionJoinEvent::SessionJoinRequest(_))
+                | NetMessageEvent::SessionJoinEvent(SessionJoinEvent::SessionSpectateRequest(_))
+                | NetMessageEvent::SessionJoinEvent(SessionJoinEvent::SessionReconnectRequest(_))
+        )
+    }
+
+    fn send(
+        transport_resource: &mut TransportResource,
+        server_socket_addr: SocketAddr,
+        net_message_event: &NetMessageEvent,
+    ) {
+        match bincode::serialize(net_message_event) {
+            Ok(payload) => {
+                debug!("Sending `NetMessageEvent`: `{:?}`.", net_message_event);
+                // Connect to `server_socket_addr` and send request.
+                transport_resource.send_with_requirements(
+                    server_socket_addr,
+                    &payload,
+                    // None means it uses a default multiplexed stream.
+                    //
+                    // Suspect if we give it a value, the value will be a "channel" over the
+                    // same socket connection.
+                    DeliveryRequirement::ReliableOrdered(None),
+                    UrgencyRequirement::OnTick,
+                );
+            }
+            Err(e) => error!("Failed to serialize `NetMessageEvent`. Error: `{}`.", e),
+        }
+    }
+}
+
 impl<'s> System<'s> for NetMessageRequestSystem {
     type SystemData = NetMessageRequestSystemData<'s>;
 
@@ -52,23 +97,22 @@ impl<'s> System<'s> for NetMessageRequestSystem {
         net_message_ec
             .read(&mut self.net_message_event_rid)
             .for_each(|net_message_event| {
-                match bincode::serialize(net_message_event) {
-                    Ok(payload) => {
-                        debug!("Sending `NetMessageEvent`: `{:?}`.", net_message_event);
-                        // Connect to `server_socket_addr` and send request.
-                        transport_resource.send_with_requirements(
-                            server_socket_addr,
-                            &payload,
-                            // None means it uses a default multiplexed stream.
-                            //
-                            // Suspect if we give it a value, the value will be a "channel" over the
-                            // same socket connection.
-                            DeliveryRequirement::ReliableOrdered(None),
-                            UrgencyRequirement::OnTick,
-                        );
-                    }
-                    Err(e) => error!("Failed to serialize `NetMessageEvent`. Error: `{}`.", e),
+                if Self::is_session_request(net_message_event) {
+                    let net_handshake_event = NetMessageEvent::NetHandshakeEvent(
+                        NetHandshakeEvent::Request(NetProtocolVersion::CURRENT),
+                    );
+                    Self::send(
+                        &mut transport_resource,
+                        server_socket_addr,
+                        &net_handshake_event,
+                    );
                 }
+
+                Self::send(
+                    &mut transport_resource,
+                    server_socket_addr,
+                    net_message_event,
+                );
             });
     }
 }
@@ -4,7 +4,8 @@
 
 pub use crate::system::{
     GameInputTickRequestSystem, NetworkInputRequestSystem, NetworkInputRequestSystemDesc,
-    NetworkInputResponseSystem, NetworkInputResponseSystemDesc,
+    NetworkInputResponseSystem, NetworkInputResponseSystemDesc, SessionChecksumRequestSystem,
+    SessionInputCatchUpSystem,
 };
 
 mod system;
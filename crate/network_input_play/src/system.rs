@@ -2,8 +2,12 @@ pub use self::{
     game_input_tick_request_system::GameInputTickRequestSystem,
     network_input_request_system::{NetworkInputRequestSystem, NetworkInputRequestSystemDesc},
     network_input_response_system::{NetworkInputResponseSystem, NetworkInputResponseSystemDesc},
+    session_checksum_request_system::SessionChecksumRequestSystem,
+    session_input_catch_up_system::SessionInputCatchUpSystem,
 };
 
 mod game_input_tick_request_system;
 mod network_input_request_system;
 mod network_input_response_system;
+mod session_checksum_request_system;
+mod session_input_catch_up_system;
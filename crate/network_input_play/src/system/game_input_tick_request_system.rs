@@ -5,11 +5,13 @@ use amethyst::{
 };
 use derivative::Derivative;
 use derive_new::new;
+use game_play_model::GamePlayStatus;
 use net_model::play::NetMessageEvent;
 use network_session_model::{
     play::{SessionCondition, SessionStatus},
     SessionMessageEvent,
 };
+use rollback_model::config::SessionSyncMode;
 
 /// Informs the session server all client network input has been sent.
 #[derive(Debug, new)]
@@ -24,6 +26,12 @@ pub struct GameInputTickRequestSystemData<'s> {
     /// `SessionCondition` resource.
     #[derivative(Debug = "ignore")]
     pub session_condition: Write<'s, SessionCondition>,
+    /// `SessionSyncMode` resource.
+    #[derivative(Debug = "ignore")]
+    pub session_sync_mode: Read<'s, SessionSyncMode>,
+    /// `GamePlayStatus` resource.
+    #[derivative(Debug = "ignore")]
+    pub game_play_status: Read<'s, GamePlayStatus>,
     /// `NetworkMessageEvent` channel.
     #[derivative(Debug = "ignore")]
     pub net_message_ec: Write<'s, EventChannel<NetMessageEvent>>,
@@ -37,6 +45,8 @@ impl<'s> System<'s> for GameInputTickRequestSystem {
         GameInputTickRequestSystemData {
             session_status,
             mut session_condition,
+            session_sync_mode,
+            game_play_status,
             mut net_message_ec,
         }: Self::SystemData,
     ) {
@@ -44,7 +54,12 @@ impl<'s> System<'s> for GameInputTickRequestSystem {
         let session_established = *session_status == SessionStatus::JoinEstablished
             || *session_status == SessionStatus::HostEstablished;
 
-        if session_established && *session_condition == SessionCondition::Ready {
+        // Rollback game play does not wait for other devices' input.
+        let rollback_active =
+            session_sync_mode.is_rollback_active(&session_status, *game_play_status);
+
+        if session_established && !rollback_active && *session_condition == SessionCondition::Ready
+        {
             net_message_ec.single_write(NetMessageEvent::SessionMessageEvent(
                 SessionMessageEvent::GameInputTick,
             ));
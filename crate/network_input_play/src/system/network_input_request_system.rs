@@ -9,9 +9,11 @@ use amethyst::{
 };
 use derivative::Derivative;
 use derive_new::new;
-use game_input_model::{config::ControlBindings, GameInputEvent};
+use game_input_model::{config::ControlBindings, play::AxisInputQuantizer, GameInputEvent};
+use game_play_model::GamePlayStatus;
 use net_model::play::NetMessageEvent;
 use network_session_model::play::SessionStatus;
+use rollback_model::config::SessionSyncMode;
 
 /// Sends network input to a session server.
 #[derive(Debug, SystemDesc, new)]
@@ -20,6 +22,10 @@ pub struct NetworkInputRequestSystem {
     /// Reader ID for the `InputEvent<ControlBindings>` channel.
     #[system_desc(event_channel_reader)]
     input_event_rid: ReaderId<InputEvent<ControlBindings>>,
+    /// Quantizes analog axis values into the same values as keyboard axes.
+    #[system_desc(skip)]
+    #[new(default)]
+    axis_input_quantizer: AxisInputQuantizer,
 }
 
 #[derive(Derivative, SystemData)]
@@ -31,6 +37,12 @@ pub struct NetworkInputRequestSystemData<'s> {
     /// `SessionStatus` resource.
     #[derivative(Debug = "ignore")]
     pub session_status: Read<'s, SessionStatus>,
+    /// `SessionSyncMode` resource.
+    #[derivative(Debug = "ignore")]
+    pub session_sync_mode: Read<'s, SessionSyncMode>,
+    /// `GamePlayStatus` resource.
+    #[derivative(Debug = "ignore")]
+    pub game_play_status: Read<'s, GamePlayStatus>,
     /// `NetworkMessageEvent` channel.
     #[derivative(Debug = "ignore")]
     pub net_message_ec: Write<'s, EventChannel<NetMessageEvent>>,
@@ -44,17 +56,25 @@ impl<'s> System<'s> for NetworkInputRequestSystem {
         NetworkInputRequestSystemData {
             input_ec,
             session_status,
+            session_sync_mode,
+            game_play_status,
             mut net_message_ec,
         }: Self::SystemData,
     ) {
+        let axis_input_quantizer = &mut self.axis_input_quantizer;
         let input_events = input_ec.read(&mut self.input_event_rid);
 
         // Guard against sending input events if the application is not in a session.
-        if *session_status == SessionStatus::JoinEstablished
-            || *session_status == SessionStatus::HostEstablished
+        let session_established = *session_status == SessionStatus::JoinEstablished
+            || *session_status == SessionStatus::HostEstablished;
+
+        // Input is sent by the `RollbackInputRequestSystem` during rollback game play.
+        if session_established
+            && !session_sync_mode.is_rollback_active(&session_status, *game_play_status)
         {
             input_events
                 .filter_map(|ev| GameInputEvent::try_from(ev).ok())
+                .filter_map(|ev| axis_input_quantizer.quantize(ev))
                 .for_each(|ev| {
                     net_message_ec.single_write(NetMessageEvent::GameInputEvent(ev));
                 });
@@ -10,8 +10,12 @@ use game_input_model::GameInputEvent;
 use log::debug;
 use net_model::play::{NetData, NetEventChannel};
 use network_session_model::play::SessionStatus;
+use rollback_model::play::SessionInputHistory;
 
 /// Writes received `GameInputEvent`s from the net channel to the regular event channel.
+///
+/// While a spectator is catching up with a session, received events are recorded in the
+/// `SessionInputHistory` instead, to be applied after the earlier input.
 #[derive(Debug, SystemDesc, new)]
 #[system_desc(name(NetworkInputResponseSystemDesc))]
 pub struct NetworkInputResponseSystem {
@@ -32,6 +36,9 @@ pub struct NetworkInputResponseSystemData<'s> {
     /// `SessionStatus` resource.
     #[derivative(Debug = "ignore")]
     pub session_status: Read<'s, SessionStatus>,
+    /// `SessionInputHistory` resource.
+    #[derivative(Debug = "ignore")]
+    pub session_input_history: Write<'s, SessionInputHistory>,
 }
 
 impl<'s> System<'s> for NetworkInputResponseSystem {
@@ -43,6 +50,7 @@ impl<'s> System<'s> for NetworkInputResponseSystem {
             game_input_nec,
             mut game_input_ec,
             session_status,
+            mut session_input_history,
         }: Self::SystemData,
     ) {
         let game_input_events = game_input_nec.read(&mut self.game_input_event_rid);
@@ -62,7 +70,11 @@ impl<'s> System<'s> for NetworkInputResponseSystem {
                     game_input_event
                 );
 
-                game_input_ec.single_write(*game_input_event);
+                if session_input_history.is_empty() {
+                    game_input_ec.single_write(*game_input_event);
+                } else {
+                    session_input_history.push_game_input_event(*game_input_event);
+                }
             });
         }
     }
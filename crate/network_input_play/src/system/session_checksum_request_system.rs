@@ -0,0 +1,160 @@
+use amethyst::{
+    core::Transform,
+    ecs::{Entities, Join, Read, ReadStorage, System, World, Write},
+    shred::{ResourceId, SystemData},
+    shrev::EventChannel,
+};
+use derivative::Derivative;
+use derive_new::new;
+use game_input_model::play::InputControlled;
+use game_play_model::{GamePlayEntity, GamePlayStatus};
+use net_model::play::NetMessageEvent;
+use network_session_model::{
+    play::{
+        SessionChecksum, SessionChecksumEntry, SessionChecksums, SessionCondition, SessionStatus,
+        SessionTickCount,
+    },
+    SessionMessageEvent,
+};
+use object_model::play::HealthPoints;
+use rollback_model::config::SessionSyncMode;
+use sequence_model::loaded::SequenceId;
+
+/// Number of ticks whose checksums are sent to the session server together.
+const SESSION_CHECKSUM_INTERVAL: usize = 10;
+
+/// Calculates a checksum of the game play state each session tick, and periodically sends them to
+/// the session server.
+///
+/// This must run after the game play systems, so that the checksum includes the tick's changes.
+///
+/// Only lockstep sessions are checked. Rollback sessions are not covered: their game play state is
+/// simulated ahead of confirmed input, and restored snapshots are not checksummed, so no
+/// checksums are sent while rollback is active.
+#[derive(Debug, new)]
+pub struct SessionChecksumRequestSystem {
+    /// Session tick of the last checksum.
+    #[new(default)]
+    session_tick_count_last: Option<SessionTickCount>,
+    /// Checksums that have not been sent.
+    #[new(default)]
+    session_checksums: SessionChecksums,
+}
+
+/// `SessionChecksumRequestSystemData`.
+#[derive(Derivative, SystemData)]
+#[derivative(Debug)]
+pub struct SessionChecksumRequestSystemData<'s> {
+    /// `SessionStatus` resource.
+    #[derivative(Debug = "ignore")]
+    pub session_status: Read<'s, SessionStatus>,
+    /// `SessionSyncMode` resource.
+    #[derivative(Debug = "ignore")]
+    pub session_sync_mode: Read<'s, SessionSyncMode>,
+    /// `GamePlayStatus` resource.
+    #[derivative(Debug = "ignore")]
+    pub game_play_status: Read<'s, GamePlayStatus>,
+    /// `SessionCondition` resource.
+    #[derivative(Debug = "ignore")]
+    pub session_condition: Read<'s, SessionCondition>,
+    /// `SessionTickCount` resource.
+    #[derivative(Debug = "ignore")]
+    pub session_tick_count: Read<'s, SessionTickCount>,
+    /// `Entities` resource.
+    #[derivative(Debug = "ignore")]
+    pub entities: Entities<'s>,
+    /// `GamePlayEntity` components.
+    #[derivative(Debug = "ignore")]
+    pub game_play_entities: ReadStorage<'s, GamePlayEntity>,
+    /// `InputControlled` components.
+    #[derivative(Debug = "ignore")]
+    pub input_controlleds: ReadStorage<'s, InputControlled>,
+    /// `Transform` components.
+    #[derivative(Debug = "ignore")]
+    pub transforms: ReadStorage<'s, Transform>,
+    /// `HealthPoints` components.
+    #[derivative(Debug = "ignore")]
+    pub health_pointses: ReadStorage<'s, HealthPoints>,
+    /// `SequenceId` components.
+    #[derivative(Debug = "ignore")]
+    pub sequence_ids: ReadStorage<'s, SequenceId>,
+    /// `NetMessageEvent` channel.
+    #[derivative(Debug = "ignore")]
+    pub net_message_ec: Write<'s, EventChannel<NetMessageEvent>>,
+}
+
+impl<'s> System<'s> for SessionChecksumRequestSystem {
+    type SystemData = SessionChecksumRequestSystemData<'s>;
+
+    fn run(
+        &mut self,
+        SessionChecksumRequestSystemData {
+            session_status,
+            session_sync_mode,
+            game_play_status,
+            session_condition,
+            session_tick_count,
+            entities,
+            game_play_entities,
+            input_controlleds,
+            transforms,
+            health_pointses,
+            sequence_ids,
+            mut net_message_ec,
+        }: Self::SystemData,
+    ) {
+        let session_established = *session_status == SessionStatus::JoinEstablished
+            || *session_status == SessionStatus::HostEstablished;
+
+        // Rollback game play simulates ahead of confirmed input, so its state is not comparable,
+        // and is not checked.
+        let lockstep_playing = session_established
+            && *game_play_status == GamePlayStatus::Playing
+            && !session_sync_mode.is_rollback_active(&session_status, *game_play_status);
+        if !lockstep_playing {
+            self.session_tick_count_last = None;
+            self.session_checksums.clear();
+            return;
+        }
+
+        // The game only ticks in frames where the session condition is ready.
+        let session_ticked = *session_condition == SessionCondition::Ready
+            && self.session_tick_count_last != Some(*session_tick_count);
+        if !session_ticked {
+            return;
+        }
+        self.session_tick_count_last = Some(*session_tick_count);
+
+        let entries = (
+            &entities,
+            &game_play_entities,
+            input_controlleds.maybe(),
+            &transforms,
+            health_pointses.maybe(),
+            &sequence_ids,
+        )
+            .join()
+            .map(
+                |(_, _, input_controlled, transform, health_points, sequence_id)| {
+                    let translation = transform.translation();
+                    SessionChecksumEntry::new(
+                        input_controlled.map(|input_controlled| input_controlled.controller_id),
+                        [translation.x, translation.y, translation.z],
+                        health_points.map(|health_points| health_points.0),
+                        sequence_id.0,
+                    )
+                },
+            )
+            .collect::<Vec<SessionChecksumEntry>>();
+
+        self.session_checksums
+            .push(SessionChecksum::from_entries(*session_tick_count, entries));
+
+        if self.session_checksums.len() >= SESSION_CHECKSUM_INTERVAL {
+            let session_checksums = std::mem::take(&mut self.session_checksums);
+            net_message_ec.single_write(NetMessageEvent::SessionMessageEvent(
+                SessionMessageEvent::SessionChecksums(session_checksums),
+            ));
+        }
+    }
+}
@@ -0,0 +1,130 @@
+use amethyst::{
+    ecs::{Read, System, World, Write},
+    shred::{ResourceId, SystemData},
+    shrev::EventChannel,
+};
+use derivative::Derivative;
+use derive_new::new;
+use game_input_model::GameInputEvent;
+use game_play_model::GamePlayStatus;
+use log::debug;
+use network_session_model::play::{SessionCondition, SessionStatus, SessionTickCount};
+use rollback_model::{
+    config::SessionSyncMode,
+    play::{InputHistory, RollbackStatus, SessionInputHistory},
+};
+
+/// Applies a session's earlier input to a spectator that joined after the session started, or to a
+/// device that reconnected to the session.
+///
+/// One tick of recorded input is applied per frame, in place of the session server's
+/// `GameInputTick`, until the spectator has caught up with the live input.
+///
+/// During rollback game play, recorded input within the rollback window is inserted at its original
+/// tick, and earlier input is discarded. Spectators also record the latest tick that every device
+/// has confirmed, which they do not simulate past.
+#[derive(Debug, new)]
+pub struct SessionInputCatchUpSystem;
+
+/// `SessionInputCatchUpSystemData`.
+#[derive(Derivative, SystemData)]
+#[derivative(Debug)]
+pub struct SessionInputCatchUpSystemData<'s> {
+    /// `SessionStatus` resource.
+    #[derivative(Debug = "ignore")]
+    pub session_status: Read<'s, SessionStatus>,
+    /// `SessionSyncMode` resource.
+    #[derivative(Debug = "ignore")]
+    pub session_sync_mode: Read<'s, SessionSyncMode>,
+    /// `GamePlayStatus` resource.
+    #[derivative(Debug = "ignore")]
+    pub game_play_status: Read<'s, GamePlayStatus>,
+    /// `SessionCondition` resource.
+    #[derivative(Debug = "ignore")]
+    pub session_condition: Write<'s, SessionCondition>,
+    /// `SessionTickCount` resource.
+    #[derivative(Debug = "ignore")]
+    pub session_tick_count: Write<'s, SessionTickCount>,
+    /// `SessionInputHistory` resource.
+    #[derivative(Debug = "ignore")]
+    pub session_input_history: Write<'s, SessionInputHistory>,
+    /// `GameInputEvent` channel.
+    #[derivative(Debug = "ignore")]
+    pub game_input_ec: Write<'s, EventChannel<GameInputEvent>>,
+    /// `RollbackStatus` resource.
+    #[derivative(Debug = "ignore")]
+    pub rollback_status: Write<'s, RollbackStatus>,
+    /// `InputHistory` resource.
+    #[derivative(Debug = "ignore")]
+    pub input_history: Write<'s, InputHistory>,
+}
+
+impl<'s> System<'s> for SessionInputCatchUpSystem {
+    type SystemData = SessionInputCatchUpSystemData<'s>;
+
+    fn run(
+        &mut self,
+        SessionInputCatchUpSystemData {
+            session_status,
+            session_sync_mode,
+            game_play_status,
+            mut session_condition,
+            mut session_tick_count,
+            mut session_input_history,
+            mut game_input_ec,
+            mut rollback_status,
+            mut input_history,
+        }: Self::SystemData,
+    ) {
+        let session_established = *session_status == SessionStatus::JoinEstablished
+            || *session_status == SessionStatus::HostEstablished;
+
+        if !session_established || session_input_history.is_empty() {
+            return;
+        }
+
+        if session_sync_mode.is_rollback_active(&session_status, *game_play_status) {
+            // Lockstep input does not apply during rollback game play.
+            session_input_history.game_input_ticks.clear();
+            session_input_history.game_input_events_pending.clear();
+
+            let ticked_game_input_events =
+                std::mem::take(&mut session_input_history.ticked_game_input_events);
+            debug!(
+                "Catching up with {} `TickedGameInputEvent`s.",
+                ticked_game_input_events.len()
+            );
+
+            // Spectators are only sent confirmed input, and do not simulate past the confirmed
+            // tick.
+            if let Some(tick_confirmed) = session_input_history.tick_confirmed.take() {
+                rollback_status.tick_confirmed = Some(tick_confirmed);
+            }
+
+            // Input older than the rollback window can no longer be applied, so it is discarded
+            // instead of being applied at the wrong tick. Spectators start simulating from the
+            // first tick, so they receive all of the session's rollback input.
+            let tick_min = rollback_status.tick_min();
+            ticked_game_input_events
+                .into_iter()
+                .filter(|ticked_game_input_event| ticked_game_input_event.tick >= tick_min)
+                .for_each(|ticked_game_input_event| {
+                    rollback_status.request_rollback(ticked_game_input_event.tick);
+                    input_history.insert(ticked_game_input_event);
+                });
+        } else if *session_condition == SessionCondition::PendingGameInputTick {
+            if let Some(game_input_events) = session_input_history.pop_tick() {
+                game_input_ec.iter_write(game_input_events);
+                *session_condition = SessionCondition::Ready;
+                session_tick_count.0 += 1;
+            } else {
+                // Caught up to the tick in progress, which is completed by the live `GameInputTick`.
+                debug!("Caught up with session input.");
+
+                let game_input_events =
+                    std::mem::take(&mut session_input_history.game_input_events_pending);
+                game_input_ec.iter_write(game_input_events);
+            }
+        }
+    }
+}
@@ -1,8 +1,13 @@
+use std::any;
+
 use amethyst::{GameData, Trans};
 use application_event::AppEvent;
 use network_mode_selection_model::NetworkModeIndex;
+use session_browse::{SessionBrowseStateBuilder, SessionBrowseStateDelegate};
+use session_browse_play::{SessionListingSelectionSystem, SessionListingsDisplaySystem};
 use session_host::{SessionHostStateBuilder, SessionHostStateDelegate};
-use session_join::{SessionJoinStateBuilder, SessionJoinStateDelegate};
+use session_join::{SessionJoinState, SessionJoinStateBuilder, SessionJoinStateDelegate};
+use session_join_play::{SessionCodePrefillSystem, SessionJoinStatusDisplaySystem};
 
 /// Returns the `Trans` for a given `NetworkModeIndex`.
 #[derive(Debug)]
@@ -23,12 +28,42 @@ impl NetworkModeSelectionTrans {
 
                 Trans::Push(Box::new(state))
             }
-            NetworkModeIndex::Join => {
-                let state = SessionJoinStateBuilder::new(SessionJoinStateDelegate::new()).build();
+            NetworkModeIndex::Join => Trans::Push(Box::new(Self::session_join_state())),
+            NetworkModeIndex::Browse => {
+                let session_join_state_fn = || Box::new(Self::session_join_state());
+                let state = SessionBrowseStateBuilder::new(SessionBrowseStateDelegate::new(
+                    session_join_state_fn,
+                ))
+                .with_system(
+                    SessionListingSelectionSystem::new(),
+                    any::type_name::<SessionListingSelectionSystem>(),
+                    &[],
+                )
+                .with_system(
+                    SessionListingsDisplaySystem::new(),
+                    any::type_name::<SessionListingsDisplaySystem>(),
+                    &[any::type_name::<SessionListingSelectionSystem>()],
+                )
+                .build();
 
                 Trans::Push(Box::new(state))
             }
             NetworkModeIndex::Back => Trans::Pop,
         }
     } // kcov-ignore
+
+    fn session_join_state() -> SessionJoinState {
+        SessionJoinStateBuilder::new(SessionJoinStateDelegate::new())
+            .with_system(
+                SessionJoinStatusDisplaySystem::new(),
+                any::type_name::<SessionJoinStatusDisplaySystem>(),
+                &[],
+            )
+            .with_system(
+                SessionCodePrefillSystem::new(),
+                any::type_name::<SessionCodePrefillSystem>(),
+                &[],
+            )
+            .build()
+    }
 }
@@ -3,12 +3,14 @@
 //! Types used during network mode selection.
 
 pub use crate::{
+    net_server_list_entity::NetServerListEntity,
     network_mode_index::NetworkModeIndex,
     network_mode_selection_entity::NetworkModeSelectionEntity,
     network_mode_selection_event::NetworkModeSelectionEvent,
     network_mode_selection_event_args::NetworkModeSelectionEventArgs,
 };
 
+mod net_server_list_entity;
 mod network_mode_index;
 mod network_mode_selection_entity;
 mod network_mode_selection_event;
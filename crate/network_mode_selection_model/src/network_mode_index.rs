@@ -12,6 +12,8 @@ pub enum NetworkModeIndex {
     Host,
     /// Join a game.
     Join,
+    /// Browse public games.
+    Browse,
     /// Return to the previous menu.
     Back,
 }
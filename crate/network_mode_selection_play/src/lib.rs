@@ -0,0 +1,7 @@
+#![deny(missing_debug_implementations, missing_docs)] // kcov-ignore
+
+//! Provides logic for the network mode selection screen.
+
+pub use crate::system::{NetServerListDisplaySystem, NetServerSelectionSystem};
+
+mod system;
@@ -0,0 +1,7 @@
+pub use self::{
+    net_server_list_display_system::NetServerListDisplaySystem,
+    net_server_selection_system::NetServerSelectionSystem,
+};
+
+mod net_server_list_display_system;
+mod net_server_selection_system;
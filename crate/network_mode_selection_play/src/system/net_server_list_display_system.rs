@@ -0,0 +1,155 @@
+use std::{fmt::Write as _, net::SocketAddr};
+
+use amethyst::{
+    ecs::{Entities, Join, Read, ReadExpect, System, World, WriteStorage},
+    shred::{ResourceId, SystemData},
+    ui::{Anchor, UiText, UiTransform},
+};
+use application_ui::{FontVariant, Theme};
+use derivative::Derivative;
+use derive_new::new;
+use net_model::play::NetServerDiscoveries;
+use network_mode_selection_model::{NetServerListEntity, NetworkModeSelectionEntity};
+use network_session_model::config::SessionServerConfig;
+
+const FONT_COLOUR: [f32; 4] = [0.8, 0.8, 0.8, 1.];
+const FONT_SIZE_WIDGET: f32 = 20.;
+const LABEL_WIDTH: f32 = 800.;
+const LABEL_HEIGHT: f32 = 200.;
+
+/// Displays the session servers found on the local network.
+#[derive(Debug, Default, new)]
+pub struct NetServerListDisplaySystem;
+
+/// `NetServerListDisplaySystemData`.
+#[derive(Derivative, SystemData)]
+#[derivative(Debug)]
+pub struct NetServerListDisplaySystemData<'s> {
+    /// `Entities`.
+    #[derivative(Debug = "ignore")]
+    pub entities: Entities<'s>,
+    /// `NetServerDiscoveries` resource.
+    #[derivative(Debug = "ignore")]
+    pub net_server_discoveries: Read<'s, NetServerDiscoveries>,
+    /// `SessionServerConfig` resource.
+    #[derivative(Debug = "ignore")]
+    pub session_server_config: ReadExpect<'s, SessionServerConfig>,
+    /// `NetServerListEntity` components.
+    #[derivative(Debug = "ignore")]
+    pub net_server_list_entities: WriteStorage<'s, NetServerListEntity>,
+    /// `NetworkModeSelectionEntity` components.
+    #[derivative(Debug = "ignore")]
+    pub network_mode_selection_entities: WriteStorage<'s, NetworkModeSelectionEntity>,
+
+    // Resources needed to display text.
+    /// `Theme` resource.
+    #[derivative(Debug = "ignore")]
+    pub theme: ReadExpect<'s, Theme>,
+    /// `UiTransform` components.
+    #[derivative(Debug = "ignore")]
+    pub ui_transforms: WriteStorage<'s, UiTransform>,
+    /// `UiText` components.
+    #[derivative(Debug = "ignore")]
+    pub ui_texts: WriteStorage<'s, UiText>,
+}
+
+impl NetServerListDisplaySystem {
+    /// Returns the text listing the discovered session servers.
+    fn server_list_text(
+        net_server_discoveries: &NetServerDiscoveries,
+        session_server_config: &SessionServerConfig,
+    ) -> String {
+        let mut text = String::from("LAN session servers (press Special to select):");
+
+        if net_server_discoveries.is_empty() {
+            text.push_str("\n  Searching...");
+        } else {
+            let socket_addr_selected =
+                SocketAddr::new(session_server_config.address, session_server_config.port);
+
+            net_server_discoveries
+                .iter()
+                .for_each(|net_server_discovery| {
+                    let marker = if net_server_discovery.socket_addr == socket_addr_selected {
+                        '>'
+                    } else {
+                        ' '
+                    };
+                    let _ = write!(text, "\n{} {}", marker, net_server_discovery.socket_addr);
+                    if !net_server_discovery.net_protocol_version.is_current() {
+                        text.push_str(" (incompatible)");
+                    }
+                });
+        }
+
+        text
+    }
+}
+
+impl<'s> System<'s> for NetServerListDisplaySystem {
+    type SystemData = NetServerListDisplaySystemData<'s>;
+
+    fn run(
+        &mut self,
+        NetServerListDisplaySystemData {
+            entities,
+            net_server_discoveries,
+            session_server_config,
+            mut net_server_list_entities,
+            mut network_mode_selection_entities,
+            theme,
+            mut ui_transforms,
+            mut ui_texts,
+        }: Self::SystemData,
+    ) {
+        let text = Self::server_list_text(&net_server_discoveries, &session_server_config);
+
+        let ui_text_existing = (&net_server_list_entities, &mut ui_texts)
+            .join()
+            .next()
+            .map(|(_, ui_text)| ui_text);
+
+        if let Some(ui_text) = ui_text_existing {
+            if ui_text.text != text {
+                ui_text.text = text;
+            }
+        } else {
+            let font = theme
+                .fonts
+                .get(&FontVariant::Regular)
+                .expect("Failed to get regular font handle.");
+
+            let x = -LABEL_WIDTH / 2.;
+            let y = LABEL_HEIGHT / 2.;
+            let z = 1.;
+
+            let ui_transform = UiTransform::new(
+                String::from("net_server_list_text"),
+                Anchor::BottomMiddle,
+                Anchor::MiddleLeft,
+                x,
+                y,
+                z,
+                LABEL_WIDTH,
+                LABEL_HEIGHT,
+            );
+
+            let ui_text = UiText::new(font.clone(), text, FONT_COLOUR, FONT_SIZE_WIDGET);
+
+            entities
+                .build_entity()
+                .with(
+                    NetworkModeSelectionEntity,
+                    &mut network_mode_selection_entities,
+                )
+                .with(NetServerListEntity, &mut net_server_list_entities)
+                .with(ui_transform, &mut ui_transforms)
+                .with(ui_text, &mut ui_texts)
+                .build();
+        }
+    }
+
+    fn setup(&mut self, world: &mut World) {
+        Self::SystemData::setup(world);
+    }
+}
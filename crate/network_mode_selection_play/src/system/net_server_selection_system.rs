@@ -0,0 +1,105 @@
+use std::net::SocketAddr;
+
+use amethyst::{
+    ecs::{Read, System, World, WriteExpect},
+    shred::{ResourceId, SystemData},
+    shrev::{EventChannel, ReaderId},
+};
+use derivative::Derivative;
+use derive_new::new;
+use game_input_model::{
+    config::ControlAction,
+    play::{ControlActionEventData, ControlInputEvent},
+};
+use log::debug;
+use net_model::play::NetServerDiscoveries;
+use network_session_model::config::SessionServerConfig;
+
+/// Selects the next compatible session server found on the local network when `Special` is pressed.
+#[derive(Debug, Default, new)]
+pub struct NetServerSelectionSystem {
+    /// Reader ID for the `ControlInputEvent` event channel.
+    #[new(default)]
+    control_input_event_rid: Option<ReaderId<ControlInputEvent>>,
+}
+
+/// `NetServerSelectionSystemData`.
+#[derive(Derivative, SystemData)]
+#[derivative(Debug)]
+pub struct NetServerSelectionSystemData<'s> {
+    /// `ControlInputEvent` channel.
+    #[derivative(Debug = "ignore")]
+    pub control_input_ec: Read<'s, EventChannel<ControlInputEvent>>,
+    /// `NetServerDiscoveries` resource.
+    #[derivative(Debug = "ignore")]
+    pub net_server_discoveries: Read<'s, NetServerDiscoveries>,
+    /// `SessionServerConfig` resource.
+    #[derivative(Debug = "ignore")]
+    pub session_server_config: WriteExpect<'s, SessionServerConfig>,
+}
+
+impl<'s> System<'s> for NetServerSelectionSystem {
+    type SystemData = NetServerSelectionSystemData<'s>;
+
+    fn run(
+        &mut self,
+        NetServerSelectionSystemData {
+            control_input_ec,
+            net_server_discoveries,
+            mut session_server_config,
+        }: Self::SystemData,
+    ) {
+        let control_input_event_rid = self
+            .control_input_event_rid
+            .as_mut()
+            .expect("Expected `control_input_event_rid` field to be set.");
+
+        // Multiple events may be sent for the same key press, one per controlled entity, so we
+        // select at most one server per frame.
+        let special_pressed = control_input_ec.read(control_input_event_rid).any(|ev| {
+            matches!(
+                ev,
+                ControlInputEvent::ControlActionPress(ControlActionEventData {
+                    control_action: ControlAction::Special,
+                    ..
+                })
+            )
+        });
+
+        if !special_pressed {
+            return;
+        }
+
+        let socket_addr_selected =
+            SocketAddr::new(session_server_config.address, session_server_config.port);
+        let start = net_server_discoveries
+            .position(socket_addr_selected)
+            .map(|position| position + 1)
+            .unwrap_or(0);
+        let len = net_server_discoveries.len();
+
+        let net_server_discovery_next = (0..len)
+            .map(|offset| &net_server_discoveries[(start + offset) % len])
+            .find(|net_server_discovery| net_server_discovery.net_protocol_version.is_current());
+
+        if let Some(net_server_discovery) = net_server_discovery_next {
+            debug!(
+                "Selected session server: {}",
+                net_server_discovery.socket_addr
+            );
+
+            session_server_config.address = net_server_discovery.socket_addr.ip();
+            session_server_config.port = net_server_discovery.socket_addr.port();
+        }
+    }
+
+    fn setup(&mut self, world: &mut World) {
+        Self::SystemData::setup(world);
+
+        self.control_input_event_rid = Some(
+            world
+                .fetch_mut::<EventChannel<ControlInputEvent>>()
+                .register_reader(),
+        );
+    }
+}
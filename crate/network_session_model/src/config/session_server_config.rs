@@ -1,4 +1,7 @@
-use std::net::{IpAddr, Ipv4Addr};
+use std::{
+    io,
+    net::{IpAddr, Ipv4Addr, ToSocketAddrs},
+};
 
 use serde::{Deserialize, Serialize};
 
@@ -8,12 +11,44 @@ use serde::{Deserialize, Serialize};
 pub struct SessionServerConfig {
     /// Address of the server.
     ///
-    /// Currently must be an `IpAddr`, in the future we may accept hostnames.
+    /// Hostnames are resolved to an address with `SessionServerConfig::resolve`.
     pub address: IpAddr,
     /// Port that the server is listening on.
     pub port: u16,
 }
 
+impl SessionServerConfig {
+    /// Returns the `SessionServerConfig` for a session server's IP address or hostname.
+    ///
+    /// If the hostname resolves to multiple addresses, IPv4 addresses are preferred.
+    ///
+    /// # Parameters
+    ///
+    /// * `host`: IP address or hostname of the session server.
+    /// * `port`: Port that the server is listening on.
+    pub fn resolve(host: &str, port: u16) -> io::Result<Self> {
+        if let Ok(address) = host.parse::<IpAddr>() {
+            return Ok(SessionServerConfig { address, port });
+        }
+
+        let socket_addrs = (host, port).to_socket_addrs()?.collect::<Vec<_>>();
+        socket_addrs
+            .iter()
+            .find(|socket_addr| socket_addr.is_ipv4())
+            .or_else(|| socket_addrs.first())
+            .map(|socket_addr| SessionServerConfig {
+                address: socket_addr.ip(),
+                port,
+            })
+            .ok_or_else(|| {
+                io::Error::new(
+                    io::ErrorKind::NotFound,
+                    format!("No addresses found for session server host: `{}`.", host),
+                )
+            })
+    }
+}
+
 impl Default for SessionServerConfig {
     fn default() -> Self {
         SessionServerConfig {
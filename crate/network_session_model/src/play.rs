@@ -2,20 +2,39 @@
 
 pub use self::{
     network_session_model_error::NetworkSessionModelError, session::Session,
-    session_code::SessionCode, session_condition::SessionCondition, session_device::SessionDevice,
-    session_device_id::SessionDeviceId, session_device_join::SessionDeviceJoin,
-    session_device_name::SessionDeviceName, session_devices::SessionDevices,
-    session_status::SessionStatus, sessions::Sessions,
+    session_checksum::SessionChecksum, session_checksum_diff::SessionChecksumDiff,
+    session_checksum_entry::SessionChecksumEntry, session_checksums::SessionChecksums,
+    session_code::SessionCode, session_condition::SessionCondition, session_desync::SessionDesync,
+    session_device::SessionDevice, session_device_id::SessionDeviceId,
+    session_device_join::SessionDeviceJoin, session_device_latencies::SessionDeviceLatencies,
+    session_device_latency::SessionDeviceLatency, session_device_name::SessionDeviceName,
+    session_devices::SessionDevices, session_input_error::SessionInputError,
+    session_latency::SessionLatency, session_ping::SessionPing, session_pong::SessionPong,
+    session_reconnect_token::SessionReconnectToken, session_status::SessionStatus,
+    session_tick_count::SessionTickCount, sessions::Sessions,
 };
 
 mod network_session_model_error;
 mod session;
+mod session_checksum;
+mod session_checksum_diff;
+mod session_checksum_entry;
+mod session_checksums;
 mod session_code;
 mod session_condition;
+mod session_desync;
 mod session_device;
 mod session_device_id;
 mod session_device_join;
+mod session_device_latencies;
+mod session_device_latency;
 mod session_device_name;
 mod session_devices;
+mod session_input_error;
+mod session_latency;
+mod session_ping;
+mod session_pong;
+mod session_reconnect_token;
 mod session_status;
+mod session_tick_count;
 mod sessions;
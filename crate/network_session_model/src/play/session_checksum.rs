@@ -0,0 +1,76 @@
+use derive_new::new;
+use serde::{Deserialize, Serialize};
+
+use crate::play::{SessionChecksumEntry, SessionTickCount};
+
+/// FNV-1a offset basis.
+const FNV_OFFSET_BASIS: u64 = 0xcbf2_9ce4_8422_2325;
+/// FNV-1a prime.
+const FNV_PRIME: u64 = 0x0000_0100_0000_01b3;
+
+/// Checksum of the game play state of a device for a session tick.
+///
+/// The checksum is calculated from the bits of each value, so it does not depend on the platform's
+/// hasher implementation, and differs whenever any value differs. Entries are sorted by
+/// `SessionChecksumEntry::sort_key` first, so the checksum does not depend on `Entity` indices.
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize, new)]
+pub struct SessionChecksum {
+    /// Session tick that the checksum was calculated for.
+    pub session_tick_count: SessionTickCount,
+    /// Checksum of the entries.
+    pub checksum: u64,
+    /// Component values that the checksum was calculated from, used to report differences.
+    pub entries: Vec<SessionChecksumEntry>,
+}
+
+impl SessionChecksum {
+    /// Returns a `SessionChecksum` calculated from the given entries.
+    ///
+    /// The entries are sorted before the checksum is calculated.
+    pub fn from_entries(
+        session_tick_count: SessionTickCount,
+        mut entries: Vec<SessionChecksumEntry>,
+    ) -> Self {
+        entries.sort_by_key(SessionChecksumEntry::sort_key);
+
+        let checksum = entries.iter().fold(FNV_OFFSET_BASIS, |checksum, entry| {
+            let SessionChecksumEntry {
+                controller_id,
+                translation,
+                health_points,
+                sequence_id,
+            } = entry;
+
+            let checksum = match controller_id {
+                Some(controller_id) => {
+                    let checksum = Self::hash_bytes(checksum, &[1]);
+                    Self::hash_bytes(checksum, &(*controller_id as u64).to_le_bytes())
+                }
+                None => Self::hash_bytes(checksum, &[0]),
+            };
+            let checksum = translation.iter().fold(checksum, |checksum, value| {
+                Self::hash_bytes(checksum, &value.to_bits().to_le_bytes())
+            });
+            let checksum = match health_points {
+                Some(health_points) => {
+                    let checksum = Self::hash_bytes(checksum, &[1]);
+                    Self::hash_bytes(checksum, &health_points.to_le_bytes())
+                }
+                None => Self::hash_bytes(checksum, &[0]),
+            };
+            Self::hash_bytes(checksum, &(*sequence_id as u64).to_le_bytes())
+        });
+
+        SessionChecksum {
+            session_tick_count,
+            checksum,
+            entries,
+        }
+    }
+
+    fn hash_bytes(checksum: u64, bytes: &[u8]) -> u64 {
+        bytes.iter().fold(checksum, |checksum, byte| {
+            (checksum ^ u64::from(*byte)).wrapping_mul(FNV_PRIME)
+        })
+    }
+}
@@ -0,0 +1,17 @@
+use derive_new::new;
+use serde::{Deserialize, Serialize};
+
+use crate::play::{SessionChecksumEntry, SessionDeviceId};
+
+/// Entry that differs between the reference device and another device in the session.
+#[derive(Clone, Copy, Debug, Deserialize, PartialEq, Serialize, new)]
+pub struct SessionChecksumDiff {
+    /// ID of the device whose entry differs from the reference device.
+    pub session_device_id: SessionDeviceId,
+    /// Index of the entry in the `SessionChecksum`.
+    pub index: usize,
+    /// Entry on the reference device.
+    pub expected: Option<SessionChecksumEntry>,
+    /// Entry on the differing device.
+    pub actual: Option<SessionChecksumEntry>,
+}
@@ -0,0 +1,33 @@
+use derive_new::new;
+use game_input_model::config::ControllerId;
+use serde::{Deserialize, Serialize};
+
+/// Game play component values of an entity that are included in a `SessionChecksum`.
+#[derive(Clone, Copy, Debug, Deserialize, PartialEq, Serialize, new)]
+pub struct SessionChecksumEntry {
+    /// ID of the controller that controls the entity, if it is input controlled.
+    pub controller_id: Option<ControllerId>,
+    /// `Transform` translation of the entity.
+    pub translation: [f32; 3],
+    /// `HealthPoints` of the entity, if it has any.
+    pub health_points: Option<u32>,
+    /// `SequenceId` of the entity.
+    pub sequence_id: usize,
+}
+
+impl SessionChecksumEntry {
+    /// Returns the key that entries are ordered by before they are hashed.
+    ///
+    /// `Entity` indices differ between devices, so entries are ordered by controller ID, then by
+    /// their values. Entries with equal values are interchangeable, so their order does not matter.
+    pub fn sort_key(&self) -> (bool, Option<ControllerId>, usize, Option<u32>, [u32; 3]) {
+        let [x, y, z] = self.translation;
+        (
+            self.controller_id.is_none(),
+            self.controller_id,
+            self.sequence_id,
+            self.health_points,
+            [x.to_bits(), y.to_bits(), z.to_bits()],
+        )
+    }
+}
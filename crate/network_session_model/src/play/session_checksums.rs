@@ -0,0 +1,11 @@
+use derive_deref::{Deref, DerefMut};
+use derive_new::new;
+use serde::{Deserialize, Serialize};
+
+use crate::play::SessionChecksum;
+
+/// `SessionChecksum`s of consecutive session ticks, sent together.
+///
+/// Newtype for `Vec<SessionChecksum>`.
+#[derive(Clone, Debug, Default, Deref, DerefMut, Deserialize, PartialEq, Serialize, new)]
+pub struct SessionChecksums(pub Vec<SessionChecksum>);
@@ -3,6 +3,8 @@
 pub enum SessionCondition {
     /// Waiting for the `SessionMessageEvent::GameInputTick` message to be received from the server.
     PendingGameInputTick,
+    /// Spectator is waiting for every device to confirm its input for the rollback tick.
+    PendingRollbackTickConfirm,
     /// There is no pending messages for the session to proceed.
     Ready,
 }
@@ -0,0 +1,98 @@
+use std::fmt::{self, Display};
+
+use derive_new::new;
+use serde::{Deserialize, Serialize};
+
+use crate::play::{SessionChecksum, SessionChecksumDiff, SessionDeviceId, SessionTickCount};
+
+/// Game play state of devices in a session differ.
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize, new)]
+pub struct SessionDesync {
+    /// First session tick whose game play state differs.
+    pub session_tick_count: SessionTickCount,
+    /// ID of the device whose state the other devices are compared against.
+    pub session_device_id_reference: SessionDeviceId,
+    /// IDs of the devices whose state differs from the reference device.
+    pub session_device_ids: Vec<SessionDeviceId>,
+    /// Entries that differ from the reference device.
+    pub session_checksum_diffs: Vec<SessionChecksumDiff>,
+}
+
+impl SessionDesync {
+    /// Compares each device's checksum against the reference device's checksum.
+    ///
+    /// Returns `None` if all checksums match.
+    ///
+    /// # Parameters
+    ///
+    /// * `reference`: ID and checksum of the device to compare against.
+    /// * `session_checksums`: IDs and checksums of the other devices.
+    pub fn detect<'c>(
+        reference: (SessionDeviceId, &SessionChecksum),
+        session_checksums: impl Iterator<Item = (SessionDeviceId, &'c SessionChecksum)>,
+    ) -> Option<SessionDesync> {
+        let (session_device_id_reference, session_checksum_reference) = reference;
+
+        let mut session_device_ids = Vec::new();
+        let mut session_checksum_diffs = Vec::new();
+        session_checksums
+            .filter(|(_, session_checksum)| {
+                session_checksum.checksum != session_checksum_reference.checksum
+            })
+            .for_each(|(session_device_id, session_checksum)| {
+                session_device_ids.push(session_device_id);
+
+                let entries_expected = &session_checksum_reference.entries;
+                let entries_actual = &session_checksum.entries;
+                let entry_count = std::cmp::max(entries_expected.len(), entries_actual.len());
+                (0..entry_count).for_each(|index| {
+                    let expected = entries_expected.get(index).copied();
+                    let actual = entries_actual.get(index).copied();
+                    if expected != actual {
+                        session_checksum_diffs.push(SessionChecksumDiff::new(
+                            session_device_id,
+                            index,
+                            expected,
+                            actual,
+                        ));
+                    }
+                });
+            });
+
+        if session_device_ids.is_empty() {
+            None
+        } else {
+            Some(SessionDesync {
+                session_tick_count: session_checksum_reference.session_tick_count,
+                session_device_id_reference,
+                session_device_ids,
+                session_checksum_diffs,
+            })
+        }
+    }
+}
+
+impl Display for SessionDesync {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        writeln!(
+            f,
+            "Session desync at tick {}, compared against device #{}.",
+            self.session_tick_count, self.session_device_id_reference
+        )?;
+        self.session_checksum_diffs
+            .iter()
+            .try_for_each(|session_checksum_diff| {
+                let SessionChecksumDiff {
+                    session_device_id,
+                    index,
+                    expected,
+                    actual,
+                } = session_checksum_diff;
+                writeln!(
+                    f,
+                    "  device #{}, entry {}: expected `{:?}`, actual `{:?}`",
+                    session_device_id, index, expected, actual
+                )
+            })
+    }
+}
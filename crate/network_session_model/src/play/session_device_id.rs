@@ -27,6 +27,11 @@ use serde::{Deserialize, Serialize};
 )]
 pub struct SessionDeviceId(pub u64);
 
+impl SessionDeviceId {
+    /// ID of the device that hosts the session.
+    pub const HOST: SessionDeviceId = SessionDeviceId(0);
+}
+
 impl FromStr for SessionDeviceId {
     type Err = ParseIntError;
 
@@ -0,0 +1,12 @@
+use std::collections::HashMap;
+
+use derive_deref::{Deref, DerefMut};
+use derive_new::new;
+
+use crate::play::{SessionDeviceId, SessionLatency};
+
+/// Latency of each device in the session.
+///
+/// Newtype for `HashMap<SessionDeviceId, SessionLatency>`.
+#[derive(Clone, Debug, Default, Deref, DerefMut, PartialEq, new)]
+pub struct SessionDeviceLatencies(pub HashMap<SessionDeviceId, SessionLatency>);
@@ -0,0 +1,13 @@
+use derive_new::new;
+use serde::{Deserialize, Serialize};
+
+use crate::play::{SessionDeviceId, SessionLatency};
+
+/// Latency of a device in the session, relayed by the session server.
+#[derive(Clone, Copy, Debug, Deserialize, PartialEq, Serialize, new)]
+pub struct SessionDeviceLatency {
+    /// ID of the device the latency was measured by.
+    pub session_device_id: SessionDeviceId,
+    /// Latency between the device and the session server.
+    pub session_latency: SessionLatency,
+}
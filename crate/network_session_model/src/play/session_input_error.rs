@@ -0,0 +1,21 @@
+use std::fmt;
+
+use serde::{Deserialize, Serialize};
+
+/// Reason the session server rejected input from a device.
+#[derive(Clone, Copy, Debug, Deserialize, PartialEq, Serialize)]
+pub enum SessionInputError {
+    /// The device is spectating the session, so its input is not sent to other devices.
+    Spectator,
+    /// The device is not in a session.
+    SessionNotFound,
+}
+
+impl fmt::Display for SessionInputError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Spectator => write!(f, "Spectators cannot send input to the session."),
+            Self::SessionNotFound => write!(f, "Input was sent without joining a session."),
+        }
+    }
+}
@@ -0,0 +1,39 @@
+use std::time::Duration;
+
+use derive_new::new;
+use serde::{Deserialize, Serialize};
+
+/// Smoothed round trip time and jitter between a device and the session server.
+///
+/// This uses the same weights as TCP's retransmission timer calculation ([RFC 6298]).
+///
+/// [RFC 6298]: https://tools.ietf.org/html/rfc6298
+#[derive(Clone, Copy, Debug, Default, Deserialize, PartialEq, Serialize, new)]
+pub struct SessionLatency {
+    /// Smoothed round trip time.
+    pub rtt: Duration,
+    /// Smoothed variation between round trip time samples.
+    pub jitter: Duration,
+}
+
+impl SessionLatency {
+    /// Returns a `SessionLatency` initialized from the first round trip time sample.
+    pub fn from_sample(sample: Duration) -> Self {
+        SessionLatency {
+            rtt: sample,
+            jitter: sample / 2,
+        }
+    }
+
+    /// Updates the smoothed values with a new round trip time sample.
+    pub fn update(&mut self, sample: Duration) {
+        let deviation = if self.rtt > sample {
+            self.rtt - sample
+        } else {
+            sample - self.rtt
+        };
+
+        self.jitter = (self.jitter * 3 + deviation) / 4;
+        self.rtt = (self.rtt * 7 + sample) / 8;
+    }
+}
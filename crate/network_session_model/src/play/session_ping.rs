@@ -0,0 +1,13 @@
+use derive_new::new;
+use serde::{Deserialize, Serialize};
+
+use crate::play::SessionLatency;
+
+/// Latency probe sent from a device to the session server.
+#[derive(Clone, Copy, Debug, Deserialize, PartialEq, Serialize, new)]
+pub struct SessionPing {
+    /// Sequence number of this ping, echoed back in the `SessionPong`.
+    pub sequence: u64,
+    /// Latency measured by the device so far, which is relayed to other devices.
+    pub session_latency: Option<SessionLatency>,
+}
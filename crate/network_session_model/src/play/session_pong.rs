@@ -0,0 +1,9 @@
+use derive_new::new;
+use serde::{Deserialize, Serialize};
+
+/// Response from the session server to a `SessionPing`.
+#[derive(Clone, Copy, Debug, Deserialize, PartialEq, Serialize, new)]
+pub struct SessionPong {
+    /// Sequence number of the `SessionPing` this responds to.
+    pub sequence: u64,
+}
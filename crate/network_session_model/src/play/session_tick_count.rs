@@ -0,0 +1,44 @@
+use std::{
+    fmt::{self, Display},
+    num::ParseIntError,
+    str::FromStr,
+};
+
+use derive_deref::{Deref, DerefMut};
+use derive_new::new;
+use serde::{Deserialize, Serialize};
+
+/// Number of `GameInputTick`s this client has applied since the session started (`u64` newtype).
+///
+/// This is sent when reconnecting, so the session server only sends the input that was missed.
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Default,
+    Deref,
+    DerefMut,
+    Deserialize,
+    Eq,
+    Hash,
+    PartialEq,
+    PartialOrd,
+    Ord,
+    Serialize,
+    new,
+)]
+pub struct SessionTickCount(pub u64);
+
+impl FromStr for SessionTickCount {
+    type Err = ParseIntError;
+
+    fn from_str(session_tick_count_str: &str) -> Result<Self, ParseIntError> {
+        session_tick_count_str.parse::<u64>().map(SessionTickCount)
+    }
+}
+
+impl Display for SessionTickCount {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
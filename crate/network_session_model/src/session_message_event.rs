@@ -1,6 +1,9 @@
 use serde::{Deserialize, Serialize};
 
-use crate::play::SessionDeviceJoin;
+use crate::play::{
+    SessionChecksums, SessionDesync, SessionDeviceJoin, SessionDeviceLatency, SessionInputError,
+    SessionPing, SessionPong,
+};
 
 /// Session message events.
 #[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
@@ -14,4 +17,16 @@ pub enum SessionMessageEvent {
     GameInputTick,
     /// An additional device joined the session.
     SessionDeviceJoin(SessionDeviceJoin),
+    /// Latency probe sent by a client, which the session server responds to with `SessionPong`.
+    SessionPing(SessionPing),
+    /// Response from the session server to a `SessionPing`.
+    SessionPong(SessionPong),
+    /// Latency of a device in the session, sent by the session server to all devices.
+    SessionDeviceLatencyNotify(SessionDeviceLatency),
+    /// Checksums of the game play state for recent ticks, sent by a client.
+    SessionChecksums(SessionChecksums),
+    /// Game play state of devices in the session differ, sent by the session server to all devices.
+    SessionDesyncNotify(SessionDesync),
+    /// Input from a device was not sent to the session, sent by the session server to the device.
+    SessionInputReject(SessionInputError),
 }
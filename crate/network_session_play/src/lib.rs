@@ -4,12 +4,14 @@
 
 pub use crate::{
     session_code_generator::SessionCodeGenerator,
+    session_reconnect_token_generator::SessionReconnectTokenGenerator,
     system::{
         SessionInputResourcesSyncSystem, SessionInputResourcesSyncSystemDesc,
-        SessionMessageResponseSystem, SessionMessageResponseSystemDesc,
-        SessionStatusNotifierSystem,
+        SessionMessageResponseSystem, SessionMessageResponseSystemDesc, SessionPingSystem,
+        SessionPingSystemDesc, SessionStatusNotifierSystem,
     },
 };
 
 mod session_code_generator;
+mod session_reconnect_token_generator;
 mod system;
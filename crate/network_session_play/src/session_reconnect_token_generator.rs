@@ -0,0 +1,25 @@
+use derive_new::new;
+use network_session_model::play::SessionReconnectToken;
+use rand::{rngs::StdRng, Rng, SeedableRng};
+
+/// Generates reconnect tokens for session devices.
+#[derive(Debug, new)]
+pub struct SessionReconnectTokenGenerator {
+    /// The random number generator to use.
+    rng: StdRng,
+}
+
+impl Default for SessionReconnectTokenGenerator {
+    fn default() -> Self {
+        SessionReconnectTokenGenerator {
+            rng: StdRng::from_entropy(),
+        }
+    }
+}
+
+impl SessionReconnectTokenGenerator {
+    /// Returns a randomly generated reconnect token.
+    pub fn generate(&mut self) -> SessionReconnectToken {
+        SessionReconnectToken::new(self.rng.gen())
+    }
+}
@@ -5,9 +5,11 @@ pub use self::{
     session_message_response_system::{
         SessionMessageResponseSystem, SessionMessageResponseSystemDesc,
     },
+    session_ping_system::{SessionPingSystem, SessionPingSystemDesc},
     session_status_notifier_system::SessionStatusNotifierSystem,
 };
 
 mod session_input_resources_sync_system;
 mod session_message_response_system;
+mod session_ping_system;
 mod session_status_notifier_system;
@@ -7,12 +7,16 @@ use amethyst::{
 use derivative::Derivative;
 use derive_new::new;
 use game_input_model::loaded::PlayerControllers;
-use log::debug;
+use log::{debug, error, warn};
 use net_model::play::{NetData, NetEventChannel};
 use network_session_model::{
-    play::{SessionCondition, SessionDeviceJoin, SessionDevices, SessionStatus},
+    play::{
+        SessionCondition, SessionDeviceJoin, SessionDeviceLatencies, SessionDeviceLatency,
+        SessionDevices, SessionStatus, SessionTickCount,
+    },
     SessionMessageEvent,
 };
+use rollback_model::play::SessionInputHistory;
 
 /// Records the session code and devices in the world when accepted into a session.
 #[derive(Debug, SystemDesc, new)]
@@ -41,6 +45,15 @@ pub struct SessionMessageResponseSystemData<'s> {
     /// `PlayerControllers` resource.
     #[derivative(Debug = "ignore")]
     pub player_controllers: Write<'s, PlayerControllers>,
+    /// `SessionInputHistory` resource.
+    #[derivative(Debug = "ignore")]
+    pub session_input_history: Write<'s, SessionInputHistory>,
+    /// `SessionTickCount` resource.
+    #[derivative(Debug = "ignore")]
+    pub session_tick_count: Write<'s, SessionTickCount>,
+    /// `SessionDeviceLatencies` resource.
+    #[derivative(Debug = "ignore")]
+    pub session_device_latencies: Write<'s, SessionDeviceLatencies>,
 }
 
 impl<'s> System<'s> for SessionMessageResponseSystem {
@@ -54,6 +67,9 @@ impl<'s> System<'s> for SessionMessageResponseSystem {
             mut session_condition,
             mut session_devices,
             mut player_controllers,
+            mut session_input_history,
+            mut session_tick_count,
+            mut session_device_latencies,
         }: Self::SystemData,
     ) {
         let session_message_events = session_message_nec.read(&mut self.session_message_event_rid);
@@ -69,7 +85,14 @@ impl<'s> System<'s> for SessionMessageResponseSystem {
                     SessionMessageEvent::GameInputTick => {
                         // Have received all `GameInputEvent`s from the session server.
                         debug!("Session `GameInputTick` received.");
-                        *session_condition = SessionCondition::Ready;
+
+                        // While catching up, the tick is applied after the earlier input.
+                        if session_input_history.is_empty() {
+                            *session_condition = SessionCondition::Ready;
+                            session_tick_count.0 += 1;
+                        } else {
+                            session_input_history.tick();
+                        }
                     }
                     SessionMessageEvent::SessionDeviceJoin(session_device_join) => {
                         let SessionDeviceJoin {
@@ -82,11 +105,34 @@ impl<'s> System<'s> for SessionMessageResponseSystem {
                         session_devices.push(session_device.clone());
                         *player_controllers = player_controllers_received.clone();
                     }
+                    SessionMessageEvent::SessionDeviceLatencyNotify(session_device_latency) => {
+                        let SessionDeviceLatency {
+                            session_device_id,
+                            session_latency,
+                        } = *session_device_latency;
+
+                        session_device_latencies.insert(session_device_id, session_latency);
+                    }
+                    SessionMessageEvent::SessionDesyncNotify(session_desync) => {
+                        error!("{}", session_desync);
+                    }
+                    SessionMessageEvent::SessionInputReject(session_input_error) => {
+                        warn!("Session server rejected input: {}", session_input_error);
+                    }
+                    // Handled by `SessionPingSystem`.
+                    SessionMessageEvent::SessionPong(_) => {}
+                    // Only sent by clients.
+                    SessionMessageEvent::SessionPing(_)
+                    | SessionMessageEvent::SessionChecksums(_) => {}
                 }
             });
         } else {
             // Unfreeze the other systems if not in a network game.
             *session_condition = SessionCondition::Ready;
+
+            if !session_device_latencies.is_empty() {
+                session_device_latencies.clear();
+            }
         }
     }
 }
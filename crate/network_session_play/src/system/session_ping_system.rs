@@ -0,0 +1,156 @@
+use std::{
+    collections::VecDeque,
+    time::{Duration, Instant},
+};
+
+use amethyst::{
+    derive::SystemDesc,
+    ecs::{Read, System, World, Write},
+    shred::{ResourceId, SystemData},
+    shrev::{EventChannel, ReaderId},
+};
+use derivative::Derivative;
+use derive_new::new;
+use log::debug;
+use net_model::play::{NetData, NetEventChannel, NetMessageEvent};
+use network_session_model::{
+    play::{SessionLatency, SessionPing, SessionPong, SessionStatus},
+    SessionMessageEvent,
+};
+
+/// Interval between `SessionPing`s sent to the session server.
+const SESSION_PING_INTERVAL: Duration = Duration::from_secs(1);
+/// Number of unanswered `SessionPing`s to track before the oldest are discarded.
+const SESSION_PINGS_PENDING_LIMIT: usize = 16;
+
+/// Periodically sends `SessionPing`s to the session server and measures the round trip time.
+///
+/// The measured latency is sent with the next ping, and the session server relays it to all
+/// devices in the session.
+#[derive(Debug, SystemDesc, new)]
+#[system_desc(name(SessionPingSystemDesc))]
+pub struct SessionPingSystem {
+    /// Reader ID for the `SessionMessageEvent` channel.
+    #[system_desc(event_channel_reader)]
+    session_message_event_rid: ReaderId<NetData<SessionMessageEvent>>,
+    /// Sequence number of the next `SessionPing`.
+    #[system_desc(skip)]
+    #[new(default)]
+    sequence_next: u64,
+    /// Sequence numbers and send times of `SessionPing`s awaiting a `SessionPong`.
+    #[system_desc(skip)]
+    #[new(default)]
+    pings_pending: VecDeque<(u64, Instant)>,
+    /// When the last `SessionPing` was sent.
+    #[system_desc(skip)]
+    #[new(default)]
+    ping_sent_at: Option<Instant>,
+    /// Latency measured so far.
+    #[system_desc(skip)]
+    #[new(default)]
+    session_latency: Option<SessionLatency>,
+}
+
+#[derive(Derivative, SystemData)]
+#[derivative(Debug)]
+pub struct SessionPingSystemData<'s> {
+    /// `SessionMessageEvent` channel.
+    #[derivative(Debug = "ignore")]
+    pub session_message_nec: Read<'s, NetEventChannel<SessionMessageEvent>>,
+    /// `SessionStatus` resource.
+    #[derivative(Debug = "ignore")]
+    pub session_status: Read<'s, SessionStatus>,
+    /// `NetMessageEvent` channel.
+    #[derivative(Debug = "ignore")]
+    pub net_message_ec: Write<'s, EventChannel<NetMessageEvent>>,
+}
+
+impl SessionPingSystem {
+    /// Updates the measured latency with the round trip time of the pinged sequence.
+    fn pong_receive(&mut self, session_pong: SessionPong, now: Instant) {
+        let SessionPong { sequence } = session_pong;
+
+        // Pings before this sequence have already been responded to, or were lost.
+        while let Some((sequence_pending, _)) = self.pings_pending.front() {
+            if *sequence_pending < sequence {
+                self.pings_pending.pop_front();
+            } else {
+                break;
+            }
+        }
+
+        if let Some((sequence_pending, sent_at)) = self.pings_pending.front().copied() {
+            if sequence_pending == sequence {
+                self.pings_pending.pop_front();
+
+                let sample = now.duration_since(sent_at);
+                match self.session_latency.as_mut() {
+                    Some(session_latency) => session_latency.update(sample),
+                    None => self.session_latency = Some(SessionLatency::from_sample(sample)),
+                }
+
+                debug!("Session latency: {:?}", self.session_latency);
+            }
+        }
+    }
+
+    /// Returns whether the next `SessionPing` should be sent.
+    fn ping_due(&self, now: Instant) -> bool {
+        self.ping_sent_at
+            .map(|sent_at| now.duration_since(sent_at) >= SESSION_PING_INTERVAL)
+            .unwrap_or(true)
+    }
+}
+
+impl<'s> System<'s> for SessionPingSystem {
+    type SystemData = SessionPingSystemData<'s>;
+
+    fn run(
+        &mut self,
+        SessionPingSystemData {
+            session_message_nec,
+            session_status,
+            mut net_message_ec,
+        }: Self::SystemData,
+    ) {
+        let now = Instant::now();
+        let session_pongs = session_message_nec
+            .read(&mut self.session_message_event_rid)
+            .filter_map(|ev| {
+                if let SessionMessageEvent::SessionPong(session_pong) = &ev.data {
+                    Some(*session_pong)
+                } else {
+                    None
+                }
+            })
+            .collect::<Vec<SessionPong>>();
+
+        let session_established = *session_status == SessionStatus::JoinEstablished
+            || *session_status == SessionStatus::HostEstablished;
+
+        if session_established {
+            session_pongs
+                .into_iter()
+                .for_each(|session_pong| self.pong_receive(session_pong, now));
+
+            if self.ping_due(now) {
+                let sequence = self.sequence_next;
+                self.sequence_next += 1;
+                if self.pings_pending.len() >= SESSION_PINGS_PENDING_LIMIT {
+                    self.pings_pending.pop_front();
+                }
+                self.pings_pending.push_back((sequence, now));
+                self.ping_sent_at = Some(now);
+
+                let session_ping = SessionPing::new(sequence, self.session_latency);
+                net_message_ec.single_write(NetMessageEvent::SessionMessageEvent(
+                    SessionMessageEvent::SessionPing(session_ping),
+                ));
+            }
+        } else {
+            self.pings_pending.clear();
+            self.ping_sent_at = None;
+            self.session_latency = None;
+        }
+    }
+}
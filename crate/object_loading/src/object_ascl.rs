@@ -7,6 +7,7 @@ use character_model::{
 };
 use energy_model::config::{EnergySequence, EnergySequenceName};
 use input_reaction_loading::{IrsLoader, IrsLoaderParams};
+use item_model::config::{ItemSequence, ItemSequenceName};
 use kinematic_model::{
     config::{PositionInit, VelocityInit},
     play::PositionZAsY,
@@ -36,14 +37,17 @@ impl ObjectAscl {
                 DefinitionLoadingResourcesRead {
                     character_definition_assets,
                     energy_definition_assets,
+                    item_definition_assets,
                     asset_character_definition_handle,
                     asset_energy_definition_handle,
+                    asset_item_definition_handle,
                     ..
                 },
             id_mapping_resources_read:
                 IdMappingResourcesRead {
                     asset_sequence_id_mappings_character,
                     asset_sequence_id_mappings_energy,
+                    asset_sequence_id_mappings_item,
                     ..
                 },
             texture_loading_resources_read:
@@ -93,6 +97,7 @@ impl ObjectAscl {
             asset_type_mappings,
             asset_sequence_id_mappings_character,
             asset_sequence_id_mappings_energy,
+            asset_sequence_id_mappings_item,
             wait_sequence_assets,
             source_assets,
             source_sequence_assets,
@@ -206,6 +211,40 @@ impl ObjectAscl {
 
                 (sequence_id_init, object)
             }
+            ObjectType::Item => {
+                let item_definition = asset_item_definition_handle
+                    .get(asset_id)
+                    .and_then(|item_definition_handle| {
+                        item_definition_assets.get(item_definition_handle)
+                    })
+                    .expect("Expected `ItemDefinition` to be loaded.");
+
+                let sequence_id_mappings = asset_sequence_id_mappings_item
+                    .get(asset_id)
+                    .expect("Expected `SequenceIdMapping` to be loaded.");
+                let sequence_id_init = {
+                    let sequence_name_default = ItemSequenceName::default();
+                    sequence_id_mappings
+                        .id_by_name(sequence_name_default)
+                        .copied()
+                        .unwrap_or_else(|| {
+                            warn!(
+                                "`{}` sequence ID not found for asset: `{}`. \
+                                 Falling back to first declared sequence.",
+                                sequence_name_default, asset_slug
+                            );
+
+                            SequenceId::new(0)
+                        })
+                };
+
+                let object = ObjectLoader::load::<ItemSequence>(
+                    object_loader_params,
+                    &item_definition.object_definition,
+                );
+
+                (sequence_id_init, object)
+            }
             ObjectType::TestObject => panic!("`TestObject` loading is not supported."),
         };
         let Object {
@@ -12,6 +12,7 @@ use collision_model::{
     },
 };
 use energy_model::config::EnergySequenceName;
+use item_model::config::ItemSequenceName;
 use kinematic_model::{
     config::{ObjectAcceleration, Position, Velocity},
     loaded::{ObjectAccelerationSequence, ObjectAccelerationSequenceHandles},
@@ -53,6 +54,7 @@ impl ObjectLoader {
             asset_type_mappings,
             asset_sequence_id_mappings_character,
             asset_sequence_id_mappings_energy,
+            asset_sequence_id_mappings_item,
             wait_sequence_assets,
             source_assets,
             source_sequence_assets,
@@ -290,6 +292,26 @@ impl ObjectLoader {
                                                             .unwrap_or_else(|| panic!("`{}` sequence not found for `{}`", sequence_default, spawn_asset_slug))
                                                 }
                                             }
+                                            ObjectType::Item => {
+                                                let spawn_sequence_id_mappings = asset_sequence_id_mappings_item.get(spawn_asset_id)
+                                                    .unwrap_or_else(|| panic!("`SequenceIdMappings<Item>` not found for `{}`.", spawn_asset_slug));
+
+                                                if let Some(sequence_string) = spawn_config.sequence.as_ref() {
+                                                    let sequence_name_string = SequenceNameString::from_str(sequence_string).expect("Expected `SequenceNameString::from_str` to succeed.");
+                                                    spawn_sequence_id_mappings.id(&sequence_name_string).copied().unwrap_or_else(|| {
+                                                        let message = format!("Sequence ID not found for string: `{}` in `{}`. Falling back to default.", sequence_string, spawn_asset_slug);
+                                                        error!("{}", message);
+
+                                                        let sequence_default = ItemSequenceName::default();
+                                                        spawn_sequence_id_mappings.id(&SequenceNameString::from(sequence_default)).copied()
+                                                            .unwrap_or_else(|| panic!("`{}` sequence not found for `{}`", sequence_default, spawn_asset_slug))
+                                                    })
+                                                } else {
+                                                    let sequence_default = ItemSequenceName::default();
+                                                        spawn_sequence_id_mappings.id(&SequenceNameString::from(sequence_default)).copied()
+                                                            .unwrap_or_else(|| panic!("`{}` sequence not found for `{}`", sequence_default, spawn_asset_slug))
+                                                }
+                                            }
                                             ObjectType::TestObject => {
                                                 panic!("Spawning `TestObject`s is not supported.")
                                             }
@@ -13,6 +13,7 @@ use collision_model::{
 };
 use derivative::Derivative;
 use energy_model::config::EnergySequenceName;
+use item_model::config::ItemSequenceName;
 use kinematic_model::loaded::ObjectAccelerationSequence;
 use sequence_model::loaded::{AssetSequenceIdMappings, WaitSequence};
 use spawn_model::loaded::{Spawns, SpawnsSequence};
@@ -38,6 +39,9 @@ pub struct ObjectLoaderSystemData<'s> {
     /// `AssetSequenceIdMappings<EnergySequenceName>` resource.
     #[derivative(Debug = "ignore")]
     pub asset_sequence_id_mappings_energy: Read<'s, AssetSequenceIdMappings<EnergySequenceName>>,
+    /// `AssetSequenceIdMappings<ItemSequenceName>` resource.
+    #[derivative(Debug = "ignore")]
+    pub asset_sequence_id_mappings_item: Read<'s, AssetSequenceIdMappings<ItemSequenceName>>,
     /// `WaitSequence`s assets.
     #[derivative(Debug = "ignore")]
     pub wait_sequence_assets: Read<'s, AssetStorage<WaitSequence>>,
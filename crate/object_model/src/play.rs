@@ -3,8 +3,14 @@
 //! This differs from the `loaded` types as these may contain mutable state that are specific to an
 //! entity.
 
-pub use self::{grounding::Grounding, health_points::HealthPoints, skill_points::SkillPoints};
+pub use self::{
+    grounding::Grounding,
+    guard_points::{GuardPoints, GUARD_POINTS_DEFAULT},
+    health_points::HealthPoints,
+    skill_points::SkillPoints,
+};
 
 mod grounding;
+mod guard_points;
 mod health_points;
 mod skill_points;
@@ -4,9 +4,10 @@ use amethyst::{
 };
 use asset_model::ItemComponent;
 use derivative::Derivative;
+use serde::{Deserialize, Serialize};
 
 /// State that tracks an object's attachment to the surrounding environment.
-#[derive(Clone, Component, Copy, Debug, Derivative, PartialEq, Eq)]
+#[derive(Clone, Component, Copy, Debug, Derivative, Deserialize, PartialEq, Eq, Serialize)]
 #[derivative(Default)]
 #[storage(VecStorage)]
 pub enum Grounding {
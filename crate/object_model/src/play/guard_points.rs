@@ -0,0 +1,17 @@
+use amethyst::ecs::{storage::VecStorage, Component};
+use derivative::Derivative;
+use derive_more::{Add, AddAssign, Display, From, Sub, SubAssign};
+use numeric_newtype_derive::numeric_newtype;
+use serde::{Deserialize, Serialize};
+
+/// Default and maximum guard points of an object.
+pub const GUARD_POINTS_DEFAULT: u32 = 100;
+
+/// Guard points of an object.
+///
+/// These are consumed when the object blocks a hit. When they run out, the object's guard breaks.
+#[numeric_newtype]
+#[derive(Component, Debug, Derivative, Deserialize, Hash, Serialize)]
+#[storage(VecStorage)]
+#[derivative(Default)]
+pub struct GuardPoints(#[derivative(Default(value = "GUARD_POINTS_DEFAULT"))] pub u32);
@@ -1,3 +1,7 @@
-pub use self::stun_points_reduction_system::StunPointsReductionSystem;
+pub use self::{
+    guard_points_recovery_system::GuardPointsRecoverySystem,
+    stun_points_reduction_system::StunPointsReductionSystem,
+};
 
+mod guard_points_recovery_system;
 mod stun_points_reduction_system;
@@ -18,6 +18,9 @@ pub enum ObjectType {
     /// Energy / aura / spark effects.
     #[evt(skip)]
     Energy,
+    /// Weapons and throwable objects.
+    #[evt(skip)]
+    Item,
     /// Used in tests.
     #[evt(skip)]
     TestObject,
@@ -1,5 +1,6 @@
-use amethyst::ecs::{storage::DenseVecStorage, Component, Entity};
+use amethyst::ecs::{storage::DenseVecStorage, world::EntitiesRes, Component, Entity};
 use derive_new::new;
+use snapshot_model::play::{SnapshotComponent, SnapshotEntity};
 
 /// Links a child entity to the parent entity.
 ///
@@ -11,3 +12,15 @@ use derive_new::new;
 /// are looking for the `spawn_model::play::SpawnParent` component.
 #[derive(Clone, Component, Copy, Debug, PartialEq, new)]
 pub struct ParentEntity(pub Entity);
+
+impl SnapshotComponent for ParentEntity {
+    type Data = SnapshotEntity;
+
+    fn to_snapshot(&self) -> Self::Data {
+        SnapshotEntity::from(self.0)
+    }
+
+    fn from_snapshot(snapshot_entity: Self::Data, entities: &EntitiesRes) -> Option<Self> {
+        snapshot_entity.entity(entities).map(ParentEntity)
+    }
+}
@@ -0,0 +1,17 @@
+#![deny(missing_debug_implementations, missing_docs)] // kcov-ignore
+
+//! Records matches to replay files, and plays them back.
+
+pub use crate::{
+    replay_match::{ReplayMatch, ReplayState},
+    system::{
+        ReplayControlInputPlaybackSystem, ReplayControlInputPlaybackSystemData,
+        ReplayControlInputPlaybackSystemDesc, ReplayDivergenceDetectionSystem,
+        ReplayDivergenceDetectionSystemData, ReplayDivergenceDetectionSystemDesc,
+        ReplayPlaybackSystem, ReplayPlaybackSystemData, ReplayRecordSystem, ReplayRecordSystemData,
+        ReplayRecordSystemDesc,
+    },
+};
+
+mod replay_match;
+mod system;
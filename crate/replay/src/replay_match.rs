@@ -0,0 +1,80 @@
+use amethyst::ecs::{World, WorldExt};
+use asset_model::{config::AssetSlug, loaded::AssetIdMappings};
+use character_selection_model::{CharacterSelections, CharacterSelectionsStatus};
+use game_play_model::play::GamePlaySeed;
+use log::{info, warn};
+use map_selection_model::MapSelection;
+use replay_model::play::ReplayPlayback;
+use simulation::{HeadlessMatch, HeadlessMatchState};
+
+/// `State` that plays back a recorded match, then quits.
+pub type ReplayState = HeadlessMatchState<ReplayMatch>;
+
+/// Sets up the match from the `ReplayPlayback` resource, and logs whether the playback matched
+/// the recording.
+#[derive(Debug)]
+pub struct ReplayMatch;
+
+impl HeadlessMatch for ReplayMatch {
+    fn selections_insert(world: &mut World) -> Result<Option<GamePlaySeed>, String> {
+        let (game_mode, character_selections, map_selection, game_play_seed) = {
+            let replay_playback = world
+                .try_fetch::<ReplayPlayback>()
+                .ok_or_else(|| String::from("`ReplayPlayback` resource is not present."))?;
+            let replay = &replay_playback.replay;
+            let asset_id_mappings = world.read_resource::<AssetIdMappings>();
+            let asset_id = |asset_slug: &AssetSlug| {
+                asset_id_mappings
+                    .id(asset_slug)
+                    .copied()
+                    .ok_or_else(|| format!("Asset `{}` is not loaded.", asset_slug))
+            };
+
+            let mut character_selections = CharacterSelections::default();
+            replay.characters.iter().try_for_each(|replay_character| {
+                let controller_id = replay_character.controller_id;
+                let asset_id = asset_id(&replay_character.character)?;
+                character_selections
+                    .selections
+                    .insert(controller_id, asset_id);
+                if let Some(difficulty) = replay_character.cpu {
+                    character_selections
+                        .cpu_selections
+                        .insert(controller_id, difficulty);
+                }
+                Ok::<(), String>(())
+            })?;
+            let map_selection = MapSelection::Id(asset_id(&replay.map)?);
+
+            (
+                replay.game_mode,
+                character_selections,
+                map_selection,
+                replay.seed,
+            )
+        };
+
+        world.insert(game_mode);
+        world.insert(character_selections);
+        world.insert(CharacterSelectionsStatus::Ready);
+        world.insert(map_selection);
+
+        Ok(Some(game_play_seed))
+    }
+
+    fn report(world: &World) {
+        if let Some(replay_playback) = world.try_fetch::<ReplayPlayback>() {
+            if let Some(divergence_tick) = replay_playback.divergence_tick {
+                warn!(
+                    "Replay diverged from the recording at tick {}.",
+                    divergence_tick
+                );
+            } else {
+                info!(
+                    "Replay played back {} of {} ticks without divergence.",
+                    replay_playback.tick, replay_playback.replay.tick_count
+                );
+            }
+        }
+    }
+}
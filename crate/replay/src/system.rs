@@ -0,0 +1,17 @@
+pub use self::{
+    replay_control_input_playback_system::{
+        ReplayControlInputPlaybackSystem, ReplayControlInputPlaybackSystemData,
+        ReplayControlInputPlaybackSystemDesc,
+    },
+    replay_divergence_detection_system::{
+        ReplayDivergenceDetectionSystem, ReplayDivergenceDetectionSystemData,
+        ReplayDivergenceDetectionSystemDesc,
+    },
+    replay_playback_system::{ReplayPlaybackSystem, ReplayPlaybackSystemData},
+    replay_record_system::{ReplayRecordSystem, ReplayRecordSystemData, ReplayRecordSystemDesc},
+};
+
+mod replay_control_input_playback_system;
+mod replay_divergence_detection_system;
+mod replay_playback_system;
+mod replay_record_system;
@@ -0,0 +1,144 @@
+use amethyst::{
+    derive::SystemDesc,
+    ecs::{Entities, Join, Read, ReadStorage, System, World, Write},
+    shred::{ResourceId, SystemData},
+    shrev::{EventChannel, ReaderId},
+};
+use derivative::Derivative;
+use derive_new::new;
+use game_input_model::play::{ControlInputEvent, InputControlled};
+use replay_model::{config::ReplayControlInputEvent, play::ReplayPlayback};
+use state_registry::StateId;
+
+/// Sends recorded `ControlInputEvent`s that are not reproduced by the played back input.
+///
+/// `ControlInputEvent`s from stdin or simulation scripts do not originate from `GameInputEvent`s,
+/// so they are sent from the recording. Events for computer controlled characters are not sent, as
+/// those are decided again during playback, and compared with the recording for divergence.
+///
+/// This must run after every other system that sends `ControlInputEvent`s, and before the
+/// `ReplayDivergenceDetectionSystem`.
+#[derive(Debug, SystemDesc, new)]
+#[system_desc(name(ReplayControlInputPlaybackSystemDesc))]
+pub struct ReplayControlInputPlaybackSystem {
+    /// Reader ID for the `ControlInputEvent` channel.
+    #[system_desc(event_channel_reader)]
+    control_input_event_rid: ReaderId<ControlInputEvent>,
+    /// Pre-allocated vector to hold the `ControlInputEvent`s sent for the current tick.
+    #[system_desc(skip)]
+    #[new(default)]
+    control_input_events_sent: Vec<ReplayControlInputEvent>,
+    /// Pre-allocated vector to hold the recorded `ControlInputEvent`s to send.
+    #[system_desc(skip)]
+    #[new(default)]
+    control_input_events: Vec<ControlInputEvent>,
+}
+
+/// `ReplayControlInputPlaybackSystemData`.
+#[derive(Derivative, SystemData)]
+#[derivative(Debug)]
+pub struct ReplayControlInputPlaybackSystemData<'s> {
+    /// `StateId` resource.
+    #[derivative(Debug = "ignore")]
+    pub state_id: Read<'s, StateId>,
+    /// `ReplayPlayback` resource.
+    #[derivative(Debug = "ignore")]
+    pub replay_playback: Option<Read<'s, ReplayPlayback>>,
+    /// `Entities` resource.
+    #[derivative(Debug = "ignore")]
+    pub entities: Entities<'s>,
+    /// `InputControlled` components.
+    #[derivative(Debug = "ignore")]
+    pub input_controlleds: ReadStorage<'s, InputControlled>,
+    /// `ControlInputEvent` channel.
+    #[derivative(Debug = "ignore")]
+    pub control_input_ec: Write<'s, EventChannel<ControlInputEvent>>,
+}
+
+impl<'s> System<'s> for ReplayControlInputPlaybackSystem {
+    type SystemData = ReplayControlInputPlaybackSystemData<'s>;
+
+    fn run(
+        &mut self,
+        ReplayControlInputPlaybackSystemData {
+            state_id,
+            replay_playback,
+            entities,
+            input_controlleds,
+            mut control_input_ec,
+        }: Self::SystemData,
+    ) {
+        self.control_input_events_sent.clear();
+        self.control_input_events_sent.extend(
+            control_input_ec
+                .read(&mut self.control_input_event_rid)
+                .copied()
+                .map(ReplayControlInputEvent::from),
+        );
+
+        if *state_id != StateId::GamePlay {
+            return;
+        }
+
+        let replay_playback = if let Some(replay_playback) = replay_playback {
+            replay_playback
+        } else {
+            return;
+        };
+        if replay_playback.tick == 0 {
+            return;
+        }
+
+        // The `ReplayPlaybackSystem` has already advanced past the tick that is being played.
+        let tick = replay_playback.tick - 1;
+        let replay = &replay_playback.replay;
+        let replay_tick = if let Some(replay_tick) = replay.tick(tick) {
+            replay_tick
+        } else {
+            return;
+        };
+
+        let control_input_events_sent = &mut self.control_input_events_sent;
+        let control_input_events_iter = replay_tick
+            .control_input_events
+            .iter()
+            .copied()
+            .filter(|replay_control_input_event| {
+                let controller_id = replay_control_input_event.controller_id();
+                !replay.characters.iter().any(|replay_character| {
+                    replay_character.controller_id == controller_id
+                        && replay_character.cpu.is_some()
+                })
+            })
+            .filter(|replay_control_input_event| {
+                // Events that were reproduced are not sent again.
+                let position = control_input_events_sent
+                    .iter()
+                    .position(|sent| sent == replay_control_input_event);
+                if let Some(position) = position {
+                    control_input_events_sent.swap_remove(position);
+                    false
+                } else {
+                    true
+                }
+            })
+            .filter_map(|replay_control_input_event| {
+                let controller_id = replay_control_input_event.controller_id();
+                (&entities, &input_controlleds)
+                    .join()
+                    .find(|(_, input_controlled)| input_controlled.controller_id == controller_id)
+                    .map(|(entity, _)| replay_control_input_event.control_input_event(entity))
+            });
+        self.control_input_events.extend(control_input_events_iter);
+
+        if !self.control_input_events.is_empty() {
+            control_input_ec.drain_vec_write(&mut self.control_input_events);
+
+            // Skip the events that this system sent, so they are not counted as reproduced input
+            // for the next tick.
+            control_input_ec
+                .read(&mut self.control_input_event_rid)
+                .for_each(|_| {});
+        }
+    }
+}
@@ -0,0 +1,95 @@
+use amethyst::{
+    derive::SystemDesc,
+    ecs::{Read, System, World, Write},
+    shred::{ResourceId, SystemData},
+    shrev::{EventChannel, ReaderId},
+};
+use derivative::Derivative;
+use derive_new::new;
+use game_input_model::play::ControlInputEvent;
+use log::warn;
+use replay_model::{config::ReplayControlInputEvent, play::ReplayPlayback};
+use state_registry::StateId;
+
+/// Detects when the `ControlInputEvent`s during playback differ from the recording.
+///
+/// This must run after the `ReplayPlaybackSystem` and every system that sends
+/// `ControlInputEvent`s.
+#[derive(Debug, SystemDesc, new)]
+#[system_desc(name(ReplayDivergenceDetectionSystemDesc))]
+pub struct ReplayDivergenceDetectionSystem {
+    /// Reader ID for the `ControlInputEvent` channel.
+    #[system_desc(event_channel_reader)]
+    control_input_event_rid: ReaderId<ControlInputEvent>,
+    /// Pre-allocated vector to hold the `ControlInputEvent`s for the current tick.
+    #[system_desc(skip)]
+    #[new(default)]
+    control_input_events: Vec<ReplayControlInputEvent>,
+}
+
+/// `ReplayDivergenceDetectionSystemData`.
+#[derive(Derivative, SystemData)]
+#[derivative(Debug)]
+pub struct ReplayDivergenceDetectionSystemData<'s> {
+    /// `StateId` resource.
+    #[derivative(Debug = "ignore")]
+    pub state_id: Read<'s, StateId>,
+    /// `ReplayPlayback` resource.
+    #[derivative(Debug = "ignore")]
+    pub replay_playback: Option<Write<'s, ReplayPlayback>>,
+    /// `ControlInputEvent` channel.
+    #[derivative(Debug = "ignore")]
+    pub control_input_ec: Read<'s, EventChannel<ControlInputEvent>>,
+}
+
+impl<'s> System<'s> for ReplayDivergenceDetectionSystem {
+    type SystemData = ReplayDivergenceDetectionSystemData<'s>;
+
+    fn run(
+        &mut self,
+        ReplayDivergenceDetectionSystemData {
+            state_id,
+            replay_playback,
+            control_input_ec,
+        }: Self::SystemData,
+    ) {
+        self.control_input_events.clear();
+        self.control_input_events.extend(
+            control_input_ec
+                .read(&mut self.control_input_event_rid)
+                .copied()
+                .map(ReplayControlInputEvent::from),
+        );
+
+        if *state_id != StateId::GamePlay {
+            return;
+        }
+
+        let mut replay_playback = if let Some(replay_playback) = replay_playback {
+            replay_playback
+        } else {
+            return;
+        };
+        if replay_playback.tick == 0 || replay_playback.divergence_tick.is_some() {
+            return;
+        }
+
+        // The `ReplayPlaybackSystem` has already advanced past the tick that was just played.
+        let tick = replay_playback.tick - 1;
+        let control_input_events_expected = replay_playback
+            .replay
+            .tick(tick)
+            .map(|replay_tick| replay_tick.control_input_events.as_slice())
+            .unwrap_or(&[]);
+
+        if control_input_events_expected != self.control_input_events.as_slice() {
+            warn!(
+                "Replay diverged at tick {}.\n\
+                 Expected: {:?}\n\
+                 Actual: {:?}",
+                tick, control_input_events_expected, self.control_input_events
+            );
+            replay_playback.divergence_tick = Some(tick);
+        }
+    }
+}
@@ -0,0 +1,76 @@
+use amethyst::{
+    ecs::{Read, System, World, Write},
+    shred::{ResourceId, SystemData},
+    shrev::EventChannel,
+};
+use derivative::Derivative;
+use derive_new::new;
+use game_input_model::GameInputEvent;
+use game_play_model::GamePlayEvent;
+use log::info;
+use replay_model::play::ReplayPlayback;
+use state_registry::StateId;
+
+/// Sends recorded `GameInputEvent`s in place of device input during game play.
+///
+/// Recorded `ControlInputEvent`s that do not originate from `GameInputEvent`s are sent by the
+/// `ReplayControlInputPlaybackSystem`.
+///
+/// When every recorded tick has been played back, this sends `GamePlayEvent::Return`.
+#[derive(Debug, Default, new)]
+pub struct ReplayPlaybackSystem;
+
+/// `ReplayPlaybackSystemData`.
+#[derive(Derivative, SystemData)]
+#[derivative(Debug)]
+pub struct ReplayPlaybackSystemData<'s> {
+    /// `StateId` resource.
+    #[derivative(Debug = "ignore")]
+    pub state_id: Read<'s, StateId>,
+    /// `ReplayPlayback` resource.
+    #[derivative(Debug = "ignore")]
+    pub replay_playback: Option<Write<'s, ReplayPlayback>>,
+    /// `GameInputEvent` channel.
+    #[derivative(Debug = "ignore")]
+    pub game_input_ec: Write<'s, EventChannel<GameInputEvent>>,
+    /// `GamePlayEvent` channel.
+    #[derivative(Debug = "ignore")]
+    pub game_play_ec: Write<'s, EventChannel<GamePlayEvent>>,
+}
+
+impl<'s> System<'s> for ReplayPlaybackSystem {
+    type SystemData = ReplayPlaybackSystemData<'s>;
+
+    fn run(
+        &mut self,
+        ReplayPlaybackSystemData {
+            state_id,
+            replay_playback,
+            mut game_input_ec,
+            mut game_play_ec,
+        }: Self::SystemData,
+    ) {
+        if *state_id != StateId::GamePlay {
+            return;
+        }
+
+        let mut replay_playback = if let Some(replay_playback) = replay_playback {
+            replay_playback
+        } else {
+            return;
+        };
+        if replay_playback.is_complete() {
+            return;
+        }
+
+        if let Some(replay_tick) = replay_playback.replay.tick(replay_playback.tick) {
+            game_input_ec.iter_write(replay_tick.game_input_events.iter().copied());
+        }
+        replay_playback.tick += 1;
+
+        if replay_playback.is_complete() {
+            info!("Replay playback complete at tick {}.", replay_playback.tick);
+            game_play_ec.single_write(GamePlayEvent::Return);
+        }
+    }
+}
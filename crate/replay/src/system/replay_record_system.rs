@@ -0,0 +1,161 @@
+use amethyst::{
+    derive::SystemDesc,
+    ecs::{Read, System, World, Write},
+    shred::{ResourceId, SystemData},
+    shrev::{EventChannel, ReaderId},
+};
+use asset_model::loaded::AssetIdMappings;
+use character_selection_model::CharacterSelections;
+use derivative::Derivative;
+use derive_new::new;
+use game_input_model::{play::ControlInputEvent, GameInputEvent};
+use game_play_model::{play::GamePlaySeed, GameMode};
+use map_selection_model::MapSelection;
+use replay_model::{
+    config::{Replay, ReplayCharacter, ReplayControlInputEvent, ReplayTick},
+    play::ReplayRecorder,
+};
+use state_registry::StateId;
+
+/// Records the input of each tick during game play, and writes it to a replay file.
+///
+/// The replay file is written when game play ends, or when the `ReplayRecorder` is dropped as the
+/// application quits. This must run after every system that sends `ControlInputEvent`s.
+#[derive(Debug, SystemDesc, new)]
+#[system_desc(name(ReplayRecordSystemDesc))]
+pub struct ReplayRecordSystem {
+    /// Reader ID for the `GameInputEvent` channel.
+    #[system_desc(event_channel_reader)]
+    game_input_event_rid: ReaderId<GameInputEvent>,
+    /// Reader ID for the `ControlInputEvent` channel.
+    #[system_desc(event_channel_reader)]
+    control_input_event_rid: ReaderId<ControlInputEvent>,
+}
+
+/// `ReplayRecordSystemData`.
+#[derive(Derivative, SystemData)]
+#[derivative(Debug)]
+pub struct ReplayRecordSystemData<'s> {
+    /// `StateId` resource.
+    #[derivative(Debug = "ignore")]
+    pub state_id: Read<'s, StateId>,
+    /// `GameMode` resource.
+    #[derivative(Debug = "ignore")]
+    pub game_mode: Read<'s, GameMode>,
+    /// `CharacterSelections` resource.
+    #[derivative(Debug = "ignore")]
+    pub character_selections: Read<'s, CharacterSelections>,
+    /// `MapSelection` resource.
+    #[derivative(Debug = "ignore")]
+    pub map_selection: Read<'s, MapSelection>,
+    /// `AssetIdMappings` resource.
+    #[derivative(Debug = "ignore")]
+    pub asset_id_mappings: Read<'s, AssetIdMappings>,
+    /// `GamePlaySeed` resource.
+    #[derivative(Debug = "ignore")]
+    pub game_play_seed: Read<'s, GamePlaySeed>,
+    /// `GameInputEvent` channel.
+    #[derivative(Debug = "ignore")]
+    pub game_input_ec: Read<'s, EventChannel<GameInputEvent>>,
+    /// `ControlInputEvent` channel.
+    #[derivative(Debug = "ignore")]
+    pub control_input_ec: Read<'s, EventChannel<ControlInputEvent>>,
+    /// `ReplayRecorder` resource.
+    #[derivative(Debug = "ignore")]
+    pub replay_recorder: Write<'s, ReplayRecorder>,
+}
+
+impl ReplayRecordSystem {
+    /// Returns a new `Replay` for the selected characters and map.
+    fn replay_begin(
+        ReplayRecordSystemData {
+            game_mode,
+            character_selections,
+            map_selection,
+            asset_id_mappings,
+            game_play_seed,
+            ..
+        }: &ReplayRecordSystemData<'_>,
+    ) -> Replay {
+        let asset_slug = |asset_id| {
+            asset_id_mappings
+                .slug(asset_id)
+                .cloned()
+                .unwrap_or_else(|| {
+                    panic!(
+                        "Expected asset ID `{:?}` to have an asset ID mapping.",
+                        asset_id
+                    )
+                })
+        };
+
+        let mut characters = character_selections
+            .selections
+            .iter()
+            .map(|(controller_id, asset_id)| {
+                let cpu = character_selections
+                    .cpu_selections
+                    .get(controller_id)
+                    .copied();
+                ReplayCharacter::new(*controller_id, asset_slug(*asset_id), cpu)
+            })
+            .collect::<Vec<ReplayCharacter>>();
+        characters.sort_by_key(|replay_character| replay_character.controller_id);
+
+        let map = map_selection
+            .asset_id()
+            .map(asset_slug)
+            .expect("Expected a map to be selected during game play.");
+
+        Replay::new(
+            **game_mode,
+            characters,
+            map,
+            **game_play_seed,
+            0,
+            Vec::new(),
+        )
+    }
+}
+
+impl<'s> System<'s> for ReplayRecordSystem {
+    type SystemData = ReplayRecordSystemData<'s>;
+
+    fn run(&mut self, mut replay_record_system_data: Self::SystemData) {
+        let game_input_events = replay_record_system_data
+            .game_input_ec
+            .read(&mut self.game_input_event_rid)
+            .copied()
+            .collect::<Vec<GameInputEvent>>();
+        let control_input_events = replay_record_system_data
+            .control_input_ec
+            .read(&mut self.control_input_event_rid)
+            .copied()
+            .map(ReplayControlInputEvent::from)
+            .collect::<Vec<ReplayControlInputEvent>>();
+
+        if replay_record_system_data.replay_recorder.path.is_none() {
+            return;
+        }
+
+        if *replay_record_system_data.state_id == StateId::GamePlay {
+            if replay_record_system_data.replay_recorder.replay.is_none() {
+                let replay = Self::replay_begin(&replay_record_system_data);
+                replay_record_system_data.replay_recorder.replay = Some(replay);
+            }
+
+            if let Some(replay) = replay_record_system_data.replay_recorder.replay.as_mut() {
+                if !game_input_events.is_empty() || !control_input_events.is_empty() {
+                    replay.ticks.push(ReplayTick::new(
+                        replay.tick_count,
+                        game_input_events,
+                        control_input_events,
+                    ));
+                }
+                replay.tick_count += 1;
+            }
+        } else {
+            replay_record_system_data.replay_recorder.flush();
+        }
+    }
+}
@@ -0,0 +1,11 @@
+//! Contains data types for replay files.
+
+pub use self::{
+    replay::Replay, replay_character::ReplayCharacter,
+    replay_control_input_event::ReplayControlInputEvent, replay_tick::ReplayTick,
+};
+
+mod replay;
+mod replay_character;
+mod replay_control_input_event;
+mod replay_tick;
@@ -0,0 +1,59 @@
+use asset_model::config::AssetSlug;
+use derive_new::new;
+use game_play_model::{play::GamePlaySeed, GameMode};
+use serde::{Deserialize, Serialize};
+
+use crate::config::{ReplayCharacter, ReplayTick};
+
+/// Recorded match that can be played back.
+///
+/// # Examples
+///
+/// ```yaml
+/// game_mode: "versus"
+/// characters:
+///   - { controller_id: 0, character: "default/hero" }
+///   - { controller_id: 1, character: "default/rival", cpu: "normal" }
+/// map: "default/arena"
+/// seed: 0
+/// tick_count: 300
+/// ticks:
+///   - tick: 12
+///     game_input_events:
+///       - action_pressed: { player: 0, action: "attack" }
+///     control_input_events:
+///       - control_action_press: { controller_id: 0, control_action: "attack" }
+/// ```
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize, new)]
+#[serde(deny_unknown_fields)]
+pub struct Replay {
+    /// Mode of play for the match.
+    #[serde(default)]
+    pub game_mode: GameMode,
+    /// Characters selected for each controller.
+    pub characters: Vec<ReplayCharacter>,
+    /// Selected map.
+    #[serde(
+        serialize_with = "AssetSlug::serialize_str",
+        deserialize_with = "AssetSlug::deserialize_str"
+    )]
+    pub map: AssetSlug,
+    /// Seed for random number generation during game play.
+    #[serde(default)]
+    pub seed: GamePlaySeed,
+    /// Number of ticks that the match ran for.
+    pub tick_count: u32,
+    /// Input events for each tick that had input, in tick order.
+    #[serde(default)]
+    pub ticks: Vec<ReplayTick>,
+}
+
+impl Replay {
+    /// Returns the recorded input for the given tick, if there was any.
+    pub fn tick(&self, tick: u32) -> Option<&ReplayTick> {
+        self.ticks
+            .binary_search_by_key(&tick, |replay_tick| replay_tick.tick)
+            .ok()
+            .map(|index| &self.ticks[index])
+    }
+}
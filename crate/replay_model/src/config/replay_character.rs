@@ -0,0 +1,22 @@
+use ai_model::config::AiDifficulty;
+use asset_model::config::AssetSlug;
+use derive_new::new;
+use game_input_model::config::ControllerId;
+use serde::{Deserialize, Serialize};
+
+/// Character selected for a controller in a replay.
+#[derive(Clone, Debug, Deserialize, PartialEq, Eq, Serialize, new)]
+#[serde(deny_unknown_fields)]
+pub struct ReplayCharacter {
+    /// ID of the controller that selected the character.
+    pub controller_id: ControllerId,
+    /// Slug of the selected character.
+    #[serde(
+        serialize_with = "AssetSlug::serialize_str",
+        deserialize_with = "AssetSlug::deserialize_str"
+    )]
+    pub character: AssetSlug,
+    /// Difficulty, if the character is controlled by the computer.
+    #[serde(default)]
+    pub cpu: Option<AiDifficulty>,
+}
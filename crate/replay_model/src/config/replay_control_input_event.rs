@@ -0,0 +1,115 @@
+use amethyst::ecs::Entity;
+use game_input_model::{
+    config::{Axis, ControlAction, ControllerId},
+    play::{AxisMoveEventData, ControlActionEventData, ControlInputEvent},
+};
+use serde::{Deserialize, Serialize};
+
+/// `ControlInputEvent` without the entity, which differs between runs.
+#[derive(Clone, Copy, Debug, Deserialize, PartialEq, Serialize)]
+#[serde(deny_unknown_fields, rename_all = "snake_case")]
+pub enum ReplayControlInputEvent {
+    /// `Axis` value has changed.
+    AxisMoved {
+        /// `ControllerId` that the input originated from.
+        controller_id: ControllerId,
+        /// `Axis` whose value changed.
+        axis: Axis,
+        /// New value for the axis input.
+        value: f32,
+    },
+    /// `ControlAction` has been pressed.
+    ControlActionPress {
+        /// `ControllerId` that the input originated from.
+        controller_id: ControllerId,
+        /// `ControlAction` that was pressed.
+        control_action: ControlAction,
+    },
+    /// `ControlAction` has been released.
+    ControlActionRelease {
+        /// `ControllerId` that the input originated from.
+        controller_id: ControllerId,
+        /// `ControlAction` that was released.
+        control_action: ControlAction,
+    },
+}
+
+impl ReplayControlInputEvent {
+    /// Returns the `ControllerId` that the input originated from.
+    pub fn controller_id(self) -> ControllerId {
+        match self {
+            ReplayControlInputEvent::AxisMoved { controller_id, .. }
+            | ReplayControlInputEvent::ControlActionPress { controller_id, .. }
+            | ReplayControlInputEvent::ControlActionRelease { controller_id, .. } => controller_id,
+        }
+    }
+
+    /// Returns the `ControlInputEvent` for the given entity.
+    ///
+    /// # Parameters
+    ///
+    /// * `entity`: Entity controlled by the controller.
+    pub fn control_input_event(self, entity: Entity) -> ControlInputEvent {
+        match self {
+            ReplayControlInputEvent::AxisMoved {
+                controller_id,
+                axis,
+                value,
+            } => ControlInputEvent::AxisMoved(AxisMoveEventData {
+                controller_id,
+                entity,
+                axis,
+                value,
+            }),
+            ReplayControlInputEvent::ControlActionPress {
+                controller_id,
+                control_action,
+            } => ControlInputEvent::ControlActionPress(ControlActionEventData {
+                controller_id,
+                entity,
+                control_action,
+            }),
+            ReplayControlInputEvent::ControlActionRelease {
+                controller_id,
+                control_action,
+            } => ControlInputEvent::ControlActionRelease(ControlActionEventData {
+                controller_id,
+                entity,
+                control_action,
+            }),
+        }
+    }
+}
+
+impl From<ControlInputEvent> for ReplayControlInputEvent {
+    fn from(control_input_event: ControlInputEvent) -> Self {
+        match control_input_event {
+            ControlInputEvent::AxisMoved(AxisMoveEventData {
+                controller_id,
+                axis,
+                value,
+                ..
+            }) => ReplayControlInputEvent::AxisMoved {
+                controller_id,
+                axis,
+                value,
+            },
+            ControlInputEvent::ControlActionPress(ControlActionEventData {
+                controller_id,
+                control_action,
+                ..
+            }) => ReplayControlInputEvent::ControlActionPress {
+                controller_id,
+                control_action,
+            },
+            ControlInputEvent::ControlActionRelease(ControlActionEventData {
+                controller_id,
+                control_action,
+                ..
+            }) => ReplayControlInputEvent::ControlActionRelease {
+                controller_id,
+                control_action,
+            },
+        }
+    }
+}
@@ -0,0 +1,19 @@
+use derive_new::new;
+use game_input_model::GameInputEvent;
+use serde::{Deserialize, Serialize};
+
+use crate::config::ReplayControlInputEvent;
+
+/// Input events recorded in a single tick.
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize, new)]
+#[serde(deny_unknown_fields)]
+pub struct ReplayTick {
+    /// Tick that the events occurred in, counted from the start of game play.
+    pub tick: u32,
+    /// `GameInputEvent`s that are played back in place of device input.
+    #[serde(default)]
+    pub game_input_events: Vec<GameInputEvent>,
+    /// `ControlInputEvent`s that resulted from the input, used to detect divergence on playback.
+    #[serde(default)]
+    pub control_input_events: Vec<ReplayControlInputEvent>,
+}
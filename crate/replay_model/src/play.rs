@@ -0,0 +1,6 @@
+//! Contains data types used at runtime.
+
+pub use self::{replay_playback::ReplayPlayback, replay_recorder::ReplayRecorder};
+
+mod replay_playback;
+mod replay_recorder;
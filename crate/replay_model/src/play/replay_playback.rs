@@ -0,0 +1,23 @@
+use derive_new::new;
+
+use crate::config::Replay;
+
+/// Replay that is being played back.
+#[derive(Clone, Debug, PartialEq, new)]
+pub struct ReplayPlayback {
+    /// The recorded match.
+    pub replay: Replay,
+    /// Number of ticks that have been played back.
+    #[new(default)]
+    pub tick: u32,
+    /// First tick whose `ControlInputEvent`s differ from the recording.
+    #[new(default)]
+    pub divergence_tick: Option<u32>,
+}
+
+impl ReplayPlayback {
+    /// Returns whether every recorded tick has been played back.
+    pub fn is_complete(&self) -> bool {
+        self.tick >= self.replay.tick_count
+    }
+}
@@ -0,0 +1,44 @@
+use std::{fs, path::PathBuf};
+
+use derive_new::new;
+use log::{error, info};
+
+use crate::config::Replay;
+
+/// Records the match being played, and where to write it.
+///
+/// The replay is also written when this is dropped, so that a match in progress is not lost when
+/// the application quits.
+#[derive(Debug, Default, PartialEq, new)]
+pub struct ReplayRecorder {
+    /// Path to write the replay file to, if recording is enabled.
+    pub path: Option<PathBuf>,
+    /// Replay of the match in progress.
+    #[new(default)]
+    pub replay: Option<Replay>,
+}
+
+impl ReplayRecorder {
+    /// Writes the replay of the match in progress to the replay file, and clears it.
+    pub fn flush(&mut self) {
+        let (path, replay) = match (self.path.as_ref(), self.replay.take()) {
+            (Some(path), Some(replay)) => (path, replay),
+            _ => return,
+        };
+
+        let result = serde_yaml::to_string(&replay)
+            .map_err(|e| e.to_string())
+            .and_then(|replay_yaml| fs::write(path, replay_yaml).map_err(|e| e.to_string()));
+
+        match result {
+            Ok(()) => info!("Replay written to `{}`.", path.display()),
+            Err(e) => error!("Failed to write replay to `{}`: {}", path.display(), e),
+        }
+    }
+}
+
+impl Drop for ReplayRecorder {
+    fn drop(&mut self) {
+        self.flush();
+    }
+}
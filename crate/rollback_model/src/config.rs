@@ -0,0 +1,5 @@
+//! Contains data types used for configuration.
+
+pub use self::session_sync_mode::SessionSyncMode;
+
+mod session_sync_mode;
@@ -0,0 +1,46 @@
+use derivative::Derivative;
+use game_play_model::GamePlayStatus;
+use network_session_model::play::SessionStatus;
+use serde::{Deserialize, Serialize};
+use strum_macros::{Display, EnumString};
+
+/// How game clients in a network session keep their simulations in sync.
+///
+/// Every client in a session must use the same mode.
+#[derive(
+    Clone, Copy, Debug, Derivative, Deserialize, Display, EnumString, Hash, PartialEq, Eq, Serialize,
+)]
+#[derivative(Default)]
+#[serde(deny_unknown_fields, rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum SessionSyncMode {
+    /// Clients wait for every device's input before simulating each tick.
+    #[derivative(Default)]
+    Lockstep,
+    /// Clients predict remote input, and resimulate when late input arrives.
+    Rollback,
+}
+
+impl SessionSyncMode {
+    /// Returns whether input should be handled by the rollback systems.
+    ///
+    /// Rollback only applies while a round is in play in an established session. All other
+    /// input, such as menu navigation, is synchronized in lockstep.
+    ///
+    /// # Parameters
+    ///
+    /// * `session_status`: Status of the network session.
+    /// * `game_play_status`: Status within the `GamePlayState`.
+    pub fn is_rollback_active(
+        self,
+        session_status: &SessionStatus,
+        game_play_status: GamePlayStatus,
+    ) -> bool {
+        let session_established = *session_status == SessionStatus::JoinEstablished
+            || *session_status == SessionStatus::HostEstablished;
+
+        self == SessionSyncMode::Rollback
+            && session_established
+            && game_play_status == GamePlayStatus::Playing
+    }
+}
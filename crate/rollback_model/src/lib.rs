@@ -0,0 +1,6 @@
+#![deny(missing_debug_implementations, missing_docs)] // kcov-ignore
+
+//! Types used for rollback network play.
+
+pub mod config;
+pub mod play;
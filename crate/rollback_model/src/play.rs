@@ -0,0 +1,20 @@
+//! Contains data types used at runtime.
+
+pub use self::{
+    input_history::InputHistory,
+    rollback_status::{RollbackStatus, ROLLBACK_TICKS_MAX},
+    rollback_tick::RollbackTick,
+    rollback_tick_confirm::RollbackTickConfirm,
+    session_input_history::{
+        SessionInputHistory, SESSION_INPUT_HISTORY_TICKED_EVENTS_MAX,
+        SESSION_INPUT_HISTORY_TICKS_MAX,
+    },
+    ticked_game_input_event::TickedGameInputEvent,
+};
+
+mod input_history;
+mod rollback_status;
+mod rollback_tick;
+mod rollback_tick_confirm;
+mod session_input_history;
+mod ticked_game_input_event;
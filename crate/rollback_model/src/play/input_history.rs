@@ -0,0 +1,47 @@
+use std::collections::BTreeMap;
+
+use game_input_model::GameInputEvent;
+
+use crate::play::{RollbackTick, TickedGameInputEvent};
+
+/// Confirmed `GameInputEvent`s of every device in a rollback session, by tick.
+///
+/// Ticks that are absent have no input, which predicts that each device holds its previous input.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct InputHistory {
+    /// `GameInputEvent`s by the tick they apply to.
+    game_input_events: BTreeMap<RollbackTick, Vec<GameInputEvent>>,
+}
+
+impl InputHistory {
+    /// Records a `TickedGameInputEvent`.
+    pub fn insert(&mut self, ticked_game_input_event: TickedGameInputEvent) {
+        let TickedGameInputEvent {
+            tick,
+            game_input_event,
+        } = ticked_game_input_event;
+
+        self.game_input_events
+            .entry(tick)
+            .or_insert_with(Vec::new)
+            .push(game_input_event);
+    }
+
+    /// Returns the `GameInputEvent`s for the given tick.
+    pub fn events(&self, tick: RollbackTick) -> &[GameInputEvent] {
+        self.game_input_events
+            .get(&tick)
+            .map(Vec::as_slice)
+            .unwrap_or(&[])
+    }
+
+    /// Discards input for ticks before the given tick.
+    pub fn prune_before(&mut self, tick: RollbackTick) {
+        self.game_input_events = self.game_input_events.split_off(&tick);
+    }
+
+    /// Discards all recorded input.
+    pub fn clear(&mut self) {
+        self.game_input_events.clear();
+    }
+}
@@ -0,0 +1,55 @@
+use crate::play::RollbackTick;
+
+/// Number of ticks that may be resimulated when late input arrives.
+///
+/// Input that arrives later than this is applied to the current tick instead.
+pub const ROLLBACK_TICKS_MAX: u32 = 12;
+
+/// Tracks the simulation tick of a rollback session.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct RollbackStatus {
+    /// Tick that is being simulated.
+    pub tick: RollbackTick,
+    /// Tick to simulate once resimulation completes, while mispredicted ticks are resimulated.
+    pub tick_live: Option<RollbackTick>,
+    /// Earliest tick that received remote input since the simulation last advanced.
+    pub rollback_tick: Option<RollbackTick>,
+    /// Latest tick whose input from every device has been received.
+    ///
+    /// This is only tracked by spectators, which do not simulate ticks after it.
+    pub tick_confirmed: Option<RollbackTick>,
+}
+
+impl RollbackStatus {
+    /// Requests the simulation be rewound to the given tick.
+    ///
+    /// If a rollback to an earlier tick is already requested, that request is kept.
+    pub fn request_rollback(&mut self, tick: RollbackTick) {
+        let rollback_tick = match self.rollback_tick {
+            Some(rollback_tick) if rollback_tick <= tick => rollback_tick,
+            _ => tick,
+        };
+        self.rollback_tick = Some(rollback_tick);
+    }
+
+    /// Returns the tick that local input applies to.
+    ///
+    /// While resimulating, input received from devices belongs to the live tick.
+    pub fn input_tick(&self) -> RollbackTick {
+        self.tick_live.unwrap_or(self.tick)
+    }
+
+    /// Returns whether every device's input for the tick being simulated has been received.
+    ///
+    /// This is always `true` for devices that play in the session.
+    pub fn is_tick_confirmed(&self) -> bool {
+        self.tick_confirmed
+            .map(|tick_confirmed| self.tick <= tick_confirmed)
+            .unwrap_or(true)
+    }
+
+    /// Returns the earliest tick that can be rewound to.
+    pub fn tick_min(&self) -> RollbackTick {
+        RollbackTick::new(self.input_tick().0.saturating_sub(ROLLBACK_TICKS_MAX))
+    }
+}
@@ -0,0 +1,8 @@
+use derive_more::{Add, AddAssign, Display, From, Sub, SubAssign};
+use numeric_newtype_derive::numeric_newtype;
+use serde::{Deserialize, Serialize};
+
+/// Tick number of the game play simulation in a rollback session.
+#[numeric_newtype]
+#[derive(Debug, Default, Deserialize, Hash, Serialize)]
+pub struct RollbackTick(pub u32);
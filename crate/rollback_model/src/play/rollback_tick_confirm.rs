@@ -0,0 +1,16 @@
+use derive_new::new;
+use serde::{Deserialize, Serialize};
+
+use crate::play::RollbackTick;
+
+/// Notifies that all input up to and including a tick has been sent.
+///
+/// Devices send this to the session server after their input for each tick. Once every device in
+/// the session has confirmed a tick, the session server sends the input for it to spectators,
+/// followed by this message.
+#[derive(Clone, Copy, Debug, Deserialize, PartialEq, Serialize, new)]
+#[serde(deny_unknown_fields)]
+pub struct RollbackTickConfirm {
+    /// Latest tick whose input has been sent.
+    pub tick: RollbackTick,
+}
@@ -0,0 +1,147 @@
+use std::collections::VecDeque;
+
+use game_input_model::GameInputEvent;
+use serde::{Deserialize, Serialize};
+
+use crate::play::{RollbackTick, TickedGameInputEvent};
+
+/// Maximum number of completed ticks kept in a `SessionInputHistory`.
+///
+/// This is 10 minutes of input at 60 ticks per second, which bounds the size of the history sent
+/// to a spectator or reconnecting device.
+pub const SESSION_INPUT_HISTORY_TICKS_MAX: usize = 36_000;
+
+/// Maximum number of `TickedGameInputEvent`s kept in a `SessionInputHistory`.
+pub const SESSION_INPUT_HISTORY_TICKED_EVENTS_MAX: usize = 36_000;
+
+/// Input broadcast in a session since it started, used to bring spectators up to date.
+///
+/// The session server records this for each started session. A spectator that joins late receives
+/// the recorded input, and applies one tick of it per frame until it has caught up to live input.
+///
+/// During rollback game play, spectators are only sent the input of ticks that every device has
+/// confirmed, so that they never mispredict input.
+///
+/// Only the most recent input is kept, so a spectator cannot catch up once earlier ticks have been
+/// discarded.
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct SessionInputHistory {
+    /// Number of completed ticks that have been discarded from the front of the history.
+    #[serde(default)]
+    pub ticks_discarded: usize,
+    /// `GameInputEvent`s of each tick that every device has completed.
+    pub game_input_ticks: VecDeque<Vec<GameInputEvent>>,
+    /// `GameInputEvent`s of the tick that is still in progress.
+    pub game_input_events_pending: Vec<GameInputEvent>,
+    /// `TickedGameInputEvent`s sent during rollback game play.
+    pub ticked_game_input_events: VecDeque<TickedGameInputEvent>,
+    /// Number of `TickedGameInputEvent`s that have been discarded from the front of the history.
+    #[serde(default)]
+    pub ticked_game_input_events_discarded: usize,
+    /// Latest rollback tick whose input from every device has been received.
+    #[serde(default)]
+    pub tick_confirmed: Option<RollbackTick>,
+}
+
+impl SessionInputHistory {
+    /// Records a `GameInputEvent` for the tick in progress.
+    pub fn push_game_input_event(&mut self, game_input_event: GameInputEvent) {
+        self.game_input_events_pending.push(game_input_event);
+    }
+
+    /// Records a `TickedGameInputEvent`.
+    ///
+    /// The earliest event is discarded when there are more than
+    /// `SESSION_INPUT_HISTORY_TICKED_EVENTS_MAX` events.
+    pub fn push_ticked_game_input_event(&mut self, ticked_game_input_event: TickedGameInputEvent) {
+        self.ticked_game_input_events
+            .push_back(ticked_game_input_event);
+        if self.ticked_game_input_events.len() > SESSION_INPUT_HISTORY_TICKED_EVENTS_MAX {
+            self.ticked_game_input_events.pop_front();
+            self.ticked_game_input_events_discarded += 1;
+        }
+    }
+
+    /// Completes the tick in progress.
+    ///
+    /// The earliest tick is discarded when there are more than `SESSION_INPUT_HISTORY_TICKS_MAX`
+    /// ticks.
+    pub fn tick(&mut self) {
+        let game_input_events = std::mem::take(&mut self.game_input_events_pending);
+        self.game_input_ticks.push_back(game_input_events);
+        if self.game_input_ticks.len() > SESSION_INPUT_HISTORY_TICKS_MAX {
+            self.game_input_ticks.pop_front();
+            self.ticks_discarded += 1;
+        }
+    }
+
+    /// Removes and returns the `GameInputEvent`s of the earliest completed tick.
+    pub fn pop_tick(&mut self) -> Option<Vec<GameInputEvent>> {
+        self.game_input_ticks.pop_front()
+    }
+
+    /// Returns a copy of this history without the first `tick_count` completed ticks.
+    ///
+    /// This is used to send a reconnecting device only the input it has not applied. Returns
+    /// `None` if ticks that the device has not applied have been discarded.
+    pub fn since_tick(&self, tick_count: usize) -> Option<SessionInputHistory> {
+        if tick_count < self.ticks_discarded {
+            return None;
+        }
+
+        Some(SessionInputHistory {
+            ticks_discarded: tick_count,
+            game_input_ticks: self
+                .game_input_ticks
+                .iter()
+                .skip(tick_count - self.ticks_discarded)
+                .cloned()
+                .collect(),
+            game_input_events_pending: self.game_input_events_pending.clone(),
+            ticked_game_input_events: self.ticked_game_input_events.clone(),
+            ticked_game_input_events_discarded: self.ticked_game_input_events_discarded,
+            // Devices that play in the session do not wait for ticks to be confirmed.
+            tick_confirmed: None,
+        })
+    }
+
+    /// Returns a copy of this history with only the `TickedGameInputEvent`s of confirmed ticks.
+    ///
+    /// This is sent to spectators, which receive the input of later ticks once every device has
+    /// confirmed them.
+    pub fn confirmed(&self) -> SessionInputHistory {
+        let tick_confirmed = self.tick_confirmed;
+        let ticked_game_input_events = self
+            .ticked_game_input_events
+            .iter()
+            .filter(|ticked_game_input_event| {
+                tick_confirmed
+                    .map(|tick_confirmed| ticked_game_input_event.tick <= tick_confirmed)
+                    .unwrap_or(false)
+            })
+            .copied()
+            .collect::<VecDeque<TickedGameInputEvent>>();
+
+        SessionInputHistory {
+            ticks_discarded: self.ticks_discarded,
+            game_input_ticks: self.game_input_ticks.clone(),
+            game_input_events_pending: self.game_input_events_pending.clone(),
+            ticked_game_input_events,
+            ticked_game_input_events_discarded: self.ticked_game_input_events_discarded,
+            tick_confirmed,
+        }
+    }
+
+    /// Returns `true` if every tick since the session started is in this history.
+    pub fn is_complete(&self) -> bool {
+        self.ticks_discarded == 0 && self.ticked_game_input_events_discarded == 0
+    }
+
+    /// Returns `true` if there is no recorded input left to apply.
+    pub fn is_empty(&self) -> bool {
+        self.game_input_ticks.is_empty()
+            && self.game_input_events_pending.is_empty()
+            && self.ticked_game_input_events.is_empty()
+    }
+}
@@ -0,0 +1,15 @@
+use derive_new::new;
+use game_input_model::GameInputEvent;
+use serde::{Deserialize, Serialize};
+
+use crate::play::RollbackTick;
+
+/// `GameInputEvent` stamped with the tick it applies to.
+#[derive(Clone, Copy, Debug, Deserialize, PartialEq, Serialize, new)]
+#[serde(deny_unknown_fields)]
+pub struct TickedGameInputEvent {
+    /// Tick that the input applies to.
+    pub tick: RollbackTick,
+    /// The input event.
+    pub game_input_event: GameInputEvent,
+}
@@ -0,0 +1,25 @@
+#![deny(missing_debug_implementations, missing_docs)] // kcov-ignore
+
+//! Provides logic for rollback network play.
+//!
+//! Each client simulates ahead using the input it has received, predicting that remote devices
+//! hold their previous input. When input for a past tick arrives, the world is restored to the
+//! snapshot for that tick, and resimulated up to the current tick.
+
+pub use crate::{
+    rollback_event_channels::RollbackEventChannels,
+    rollback_simulator::RollbackSimulator,
+    simulated_latency_transport::SimulatedLatencyTransport,
+    system::{
+        RollbackInputReplaySystem, RollbackInputReplaySystemData, RollbackInputRequestSystem,
+        RollbackInputRequestSystemDesc, RollbackInputResponseSystem,
+        RollbackInputResponseSystemDesc,
+    },
+    world_snapshot::WorldSnapshot,
+};
+
+mod rollback_event_channels;
+mod rollback_simulator;
+mod simulated_latency_transport;
+mod system;
+mod world_snapshot;
@@ -0,0 +1,62 @@
+use std::fmt::{self, Debug, Formatter};
+
+use amethyst::{
+    ecs::World,
+    shrev::{Event, EventChannel},
+};
+
+/// Event channels that are swapped out of the `World` while ticks are resimulated.
+///
+/// The systems that resimulate game play read and write events on their own channels, so that
+/// events sent while resimulating are not read again by the systems that run each frame, and
+/// events sent each frame are not read while resimulating.
+#[derive(Clone, Default)]
+pub struct RollbackEventChannels {
+    /// Functions that swap each registered event channel.
+    swap_fns: Vec<fn(&mut World)>,
+}
+
+impl RollbackEventChannels {
+    /// Registers an event channel to be swapped while resimulating.
+    pub fn register<E>(&mut self) -> &mut Self
+    where
+        E: Event,
+    {
+        self.swap_fns.push(Self::swap_channel::<E>);
+        self
+    }
+
+    /// Swaps the registered event channels in the `World` with the resimulation channels.
+    ///
+    /// Calling this again swaps the original channels back into the `World`.
+    pub fn swap(&self, world: &mut World) {
+        self.swap_fns.iter().for_each(|swap_fn| swap_fn(world));
+    }
+
+    fn swap_channel<E>(world: &mut World)
+    where
+        E: Event,
+    {
+        let event_channel = world.remove::<EventChannel<E>>().unwrap_or_default();
+        let event_channel_swapped = world
+            .remove::<SwappedEventChannel<E>>()
+            .map(|swapped_event_channel| swapped_event_channel.0)
+            .unwrap_or_default();
+
+        world.insert(event_channel_swapped);
+        world.insert(SwappedEventChannel(event_channel));
+    }
+}
+
+impl Debug for RollbackEventChannels {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_struct("RollbackEventChannels")
+            .field("channels", &self.swap_fns.len())
+            .finish()
+    }
+}
+
+/// Event channel that is swapped out of the `World`.
+struct SwappedEventChannel<E>(EventChannel<E>)
+where
+    E: Event;
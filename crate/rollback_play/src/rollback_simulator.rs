@@ -0,0 +1,224 @@
+use std::collections::VecDeque;
+
+use amethyst::ecs::{BitSet, Dispatcher, Entity, Join, World, WorldExt};
+use derivative::Derivative;
+use log::{debug, error, warn};
+use network_session_model::play::SessionCondition;
+use rollback_model::play::{InputHistory, RollbackStatus, RollbackTick, ROLLBACK_TICKS_MAX};
+use snapshot_model::play::{DeferredDelete, DeleteMode, SnapshotRegistry};
+
+use crate::{RollbackEventChannels, WorldSnapshot};
+
+/// Steps the game play simulation, rewinding and resimulating when late input arrives.
+///
+/// A snapshot of the world is captured before each tick is simulated. When remote input arrives
+/// for a tick that has already been simulated, the world is restored to the snapshot of that
+/// tick, and each tick up to the current tick is simulated again with the input recorded in the
+/// `InputHistory`.
+///
+/// Ticks are resimulated with a dispatcher that only contains the game play systems, and the
+/// `RollbackEventChannels` are swapped in while resimulating.
+///
+/// Entity deletions are deferred with `DeleteMode::Deferred`, so that entities deleted after a
+/// snapshot was captured are restored with it. Entities are deleted once their deletion is older
+/// than the rollback window.
+///
+/// Spectators only simulate ticks whose input every device has confirmed. While waiting, the
+/// `SessionCondition` is set to `PendingRollbackTickConfirm`, which pauses game play systems.
+#[derive(Derivative)]
+#[derivative(Debug(bound = ""), Default(bound = ""))]
+pub struct RollbackSimulator<S> {
+    /// Snapshots of the world before each tick was simulated, oldest first.
+    #[derivative(Debug = "ignore")]
+    snapshots: VecDeque<(RollbackTick, S)>,
+}
+
+impl<S> RollbackSimulator<S>
+where
+    S: WorldSnapshot,
+{
+    /// Returns a new `RollbackSimulator`.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Resimulates mispredicted ticks if necessary, then simulates the current tick.
+    ///
+    /// # Parameters
+    ///
+    /// * `world`: `World` to simulate.
+    /// * `dispatcher`: Dispatcher with the game play systems, which resimulates past ticks.
+    /// * `step_fn`: Function that runs all systems for the current tick.
+    pub fn update<F>(
+        &mut self,
+        world: &mut World,
+        dispatcher: &mut Dispatcher<'_, '_>,
+        mut step_fn: F,
+    ) where
+        F: FnMut(&mut World),
+    {
+        world.insert(DeleteMode::Deferred);
+
+        let (tick_current, rollback_tick) = {
+            let mut rollback_status = world.write_resource::<RollbackStatus>();
+            (rollback_status.tick, rollback_status.rollback_tick.take())
+        };
+
+        if let Some(rollback_tick) = rollback_tick {
+            if rollback_tick < tick_current {
+                // Ticks that have already been simulated were confirmed before they were simulated.
+                Self::session_condition_update(world, true);
+                self.rewind(world, rollback_tick, tick_current, dispatcher);
+            }
+        }
+
+        let tick_confirmed = world.read_resource::<RollbackStatus>().is_tick_confirmed();
+        Self::session_condition_update(world, tick_confirmed);
+
+        if tick_confirmed {
+            self.step(world, &mut step_fn);
+        } else {
+            // Non game play systems still run, so that the confirmation is received.
+            step_fn(world);
+            world.maintain();
+        }
+    }
+
+    /// Discards all snapshots.
+    pub fn reset(&mut self) {
+        self.snapshots.clear();
+    }
+
+    /// Pauses game play systems while waiting for the tick to be confirmed.
+    fn session_condition_update(world: &mut World, tick_confirmed: bool) {
+        let mut session_condition = world
+            .entry::<SessionCondition>()
+            .or_insert_with(SessionCondition::default);
+        if !tick_confirmed {
+            *session_condition = SessionCondition::PendingRollbackTickConfirm;
+        } else if *session_condition == SessionCondition::PendingRollbackTickConfirm {
+            *session_condition = SessionCondition::Ready;
+        }
+    }
+
+    /// Restores the snapshot for `rollback_tick`, and resimulates up to `tick_current`.
+    fn rewind(
+        &mut self,
+        world: &mut World,
+        rollback_tick: RollbackTick,
+        tick_current: RollbackTick,
+        dispatcher: &mut Dispatcher<'_, '_>,
+    ) {
+        let snapshot_index = self
+            .snapshots
+            .iter()
+            .position(|(tick, _)| *tick == rollback_tick);
+
+        if let Some(snapshot_index) = snapshot_index {
+            debug!(
+                "Rolling back from tick `{}` to tick `{}`.",
+                tick_current, rollback_tick
+            );
+
+            let (_, snapshot) = &self.snapshots[snapshot_index];
+            snapshot.restore(world);
+            world.maintain();
+            self.snapshots.truncate(snapshot_index);
+
+            {
+                let mut rollback_status = world.write_resource::<RollbackStatus>();
+                rollback_status.tick = rollback_tick;
+                rollback_status.tick_live = Some(tick_current);
+            }
+
+            let rollback_event_channels = world
+                .try_fetch::<RollbackEventChannels>()
+                .map(|rollback_event_channels| (*rollback_event_channels).clone())
+                .unwrap_or_default();
+            rollback_event_channels.swap(world);
+            let mut dispatch_fn = |world: &mut World| dispatcher.dispatch(world);
+            while world.read_resource::<RollbackStatus>().tick < tick_current {
+                self.step(world, &mut dispatch_fn);
+            }
+            rollback_event_channels.swap(world);
+
+            world.write_resource::<RollbackStatus>().tick_live = None;
+        } else {
+            warn!(
+                "Unable to roll back to tick `{}`, snapshot is no longer available.",
+                rollback_tick
+            );
+        }
+    }
+
+    /// Captures a snapshot, and simulates a single tick.
+    fn step<F>(&mut self, world: &mut World, step_fn: &mut F)
+    where
+        F: FnMut(&mut World),
+    {
+        let tick = world.read_resource::<RollbackStatus>().tick;
+        self.snapshots.push_back((tick, S::capture(world)));
+        while self.snapshots.len() > ROLLBACK_TICKS_MAX as usize + 1 {
+            self.snapshots.pop_front();
+        }
+
+        step_fn(world);
+        Self::deferred_delete_update(world);
+        world.maintain();
+
+        let tick_min = {
+            let mut rollback_status = world.write_resource::<RollbackStatus>();
+            rollback_status.tick = RollbackTick::new(rollback_status.tick.0 + 1);
+            rollback_status.tick_min()
+        };
+        world
+            .write_resource::<InputHistory>()
+            .prune_before(tick_min);
+    }
+
+    /// Takes newly deleted entities out of game play, and deletes entities whose deletion is older
+    /// than the rollback window.
+    fn deferred_delete_update(world: &World) {
+        let deferred_deletes = {
+            let entities = world.entities();
+            let deferred_deletes = world.read_storage::<DeferredDelete>();
+            (&entities, &deferred_deletes)
+                .join()
+                .map(|(entity, deferred_delete)| (entity, *deferred_delete))
+                .collect::<Vec<(Entity, DeferredDelete)>>()
+        };
+        if deferred_deletes.is_empty() {
+            return;
+        }
+
+        // Entities deleted this tick keep their other components, such as asset handles, so that
+        // they are still complete when restored.
+        let mut entities_deleted = BitSet::new();
+        deferred_deletes
+            .iter()
+            .filter(|(_, deferred_delete)| deferred_delete.ticks == 0)
+            .for_each(|(entity, _)| {
+                entities_deleted.add(entity.id());
+            });
+        if let Some(snapshot_registry) = world.try_fetch::<SnapshotRegistry>() {
+            snapshot_registry.remove(world, &entities_deleted);
+        }
+
+        let entities = world.entities();
+        let mut deferred_delete_storage = world.write_storage::<DeferredDelete>();
+        deferred_deletes
+            .into_iter()
+            .for_each(|(entity, deferred_delete)| {
+                let ticks = deferred_delete.ticks + 1;
+                if ticks > ROLLBACK_TICKS_MAX {
+                    if let Err(e) = entities.delete(entity) {
+                        error!("Failed to delete entity: {}", e);
+                    }
+                } else {
+                    deferred_delete_storage
+                        .insert(entity, DeferredDelete::new(ticks))
+                        .expect("Failed to insert `DeferredDelete` component.");
+                }
+            });
+    }
+}
@@ -0,0 +1,56 @@
+use std::collections::VecDeque;
+
+use derivative::Derivative;
+use rollback_model::play::RollbackTick;
+
+/// Stand-in for a network transport that delivers messages after a fixed number of ticks.
+///
+/// This is used to exercise rollback logic without a network connection.
+#[derive(Clone, Derivative)]
+#[derivative(Debug(bound = ""))]
+pub struct SimulatedLatencyTransport<T> {
+    /// Number of ticks between a message being sent and it being received.
+    pub latency: u32,
+    /// Messages in transit, with the tick that they are delivered on.
+    #[derivative(Debug = "ignore")]
+    messages: VecDeque<(RollbackTick, T)>,
+}
+
+impl<T> SimulatedLatencyTransport<T> {
+    /// Returns a new `SimulatedLatencyTransport`.
+    ///
+    /// # Parameters
+    ///
+    /// * `latency`: Number of ticks between a message being sent and it being received.
+    pub fn new(latency: u32) -> Self {
+        SimulatedLatencyTransport {
+            latency,
+            messages: VecDeque::new(),
+        }
+    }
+
+    /// Sends a message on the given tick.
+    pub fn send(&mut self, tick: RollbackTick, message: T) {
+        let delivery_tick = RollbackTick::new(tick.0 + self.latency);
+        self.messages.push_back((delivery_tick, message));
+    }
+
+    /// Returns the messages that have arrived by the given tick.
+    pub fn receive(&mut self, tick: RollbackTick) -> Vec<T> {
+        let mut messages_received = Vec::new();
+        while let Some((delivery_tick, _)) = self.messages.front() {
+            if *delivery_tick > tick {
+                break;
+            }
+            if let Some((_, message)) = self.messages.pop_front() {
+                messages_received.push(message);
+            }
+        }
+        messages_received
+    }
+
+    /// Returns whether there are messages in transit.
+    pub fn is_empty(&self) -> bool {
+        self.messages.is_empty()
+    }
+}
@@ -0,0 +1,11 @@
+pub use self::{
+    rollback_input_replay_system::{RollbackInputReplaySystem, RollbackInputReplaySystemData},
+    rollback_input_request_system::{RollbackInputRequestSystem, RollbackInputRequestSystemDesc},
+    rollback_input_response_system::{
+        RollbackInputResponseSystem, RollbackInputResponseSystemDesc,
+    },
+};
+
+mod rollback_input_replay_system;
+mod rollback_input_request_system;
+mod rollback_input_response_system;
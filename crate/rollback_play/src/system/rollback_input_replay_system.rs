@@ -0,0 +1,77 @@
+use amethyst::{
+    ecs::{Read, System, World, Write},
+    shred::{ResourceId, SystemData},
+    shrev::EventChannel,
+};
+use derivative::Derivative;
+use derive_new::new;
+use game_input_model::GameInputEvent;
+use game_play_model::GamePlayStatus;
+use network_session_model::play::{SessionCondition, SessionStatus};
+use rollback_model::{
+    config::SessionSyncMode,
+    play::{InputHistory, RollbackStatus},
+};
+
+/// Sends the recorded `GameInputEvent`s for the current tick.
+///
+/// This runs each time a tick is simulated, including when the tick is resimulated.
+///
+/// Input is not sent while a spectator waits for the tick to be confirmed, as game play does not
+/// advance until then.
+#[derive(Debug, Default, new)]
+pub struct RollbackInputReplaySystem;
+
+/// `RollbackInputReplaySystemData`.
+#[derive(Derivative, SystemData)]
+#[derivative(Debug)]
+pub struct RollbackInputReplaySystemData<'s> {
+    /// `SessionSyncMode` resource.
+    #[derivative(Debug = "ignore")]
+    pub session_sync_mode: Read<'s, SessionSyncMode>,
+    /// `SessionStatus` resource.
+    #[derivative(Debug = "ignore")]
+    pub session_status: Read<'s, SessionStatus>,
+    /// `GamePlayStatus` resource.
+    #[derivative(Debug = "ignore")]
+    pub game_play_status: Read<'s, GamePlayStatus>,
+    /// `RollbackStatus` resource.
+    #[derivative(Debug = "ignore")]
+    pub rollback_status: Read<'s, RollbackStatus>,
+    /// `SessionCondition` resource.
+    #[derivative(Debug = "ignore")]
+    pub session_condition: Write<'s, SessionCondition>,
+    /// `InputHistory` resource.
+    #[derivative(Debug = "ignore")]
+    pub input_history: Read<'s, InputHistory>,
+    /// `GameInputEvent` channel.
+    #[derivative(Debug = "ignore")]
+    pub game_input_ec: Write<'s, EventChannel<GameInputEvent>>,
+}
+
+impl<'s> System<'s> for RollbackInputReplaySystem {
+    type SystemData = RollbackInputReplaySystemData<'s>;
+
+    fn run(
+        &mut self,
+        RollbackInputReplaySystemData {
+            session_sync_mode,
+            session_status,
+            game_play_status,
+            rollback_status,
+            mut session_condition,
+            input_history,
+            mut game_input_ec,
+        }: Self::SystemData,
+    ) {
+        if session_sync_mode.is_rollback_active(&session_status, *game_play_status) {
+            if *session_condition != SessionCondition::PendingRollbackTickConfirm {
+                let game_input_events = input_history.events(rollback_status.tick);
+                game_input_ec.iter_write(game_input_events.iter().copied());
+            }
+        } else if *session_condition == SessionCondition::PendingRollbackTickConfirm {
+            // Game play has ended, so there are no more ticks to wait for.
+            *session_condition = SessionCondition::Ready;
+        }
+    }
+}
@@ -0,0 +1,99 @@
+use std::convert::TryFrom;
+
+use amethyst::{
+    derive::SystemDesc,
+    ecs::{Read, System, World, Write},
+    input::InputEvent,
+    shred::{ResourceId, SystemData},
+    shrev::{EventChannel, ReaderId},
+};
+use derivative::Derivative;
+use derive_new::new;
+use game_input_model::{config::ControlBindings, play::AxisInputQuantizer, GameInputEvent};
+use game_play_model::GamePlayStatus;
+use net_model::play::NetMessageEvent;
+use network_session_model::play::SessionStatus;
+use rollback_model::{
+    config::SessionSyncMode,
+    play::{InputHistory, RollbackStatus, RollbackTickConfirm, TickedGameInputEvent},
+};
+
+/// Records local input for the current tick, and sends it to the session server.
+///
+/// Each tick's input is followed by a `RollbackTickConfirm`, so that the session server knows when
+/// it may send the tick's input to spectators.
+#[derive(Debug, SystemDesc, new)]
+#[system_desc(name(RollbackInputRequestSystemDesc))]
+pub struct RollbackInputRequestSystem {
+    /// Reader ID for the `InputEvent<ControlBindings>` channel.
+    #[system_desc(event_channel_reader)]
+    input_event_rid: ReaderId<InputEvent<ControlBindings>>,
+    /// Quantizes analog axis values into the same values as keyboard axes.
+    #[system_desc(skip)]
+    #[new(default)]
+    axis_input_quantizer: AxisInputQuantizer,
+}
+
+/// `RollbackInputRequestSystemData`.
+#[derive(Derivative, SystemData)]
+#[derivative(Debug)]
+pub struct RollbackInputRequestSystemData<'s> {
+    /// `InputEvent<ControlBindings>` channel.
+    #[derivative(Debug = "ignore")]
+    pub input_ec: Read<'s, EventChannel<InputEvent<ControlBindings>>>,
+    /// `SessionSyncMode` resource.
+    #[derivative(Debug = "ignore")]
+    pub session_sync_mode: Read<'s, SessionSyncMode>,
+    /// `SessionStatus` resource.
+    #[derivative(Debug = "ignore")]
+    pub session_status: Read<'s, SessionStatus>,
+    /// `GamePlayStatus` resource.
+    #[derivative(Debug = "ignore")]
+    pub game_play_status: Read<'s, GamePlayStatus>,
+    /// `RollbackStatus` resource.
+    #[derivative(Debug = "ignore")]
+    pub rollback_status: Read<'s, RollbackStatus>,
+    /// `InputHistory` resource.
+    #[derivative(Debug = "ignore")]
+    pub input_history: Write<'s, InputHistory>,
+    /// `NetMessageEvent` channel.
+    #[derivative(Debug = "ignore")]
+    pub net_message_ec: Write<'s, EventChannel<NetMessageEvent>>,
+}
+
+impl<'s> System<'s> for RollbackInputRequestSystem {
+    type SystemData = RollbackInputRequestSystemData<'s>;
+
+    fn run(
+        &mut self,
+        RollbackInputRequestSystemData {
+            input_ec,
+            session_sync_mode,
+            session_status,
+            game_play_status,
+            rollback_status,
+            mut input_history,
+            mut net_message_ec,
+        }: Self::SystemData,
+    ) {
+        let axis_input_quantizer = &mut self.axis_input_quantizer;
+        let input_events = input_ec.read(&mut self.input_event_rid);
+
+        if session_sync_mode.is_rollback_active(&session_status, *game_play_status) {
+            let tick = rollback_status.input_tick();
+            input_events
+                .filter_map(|ev| GameInputEvent::try_from(ev).ok())
+                .filter_map(|ev| axis_input_quantizer.quantize(ev))
+                .map(|game_input_event| TickedGameInputEvent::new(tick, game_input_event))
+                .for_each(|ticked_game_input_event| {
+                    input_history.insert(ticked_game_input_event);
+                    net_message_ec.single_write(NetMessageEvent::TickedGameInputEvent(
+                        ticked_game_input_event,
+                    ));
+                });
+            net_message_ec.single_write(NetMessageEvent::RollbackTickConfirm(
+                RollbackTickConfirm::new(tick),
+            ));
+        }
+    }
+}
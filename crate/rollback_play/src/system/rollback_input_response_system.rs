@@ -0,0 +1,138 @@
+use amethyst::{
+    derive::SystemDesc,
+    ecs::{Read, System, World, Write},
+    shred::{ResourceId, SystemData},
+    shrev::ReaderId,
+};
+use derivative::Derivative;
+use derive_new::new;
+use game_play_model::GamePlayStatus;
+use log::{debug, warn};
+use net_model::play::{NetData, NetEventChannel};
+use network_session_model::play::SessionStatus;
+use rollback_model::{
+    config::SessionSyncMode,
+    play::{
+        InputHistory, RollbackStatus, RollbackTickConfirm, SessionInputHistory,
+        TickedGameInputEvent,
+    },
+};
+
+/// Records remote input, and requests a rollback to the tick it applies to.
+///
+/// The `RollbackSimulator` only rewinds if the tick has already been simulated.
+///
+/// Spectators are also sent `RollbackTickConfirm`s, which record the latest tick that they may
+/// simulate.
+///
+/// While a spectator is catching up with a session, remote input is recorded in the
+/// `SessionInputHistory` instead, to be applied after the earlier input.
+#[derive(Debug, SystemDesc, new)]
+#[system_desc(name(RollbackInputResponseSystemDesc))]
+pub struct RollbackInputResponseSystem {
+    /// Reader ID for the `TickedGameInputEvent` channel.
+    #[system_desc(event_channel_reader)]
+    ticked_game_input_event_rid: ReaderId<NetData<TickedGameInputEvent>>,
+    /// Reader ID for the `RollbackTickConfirm` channel.
+    #[system_desc(event_channel_reader)]
+    rollback_tick_confirm_rid: ReaderId<NetData<RollbackTickConfirm>>,
+}
+
+/// `RollbackInputResponseSystemData`.
+#[derive(Derivative, SystemData)]
+#[derivative(Debug)]
+pub struct RollbackInputResponseSystemData<'s> {
+    /// Net `TickedGameInputEvent` channel.
+    #[derivative(Debug = "ignore")]
+    pub ticked_game_input_nec: Read<'s, NetEventChannel<TickedGameInputEvent>>,
+    /// Net `RollbackTickConfirm` channel.
+    #[derivative(Debug = "ignore")]
+    pub rollback_tick_confirm_nec: Read<'s, NetEventChannel<RollbackTickConfirm>>,
+    /// `SessionSyncMode` resource.
+    #[derivative(Debug = "ignore")]
+    pub session_sync_mode: Read<'s, SessionSyncMode>,
+    /// `SessionStatus` resource.
+    #[derivative(Debug = "ignore")]
+    pub session_status: Read<'s, SessionStatus>,
+    /// `GamePlayStatus` resource.
+    #[derivative(Debug = "ignore")]
+    pub game_play_status: Read<'s, GamePlayStatus>,
+    /// `RollbackStatus` resource.
+    #[derivative(Debug = "ignore")]
+    pub rollback_status: Write<'s, RollbackStatus>,
+    /// `InputHistory` resource.
+    #[derivative(Debug = "ignore")]
+    pub input_history: Write<'s, InputHistory>,
+    /// `SessionInputHistory` resource.
+    #[derivative(Debug = "ignore")]
+    pub session_input_history: Write<'s, SessionInputHistory>,
+}
+
+impl<'s> System<'s> for RollbackInputResponseSystem {
+    type SystemData = RollbackInputResponseSystemData<'s>;
+
+    fn run(
+        &mut self,
+        RollbackInputResponseSystemData {
+            ticked_game_input_nec,
+            rollback_tick_confirm_nec,
+            session_sync_mode,
+            session_status,
+            game_play_status,
+            mut rollback_status,
+            mut input_history,
+            mut session_input_history,
+        }: Self::SystemData,
+    ) {
+        let ticked_game_input_events =
+            ticked_game_input_nec.read(&mut self.ticked_game_input_event_rid);
+        let tick_confirmed = rollback_tick_confirm_nec
+            .read(&mut self.rollback_tick_confirm_rid)
+            .map(|ev| ev.data.tick)
+            .last();
+
+        if !session_input_history.is_empty() {
+            ticked_game_input_events.for_each(|ev| {
+                session_input_history.push_ticked_game_input_event(ev.data);
+            });
+            if tick_confirmed.is_some() {
+                session_input_history.tick_confirmed = tick_confirmed;
+            }
+            return;
+        }
+
+        if !session_sync_mode.is_rollback_active(&session_status, *game_play_status) {
+            return;
+        }
+
+        ticked_game_input_events.for_each(|ev| {
+            let NetData {
+                data: ticked_game_input_event,
+                ..
+            } = ev;
+            let mut ticked_game_input_event = *ticked_game_input_event;
+
+            debug!(
+                "`NetData<TickedGameInputEvent>` received: {:?}.",
+                ticked_game_input_event
+            );
+
+            let tick_min = rollback_status.tick_min();
+            if ticked_game_input_event.tick < tick_min {
+                warn!(
+                    "Received input for tick `{}`, which is before the earliest tick that can be \
+                    rolled back to: `{}`. Applying input to the current tick.",
+                    ticked_game_input_event.tick, tick_min
+                );
+                ticked_game_input_event.tick = rollback_status.input_tick();
+            }
+
+            rollback_status.request_rollback(ticked_game_input_event.tick);
+            input_history.insert(ticked_game_input_event);
+        });
+
+        if tick_confirmed.is_some() {
+            rollback_status.tick_confirmed = tick_confirmed;
+        }
+    }
+}
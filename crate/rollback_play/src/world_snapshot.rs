@@ -0,0 +1,10 @@
+use amethyst::ecs::World;
+
+/// Captures and restores the world's game play state.
+pub trait WorldSnapshot: Send + Sync + 'static {
+    /// Returns a snapshot of the world's current state.
+    fn capture(world: &World) -> Self;
+
+    /// Restores the world's state to this snapshot.
+    fn restore(&self, world: &World);
+}
@@ -1,8 +1,9 @@
 use amethyst::ecs::{storage::VecStorage, Component};
 use derivative::Derivative;
+use serde::{Deserialize, Serialize};
 
 /// Statuses that indicate whether a sequence has just begun, is ongoing, or has ended.
-#[derive(Clone, Copy, Debug, Derivative, PartialEq, Eq)]
+#[derive(Clone, Copy, Debug, Derivative, Deserialize, PartialEq, Eq, Serialize)]
 #[derivative(Default)]
 pub enum SequenceStatus {
     /// The sequence has just begun.
@@ -9,6 +9,7 @@ use sequence_model::{
     loaded::{SequenceEndTransition, SequenceId},
     play::SequenceUpdateEvent,
 };
+use snapshot_model::play::{DeferredDelete, DeleteMode};
 
 /// Transitions an object when their `SequenceUpdateEvent::SequenceEnd`
 #[derive(Debug, Default, new)]
@@ -25,6 +26,9 @@ pub struct SequenceEndTransitionSystemData<'s> {
     /// `Entities` resource.
     #[derivative(Debug = "ignore")]
     pub entities: Entities<'s>,
+    /// `DeleteMode` resource.
+    #[derivative(Debug = "ignore")]
+    pub delete_mode: Read<'s, DeleteMode>,
     /// Event channel for `SequenceUpdateEvent`s.
     #[derivative(Debug = "ignore")]
     pub sequence_update_ec: Read<'s, EventChannel<SequenceUpdateEvent>>,
@@ -34,6 +38,9 @@ pub struct SequenceEndTransitionSystemData<'s> {
     /// `SequenceId` components.
     #[derivative(Debug = "ignore")]
     pub sequence_ids: WriteStorage<'s, SequenceId>,
+    /// `DeferredDelete` components.
+    #[derivative(Debug = "ignore")]
+    pub deferred_deletes: WriteStorage<'s, DeferredDelete>,
 }
 
 impl<'s> System<'s> for SequenceEndTransitionSystem {
@@ -43,9 +50,11 @@ impl<'s> System<'s> for SequenceEndTransitionSystem {
         &mut self,
         SequenceEndTransitionSystemData {
             entities,
+            delete_mode,
             sequence_update_ec,
             sequence_end_transitions,
             mut sequence_ids,
+            mut deferred_deletes,
         }: Self::SystemData,
     ) {
         sequence_update_ec
@@ -73,8 +82,8 @@ impl<'s> System<'s> for SequenceEndTransitionSystem {
                                 .expect("Failed to insert `SequenceId` component.");
                         }
                         SequenceEndTransition::Delete => {
-                            entities
-                                .delete(entity)
+                            delete_mode
+                                .delete(&entities, &mut deferred_deletes, entity)
                                 .expect("Failed to delete entity on `SequenceEndTransition`.");
                         }
                         SequenceEndTransition::SequenceId(sequence_id) => {
@@ -0,0 +1,9 @@
+#![deny(missing_debug_implementations, missing_docs)] // kcov-ignore
+
+//! State where the player browses public sessions to join.
+
+pub use crate::session_browse_state::{
+    SessionBrowseState, SessionBrowseStateBuilder, SessionBrowseStateDelegate,
+};
+
+mod session_browse_state;
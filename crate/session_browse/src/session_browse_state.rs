@@ -0,0 +1,121 @@
+use std::{fmt::Debug, marker::PhantomData};
+
+use amethyst::{
+    ecs::{World, WorldExt},
+    shrev::EventChannel,
+    GameData, State, StateData, Trans,
+};
+use application_event::AppEvent;
+use application_state::{AppState, AppStateBuilder, AutexState};
+use derivative::Derivative;
+use derive_new::new;
+use log::debug;
+use session_browse_model::{
+    play::{SessionListingIndex, SessionListings, SessionSelectParams},
+    SessionBrowseEntity, SessionBrowseEvent,
+};
+use session_join_model::play::SessionCodePrefill;
+use state_registry::StateId;
+
+/// `State` where public sessions are browsed.
+///
+/// This state is not intended to be constructed directly, but through the
+/// [`SessionBrowseStateBuilder`][state_builder].
+///
+/// # Type Parameters
+///
+/// * `F`: Function to construct the session join state.
+/// * `S`: Session join state.
+///
+/// [state_builder]: session_browse_state/struct.SessionBrowseStateBuilder.html
+pub type SessionBrowseState<'a, 'b, F, S> =
+    AppState<'a, 'b, SessionBrowseStateDelegate<'a, 'b, F, S>, SessionBrowseEntity>;
+
+/// Builder for a `SessionBrowseState`.
+///
+/// `SystemBundle`s to run in the `SessionBrowseState`'s dispatcher are registered on this builder.
+///
+/// # Type Parameters
+///
+/// * `F`: Function to construct the session join state.
+/// * `S`: Session join state.
+pub type SessionBrowseStateBuilder<'a, 'b, F, S> =
+    AppStateBuilder<'a, 'b, SessionBrowseStateDelegate<'a, 'b, F, S>, SessionBrowseEntity>;
+
+/// Delegate `State` for session browsing.
+///
+/// This state is not intended to be used directly, but wrapped in an `AppState`. The
+/// `SessionBrowseState` is an alias with this as a delegate state.
+///
+/// When a session is selected, this state is switched with the session join state, with the
+/// selected session code filled in.
+///
+/// # Type Parameters
+///
+/// * `F`: Function to construct the session join state.
+/// * `S`: Session join state.
+#[derive(Derivative, new)]
+#[derivative(Debug)]
+pub struct SessionBrowseStateDelegate<'a, 'b, F, S>
+where
+    F: Fn() -> Box<S>,
+    S: AutexState<'a, 'b> + 'static,
+{
+    /// Function to construct the session join state.
+    #[derivative(Debug(bound = "F: Debug"))]
+    session_join_state_fn: F,
+    /// `PhantomData`.
+    marker: PhantomData<dyn AutexState<'a, 'b>>,
+}
+
+impl<'a, 'b, F, S> SessionBrowseStateDelegate<'a, 'b, F, S>
+where
+    F: Fn() -> Box<S>,
+    S: AutexState<'a, 'b> + 'static,
+{
+    fn initialize_state(world: &mut World) {
+        world.insert(StateId::SessionBrowse);
+        world.insert(SessionListings::default());
+        world.insert(SessionListingIndex::default());
+
+        world
+            .write_resource::<EventChannel<SessionBrowseEvent>>()
+            .single_write(SessionBrowseEvent::SessionListRequest);
+    }
+}
+
+impl<'a, 'b, F, S> State<GameData<'a, 'b>, AppEvent> for SessionBrowseStateDelegate<'a, 'b, F, S>
+where
+    F: Fn() -> Box<S>,
+    S: AutexState<'a, 'b> + 'static,
+{
+    fn on_start(&mut self, data: StateData<'_, GameData<'a, 'b>>) {
+        Self::initialize_state(data.world);
+    }
+
+    fn on_resume(&mut self, data: StateData<'_, GameData<'a, 'b>>) {
+        Self::initialize_state(data.world);
+    }
+
+    fn handle_event(
+        &mut self,
+        data: StateData<'_, GameData<'a, 'b>>,
+        event: AppEvent,
+    ) -> Trans<GameData<'a, 'b>, AppEvent> {
+        if let AppEvent::SessionBrowse(session_browse_event) = event {
+            debug!("Received session_browse_event: {:?}", session_browse_event);
+            match session_browse_event {
+                SessionBrowseEvent::SessionSelect(SessionSelectParams { session_code }) => {
+                    data.world
+                        .insert(SessionCodePrefill::new(Some(session_code)));
+
+                    Trans::Switch((self.session_join_state_fn)())
+                }
+                SessionBrowseEvent::Back => Trans::Pop,
+                _ => Trans::None,
+            }
+        } else {
+            Trans::None
+        }
+    }
+}
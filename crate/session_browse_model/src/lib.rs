@@ -0,0 +1,12 @@
+#![deny(missing_debug_implementations, missing_docs)] // kcov-ignore
+
+//! Types used when browsing public sessions.
+
+pub use crate::{
+    session_browse_entity::SessionBrowseEntity, session_browse_event::SessionBrowseEvent,
+};
+
+pub mod play;
+
+mod session_browse_entity;
+mod session_browse_event;
@@ -0,0 +1,16 @@
+//! Data types used at runtime.
+
+pub use self::{
+    session_browse_model_error::SessionBrowseModelError,
+    session_list_response::SessionListResponse, session_listing::SessionListing,
+    session_listing_index::SessionListingIndex, session_listing_status::SessionListingStatus,
+    session_listings::SessionListings, session_select_params::SessionSelectParams,
+};
+
+mod session_browse_model_error;
+mod session_list_response;
+mod session_listing;
+mod session_listing_index;
+mod session_listing_status;
+mod session_listings;
+mod session_select_params;
@@ -0,0 +1,38 @@
+use std::{
+    error::Error,
+    fmt::{self, Display, Formatter},
+};
+
+/// Errors when using `session_browse_model` types.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum SessionBrowseModelError {
+    /// Failed to parse a `SessionListing` from string.
+    SessionListingParseError,
+    /// Failed to parse `SessionListings` from string.
+    SessionListingsParseError,
+}
+
+impl Display for SessionBrowseModelError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::SessionListingParseError => write!(
+                f,
+                "Session listing must be in the form:\n\
+                \n\
+                <session_code>:<host_name>:<device_count>:<status>\n\
+                \n\
+                Example: `abcd:azriel:2:lobby`\n"
+            ),
+            Self::SessionListingsParseError => write!(
+                f,
+                "Session listings must be space separated in the form:\n\
+                \n\
+                <session_listing_0> <session_listing_1>\n\
+                \n\
+                Example: `abcd:azriel:2:lobby efgh:byron:3:in_game`\n"
+            ),
+        }
+    }
+}
+
+impl Error for SessionBrowseModelError {}
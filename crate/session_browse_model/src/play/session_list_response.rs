@@ -0,0 +1,13 @@
+use derive_new::new;
+use serde::{Deserialize, Serialize};
+use structopt_derive::StructOpt;
+
+use crate::play::SessionListings;
+
+/// Public sessions hosted on the session server.
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize, StructOpt, new)]
+pub struct SessionListResponse {
+    /// Public sessions, e.g. `abcd:azriel:2:lobby efgh:byron:3:in_game`.
+    #[structopt(long)]
+    pub session_listings: SessionListings,
+}
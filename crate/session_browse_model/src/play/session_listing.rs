@@ -0,0 +1,66 @@
+use std::str::FromStr;
+
+use derive_new::new;
+use network_session_model::play::{SessionCode, SessionDeviceName};
+use serde::{Deserialize, Serialize};
+
+use crate::play::{SessionBrowseModelError, SessionListingStatus};
+
+/// Summary of a public session.
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize, new)]
+pub struct SessionListing {
+    /// Code of the session.
+    pub session_code: SessionCode,
+    /// Name of the device that hosted the session.
+    pub session_host_name: SessionDeviceName,
+    /// Number of devices in the session.
+    pub session_device_count: usize,
+    /// Whether the session is in the lobby or in game.
+    pub session_listing_status: SessionListingStatus,
+}
+
+impl FromStr for SessionListing {
+    type Err = SessionBrowseModelError;
+
+    fn from_str(session_listing_str: &str) -> Result<Self, SessionBrowseModelError> {
+        let mut session_listing_split = session_listing_str.split(':');
+
+        let session_code = session_listing_split
+            .next()
+            .map(String::from)
+            .map(SessionCode::from);
+        let session_host_name = session_listing_split
+            .next()
+            .map(String::from)
+            .map(SessionDeviceName::from);
+        let session_device_count = session_listing_split
+            .next()
+            .and_then(|count_str| count_str.parse::<usize>().ok());
+        let session_listing_status = session_listing_split
+            .next()
+            .and_then(|status_str| SessionListingStatus::from_str(status_str).ok());
+
+        if let (
+            Some(session_code),
+            Some(session_host_name),
+            Some(session_device_count),
+            Some(session_listing_status),
+            None,
+        ) = (
+            session_code,
+            session_host_name,
+            session_device_count,
+            session_listing_status,
+            session_listing_split.next(),
+        ) {
+            Ok(SessionListing::new(
+                session_code,
+                session_host_name,
+                session_device_count,
+                session_listing_status,
+            ))
+        } else {
+            Err(SessionBrowseModelError::SessionListingParseError)
+        }
+    }
+}
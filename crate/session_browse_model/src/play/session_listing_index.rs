@@ -0,0 +1,6 @@
+use derive_deref::{Deref, DerefMut};
+use derive_new::new;
+
+/// Index of the selected `SessionListing` (`usize` newtype).
+#[derive(Clone, Copy, Debug, Default, Deref, DerefMut, PartialEq, Eq, new)]
+pub struct SessionListingIndex(pub usize);
@@ -0,0 +1,13 @@
+use serde::{Deserialize, Serialize};
+use strum_macros::{Display, EnumString};
+
+/// Whether a public session is in the lobby or in game.
+#[derive(Clone, Copy, Debug, Deserialize, Display, EnumString, PartialEq, Eq, Serialize)]
+#[serde(deny_unknown_fields, rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum SessionListingStatus {
+    /// Session devices are in the lobby, and the session has not started.
+    Lobby,
+    /// Session has started, and new devices may only spectate.
+    InGame,
+}
@@ -0,0 +1,30 @@
+use std::str::FromStr;
+
+use derive_deref::{Deref, DerefMut};
+use derive_new::new;
+use serde::{Deserialize, Serialize};
+
+use crate::play::{SessionBrowseModelError, SessionListing};
+
+/// Public sessions hosted on the session server (`Vec<SessionListing>` newtype).
+#[derive(Clone, Debug, Default, Deref, DerefMut, Deserialize, PartialEq, Serialize, new)]
+pub struct SessionListings(pub Vec<SessionListing>);
+
+impl FromStr for SessionListings {
+    type Err = SessionBrowseModelError;
+
+    fn from_str(s: &str) -> Result<Self, SessionBrowseModelError> {
+        s.split_whitespace()
+            .try_fold(
+                SessionListings::default(),
+                |mut session_listings, session_listing_str| {
+                    let session_listing = SessionListing::from_str(session_listing_str)?;
+                    session_listings.push(session_listing);
+                    Ok(session_listings)
+                },
+            )
+            .map_err(|_: SessionBrowseModelError| {
+                SessionBrowseModelError::SessionListingsParseError
+            })
+    }
+}
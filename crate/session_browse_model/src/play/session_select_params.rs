@@ -0,0 +1,12 @@
+use derive_new::new;
+use network_session_model::play::SessionCode;
+use serde::{Deserialize, Serialize};
+use structopt_derive::StructOpt;
+
+/// Session selected from the list of public sessions.
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize, StructOpt, new)]
+pub struct SessionSelectParams {
+    /// Code of the selected session.
+    #[structopt(long)]
+    pub session_code: SessionCode,
+}
@@ -0,0 +1,31 @@
+use serde::{Deserialize, Serialize};
+use structopt_derive::StructOpt;
+
+use crate::play::{SessionListResponse, SessionSelectParams};
+
+/// Session browse state events.
+///
+/// # Examples
+///
+/// When read in as a command, the command string should look like the following:
+///
+/// * `session_browse session_list_request`
+/// * `session_browse session_list_response --session-listings "abcd:azriel:2:lobby efgh:byron:3:in_game"`
+/// * `session_browse session_select --session-code abcd`
+/// * `session_browse back`
+///
+/// **Note:** The `session_list_response` subcommand is designed to be received from the server, so
+/// sending this as a local command may cause undefined behaviour.
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize, StructOpt)]
+#[serde(deny_unknown_fields, rename_all = "snake_case")]
+#[structopt(rename_all = "snake_case")]
+pub enum SessionBrowseEvent {
+    /// Player requested the list of public sessions.
+    SessionListRequest,
+    /// Server responded with the list of public sessions.
+    SessionListResponse(SessionListResponse),
+    /// Player selected a session to join.
+    SessionSelect(SessionSelectParams),
+    /// Return to the previous menu.
+    Back,
+}
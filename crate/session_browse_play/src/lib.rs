@@ -0,0 +1,10 @@
+#![deny(missing_debug_implementations, missing_docs)] // kcov-ignore
+
+//! Provides logic for browsing public sessions.
+
+pub use crate::system::{
+    SessionBrowseRequestSystem, SessionBrowseRequestSystemDesc, SessionBrowseResponseSystem,
+    SessionBrowseResponseSystemDesc, SessionListingSelectionSystem, SessionListingsDisplaySystem,
+};
+
+mod system;
@@ -0,0 +1,13 @@
+pub use self::{
+    session_browse_request_system::{SessionBrowseRequestSystem, SessionBrowseRequestSystemDesc},
+    session_browse_response_system::{
+        SessionBrowseResponseSystem, SessionBrowseResponseSystemDesc,
+    },
+    session_listing_selection_system::SessionListingSelectionSystem,
+    session_listings_display_system::SessionListingsDisplaySystem,
+};
+
+mod session_browse_request_system;
+mod session_browse_response_system;
+mod session_listing_selection_system;
+mod session_listings_display_system;
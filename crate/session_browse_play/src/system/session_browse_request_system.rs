@@ -0,0 +1,53 @@
+use amethyst::{
+    derive::SystemDesc,
+    ecs::{Read, System, World, Write},
+    shred::{ResourceId, SystemData},
+    shrev::{EventChannel, ReaderId},
+};
+use derivative::Derivative;
+use derive_new::new;
+use net_model::play::NetMessageEvent;
+use session_browse_model::SessionBrowseEvent;
+
+/// Sends requests to the session server for the list of public sessions.
+#[derive(Debug, SystemDesc, new)]
+#[system_desc(name(SessionBrowseRequestSystemDesc))]
+pub struct SessionBrowseRequestSystem {
+    /// Reader ID for the `SessionBrowseEvent` channel.
+    #[system_desc(event_channel_reader)]
+    session_browse_event_rid: ReaderId<SessionBrowseEvent>,
+}
+
+#[derive(Derivative, SystemData)]
+#[derivative(Debug)]
+pub struct SessionBrowseRequestSystemData<'s> {
+    /// `SessionBrowseEvent` channel.
+    #[derivative(Debug = "ignore")]
+    pub session_browse_ec: Read<'s, EventChannel<SessionBrowseEvent>>,
+    /// `NetworkMessageEvent` channel.
+    #[derivative(Debug = "ignore")]
+    pub net_message_ec: Write<'s, EventChannel<NetMessageEvent>>,
+}
+
+impl<'s> System<'s> for SessionBrowseRequestSystem {
+    type SystemData = SessionBrowseRequestSystemData<'s>;
+
+    fn run(
+        &mut self,
+        SessionBrowseRequestSystemData {
+            session_browse_ec,
+            mut net_message_ec,
+        }: Self::SystemData,
+    ) {
+        // Only send one request if multiple are received.
+        let session_list_requested = session_browse_ec
+            .read(&mut self.session_browse_event_rid)
+            .any(|ev| matches!(ev, SessionBrowseEvent::SessionListRequest));
+
+        if session_list_requested {
+            net_message_ec.single_write(NetMessageEvent::SessionBrowseEvent(
+                SessionBrowseEvent::SessionListRequest,
+            ));
+        }
+    }
+}
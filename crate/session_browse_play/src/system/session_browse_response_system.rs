@@ -0,0 +1,83 @@
+use amethyst::{
+    derive::SystemDesc,
+    ecs::{Read, System, World, Write},
+    shred::{ResourceId, SystemData},
+    shrev::{EventChannel, ReaderId},
+};
+use derivative::Derivative;
+use derive_new::new;
+use log::debug;
+use net_model::play::{NetData, NetEventChannel};
+use session_browse_model::{
+    play::{SessionListingIndex, SessionListings},
+    SessionBrowseEvent,
+};
+
+/// Records the public sessions in the world when the session server responds.
+#[derive(Debug, SystemDesc, new)]
+#[system_desc(name(SessionBrowseResponseSystemDesc))]
+pub struct SessionBrowseResponseSystem {
+    /// Reader ID for the `SessionBrowseEvent` channel.
+    #[system_desc(event_channel_reader)]
+    session_browse_event_rid: ReaderId<NetData<SessionBrowseEvent>>,
+}
+
+#[derive(Derivative, SystemData)]
+#[derivative(Debug)]
+pub struct SessionBrowseResponseSystemData<'s> {
+    /// `SessionBrowseEvent` channel.
+    #[derivative(Debug = "ignore")]
+    pub session_browse_nec: Read<'s, NetEventChannel<SessionBrowseEvent>>,
+    /// `SessionBrowseEvent` channel.
+    #[derivative(Debug = "ignore")]
+    pub session_browse_ec: Write<'s, EventChannel<SessionBrowseEvent>>,
+    /// `SessionListings` resource.
+    #[derivative(Debug = "ignore")]
+    pub session_listings: Write<'s, SessionListings>,
+    /// `SessionListingIndex` resource.
+    #[derivative(Debug = "ignore")]
+    pub session_listing_index: Write<'s, SessionListingIndex>,
+}
+
+impl<'s> System<'s> for SessionBrowseResponseSystem {
+    type SystemData = SessionBrowseResponseSystemData<'s>;
+
+    fn run(
+        &mut self,
+        SessionBrowseResponseSystemData {
+            session_browse_nec,
+            mut session_browse_ec,
+            mut session_listings,
+            mut session_listing_index,
+        }: Self::SystemData,
+    ) {
+        let session_list_response = session_browse_nec
+            .read(&mut self.session_browse_event_rid)
+            .filter_map(|net_data| {
+                if let SessionBrowseEvent::SessionListResponse(session_list_response) =
+                    &net_data.data
+                {
+                    Some(session_list_response)
+                } else {
+                    None
+                }
+            })
+            .last();
+
+        if let Some(session_list_response) = session_list_response {
+            debug!(
+                "Received session listings: {:?}",
+                session_list_response.session_listings
+            );
+
+            *session_listings = session_list_response.session_listings.clone();
+            if **session_listing_index >= session_listings.len() {
+                *session_listing_index = SessionListingIndex::default();
+            }
+
+            session_browse_ec.single_write(SessionBrowseEvent::SessionListResponse(
+                session_list_response.clone(),
+            ));
+        }
+    }
+}
@@ -0,0 +1,118 @@
+use amethyst::{
+    ecs::{Read, System, World, Write},
+    shred::{ResourceId, SystemData},
+    shrev::{EventChannel, ReaderId},
+};
+use derivative::Derivative;
+use derive_new::new;
+use game_input_model::{
+    config::{Axis, ControlAction, PlayerActionControl, PlayerAxisControl},
+    GameInputEvent,
+};
+use session_browse_model::{
+    play::{SessionListingIndex, SessionListings, SessionSelectParams},
+    SessionBrowseEvent,
+};
+
+/// Selects a public session from the session listings.
+///
+/// * Up and down move the selection.
+/// * `Attack` joins the selected session.
+/// * `Special` refreshes the session listings.
+/// * `Jump` returns to the previous menu.
+///
+/// `GameInputEvent`s are read instead of `ControlInputEvent`s, as the session browser does not
+/// have any input controlled entities.
+#[derive(Debug, Default, new)]
+pub struct SessionListingSelectionSystem {
+    /// Reader ID for the `GameInputEvent` event channel.
+    #[new(default)]
+    game_input_event_rid: Option<ReaderId<GameInputEvent>>,
+}
+
+/// `SessionListingSelectionSystemData`.
+#[derive(Derivative, SystemData)]
+#[derivative(Debug)]
+pub struct SessionListingSelectionSystemData<'s> {
+    /// `GameInputEvent` channel.
+    #[derivative(Debug = "ignore")]
+    pub game_input_ec: Read<'s, EventChannel<GameInputEvent>>,
+    /// `SessionListings` resource.
+    #[derivative(Debug = "ignore")]
+    pub session_listings: Read<'s, SessionListings>,
+    /// `SessionListingIndex` resource.
+    #[derivative(Debug = "ignore")]
+    pub session_listing_index: Write<'s, SessionListingIndex>,
+    /// `SessionBrowseEvent` channel.
+    #[derivative(Debug = "ignore")]
+    pub session_browse_ec: Write<'s, EventChannel<SessionBrowseEvent>>,
+}
+
+impl<'s> System<'s> for SessionListingSelectionSystem {
+    type SystemData = SessionListingSelectionSystemData<'s>;
+
+    fn run(
+        &mut self,
+        SessionListingSelectionSystemData {
+            game_input_ec,
+            session_listings,
+            mut session_listing_index,
+            mut session_browse_ec,
+        }: Self::SystemData,
+    ) {
+        let game_input_event_rid = self
+            .game_input_event_rid
+            .as_mut()
+            .expect("Expected `game_input_event_rid` field to be set.");
+
+        let session_listing_count = session_listings.len();
+        game_input_ec
+            .read(game_input_event_rid)
+            .for_each(|ev| match *ev {
+                GameInputEvent::AxisMoved {
+                    axis: PlayerAxisControl { axis: Axis::Z, .. },
+                    value,
+                } if session_listing_count > 0 => {
+                    // Positive Z is downwards.
+                    if value > 0. {
+                        **session_listing_index =
+                            (**session_listing_index + 1) % session_listing_count;
+                    } else if value < 0. {
+                        **session_listing_index = (**session_listing_index + session_listing_count
+                            - 1)
+                            % session_listing_count;
+                    }
+                }
+                GameInputEvent::ActionPressed(PlayerActionControl { action, .. }) => {
+                    let session_browse_event =
+                        match action {
+                            ControlAction::Attack => session_listings
+                                .get(**session_listing_index)
+                                .map(|session_listing| {
+                                    SessionBrowseEvent::SessionSelect(SessionSelectParams::new(
+                                        session_listing.session_code.clone(),
+                                    ))
+                                }),
+                            ControlAction::Special => Some(SessionBrowseEvent::SessionListRequest),
+                            ControlAction::Jump => Some(SessionBrowseEvent::Back),
+                            ControlAction::Defend => None,
+                        };
+
+                    if let Some(session_browse_event) = session_browse_event {
+                        session_browse_ec.single_write(session_browse_event);
+                    }
+                }
+                _ => {}
+            });
+    }
+
+    fn setup(&mut self, world: &mut World) {
+        Self::SystemData::setup(world);
+
+        self.game_input_event_rid = Some(
+            world
+                .fetch_mut::<EventChannel<GameInputEvent>>()
+                .register_reader(),
+        );
+    }
+}
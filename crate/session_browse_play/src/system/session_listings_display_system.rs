@@ -0,0 +1,154 @@
+use std::fmt::Write as _;
+
+use amethyst::{
+    ecs::{Entities, Join, Read, ReadExpect, System, World, WriteStorage},
+    shred::{ResourceId, SystemData},
+    ui::{Anchor, UiText, UiTransform},
+};
+use application_ui::{FontVariant, Theme};
+use derivative::Derivative;
+use derive_new::new;
+use session_browse_model::{
+    play::{SessionListingIndex, SessionListings},
+    SessionBrowseEntity,
+};
+
+const FONT_COLOUR: [f32; 4] = [0.8, 0.8, 0.8, 1.];
+const FONT_SIZE_WIDGET: f32 = 20.;
+const LABEL_WIDTH: f32 = 800.;
+const LABEL_HEIGHT: f32 = 400.;
+
+/// Displays the public sessions hosted on the session server.
+#[derive(Debug, Default, new)]
+pub struct SessionListingsDisplaySystem;
+
+/// `SessionListingsDisplaySystemData`.
+#[derive(Derivative, SystemData)]
+#[derivative(Debug)]
+pub struct SessionListingsDisplaySystemData<'s> {
+    /// `Entities`.
+    #[derivative(Debug = "ignore")]
+    pub entities: Entities<'s>,
+    /// `SessionListings` resource.
+    #[derivative(Debug = "ignore")]
+    pub session_listings: Read<'s, SessionListings>,
+    /// `SessionListingIndex` resource.
+    #[derivative(Debug = "ignore")]
+    pub session_listing_index: Read<'s, SessionListingIndex>,
+    /// `SessionBrowseEntity` components.
+    #[derivative(Debug = "ignore")]
+    pub session_browse_entities: WriteStorage<'s, SessionBrowseEntity>,
+
+    // Resources needed to display text.
+    /// `Theme` resource.
+    #[derivative(Debug = "ignore")]
+    pub theme: ReadExpect<'s, Theme>,
+    /// `UiTransform` components.
+    #[derivative(Debug = "ignore")]
+    pub ui_transforms: WriteStorage<'s, UiTransform>,
+    /// `UiText` components.
+    #[derivative(Debug = "ignore")]
+    pub ui_texts: WriteStorage<'s, UiText>,
+}
+
+impl SessionListingsDisplaySystem {
+    /// Returns the text listing the public sessions.
+    fn session_listings_text(
+        session_listings: &SessionListings,
+        session_listing_index: SessionListingIndex,
+    ) -> String {
+        let mut text = String::from(
+            "Public sessions (Attack: join, Special: refresh, Jump: back):\n\
+             \n  Code      Host                Devices  Status",
+        );
+
+        if session_listings.is_empty() {
+            text.push_str("\n  No public sessions found.");
+        } else {
+            session_listings
+                .iter()
+                .enumerate()
+                .for_each(|(index, session_listing)| {
+                    let marker = if index == *session_listing_index {
+                        '>'
+                    } else {
+                        ' '
+                    };
+                    let _ = write!(
+                        text,
+                        "\n{} {:<9} {:<19} {:>7}  {}",
+                        marker,
+                        session_listing.session_code.0,
+                        session_listing.session_host_name.0,
+                        session_listing.session_device_count,
+                        session_listing.session_listing_status
+                    );
+                });
+        }
+
+        text
+    }
+}
+
+impl<'s> System<'s> for SessionListingsDisplaySystem {
+    type SystemData = SessionListingsDisplaySystemData<'s>;
+
+    fn run(
+        &mut self,
+        SessionListingsDisplaySystemData {
+            entities,
+            session_listings,
+            session_listing_index,
+            mut session_browse_entities,
+            theme,
+            mut ui_transforms,
+            mut ui_texts,
+        }: Self::SystemData,
+    ) {
+        let text = Self::session_listings_text(&session_listings, *session_listing_index);
+
+        let ui_text_existing = (&session_browse_entities, &mut ui_texts)
+            .join()
+            .next()
+            .map(|(_, ui_text)| ui_text);
+
+        if let Some(ui_text) = ui_text_existing {
+            if ui_text.text != text {
+                ui_text.text = text;
+            }
+        } else {
+            let font = theme
+                .fonts
+                .get(&FontVariant::Regular)
+                .expect("Failed to get regular font handle.");
+
+            let x = -LABEL_WIDTH / 2.;
+            let y = -LABEL_HEIGHT / 2.;
+            let z = 1.;
+
+            let ui_transform = UiTransform::new(
+                String::from("session_listings_text"),
+                Anchor::TopMiddle,
+                Anchor::MiddleLeft,
+                x,
+                y,
+                z,
+                LABEL_WIDTH,
+                LABEL_HEIGHT,
+            );
+
+            let ui_text = UiText::new(font.clone(), text, FONT_COLOUR, FONT_SIZE_WIDGET);
+
+            entities
+                .build_entity()
+                .with(SessionBrowseEntity, &mut session_browse_entities)
+                .with(ui_transform, &mut ui_transforms)
+                .with(ui_text, &mut ui_texts)
+                .build();
+        }
+    }
+
+    fn setup(&mut self, world: &mut World) {
+        Self::SystemData::setup(world);
+    }
+}
@@ -0,0 +1,13 @@
+#![deny(missing_debug_implementations, missing_docs)] // kcov-ignore
+
+//! Extension to enable `session_browse` to be controlled by stdio.
+
+pub use crate::{
+    session_browse_event_stdin_mapper::SessionBrowseEventStdinMapper,
+    session_browse_stdio_bundle::SessionBrowseStdioBundle,
+    session_listings_log_system::{SessionListingsLogSystem, SessionListingsLogSystemDesc},
+};
+
+mod session_browse_event_stdin_mapper;
+mod session_browse_stdio_bundle;
+mod session_listings_log_system;
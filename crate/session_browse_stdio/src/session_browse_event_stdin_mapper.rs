@@ -0,0 +1,17 @@
+use amethyst::Error;
+use session_browse_model::SessionBrowseEvent;
+use stdio_spi::StdinMapper;
+
+/// Builds a `SessionBrowseEvent` from stdin tokens.
+#[derive(Debug)]
+pub struct SessionBrowseEventStdinMapper;
+
+impl StdinMapper for SessionBrowseEventStdinMapper {
+    type SystemData = ();
+    type Event = SessionBrowseEvent;
+    type Args = SessionBrowseEvent;
+
+    fn map(_: &(), args: Self::Args) -> Result<Self::Event, Error> {
+        Ok(args)
+    }
+}
@@ -0,0 +1,39 @@
+use std::any;
+
+use amethyst::{
+    core::{bundle::SystemBundle, SystemDesc},
+    ecs::{DispatcherBuilder, World},
+    Error,
+};
+use application_event::AppEventVariant;
+use derive_new::new;
+use stdio_spi::MapperSystem;
+
+use crate::{
+    SessionBrowseEventStdinMapper, SessionListingsLogSystem, SessionListingsLogSystemDesc,
+};
+
+/// Adds a `MapperSystem<SessionBrowseEventStdinMapper>` and `SessionListingsLogSystem` to the
+/// `World`.
+#[derive(Debug, new)]
+pub struct SessionBrowseStdioBundle;
+
+impl<'a, 'b> SystemBundle<'a, 'b> for SessionBrowseStdioBundle {
+    fn build(
+        self,
+        world: &mut World,
+        builder: &mut DispatcherBuilder<'a, 'b>,
+    ) -> Result<(), Error> {
+        builder.add(
+            MapperSystem::<SessionBrowseEventStdinMapper>::new(AppEventVariant::SessionBrowse),
+            any::type_name::<MapperSystem<SessionBrowseEventStdinMapper>>(),
+            &[],
+        ); // kcov-ignore
+        builder.add(
+            SessionListingsLogSystemDesc::default().build(world),
+            any::type_name::<SessionListingsLogSystem>(),
+            &[],
+        ); // kcov-ignore
+        Ok(())
+    }
+}
@@ -0,0 +1,69 @@
+use std::iter;
+
+use amethyst::{
+    derive::SystemDesc,
+    ecs::{Read, System, World},
+    shred::{ResourceId, SystemData},
+    shrev::{EventChannel, ReaderId},
+};
+use derivative::Derivative;
+use derive_new::new;
+use log::info;
+use session_browse_model::{play::SessionListings, SessionBrowseEvent};
+
+/// Logs the public sessions when the session server responds.
+///
+/// This lets a stdio user find the session code to select.
+#[derive(Debug, SystemDesc, new)]
+#[system_desc(name(SessionListingsLogSystemDesc))]
+pub struct SessionListingsLogSystem {
+    /// Reader ID for the `SessionBrowseEvent` channel.
+    #[system_desc(event_channel_reader)]
+    session_browse_event_rid: ReaderId<SessionBrowseEvent>,
+}
+
+/// `SessionListingsLogSystemData`.
+#[derive(Derivative, SystemData)]
+#[derivative(Debug)]
+pub struct SessionListingsLogSystemData<'s> {
+    /// `SessionBrowseEvent` channel.
+    #[derivative(Debug = "ignore")]
+    pub session_browse_ec: Read<'s, EventChannel<SessionBrowseEvent>>,
+}
+
+impl SessionListingsLogSystem {
+    /// Returns the lines to display for the public sessions.
+    pub fn listing_lines(session_listings: &SessionListings) -> Vec<String> {
+        if session_listings.is_empty() {
+            vec![String::from("No public sessions found.")]
+        } else {
+            iter::once(String::from("Public sessions:"))
+                .chain(session_listings.iter().map(|session_listing| {
+                    format!(
+                        "* `{}` hosted by `{}`, {} device(s), {}",
+                        session_listing.session_code,
+                        session_listing.session_host_name,
+                        session_listing.session_device_count,
+                        session_listing.session_listing_status
+                    )
+                }))
+                .collect::<Vec<String>>()
+        }
+    }
+}
+
+impl<'s> System<'s> for SessionListingsLogSystem {
+    type SystemData = SessionListingsLogSystemData<'s>;
+
+    fn run(&mut self, SessionListingsLogSystemData { session_browse_ec }: Self::SystemData) {
+        session_browse_ec
+            .read(&mut self.session_browse_event_rid)
+            .for_each(|ev| {
+                if let SessionBrowseEvent::SessionListResponse(session_list_response) = ev {
+                    Self::listing_lines(&session_list_response.session_listings)
+                        .iter()
+                        .for_each(|listing_line| info!("{}", listing_line));
+                }
+            });
+    }
+}
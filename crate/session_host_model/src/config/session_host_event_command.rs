@@ -9,6 +9,8 @@ use serde::{Deserialize, Serialize};
 pub enum SessionHostEventCommand {
     /// Player requested to host a session.
     SessionHostRequest,
+    /// Player requested to host a session that is listed in the session browser.
+    SessionHostPublicRequest,
     /// Player cancelled the request to host.
     HostCancel,
     /// Return to the previous menu.
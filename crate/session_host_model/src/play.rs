@@ -1,11 +1,12 @@
 //! Data types used at runtime.
 
 pub use self::{
-    session_accept_response::SessionAcceptResponse,
+    session_accept_response::SessionAcceptResponse, session_host_error::SessionHostError,
     session_host_request_params::SessionHostRequestParams,
     session_reject_response::SessionRejectResponse,
 };
 
 mod session_accept_response;
+mod session_host_error;
 mod session_host_request_params;
 mod session_reject_response;
@@ -1,6 +1,6 @@
 use derive_new::new;
 use game_input_model::loaded::PlayerControllers;
-use network_session_model::play::{Session, SessionDeviceId};
+use network_session_model::play::{Session, SessionDeviceId, SessionReconnectToken};
 use serde::{Deserialize, Serialize};
 use structopt_derive::StructOpt;
 
@@ -21,4 +21,7 @@ pub struct SessionAcceptResponse {
     /// All player controllers.
     #[structopt(long)]
     pub player_controllers: PlayerControllers,
+    /// Token to rejoin the session with if the connection drops.
+    #[structopt(skip)]
+    pub session_reconnect_token: SessionReconnectToken,
 }
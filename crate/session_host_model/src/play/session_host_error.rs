@@ -0,0 +1,25 @@
+use std::fmt;
+
+use serde::{Deserialize, Serialize};
+use strum_macros::EnumString;
+
+/// Error when attempting to host a session.
+#[derive(Clone, Copy, Debug, Deserialize, EnumString, PartialEq, Serialize)]
+#[strum(serialize_all = "snake_case")]
+pub enum SessionHostError {
+    /// The server has reached its limit of hosted sessions.
+    SessionCountLimitReached,
+    /// The client's protocol version is not compatible with the server's.
+    ProtocolVersionMismatch,
+}
+
+impl fmt::Display for SessionHostError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::SessionCountLimitReached => write!(f, "Server is hosting too many sessions."),
+            Self::ProtocolVersionMismatch => {
+                write!(f, "Game version is not compatible with the server.")
+            }
+        }
+    }
+}
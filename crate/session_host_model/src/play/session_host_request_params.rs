@@ -13,4 +13,8 @@ pub struct SessionHostRequestParams {
     /// Player controllers from this session device.
     #[structopt(long)]
     pub player_controllers: PlayerControllers,
+    /// Whether the session is listed in the session browser.
+    #[structopt(long)]
+    #[serde(default)]
+    pub session_public: bool,
 }
@@ -2,8 +2,12 @@ use derive_new::new;
 use serde::{Deserialize, Serialize};
 use structopt_derive::StructOpt;
 
+use crate::play::SessionHostError;
+
 /// Response when a session host request is rejected.
-///
-/// We should also include a reason.
 #[derive(Clone, Debug, Deserialize, PartialEq, Serialize, StructOpt, new)]
-pub struct SessionRejectResponse {}
+pub struct SessionRejectResponse {
+    /// Session host rejection reason.
+    #[structopt(long)]
+    pub session_host_error: SessionHostError,
+}
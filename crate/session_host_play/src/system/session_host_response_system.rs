@@ -10,7 +10,7 @@ use game_input_model::loaded::PlayerControllers;
 use log::debug;
 use net_model::play::{NetData, NetEventChannel};
 use network_session_model::play::{
-    Session, SessionCode, SessionDeviceId, SessionDevices, SessionStatus,
+    Session, SessionCode, SessionDeviceId, SessionDevices, SessionReconnectToken, SessionStatus,
 };
 use session_host_model::{play::SessionAcceptResponse, SessionHostEvent};
 
@@ -47,6 +47,9 @@ pub struct SessionHostResponseSystemData<'s> {
     /// `PlayerControllers` resource.
     #[derivative(Debug = "ignore")]
     pub player_controllers: Write<'s, PlayerControllers>,
+    /// `SessionReconnectToken` resource.
+    #[derivative(Debug = "ignore")]
+    pub session_reconnect_token: Write<'s, SessionReconnectToken>,
 }
 
 impl<'s> System<'s> for SessionHostResponseSystem {
@@ -62,6 +65,7 @@ impl<'s> System<'s> for SessionHostResponseSystem {
             mut session_devices,
             mut session_status,
             mut player_controllers,
+            mut session_reconnect_token,
         }: Self::SystemData,
     ) {
         let session_host_events = session_host_nec.read(&mut self.session_host_event_rid);
@@ -85,6 +89,7 @@ impl<'s> System<'s> for SessionHostResponseSystem {
                                     },
                                 session_device_id: session_device_id_received,
                                 player_controllers: player_controllers_received,
+                                session_reconnect_token: session_reconnect_token_received,
                             } = session_accept_response.clone();
 
                             // Write to resources.
@@ -93,6 +98,7 @@ impl<'s> System<'s> for SessionHostResponseSystem {
                             *session_devices = session_devices_received;
                             session_status_new = Some(SessionStatus::HostEstablished);
                             *player_controllers = player_controllers_received;
+                            *session_reconnect_token = session_reconnect_token_received;
 
                             session_host_ec.single_write(SessionHostEvent::SessionAccept(
                                 session_accept_response.clone(),
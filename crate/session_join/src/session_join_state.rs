@@ -55,7 +55,7 @@ impl State<GameData<'static, 'static>, AppEvent> for SessionJoinStateDelegate {
         if let AppEvent::SessionJoin(session_join_event) = event {
             debug!("Received session_join_event: {:?}", session_join_event);
             match session_join_event {
-                SessionJoinEvent::SessionAccept(_) => {
+                SessionJoinEvent::SessionAccept(_) | SessionJoinEvent::SessionSpectateAccept(_) => {
                     let session_lobby_state =
                         SessionLobbyStateBuilder::new(SessionLobbyStateDelegate::new()).build();
                     Trans::Push(Box::new(session_lobby_state))
@@ -11,6 +11,10 @@ pub enum SessionJoinEventCommand {
     ///
     /// The `SessionJoinRequestParams` is specially looked up by code.
     SessionJoinRequest,
+    /// Player entered a session code to spectate.
+    ///
+    /// The `SessionSpectateRequestParams` is specially looked up by code.
+    SessionSpectateRequest,
     /// Player cancelled the request to join.
     JoinCancel,
     /// Return to the previous menu.
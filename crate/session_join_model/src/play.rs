@@ -1,12 +1,23 @@
 //! Data types used at runtime.
 
 pub use self::{
-    session_accept_response::SessionAcceptResponse, session_join_error::SessionJoinError,
-    session_join_request_params::SessionJoinRequestParams,
+    session_accept_response::SessionAcceptResponse, session_code_prefill::SessionCodePrefill,
+    session_join_error::SessionJoinError, session_join_request_params::SessionJoinRequestParams,
+    session_join_status_entity::SessionJoinStatusEntity,
+    session_reconnect_accept_response::SessionReconnectAcceptResponse,
+    session_reconnect_request_params::SessionReconnectRequestParams,
     session_reject_response::SessionRejectResponse,
+    session_spectate_accept_response::SessionSpectateAcceptResponse,
+    session_spectate_request_params::SessionSpectateRequestParams,
 };
 
 mod session_accept_response;
+mod session_code_prefill;
 mod session_join_error;
 mod session_join_request_params;
+mod session_join_status_entity;
+mod session_reconnect_accept_response;
+mod session_reconnect_request_params;
 mod session_reject_response;
+mod session_spectate_accept_response;
+mod session_spectate_request_params;
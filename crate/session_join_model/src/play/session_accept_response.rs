@@ -1,6 +1,6 @@
 use derive_new::new;
 use game_input_model::{loaded::PlayerControllers, play::ControllerIdOffset};
-use network_session_model::play::{Session, SessionDeviceId};
+use network_session_model::play::{Session, SessionDeviceId, SessionReconnectToken};
 use serde::{Deserialize, Serialize};
 use structopt_derive::StructOpt;
 
@@ -24,4 +24,7 @@ pub struct SessionAcceptResponse {
     /// Offset to use for local `ControllerId`s.
     #[structopt(long)]
     pub controller_id_offset: ControllerIdOffset,
+    /// Token to rejoin the session with if the connection drops.
+    #[structopt(skip)]
+    pub session_reconnect_token: SessionReconnectToken,
 }
@@ -0,0 +1,10 @@
+use derive_deref::{Deref, DerefMut};
+use derive_new::new;
+use network_session_model::play::SessionCode;
+
+/// Session code to fill in the session join form with (`Option<SessionCode>` newtype).
+///
+/// This is set when a session is selected from the session browser, and cleared once the form is
+/// filled in.
+#[derive(Clone, Debug, Default, Deref, DerefMut, PartialEq, new)]
+pub struct SessionCodePrefill(pub Option<SessionCode>);
@@ -1,3 +1,5 @@
+use std::fmt;
+
 use serde::{Deserialize, Serialize};
 use strum_macros::EnumString;
 
@@ -7,4 +9,36 @@ use strum_macros::EnumString;
 pub enum SessionJoinError {
     /// The session code does not exist on the server.
     SessionCodeNotFound,
+    /// The session has reached its device or controller limit.
+    SessionFull,
+    /// The session has already started, so players may no longer join.
+    AlreadyInGame,
+    /// The client's protocol version is not compatible with the server's.
+    ProtocolVersionMismatch,
+    /// Another device in the session has the same name.
+    DuplicateDeviceName,
+    /// The reconnect token does not match a disconnected device, or its grace period has expired.
+    ReconnectTokenInvalid,
+    /// Input that the device needs to catch up with the session has been discarded.
+    SessionInputHistoryDiscarded,
+}
+
+impl fmt::Display for SessionJoinError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::SessionCodeNotFound => write!(f, "Session code not found."),
+            Self::SessionFull => write!(f, "Session is full."),
+            Self::AlreadyInGame => write!(f, "Session has already started."),
+            Self::ProtocolVersionMismatch => {
+                write!(f, "Game version is not compatible with the server.")
+            }
+            Self::DuplicateDeviceName => write!(f, "Device name is already in use."),
+            Self::ReconnectTokenInvalid => {
+                write!(f, "Unable to rejoin session, it may have timed out.")
+            }
+            Self::SessionInputHistoryDiscarded => {
+                write!(f, "Session has been running for too long to catch up with.")
+            }
+        }
+    }
 }
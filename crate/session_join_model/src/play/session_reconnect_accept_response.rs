@@ -0,0 +1,31 @@
+use derive_new::new;
+use game_input_model::{loaded::PlayerControllers, play::ControllerIdOffset};
+use network_session_model::play::{Session, SessionDeviceId};
+use rollback_model::play::SessionInputHistory;
+use serde::{Deserialize, Serialize};
+use structopt_derive::StructOpt;
+
+/// Response when a session reconnect request is accepted.
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize, StructOpt, new)]
+pub struct SessionReconnectAcceptResponse {
+    // Structopt actually disallows us to have docs on this. `._.`
+    //
+    // Session information.
+    //
+    // This includes the reconnecting device.
+    #[allow(missing_docs)]
+    #[structopt(flatten)]
+    pub session: Session,
+    /// ID that the server generated for the reconnecting device.
+    #[structopt(long)]
+    pub session_device_id: SessionDeviceId,
+    /// All player controllers.
+    #[structopt(long)]
+    pub player_controllers: PlayerControllers,
+    /// Offset to use for local `ControllerId`s.
+    #[structopt(long)]
+    pub controller_id_offset: ControllerIdOffset,
+    /// Input sent in the session that the device has not applied.
+    #[structopt(skip)]
+    pub session_input_history: SessionInputHistory,
+}
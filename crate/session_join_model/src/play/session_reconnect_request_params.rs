@@ -0,0 +1,23 @@
+use derive_new::new;
+use network_session_model::play::{
+    SessionCode, SessionDeviceId, SessionReconnectToken, SessionTickCount,
+};
+use serde::{Deserialize, Serialize};
+use structopt_derive::StructOpt;
+
+/// Parameters required to rejoin a session after disconnecting.
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize, StructOpt, new)]
+pub struct SessionReconnectRequestParams {
+    /// Code of the session to rejoin.
+    #[structopt(long)]
+    pub session_code: SessionCode,
+    /// ID that the server generated for this device when it joined.
+    #[structopt(long)]
+    pub session_device_id: SessionDeviceId,
+    /// Token that the server generated for this device when it joined.
+    #[structopt(long)]
+    pub session_reconnect_token: SessionReconnectToken,
+    /// Number of `GameInputTick`s this device applied before disconnecting.
+    #[structopt(long)]
+    pub session_tick_count: SessionTickCount,
+}
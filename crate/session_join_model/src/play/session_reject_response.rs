@@ -6,8 +6,6 @@ use structopt_derive::StructOpt;
 use crate::play::SessionJoinError;
 
 /// Response when a session join request is rejected.
-///
-/// We should also include a reason.
 #[derive(Clone, Debug, Deserialize, PartialEq, Serialize, StructOpt, new)]
 pub struct SessionRejectResponse {
     /// Code of the session.
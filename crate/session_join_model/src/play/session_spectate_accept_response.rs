@@ -0,0 +1,26 @@
+use derive_new::new;
+use game_input_model::loaded::PlayerControllers;
+use network_session_model::play::{Session, SessionDeviceId};
+use rollback_model::play::SessionInputHistory;
+use serde::{Deserialize, Serialize};
+use structopt_derive::StructOpt;
+
+/// Response when a session spectate request is accepted.
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize, StructOpt, new)]
+pub struct SessionSpectateAcceptResponse {
+    // Session information.
+    //
+    // This includes the spectator's device, which has no player controllers.
+    #[allow(missing_docs)]
+    #[structopt(flatten)]
+    pub session: Session,
+    /// ID that the server generated for the spectator.
+    #[structopt(long)]
+    pub session_device_id: SessionDeviceId,
+    /// All player controllers.
+    #[structopt(long)]
+    pub player_controllers: PlayerControllers,
+    /// Input sent in the session since it started, if it has started.
+    #[structopt(skip)]
+    pub session_input_history: Option<SessionInputHistory>,
+}
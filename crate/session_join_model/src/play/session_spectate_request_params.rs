@@ -0,0 +1,15 @@
+use derive_new::new;
+use network_session_model::play::{SessionCode, SessionDeviceName};
+use serde::{Deserialize, Serialize};
+use structopt_derive::StructOpt;
+
+/// Parameters required to spectate a session.
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize, StructOpt, new)]
+pub struct SessionSpectateRequestParams {
+    /// Code of the session to spectate.
+    #[structopt(long)]
+    pub session_code: SessionCode,
+    /// Name of the spectator's computer.
+    #[structopt(long = "device-name")]
+    pub session_device_name: SessionDeviceName,
+}
@@ -1,7 +1,11 @@
 use serde::{Deserialize, Serialize};
 use structopt_derive::StructOpt;
 
-use crate::play::{SessionAcceptResponse, SessionJoinRequestParams, SessionRejectResponse};
+use crate::play::{
+    SessionAcceptResponse, SessionJoinRequestParams, SessionReconnectAcceptResponse,
+    SessionReconnectRequestParams, SessionRejectResponse, SessionSpectateAcceptResponse,
+    SessionSpectateRequestParams,
+};
 
 /// Session join state events.
 ///
@@ -10,22 +14,33 @@ use crate::play::{SessionAcceptResponse, SessionJoinRequestParams, SessionReject
 /// When read in as a command, the command string should look like the following:
 ///
 /// * `session_join session_join_request --session-code abcd --device-name azriel --player-controllers "0:azriel 1:friend_a`
+/// * `session_join session_spectate_request --session-code abcd --device-name byron`
+/// * `session_join session_reconnect_request --session-code abcd --session-device-id 2 --session-reconnect-token 123 --session-tick-count 456`
 /// * `session_join join_cancel`
 /// * `session_join session_accept --session-code abcd --session-devices "0:azriel::0:azriel::1:friend_a 1:byron::0:friend_b 2:carlo::0:friend_c" --session-device-id 2`
 /// * `session_join back`
 ///
-/// **Note:** The `session_accept` subcommand is designed to be received from the server, so sending
-/// this as a local command may cause undefined behaviour.
+/// **Note:** The `session_accept`, `session_spectate_accept`, and `session_reconnect_accept`
+/// subcommands are designed to be received from the server, so sending these as local commands
+/// may cause undefined behaviour.
 #[derive(Clone, Debug, Deserialize, PartialEq, Serialize, StructOpt)]
 #[serde(deny_unknown_fields, rename_all = "snake_case")]
 #[structopt(rename_all = "snake_case")]
 pub enum SessionJoinEvent {
     /// Player entered a session code.
     SessionJoinRequest(SessionJoinRequestParams),
+    /// Player entered a session code to spectate.
+    SessionSpectateRequest(SessionSpectateRequestParams),
+    /// Device requested to rejoin a session after disconnecting.
+    SessionReconnectRequest(SessionReconnectRequestParams),
     /// Player cancelled the request to join.
     JoinCancel,
     /// Server accepted the client's request.
     SessionAccept(SessionAcceptResponse),
+    /// Server accepted the client's request to spectate.
+    SessionSpectateAccept(SessionSpectateAcceptResponse),
+    /// Server accepted the device's request to rejoin a session.
+    SessionReconnectAccept(SessionReconnectAcceptResponse),
     /// Server rejected the client's request.
     SessionReject(SessionRejectResponse),
     /// Return to the previous menu.
@@ -3,8 +3,9 @@
 //! Provides logic for the network play join process.
 
 pub use crate::system::{
-    SessionJoinRequestSystem, SessionJoinRequestSystemDesc, SessionJoinResponseSystem,
-    SessionJoinResponseSystemDesc,
+    SessionCodePrefillSystem, SessionJoinRequestSystem, SessionJoinRequestSystemDesc,
+    SessionJoinResponseSystem, SessionJoinResponseSystemDesc, SessionJoinStatusDisplaySystem,
+    SessionReconnectSystem, SessionReconnectSystemDesc,
 };
 
 mod system;
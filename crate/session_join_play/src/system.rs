@@ -1,7 +1,13 @@
 pub use self::{
+    session_code_prefill_system::SessionCodePrefillSystem,
     session_join_request_system::{SessionJoinRequestSystem, SessionJoinRequestSystemDesc},
     session_join_response_system::{SessionJoinResponseSystem, SessionJoinResponseSystemDesc},
+    session_join_status_display_system::SessionJoinStatusDisplaySystem,
+    session_reconnect_system::{SessionReconnectSystem, SessionReconnectSystemDesc},
 };
 
+mod session_code_prefill_system;
 mod session_join_request_system;
 mod session_join_response_system;
+mod session_join_status_display_system;
+mod session_reconnect_system;
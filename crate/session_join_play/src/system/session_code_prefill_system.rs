@@ -0,0 +1,64 @@
+use amethyst::{
+    ecs::{Read, System, World, Write, WriteStorage},
+    shred::{ResourceId, SystemData},
+    ui::UiText,
+};
+use derivative::Derivative;
+use derive_new::new;
+use session_join_model::play::SessionCodePrefill;
+use ui_form_model::play::UiFormInputEntities;
+
+/// Index of the session code input field in the session join form.
+const SESSION_CODE_INPUT_INDEX: usize = 1;
+
+/// Fills in the session code input field with the session selected in the session browser.
+#[derive(Debug, Default, new)]
+pub struct SessionCodePrefillSystem;
+
+/// `SessionCodePrefillSystemData`.
+#[derive(Derivative, SystemData)]
+#[derivative(Debug)]
+pub struct SessionCodePrefillSystemData<'s> {
+    /// `SessionCodePrefill` resource.
+    #[derivative(Debug = "ignore")]
+    pub session_code_prefill: Write<'s, SessionCodePrefill>,
+    /// `UiFormInputEntities` resource.
+    #[derivative(Debug = "ignore")]
+    pub ui_form_input_entities: Read<'s, UiFormInputEntities>,
+    /// `UiText` components.
+    #[derivative(Debug = "ignore")]
+    pub ui_texts: WriteStorage<'s, UiText>,
+}
+
+impl<'s> System<'s> for SessionCodePrefillSystem {
+    type SystemData = SessionCodePrefillSystemData<'s>;
+
+    fn run(
+        &mut self,
+        SessionCodePrefillSystemData {
+            mut session_code_prefill,
+            ui_form_input_entities,
+            mut ui_texts,
+        }: Self::SystemData,
+    ) {
+        if session_code_prefill.is_none() {
+            return;
+        }
+
+        // The form's input fields may not have been created yet, in which case we try again on the
+        // next frame.
+        let ui_text = ui_form_input_entities
+            .get(SESSION_CODE_INPUT_INDEX)
+            .and_then(|entity| ui_texts.get_mut(*entity));
+
+        if let Some(ui_text) = ui_text {
+            if let Some(session_code) = session_code_prefill.take() {
+                ui_text.text = session_code.0;
+            }
+        }
+    }
+
+    fn setup(&mut self, world: &mut World) {
+        Self::SystemData::setup(world);
+    }
+}
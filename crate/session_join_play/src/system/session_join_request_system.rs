@@ -52,22 +52,36 @@ impl<'s> System<'s> for SessionJoinRequestSystem {
         }
 
         // Only process one session join event if multiple are received.
-        let session_join_request_params = session_join_events.find_map(|ev| {
-            if let SessionJoinEvent::SessionJoinRequest(session_join_request_params) = ev {
-                Some(session_join_request_params)
-            } else {
-                None
-            }
+        let session_join_event = session_join_events.find(|ev| {
+            matches!(
+                ev,
+                SessionJoinEvent::SessionJoinRequest(_)
+                    | SessionJoinEvent::SessionSpectateRequest(_)
+            )
         });
 
-        if let Some(session_join_request_params) = session_join_request_params {
-            net_message_ec.single_write(NetMessageEvent::SessionJoinEvent(
-                SessionJoinEvent::SessionJoinRequest(session_join_request_params.clone()),
-            ));
+        match session_join_event {
+            Some(SessionJoinEvent::SessionJoinRequest(session_join_request_params)) => {
+                net_message_ec.single_write(NetMessageEvent::SessionJoinEvent(
+                    SessionJoinEvent::SessionJoinRequest(session_join_request_params.clone()),
+                ));
+
+                *session_status = SessionStatus::JoinRequested {
+                    session_code: session_join_request_params.session_code.clone(),
+                };
+            }
+            Some(SessionJoinEvent::SessionSpectateRequest(session_spectate_request_params)) => {
+                net_message_ec.single_write(NetMessageEvent::SessionJoinEvent(
+                    SessionJoinEvent::SessionSpectateRequest(
+                        session_spectate_request_params.clone(),
+                    ),
+                ));
 
-            *session_status = SessionStatus::JoinRequested {
-                session_code: session_join_request_params.session_code.clone(),
-            };
+                *session_status = SessionStatus::JoinRequested {
+                    session_code: session_spectate_request_params.session_code.clone(),
+                };
+            }
+            _ => {}
         }
     }
 }
@@ -7,14 +7,23 @@ use amethyst::{
 use derivative::Derivative;
 use derive_new::new;
 use game_input_model::{loaded::PlayerControllers, play::ControllerIdOffset};
-use log::debug;
+use log::{debug, warn};
 use net_model::play::{NetData, NetEventChannel};
 use network_session_model::play::{
-    Session, SessionCode, SessionDeviceId, SessionDevices, SessionStatus,
+    Session, SessionCode, SessionDeviceId, SessionDevices, SessionReconnectToken, SessionStatus,
+    SessionTickCount,
 };
-use session_join_model::{play::SessionAcceptResponse, SessionJoinEvent};
+use rollback_model::play::SessionInputHistory;
+use session_join_model::{
+    play::{SessionAcceptResponse, SessionSpectateAcceptResponse},
+    SessionJoinEvent,
+};
+use session_lobby_model::SessionLobbyEvent;
 
 /// Records the session code and devices in the world when accepted into a session.
+///
+/// When accepted as a spectator of a session that has already started, the session's input
+/// history is recorded so that it can be caught up, and the session start is notified.
 #[derive(Debug, SystemDesc, new)]
 #[system_desc(name(SessionJoinResponseSystemDesc))]
 pub struct SessionJoinResponseSystem {
@@ -50,6 +59,18 @@ pub struct SessionJoinResponseSystemData<'s> {
     /// `ControllerIdOffset` resource.
     #[derivative(Debug = "ignore")]
     pub controller_id_offset: Write<'s, ControllerIdOffset>,
+    /// `SessionInputHistory` resource.
+    #[derivative(Debug = "ignore")]
+    pub session_input_history: Write<'s, SessionInputHistory>,
+    /// `SessionLobbyEvent` channel.
+    #[derivative(Debug = "ignore")]
+    pub session_lobby_ec: Write<'s, EventChannel<SessionLobbyEvent>>,
+    /// `SessionReconnectToken` resource.
+    #[derivative(Debug = "ignore")]
+    pub session_reconnect_token: Write<'s, SessionReconnectToken>,
+    /// `SessionTickCount` resource.
+    #[derivative(Debug = "ignore")]
+    pub session_tick_count: Write<'s, SessionTickCount>,
 }
 
 impl<'s> System<'s> for SessionJoinResponseSystem {
@@ -66,6 +87,10 @@ impl<'s> System<'s> for SessionJoinResponseSystem {
             mut session_status,
             mut player_controllers,
             mut controller_id_offset,
+            mut session_input_history,
+            mut session_lobby_ec,
+            mut session_reconnect_token,
+            mut session_tick_count,
         }: Self::SystemData,
     ) {
         let session_join_events = session_join_nec.read(&mut self.session_join_event_rid);
@@ -95,6 +120,7 @@ impl<'s> System<'s> for SessionJoinResponseSystem {
                                 session_device_id: session_device_id_received,
                                 player_controllers: player_controllers_received,
                                 controller_id_offset: controller_id_offset_received,
+                                session_reconnect_token: session_reconnect_token_received,
                             } = session_accept_response.clone();
 
                             // Write to resources.
@@ -104,16 +130,68 @@ impl<'s> System<'s> for SessionJoinResponseSystem {
                             session_status_new = Some(SessionStatus::JoinEstablished);
                             *player_controllers = player_controllers_received;
                             *controller_id_offset = controller_id_offset_received;
+                            *session_reconnect_token = session_reconnect_token_received;
 
                             session_join_ec.single_write(SessionJoinEvent::SessionAccept(
                                 session_accept_response.clone(),
                             ));
                         }
+                        NetData {
+                            data:
+                                SessionJoinEvent::SessionSpectateAccept(
+                                    session_spectate_accept_response,
+                                ),
+                            ..
+                        } if &session_spectate_accept_response.session.session_code
+                            == session_code_requested =>
+                        {
+                            debug!(
+                                "Session spectate accepted: {:?}",
+                                session_spectate_accept_response
+                            );
+
+                            let SessionSpectateAcceptResponse {
+                                session:
+                                    Session {
+                                        session_code: session_code_received,
+                                        session_devices: session_devices_received,
+                                    },
+                                session_device_id: session_device_id_received,
+                                player_controllers: player_controllers_received,
+                                session_input_history: session_input_history_received,
+                            } = session_spectate_accept_response.clone();
+
+                            // Write to resources.
+                            *session_code = session_code_received;
+                            *session_device_id = session_device_id_received;
+                            *session_devices = session_devices_received;
+                            session_status_new = Some(SessionStatus::JoinEstablished);
+                            *player_controllers = player_controllers_received;
+                            *controller_id_offset = ControllerIdOffset::default();
+
+                            session_join_ec.single_write(SessionJoinEvent::SessionSpectateAccept(
+                                session_spectate_accept_response.clone(),
+                            ));
+
+                            // Go straight to the started session, and catch up with its input.
+                            if let Some(session_input_history_received) =
+                                session_input_history_received
+                            {
+                                *session_input_history = session_input_history_received;
+                                *session_tick_count = SessionTickCount::default();
+                                session_lobby_ec
+                                    .single_write(SessionLobbyEvent::SessionStartNotify);
+                            }
+                        }
                         NetData {
                             data: SessionJoinEvent::SessionReject(session_reject_response),
                             ..
                         } if &session_reject_response.session_code == session_code_requested => {
-                            debug!("Session rejected: {:?}", session_reject_response);
+                            warn!(
+                                "Failed to join session `{}`: {}",
+                                session_reject_response.session_code,
+                                session_reject_response.session_join_error
+                            );
 
                             session_status_new = Some(SessionStatus::None);
 
@@ -0,0 +1,157 @@
+use amethyst::{
+    ecs::{Entities, Join, Read, ReadExpect, System, World, WriteStorage},
+    shred::{ResourceId, SystemData},
+    shrev::{EventChannel, ReaderId},
+    ui::{Anchor, UiText, UiTransform},
+};
+use application_ui::{FontVariant, Theme};
+use derivative::Derivative;
+use derive_new::new;
+use session_join_model::{play::SessionJoinStatusEntity, SessionJoinEntity, SessionJoinEvent};
+
+const FONT_COLOUR_ERROR: [f32; 4] = [1., 0.6, 0.6, 1.];
+const FONT_SIZE_WIDGET: f32 = 25.;
+const LABEL_WIDTH: f32 = 800.;
+const LABEL_HEIGHT: f32 = 50.;
+
+/// Displays the reason a session join request was rejected.
+///
+/// The message is cleared when another request is sent, or the request is cancelled.
+#[derive(Debug, Default, new)]
+pub struct SessionJoinStatusDisplaySystem {
+    /// Reader ID for the `SessionJoinEvent` event channel.
+    #[new(default)]
+    session_join_event_rid: Option<ReaderId<SessionJoinEvent>>,
+}
+
+/// `SessionJoinStatusDisplaySystemData`.
+#[derive(Derivative, SystemData)]
+#[derivative(Debug)]
+pub struct SessionJoinStatusDisplaySystemData<'s> {
+    /// `Entities`.
+    #[derivative(Debug = "ignore")]
+    pub entities: Entities<'s>,
+    /// `SessionJoinEvent` channel.
+    #[derivative(Debug = "ignore")]
+    pub session_join_ec: Read<'s, EventChannel<SessionJoinEvent>>,
+    /// `SessionJoinStatusEntity` components.
+    #[derivative(Debug = "ignore")]
+    pub session_join_status_entities: WriteStorage<'s, SessionJoinStatusEntity>,
+    /// `SessionJoinEntity` components.
+    #[derivative(Debug = "ignore")]
+    pub session_join_entities: WriteStorage<'s, SessionJoinEntity>,
+
+    // Resources needed to display text.
+    /// `Theme` resource.
+    #[derivative(Debug = "ignore")]
+    pub theme: ReadExpect<'s, Theme>,
+    /// `UiTransform` components.
+    #[derivative(Debug = "ignore")]
+    pub ui_transforms: WriteStorage<'s, UiTransform>,
+    /// `UiText` components.
+    #[derivative(Debug = "ignore")]
+    pub ui_texts: WriteStorage<'s, UiText>,
+}
+
+impl SessionJoinStatusDisplaySystem {
+    /// Deletes existing entities used to display session join status.
+    fn delete_existing(
+        entities: &Entities<'_>,
+        session_join_status_entities: &mut WriteStorage<'_, SessionJoinStatusEntity>,
+    ) {
+        (entities, session_join_status_entities)
+            .join()
+            .for_each(|(entity, _)| {
+                entities
+                    .delete(entity)
+                    .expect("Failed to delete `SessionJoinStatus` entity");
+            });
+    }
+}
+
+impl<'s> System<'s> for SessionJoinStatusDisplaySystem {
+    type SystemData = SessionJoinStatusDisplaySystemData<'s>;
+
+    fn run(
+        &mut self,
+        SessionJoinStatusDisplaySystemData {
+            entities,
+            session_join_ec,
+            mut session_join_status_entities,
+            mut session_join_entities,
+            theme,
+            mut ui_transforms,
+            mut ui_texts,
+        }: Self::SystemData,
+    ) {
+        let session_join_event_rid = self
+            .session_join_event_rid
+            .as_mut()
+            .expect("Expected `session_join_event_rid` field to be set.");
+
+        session_join_ec
+            .read(session_join_event_rid)
+            .for_each(|ev| match ev {
+                SessionJoinEvent::SessionJoinRequest(_)
+                | SessionJoinEvent::SessionSpectateRequest(_)
+                | SessionJoinEvent::JoinCancel => {
+                    Self::delete_existing(&entities, &mut session_join_status_entities);
+                }
+                SessionJoinEvent::SessionReject(session_reject_response) => {
+                    Self::delete_existing(&entities, &mut session_join_status_entities);
+
+                    let font = theme
+                        .fonts
+                        .get(&FontVariant::Regular)
+                        .expect("Failed to get regular font handle.");
+
+                    let x = -LABEL_WIDTH / 2.;
+                    let y = LABEL_HEIGHT;
+                    let z = 1.;
+
+                    let ui_transform = UiTransform::new(
+                        String::from("session_join_status_text"),
+                        Anchor::BottomMiddle,
+                        Anchor::MiddleLeft,
+                        x,
+                        y,
+                        z,
+                        LABEL_WIDTH,
+                        LABEL_HEIGHT,
+                    );
+
+                    let status_text = format!(
+                        "Failed to join session `{}`: {}",
+                        session_reject_response.session_code,
+                        session_reject_response.session_join_error
+                    );
+
+                    let ui_text = UiText::new(
+                        font.clone(),
+                        status_text,
+                        FONT_COLOUR_ERROR,
+                        FONT_SIZE_WIDGET,
+                    );
+
+                    entities
+                        .build_entity()
+                        .with(SessionJoinEntity, &mut session_join_entities)
+                        .with(SessionJoinStatusEntity, &mut session_join_status_entities)
+                        .with(ui_transform, &mut ui_transforms)
+                        .with(ui_text, &mut ui_texts)
+                        .build();
+                }
+                _ => {}
+            });
+    }
+
+    fn setup(&mut self, world: &mut World) {
+        Self::SystemData::setup(world);
+
+        self.session_join_event_rid = Some(
+            world
+                .fetch_mut::<EventChannel<SessionJoinEvent>>()
+                .register_reader(),
+        );
+    }
+}
@@ -0,0 +1,222 @@
+use std::{
+    net::SocketAddr,
+    time::{Duration, Instant},
+};
+
+use amethyst::{
+    derive::SystemDesc,
+    ecs::{Read, ReadExpect, System, World, Write},
+    network::simulation::NetworkSimulationEvent,
+    shred::{ResourceId, SystemData},
+    shrev::{EventChannel, ReaderId},
+};
+use derivative::Derivative;
+use derive_new::new;
+use game_input_model::{loaded::PlayerControllers, play::ControllerIdOffset};
+use log::{debug, warn};
+use net_model::play::{NetData, NetEventChannel, NetMessageEvent};
+use network_session_model::{
+    config::SessionServerConfig,
+    play::{
+        Session, SessionCode, SessionDeviceId, SessionDevices, SessionReconnectToken,
+        SessionStatus, SessionTickCount,
+    },
+};
+use rollback_model::play::SessionInputHistory;
+use session_join_model::{
+    play::{SessionReconnectAcceptResponse, SessionReconnectRequestParams},
+    SessionJoinEvent,
+};
+
+/// Duration to wait between reconnect requests.
+const RECONNECT_INTERVAL: Duration = Duration::from_secs(2);
+
+/// Rejoins the session when the connection to the session server drops.
+///
+/// A reconnect request is sent periodically until the session server accepts or rejects it. When
+/// accepted, the input that was missed is applied by the `SessionInputCatchUpSystem`.
+#[derive(Debug, SystemDesc, new)]
+#[system_desc(name(SessionReconnectSystemDesc))]
+pub struct SessionReconnectSystem {
+    /// Reader ID for the `NetworkSimulationEvent` channel.
+    #[system_desc(event_channel_reader)]
+    network_simulation_event_rid: ReaderId<NetworkSimulationEvent>,
+    /// Reader ID for the `SessionJoinEvent` channel.
+    #[system_desc(event_channel_reader)]
+    session_join_event_rid: ReaderId<NetData<SessionJoinEvent>>,
+    /// Whether the device is waiting to rejoin the session.
+    #[system_desc(skip)]
+    #[new(default)]
+    reconnecting: bool,
+    /// When the last reconnect request was sent.
+    #[system_desc(skip)]
+    #[new(default)]
+    reconnect_requested_at: Option<Instant>,
+}
+
+/// `SessionReconnectSystemData`.
+#[derive(Derivative, SystemData)]
+#[derivative(Debug)]
+pub struct SessionReconnectSystemData<'s> {
+    /// `NetworkSimulationEvent` channel.
+    #[derivative(Debug = "ignore")]
+    pub network_simulation_ec: Read<'s, EventChannel<NetworkSimulationEvent>>,
+    /// `SessionJoinEvent` channel.
+    #[derivative(Debug = "ignore")]
+    pub session_join_nec: Read<'s, NetEventChannel<SessionJoinEvent>>,
+    /// `SessionServerConfig` resource.
+    #[derivative(Debug = "ignore")]
+    pub session_server_config: ReadExpect<'s, SessionServerConfig>,
+    /// `SessionStatus` resource.
+    #[derivative(Debug = "ignore")]
+    pub session_status: Write<'s, SessionStatus>,
+    /// `SessionCode` resource.
+    #[derivative(Debug = "ignore")]
+    pub session_code: Read<'s, SessionCode>,
+    /// `SessionDeviceId` resource.
+    #[derivative(Debug = "ignore")]
+    pub session_device_id: Read<'s, SessionDeviceId>,
+    /// `SessionReconnectToken` resource.
+    #[derivative(Debug = "ignore")]
+    pub session_reconnect_token: Read<'s, SessionReconnectToken>,
+    /// `SessionTickCount` resource.
+    #[derivative(Debug = "ignore")]
+    pub session_tick_count: Read<'s, SessionTickCount>,
+    /// `SessionDevices` resource.
+    #[derivative(Debug = "ignore")]
+    pub session_devices: Write<'s, SessionDevices>,
+    /// `PlayerControllers` resource.
+    #[derivative(Debug = "ignore")]
+    pub player_controllers: Write<'s, PlayerControllers>,
+    /// `ControllerIdOffset` resource.
+    #[derivative(Debug = "ignore")]
+    pub controller_id_offset: Write<'s, ControllerIdOffset>,
+    /// `SessionInputHistory` resource.
+    #[derivative(Debug = "ignore")]
+    pub session_input_history: Write<'s, SessionInputHistory>,
+    /// `NetMessageEvent` channel.
+    #[derivative(Debug = "ignore")]
+    pub net_message_ec: Write<'s, EventChannel<NetMessageEvent>>,
+}
+
+impl<'s> System<'s> for SessionReconnectSystem {
+    type SystemData = SessionReconnectSystemData<'s>;
+
+    fn run(
+        &mut self,
+        SessionReconnectSystemData {
+            network_simulation_ec,
+            session_join_nec,
+            session_server_config,
+            mut session_status,
+            session_code,
+            session_device_id,
+            session_reconnect_token,
+            session_tick_count,
+            mut session_devices,
+            mut player_controllers,
+            mut controller_id_offset,
+            mut session_input_history,
+            mut net_message_ec,
+        }: Self::SystemData,
+    ) {
+        let server_socket_addr =
+            SocketAddr::new(session_server_config.address, session_server_config.port);
+        let session_established = *session_status == SessionStatus::JoinEstablished
+            || *session_status == SessionStatus::HostEstablished;
+
+        let server_disconnected = network_simulation_ec
+            .read(&mut self.network_simulation_event_rid)
+            .any(|ev| {
+                matches!(ev, NetworkSimulationEvent::Disconnect(socket_addr)
+                    if *socket_addr == server_socket_addr)
+            });
+        if session_established && server_disconnected && !self.reconnecting {
+            warn!(
+                "Lost connection to session server. Reconnecting to session `{}`.",
+                *session_code
+            );
+
+            self.reconnecting = true;
+            self.reconnect_requested_at = None;
+        }
+
+        let session_code = &*session_code;
+        let reconnecting = &mut self.reconnecting;
+        session_join_nec
+            .read(&mut self.session_join_event_rid)
+            .for_each(|ev| {
+                if !*reconnecting {
+                    return;
+                }
+
+                match &ev.data {
+                    SessionJoinEvent::SessionReconnectAccept(session_reconnect_accept_response)
+                        if &session_reconnect_accept_response.session.session_code
+                            == session_code =>
+                    {
+                        debug!(
+                            "Session reconnect accepted: {:?}",
+                            session_reconnect_accept_response
+                        );
+
+                        let SessionReconnectAcceptResponse {
+                            session:
+                                Session {
+                                    session_devices: session_devices_received,
+                                    ..
+                                },
+                            player_controllers: player_controllers_received,
+                            controller_id_offset: controller_id_offset_received,
+                            session_input_history: session_input_history_received,
+                            ..
+                        } = session_reconnect_accept_response.clone();
+
+                        // Write to resources.
+                        *session_devices = session_devices_received;
+                        *player_controllers = player_controllers_received;
+                        *controller_id_offset = controller_id_offset_received;
+                        *session_input_history = session_input_history_received;
+
+                        *reconnecting = false;
+                    }
+                    SessionJoinEvent::SessionReject(session_reject_response)
+                        if &session_reject_response.session_code == session_code =>
+                    {
+                        warn!(
+                            "Failed to rejoin session `{}`: {}",
+                            session_reject_response.session_code,
+                            session_reject_response.session_join_error
+                        );
+
+                        *session_status = SessionStatus::None;
+                        *reconnecting = false;
+                    }
+                    _ => {}
+                }
+            });
+
+        if self.reconnecting {
+            // Send the first request immediately.
+            let reconnect_request_due = self
+                .reconnect_requested_at
+                .map(|reconnect_requested_at| {
+                    reconnect_requested_at.elapsed() >= RECONNECT_INTERVAL
+                })
+                .unwrap_or(true);
+            if reconnect_request_due {
+                let session_reconnect_request_params = SessionReconnectRequestParams::new(
+                    session_code.clone(),
+                    *session_device_id,
+                    *session_reconnect_token,
+                    *session_tick_count,
+                );
+                net_message_ec.single_write(NetMessageEvent::SessionJoinEvent(
+                    SessionJoinEvent::SessionReconnectRequest(session_reconnect_request_params),
+                ));
+
+                self.reconnect_requested_at = Some(Instant::now());
+            }
+        }
+    }
+}
@@ -4,8 +4,10 @@
 
 pub use crate::{
     session_join_event_stdin_mapper::SessionJoinEventStdinMapper,
+    session_join_status_log_system::{SessionJoinStatusLogSystem, SessionJoinStatusLogSystemDesc},
     session_join_stdio_bundle::SessionJoinStdioBundle,
 };
 
 mod session_join_event_stdin_mapper;
+mod session_join_status_log_system;
 mod session_join_stdio_bundle;
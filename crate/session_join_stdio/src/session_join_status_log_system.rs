@@ -0,0 +1,64 @@
+use amethyst::{
+    derive::SystemDesc,
+    ecs::{Read, System, World},
+    shred::{ResourceId, SystemData},
+    shrev::{EventChannel, ReaderId},
+};
+use derivative::Derivative;
+use derive_new::new;
+use log::info;
+use session_join_model::SessionJoinEvent;
+
+/// Logs the outcome of session join requests.
+///
+/// This lets a stdio user see why a request was rejected.
+#[derive(Debug, SystemDesc, new)]
+#[system_desc(name(SessionJoinStatusLogSystemDesc))]
+pub struct SessionJoinStatusLogSystem {
+    /// Reader ID for the `SessionJoinEvent` channel.
+    #[system_desc(event_channel_reader)]
+    session_join_event_rid: ReaderId<SessionJoinEvent>,
+}
+
+/// `SessionJoinStatusLogSystemData`.
+#[derive(Derivative, SystemData)]
+#[derivative(Debug)]
+pub struct SessionJoinStatusLogSystemData<'s> {
+    /// `SessionJoinEvent` channel.
+    #[derivative(Debug = "ignore")]
+    pub session_join_ec: Read<'s, EventChannel<SessionJoinEvent>>,
+}
+
+impl SessionJoinStatusLogSystem {
+    /// Returns the status message for a `SessionJoinEvent`, if it is a server response.
+    pub fn status_message(session_join_event: &SessionJoinEvent) -> Option<String> {
+        match session_join_event {
+            SessionJoinEvent::SessionAccept(session_accept_response) => Some(format!(
+                "Joined session `{}`.",
+                session_accept_response.session.session_code
+            )),
+            SessionJoinEvent::SessionSpectateAccept(session_spectate_accept_response) => {
+                Some(format!(
+                    "Spectating session `{}`.",
+                    session_spectate_accept_response.session.session_code
+                ))
+            }
+            SessionJoinEvent::SessionReject(session_reject_response) => Some(format!(
+                "Failed to join session `{}`: {}",
+                session_reject_response.session_code, session_reject_response.session_join_error
+            )),
+            _ => None,
+        }
+    }
+}
+
+impl<'s> System<'s> for SessionJoinStatusLogSystem {
+    type SystemData = SessionJoinStatusLogSystemData<'s>;
+
+    fn run(&mut self, SessionJoinStatusLogSystemData { session_join_ec }: Self::SystemData) {
+        session_join_ec
+            .read(&mut self.session_join_event_rid)
+            .filter_map(Self::status_message)
+            .for_each(|status_message| info!("{}", status_message));
+    }
+}
@@ -1,7 +1,7 @@
 use std::any;
 
 use amethyst::{
-    core::bundle::SystemBundle,
+    core::{bundle::SystemBundle, SystemDesc},
     ecs::{DispatcherBuilder, World},
     Error,
 };
@@ -9,16 +9,19 @@ use application_event::AppEventVariant;
 use derive_new::new;
 use stdio_spi::MapperSystem;
 
-use crate::SessionJoinEventStdinMapper;
+use crate::{
+    SessionJoinEventStdinMapper, SessionJoinStatusLogSystem, SessionJoinStatusLogSystemDesc,
+};
 
-/// Adds a `MapperSystem<SessionJoinEventStdinMapper>` to the `World`.
+/// Adds a `MapperSystem<SessionJoinEventStdinMapper>` and `SessionJoinStatusLogSystem` to the
+/// `World`.
 #[derive(Debug, new)]
 pub struct SessionJoinStdioBundle;
 
 impl<'a, 'b> SystemBundle<'a, 'b> for SessionJoinStdioBundle {
     fn build(
         self,
-        _world: &mut World,
+        world: &mut World,
         builder: &mut DispatcherBuilder<'a, 'b>,
     ) -> Result<(), Error> {
         builder.add(
@@ -26,6 +29,11 @@ impl<'a, 'b> SystemBundle<'a, 'b> for SessionJoinStdioBundle {
             any::type_name::<MapperSystem<SessionJoinEventStdinMapper>>(),
             &[],
         ); // kcov-ignore
+        builder.add(
+            SessionJoinStatusLogSystemDesc::default().build(world),
+            any::type_name::<SessionJoinStatusLogSystem>(),
+            &[],
+        ); // kcov-ignore
         Ok(())
     }
 }
@@ -12,7 +12,10 @@ use game_loading::GameLoadingState;
 use game_play::GamePlayState;
 use log::debug;
 use map_selection::{MapSelectionBundle, MapSelectionStateBuilder, MapSelectionStateDelegate};
-use session_lobby_model::{SessionLobbyEntity, SessionLobbyEvent};
+use session_lobby_model::{
+    play::{SessionChatLog, SessionDeviceReadies},
+    SessionLobbyEntity, SessionLobbyEvent,
+};
 use state_registry::StateId;
 
 /// `State` that displays the devices in the same online session.
@@ -72,6 +75,10 @@ impl SessionLobbyStateDelegate {
 
 impl State<GameData<'static, 'static>, AppEvent> for SessionLobbyStateDelegate {
     fn on_start(&mut self, data: StateData<'_, GameData<'static, 'static>>) {
+        // Chat messages and ready flags from a previous session are not carried over.
+        data.world.insert(SessionChatLog::default());
+        data.world.insert(SessionDeviceReadies::default());
+
         Self::initialize_state(data);
     }
 
@@ -9,6 +9,8 @@ use serde::{Deserialize, Serialize};
 pub enum SessionLobbyEventCommand {
     /// Host has requested to start the session.
     SessionStartRequest,
+    /// Device has toggled whether it is ready to start the session.
+    SessionReadyToggle,
     /// Return to the previous menu.
     Back,
 }
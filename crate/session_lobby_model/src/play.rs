@@ -1,5 +1,29 @@
 //! Data types used at runtime.
 
-pub use self::session_start_request_params::SessionStartRequestParams;
+pub use self::{
+    session_character_selection::SessionCharacterSelection,
+    session_character_selection_params::SessionCharacterSelectionParams,
+    session_character_selections::SessionCharacterSelections,
+    session_chat_log::{SessionChatLog, SESSION_CHAT_LOG_CAPACITY},
+    session_chat_message::SessionChatMessage,
+    session_chat_request_params::SessionChatRequestParams,
+    session_device_readies::SessionDeviceReadies,
+    session_device_ready::SessionDeviceReady,
+    session_lobby_model_error::SessionLobbyModelError,
+    session_map_selection_params::SessionMapSelectionParams,
+    session_ready_request_params::SessionReadyRequestParams,
+    session_start_request_params::SessionStartRequestParams,
+};
 
+mod session_character_selection;
+mod session_character_selection_params;
+mod session_character_selections;
+mod session_chat_log;
+mod session_chat_message;
+mod session_chat_request_params;
+mod session_device_readies;
+mod session_device_ready;
+mod session_lobby_model_error;
+mod session_map_selection_params;
+mod session_ready_request_params;
 mod session_start_request_params;
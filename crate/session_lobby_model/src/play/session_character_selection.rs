@@ -0,0 +1,42 @@
+use std::str::FromStr;
+
+use asset_model::config::AssetSlug;
+use derive_new::new;
+use game_input_model::config::ControllerId;
+use serde::{Deserialize, Serialize};
+
+use crate::play::SessionLobbyModelError;
+
+/// Character selected by a controller, sent between session devices.
+///
+/// The `AssetSlug` is sent instead of the `AssetId`, as asset IDs are only valid on the device
+/// that loaded the assets.
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize, new)]
+pub struct SessionCharacterSelection {
+    /// ID of the controller that selected the character.
+    pub controller_id: ControllerId,
+    /// Slug of the selected character.
+    pub asset_slug: AssetSlug,
+}
+
+impl FromStr for SessionCharacterSelection {
+    type Err = SessionLobbyModelError;
+
+    fn from_str(s: &str) -> Result<Self, SessionLobbyModelError> {
+        let mut split = s.splitn(2, ':');
+        let controller_id = split
+            .next()
+            .map(str::parse::<ControllerId>)
+            .and_then(Result::ok);
+        let asset_slug = split
+            .next()
+            .map(str::parse::<AssetSlug>)
+            .and_then(Result::ok);
+
+        if let (Some(controller_id), Some(asset_slug)) = (controller_id, asset_slug) {
+            Ok(SessionCharacterSelection::new(controller_id, asset_slug))
+        } else {
+            Err(SessionLobbyModelError::SessionCharacterSelectionParseError)
+        }
+    }
+}
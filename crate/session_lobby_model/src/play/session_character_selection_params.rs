@@ -0,0 +1,13 @@
+use derive_new::new;
+use serde::{Deserialize, Serialize};
+use structopt_derive::StructOpt;
+
+use crate::play::SessionCharacterSelections;
+
+/// Characters selected by a device's controllers.
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize, StructOpt, new)]
+pub struct SessionCharacterSelectionParams {
+    /// Selected characters, e.g. `0:default/bat 1:default/spinner`.
+    #[structopt(long)]
+    pub character_selections: SessionCharacterSelections,
+}
@@ -0,0 +1,32 @@
+use std::str::FromStr;
+
+use derive_deref::{Deref, DerefMut};
+use derive_new::new;
+use serde::{Deserialize, Serialize};
+
+use crate::play::{SessionCharacterSelection, SessionLobbyModelError};
+
+/// Characters selected by a session device's controllers (`Vec<SessionCharacterSelection>`
+/// newtype).
+#[derive(Clone, Debug, Default, Deref, DerefMut, Deserialize, PartialEq, Serialize, new)]
+pub struct SessionCharacterSelections(pub Vec<SessionCharacterSelection>);
+
+impl FromStr for SessionCharacterSelections {
+    type Err = SessionLobbyModelError;
+
+    fn from_str(s: &str) -> Result<Self, SessionLobbyModelError> {
+        s.split_whitespace()
+            .try_fold(
+                SessionCharacterSelections::default(),
+                |mut session_character_selections, session_character_selection_str| {
+                    let session_character_selection =
+                        SessionCharacterSelection::from_str(session_character_selection_str)?;
+                    session_character_selections.push(session_character_selection);
+                    Ok(session_character_selections)
+                },
+            )
+            .map_err(|_: SessionLobbyModelError| {
+                SessionLobbyModelError::SessionCharacterSelectionsParseError
+            })
+    }
+}
@@ -0,0 +1,25 @@
+use derive_deref::{Deref, DerefMut};
+use derive_new::new;
+
+use crate::play::SessionChatMessage;
+
+/// Maximum number of chat messages to keep.
+pub const SESSION_CHAT_LOG_CAPACITY: usize = 100;
+
+/// Chat messages received in the session lobby, oldest first.
+///
+/// `Vec<SessionChatMessage>` newtype.
+#[derive(Clone, Debug, Default, Deref, DerefMut, PartialEq, new)]
+pub struct SessionChatLog(pub Vec<SessionChatMessage>);
+
+impl SessionChatLog {
+    /// Appends a message, discarding the oldest messages beyond the log's capacity.
+    pub fn push(&mut self, session_chat_message: SessionChatMessage) {
+        self.0.push(session_chat_message);
+
+        if self.0.len() > SESSION_CHAT_LOG_CAPACITY {
+            let excess = self.0.len() - SESSION_CHAT_LOG_CAPACITY;
+            self.0.drain(0..excess);
+        }
+    }
+}
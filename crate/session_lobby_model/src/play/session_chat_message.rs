@@ -0,0 +1,15 @@
+use derive_new::new;
+use network_session_model::play::SessionDeviceName;
+use serde::{Deserialize, Serialize};
+use structopt_derive::StructOpt;
+
+/// Chat message sent by a device in the session lobby.
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize, StructOpt, new)]
+pub struct SessionChatMessage {
+    /// Name of the device that sent the message.
+    #[structopt(long)]
+    pub session_device_name: SessionDeviceName,
+    /// Text of the message.
+    #[structopt(long)]
+    pub message: String,
+}
@@ -0,0 +1,11 @@
+use derive_new::new;
+use serde::{Deserialize, Serialize};
+use structopt_derive::StructOpt;
+
+/// Parameters to send a chat message to the session lobby.
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize, StructOpt, new)]
+pub struct SessionChatRequestParams {
+    /// Text of the message.
+    #[structopt(long)]
+    pub message: String,
+}
@@ -0,0 +1,36 @@
+use std::collections::HashSet;
+
+use derive_deref::{Deref, DerefMut};
+use derive_new::new;
+use network_session_model::play::{SessionDeviceId, SessionDevices};
+
+/// IDs of the devices in the session lobby that are ready to start the session.
+///
+/// `HashSet<SessionDeviceId>` newtype.
+#[derive(Clone, Debug, Default, Deref, DerefMut, PartialEq, new)]
+pub struct SessionDeviceReadies(pub HashSet<SessionDeviceId>);
+
+impl SessionDeviceReadies {
+    /// Records whether the given device is ready.
+    pub fn set(&mut self, session_device_id: SessionDeviceId, ready: bool) {
+        if ready {
+            self.0.insert(session_device_id);
+        } else {
+            self.0.remove(&session_device_id);
+        }
+    }
+
+    /// Returns `true` if every one of the given devices that has player controllers is ready.
+    ///
+    /// Spectators have no player controllers, so they do not need to be ready. This returns
+    /// `false` if there are no such devices.
+    pub fn all_ready(&self, session_devices: &SessionDevices) -> bool {
+        let mut player_devices = session_devices
+            .iter()
+            .filter(|session_device| !session_device.player_controllers.is_empty())
+            .peekable();
+
+        player_devices.peek().is_some()
+            && player_devices.all(|session_device| self.0.contains(&session_device.id))
+    }
+}
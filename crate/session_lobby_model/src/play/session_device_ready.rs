@@ -0,0 +1,15 @@
+use derive_new::new;
+use network_session_model::play::SessionDeviceId;
+use serde::{Deserialize, Serialize};
+use structopt_derive::StructOpt;
+
+/// Whether a device in the session lobby is ready to start the session.
+#[derive(Clone, Copy, Debug, Deserialize, PartialEq, Serialize, StructOpt, new)]
+pub struct SessionDeviceReady {
+    /// ID of the device whose ready flag changed.
+    #[structopt(long)]
+    pub session_device_id: SessionDeviceId,
+    /// Whether the device is ready.
+    #[structopt(long)]
+    pub ready: bool,
+}
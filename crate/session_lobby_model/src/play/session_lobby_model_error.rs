@@ -0,0 +1,38 @@
+use std::{
+    error::Error,
+    fmt::{self, Display, Formatter},
+};
+
+/// Errors when using `session_lobby_model` types.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum SessionLobbyModelError {
+    /// Failed to parse a `SessionCharacterSelection` from string.
+    SessionCharacterSelectionParseError,
+    /// Failed to parse `SessionCharacterSelections` from string.
+    SessionCharacterSelectionsParseError,
+}
+
+impl Display for SessionLobbyModelError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::SessionCharacterSelectionParseError => write!(
+                f,
+                "Character selection must be in the form:\n\
+                \n\
+                <controller_id>:<namespace>/<name>\n\
+                \n\
+                Example: `0:default/bat`\n"
+            ),
+            Self::SessionCharacterSelectionsParseError => write!(
+                f,
+                "Character selections must be space separated in the form:\n\
+                \n\
+                <character_selection_0> <character_selection_1>\n\
+                \n\
+                Example: `0:default/bat 1:default/spinner`\n"
+            ),
+        }
+    }
+}
+
+impl Error for SessionLobbyModelError {}
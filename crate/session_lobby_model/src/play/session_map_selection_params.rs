@@ -0,0 +1,12 @@
+use asset_model::config::AssetSlug;
+use derive_new::new;
+use serde::{Deserialize, Serialize};
+use structopt_derive::StructOpt;
+
+/// Map selected by the session host.
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize, StructOpt, new)]
+pub struct SessionMapSelectionParams {
+    /// Slug of the selected map, e.g. `default/eruption`.
+    #[structopt(long)]
+    pub map_slug: AssetSlug,
+}
@@ -0,0 +1,11 @@
+use derive_new::new;
+use serde::{Deserialize, Serialize};
+use structopt_derive::StructOpt;
+
+/// Parameters to mark the device as ready or not ready to start the session.
+#[derive(Clone, Copy, Debug, Deserialize, PartialEq, Serialize, StructOpt, new)]
+pub struct SessionReadyRequestParams {
+    /// Whether the device is ready.
+    #[structopt(long)]
+    pub ready: bool,
+}
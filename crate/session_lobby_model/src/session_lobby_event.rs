@@ -1,7 +1,11 @@
 use serde::{Deserialize, Serialize};
 use structopt_derive::StructOpt;
 
-use crate::play::SessionStartRequestParams;
+use crate::play::{
+    SessionCharacterSelectionParams, SessionChatMessage, SessionChatRequestParams,
+    SessionDeviceReady, SessionMapSelectionParams, SessionReadyRequestParams,
+    SessionStartRequestParams,
+};
 
 /// Session lobby state events.
 ///
@@ -11,6 +15,14 @@ use crate::play::SessionStartRequestParams;
 ///
 /// * `session_lobby session_start_request --session-code ABCD`
 /// * `session_lobby session_start_notify`
+/// * `session_lobby session_chat_request --message "I'll be Bat"`
+/// * `session_lobby session_chat_notify --session-device-name azriel --message "I'll be Bat"`
+/// * `session_lobby session_ready_request --ready`
+/// * `session_lobby session_ready_notify --session-device-id 1 --ready`
+/// * `session_lobby session_character_select_request --character-selections "0:default/bat"`
+/// * `session_lobby session_character_select_notify --character-selections "0:default/bat"`
+/// * `session_lobby session_map_select_request --map-slug default/eruption`
+/// * `session_lobby session_map_select_notify --map-slug default/eruption`
 /// * `session_lobby back`
 #[derive(Clone, Debug, Deserialize, PartialEq, Serialize, StructOpt)]
 #[serde(deny_unknown_fields, rename_all = "snake_case")]
@@ -20,6 +32,24 @@ pub enum SessionLobbyEvent {
     SessionStartRequest(SessionStartRequestParams),
     /// Notification from the session server to start the session.
     SessionStartNotify,
+    /// Device has requested to send a chat message to the session lobby.
+    SessionChatRequest(SessionChatRequestParams),
+    /// Notification from the session server of a chat message in the session lobby.
+    SessionChatNotify(SessionChatMessage),
+    /// Device has requested to change whether it is ready to start the session.
+    SessionReadyRequest(SessionReadyRequestParams),
+    /// Notification from the session server that a device's ready flag has changed.
+    SessionReadyNotify(SessionDeviceReady),
+    /// Device has requested to send its controllers' character selections.
+    SessionCharacterSelectRequest(SessionCharacterSelectionParams),
+    /// Notification from the session server of a device's character selections.
+    SessionCharacterSelectNotify(SessionCharacterSelectionParams),
+    /// Host has requested to send the selected map.
+    SessionMapSelectRequest(SessionMapSelectionParams),
+    /// Notification from the session server of the map selected by the host.
+    ///
+    /// This is sent after every device's character selections have been sent.
+    SessionMapSelectNotify(SessionMapSelectionParams),
     /// Return to the previous menu.
     Back,
 }
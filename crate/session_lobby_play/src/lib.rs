@@ -4,7 +4,8 @@
 
 pub use crate::system::{
     SessionLobbyRequestSystem, SessionLobbyRequestSystemDesc, SessionLobbyResponseSystem,
-    SessionLobbyResponseSystemDesc,
+    SessionLobbyResponseSystemDesc, SessionSelectionRequestSystem,
+    SessionSelectionRequestSystemDesc,
 };
 
 mod system;
@@ -1,7 +1,11 @@
 pub use self::{
     session_lobby_request_system::{SessionLobbyRequestSystem, SessionLobbyRequestSystemDesc},
     session_lobby_response_system::{SessionLobbyResponseSystem, SessionLobbyResponseSystemDesc},
+    session_selection_request_system::{
+        SessionSelectionRequestSystem, SessionSelectionRequestSystemDesc,
+    },
 };
 
 mod session_lobby_request_system;
 mod session_lobby_response_system;
+mod session_selection_request_system;
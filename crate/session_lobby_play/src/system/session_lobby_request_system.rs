@@ -6,11 +6,15 @@ use amethyst::{
 };
 use derivative::Derivative;
 use derive_new::new;
+use log::debug;
 use net_model::play::NetMessageEvent;
-use network_session_model::play::SessionStatus;
-use session_lobby_model::SessionLobbyEvent;
+use network_session_model::play::{SessionDevices, SessionStatus};
+use session_lobby_model::{play::SessionDeviceReadies, SessionLobbyEvent};
 
 /// Sends requests to a game server to lobby a session.
+///
+/// Session start and map selection requests are only sent by the session host. Session start
+/// requests are only sent when every device in the session is ready.
 #[derive(Debug, SystemDesc, new)]
 #[system_desc(name(SessionLobbyRequestSystemDesc))]
 pub struct SessionLobbyRequestSystem {
@@ -28,6 +32,12 @@ pub struct SessionLobbyRequestSystemData<'s> {
     /// `SessionStatus` resource.
     #[derivative(Debug = "ignore")]
     pub session_status: Read<'s, SessionStatus>,
+    /// `SessionDevices` resource.
+    #[derivative(Debug = "ignore")]
+    pub session_devices: Read<'s, SessionDevices>,
+    /// `SessionDeviceReadies` resource.
+    #[derivative(Debug = "ignore")]
+    pub session_device_readies: Read<'s, SessionDeviceReadies>,
     /// `NetworkMessageEvent` channel.
     #[derivative(Debug = "ignore")]
     pub net_message_ec: Write<'s, EventChannel<NetMessageEvent>>,
@@ -41,29 +51,70 @@ impl<'s> System<'s> for SessionLobbyRequestSystem {
         SessionLobbyRequestSystemData {
             session_lobby_ec,
             session_status,
+            session_devices,
+            session_device_readies,
             mut net_message_ec,
         }: Self::SystemData,
     ) {
-        let mut session_lobby_events = session_lobby_ec.read(&mut self.session_lobby_event_rid);
+        let session_lobby_events = session_lobby_ec.read(&mut self.session_lobby_event_rid);
 
-        // Guard against requesting session to start if the application is not in a session.
+        // Guard against sending requests if the application is not in a session.
         if *session_status == SessionStatus::JoinEstablished
             || *session_status == SessionStatus::HostEstablished
         {
-            // Only process one session lobby request event if multiple are received.
-            let session_start_request_params = session_lobby_events.find_map(|ev| {
-                if let SessionLobbyEvent::SessionStartRequest(session_start_request_params) = ev {
-                    Some(session_start_request_params)
-                } else {
-                    None
+            let is_host = *session_status == SessionStatus::HostEstablished;
+
+            // Only process one session start request event if multiple are received.
+            let mut session_start_requested = false;
+            session_lobby_events.for_each(|ev| match ev {
+                SessionLobbyEvent::SessionStartRequest(_) if !is_host => {
+                    debug!("Only the session host may start the session.");
+                }
+                SessionLobbyEvent::SessionStartRequest(_)
+                    if !session_device_readies.all_ready(&session_devices) =>
+                {
+                    debug!("Not starting session as not every device is ready.");
+                }
+                SessionLobbyEvent::SessionStartRequest(session_start_request_params)
+                    if !session_start_requested =>
+                {
+                    session_start_requested = true;
+                    net_message_ec.single_write(NetMessageEvent::SessionLobbyEvent(
+                        SessionLobbyEvent::SessionStartRequest(
+                            session_start_request_params.clone(),
+                        ),
+                    ));
                 }
+                SessionLobbyEvent::SessionChatRequest(session_chat_request_params) => {
+                    net_message_ec.single_write(NetMessageEvent::SessionLobbyEvent(
+                        SessionLobbyEvent::SessionChatRequest(session_chat_request_params.clone()),
+                    ));
+                }
+                SessionLobbyEvent::SessionReadyRequest(session_ready_request_params) => {
+                    net_message_ec.single_write(NetMessageEvent::SessionLobbyEvent(
+                        SessionLobbyEvent::SessionReadyRequest(*session_ready_request_params),
+                    ));
+                }
+                SessionLobbyEvent::SessionCharacterSelectRequest(
+                    session_character_selection_params,
+                ) => {
+                    net_message_ec.single_write(NetMessageEvent::SessionLobbyEvent(
+                        SessionLobbyEvent::SessionCharacterSelectRequest(
+                            session_character_selection_params.clone(),
+                        ),
+                    ));
+                }
+                SessionLobbyEvent::SessionMapSelectRequest(session_map_selection_params)
+                    if is_host =>
+                {
+                    net_message_ec.single_write(NetMessageEvent::SessionLobbyEvent(
+                        SessionLobbyEvent::SessionMapSelectRequest(
+                            session_map_selection_params.clone(),
+                        ),
+                    ));
+                }
+                _ => {}
             });
-
-            if let Some(session_start_request_params) = session_start_request_params {
-                net_message_ec.single_write(NetMessageEvent::SessionLobbyEvent(
-                    SessionLobbyEvent::SessionStartRequest(session_start_request_params.clone()),
-                ));
-            }
         }
     }
 }
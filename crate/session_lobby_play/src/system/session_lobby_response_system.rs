@@ -4,14 +4,29 @@ use amethyst::{
     shred::{ResourceId, SystemData},
     shrev::{EventChannel, ReaderId},
 };
+use asset_model::loaded::AssetIdMappings;
+use character_selection_model::CharacterSelections;
 use derivative::Derivative;
 use derive_new::new;
-use log::debug;
+use log::{debug, warn};
+use map_selection_model::MapSelection;
 use net_model::play::{NetData, NetEventChannel};
-use network_session_model::play::SessionStatus;
-use session_lobby_model::SessionLobbyEvent;
+use network_session_model::play::{SessionStatus, SessionTickCount};
+use session_lobby_model::{
+    play::{
+        SessionCharacterSelectionParams, SessionChatLog, SessionDeviceReadies,
+        SessionMapSelectionParams,
+    },
+    SessionLobbyEvent,
+};
 
 /// Writes received `SessionLobbyEvent`s from the net channel to the regular event channel.
+///
+/// Chat messages are also recorded in the `SessionChatLog`, and ready flags in the
+/// `SessionDeviceReadies`.
+///
+/// Character and map selections from the session server are written to the `CharacterSelections`
+/// and `MapSelection`, so that every device loads the same game.
 #[derive(Debug, SystemDesc, new)]
 #[system_desc(name(SessionLobbyResponseSystemDesc))]
 pub struct SessionLobbyResponseSystem {
@@ -32,6 +47,67 @@ pub struct SessionLobbyResponseSystemData<'s> {
     /// `SessionStatus` resource.
     #[derivative(Debug = "ignore")]
     pub session_status: Read<'s, SessionStatus>,
+    /// `SessionTickCount` resource.
+    #[derivative(Debug = "ignore")]
+    pub session_tick_count: Write<'s, SessionTickCount>,
+    /// `SessionChatLog` resource.
+    #[derivative(Debug = "ignore")]
+    pub session_chat_log: Write<'s, SessionChatLog>,
+    /// `SessionDeviceReadies` resource.
+    #[derivative(Debug = "ignore")]
+    pub session_device_readies: Write<'s, SessionDeviceReadies>,
+    /// `AssetIdMappings` resource.
+    #[derivative(Debug = "ignore")]
+    pub asset_id_mappings: Read<'s, AssetIdMappings>,
+    /// `CharacterSelections` resource.
+    #[derivative(Debug = "ignore")]
+    pub character_selections: Write<'s, CharacterSelections>,
+    /// `MapSelection` resource.
+    #[derivative(Debug = "ignore")]
+    pub map_selection: Write<'s, MapSelection>,
+}
+
+impl SessionLobbyResponseSystem {
+    fn character_selections_update(
+        asset_id_mappings: &AssetIdMappings,
+        character_selections: &mut CharacterSelections,
+        session_character_selection_params: &SessionCharacterSelectionParams,
+    ) {
+        session_character_selection_params
+            .character_selections
+            .iter()
+            .for_each(|session_character_selection| {
+                let controller_id = session_character_selection.controller_id;
+                let asset_slug = &session_character_selection.asset_slug;
+                if let Some(asset_id) = asset_id_mappings.id(asset_slug).copied() {
+                    character_selections
+                        .selections
+                        .insert(controller_id, asset_id);
+                } else {
+                    warn!(
+                        "Received character selection `{}` for controller {}, but the asset is \
+                        not loaded.",
+                        asset_slug, controller_id
+                    );
+                }
+            });
+    }
+
+    fn map_selection_update(
+        asset_id_mappings: &AssetIdMappings,
+        map_selection: &mut MapSelection,
+        session_map_selection_params: &SessionMapSelectionParams,
+    ) {
+        let map_slug = &session_map_selection_params.map_slug;
+        if let Some(asset_id) = asset_id_mappings.id(map_slug).copied() {
+            *map_selection = MapSelection::Id(asset_id);
+        } else {
+            warn!(
+                "Received map selection `{}`, but the asset is not loaded.",
+                map_slug
+            );
+        }
+    }
 }
 
 impl<'s> System<'s> for SessionLobbyResponseSystem {
@@ -43,6 +119,12 @@ impl<'s> System<'s> for SessionLobbyResponseSystem {
             session_lobby_nec,
             mut session_lobby_ec,
             session_status,
+            mut session_tick_count,
+            mut session_chat_log,
+            mut session_device_readies,
+            asset_id_mappings,
+            mut character_selections,
+            mut map_selection,
         }: Self::SystemData,
     ) {
         let session_lobby_events = session_lobby_nec.read(&mut self.session_lobby_event_rid);
@@ -51,15 +133,63 @@ impl<'s> System<'s> for SessionLobbyResponseSystem {
         if session_status == &SessionStatus::JoinEstablished
             || session_status == &SessionStatus::HostEstablished
         {
-            session_lobby_events.for_each(|ev| {
-                if let NetData {
-                    data: SessionLobbyEvent::SessionStartNotify,
-                    ..
-                } = ev
-                {
+            session_lobby_events.for_each(|ev| match &ev.data {
+                SessionLobbyEvent::SessionStartNotify => {
                     debug!("Session start notification received.");
+                    *session_tick_count = SessionTickCount::default();
+                    session_device_readies.clear();
                     session_lobby_ec.single_write(SessionLobbyEvent::SessionStartNotify);
                 }
+                SessionLobbyEvent::SessionChatNotify(session_chat_message) => {
+                    debug!("Session chat message received: {:?}", session_chat_message);
+                    session_chat_log.push(session_chat_message.clone());
+                    session_lobby_ec.single_write(SessionLobbyEvent::SessionChatNotify(
+                        session_chat_message.clone(),
+                    ));
+                }
+                SessionLobbyEvent::SessionReadyNotify(session_device_ready) => {
+                    debug!(
+                        "Session ready notification received: {:?}",
+                        session_device_ready
+                    );
+                    session_device_readies.set(
+                        session_device_ready.session_device_id,
+                        session_device_ready.ready,
+                    );
+                    session_lobby_ec
+                        .single_write(SessionLobbyEvent::SessionReadyNotify(*session_device_ready));
+                }
+                SessionLobbyEvent::SessionCharacterSelectNotify(
+                    session_character_selection_params,
+                ) => {
+                    debug!(
+                        "Session character selections received: {:?}",
+                        session_character_selection_params
+                    );
+                    Self::character_selections_update(
+                        &asset_id_mappings,
+                        &mut character_selections,
+                        session_character_selection_params,
+                    );
+                    session_lobby_ec.single_write(SessionLobbyEvent::SessionCharacterSelectNotify(
+                        session_character_selection_params.clone(),
+                    ));
+                }
+                SessionLobbyEvent::SessionMapSelectNotify(session_map_selection_params) => {
+                    debug!(
+                        "Session map selection received: {:?}",
+                        session_map_selection_params
+                    );
+                    Self::map_selection_update(
+                        &asset_id_mappings,
+                        &mut map_selection,
+                        session_map_selection_params,
+                    );
+                    session_lobby_ec.single_write(SessionLobbyEvent::SessionMapSelectNotify(
+                        session_map_selection_params.clone(),
+                    ));
+                }
+                _ => {}
             });
         }
     }
@@ -0,0 +1,134 @@
+use amethyst::{
+    derive::SystemDesc,
+    ecs::{Read, System, World, Write},
+    shred::{ResourceId, SystemData},
+    shrev::{EventChannel, ReaderId},
+};
+use asset_model::loaded::AssetIdMappings;
+use asset_selection_model::play::AssetSelectionEvent;
+use character_selection_model::CharacterSelections;
+use derivative::Derivative;
+use derive_new::new;
+use log::debug;
+use map_selection_model::MapSelection;
+use network_session_model::play::SessionStatus;
+use session_lobby_model::{
+    play::{
+        SessionCharacterSelection, SessionCharacterSelectionParams, SessionCharacterSelections,
+        SessionMapSelectionParams,
+    },
+    SessionLobbyEvent,
+};
+use state_registry::StateId;
+
+/// Sends character and map selections to the session server when they are confirmed.
+///
+/// Map selections are only sent by the session host.
+#[derive(Debug, SystemDesc, new)]
+#[system_desc(name(SessionSelectionRequestSystemDesc))]
+pub struct SessionSelectionRequestSystem {
+    /// Reader ID for the `AssetSelectionEvent` channel.
+    #[system_desc(event_channel_reader)]
+    asset_selection_event_rid: ReaderId<AssetSelectionEvent>,
+}
+
+#[derive(Derivative, SystemData)]
+#[derivative(Debug)]
+pub struct SessionSelectionRequestSystemData<'s> {
+    /// `AssetSelectionEvent` channel.
+    #[derivative(Debug = "ignore")]
+    pub asset_selection_ec: Read<'s, EventChannel<AssetSelectionEvent>>,
+    /// `SessionStatus` resource.
+    #[derivative(Debug = "ignore")]
+    pub session_status: Read<'s, SessionStatus>,
+    /// `StateId` resource.
+    #[derivative(Debug = "ignore")]
+    pub state_id: Read<'s, StateId>,
+    /// `AssetIdMappings` resource.
+    #[derivative(Debug = "ignore")]
+    pub asset_id_mappings: Read<'s, AssetIdMappings>,
+    /// `CharacterSelections` resource.
+    #[derivative(Debug = "ignore")]
+    pub character_selections: Read<'s, CharacterSelections>,
+    /// `MapSelection` resource.
+    #[derivative(Debug = "ignore")]
+    pub map_selection: Read<'s, MapSelection>,
+    /// `SessionLobbyEvent` channel.
+    #[derivative(Debug = "ignore")]
+    pub session_lobby_ec: Write<'s, EventChannel<SessionLobbyEvent>>,
+}
+
+impl SessionSelectionRequestSystem {
+    fn session_character_selections(
+        asset_id_mappings: &AssetIdMappings,
+        character_selections: &CharacterSelections,
+    ) -> SessionCharacterSelections {
+        let mut session_character_selections = character_selections
+            .selections
+            .iter()
+            .filter_map(|(controller_id, asset_id)| {
+                asset_id_mappings
+                    .slug(*asset_id)
+                    .cloned()
+                    .map(|asset_slug| SessionCharacterSelection::new(*controller_id, asset_slug))
+            })
+            .collect::<Vec<SessionCharacterSelection>>();
+        session_character_selections.sort_by_key(|selection| selection.controller_id);
+
+        SessionCharacterSelections::new(session_character_selections)
+    }
+}
+
+impl<'s> System<'s> for SessionSelectionRequestSystem {
+    type SystemData = SessionSelectionRequestSystemData<'s>;
+
+    fn run(
+        &mut self,
+        SessionSelectionRequestSystemData {
+            asset_selection_ec,
+            session_status,
+            state_id,
+            asset_id_mappings,
+            character_selections,
+            map_selection,
+            mut session_lobby_ec,
+        }: Self::SystemData,
+    ) {
+        let confirmed = asset_selection_ec
+            .read(&mut self.asset_selection_event_rid)
+            .any(|ev| *ev == AssetSelectionEvent::Confirm);
+
+        // Guard against sending requests if the application is not in a session.
+        let session_established = *session_status == SessionStatus::JoinEstablished
+            || *session_status == SessionStatus::HostEstablished;
+        if !(confirmed && session_established) {
+            return;
+        }
+
+        match *state_id {
+            StateId::CharacterSelection => {
+                let character_selections =
+                    Self::session_character_selections(&asset_id_mappings, &character_selections);
+                debug!("Sending character selections: {:?}", character_selections);
+
+                session_lobby_ec.single_write(SessionLobbyEvent::SessionCharacterSelectRequest(
+                    SessionCharacterSelectionParams::new(character_selections),
+                ));
+            }
+            StateId::MapSelection if *session_status == SessionStatus::HostEstablished => {
+                let map_slug = map_selection
+                    .asset_id()
+                    .and_then(|asset_id| asset_id_mappings.slug(asset_id))
+                    .cloned();
+                if let Some(map_slug) = map_slug {
+                    debug!("Sending map selection: {}", map_slug);
+
+                    session_lobby_ec.single_write(SessionLobbyEvent::SessionMapSelectRequest(
+                        SessionMapSelectionParams::new(map_slug),
+                    ));
+                }
+            }
+            _ => {}
+        }
+    }
+}
@@ -0,0 +1,13 @@
+#![deny(missing_debug_implementations, missing_docs)] // kcov-ignore
+
+//! Extension to enable `session_lobby` to be controlled by stdio.
+
+pub use crate::{
+    session_chat_log_system::{SessionChatLogSystem, SessionChatLogSystemDesc},
+    session_lobby_event_stdin_mapper::SessionLobbyEventStdinMapper,
+    session_lobby_stdio_bundle::SessionLobbyStdioBundle,
+};
+
+mod session_chat_log_system;
+mod session_lobby_event_stdin_mapper;
+mod session_lobby_stdio_bundle;
@@ -0,0 +1,52 @@
+use amethyst::{
+    derive::SystemDesc,
+    ecs::{Read, System, World},
+    shred::{ResourceId, SystemData},
+    shrev::{EventChannel, ReaderId},
+};
+use derivative::Derivative;
+use derive_new::new;
+use log::info;
+use session_lobby_model::{play::SessionChatMessage, SessionLobbyEvent};
+
+/// Logs session lobby chat messages.
+#[derive(Debug, SystemDesc, new)]
+#[system_desc(name(SessionChatLogSystemDesc))]
+pub struct SessionChatLogSystem {
+    /// Reader ID for the `SessionLobbyEvent` channel.
+    #[system_desc(event_channel_reader)]
+    session_lobby_event_rid: ReaderId<SessionLobbyEvent>,
+}
+
+/// `SessionChatLogSystemData`.
+#[derive(Derivative, SystemData)]
+#[derivative(Debug)]
+pub struct SessionChatLogSystemData<'s> {
+    /// `SessionLobbyEvent` channel.
+    #[derivative(Debug = "ignore")]
+    pub session_lobby_ec: Read<'s, EventChannel<SessionLobbyEvent>>,
+}
+
+impl SessionChatLogSystem {
+    /// Returns the line to display for a `SessionChatMessage`.
+    pub fn chat_line(session_chat_message: &SessionChatMessage) -> String {
+        format!(
+            "{}: {}",
+            session_chat_message.session_device_name, session_chat_message.message
+        )
+    }
+}
+
+impl<'s> System<'s> for SessionChatLogSystem {
+    type SystemData = SessionChatLogSystemData<'s>;
+
+    fn run(&mut self, SessionChatLogSystemData { session_lobby_ec }: Self::SystemData) {
+        session_lobby_ec
+            .read(&mut self.session_lobby_event_rid)
+            .for_each(|ev| {
+                if let SessionLobbyEvent::SessionChatNotify(session_chat_message) = ev {
+                    info!("{}", Self::chat_line(session_chat_message));
+                }
+            });
+    }
+}
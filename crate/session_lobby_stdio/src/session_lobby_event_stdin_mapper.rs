@@ -0,0 +1,17 @@
+use amethyst::Error;
+use session_lobby_model::SessionLobbyEvent;
+use stdio_spi::StdinMapper;
+
+/// Builds a `SessionLobbyEvent` from stdin tokens.
+#[derive(Debug)]
+pub struct SessionLobbyEventStdinMapper;
+
+impl StdinMapper for SessionLobbyEventStdinMapper {
+    type SystemData = ();
+    type Event = SessionLobbyEvent;
+    type Args = SessionLobbyEvent;
+
+    fn map(_: &(), args: Self::Args) -> Result<Self::Event, Error> {
+        Ok(args)
+    }
+}
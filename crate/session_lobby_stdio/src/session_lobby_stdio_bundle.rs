@@ -0,0 +1,37 @@
+use std::any;
+
+use amethyst::{
+    core::{bundle::SystemBundle, SystemDesc},
+    ecs::{DispatcherBuilder, World},
+    Error,
+};
+use application_event::AppEventVariant;
+use derive_new::new;
+use stdio_spi::MapperSystem;
+
+use crate::{SessionChatLogSystem, SessionChatLogSystemDesc, SessionLobbyEventStdinMapper};
+
+/// Adds a `MapperSystem<SessionLobbyEventStdinMapper>` and `SessionChatLogSystem` to the
+/// `World`.
+#[derive(Debug, new)]
+pub struct SessionLobbyStdioBundle;
+
+impl<'a, 'b> SystemBundle<'a, 'b> for SessionLobbyStdioBundle {
+    fn build(
+        self,
+        world: &mut World,
+        builder: &mut DispatcherBuilder<'a, 'b>,
+    ) -> Result<(), Error> {
+        builder.add(
+            MapperSystem::<SessionLobbyEventStdinMapper>::new(AppEventVariant::SessionLobby),
+            any::type_name::<MapperSystem<SessionLobbyEventStdinMapper>>(),
+            &[],
+        ); // kcov-ignore
+        builder.add(
+            SessionChatLogSystemDesc::default().build(world),
+            any::type_name::<SessionChatLogSystem>(),
+            &[],
+        ); // kcov-ignore
+        Ok(())
+    }
+}
@@ -11,4 +11,7 @@ pub struct SessionLobbyUi {
     pub session_code: UiLabel,
     /// List of session devices
     pub session_devices: SessionDevicesWidget,
+    /// Attributes of the label that displays lobby chat messages.
+    #[serde(default)]
+    pub session_chat_log: Option<UiLabel>,
 }
@@ -1,8 +1,10 @@
 //! Types representing loaded configuration.
 
 pub use self::{
-    session_code_label::SessionCodeLabel, session_devices_widget::SessionDevicesWidget,
+    session_chat_log_label::SessionChatLogLabel, session_code_label::SessionCodeLabel,
+    session_devices_widget::SessionDevicesWidget,
 };
 
+mod session_chat_log_label;
 mod session_code_label;
 mod session_devices_widget;
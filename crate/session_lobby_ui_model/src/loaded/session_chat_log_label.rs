@@ -0,0 +1,36 @@
+use amethyst::{
+    ecs::{storage::NullStorage, Component, Entity, World, WriteStorage},
+    shred::{ResourceId, SystemData},
+};
+use asset_model::ItemComponent;
+use derivative::Derivative;
+
+/// Marks entities that should display the `SessionChatLog`.
+#[derive(Clone, Component, Copy, Debug, Default, PartialEq)]
+#[storage(NullStorage)]
+pub struct SessionChatLogLabel;
+
+/// `SessionChatLogLabelSystemData`.
+#[derive(Derivative, SystemData)]
+#[derivative(Debug)]
+pub struct SessionChatLogLabelSystemData<'s> {
+    /// `SessionChatLogLabel` components.
+    #[derivative(Debug = "ignore")]
+    pub session_chat_log_labels: WriteStorage<'s, SessionChatLogLabel>,
+}
+
+impl<'s> ItemComponent<'s> for SessionChatLogLabel {
+    type SystemData = SessionChatLogLabelSystemData<'s>;
+
+    fn augment(&self, system_data: &mut Self::SystemData, entity: Entity) {
+        let SessionChatLogLabelSystemData {
+            session_chat_log_labels,
+        } = system_data;
+
+        if session_chat_log_labels.get(entity).is_none() {
+            session_chat_log_labels
+                .insert(entity, SessionChatLogLabel)
+                .expect("Failed to insert `SessionChatLogLabel` component.");
+        }
+    }
+}
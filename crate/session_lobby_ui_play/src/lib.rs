@@ -3,8 +3,8 @@
 //! Provides logic to update the session lobby UI.
 
 pub use crate::system::{
-    SessionCodeLabelUpdateSystem, SessionDeviceEntityCreateDeleteSystem,
-    SessionDeviceWidgetUpdateSystem,
+    SessionChatLogLabelUpdateSystem, SessionCodeLabelUpdateSystem,
+    SessionDeviceEntityCreateDeleteSystem, SessionDeviceWidgetUpdateSystem,
 };
 
 mod system;
@@ -1,9 +1,11 @@
 pub use self::{
+    session_chat_log_label_update_system::SessionChatLogLabelUpdateSystem,
     session_code_label_update_system::SessionCodeLabelUpdateSystem,
     session_device_entity_create_delete_system::SessionDeviceEntityCreateDeleteSystem,
     session_device_widget_update_system::SessionDeviceWidgetUpdateSystem,
 };
 
+mod session_chat_log_label_update_system;
 mod session_code_label_update_system;
 mod session_device_entity_create_delete_system;
 mod session_device_widget_update_system;
@@ -0,0 +1,63 @@
+use amethyst::{
+    ecs::{Join, Read, ReadStorage, System, World, WriteStorage},
+    shred::{ResourceId, SystemData},
+    ui::UiText,
+};
+use derivative::Derivative;
+use derive_new::new;
+use session_lobby_model::play::SessionChatLog;
+use session_lobby_ui_model::loaded::SessionChatLogLabel;
+
+/// Number of most recent chat messages to display.
+const SESSION_CHAT_LOG_LINES: usize = 8;
+
+/// Updates `SessionChatLogLabel` entities' text with the most recent chat messages.
+#[derive(Debug, new)]
+pub struct SessionChatLogLabelUpdateSystem;
+
+#[derive(Derivative, SystemData)]
+#[derivative(Debug)]
+pub struct SessionChatLogLabelUpdateSystemData<'s> {
+    /// `SessionChatLog` resource.
+    #[derivative(Debug = "ignore")]
+    pub session_chat_log: Read<'s, SessionChatLog>,
+    /// `SessionChatLogLabel` components.
+    #[derivative(Debug = "ignore")]
+    pub session_chat_log_labels: ReadStorage<'s, SessionChatLogLabel>,
+    /// `UiText` components.
+    #[derivative(Debug = "ignore")]
+    pub ui_texts: WriteStorage<'s, UiText>,
+}
+
+impl<'s> System<'s> for SessionChatLogLabelUpdateSystem {
+    type SystemData = SessionChatLogLabelUpdateSystemData<'s>;
+
+    fn run(
+        &mut self,
+        SessionChatLogLabelUpdateSystemData {
+            session_chat_log,
+            session_chat_log_labels,
+            mut ui_texts,
+        }: Self::SystemData,
+    ) {
+        let line_skip = session_chat_log
+            .len()
+            .saturating_sub(SESSION_CHAT_LOG_LINES);
+        let session_chat_log_text = session_chat_log
+            .iter()
+            .skip(line_skip)
+            .map(|session_chat_message| {
+                format!(
+                    "{}: {}",
+                    session_chat_message.session_device_name, session_chat_message.message
+                )
+            })
+            .collect::<Vec<String>>()
+            .join("\n");
+
+        (&session_chat_log_labels, &mut ui_texts)
+            .join()
+            .filter(|(_, ui_text)| ui_text.text != session_chat_log_text)
+            .for_each(|(_, ui_text)| ui_text.text = session_chat_log_text.clone());
+    }
+}
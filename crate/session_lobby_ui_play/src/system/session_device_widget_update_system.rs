@@ -5,10 +5,14 @@ use amethyst::{
 };
 use derivative::Derivative;
 use derive_new::new;
-use network_session_model::play::SessionDevices;
+use network_session_model::play::{SessionDeviceLatencies, SessionDevices};
+use session_lobby_model::play::SessionDeviceReadies;
 use session_lobby_ui_model::play::{SessionDeviceWidget, SessionDevicesEntities};
 
 /// Updates the text in each `SessionDeviceWidget` with `SessionDevice` ID and name.
+///
+/// Devices that are ready to start the session have their name marked as ready, and the round
+/// trip time and jitter of each device is shown once it has been measured.
 #[derive(Debug, new)]
 pub struct SessionDeviceWidgetUpdateSystem;
 
@@ -21,6 +25,12 @@ pub struct SessionDeviceWidgetUpdateSystemData<'s> {
     /// `SessionDevices` resource.
     #[derivative(Debug = "ignore")]
     pub session_devices: Read<'s, SessionDevices>,
+    /// `SessionDeviceReadies` resource.
+    #[derivative(Debug = "ignore")]
+    pub session_device_readies: Read<'s, SessionDeviceReadies>,
+    /// `SessionDeviceLatencies` resource.
+    #[derivative(Debug = "ignore")]
+    pub session_device_latencies: Read<'s, SessionDeviceLatencies>,
     /// `SessionDeviceWidget` components.
     #[derivative(Debug = "ignore")]
     pub session_device_widgets: ReadStorage<'s, SessionDeviceWidget>,
@@ -37,6 +47,8 @@ impl<'s> System<'s> for SessionDeviceWidgetUpdateSystem {
         SessionDeviceWidgetUpdateSystemData {
             session_devices_entities,
             session_devices,
+            session_device_readies,
+            session_device_latencies,
             session_device_widgets,
             mut ui_texts,
         }: Self::SystemData,
@@ -69,8 +81,21 @@ impl<'s> System<'s> for SessionDeviceWidgetUpdateSystem {
                             ui_text_id.text = format!("#{}", session_device.id);
                         }
                         if let Some(ui_text_name) = ui_texts.get_mut(entity_name) {
-                            if ui_text_name.text != session_device.name.0 {
-                                ui_text_name.text = session_device.name.0.clone();
+                            let mut name = session_device.name.0.clone();
+                            if session_device_readies.contains(&session_device.id) {
+                                name.push_str(" (ready)");
+                            }
+                            if let Some(session_latency) =
+                                session_device_latencies.get(&session_device.id)
+                            {
+                                name.push_str(&format!(
+                                    " {} ms (jitter {} ms)",
+                                    session_latency.rtt.as_millis(),
+                                    session_latency.jitter.as_millis()
+                                ));
+                            }
+                            if ui_text_name.text != name {
+                                ui_text_name.text = name;
                             }
                         }
                     }
@@ -34,6 +34,8 @@ pub enum Volume {
         /// * X axis: Use the X offset pixel coordinate.
         /// * Y axis: Use the Y offset pixel coordinate.
         /// * Z axis: Use 0.
+        ///
+        /// The cross section of the cylinder is centered on the object's position.
         center: i32,
         /// Radius of the cylinder.
         r: u32,
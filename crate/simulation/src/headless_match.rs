@@ -0,0 +1,13 @@
+use amethyst::ecs::World;
+use game_play_model::play::GamePlaySeed;
+
+/// Sets up a match that runs without user interaction, and reports on it when it ends.
+pub trait HeadlessMatch {
+    /// Inserts the game mode and selections for the match into the `World`.
+    ///
+    /// Returns the seed to play the match with, or `None` to use a random seed.
+    fn selections_insert(world: &mut World) -> Result<Option<GamePlaySeed>, String>;
+
+    /// Reports on the match after game play has ended.
+    fn report(world: &World);
+}
@@ -0,0 +1,63 @@
+use std::marker::PhantomData;
+
+use amethyst::{GameData, State, StateData, Trans};
+use application_event::AppEvent;
+use derivative::Derivative;
+use derive_new::new;
+use game_loading::GameLoadingState;
+use game_play::GamePlayState;
+use log::error;
+
+use crate::HeadlessMatch;
+
+/// `State` that runs a single match without user interaction, then quits.
+///
+/// The match is set up and reported on by the `HeadlessMatch` type parameter.
+#[derive(Derivative, new)]
+#[derivative(Debug(bound = ""), Default(bound = ""))]
+pub struct HeadlessMatchState<M>
+where
+    M: HeadlessMatch,
+{
+    /// Whether the match has been started.
+    #[new(default)]
+    started: bool,
+    /// Marker.
+    #[new(default)]
+    marker: PhantomData<M>,
+}
+
+impl<M> State<GameData<'static, 'static>, AppEvent> for HeadlessMatchState<M>
+where
+    M: HeadlessMatch,
+{
+    fn update(
+        &mut self,
+        data: StateData<'_, GameData<'static, 'static>>,
+    ) -> Trans<GameData<'static, 'static>, AppEvent> {
+        data.data.update(&data.world);
+
+        if self.started {
+            // Game play has ended.
+            M::report(data.world);
+            return Trans::Quit;
+        }
+
+        self.started = true;
+        match M::selections_insert(data.world) {
+            Ok(game_play_seed) => {
+                let game_play_fn = move || {
+                    let game_play_state = game_play_seed
+                        .map(GamePlayState::new_seeded)
+                        .unwrap_or_else(GamePlayState::new);
+                    Box::new(game_play_state)
+                };
+                Trans::Push(Box::new(GameLoadingState::new(game_play_fn)))
+            }
+            Err(e) => {
+                error!("Failed to start match: {}", e);
+                Trans::Quit
+            }
+        }
+    }
+}
@@ -0,0 +1,18 @@
+#![deny(missing_debug_implementations, missing_docs)] // kcov-ignore
+
+//! Runs matches to completion without user interaction, and reports the result.
+
+pub use crate::{
+    headless_match::HeadlessMatch,
+    headless_match_state::HeadlessMatchState,
+    simulation_match::{SimulationMatch, SimulationState},
+    system::{
+        SimulationProgressSystem, SimulationProgressSystemData, SimulationScriptSystem,
+        SimulationScriptSystemData,
+    },
+};
+
+mod headless_match;
+mod headless_match_state;
+mod simulation_match;
+mod system;
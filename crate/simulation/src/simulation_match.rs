@@ -0,0 +1,75 @@
+use amethyst::ecs::{World, WorldExt};
+use asset_model::{config::AssetSlug, loaded::AssetIdMappings};
+use character_selection_model::{CharacterSelections, CharacterSelectionsStatus};
+use game_play_model::{play::GamePlaySeed, GameMode};
+use log::error;
+use map_selection_model::MapSelection;
+use simulation_model::{
+    config::{Simulation, SimulationController},
+    play::SimulationProgress,
+};
+
+use crate::{HeadlessMatch, HeadlessMatchState};
+
+/// `State` that runs a match without user interaction, then prints the result as JSON.
+pub type SimulationState = HeadlessMatchState<SimulationMatch>;
+
+/// Sets up the match from the `Simulation` resource, and prints the result as JSON.
+#[derive(Debug)]
+pub struct SimulationMatch;
+
+impl HeadlessMatch for SimulationMatch {
+    fn selections_insert(world: &mut World) -> Result<Option<GamePlaySeed>, String> {
+        let (character_selections, map_selection, game_play_seed) = {
+            let simulation = world
+                .try_fetch::<Simulation>()
+                .ok_or_else(|| String::from("`Simulation` resource is not present."))?;
+            let asset_id_mappings = world.read_resource::<AssetIdMappings>();
+            let asset_id = |asset_slug: &AssetSlug| {
+                asset_id_mappings
+                    .id(asset_slug)
+                    .copied()
+                    .ok_or_else(|| format!("Asset `{}` is not loaded.", asset_slug))
+            };
+
+            let mut character_selections = CharacterSelections::default();
+            simulation.characters.iter().enumerate().try_for_each(
+                |(controller_id, simulation_character)| {
+                    let asset_id = asset_id(&simulation_character.character)?;
+                    character_selections
+                        .selections
+                        .insert(controller_id, asset_id);
+                    if let SimulationController::Ai(difficulty) = simulation_character.controller {
+                        character_selections
+                            .cpu_selections
+                            .insert(controller_id, difficulty);
+                    }
+                    Ok::<(), String>(())
+                },
+            )?;
+            let map_selection = MapSelection::Id(asset_id(&simulation.map)?);
+
+            (character_selections, map_selection, simulation.seed)
+        };
+
+        world.insert(GameMode::Versus);
+        world.insert(character_selections);
+        world.insert(CharacterSelectionsStatus::Ready);
+        world.insert(map_selection);
+        world.insert(SimulationProgress::default());
+
+        Ok(game_play_seed)
+    }
+
+    fn report(world: &World) {
+        let simulation_progress = world.read_resource::<SimulationProgress>();
+        if let Some(simulation_result) = simulation_progress.result.as_ref() {
+            match serde_json::to_string(simulation_result) {
+                Ok(simulation_result_json) => println!("{}", simulation_result_json),
+                Err(e) => error!("Failed to serialize simulation result: {}", e),
+            }
+        } else {
+            error!("Simulation ended without a result.");
+        }
+    }
+}
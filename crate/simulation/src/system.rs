@@ -0,0 +1,7 @@
+pub use self::{
+    simulation_progress_system::{SimulationProgressSystem, SimulationProgressSystemData},
+    simulation_script_system::{SimulationScriptSystem, SimulationScriptSystemData},
+};
+
+mod simulation_progress_system;
+mod simulation_script_system;
@@ -0,0 +1,153 @@
+use ai_model::play::AiControlled;
+use amethyst::{
+    ecs::{Join, Read, ReadStorage, System, World, Write},
+    shred::{ResourceId, SystemData},
+    shrev::EventChannel,
+};
+use asset_model::loaded::{AssetId, AssetIdMappings};
+use derivative::Derivative;
+use derive_new::new;
+use game_input_model::play::InputControlled;
+use game_play_model::{play::GamePlaySeed, GamePlayEvent, GamePlayStatus};
+use game_stats_model::play::{DamageDealt, WinStatus};
+use log::info;
+use object_model::play::HealthPoints;
+use simulation_model::{
+    config::Simulation,
+    play::{SimulationCharacterResult, SimulationProgress, SimulationResult},
+};
+use state_registry::StateId;
+
+/// Counts simulated ticks, and ends game play when the match ends or the tick limit is reached.
+///
+/// The `SimulationResult` is stored in the `SimulationProgress` before the game play entities are
+/// deleted.
+#[derive(Debug, Default, new)]
+pub struct SimulationProgressSystem;
+
+/// `SimulationProgressSystemData`.
+#[derive(Derivative, SystemData)]
+#[derivative(Debug)]
+pub struct SimulationProgressSystemData<'s> {
+    /// `StateId` resource.
+    #[derivative(Debug = "ignore")]
+    pub state_id: Read<'s, StateId>,
+    /// `GamePlayStatus` resource.
+    #[derivative(Debug = "ignore")]
+    pub game_play_status: Read<'s, GamePlayStatus>,
+    /// `Simulation` resource.
+    #[derivative(Debug = "ignore")]
+    pub simulation: Option<Read<'s, Simulation>>,
+    /// `SimulationProgress` resource.
+    #[derivative(Debug = "ignore")]
+    pub simulation_progress: Write<'s, SimulationProgress>,
+    /// `GamePlaySeed` resource.
+    #[derivative(Debug = "ignore")]
+    pub game_play_seed: Read<'s, GamePlaySeed>,
+    /// `WinStatus` resource.
+    #[derivative(Debug = "ignore")]
+    pub win_status: Read<'s, WinStatus>,
+    /// `AssetIdMappings` resource.
+    #[derivative(Debug = "ignore")]
+    pub asset_id_mappings: Read<'s, AssetIdMappings>,
+    /// `AssetId` components.
+    #[derivative(Debug = "ignore")]
+    pub asset_ids: ReadStorage<'s, AssetId>,
+    /// `InputControlled` components.
+    #[derivative(Debug = "ignore")]
+    pub input_controlleds: ReadStorage<'s, InputControlled>,
+    /// `AiControlled` components.
+    #[derivative(Debug = "ignore")]
+    pub ai_controlleds: ReadStorage<'s, AiControlled>,
+    /// `HealthPoints` components.
+    #[derivative(Debug = "ignore")]
+    pub health_pointses: ReadStorage<'s, HealthPoints>,
+    /// `DamageDealt` components.
+    #[derivative(Debug = "ignore")]
+    pub damage_dealts: ReadStorage<'s, DamageDealt>,
+    /// `GamePlayEvent` channel.
+    #[derivative(Debug = "ignore")]
+    pub game_play_ec: Write<'s, EventChannel<GamePlayEvent>>,
+}
+
+impl SimulationProgressSystem {
+    /// Returns the result for each character, in controller order.
+    fn character_results(
+        SimulationProgressSystemData {
+            asset_id_mappings,
+            asset_ids,
+            input_controlleds,
+            ai_controlleds,
+            health_pointses,
+            damage_dealts,
+            ..
+        }: &SimulationProgressSystemData<'_>,
+    ) -> Vec<SimulationCharacterResult> {
+        let mut character_results = (
+            asset_ids,
+            health_pointses,
+            damage_dealts.maybe(),
+            input_controlleds.maybe(),
+            ai_controlleds.maybe(),
+        )
+            .join()
+            .filter_map(
+                |(asset_id, health_points, damage_dealt, input_controlled, ai_controlled)| {
+                    let controller_id = input_controlled
+                        .map(|input_controlled| input_controlled.controller_id)
+                        .or_else(|| {
+                            ai_controlled.map(|ai_controlled| ai_controlled.controller_id)
+                        })?;
+                    let character = asset_id_mappings.slug(*asset_id)?.clone();
+                    let damage_dealt = damage_dealt.copied().unwrap_or_default();
+
+                    Some(SimulationCharacterResult::new(
+                        controller_id,
+                        character,
+                        health_points.0,
+                        damage_dealt.0,
+                    ))
+                },
+            )
+            .collect::<Vec<SimulationCharacterResult>>();
+        character_results.sort_by_key(|character_result| character_result.controller_id);
+        character_results
+    }
+}
+
+impl<'s> System<'s> for SimulationProgressSystem {
+    type SystemData = SimulationProgressSystemData<'s>;
+
+    fn run(&mut self, mut system_data: Self::SystemData) {
+        if *system_data.state_id != StateId::GamePlay
+            || system_data.simulation_progress.result.is_some()
+        {
+            return;
+        }
+        let tick_limit = if let Some(simulation) = system_data.simulation.as_ref() {
+            simulation.tick_limit
+        } else {
+            return;
+        };
+
+        if *system_data.game_play_status == GamePlayStatus::Playing {
+            system_data.simulation_progress.tick += 1;
+        }
+
+        let tick = system_data.simulation_progress.tick;
+        let match_ended = *system_data.game_play_status == GamePlayStatus::Ended;
+        if match_ended || tick >= tick_limit {
+            let character_results = Self::character_results(&system_data);
+            let simulation_result = SimulationResult::new(
+                system_data.win_status.outcome,
+                tick,
+                *system_data.game_play_seed,
+                character_results,
+            );
+
+            info!("Simulation ended at tick {}.", tick);
+            system_data.simulation_progress.result = Some(simulation_result);
+            system_data.game_play_ec.single_write(GamePlayEvent::Return);
+        }
+    }
+}
@@ -0,0 +1,72 @@
+use amethyst::{
+    ecs::{Entities, Join, Read, ReadStorage, System, World, Write},
+    shred::{ResourceId, SystemData},
+    shrev::EventChannel,
+};
+use derivative::Derivative;
+use derive_new::new;
+use game_input_model::play::{ControlInputEvent, InputControlled};
+use game_play_model::GamePlayStatus;
+use simulation_model::play::{SimulationProgress, SimulationScripts};
+
+/// Sends `ControlInputEvent`s for scripted characters.
+#[derive(Debug, Default, new)]
+pub struct SimulationScriptSystem;
+
+/// `SimulationScriptSystemData`.
+#[derive(Derivative, SystemData)]
+#[derivative(Debug)]
+pub struct SimulationScriptSystemData<'s> {
+    /// `Entities`.
+    #[derivative(Debug = "ignore")]
+    pub entities: Entities<'s>,
+    /// `GamePlayStatus` resource.
+    #[derivative(Debug = "ignore")]
+    pub game_play_status: Read<'s, GamePlayStatus>,
+    /// `SimulationProgress` resource.
+    #[derivative(Debug = "ignore")]
+    pub simulation_progress: Read<'s, SimulationProgress>,
+    /// `SimulationScripts` resource.
+    #[derivative(Debug = "ignore")]
+    pub simulation_scripts: Read<'s, SimulationScripts>,
+    /// `InputControlled` components.
+    #[derivative(Debug = "ignore")]
+    pub input_controlleds: ReadStorage<'s, InputControlled>,
+    /// `ControlInputEvent` channel.
+    #[derivative(Debug = "ignore")]
+    pub control_input_ec: Write<'s, EventChannel<ControlInputEvent>>,
+}
+
+impl<'s> System<'s> for SimulationScriptSystem {
+    type SystemData = SimulationScriptSystemData<'s>;
+
+    fn run(
+        &mut self,
+        SimulationScriptSystemData {
+            entities,
+            game_play_status,
+            simulation_progress,
+            simulation_scripts,
+            input_controlleds,
+            mut control_input_ec,
+        }: Self::SystemData,
+    ) {
+        if *game_play_status != GamePlayStatus::Playing || simulation_scripts.is_empty() {
+            return;
+        }
+
+        let tick = simulation_progress.tick;
+        (&entities, &input_controlleds)
+            .join()
+            .for_each(|(entity, input_controlled)| {
+                let controller_id = input_controlled.controller_id;
+                if let Some(simulation_script) = simulation_scripts.get(&controller_id) {
+                    control_input_ec.iter_write(simulation_script.events(tick).map(
+                        |simulation_script_event| {
+                            simulation_script_event.control_input_event(controller_id, entity)
+                        },
+                    ));
+                }
+            });
+    }
+}
@@ -0,0 +1,16 @@
+//! Contains data types for simulation parameters.
+
+pub use self::{
+    simulation::Simulation, simulation_arg_error::SimulationArgError,
+    simulation_character::SimulationCharacter, simulation_controller::SimulationController,
+    simulation_script::SimulationScript, simulation_script_event::SimulationScriptEvent,
+    simulation_script_tick::SimulationScriptTick,
+};
+
+mod simulation;
+mod simulation_arg_error;
+mod simulation_character;
+mod simulation_controller;
+mod simulation_script;
+mod simulation_script_event;
+mod simulation_script_tick;
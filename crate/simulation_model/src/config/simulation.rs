@@ -0,0 +1,18 @@
+use asset_model::config::AssetSlug;
+use derive_new::new;
+use game_play_model::play::GamePlaySeed;
+
+use crate::config::SimulationCharacter;
+
+/// Match to simulate without user interaction.
+#[derive(Clone, Debug, PartialEq, new)]
+pub struct Simulation {
+    /// Characters in the match, in controller order.
+    pub characters: Vec<SimulationCharacter>,
+    /// Map to play the match on.
+    pub map: AssetSlug,
+    /// Maximum number of ticks to run the match for.
+    pub tick_limit: u32,
+    /// Seed to run the match with, or `None` to use a random seed.
+    pub seed: Option<GamePlaySeed>,
+}
@@ -0,0 +1,37 @@
+use std::{error::Error, fmt};
+
+use asset_model::config::AssetSlugBuildError;
+
+/// Error when parsing simulation parameters from the command line.
+#[derive(Clone, Debug, PartialEq)]
+pub enum SimulationArgError {
+    /// The character slug is invalid.
+    CharacterInvalid {
+        /// Value that was provided.
+        value: String,
+        /// Reason the slug is invalid.
+        error: AssetSlugBuildError,
+    },
+    /// The controller is not `ai`, `ai:<difficulty>`, or `script:<path>`.
+    ControllerInvalid {
+        /// Value that was provided.
+        value: String,
+    },
+}
+
+impl fmt::Display for SimulationArgError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SimulationArgError::CharacterInvalid { value, error } => {
+                write!(f, "Invalid character `{}`: {}", value, error)
+            }
+            SimulationArgError::ControllerInvalid { value } => write!(
+                f,
+                "Invalid controller `{}`, expected `ai`, `ai:<difficulty>`, or `script:<path>`.",
+                value
+            ),
+        }
+    }
+}
+
+impl Error for SimulationArgError {}
@@ -0,0 +1,39 @@
+use std::str::FromStr;
+
+use asset_model::config::AssetSlug;
+use derive_new::new;
+
+use crate::config::{SimulationArgError, SimulationController};
+
+/// Character to simulate, and how it is controlled.
+///
+/// This is parsed from `<slug>` or `<slug>=<controller>`, for example `default/hero=ai:hard`.
+#[derive(Clone, Debug, PartialEq, new)]
+pub struct SimulationCharacter {
+    /// Slug of the character asset.
+    pub character: AssetSlug,
+    /// How the character is controlled.
+    pub controller: SimulationController,
+}
+
+impl FromStr for SimulationCharacter {
+    type Err = SimulationArgError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut segments = s.splitn(2, '=');
+        let character = segments.next().unwrap_or_default();
+        let character = AssetSlug::from_str(character).map_err(|error| {
+            SimulationArgError::CharacterInvalid {
+                value: character.to_string(),
+                error,
+            }
+        })?;
+        let controller = segments
+            .next()
+            .map(SimulationController::from_str)
+            .transpose()?
+            .unwrap_or_default();
+
+        Ok(SimulationCharacter::new(character, controller))
+    }
+}
@@ -0,0 +1,39 @@
+use std::{path::PathBuf, str::FromStr};
+
+use ai_model::config::AiDifficulty;
+use derivative::Derivative;
+
+use crate::config::SimulationArgError;
+
+/// How a simulated character is controlled.
+#[derive(Clone, Debug, Derivative, PartialEq)]
+#[derivative(Default)]
+pub enum SimulationController {
+    /// Controlled by the computer.
+    #[derivative(Default)]
+    Ai(AiDifficulty),
+    /// Sends the input in a `SimulationScript` file.
+    Scripted(PathBuf),
+}
+
+impl FromStr for SimulationController {
+    type Err = SimulationArgError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut segments = s.splitn(2, ':');
+        match (segments.next(), segments.next()) {
+            (Some("ai"), None) => Ok(SimulationController::Ai(AiDifficulty::default())),
+            (Some("ai"), Some(difficulty)) => AiDifficulty::from_str(difficulty)
+                .map(SimulationController::Ai)
+                .map_err(|_| SimulationArgError::ControllerInvalid {
+                    value: s.to_string(),
+                }),
+            (Some("script"), Some(path)) if !path.is_empty() => {
+                Ok(SimulationController::Scripted(PathBuf::from(path)))
+            }
+            _ => Err(SimulationArgError::ControllerInvalid {
+                value: s.to_string(),
+            }),
+        }
+    }
+}
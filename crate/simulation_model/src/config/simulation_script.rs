@@ -0,0 +1,36 @@
+use serde::{Deserialize, Serialize};
+
+use crate::config::{SimulationScriptEvent, SimulationScriptTick};
+
+/// Input to send for a scripted character.
+///
+/// # Examples
+///
+/// ```yaml
+/// - tick: 0
+///   events:
+///     - axis_moved: { axis: "x", value: 1.0 }
+/// - tick: 30
+///   events:
+///     - axis_moved: { axis: "x", value: 0.0 }
+///     - control_action_press: { control_action: "attack" }
+/// - tick: 31
+///   events:
+///     - control_action_release: { control_action: "attack" }
+/// ```
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Serialize)]
+#[serde(transparent)]
+pub struct SimulationScript {
+    /// Input events for each tick that has input.
+    pub ticks: Vec<SimulationScriptTick>,
+}
+
+impl SimulationScript {
+    /// Returns the input events to send on the given tick.
+    pub fn events(&self, tick: u32) -> impl Iterator<Item = SimulationScriptEvent> + '_ {
+        self.ticks
+            .iter()
+            .filter(move |script_tick| script_tick.tick == tick)
+            .flat_map(|script_tick| script_tick.events.iter().copied())
+    }
+}
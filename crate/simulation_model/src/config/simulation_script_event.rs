@@ -0,0 +1,63 @@
+use amethyst::ecs::Entity;
+use game_input_model::{
+    config::{Axis, ControlAction, ControllerId},
+    play::{AxisMoveEventData, ControlActionEventData, ControlInputEvent},
+};
+use serde::{Deserialize, Serialize};
+
+/// Input event for a scripted character.
+#[derive(Clone, Copy, Debug, Deserialize, PartialEq, Serialize)]
+#[serde(deny_unknown_fields, rename_all = "snake_case")]
+pub enum SimulationScriptEvent {
+    /// `Axis` value has changed.
+    AxisMoved {
+        /// `Axis` whose value changed.
+        axis: Axis,
+        /// New value for the axis input.
+        value: f32,
+    },
+    /// `ControlAction` has been pressed.
+    ControlActionPress {
+        /// `ControlAction` that was pressed.
+        control_action: ControlAction,
+    },
+    /// `ControlAction` has been released.
+    ControlActionRelease {
+        /// `ControlAction` that was released.
+        control_action: ControlAction,
+    },
+}
+
+impl SimulationScriptEvent {
+    /// Returns the `ControlInputEvent` for this event, for the given controlled entity.
+    pub fn control_input_event(
+        self,
+        controller_id: ControllerId,
+        entity: Entity,
+    ) -> ControlInputEvent {
+        match self {
+            SimulationScriptEvent::AxisMoved { axis, value } => {
+                ControlInputEvent::AxisMoved(AxisMoveEventData {
+                    controller_id,
+                    entity,
+                    axis,
+                    value,
+                })
+            }
+            SimulationScriptEvent::ControlActionPress { control_action } => {
+                ControlInputEvent::ControlActionPress(ControlActionEventData {
+                    controller_id,
+                    entity,
+                    control_action,
+                })
+            }
+            SimulationScriptEvent::ControlActionRelease { control_action } => {
+                ControlInputEvent::ControlActionRelease(ControlActionEventData {
+                    controller_id,
+                    entity,
+                    control_action,
+                })
+            }
+        }
+    }
+}
@@ -0,0 +1,14 @@
+use derive_new::new;
+use serde::{Deserialize, Serialize};
+
+use crate::config::SimulationScriptEvent;
+
+/// Input events to send on a particular tick.
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize, new)]
+#[serde(deny_unknown_fields)]
+pub struct SimulationScriptTick {
+    /// Tick of game play that the events are sent on.
+    pub tick: u32,
+    /// Events to send.
+    pub events: Vec<SimulationScriptEvent>,
+}
@@ -0,0 +1,6 @@
+#![deny(missing_debug_implementations, missing_docs)] // kcov-ignore
+
+//! Types used to simulate matches without user interaction.
+
+pub mod config;
+pub mod play;
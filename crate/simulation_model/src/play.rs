@@ -0,0 +1,12 @@
+//! Contains data types used at runtime.
+
+pub use self::{
+    simulation_character_result::SimulationCharacterResult,
+    simulation_progress::SimulationProgress, simulation_result::SimulationResult,
+    simulation_scripts::SimulationScripts,
+};
+
+mod simulation_character_result;
+mod simulation_progress;
+mod simulation_result;
+mod simulation_scripts;
@@ -0,0 +1,21 @@
+use asset_model::config::AssetSlug;
+use derive_new::new;
+use game_input_model::config::ControllerId;
+use serde::{Deserialize, Serialize};
+
+/// Result of a simulated match for one character.
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize, new)]
+pub struct SimulationCharacterResult {
+    /// ID of the controller slot for the character.
+    pub controller_id: ControllerId,
+    /// Slug of the character asset.
+    #[serde(
+        serialize_with = "AssetSlug::serialize_str",
+        deserialize_with = "AssetSlug::deserialize_str"
+    )]
+    pub character: AssetSlug,
+    /// Health points remaining at the end of the match.
+    pub health_points: u32,
+    /// Health points of damage dealt to other objects.
+    pub damage_dealt: u32,
+}
@@ -0,0 +1,14 @@
+use derive_new::new;
+
+use crate::play::SimulationResult;
+
+/// Progress of the match being simulated.
+#[derive(Clone, Debug, Default, PartialEq, new)]
+pub struct SimulationProgress {
+    /// Number of game play ticks that have been simulated.
+    #[new(default)]
+    pub tick: u32,
+    /// Result of the match, once it has ended.
+    #[new(default)]
+    pub result: Option<SimulationResult>,
+}
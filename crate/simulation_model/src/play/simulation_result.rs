@@ -0,0 +1,22 @@
+use derive_new::new;
+use game_play_model::play::GamePlaySeed;
+use game_stats_model::play::WinOutcome;
+use serde::{Deserialize, Serialize};
+
+use crate::play::SimulationCharacterResult;
+
+/// Result of a simulated match.
+///
+/// The `win_outcome` is `none` when the tick limit is reached before the match ends. The `seed`
+/// is included so that the match can be run again with `--simulate_seed`.
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize, new)]
+pub struct SimulationResult {
+    /// Outcome of the match.
+    pub win_outcome: WinOutcome,
+    /// Number of ticks that the match ran for.
+    pub tick_count: u32,
+    /// Seed that the match was run with.
+    pub seed: GamePlaySeed,
+    /// Result for each character, in controller order.
+    pub characters: Vec<SimulationCharacterResult>,
+}
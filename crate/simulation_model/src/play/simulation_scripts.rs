@@ -0,0 +1,11 @@
+use std::collections::HashMap;
+
+use derive_deref::{Deref, DerefMut};
+use derive_new::new;
+use game_input_model::config::ControllerId;
+
+use crate::config::SimulationScript;
+
+/// Scripts for scripted characters (`HashMap<ControllerId, SimulationScript>` newtype).
+#[derive(Clone, Debug, Default, Deref, DerefMut, PartialEq, new)]
+pub struct SimulationScripts(pub HashMap<ControllerId, SimulationScript>);
@@ -0,0 +1,5 @@
+#![deny(missing_debug_implementations, missing_docs)] // kcov-ignore
+
+//! Types used to capture and restore the state of game play entities.
+
+pub mod play;
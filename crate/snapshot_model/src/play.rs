@@ -0,0 +1,17 @@
+//! Contains data types used at runtime.
+
+pub use self::{
+    deferred_delete::DeferredDelete, delete_mode::DeleteMode,
+    snapshot_component::SnapshotComponent, snapshot_entity::SnapshotEntity,
+    snapshot_error::SnapshotError, snapshot_registry::SnapshotRegistry,
+    storage_snapshot_data::StorageSnapshotData, world_snapshot_data::WorldSnapshotData,
+};
+
+mod deferred_delete;
+mod delete_mode;
+mod snapshot_component;
+mod snapshot_entity;
+mod snapshot_error;
+mod snapshot_registry;
+mod storage_snapshot_data;
+mod world_snapshot_data;
@@ -0,0 +1,14 @@
+use amethyst::ecs::{storage::DenseVecStorage, Component};
+use derive_new::new;
+use serde::{Deserialize, Serialize};
+
+/// Marks an entity that has been deleted, but is kept alive so that it may be restored.
+///
+/// While the `DeleteMode` is `Deferred`, deleted entities are given this component instead, and
+/// their snapshot components are removed. The entity is only deleted once no snapshot from before
+/// its deletion may be restored.
+#[derive(Clone, Component, Copy, Debug, Default, Deserialize, PartialEq, Serialize, new)]
+pub struct DeferredDelete {
+    /// Number of ticks that have been simulated since the entity was deleted.
+    pub ticks: u32,
+}
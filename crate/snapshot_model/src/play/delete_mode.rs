@@ -0,0 +1,49 @@
+use amethyst::ecs::{error::Error, world::EntitiesRes, Entity, WriteStorage};
+
+use crate::play::DeferredDelete;
+
+/// Whether entities are deleted immediately, or kept until they can no longer be restored.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum DeleteMode {
+    /// Entities are deleted immediately.
+    Immediate,
+    /// Entities are marked with `DeferredDelete`, as a snapshot from before their deletion may be
+    /// restored.
+    Deferred,
+}
+
+impl DeleteMode {
+    /// Deletes the entity, or marks it with `DeferredDelete` if deletions are deferred.
+    ///
+    /// # Parameters
+    ///
+    /// * `entities`: `Entities` resource.
+    /// * `deferred_deletes`: `DeferredDelete` components.
+    /// * `entity`: Entity to delete.
+    pub fn delete(
+        self,
+        entities: &EntitiesRes,
+        deferred_deletes: &mut WriteStorage<'_, DeferredDelete>,
+        entity: Entity,
+    ) -> Result<(), Error> {
+        match self {
+            DeleteMode::Immediate => entities.delete(entity).map_err(Error::WrongGeneration),
+            DeleteMode::Deferred => {
+                // Deleting an entity again does not restart its deferral.
+                if deferred_deletes.contains(entity) {
+                    Ok(())
+                } else {
+                    deferred_deletes
+                        .insert(entity, DeferredDelete::default())
+                        .map(|_| ())
+                }
+            }
+        }
+    }
+}
+
+impl Default for DeleteMode {
+    fn default() -> Self {
+        Self::Immediate
+    }
+}
@@ -0,0 +1,19 @@
+use amethyst::ecs::{world::EntitiesRes, Component};
+use serde::{de::DeserializeOwned, Serialize};
+
+/// Component that is converted to a different form when captured in a snapshot.
+///
+/// This is implemented for components that reference other entities, as `Entity`s must be resolved
+/// against the `World` when the snapshot is restored.
+pub trait SnapshotComponent: Component + Sized {
+    /// Serializable form of the component.
+    type Data: Serialize + DeserializeOwned;
+
+    /// Returns the serializable form of this component.
+    fn to_snapshot(&self) -> Self::Data;
+
+    /// Returns the component from its serializable form.
+    ///
+    /// Returns `None` if an entity that the component references is no longer alive.
+    fn from_snapshot(data: Self::Data, entities: &EntitiesRes) -> Option<Self>;
+}
@@ -0,0 +1,33 @@
+use amethyst::ecs::{world::EntitiesRes, Entity};
+use derive_new::new;
+use serde::{Deserialize, Serialize};
+
+/// Serializable reference to an `Entity`.
+///
+/// This stores the entity's index and generation, so that a reference is only resolved if the
+/// same entity is still alive when the snapshot is restored.
+#[derive(Clone, Copy, Debug, Deserialize, Hash, PartialEq, Eq, PartialOrd, Ord, Serialize, new)]
+pub struct SnapshotEntity {
+    /// Index of the entity.
+    pub id: u32,
+    /// Generation of the entity.
+    pub gen: i32,
+}
+
+impl SnapshotEntity {
+    /// Returns the `Entity` this refers to, if it is still alive.
+    pub fn entity(self, entities: &EntitiesRes) -> Option<Entity> {
+        let entity = entities.entity(self.id);
+        if entity.gen().id() == self.gen && entities.is_alive(entity) {
+            Some(entity)
+        } else {
+            None
+        }
+    }
+}
+
+impl From<Entity> for SnapshotEntity {
+    fn from(entity: Entity) -> Self {
+        SnapshotEntity::new(entity.id(), entity.gen().id())
+    }
+}
@@ -0,0 +1,41 @@
+use std::{
+    error::Error,
+    fmt::{self, Display, Formatter},
+};
+
+/// Errors when capturing or restoring a snapshot.
+#[derive(Debug)]
+pub enum SnapshotError {
+    /// Failed to serialize the snapshot.
+    Serialize(bincode::Error),
+    /// Failed to deserialize the snapshot.
+    Deserialize(bincode::Error),
+    /// Snapshot does not contain data for a registered component storage.
+    StorageMissing {
+        /// Key that the component storage is registered with.
+        key: String,
+    },
+}
+
+impl Display for SnapshotError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Serialize(error) => write!(f, "Failed to serialize snapshot: {}", error),
+            Self::Deserialize(error) => write!(f, "Failed to deserialize snapshot: {}", error),
+            Self::StorageMissing { key } => write!(
+                f,
+                "Snapshot does not contain data for component storage: `{}`.",
+                key
+            ),
+        }
+    }
+}
+
+impl Error for SnapshotError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            Self::Serialize(error) | Self::Deserialize(error) => Some(error.as_ref()),
+            Self::StorageMissing { .. } => None,
+        }
+    }
+}
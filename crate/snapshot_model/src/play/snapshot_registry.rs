@@ -0,0 +1,362 @@
+use std::{
+    any::TypeId,
+    fmt::{self, Debug, Formatter},
+};
+
+use amethyst::ecs::{BitSet, Component, Entity, Join, World, WorldExt};
+use log::error;
+use serde::{de::DeserializeOwned, Serialize};
+
+use crate::play::{
+    SnapshotComponent, SnapshotEntity, SnapshotError, StorageSnapshotData, WorldSnapshotData,
+};
+
+/// Functions to set up, capture, and restore a component storage.
+#[derive(Clone, Copy)]
+struct StorageSnapshotFns {
+    /// Key that identifies the component storage in a snapshot.
+    key: &'static str,
+    /// Type ID of the component, to detect keys that are registered for different types.
+    type_id: TypeId,
+    /// Registers the component storage.
+    setup: fn(&mut World),
+    /// Serializes the components of the entities in scope.
+    capture: fn(&World, &BitSet) -> Result<Vec<u8>, SnapshotError>,
+    /// Replaces the components of the entities in scope with the serialized components.
+    restore: fn(&World, &BitSet, &[u8]) -> Result<(), SnapshotError>,
+    /// Removes the components of the given entities.
+    remove: fn(&World, &BitSet),
+}
+
+/// Functions to set up and read the storage of the component that marks entities in scope.
+#[derive(Clone, Copy)]
+struct ScopeFns {
+    /// Registers the marker component storage.
+    setup: fn(&mut World),
+    /// Returns the entities with the marker component.
+    mask: fn(&World) -> BitSet,
+}
+
+/// Component storages that are captured in a world snapshot.
+///
+/// Each storage is registered with a key that identifies it in the snapshot. Keys must be stable
+/// across builds, as snapshots may be exchanged between devices, so type names are not used.
+///
+/// Components that reference other entities should be registered through [`register_mapped`],
+/// and implement [`SnapshotComponent`].
+///
+/// [`register_mapped`]: #method.register_mapped
+/// [`SnapshotComponent`]: trait.SnapshotComponent.html
+#[derive(Clone, Default)]
+pub struct SnapshotRegistry {
+    /// Functions for each registered component storage.
+    storage_snapshot_fns: Vec<StorageSnapshotFns>,
+    /// Functions for the entities that are captured and restored, or all entities if `None`.
+    scope_fns: Option<ScopeFns>,
+}
+
+impl SnapshotRegistry {
+    /// Registers a component that is serialized as is.
+    ///
+    /// # Parameters
+    ///
+    /// * `key`: Key that identifies the component storage in a snapshot.
+    pub fn register<C>(&mut self, key: &'static str) -> &mut Self
+    where
+        C: Component + Serialize + DeserializeOwned,
+    {
+        self.register_fns(StorageSnapshotFns {
+            key,
+            type_id: TypeId::of::<C>(),
+            setup: Self::setup_storage::<C>,
+            capture: Self::capture_storage::<C>,
+            restore: Self::restore_storage::<C>,
+            remove: Self::remove_storage::<C>,
+        })
+    }
+
+    /// Registers a component that is converted to its `SnapshotComponent::Data` when serialized.
+    ///
+    /// # Parameters
+    ///
+    /// * `key`: Key that identifies the component storage in a snapshot.
+    pub fn register_mapped<C>(&mut self, key: &'static str) -> &mut Self
+    where
+        C: SnapshotComponent,
+    {
+        self.register_fns(StorageSnapshotFns {
+            key,
+            type_id: TypeId::of::<C>(),
+            setup: Self::setup_storage::<C>,
+            capture: Self::capture_storage_mapped::<C>,
+            restore: Self::restore_storage_mapped::<C>,
+            remove: Self::remove_storage::<C>,
+        })
+    }
+
+    /// Limits the captured and restored entities to those with an `M` component.
+    ///
+    /// Entities outside the scope are neither captured, nor deleted or modified on restore.
+    pub fn scope<M>(&mut self) -> &mut Self
+    where
+        M: Component,
+    {
+        self.scope_fns = Some(ScopeFns {
+            setup: Self::setup_storage::<M>,
+            mask: Self::scope_mask::<M>,
+        });
+        self
+    }
+
+    /// Returns the keys of the registered component storages.
+    pub fn keys(&self) -> impl Iterator<Item = &'static str> + '_ {
+        self.storage_snapshot_fns
+            .iter()
+            .map(|storage_snapshot_fns| storage_snapshot_fns.key)
+    }
+
+    /// Registers the component storages, and the scope marker storage, in the `World`.
+    pub fn setup(&self, world: &mut World) {
+        if let Some(scope_fns) = self.scope_fns {
+            (scope_fns.setup)(world);
+        }
+        self.storage_snapshot_fns
+            .iter()
+            .for_each(|storage_snapshot_fns| (storage_snapshot_fns.setup)(world));
+    }
+
+    /// Captures the entities and registered components in the `World` in binary form.
+    pub fn capture(&self, world: &World) -> Result<Vec<u8>, SnapshotError> {
+        let scope = self.scope(world);
+        let entities = (&*world.entities(), &scope)
+            .join()
+            .map(|(entity, _)| SnapshotEntity::from(entity))
+            .collect::<Vec<SnapshotEntity>>();
+        let storages = self
+            .storage_snapshot_fns
+            .iter()
+            .map(|storage_snapshot_fns| {
+                (storage_snapshot_fns.capture)(world, &scope).map(|bytes| {
+                    StorageSnapshotData::new(String::from(storage_snapshot_fns.key), bytes)
+                })
+            })
+            .collect::<Result<Vec<StorageSnapshotData>, SnapshotError>>()?;
+
+        bincode::serialize(&WorldSnapshotData::new(entities, storages))
+            .map_err(SnapshotError::Serialize)
+    }
+
+    /// Restores the entities and registered components in the `World` from a captured snapshot.
+    ///
+    /// Entities in scope that were created after the snapshot was captured are deleted, so
+    /// `World::maintain` should be called afterwards. Entities deleted after the snapshot was
+    /// captured cannot be brought back, so their components are not restored. Deletions should be
+    /// deferred with `DeleteMode::Deferred` while snapshots may be restored.
+    pub fn restore(&self, world: &World, bytes: &[u8]) -> Result<(), SnapshotError> {
+        let WorldSnapshotData {
+            entities: mut snapshot_entities,
+            storages,
+        } = bincode::deserialize(bytes).map_err(SnapshotError::Deserialize)?;
+
+        // Check every storage is present before modifying the world.
+        let storage_restores = self
+            .storage_snapshot_fns
+            .iter()
+            .map(|storage_snapshot_fns| {
+                storages
+                    .iter()
+                    .find(|storage| storage.key == storage_snapshot_fns.key)
+                    .map(|storage| (storage_snapshot_fns.restore, storage.bytes.as_slice()))
+                    .ok_or_else(|| SnapshotError::StorageMissing {
+                        key: String::from(storage_snapshot_fns.key),
+                    })
+            })
+            .collect::<Result<Vec<_>, SnapshotError>>()?;
+
+        let scope = self.scope(world);
+        snapshot_entities.sort_unstable();
+        let entities = world.entities();
+        (&*entities, &scope)
+            .join()
+            .map(|(entity, _)| entity)
+            .filter(|entity| {
+                snapshot_entities
+                    .binary_search(&SnapshotEntity::from(*entity))
+                    .is_err()
+            })
+            .for_each(|entity| {
+                if let Err(e) = entities.delete(entity) {
+                    error!("Failed to delete entity created after snapshot: `{}`.", e);
+                }
+            });
+
+        storage_restores
+            .into_iter()
+            .try_for_each(|(restore, bytes)| restore(world, &scope, bytes))
+    }
+
+    /// Removes the registered components of the given entities.
+    ///
+    /// This takes entities whose deletion is deferred out of game play, while a snapshot from
+    /// before their deletion may still restore them.
+    pub fn remove(&self, world: &World, entities: &BitSet) {
+        self.storage_snapshot_fns
+            .iter()
+            .for_each(|storage_snapshot_fns| (storage_snapshot_fns.remove)(world, entities));
+    }
+
+    fn register_fns(&mut self, storage_snapshot_fns: StorageSnapshotFns) -> &mut Self {
+        let registered = self
+            .storage_snapshot_fns
+            .iter()
+            .find(|registered| registered.key == storage_snapshot_fns.key);
+        match registered {
+            Some(registered) if registered.type_id != storage_snapshot_fns.type_id => panic!(
+                "Snapshot storage key `{}` is already registered for a different component type.",
+                storage_snapshot_fns.key
+            ),
+            Some(_) => {}
+            None => self.storage_snapshot_fns.push(storage_snapshot_fns),
+        }
+        self
+    }
+
+    /// Returns the entities that are captured and restored.
+    fn scope(&self, world: &World) -> BitSet {
+        match self.scope_fns {
+            Some(scope_fns) => (scope_fns.mask)(world),
+            None => {
+                let mut scope = BitSet::new();
+                (&*world.entities()).join().for_each(|entity| {
+                    scope.add(entity.id());
+                });
+                scope
+            }
+        }
+    }
+
+    fn scope_mask<M>(world: &World) -> BitSet
+    where
+        M: Component,
+    {
+        world.read_storage::<M>().mask().clone()
+    }
+
+    fn setup_storage<C>(world: &mut World)
+    where
+        C: Component,
+    {
+        world.register::<C>();
+    }
+
+    fn capture_storage<C>(world: &World, scope: &BitSet) -> Result<Vec<u8>, SnapshotError>
+    where
+        C: Component + Serialize,
+    {
+        let entities = world.entities();
+        let storage = world.read_storage::<C>();
+        let components = (&entities, &storage, scope)
+            .join()
+            .map(|(entity, component, _)| (SnapshotEntity::from(entity), component))
+            .collect::<Vec<(SnapshotEntity, &C)>>();
+
+        bincode::serialize(&components).map_err(SnapshotError::Serialize)
+    }
+
+    fn restore_storage<C>(world: &World, scope: &BitSet, bytes: &[u8]) -> Result<(), SnapshotError>
+    where
+        C: Component + DeserializeOwned,
+    {
+        let components = bincode::deserialize::<Vec<(SnapshotEntity, C)>>(bytes)
+            .map_err(SnapshotError::Deserialize)?;
+
+        let entities = world.entities();
+        let components = components
+            .into_iter()
+            .filter_map(|(snapshot_entity, component)| {
+                snapshot_entity
+                    .entity(&entities)
+                    .map(|entity| (entity, component))
+            });
+        Self::insert_components(world, scope, components);
+
+        Ok(())
+    }
+
+    fn capture_storage_mapped<C>(world: &World, scope: &BitSet) -> Result<Vec<u8>, SnapshotError>
+    where
+        C: SnapshotComponent,
+    {
+        let entities = world.entities();
+        let storage = world.read_storage::<C>();
+        let components = (&entities, &storage, scope)
+            .join()
+            .map(|(entity, component, _)| (SnapshotEntity::from(entity), component.to_snapshot()))
+            .collect::<Vec<(SnapshotEntity, C::Data)>>();
+
+        bincode::serialize(&components).map_err(SnapshotError::Serialize)
+    }
+
+    fn restore_storage_mapped<C>(
+        world: &World,
+        scope: &BitSet,
+        bytes: &[u8],
+    ) -> Result<(), SnapshotError>
+    where
+        C: SnapshotComponent,
+    {
+        let components = bincode::deserialize::<Vec<(SnapshotEntity, C::Data)>>(bytes)
+            .map_err(SnapshotError::Deserialize)?;
+
+        let entities = world.entities();
+        let components = components
+            .into_iter()
+            .filter_map(|(snapshot_entity, data)| {
+                let entity = snapshot_entity.entity(&entities)?;
+                C::from_snapshot(data, &entities).map(|component| (entity, component))
+            });
+        Self::insert_components(world, scope, components);
+
+        Ok(())
+    }
+
+    fn remove_storage<C>(world: &World, entities_removed: &BitSet)
+    where
+        C: Component,
+    {
+        let entities = world.entities();
+        let mut storage = world.write_storage::<C>();
+        (&entities, entities_removed)
+            .join()
+            .for_each(|(entity, _)| {
+                storage.remove(entity);
+            });
+    }
+
+    /// Replaces the components of entities in scope with the given components.
+    fn insert_components<C>(
+        world: &World,
+        scope: &BitSet,
+        components: impl Iterator<Item = (Entity, C)>,
+    ) where
+        C: Component,
+    {
+        Self::remove_storage::<C>(world, scope);
+
+        let mut storage = world.write_storage::<C>();
+        components.for_each(|(entity, component)| {
+            storage
+                .insert(entity, component)
+                .expect("Failed to restore component from snapshot.");
+        });
+    }
+}
+
+impl Debug for SnapshotRegistry {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SnapshotRegistry")
+            .field("storages", &self.keys().collect::<Vec<&'static str>>())
+            .field("scoped", &self.scope_fns.is_some())
+            .finish()
+    }
+}
@@ -0,0 +1,11 @@
+use derive_new::new;
+use serde::{Deserialize, Serialize};
+
+/// Serialized components of a single storage.
+#[derive(Clone, Debug, Deserialize, PartialEq, Eq, Serialize, new)]
+pub struct StorageSnapshotData {
+    /// Key that the component storage is registered with.
+    pub key: String,
+    /// Serialized `(SnapshotEntity, component)` pairs.
+    pub bytes: Vec<u8>,
+}
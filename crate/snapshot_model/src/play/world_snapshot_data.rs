@@ -0,0 +1,13 @@
+use derive_new::new;
+use serde::{Deserialize, Serialize};
+
+use crate::play::{SnapshotEntity, StorageSnapshotData};
+
+/// Serialized state of the entities and their components at a point in time.
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Eq, Serialize, new)]
+pub struct WorldSnapshotData {
+    /// Entities alive when the snapshot was captured.
+    pub entities: Vec<SnapshotEntity>,
+    /// Serialized components of each registered storage.
+    pub storages: Vec<StorageSnapshotData>,
+}
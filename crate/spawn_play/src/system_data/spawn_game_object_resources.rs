@@ -7,6 +7,7 @@ use asset_model::loaded::{AssetId, AssetIdMappings, AssetItemIds, AssetTypeMappi
 use character_prefab::{CharacterComponentStorages, CharacterSpawningResources};
 use derivative::Derivative;
 use energy_prefab::EnergyComponentStorages;
+use item_prefab::{ItemComponentStorages, ItemSpawningResources};
 use spawn_model::play::SpawnEvent;
 
 /// `SpawnGameObjectResources`.
@@ -40,6 +41,12 @@ pub struct SpawnGameObjectResources<'s> {
     /// `EnergyComponentStorages`.
     #[derivative(Debug = "ignore")]
     pub energy_component_storages: EnergyComponentStorages<'s>,
+    /// `ItemSpawningResources`.
+    #[derivative(Debug = "ignore")]
+    pub item_spawning_resources: ItemSpawningResources<'s>,
+    /// `ItemComponentStorages`.
+    #[derivative(Debug = "ignore")]
+    pub item_component_storages: ItemComponentStorages<'s>,
     /// `SpawnEvent` channel.
     #[derivative(Debug = "ignore")]
     pub spawn_ec: Write<'s, EventChannel<SpawnEvent>>,
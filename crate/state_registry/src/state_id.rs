@@ -40,6 +40,8 @@ pub enum StateId {
     MapSelection,
     /// `NetworkModeSelectionState` ID.
     NetworkModeSelection,
+    /// `SessionBrowseState` ID.
+    SessionBrowse,
     /// `SessionHostState` ID.
     SessionHost,
     /// `SessionJoinState` ID.
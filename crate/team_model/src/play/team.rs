@@ -1,10 +1,14 @@
 use amethyst::ecs::{storage::VecStorage, Component};
+use serde::{Deserialize, Serialize};
 
 use crate::play::{IndependentCounter, TeamCounter};
 
 /// Represents the in-game grouping of player teams.
-#[derive(Clone, Component, Copy, Debug, Hash, PartialEq, Eq, PartialOrd)]
+#[derive(
+    Clone, Component, Copy, Debug, Deserialize, Hash, PartialEq, Eq, PartialOrd, Serialize,
+)]
 #[storage(VecStorage)]
+#[serde(rename_all = "snake_case")]
 pub enum Team {
     /// Independent team.
     ///
@@ -1,9 +1,10 @@
 use amethyst::ecs::{storage::DenseVecStorage, Component};
 use derive_deref::{Deref, DerefMut};
 use derive_new::new;
+use serde::{Deserialize, Serialize};
 
 /// Stores the last value of the component.
-#[derive(Debug, Deref, DerefMut, new)] // kcov-ignore
+#[derive(Debug, Deref, DerefMut, Deserialize, Serialize, new)] // kcov-ignore
 pub struct Last<T>(pub T)
 where
     T: Component + Clone + Send + Sync;
@@ -2,7 +2,7 @@ use amethyst::ecs::{Builder, WorldExt};
 use asset_model::{loaded::ItemId, play::AssetWorld};
 use session_lobby_ui_model::{
     config::{SessionDeviceWidgetTemplate, SessionLobbyUi},
-    loaded::{SessionCodeLabel, SessionDevicesWidget},
+    loaded::{SessionChatLogLabel, SessionCodeLabel, SessionDevicesWidget},
 };
 
 /// Loads asset items for a `SessionLobbyUi`.
@@ -23,6 +23,12 @@ impl UiAsclSessionLobby {
 
         item_ids_all.push(item_id_session_code);
         item_ids_all.push(item_id_session_devices_widget);
+
+        if let Some(item_id_session_chat_log) =
+            Self::load_item_entity_session_chat_log(asset_world, session_lobby_ui)
+        {
+            item_ids_all.push(item_id_session_chat_log);
+        }
     }
 
     fn load_item_entity_session_code(
@@ -38,6 +44,20 @@ impl UiAsclSessionLobby {
         ItemId::new(item_entity_label)
     }
 
+    fn load_item_entity_session_chat_log(
+        asset_world: &mut AssetWorld,
+        session_lobby_ui: &SessionLobbyUi,
+    ) -> Option<ItemId> {
+        session_lobby_ui.session_chat_log.clone().map(|ui_label| {
+            let item_entity_label = asset_world
+                .create_entity()
+                .with(SessionChatLogLabel)
+                .with(ui_label)
+                .build();
+            ItemId::new(item_entity_label)
+        })
+    }
+
     fn load_item_entity_session_devices_widget(
         asset_world: &mut AssetWorld,
         session_lobby_ui: &SessionLobbyUi,
@@ -0,0 +1,2 @@
+mod ai_controller_system;
+mod ai_decision_clock_system;
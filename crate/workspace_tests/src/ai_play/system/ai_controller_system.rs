@@ -0,0 +1,264 @@
+#[cfg(test)]
+mod tests {
+    use std::any;
+
+    use ai_model::{config::AiDifficulty, play::AiControlled};
+    use amethyst::{
+        ecs::{Builder, Entity, World, WorldExt},
+        shred::SystemData,
+        shrev::{EventChannel, ReaderId},
+        Error,
+    };
+    use amethyst_test::AmethystApplication;
+    use game_input_model::{
+        config::{Axis, ControlAction},
+        play::{AxisMoveEventData, ControlActionEventData, ControlInputEvent, ControllerInput},
+    };
+    use game_play_model::GamePlayStatus;
+    use kinematic_model::config::Position;
+    use mirrored_model::play::Mirrored;
+    use object_model::play::HealthPoints;
+    use team_model::play::{IndependentCounter, Team};
+
+    use ai_play::{AiControllerSystem, AiControllerSystemData};
+
+    #[test]
+    fn approaches_target_out_of_range() -> Result<(), Error> {
+        run_test(
+            SetupParams {
+                difficulty: AiDifficulty::Normal,
+                decision_due: true,
+                mirrored: Mirrored(false),
+                target_position: Position::new(200., 0., 0.),
+                target_controller_input: ControllerInput::default(),
+            },
+            |ai_entity| {
+                vec![ControlInputEvent::AxisMoved(AxisMoveEventData {
+                    controller_id: 1,
+                    entity: ai_entity,
+                    axis: Axis::X,
+                    value: 1.,
+                })]
+            },
+        )
+    }
+
+    #[test]
+    fn attacks_target_in_range() -> Result<(), Error> {
+        run_test(
+            SetupParams {
+                difficulty: AiDifficulty::Normal,
+                decision_due: true,
+                mirrored: Mirrored(false),
+                target_position: Position::new(30., 0., 0.),
+                target_controller_input: ControllerInput::default(),
+            },
+            |ai_entity| vec![action_press(ai_entity, ControlAction::Attack)],
+        )
+    }
+
+    #[test]
+    fn turns_around_to_attack_target_behind() -> Result<(), Error> {
+        run_test(
+            SetupParams {
+                difficulty: AiDifficulty::Normal,
+                decision_due: true,
+                mirrored: Mirrored(false),
+                target_position: Position::new(-30., 0., 0.),
+                target_controller_input: ControllerInput::default(),
+            },
+            |ai_entity| {
+                vec![
+                    ControlInputEvent::AxisMoved(AxisMoveEventData {
+                        controller_id: 1,
+                        entity: ai_entity,
+                        axis: Axis::X,
+                        value: -1.,
+                    }),
+                    action_press(ai_entity, ControlAction::Attack),
+                ]
+            },
+        )
+    }
+
+    #[test]
+    fn defends_when_target_attacks() -> Result<(), Error> {
+        run_test(
+            SetupParams {
+                difficulty: AiDifficulty::Normal,
+                decision_due: true,
+                mirrored: Mirrored(false),
+                target_position: Position::new(30., 0., 0.),
+                target_controller_input: ControllerInput::new(0., 0., false, false, true, false),
+            },
+            |ai_entity| vec![action_press(ai_entity, ControlAction::Defend)],
+        )
+    }
+
+    #[test]
+    fn dodges_when_target_attacks_from_further_away() -> Result<(), Error> {
+        run_test(
+            SetupParams {
+                difficulty: AiDifficulty::Normal,
+                decision_due: true,
+                mirrored: Mirrored(false),
+                target_position: Position::new(80., 0., 5.),
+                target_controller_input: ControllerInput::new(0., 0., false, false, true, false),
+            },
+            |ai_entity| {
+                vec![
+                    ControlInputEvent::AxisMoved(AxisMoveEventData {
+                        controller_id: 1,
+                        entity: ai_entity,
+                        axis: Axis::X,
+                        value: -1.,
+                    }),
+                    ControlInputEvent::AxisMoved(AxisMoveEventData {
+                        controller_id: 1,
+                        entity: ai_entity,
+                        axis: Axis::Z,
+                        value: -1.,
+                    }),
+                ]
+            },
+        )
+    }
+
+    #[test]
+    fn does_not_defend_on_easy_difficulty() -> Result<(), Error> {
+        run_test(
+            SetupParams {
+                difficulty: AiDifficulty::Easy,
+                decision_due: true,
+                mirrored: Mirrored(false),
+                target_position: Position::new(30., 0., 0.),
+                target_controller_input: ControllerInput::new(0., 0., false, false, true, false),
+            },
+            |ai_entity| vec![action_press(ai_entity, ControlAction::Attack)],
+        )
+    }
+
+    #[test]
+    fn jumps_to_reach_airborne_target() -> Result<(), Error> {
+        run_test(
+            SetupParams {
+                difficulty: AiDifficulty::Normal,
+                decision_due: true,
+                mirrored: Mirrored(false),
+                target_position: Position::new(80., 50., 0.),
+                target_controller_input: ControllerInput::default(),
+            },
+            |ai_entity| {
+                vec![
+                    ControlInputEvent::AxisMoved(AxisMoveEventData {
+                        controller_id: 1,
+                        entity: ai_entity,
+                        axis: Axis::X,
+                        value: 1.,
+                    }),
+                    action_press(ai_entity, ControlAction::Jump),
+                ]
+            },
+        )
+    }
+
+    #[test]
+    fn does_not_send_events_before_decision_is_due() -> Result<(), Error> {
+        run_test(
+            SetupParams {
+                difficulty: AiDifficulty::Normal,
+                decision_due: false,
+                mirrored: Mirrored(false),
+                target_position: Position::new(30., 0., 0.),
+                target_controller_input: ControllerInput::default(),
+            },
+            |_| vec![],
+        )
+    }
+
+    fn run_test(
+        SetupParams {
+            difficulty,
+            decision_due,
+            mirrored,
+            target_position,
+            target_controller_input,
+        }: SetupParams,
+        control_input_events_expected_fn: fn(Entity) -> Vec<ControlInputEvent>,
+    ) -> Result<(), Error> {
+        AmethystApplication::blank()
+            .with_resource(GamePlayStatus::Playing)
+            .with_setup(AiControllerSystemData::setup)
+            .with_setup(register_event_reader)
+            .with_effect(move |world| {
+                let mut ai_controlled = AiControlled::new(1, difficulty);
+                if decision_due {
+                    ai_controlled.decision_clock.complete();
+                }
+
+                let ai_entity = world
+                    .create_entity()
+                    .with(ai_controlled)
+                    .with(ControllerInput::default())
+                    .with(Position::<f32>::new(0., 0., 0.))
+                    .with(mirrored)
+                    .with(Team::Independent(IndependentCounter::new(0)))
+                    .with(HealthPoints(100))
+                    .build();
+                world
+                    .create_entity()
+                    .with(target_controller_input)
+                    .with(target_position)
+                    .with(Team::Independent(IndependentCounter::new(1)))
+                    .with(HealthPoints(100))
+                    .build();
+
+                world.insert(ai_entity);
+            })
+            .with_system_single(
+                AiControllerSystem::new(),
+                any::type_name::<AiControllerSystem>(),
+                &[],
+            ) // kcov-ignore
+            .with_assertion(move |world| {
+                let ai_entity = *world.read_resource::<Entity>();
+                let control_input_events_expected = control_input_events_expected_fn(ai_entity);
+
+                let control_input_events_actual = {
+                    let mut reader_id = world.write_resource::<ReaderId<ControlInputEvent>>();
+                    let control_input_ec = world.read_resource::<EventChannel<ControlInputEvent>>();
+                    control_input_ec
+                        .read(&mut reader_id)
+                        .copied()
+                        .collect::<Vec<ControlInputEvent>>()
+                };
+
+                assert_eq!(control_input_events_expected, control_input_events_actual);
+            })
+            .run()
+    }
+
+    fn register_event_reader(world: &mut World) {
+        let reader_id = {
+            let mut control_input_ec = world.write_resource::<EventChannel<ControlInputEvent>>();
+            control_input_ec.register_reader()
+        }; // kcov-ignore
+        world.insert(reader_id);
+    }
+
+    fn action_press(entity: Entity, control_action: ControlAction) -> ControlInputEvent {
+        ControlInputEvent::ControlActionPress(ControlActionEventData {
+            controller_id: 1,
+            entity,
+            control_action,
+        })
+    }
+
+    struct SetupParams {
+        difficulty: AiDifficulty,
+        decision_due: bool,
+        mirrored: Mirrored,
+        target_position: Position<f32>,
+        target_controller_input: ControllerInput,
+    }
+}
@@ -0,0 +1,34 @@
+#[cfg(test)]
+mod tests {
+    use ai_model::{config::AiDifficulty, play::AiControlled};
+    use amethyst::{
+        ecs::{Builder, Entity, WorldExt},
+        Error,
+    };
+    use amethyst_test::AmethystApplication;
+
+    use ai_play::AiDecisionClockSystem;
+
+    #[test]
+    fn ticks_decision_clock() -> Result<(), Error> {
+        AmethystApplication::blank()
+            .with_system(AiDecisionClockSystem::new(), "", &[])
+            .with_effect(|world| {
+                let entity = world
+                    .create_entity()
+                    .with(AiControlled::new(1, AiDifficulty::Normal))
+                    .build();
+                world.insert(entity);
+            })
+            .with_assertion(|world| {
+                let entity = *world.read_resource::<Entity>();
+                let ai_controlleds = world.read_storage::<AiControlled>();
+                let ai_controlled = ai_controlleds
+                    .get(entity)
+                    .expect("Expected `AiControlled` component to exist.");
+
+                assert_eq!(1, ai_controlled.decision_clock.value);
+            })
+            .run()
+    }
+}
@@ -318,6 +318,75 @@ mod test {
         }
     }
 
+    mod save {
+        use serde::{Deserialize, Serialize};
+        use tempfile::tempdir;
+
+        use application::{AppDir, AppFile, Format};
+
+        #[test]
+        fn save_in_yaml_writes_file_that_loads_successfully() {
+            let exe_dir = tempdir().unwrap();
+            std::fs::create_dir(exe_dir.path().join(AppDir::RESOURCES)).unwrap();
+
+            let file_path = AppFile::save_in_internal(
+                Ok(exe_dir.path().to_path_buf()),
+                AppDir::RESOURCES,
+                "test__save_config.yaml",
+                &Data { val: 123 },
+                Format::Yaml,
+            )
+            .unwrap();
+
+            assert_eq!(
+                exe_dir
+                    .path()
+                    .join(AppDir::RESOURCES)
+                    .join("test__save_config.yaml"),
+                file_path
+            );
+            assert_eq!(
+                Data { val: 123 },
+                AppFile::load_internal(
+                    Ok(exe_dir.path().join(AppDir::RESOURCES)),
+                    "test__save_config.yaml",
+                    Format::Yaml
+                )
+                .unwrap()
+            );
+        }
+
+        #[test]
+        fn save_bytes_ron_serializes_data() {
+            let bytes = AppFile::save_bytes(&Data { val: 123 }, Format::Ron).unwrap();
+
+            assert_eq!(
+                Data { val: 123 },
+                AppFile::load_bytes::<Data>(&bytes, Format::Ron).unwrap()
+            );
+        }
+
+        #[test]
+        fn save_in_returns_error_when_directory_does_not_exist() {
+            let exe_dir = tempdir().unwrap();
+
+            let save_result = AppFile::save_in_internal(
+                Ok(exe_dir.path().to_path_buf()),
+                "non_existent",
+                "test__save_config.yaml",
+                &Data { val: 123 },
+                Format::Yaml,
+            );
+
+            assert!(save_result.is_err());
+        }
+
+        #[derive(Debug, Deserialize, PartialEq, Serialize)]
+        struct Data {
+            val: i32,
+        }
+    }
+
     /// Creates a temporary resource file in a directory for tests.
     ///
     /// # Parameters
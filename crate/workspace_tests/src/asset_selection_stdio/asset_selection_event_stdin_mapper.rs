@@ -192,6 +192,7 @@ mod tests {
     test_map_with_controller_id!(maps_join_event, Join);
     test_map_with_controller_id!(maps_leave_event, Leave);
     test_map_with_controller_id!(maps_deselect_event, Deselect);
+    test_map_with_controller_id!(maps_cpu_switch_event, CpuSwitch);
     test_map_direct!(maps_return_event, Return);
     test_map_direct!(maps_confirm_event, Confirm);
 
@@ -0,0 +1,74 @@
+#[cfg(test)]
+mod test {
+    use character_model::{config::CharacterSequenceName, play::RunCounter};
+    use game_input_model::play::ControllerInput;
+    use kinematic_model::config::{Position, Velocity};
+    use mirrored_model::play::Mirrored;
+    use object_model::play::{Grounding, HealthPoints};
+    use sequence_model::play::SequenceStatus;
+
+    use character_play::{
+        sequence_handler::{CharacterSequenceHandler, Defend},
+        CharacterSequenceUpdateComponents,
+    };
+
+    #[test]
+    fn no_update_when_sequence_not_ended() {
+        let input = ControllerInput::new(0., 0., false, false, false, false);
+
+        assert_eq!(
+            None,
+            Defend::update(CharacterSequenceUpdateComponents::new(
+                &input,
+                HealthPoints::default(),
+                CharacterSequenceName::Defend,
+                SequenceStatus::default(),
+                &Position::default(),
+                &Velocity::default(),
+                Mirrored::default(),
+                Grounding::OnGround,
+                RunCounter::default()
+            ))
+        );
+    }
+
+    #[test]
+    fn repeats_defend_when_sequence_ended_and_defend_held() {
+        let input = ControllerInput::new(0., 0., true, false, false, false);
+
+        assert_eq!(
+            Some(CharacterSequenceName::Defend),
+            Defend::update(CharacterSequenceUpdateComponents::new(
+                &input,
+                HealthPoints::default(),
+                CharacterSequenceName::Defend,
+                SequenceStatus::End,
+                &Position::default(),
+                &Velocity::default(),
+                Mirrored::default(),
+                Grounding::OnGround,
+                RunCounter::default()
+            ))
+        );
+    }
+
+    #[test]
+    fn reverts_to_stand_when_sequence_ended_and_defend_released() {
+        let input = ControllerInput::new(0., 0., false, false, false, false);
+
+        assert_eq!(
+            Some(CharacterSequenceName::Stand),
+            Defend::update(CharacterSequenceUpdateComponents::new(
+                &input,
+                HealthPoints::default(),
+                CharacterSequenceName::Defend,
+                SequenceStatus::End,
+                &Position::default(),
+                &Velocity::default(),
+                Mirrored::default(),
+                Grounding::OnGround,
+                RunCounter::default()
+            ))
+        );
+    }
+}
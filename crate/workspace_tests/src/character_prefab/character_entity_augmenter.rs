@@ -22,9 +22,16 @@ mod test {
         config::{ChargeDelay, ChargeLimit, ChargeUseMode},
         play::{ChargeRetention, ChargeTrackerClock},
     };
+    use collision_model::loaded::{
+        GrabReleaseTransition, GrabbedTransition, GrabbingTransition, ThrowingTransition,
+        ThrownTransition,
+    };
     use game_input_model::play::ControllerInput;
     use map_model::play::MapBounded;
-    use object_model::{config::Mass, play::HealthPoints};
+    use object_model::{
+        config::Mass,
+        play::{GuardPoints, HealthPoints},
+    };
     use object_status_model::config::StunPoints;
     use sequence_model::loaded::{AssetSequenceIdMappings, SequenceIdMappings};
 
@@ -53,6 +60,7 @@ mod test {
 
             assert!(world.read_storage::<ControllerInput>().contains(entity));
             assert!(world.read_storage::<HealthPoints>().contains(entity));
+            assert!(world.read_storage::<GuardPoints>().contains(entity));
             assert!(world.read_storage::<StunPoints>().contains(entity));
             assert!(world.read_storage::<RunCounter>().contains(entity));
             assert!(world.read_storage::<Mass>().contains(entity));
@@ -62,6 +70,13 @@ mod test {
             assert!(world.read_storage::<ChargeDelay>().contains(entity));
             assert!(world.read_storage::<ChargeUseMode>().contains(entity));
             assert!(world.read_storage::<ChargeRetention>().contains(entity));
+            assert!(world.read_storage::<GrabbingTransition>().contains(entity));
+            assert!(world.read_storage::<GrabbedTransition>().contains(entity));
+            assert!(world.read_storage::<ThrowingTransition>().contains(entity));
+            assert!(world.read_storage::<ThrownTransition>().contains(entity));
+            assert!(world
+                .read_storage::<GrabReleaseTransition>()
+                .contains(entity));
         };
 
         AmethystApplication::blank()
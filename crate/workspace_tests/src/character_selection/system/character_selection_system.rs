@@ -2,6 +2,7 @@
 mod tests {
     use std::{any, str::FromStr};
 
+    use ai_model::config::AiDifficulty;
     use amethyst::{
         core::TransformBundle,
         ecs::{World, WorldExt},
@@ -73,6 +74,58 @@ mod tests {
         )
     }
 
+    #[test]
+    fn switches_to_easy_cpu_on_cpu_switch_event() -> Result<(), Error> {
+        run_cpu_test(None, Some(AiDifficulty::Easy))
+    }
+
+    #[test]
+    fn switches_to_next_cpu_difficulty_on_cpu_switch_event() -> Result<(), Error> {
+        run_cpu_test(Some(AiDifficulty::Normal), Some(AiDifficulty::Hard))
+    }
+
+    #[test]
+    fn switches_to_player_after_hard_cpu_on_cpu_switch_event() -> Result<(), Error> {
+        run_cpu_test(Some(AiDifficulty::Hard), None)
+    }
+
+    fn run_cpu_test(
+        cpu_selection_initial: Option<AiDifficulty>,
+        cpu_selection_expected: Option<AiDifficulty>,
+    ) -> Result<(), Error> {
+        AmethystApplication::blank()
+            .with_system(
+                CharacterSelectionSystem::new(),
+                any::type_name::<CharacterSelectionSystem>(),
+                &[],
+            ) // kcov-ignore
+            .with_effect(move |world| {
+                if let Some(ai_difficulty) = cpu_selection_initial {
+                    world
+                        .write_resource::<CharacterSelections>()
+                        .cpu_selections
+                        .insert(123, ai_difficulty);
+                }
+
+                send_event(
+                    world,
+                    AssetSelectionEvent::CpuSwitch {
+                        entity: None,
+                        controller_id: 123,
+                    },
+                );
+            })
+            .with_assertion(move |world| {
+                let character_selections = world.read_resource::<CharacterSelections>();
+
+                assert_eq!(
+                    cpu_selection_expected,
+                    character_selections.cpu_selections.get(&123).copied()
+                );
+            })
+            .run()
+    }
+
     fn run_test(
         SetupParams {
             with_character_selection_initial,
@@ -1 +1,2 @@
+mod sweep_and_prune;
 mod system;
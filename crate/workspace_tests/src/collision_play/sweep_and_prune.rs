@@ -0,0 +1,168 @@
+#[cfg(test)]
+mod tests {
+    use amethyst::core::math::Vector3;
+    use collision_model::config::{Hit, Interaction, InteractionKind};
+    use shape_model::{Axis, Volume};
+
+    use collision_play::{CollisionDetectionSystem, SweepAndPrune};
+
+    #[test]
+    fn candidates_returns_items_with_overlapping_x_and_z_ranges() {
+        let mut sweep_and_prune = SweepAndPrune::new();
+        sweep_and_prune.insert(0, [20., 30.], [0., 10.]);
+        sweep_and_prune.insert(1, [0., 10.], [0., 10.]);
+        sweep_and_prune.insert(2, [5., 15.], [20., 30.]);
+        sweep_and_prune.insert(3, [-100., 100.], [5., 6.]);
+        sweep_and_prune.prepare();
+
+        let mut candidates = sweep_and_prune
+            .candidates([8., 12.], [0., 10.])
+            .collect::<Vec<usize>>();
+        candidates.sort();
+
+        assert_eq!(vec![1, 3], candidates);
+    }
+
+    #[test]
+    fn clear_removes_all_items() {
+        let mut sweep_and_prune = SweepAndPrune::new();
+        sweep_and_prune.insert(0, [0., 10.], [0., 10.]);
+        sweep_and_prune.clear();
+        sweep_and_prune.prepare();
+
+        assert!(sweep_and_prune.is_empty());
+        assert_eq!(0, sweep_and_prune.candidates([0., 10.], [0., 10.]).count());
+    }
+
+    #[test]
+    fn pruned_collisions_match_exhaustive_collisions() {
+        let objects = objects(200);
+
+        let mut sweep_and_prune = SweepAndPrune::new();
+        objects
+            .iter()
+            .enumerate()
+            .for_each(|(index, (position, mirrored, _interaction, body))| {
+                if let Some((x_range, z_range)) = CollisionDetectionSystem::volumes_range(
+                    body.iter(),
+                    OFFSETS,
+                    *mirrored,
+                    position,
+                ) {
+                    sweep_and_prune.insert(index, x_range, z_range);
+                }
+            });
+        sweep_and_prune.prepare();
+
+        let collisions_exhaustive = objects
+            .iter()
+            .enumerate()
+            .flat_map(|(index_from, _)| {
+                (0..objects.len()).map(move |index_to| (index_from, index_to))
+            })
+            .filter(|(index_from, index_to)| collides(&objects, *index_from, *index_to))
+            .collect::<Vec<(usize, usize)>>();
+
+        let mut collisions_pruned = objects
+            .iter()
+            .enumerate()
+            .flat_map(|(index_from, (position, mirrored, interaction, _body))| {
+                let (x_range, z_range) = CollisionDetectionSystem::volumes_range(
+                    interaction.bounds.iter(),
+                    OFFSETS,
+                    *mirrored,
+                    position,
+                )
+                .expect("Expected interaction to have bounds.");
+
+                sweep_and_prune
+                    .candidates(x_range, z_range)
+                    .map(move |index_to| (index_from, index_to))
+                    .collect::<Vec<(usize, usize)>>()
+            })
+            .filter(|(index_from, index_to)| collides(&objects, *index_from, *index_to))
+            .collect::<Vec<(usize, usize)>>();
+        collisions_pruned.sort();
+
+        assert!(!collisions_exhaustive.is_empty());
+        assert_eq!(collisions_exhaustive, collisions_pruned);
+    }
+
+    const OFFSETS: [f32; 2] = [10., 20.];
+
+    type Object = (Vector3<f32>, bool, Interaction, Vec<Volume>);
+
+    fn collides(objects: &[Object], index_from: usize, index_to: usize) -> bool {
+        if index_from == index_to {
+            return false;
+        }
+
+        let (position_from, mirrored_from, interaction, _) = &objects[index_from];
+        let (position_to, mirrored_to, _, body) = &objects[index_to];
+
+        let mut relative_pos = position_to - position_from;
+        relative_pos[1] += position_to[2] - position_from[2];
+
+        body.iter().any(|volume| {
+            CollisionDetectionSystem::intersects(
+                &relative_pos,
+                (interaction, OFFSETS, *mirrored_from),
+                (volume, OFFSETS, *mirrored_to),
+            )
+        })
+    }
+
+    /// Returns objects with deterministic pseudo-random positions and volumes.
+    fn objects(count: usize) -> Vec<Object> {
+        let mut seed = 0x2545_f491_u32;
+        let mut next = move |modulus: u32| {
+            seed = seed.wrapping_mul(1_103_515_245).wrapping_add(12345);
+            (seed >> 16) % modulus
+        };
+
+        (0..count)
+            .map(|_| {
+                let position = Vector3::new(next(800) as f32, 0., next(200) as f32);
+                let mirrored = next(2) == 0;
+                let volume = match next(3) {
+                    0 => Volume::Box {
+                        x: next(20) as i32,
+                        y: next(40) as i32,
+                        z: -(next(10) as i32),
+                        w: 1 + next(30),
+                        h: 1 + next(30),
+                        d: 1 + next(20),
+                    },
+                    1 => Volume::Cylinder {
+                        axis: match next(3) {
+                            0 => Axis::X,
+                            1 => Axis::Y,
+                            _ => Axis::Z,
+                        },
+                        center: next(20) as i32,
+                        r: 1 + next(15),
+                        l: 1 + next(30),
+                    },
+                    _ => Volume::Sphere {
+                        x: next(20) as i32,
+                        y: next(40) as i32,
+                        z: 0,
+                        r: 1 + next(15),
+                    },
+                };
+                let interaction =
+                    Interaction::new(InteractionKind::Hit(Hit::default()), vec![volume], true);
+                let body = vec![Volume::Box {
+                    x: 0,
+                    y: 0,
+                    z: -10,
+                    w: 20,
+                    h: 40,
+                    d: 20,
+                }];
+
+                (position, mirrored, interaction, body)
+            })
+            .collect::<Vec<Object>>()
+    }
+}
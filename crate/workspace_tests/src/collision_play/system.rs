@@ -1,4 +1,7 @@
+mod collision_detection_system;
 mod contact_detection_system;
+mod grab_effect_system;
+mod grab_hold_system;
 mod hit_detection_system;
 mod hit_effect_system;
 mod hit_repeat_trackers_augment_system;
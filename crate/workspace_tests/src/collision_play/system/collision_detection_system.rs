@@ -0,0 +1,192 @@
+#[cfg(test)]
+mod tests {
+    use amethyst::core::math::Vector3;
+    use collision_model::config::{Hit, Interaction, InteractionKind};
+    use shape_model::{Axis, Volume};
+
+    use collision_play::CollisionDetectionSystem;
+
+    const NO_OFFSETS: [f32; 2] = [0., 0.];
+
+    #[test]
+    fn sphere_intersects_sphere_when_within_sum_of_radii() {
+        let bound = Volume::Sphere {
+            x: 0,
+            y: 0,
+            z: 0,
+            r: 5,
+        };
+        let body = Volume::Sphere {
+            x: 0,
+            y: 0,
+            z: 0,
+            r: 5,
+        };
+
+        assert!(intersects(
+            Vector3::new(9., 0., 0.),
+            (bound, NO_OFFSETS, false),
+            (body, NO_OFFSETS, false),
+        ));
+        assert!(!intersects(
+            Vector3::new(11., 0., 0.),
+            (bound, NO_OFFSETS, false),
+            (body, NO_OFFSETS, false),
+        ));
+    }
+
+    #[test]
+    fn sphere_intersects_box_on_mirrored_side() {
+        let bound = Volume::Sphere {
+            x: 10,
+            y: 0,
+            z: 0,
+            r: 3,
+        };
+        let body = Volume::Box {
+            x: 0,
+            y: 0,
+            z: 0,
+            w: 4,
+            h: 4,
+            d: 4,
+        };
+        let relative_pos = Vector3::new(-14., 0., 0.);
+
+        assert!(intersects(
+            relative_pos,
+            (bound, NO_OFFSETS, true),
+            (body, NO_OFFSETS, false),
+        ));
+        assert!(!intersects(
+            relative_pos,
+            (bound, NO_OFFSETS, false),
+            (body, NO_OFFSETS, false),
+        ));
+    }
+
+    #[test]
+    fn sphere_intersects_sphere_accounting_for_sprite_offsets() {
+        let bound = Volume::Sphere {
+            x: 20,
+            y: 30,
+            z: 0,
+            r: 2,
+        };
+        let body = Volume::Sphere {
+            x: 5,
+            y: 5,
+            z: 0,
+            r: 2,
+        };
+        let relative_pos = Vector3::new(3., 0., 0.);
+
+        assert!(intersects(
+            relative_pos,
+            (bound, [20., 30.], false),
+            (body, [5., 5.], false),
+        ));
+        assert!(!intersects(
+            relative_pos,
+            (bound, [20., 30.], false),
+            (body, NO_OFFSETS, false),
+        ));
+    }
+
+    #[test]
+    fn cylinder_intersects_box_within_radius() {
+        let bound = Volume::Cylinder {
+            axis: Axis::Y,
+            center: 0,
+            r: 5,
+            l: 10,
+        };
+        let body = Volume::Box {
+            x: 0,
+            y: 0,
+            z: 0,
+            w: 2,
+            h: 2,
+            d: 2,
+        };
+
+        assert!(intersects(
+            Vector3::new(3., 0., 3.),
+            (bound, NO_OFFSETS, false),
+            (body, NO_OFFSETS, false),
+        ));
+        assert!(!intersects(
+            Vector3::new(4., 0., 4.),
+            (bound, NO_OFFSETS, false),
+            (body, NO_OFFSETS, false),
+        ));
+    }
+
+    #[test]
+    fn cylinder_intersects_perpendicular_cylinder() {
+        let bound = Volume::Cylinder {
+            axis: Axis::X,
+            center: 0,
+            r: 2,
+            l: 10,
+        };
+        let body = Volume::Cylinder {
+            axis: Axis::Z,
+            center: 0,
+            r: 2,
+            l: 10,
+        };
+
+        assert!(intersects(
+            Vector3::new(6., 0., 0.),
+            (bound, NO_OFFSETS, false),
+            (body, NO_OFFSETS, false),
+        ));
+        assert!(!intersects(
+            Vector3::new(6., 4., 0.),
+            (bound, NO_OFFSETS, false),
+            (body, NO_OFFSETS, false),
+        ));
+    }
+
+    #[test]
+    fn cylinder_intersects_sphere_past_cylinder_end() {
+        let bound = Volume::Cylinder {
+            axis: Axis::Z,
+            center: 0,
+            r: 1,
+            l: 4,
+        };
+        let body = Volume::Sphere {
+            x: 0,
+            y: 0,
+            z: 0,
+            r: 1,
+        };
+
+        assert!(intersects(
+            Vector3::new(0., 0., 2.5),
+            (bound, NO_OFFSETS, false),
+            (body, NO_OFFSETS, false),
+        ));
+        assert!(!intersects(
+            Vector3::new(0., 0., 3.5),
+            (bound, NO_OFFSETS, false),
+            (body, NO_OFFSETS, false),
+        ));
+    }
+
+    fn intersects(
+        relative_pos: Vector3<f32>,
+        (bound, interaction_offsets, interaction_mirrored): (Volume, [f32; 2], bool),
+        (body, body_offsets, body_mirrored): (Volume, [f32; 2], bool),
+    ) -> bool {
+        let interaction = Interaction::new(InteractionKind::Hit(Hit::default()), vec![bound], true);
+
+        CollisionDetectionSystem::intersects(
+            &relative_pos,
+            (&interaction, interaction_offsets, interaction_mirrored),
+            (&body, body_offsets, body_mirrored),
+        )
+    }
+}
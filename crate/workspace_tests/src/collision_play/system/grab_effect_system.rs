@@ -0,0 +1,190 @@
+#[cfg(test)]
+mod tests {
+    use amethyst::{
+        ecs::{Builder, Entity, World, WorldExt},
+        shrev::EventChannel,
+        Error,
+    };
+    use amethyst_test::AmethystApplication;
+    use collision_model::{
+        config::{Grab, GrabDuration, Hit, Interaction, InteractionKind},
+        loaded::{GrabbedTransition, GrabbingTransition},
+        play::{ContactEvent, GrabClock, GrabTracker, GrabbedBy},
+    };
+    use kinematic_model::config::Velocity;
+    use sequence_model::loaded::SequenceId;
+    use shape_model::Volume;
+
+    use collision_play::GrabEffectSystem;
+
+    #[test]
+    fn grabs_object_on_grab_contact() -> Result<(), Error> {
+        run_test(
+            SetupParams {
+                grabber_is_grabbing: false,
+                grabbed_is_grabbed: false,
+                interaction_kind: InteractionKind::Grab(grab()),
+            },
+            |world, grabber, grabbed| {
+                assert_eq!(
+                    Some(&GrabTracker::new(grabbed, grab(), GrabClock::new(10))),
+                    world.read_storage::<GrabTracker>().get(grabber)
+                );
+                assert_eq!(
+                    Some(&GrabbedBy::new(grabber)),
+                    world.read_storage::<GrabbedBy>().get(grabbed)
+                );
+                assert_eq!(
+                    Some(&SequenceId::new(1)),
+                    world.read_storage::<SequenceId>().get(grabber)
+                );
+                assert_eq!(
+                    Some(&SequenceId::new(2)),
+                    world.read_storage::<SequenceId>().get(grabbed)
+                );
+                assert_eq!(
+                    Some(&Velocity::default()),
+                    world.read_storage::<Velocity<f32>>().get(grabbed)
+                );
+            },
+        )
+    }
+
+    #[test]
+    fn does_not_grab_object_on_hit_contact() -> Result<(), Error> {
+        run_test(
+            SetupParams {
+                grabber_is_grabbing: false,
+                grabbed_is_grabbed: false,
+                interaction_kind: InteractionKind::Hit(Hit::default()),
+            },
+            |world, grabber, grabbed| {
+                assert!(!world.read_storage::<GrabTracker>().contains(grabber));
+                assert!(!world.read_storage::<GrabbedBy>().contains(grabbed));
+                assert_eq!(
+                    Some(&SequenceId::new(0)),
+                    world.read_storage::<SequenceId>().get(grabbed)
+                );
+            },
+        )
+    }
+
+    #[test]
+    fn does_not_grab_object_when_grabber_is_grabbing() -> Result<(), Error> {
+        run_test(
+            SetupParams {
+                grabber_is_grabbing: true,
+                grabbed_is_grabbed: false,
+                interaction_kind: InteractionKind::Grab(grab()),
+            },
+            |world, grabber, grabbed| {
+                assert_ne!(
+                    Some(grabbed),
+                    world
+                        .read_storage::<GrabTracker>()
+                        .get(grabber)
+                        .map(|grab_tracker| grab_tracker.entity)
+                );
+                assert!(!world.read_storage::<GrabbedBy>().contains(grabbed));
+            },
+        )
+    }
+
+    #[test]
+    fn does_not_grab_object_when_object_is_grabbed() -> Result<(), Error> {
+        run_test(
+            SetupParams {
+                grabber_is_grabbing: false,
+                grabbed_is_grabbed: true,
+                interaction_kind: InteractionKind::Grab(grab()),
+            },
+            |world, grabber, grabbed| {
+                assert!(!world.read_storage::<GrabTracker>().contains(grabber));
+                assert_ne!(
+                    Some(&GrabbedBy::new(grabber)),
+                    world.read_storage::<GrabbedBy>().get(grabbed)
+                );
+            },
+        )
+    }
+
+    fn run_test(
+        SetupParams {
+            grabber_is_grabbing,
+            grabbed_is_grabbed,
+            interaction_kind,
+        }: SetupParams,
+        assertion_fn: fn(&mut World, Entity, Entity),
+    ) -> Result<(), Error> {
+        AmethystApplication::blank()
+            .with_system(GrabEffectSystem::new(), "", &[])
+            .with_effect(move |world| {
+                let grabbed = world
+                    .create_entity()
+                    .with(GrabbedTransition::new(SequenceId::new(2)))
+                    .with(SequenceId::new(0))
+                    .with(Velocity::<f32>::new(1., 2., 3.))
+                    .build();
+                let other = world.create_entity().build();
+                if grabbed_is_grabbed {
+                    world
+                        .write_storage::<GrabbedBy>()
+                        .insert(grabbed, GrabbedBy::new(other))
+                        .expect("Failed to insert `GrabbedBy` component.");
+                }
+
+                let grabber = {
+                    let mut entity_builder = world
+                        .create_entity()
+                        .with(GrabbingTransition::new(SequenceId::new(1)))
+                        .with(SequenceId::new(0));
+                    if grabber_is_grabbing {
+                        entity_builder = entity_builder.with(GrabTracker::new(
+                            other,
+                            grab(),
+                            GrabClock::new(10),
+                        ));
+                    }
+                    entity_builder.build()
+                };
+
+                let contact_event = ContactEvent::new(
+                    grabber,
+                    grabbed,
+                    Interaction::new(interaction_kind, vec![], false),
+                    body(),
+                );
+                world
+                    .write_resource::<EventChannel<ContactEvent>>()
+                    .single_write(contact_event);
+
+                world.insert((grabber, grabbed));
+            })
+            .with_assertion(move |world| {
+                let (grabber, grabbed) = *world.read_resource::<(Entity, Entity)>();
+                assertion_fn(world, grabber, grabbed);
+            })
+            .run()
+    }
+
+    fn grab() -> Grab {
+        Grab::new(GrabDuration::new(10), Velocity::new(5, 3, 0))
+    }
+
+    fn body() -> Volume {
+        Volume::Box {
+            x: 0,
+            y: 0,
+            z: 0,
+            w: 1,
+            h: 1,
+            d: 1,
+        }
+    }
+
+    struct SetupParams {
+        grabber_is_grabbing: bool,
+        grabbed_is_grabbed: bool,
+        interaction_kind: InteractionKind,
+    }
+}
@@ -0,0 +1,179 @@
+#[cfg(test)]
+mod tests {
+    use amethyst::{
+        ecs::{Builder, Entity, World, WorldExt},
+        shrev::EventChannel,
+        Error,
+    };
+    use amethyst_test::AmethystApplication;
+    use collision_model::{
+        config::{Grab, GrabDuration, Hit, Interaction, InteractionKind},
+        loaded::GrabReleaseTransition,
+        play::{GrabClock, GrabTracker, GrabbedBy, HitEvent},
+    };
+    use kinematic_model::config::{Position, Velocity};
+    use mirrored_model::play::Mirrored;
+    use sequence_model::loaded::SequenceId;
+    use shape_model::Volume;
+
+    use collision_play::GrabHoldSystem;
+
+    #[test]
+    fn keeps_grabbed_object_with_grabber() -> Result<(), Error> {
+        run_test(
+            0,
+            |_, _| {},
+            |world, grabber, grabbed| {
+                assert_eq!(
+                    Some(GrabClock::new_with_value(3, 1)),
+                    world
+                        .read_storage::<GrabTracker>()
+                        .get(grabber)
+                        .map(|grab_tracker| grab_tracker.clock)
+                );
+                assert_eq!(
+                    Some(&GrabbedBy::new(grabber)),
+                    world.read_storage::<GrabbedBy>().get(grabbed)
+                );
+                assert_eq!(
+                    Some(&Position::new(10., 20., 30.)),
+                    world.read_storage::<Position<f32>>().get(grabbed)
+                );
+                assert_eq!(
+                    Some(&Mirrored::new(true)),
+                    world.read_storage::<Mirrored>().get(grabbed)
+                );
+                assert_eq!(
+                    Some(&SequenceId::new(1)),
+                    world.read_storage::<SequenceId>().get(grabbed)
+                );
+            },
+        )
+    }
+
+    #[test]
+    fn releases_grabbed_object_when_clock_completes() -> Result<(), Error> {
+        run_test(
+            2,
+            |_, _| {},
+            |world, grabber, grabbed| {
+                assert!(!world.read_storage::<GrabTracker>().contains(grabber));
+                assert!(!world.read_storage::<GrabbedBy>().contains(grabbed));
+                assert_eq!(
+                    Some(&SequenceId::new(4)),
+                    world.read_storage::<SequenceId>().get(grabber)
+                );
+                assert_eq!(
+                    Some(&SequenceId::new(4)),
+                    world.read_storage::<SequenceId>().get(grabbed)
+                );
+            },
+        )
+    }
+
+    #[test]
+    fn releases_grabbed_object_when_grabber_is_hit() -> Result<(), Error> {
+        run_test(
+            0,
+            |world, grabber| {
+                let hitter = world.create_entity().build();
+                let hit_event = HitEvent::new(
+                    hitter,
+                    grabber,
+                    Interaction::new(InteractionKind::Hit(Hit::default()), vec![], false),
+                    body(),
+                );
+                world
+                    .write_resource::<EventChannel<HitEvent>>()
+                    .single_write(hit_event);
+            },
+            |world, grabber, grabbed| {
+                assert!(!world.read_storage::<GrabTracker>().contains(grabber));
+                assert!(!world.read_storage::<GrabbedBy>().contains(grabbed));
+                // The grabber's hit sequence is not overwritten.
+                assert_eq!(
+                    Some(&SequenceId::new(0)),
+                    world.read_storage::<SequenceId>().get(grabber)
+                );
+                assert_eq!(
+                    Some(&SequenceId::new(4)),
+                    world.read_storage::<SequenceId>().get(grabbed)
+                );
+            },
+        )
+    }
+
+    #[test]
+    fn releases_grabbed_object_when_grabber_is_deleted() -> Result<(), Error> {
+        run_test(
+            0,
+            |world, grabber| {
+                world
+                    .delete_entity(grabber)
+                    .expect("Failed to delete grabber.");
+            },
+            |world, _grabber, grabbed| {
+                assert!(!world.read_storage::<GrabbedBy>().contains(grabbed));
+                assert_eq!(
+                    Some(&SequenceId::new(4)),
+                    world.read_storage::<SequenceId>().get(grabbed)
+                );
+            },
+        )
+    }
+
+    fn run_test(
+        clock_value: usize,
+        grabber_fn: fn(&mut World, Entity),
+        assertion_fn: fn(&mut World, Entity, Entity),
+    ) -> Result<(), Error> {
+        AmethystApplication::blank()
+            .with_system(GrabHoldSystem::new(), "", &[])
+            .with_effect(move |world| {
+                let grabbed = world
+                    .create_entity()
+                    .with(GrabReleaseTransition::new(SequenceId::new(4)))
+                    .with(SequenceId::new(1))
+                    .with(Position::<f32>::new(0., 0., 0.))
+                    .with(Mirrored::new(false))
+                    .build();
+                let grab = Grab::new(GrabDuration::new(3), Velocity::default());
+                let grabber = world
+                    .create_entity()
+                    .with(GrabReleaseTransition::new(SequenceId::new(4)))
+                    .with(GrabTracker::new(
+                        grabbed,
+                        grab,
+                        GrabClock::new_with_value(3, clock_value),
+                    ))
+                    .with(SequenceId::new(0))
+                    .with(Position::<f32>::new(10., 20., 30.))
+                    .with(Mirrored::new(false))
+                    .build();
+                world
+                    .write_storage::<GrabbedBy>()
+                    .insert(grabbed, GrabbedBy::new(grabber))
+                    .expect("Failed to insert `GrabbedBy` component.");
+
+                grabber_fn(world, grabber);
+
+                world.insert((grabber, grabbed));
+            })
+            .with_assertion(move |world| {
+                let (grabber, grabbed) = *world.read_resource::<(Entity, Entity)>();
+                assertion_fn(world, grabber, grabbed);
+            })
+            .run()
+    }
+
+    fn body() -> Volume {
+        Volume::Box {
+            x: 0,
+            y: 0,
+            z: 0,
+            w: 1,
+            h: 1,
+            d: 1,
+        }
+    }
+}
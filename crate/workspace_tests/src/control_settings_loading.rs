@@ -0,0 +1 @@
+mod keyboard_ui_gen;
@@ -0,0 +1,192 @@
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use amethyst::winit::event::VirtualKeyCode;
+    use control_settings_loading::KeyboardUiGen;
+    use control_settings_model::config::{
+        ControlButtonLabel, ControlButtonLabels, KeyboardLayout, KeyboardSettings,
+    };
+    use game_input_model::config::PlayerInputConfigs;
+    use kinematic_model::config::PositionInit;
+    use sequence_model::config::SequenceNameString;
+    use sprite_model::config::Scale;
+    use ui_label_model::config::UiSpriteLabel;
+    use ui_model::config::UiSequences;
+
+    #[test]
+    fn generate_full_uses_layout_positions_for_layout() {
+        let keyboard_settings = keyboard_settings(
+            KeyboardLayout::Us,
+            vec![
+                (KeyboardLayout::Us, VirtualKeyCode::Y, 1, "key_y"),
+                (KeyboardLayout::Us, VirtualKeyCode::Z, 2, "key_z"),
+            ],
+        );
+
+        let control_button_labels = KeyboardUiGen::generate_full(
+            &keyboard_settings,
+            &PlayerInputConfigs::default(),
+            &mut UiSequences::default(),
+        );
+
+        assert_eq!(
+            ControlButtonLabels::new(vec![
+                control_button_label(2, "key_z"),
+                control_button_label(1, "key_y"),
+            ]),
+            control_button_labels
+        );
+    }
+
+    #[test]
+    fn generate_full_uses_physical_layout_positions_when_layout_positions_not_present() {
+        let keyboard_settings = keyboard_settings(
+            KeyboardLayout::De,
+            vec![
+                (KeyboardLayout::Uk, VirtualKeyCode::Y, 1, "key_y"),
+                (KeyboardLayout::Uk, VirtualKeyCode::Z, 2, "key_z"),
+            ],
+        );
+
+        let control_button_labels = KeyboardUiGen::generate_full(
+            &keyboard_settings,
+            &PlayerInputConfigs::default(),
+            &mut UiSequences::default(),
+        );
+
+        // German keyboards swap the `Y` and `Z` keys.
+        assert_eq!(
+            ControlButtonLabels::new(vec![
+                control_button_label(2, "key_y"),
+                control_button_label(1, "key_z"),
+            ]),
+            control_button_labels
+        );
+    }
+
+    #[test]
+    fn generate_full_derives_iso_positions_from_us_layout_positions() {
+        let keyboard_settings = keyboard_settings(
+            KeyboardLayout::Uk,
+            vec![
+                (KeyboardLayout::Us, VirtualKeyCode::Z, 10, "key_z"),
+                (KeyboardLayout::Us, VirtualKeyCode::X, 12, "key_x"),
+                (
+                    KeyboardLayout::Us,
+                    VirtualKeyCode::Apostrophe,
+                    20,
+                    "key_apostrophe",
+                ),
+                (
+                    KeyboardLayout::Us,
+                    VirtualKeyCode::Backslash,
+                    30,
+                    "key_backslash",
+                ),
+            ],
+        );
+
+        let control_button_labels = KeyboardUiGen::generate_full(
+            &keyboard_settings,
+            &PlayerInputConfigs::default(),
+            &mut UiSequences::default(),
+        );
+
+        assert_eq!(
+            ControlButtonLabels::new(vec![
+                control_button_label(8, "key_backslash"),
+                control_button_label(10, "key_z"),
+                control_button_label(12, "key_x"),
+                control_button_label(20, "key_apostrophe"),
+                control_button_label(22, "key_backslash"),
+            ]),
+            control_button_labels
+        );
+    }
+
+    #[test]
+    fn generate_full_derives_laptop_positions_from_us_layout_positions() {
+        let keyboard_settings = keyboard_settings(
+            KeyboardLayout::UsLaptop,
+            vec![
+                (
+                    KeyboardLayout::Us,
+                    VirtualKeyCode::Snapshot,
+                    1,
+                    "key_snapshot",
+                ),
+                (KeyboardLayout::Us, VirtualKeyCode::Sysrq, 2, "key_sysrq"),
+                (KeyboardLayout::Us, VirtualKeyCode::Scroll, 3, "key_scroll"),
+                (KeyboardLayout::Us, VirtualKeyCode::Insert, 4, "key_insert"),
+                (KeyboardLayout::Us, VirtualKeyCode::Delete, 5, "key_delete"),
+            ],
+        );
+
+        let control_button_labels = KeyboardUiGen::generate_full(
+            &keyboard_settings,
+            &PlayerInputConfigs::default(),
+            &mut UiSequences::default(),
+        );
+
+        assert_eq!(
+            ControlButtonLabels::new(vec![
+                control_button_label(1, "key_snapshot"),
+                control_button_label(2, "key_insert"),
+                control_button_label(3, "key_delete"),
+            ]),
+            control_button_labels
+        );
+    }
+
+    #[test]
+    fn generate_full_returns_empty_labels_when_physical_and_us_layout_positions_not_present() {
+        let keyboard_settings = keyboard_settings(
+            KeyboardLayout::Fr,
+            vec![(KeyboardLayout::De, VirtualKeyCode::A, 1, "key_a")],
+        );
+
+        let control_button_labels = KeyboardUiGen::generate_full(
+            &keyboard_settings,
+            &PlayerInputConfigs::default(),
+            &mut UiSequences::default(),
+        );
+
+        assert_eq!(ControlButtonLabels::default(), control_button_labels);
+    }
+
+    fn keyboard_settings(
+        layout: KeyboardLayout,
+        key_positions: Vec<(KeyboardLayout, VirtualKeyCode, i32, &'static str)>,
+    ) -> KeyboardSettings {
+        let layout_positions = key_positions.into_iter().fold(
+            HashMap::new(),
+            |mut layout_positions, (key_layout, key, x, sequence)| {
+                layout_positions
+                    .entry(key_layout)
+                    .or_insert_with(HashMap::new)
+                    .insert(key, ui_sprite_label(x, sequence));
+                layout_positions
+            },
+        );
+
+        KeyboardSettings::new(
+            PositionInit::default(),
+            Scale::default(),
+            Vec::new(),
+            layout,
+            layout_positions,
+        )
+    }
+
+    fn control_button_label(x: i32, sequence: &'static str) -> ControlButtonLabel {
+        ControlButtonLabel::new(ui_sprite_label(x, sequence), None)
+    }
+
+    fn ui_sprite_label(x: i32, sequence: &'static str) -> UiSpriteLabel {
+        UiSpriteLabel::new(
+            PositionInit::new(x, 0, 0),
+            SequenceNameString::String(String::from(sequence)),
+        )
+    }
+}
@@ -0,0 +1,51 @@
+#[cfg(test)]
+mod tests {
+    use control_settings_model::config::KeyboardLayout;
+
+    #[test]
+    fn layouts_list_same_number_of_buttons_as_physical_layout() {
+        [
+            KeyboardLayout::Us,
+            KeyboardLayout::UsLaptop,
+            KeyboardLayout::Uk,
+            KeyboardLayout::De,
+            KeyboardLayout::Fr,
+        ]
+        .iter()
+        .copied()
+        .for_each(|layout| {
+            assert_eq!(
+                layout.physical_layout().buttons().len(),
+                layout.buttons().len(),
+                "Expected `{:?}` buttons to be aligned with its physical layout.",
+                layout
+            );
+        });
+    }
+
+    #[test]
+    fn layouts_do_not_list_duplicate_buttons() {
+        [
+            KeyboardLayout::Us,
+            KeyboardLayout::UsLaptop,
+            KeyboardLayout::Uk,
+            KeyboardLayout::De,
+            KeyboardLayout::Fr,
+        ]
+        .iter()
+        .copied()
+        .for_each(|layout| {
+            let mut buttons = layout.buttons();
+            let button_count = buttons.len();
+            buttons.sort();
+            buttons.dedup();
+
+            assert_eq!(
+                button_count,
+                buttons.len(),
+                "Expected `{:?}` to not have duplicate buttons.",
+                layout
+            );
+        });
+    }
+}
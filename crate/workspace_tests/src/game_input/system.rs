@@ -1,3 +1,4 @@
+mod control_input_history_update_system;
 mod controller_input_update_system;
 mod game_input_to_control_input_system;
 mod input_to_game_input_system;
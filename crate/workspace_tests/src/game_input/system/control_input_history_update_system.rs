@@ -0,0 +1,83 @@
+#[cfg(test)]
+mod tests {
+    use amethyst::{
+        ecs::{Builder, Entity, WorldExt},
+        shrev::EventChannel,
+        Error,
+    };
+    use amethyst_test::AmethystApplication;
+    use game_input_model::{
+        config::{Axis, CommandInput, ControlAction, InputCommand},
+        play::{AxisMoveEventData, ControlActionEventData, ControlInputEvent, ControlInputHistory},
+    };
+    use mirrored_model::play::Mirrored;
+
+    use game_input::ControlInputHistoryUpdateSystemDesc;
+
+    #[test]
+    fn records_repeated_axis_events_once() -> Result<(), Error> {
+        AmethystApplication::blank()
+            .with_system_desc(ControlInputHistoryUpdateSystemDesc::default(), "", &[])
+            .with_effect(|world| {
+                let entity = world.create_entity().with(Mirrored::new(false)).build();
+
+                let mut control_input_events = vec![ControlInputEvent::ControlActionPress(
+                    ControlActionEventData {
+                        controller_id: 0,
+                        entity,
+                        control_action: ControlAction::Defend,
+                    },
+                )];
+                // Analog sticks send an event for each change in axis value.
+                control_input_events.extend([0.4, 0.7, 1., 1.].iter().map(|value| {
+                    ControlInputEvent::AxisMoved(AxisMoveEventData {
+                        controller_id: 0,
+                        entity,
+                        axis: Axis::X,
+                        value: *value,
+                    })
+                }));
+                control_input_events.push(ControlInputEvent::ControlActionPress(
+                    ControlActionEventData {
+                        controller_id: 0,
+                        entity,
+                        control_action: ControlAction::Attack,
+                    },
+                ));
+                world
+                    .write_resource::<EventChannel<ControlInputEvent>>()
+                    .iter_write(control_input_events);
+
+                world.insert(entity);
+            })
+            .with_assertion(|world| {
+                let entity = *world.read_resource::<Entity>();
+                let control_input_histories = world.read_storage::<ControlInputHistory>();
+                let control_input_history = control_input_histories
+                    .get(entity)
+                    .expect("Expected entity to have `ControlInputHistory` component.");
+
+                assert_eq!(
+                    vec![
+                        CommandInput::Defend,
+                        CommandInput::Forward,
+                        CommandInput::Attack
+                    ],
+                    control_input_history
+                        .entries
+                        .iter()
+                        .map(|entry| entry.input)
+                        .collect::<Vec<CommandInput>>()
+                );
+                assert!(control_input_history.matches(&InputCommand::new(
+                    vec![
+                        CommandInput::Defend,
+                        CommandInput::Forward,
+                        CommandInput::Attack
+                    ],
+                    InputCommand::WINDOW_DEFAULT
+                )));
+            })
+            .run()
+    }
+}
@@ -1 +1,2 @@
+mod command_input;
 mod input_direction;
@@ -0,0 +1,54 @@
+#[cfg(test)]
+mod tests {
+    use game_input_model::config::{Axis, CommandInput, ControlAction};
+
+    #[test]
+    fn from_axis_returns_forward_when_x_input_matches_facing_direction() {
+        assert_eq!(
+            Some(CommandInput::Forward),
+            CommandInput::from_axis(Axis::X, 1., false)
+        );
+        assert_eq!(
+            Some(CommandInput::Forward),
+            CommandInput::from_axis(Axis::X, -1., true)
+        );
+    }
+
+    #[test]
+    fn from_axis_returns_back_when_x_input_opposes_facing_direction() {
+        assert_eq!(
+            Some(CommandInput::Back),
+            CommandInput::from_axis(Axis::X, -1., false)
+        );
+        assert_eq!(
+            Some(CommandInput::Back),
+            CommandInput::from_axis(Axis::X, 1., true)
+        );
+    }
+
+    #[test]
+    fn from_axis_returns_up_or_down_for_z_input_regardless_of_mirrored() {
+        assert_eq!(
+            Some(CommandInput::Up),
+            CommandInput::from_axis(Axis::Z, -1., true)
+        );
+        assert_eq!(
+            Some(CommandInput::Down),
+            CommandInput::from_axis(Axis::Z, 1., true)
+        );
+    }
+
+    #[test]
+    fn from_axis_returns_none_when_axis_value_is_zero() {
+        assert_eq!(None, CommandInput::from_axis(Axis::X, 0., false));
+        assert_eq!(None, CommandInput::from_axis(Axis::Z, 0., false));
+    }
+
+    #[test]
+    fn from_control_action_returns_matching_input() {
+        assert_eq!(
+            CommandInput::Attack,
+            CommandInput::from(ControlAction::Attack)
+        );
+    }
+}
@@ -1 +1,4 @@
+mod axis_input_quantizer;
+mod control_input_history;
+mod gamepad_assignments;
 mod move_direction;
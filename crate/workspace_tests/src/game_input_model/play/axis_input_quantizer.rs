@@ -0,0 +1,79 @@
+#[cfg(test)]
+mod tests {
+    use game_input_model::{
+        config::{Axis, ControlAction, PlayerActionControl, PlayerAxisControl},
+        play::AxisInputQuantizer,
+        GameInputEvent,
+    };
+
+    #[test]
+    fn quantizes_analog_axis_values() {
+        let mut axis_input_quantizer = AxisInputQuantizer::default();
+
+        assert_eq!(
+            Some(axis_moved(1.)),
+            axis_input_quantizer.quantize(axis_moved(0.6))
+        );
+        assert_eq!(
+            Some(axis_moved(-1.)),
+            axis_input_quantizer.quantize(axis_moved(-0.8))
+        );
+        assert_eq!(
+            Some(axis_moved(0.)),
+            axis_input_quantizer.quantize(axis_moved(0.))
+        );
+    }
+
+    #[test]
+    fn drops_axis_events_when_quantized_value_unchanged() {
+        let mut axis_input_quantizer = AxisInputQuantizer::default();
+
+        assert_eq!(
+            Some(axis_moved(1.)),
+            axis_input_quantizer.quantize(axis_moved(0.6))
+        );
+        assert_eq!(None, axis_input_quantizer.quantize(axis_moved(0.9)));
+        assert_eq!(None, axis_input_quantizer.quantize(axis_moved(1.)));
+    }
+
+    #[test]
+    fn tracks_axes_separately() {
+        let mut axis_input_quantizer = AxisInputQuantizer::default();
+        let axis_moved_z = GameInputEvent::AxisMoved {
+            axis: PlayerAxisControl::new(0, Axis::Z),
+            value: 1.,
+        };
+
+        assert_eq!(
+            Some(axis_moved(1.)),
+            axis_input_quantizer.quantize(axis_moved(1.))
+        );
+        assert_eq!(
+            Some(axis_moved_z),
+            axis_input_quantizer.quantize(axis_moved_z)
+        );
+    }
+
+    #[test]
+    fn passes_through_action_events() {
+        let mut axis_input_quantizer = AxisInputQuantizer::default();
+        let action_pressed =
+            GameInputEvent::ActionPressed(PlayerActionControl::new(0, ControlAction::Jump));
+
+        assert_eq!(
+            Some(action_pressed),
+            axis_input_quantizer.quantize(action_pressed)
+        );
+        assert_eq!(
+            Some(action_pressed),
+            axis_input_quantizer.quantize(action_pressed)
+        );
+    }
+
+    fn axis_moved(value: f32) -> GameInputEvent {
+        GameInputEvent::AxisMoved {
+            axis: PlayerAxisControl::new(0, Axis::X),
+            value,
+        }
+    }
+}
@@ -0,0 +1,137 @@
+#[cfg(test)]
+mod tests {
+    use game_input_model::{
+        config::{Axis, CommandInput, InputCommand},
+        play::ControlInputHistory,
+    };
+
+    #[test]
+    fn matches_returns_true_when_inputs_entered_in_order_within_window() {
+        let mut control_input_history = ControlInputHistory::new();
+        control_input_history.push(CommandInput::Defend);
+        control_input_history.advance();
+        control_input_history.push(CommandInput::Forward);
+        control_input_history.advance();
+        control_input_history.push(CommandInput::Attack);
+
+        assert!(control_input_history.matches(&defend_forward_attack(2)));
+    }
+
+    #[test]
+    fn matches_returns_false_when_inputs_entered_outside_window() {
+        let mut control_input_history = ControlInputHistory::new();
+        control_input_history.push(CommandInput::Defend);
+        control_input_history.advance();
+        control_input_history.push(CommandInput::Forward);
+        control_input_history.advance();
+        control_input_history.push(CommandInput::Attack);
+
+        assert!(!control_input_history.matches(&defend_forward_attack(1)));
+    }
+
+    #[test]
+    fn matches_returns_false_when_inputs_entered_out_of_order() {
+        let mut control_input_history = ControlInputHistory::new();
+        control_input_history.push(CommandInput::Forward);
+        control_input_history.push(CommandInput::Defend);
+        control_input_history.push(CommandInput::Attack);
+
+        assert!(!control_input_history.matches(&defend_forward_attack(2)));
+    }
+
+    #[test]
+    fn matches_returns_false_when_other_input_entered_in_between() {
+        let mut control_input_history = ControlInputHistory::new();
+        control_input_history.push(CommandInput::Defend);
+        control_input_history.push(CommandInput::Up);
+        control_input_history.push(CommandInput::Forward);
+        control_input_history.push(CommandInput::Attack);
+
+        assert!(!control_input_history.matches(&defend_forward_attack(2)));
+    }
+
+    #[test]
+    fn matches_returns_false_when_last_input_not_entered_on_current_tick() {
+        let mut control_input_history = ControlInputHistory::new();
+        control_input_history.push(CommandInput::Defend);
+        control_input_history.push(CommandInput::Forward);
+        control_input_history.push(CommandInput::Attack);
+        control_input_history.advance();
+
+        assert!(!control_input_history.matches(&defend_forward_attack(2)));
+    }
+
+    #[test]
+    fn push_forgets_oldest_input_when_at_capacity() {
+        let mut control_input_history = ControlInputHistory::new();
+        control_input_history.push(CommandInput::Defend);
+        (0..ControlInputHistory::CAPACITY).for_each(|_| {
+            control_input_history.push(CommandInput::Jump);
+        });
+
+        assert_eq!(
+            ControlInputHistory::CAPACITY,
+            control_input_history.entries.len()
+        );
+        assert!(control_input_history
+            .entries
+            .iter()
+            .all(|entry| entry.input == CommandInput::Jump));
+    }
+
+    #[test]
+    fn push_axis_ignores_repeated_axis_direction() {
+        let mut control_input_history = ControlInputHistory::new();
+        control_input_history.push_axis(Axis::X, 0.5, false);
+        control_input_history.push_axis(Axis::X, 0.8, false);
+        control_input_history.push_axis(Axis::X, 1., false);
+
+        assert_eq!(vec![CommandInput::Forward], inputs(&control_input_history));
+    }
+
+    #[test]
+    fn push_axis_records_same_direction_after_neutral() {
+        let mut control_input_history = ControlInputHistory::new();
+        control_input_history.push_axis(Axis::X, 1., false);
+        control_input_history.push_axis(Axis::X, 0., false);
+        control_input_history.push_axis(Axis::X, 1., false);
+
+        assert_eq!(
+            vec![CommandInput::Forward, CommandInput::Forward],
+            inputs(&control_input_history)
+        );
+    }
+
+    #[test]
+    fn push_axis_tracks_each_axis_separately() {
+        let mut control_input_history = ControlInputHistory::new();
+        control_input_history.push_axis(Axis::X, -1., false);
+        control_input_history.push_axis(Axis::Z, 1., false);
+        control_input_history.push_axis(Axis::X, -1., false);
+        control_input_history.push_axis(Axis::Z, -1., false);
+
+        assert_eq!(
+            vec![CommandInput::Back, CommandInput::Down, CommandInput::Up],
+            inputs(&control_input_history)
+        );
+    }
+
+    fn inputs(control_input_history: &ControlInputHistory) -> Vec<CommandInput> {
+        control_input_history
+            .entries
+            .iter()
+            .map(|entry| entry.input)
+            .collect::<Vec<CommandInput>>()
+    }
+
+    fn defend_forward_attack(window: u32) -> InputCommand {
+        InputCommand::new(
+            vec![
+                CommandInput::Defend,
+                CommandInput::Forward,
+                CommandInput::Attack,
+            ],
+            window,
+        )
+    }
+}
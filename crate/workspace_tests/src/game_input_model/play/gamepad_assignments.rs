@@ -0,0 +1,42 @@
+#[cfg(test)]
+mod tests {
+    use game_input_model::play::GamepadAssignments;
+
+    #[test]
+    fn assign_assigns_gamepad_to_first_free_player() {
+        let mut gamepad_assignments = GamepadAssignments::default();
+
+        assert_eq!(Some(0), gamepad_assignments.assign(3, 2));
+        assert_eq!(Some(1), gamepad_assignments.assign(5, 2));
+        assert_eq!(Some(3), gamepad_assignments.gamepad(0));
+        assert_eq!(Some(5), gamepad_assignments.gamepad(1));
+    }
+
+    #[test]
+    fn assign_returns_existing_player_when_gamepad_already_assigned() {
+        let mut gamepad_assignments = GamepadAssignments::default();
+
+        assert_eq!(Some(0), gamepad_assignments.assign(3, 2));
+        assert_eq!(Some(0), gamepad_assignments.assign(3, 2));
+        assert_eq!(None, gamepad_assignments.gamepad(1));
+    }
+
+    #[test]
+    fn assign_returns_none_when_every_player_has_gamepad() {
+        let mut gamepad_assignments = GamepadAssignments::default();
+
+        assert_eq!(Some(0), gamepad_assignments.assign(3, 1));
+        assert_eq!(None, gamepad_assignments.assign(5, 1));
+    }
+
+    #[test]
+    fn unassign_frees_player_for_next_gamepad() {
+        let mut gamepad_assignments = GamepadAssignments::default();
+        gamepad_assignments.assign(3, 2);
+        gamepad_assignments.assign(5, 2);
+
+        assert_eq!(Some(0), gamepad_assignments.unassign(3));
+        assert_eq!(None, gamepad_assignments.unassign(3));
+        assert_eq!(Some(0), gamepad_assignments.assign(7, 2));
+    }
+}
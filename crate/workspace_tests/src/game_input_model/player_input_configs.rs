@@ -3,14 +3,20 @@ mod tests {
     use std::{collections::HashMap, convert::TryFrom};
 
     use amethyst::{
-        input::{Axis as InputAxis, BindingError, Bindings, Button},
+        input::{
+            Axis as InputAxis, BindingError, Bindings, Button, ControllerAxis, ControllerButton,
+        },
         winit::event::VirtualKeyCode,
     };
     use hamcrest::prelude::*;
 
-    use game_input_model::config::{
-        Axis, ControlAction, ControlBindings, ControllerConfig, PlayerActionControl,
-        PlayerAxisControl, PlayerInputConfig, PlayerInputConfigs,
+    use game_input_model::{
+        config::{
+            Axis, ControlAction, ControlBindingTarget, ControlBindings, ControllerConfig,
+            GamepadAxis, PlayerActionControl, PlayerAxisControl, PlayerInputConfig,
+            PlayerInputConfigs,
+        },
+        play::{ControllerIdOffset, GameInputModelError, GamepadAssignments},
     };
 
     #[test]
@@ -140,6 +146,170 @@ mod tests {
         }
     }
 
+    #[test]
+    fn generate_bindings_binds_gamepad_controls_for_assigned_players() {
+        let player_input_configs = player_input_configs();
+        let mut gamepad_assignments = GamepadAssignments::default();
+        gamepad_assignments.assign(4, player_input_configs.len());
+
+        let bindings = player_input_configs
+            .generate_bindings(ControllerIdOffset::new(0), &gamepad_assignments)
+            .expect("Failed to generate `Bindings` with gamepad controls.");
+
+        assert_eq!(
+            Some(&InputAxis::Multiple(vec![
+                InputAxis::Emulated {
+                    neg: Button::Key(VirtualKeyCode::A),
+                    pos: Button::Key(VirtualKeyCode::D),
+                },
+                InputAxis::Controller {
+                    controller_id: 4,
+                    axis: ControllerAxis::LeftX,
+                    invert: false,
+                    dead_zone: f64::from(GamepadAxis::THRESHOLD_DEFAULT),
+                },
+            ])),
+            bindings.axis(&PlayerAxisControl::new(0, Axis::X))
+        );
+        assert_eq!(
+            Some(&InputAxis::Controller {
+                controller_id: 4,
+                axis: ControllerAxis::LeftY,
+                invert: false,
+                dead_zone: f64::from(GamepadAxis::THRESHOLD_DEFAULT),
+            }),
+            bindings.axis(&PlayerAxisControl::new(0, Axis::Z))
+        );
+        assert_that!(
+            &bindings
+                .action_bindings(&PlayerActionControl::new(0, ControlAction::Jump))
+                .map(<[Button]>::to_vec)
+                .collect::<Vec<Vec<Button>>>(),
+            contains(vec![
+                vec![Button::Key(VirtualKeyCode::Key1)],
+                vec![Button::Controller(4, ControllerButton::A)],
+            ])
+            .exactly()
+        );
+
+        // Player without a gamepad only has keyboard controls.
+        assert_eq!(None, bindings.axis(&PlayerAxisControl::new(1, Axis::Z)));
+    }
+
+    #[test]
+    fn binding_target_returns_control_bound_to_button() {
+        let player_input_configs = player_input_configs();
+
+        assert_eq!(
+            Some(ControlBindingTarget::AxisPositive(PlayerAxisControl::new(
+                1,
+                Axis::X
+            ))),
+            player_input_configs.binding_target(Button::Key(VirtualKeyCode::Right))
+        );
+        assert_eq!(
+            None,
+            player_input_configs.binding_target(Button::Key(VirtualKeyCode::Z))
+        );
+    }
+
+    #[test]
+    fn rebind_replaces_action_button() {
+        let mut player_input_configs = player_input_configs();
+        let target = ControlBindingTarget::Action(PlayerActionControl::new(0, ControlAction::Jump));
+
+        player_input_configs
+            .rebind(target, Button::Key(VirtualKeyCode::Z))
+            .expect("Expected rebind to succeed.");
+
+        assert_eq!(
+            Some(Button::Key(VirtualKeyCode::Z)),
+            player_input_configs.button(target)
+        );
+        assert_eq!(
+            None,
+            player_input_configs.binding_target(Button::Key(VirtualKeyCode::Key1))
+        );
+    }
+
+    #[test]
+    fn rebind_replaces_emulated_axis_button() {
+        let mut player_input_configs = player_input_configs();
+        let target = ControlBindingTarget::AxisNegative(PlayerAxisControl::new(1, Axis::X));
+
+        player_input_configs
+            .rebind(target, Button::Key(VirtualKeyCode::Z))
+            .expect("Expected rebind to succeed.");
+
+        assert_eq!(
+            Some(&InputAxis::Emulated {
+                neg: Button::Key(VirtualKeyCode::Z),
+                pos: Button::Key(VirtualKeyCode::Right),
+            }),
+            player_input_configs[1].controller_config.axes.get(&Axis::X)
+        );
+    }
+
+    #[test]
+    fn rebind_returns_error_when_button_bound_to_another_control() {
+        let mut player_input_configs = player_input_configs();
+        let target = ControlBindingTarget::Action(PlayerActionControl::new(0, ControlAction::Jump));
+
+        assert_eq!(
+            Err(GameInputModelError::ControlBindingConflict {
+                target,
+                button: Button::Key(VirtualKeyCode::O),
+                existing: ControlBindingTarget::Action(PlayerActionControl::new(
+                    1,
+                    ControlAction::Jump
+                )),
+            }),
+            player_input_configs.rebind(target, Button::Key(VirtualKeyCode::O))
+        );
+        assert_eq!(
+            Some(Button::Key(VirtualKeyCode::Key1)),
+            player_input_configs.button(target)
+        );
+    }
+
+    #[test]
+    fn rebind_returns_error_when_player_not_found() {
+        let mut player_input_configs = player_input_configs();
+        let target = ControlBindingTarget::Action(PlayerActionControl::new(2, ControlAction::Jump));
+
+        assert_eq!(
+            Err(GameInputModelError::ControlBindingPlayerNotFound { target }),
+            player_input_configs.rebind(target, Button::Key(VirtualKeyCode::Z))
+        );
+    }
+
+    #[test]
+    fn rebind_returns_error_when_axis_not_emulated() {
+        let mut player_input_configs = player_input_configs();
+        let target = ControlBindingTarget::AxisNegative(PlayerAxisControl::new(0, Axis::Z));
+
+        assert_eq!(
+            Err(GameInputModelError::ControlBindingAxisNotEmulated { target }),
+            player_input_configs.rebind(target, Button::Key(VirtualKeyCode::Z))
+        );
+    }
+
+    fn player_input_configs() -> PlayerInputConfigs {
+        let controller_config_0 =
+            controller_config([VirtualKeyCode::A, VirtualKeyCode::D, VirtualKeyCode::Key1]);
+        let controller_config_1 = controller_config([
+            VirtualKeyCode::Left,
+            VirtualKeyCode::Right,
+            VirtualKeyCode::O,
+        ]);
+
+        let player_input_config_0 =
+            PlayerInputConfig::new(String::from("zero1"), controller_config_0);
+        let player_input_config_1 =
+            PlayerInputConfig::new(String::from("one"), controller_config_1);
+        PlayerInputConfigs::new(vec![player_input_config_0, player_input_config_1])
+    }
+
     fn controller_config(keys: [VirtualKeyCode; 3]) -> ControllerConfig {
         let mut axes = HashMap::new();
         axes.insert(
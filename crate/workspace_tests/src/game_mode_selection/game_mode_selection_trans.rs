@@ -15,6 +15,14 @@ mod test {
         );
     }
 
+    #[test]
+    fn trans_returns_push_for_stage() {
+        assert_eq_trans(
+            &Trans::Push(Box::new(MockState)),
+            &GameModeSelectionTrans::trans(GameModeIndex::Stage),
+        );
+    }
+
     #[test]
     fn trans_returns_quit_for_exit() {
         assert_eq_trans(
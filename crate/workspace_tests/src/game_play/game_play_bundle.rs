@@ -1,10 +1,20 @@
 #[cfg(test)]
 mod test {
-    use amethyst::Error;
+    use amethyst::{
+        ecs::{Builder, WorldExt},
+        Error,
+    };
     use amethyst_test::AmethystApplication;
     use game_input_model::config::ControlBindings;
+    use game_play_model::GamePlayEntity;
+    use kinematic_model::config::{Position, Velocity};
+    use mirrored_model::play::Mirrored;
+    use object_model::play::{HealthPoints, SkillPoints};
+    use rollback_play::WorldSnapshot;
+    use sequence_model::loaded::SequenceId;
+    use snapshot_model::play::SnapshotRegistry;
 
-    use game_play::GamePlayBundle;
+    use game_play::{GamePlayBundle, GamePlaySnapshot};
 
     #[test]
     fn bundle_build_should_succeed() -> Result<(), Error> {
@@ -12,4 +22,76 @@ mod test {
             .with_bundle(GamePlayBundle::new())
             .run()
     }
+
+    #[test]
+    fn simulation_bundle_build_should_succeed() -> Result<(), Error> {
+        AmethystApplication::ui_base::<ControlBindings>()
+            .with_bundle(GamePlayBundle::new_simulation())
+            .run()
+    }
+
+    #[test]
+    fn game_play_snapshot_round_trips_registered_components() -> Result<(), Error> {
+        AmethystApplication::ui_base::<ControlBindings>()
+            .with_bundle(GamePlayBundle::new())
+            .with_assertion(|world| {
+                let entity = world
+                    .create_entity()
+                    .with(GamePlayEntity)
+                    .with(Position::<f32>::new(1., 2., 3.))
+                    .with(Velocity::<f32>::new(4., 5., 6.))
+                    .with(Mirrored(true))
+                    .with(HealthPoints::new(80))
+                    .with(SkillPoints::new(60))
+                    .with(SequenceId::new(3))
+                    .build();
+                let game_play_snapshot_expected = GamePlaySnapshot::capture(world);
+
+                *world
+                    .write_storage::<Position<f32>>()
+                    .get_mut(entity)
+                    .expect("Expected entity to have `Position<f32>` component.") =
+                    Position::<f32>::new(10., 20., 30.);
+                world.write_storage::<HealthPoints>().remove(entity);
+                world.write_storage::<SequenceId>().remove(entity);
+
+                game_play_snapshot_expected.restore(world);
+                world.maintain();
+                let game_play_snapshot_actual = GamePlaySnapshot::capture(world);
+
+                assert!(!game_play_snapshot_expected.bytes.is_empty());
+                assert_eq!(game_play_snapshot_expected, game_play_snapshot_actual);
+                assert_eq!(
+                    Some(&Position::<f32>::new(1., 2., 3.)),
+                    world.read_storage::<Position<f32>>().get(entity)
+                );
+                assert_eq!(
+                    Some(&HealthPoints::new(80)),
+                    world.read_storage::<HealthPoints>().get(entity)
+                );
+                assert_eq!(
+                    Some(&SequenceId::new(3)),
+                    world.read_storage::<SequenceId>().get(entity)
+                );
+            })
+            .run()
+    }
+
+    #[test]
+    fn game_play_snapshot_registry_keys_are_unique() -> Result<(), Error> {
+        AmethystApplication::ui_base::<ControlBindings>()
+            .with_bundle(GamePlayBundle::new())
+            .with_assertion(|world| {
+                let snapshot_registry = world.read_resource::<SnapshotRegistry>();
+                let mut keys = snapshot_registry.keys().collect::<Vec<&'static str>>();
+                let key_count = keys.len();
+                keys.sort_unstable();
+                keys.dedup();
+
+                assert_eq!(key_count, keys.len());
+                assert!(keys.contains(&"position"));
+                assert!(keys.contains(&"deferred_delete"));
+            })
+            .run()
+    }
 }
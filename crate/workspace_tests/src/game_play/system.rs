@@ -4,6 +4,7 @@ mod game_play_end_detection_system;
 mod game_play_end_transition_delay_system;
 mod game_play_end_transition_system;
 mod game_play_removal_augment_system;
+mod grab_throw_system;
 mod grounding_friction_system;
 mod object_kinematics_update_system;
 mod object_transform_update_system;
@@ -102,7 +102,7 @@ impl<'s> System<'s> for CharacterHitEffectSystem {
                     sequence_id,
                 ) {
                     // TODO: Split this system with health check system.
-                    let Interaction {
+                    let (hp_damage, stun, acceleration) = if let Interaction {
                         kind:
                             InteractionKind::Hit(Hit {
                                 hp_damage,
@@ -111,7 +111,12 @@ impl<'s> System<'s> for CharacterHitEffectSystem {
                                 ..
                             }),
                         ..
-                    } = ev.interaction;
+                    } = ev.interaction
+                    {
+                        (hp_damage, stun, acceleration)
+                    } else {
+                        return;
+                    };
                     if health_points.0 < hp_damage {
                         *health_points = HealthPoints(0);
                     } else {
@@ -155,3 +160,302 @@ impl<'s> System<'s> for CharacterHitEffectSystem {
         );
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use amethyst::{
+        ecs::{Builder, Entity, Join, World, WorldExt},
+        shrev::EventChannel,
+        Error,
+    };
+    use amethyst_test::AmethystApplication;
+    use character_model::loaded::CharacterHitTransitions;
+    use collision_model::{
+        config::{Hit, HitLimit, HitRepeatDelay, Interaction, InteractionKind},
+        play::HitEvent,
+    };
+    use game_stats_model::play::DamageDealt;
+    use kinematic_model::config::{Acceleration, Velocity};
+    use mirrored_model::play::Mirrored;
+    use object_model::play::{GuardPoints, HealthPoints, GUARD_POINTS_DEFAULT};
+    use object_status_model::config::StunPoints;
+    use sequence_model::loaded::SequenceId;
+    use shape_model::Volume;
+    use spawn_model::play::SpawnParent;
+
+    use game_play::{CharacterHitEffectSystem, BLOCK_CHIP_DAMAGE_DIVISOR};
+
+    const HP_DAMAGE: u32 = 20;
+    const STUN: u32 = 30;
+    const LOW_STUN: SequenceId = SequenceId(1);
+    const HIGH_STUN: SequenceId = SequenceId(3);
+    const DEFEND: SequenceId = SequenceId(5);
+
+    #[test]
+    fn blocks_hit_when_defending_and_facing_hitter() -> Result<(), Error> {
+        run_test(
+            SetupParams {
+                sequence_id: DEFEND,
+                defend: Some(DEFEND),
+                mirrored_to: true,
+                guard_points: GUARD_POINTS_DEFAULT,
+                hitter_spawned: false,
+            },
+            |world, hit| {
+                assert_eq!(
+                    Some(&HealthPoints::new(
+                        100 - HP_DAMAGE / BLOCK_CHIP_DAMAGE_DIVISOR
+                    )),
+                    world.read_storage::<HealthPoints>().get(hit)
+                );
+                assert_eq!(
+                    Some(&GuardPoints::new(GUARD_POINTS_DEFAULT - STUN)),
+                    world.read_storage::<GuardPoints>().get(hit)
+                );
+                assert_eq!(
+                    Some(&StunPoints::new(0)),
+                    world.read_storage::<StunPoints>().get(hit)
+                );
+                assert_eq!(Some(&DEFEND), world.read_storage::<SequenceId>().get(hit));
+            },
+        )
+    }
+
+    #[test]
+    fn does_not_block_hit_from_behind() -> Result<(), Error> {
+        run_test(
+            SetupParams {
+                sequence_id: DEFEND,
+                defend: Some(DEFEND),
+                mirrored_to: false,
+                guard_points: GUARD_POINTS_DEFAULT,
+                hitter_spawned: false,
+            },
+            |world, hit| {
+                assert_eq!(
+                    Some(&HealthPoints::new(100 - HP_DAMAGE)),
+                    world.read_storage::<HealthPoints>().get(hit)
+                );
+                assert_eq!(
+                    Some(&GuardPoints::new(GUARD_POINTS_DEFAULT)),
+                    world.read_storage::<GuardPoints>().get(hit)
+                );
+                assert_eq!(
+                    Some(&StunPoints::new(STUN)),
+                    world.read_storage::<StunPoints>().get(hit)
+                );
+                assert_eq!(Some(&LOW_STUN), world.read_storage::<SequenceId>().get(hit));
+            },
+        )
+    }
+
+    #[test]
+    fn does_not_block_hit_when_not_defending() -> Result<(), Error> {
+        run_test(
+            SetupParams {
+                sequence_id: SequenceId(0),
+                defend: Some(DEFEND),
+                mirrored_to: true,
+                guard_points: GUARD_POINTS_DEFAULT,
+                hitter_spawned: false,
+            },
+            |world, hit| {
+                assert_eq!(
+                    Some(&HealthPoints::new(100 - HP_DAMAGE)),
+                    world.read_storage::<HealthPoints>().get(hit)
+                );
+                assert_eq!(Some(&LOW_STUN), world.read_storage::<SequenceId>().get(hit));
+            },
+        )
+    }
+
+    #[test]
+    fn does_not_block_hit_when_character_has_no_defend_sequence() -> Result<(), Error> {
+        run_test(
+            SetupParams {
+                sequence_id: SequenceId(0),
+                defend: None,
+                mirrored_to: true,
+                guard_points: GUARD_POINTS_DEFAULT,
+                hitter_spawned: false,
+            },
+            |world, hit| {
+                assert_eq!(
+                    Some(&HealthPoints::new(100 - HP_DAMAGE)),
+                    world.read_storage::<HealthPoints>().get(hit)
+                );
+                assert_eq!(
+                    Some(&GuardPoints::new(GUARD_POINTS_DEFAULT)),
+                    world.read_storage::<GuardPoints>().get(hit)
+                );
+                assert_eq!(
+                    Some(&StunPoints::new(STUN)),
+                    world.read_storage::<StunPoints>().get(hit)
+                );
+                assert_eq!(Some(&LOW_STUN), world.read_storage::<SequenceId>().get(hit));
+            },
+        )
+    }
+
+    #[test]
+    fn breaks_guard_into_dazed_when_guard_points_run_out() -> Result<(), Error> {
+        run_test(
+            SetupParams {
+                sequence_id: DEFEND,
+                defend: Some(DEFEND),
+                mirrored_to: true,
+                guard_points: STUN,
+                hitter_spawned: false,
+            },
+            |world, hit| {
+                assert_eq!(
+                    Some(&HealthPoints::new(
+                        100 - HP_DAMAGE / BLOCK_CHIP_DAMAGE_DIVISOR
+                    )),
+                    world.read_storage::<HealthPoints>().get(hit)
+                );
+                assert_eq!(
+                    Some(&GuardPoints::new(0)),
+                    world.read_storage::<GuardPoints>().get(hit)
+                );
+                assert_eq!(
+                    Some(&HIGH_STUN),
+                    world.read_storage::<SequenceId>().get(hit)
+                );
+            },
+        )
+    }
+
+    #[test]
+    fn credits_damage_dealt_to_hitter() -> Result<(), Error> {
+        run_test(
+            SetupParams {
+                sequence_id: SequenceId(0),
+                defend: Some(DEFEND),
+                mirrored_to: true,
+                guard_points: GUARD_POINTS_DEFAULT,
+                hitter_spawned: false,
+            },
+            |world, hit| {
+                let damage_dealts = world.read_storage::<DamageDealt>();
+                assert_eq!(None, damage_dealts.get(hit));
+                assert_eq!(
+                    vec![DamageDealt::new(HP_DAMAGE)],
+                    damage_dealts.join().copied().collect::<Vec<DamageDealt>>()
+                );
+            },
+        )
+    }
+
+    #[test]
+    fn credits_damage_dealt_by_spawned_object_to_spawn_parent() -> Result<(), Error> {
+        run_test(
+            SetupParams {
+                sequence_id: SequenceId(0),
+                defend: Some(DEFEND),
+                mirrored_to: true,
+                guard_points: GUARD_POINTS_DEFAULT,
+                hitter_spawned: true,
+            },
+            |world, hit| {
+                let spawn_parent = world
+                    .read_storage::<SpawnParent>()
+                    .join()
+                    .next()
+                    .map(|spawn_parent| spawn_parent.entity)
+                    .expect("Expected hitter to have a `SpawnParent` component.");
+                let damage_dealts = world.read_storage::<DamageDealt>();
+                assert_eq!(None, damage_dealts.get(hit));
+                assert_eq!(
+                    Some(&DamageDealt::new(HP_DAMAGE)),
+                    damage_dealts.get(spawn_parent)
+                );
+                assert_eq!(1, damage_dealts.join().count());
+            },
+        )
+    }
+
+    fn run_test(
+        SetupParams {
+            sequence_id,
+            defend,
+            mirrored_to,
+            guard_points,
+            hitter_spawned,
+        }: SetupParams,
+        assertion_fn: fn(&mut World, Entity),
+    ) -> Result<(), Error> {
+        AmethystApplication::blank()
+            .with_system(CharacterHitEffectSystem::new(), "", &[])
+            .with_effect(move |world| {
+                let hitter = if hitter_spawned {
+                    let spawn_parent = world.create_entity().with(Mirrored::new(false)).build();
+                    world
+                        .create_entity()
+                        .with(Mirrored::new(false))
+                        .with(SpawnParent::new(spawn_parent))
+                        .build()
+                } else {
+                    world.create_entity().with(Mirrored::new(false)).build()
+                };
+                let hit = world
+                    .create_entity()
+                    .with(CharacterHitTransitions::new(
+                        LOW_STUN,
+                        SequenceId(2),
+                        HIGH_STUN,
+                        SequenceId(4),
+                        defend,
+                    ))
+                    .with(HealthPoints::new(100))
+                    .with(GuardPoints::new(guard_points))
+                    .with(StunPoints::new(0))
+                    .with(Velocity::<f32>::default())
+                    .with(Mirrored::new(mirrored_to))
+                    .with(sequence_id)
+                    .build();
+
+                let interaction = Interaction::new(
+                    InteractionKind::Hit(Hit::new(
+                        HitRepeatDelay::default(),
+                        HitLimit::default(),
+                        HP_DAMAGE,
+                        0,
+                        StunPoints::new(STUN),
+                        Acceleration::default(),
+                    )),
+                    vec![],
+                    false,
+                );
+                world
+                    .write_resource::<EventChannel<HitEvent>>()
+                    .single_write(HitEvent::new(hitter, hit, interaction, body()));
+
+                world.insert(hit);
+            })
+            .with_assertion(move |world| {
+                let hit = *world.read_resource::<Entity>();
+                assertion_fn(world, hit);
+            })
+            .run()
+    }
+
+    fn body() -> Volume {
+        Volume::Box {
+            x: 0,
+            y: 0,
+            z: 0,
+            w: 1,
+            h: 1,
+            d: 1,
+        }
+    }
+
+    struct SetupParams {
+        sequence_id: SequenceId,
+        defend: Option<SequenceId>,
+        mirrored_to: bool,
+        guard_points: u32,
+        hitter_spawned: bool,
+    }
+}
@@ -7,7 +7,10 @@ mod tests {
         Error,
     };
     use amethyst_test::AmethystApplication;
-    use game_play_model::{GamePlayEvent, GamePlayStatus};
+    use game_play_model::{
+        play::{StageProgress, STAGE_ENEMY_TEAM, STAGE_PLAYER_TEAM},
+        GameMode, GamePlayEvent, GamePlayStatus,
+    };
     use game_stats_model::play::{WinOutcome, WinStatus};
     use object_model::play::HealthPoints;
     use std::any;
@@ -20,6 +23,8 @@ mod tests {
         run_test(
             SetupParams {
                 game_play_status: GamePlayStatus::Ended,
+                game_mode: GameMode::Versus,
+                stage_progress: StageProgress::default(),
                 objects: vec![
                     ObjectStatus {
                         team: Team::Number(TeamCounter::new(0)),
@@ -45,6 +50,8 @@ mod tests {
         run_test(
             SetupParams {
                 game_play_status: GamePlayStatus::Playing,
+                game_mode: GameMode::Versus,
+                stage_progress: StageProgress::default(),
                 objects: vec![
                     ObjectStatus {
                         team: winning_team,
@@ -71,6 +78,8 @@ mod tests {
         run_test(
             SetupParams {
                 game_play_status: GamePlayStatus::Playing,
+                game_mode: GameMode::Versus,
+                stage_progress: StageProgress::default(),
                 objects: vec![
                     ObjectStatus {
                         team: winning_team,
@@ -99,6 +108,8 @@ mod tests {
         run_test(
             SetupParams {
                 game_play_status: GamePlayStatus::Playing,
+                game_mode: GameMode::Versus,
+                stage_progress: StageProgress::default(),
                 objects: vec![
                     ObjectStatus {
                         team: Team::Independent(IndependentCounter::new(0)),
@@ -124,6 +135,8 @@ mod tests {
         run_test(
             SetupParams {
                 game_play_status: GamePlayStatus::Playing,
+                game_mode: GameMode::Versus,
+                stage_progress: StageProgress::default(),
                 objects: vec![
                     ObjectStatus {
                         team: Team::Independent(IndependentCounter::new(0)),
@@ -143,9 +156,105 @@ mod tests {
         )
     }
 
+    #[test]
+    fn does_not_send_game_play_end_event_when_stage_waves_remaining() -> Result<(), Error> {
+        run_test(
+            SetupParams {
+                game_play_status: GamePlayStatus::Playing,
+                game_mode: GameMode::Stage,
+                stage_progress: StageProgress {
+                    phase_index: 1,
+                    phase_count: 2,
+                    ..Default::default()
+                },
+                objects: vec![
+                    ObjectStatus {
+                        team: STAGE_PLAYER_TEAM,
+                        liveness: Liveness::Alive,
+                    },
+                    ObjectStatus {
+                        team: STAGE_ENEMY_TEAM,
+                        liveness: Liveness::Dead,
+                    },
+                ],
+            },
+            ExpectedParams {
+                game_play_status: GamePlayStatus::Playing,
+                game_play_events: vec![],
+                win_status: WinStatus::default(),
+            },
+        )
+    }
+
+    #[test]
+    fn sends_game_play_end_event_when_stage_complete() -> Result<(), Error> {
+        run_test(
+            SetupParams {
+                game_play_status: GamePlayStatus::Playing,
+                game_mode: GameMode::Stage,
+                stage_progress: StageProgress {
+                    phase_index: 2,
+                    phase_count: 2,
+                    ..Default::default()
+                },
+                objects: vec![
+                    ObjectStatus {
+                        team: STAGE_PLAYER_TEAM,
+                        liveness: Liveness::Alive,
+                    },
+                    ObjectStatus {
+                        team: STAGE_ENEMY_TEAM,
+                        liveness: Liveness::Dead,
+                    },
+                ],
+            },
+            ExpectedParams {
+                game_play_status: GamePlayStatus::Ended,
+                game_play_events: vec![GamePlayEvent::End],
+                win_status: WinStatus::new(WinOutcome::WinLoss {
+                    winning_team: STAGE_PLAYER_TEAM,
+                }),
+            },
+        )
+    }
+
+    #[test]
+    fn sends_game_play_end_event_when_stage_players_defeated() -> Result<(), Error> {
+        run_test(
+            SetupParams {
+                game_play_status: GamePlayStatus::Playing,
+                game_mode: GameMode::Stage,
+                stage_progress: StageProgress {
+                    phase_index: 1,
+                    phase_count: 2,
+                    ..Default::default()
+                },
+                objects: vec![
+                    ObjectStatus {
+                        team: STAGE_PLAYER_TEAM,
+                        liveness: Liveness::Dead,
+                    },
+                    ObjectStatus {
+                        team: STAGE_ENEMY_TEAM,
+                        liveness: Liveness::Alive,
+                    },
+                ],
+            },
+            ExpectedParams {
+                game_play_status: GamePlayStatus::Ended,
+                game_play_events: vec![GamePlayEvent::End],
+                win_status: WinStatus::new(WinOutcome::WinLoss {
+                    winning_team: STAGE_ENEMY_TEAM,
+                }),
+            },
+        )
+    }
+
     fn run_test(
         SetupParams {
             game_play_status: game_play_status_setup,
+            game_mode,
+            stage_progress,
             objects,
         }: SetupParams,
         ExpectedParams {
@@ -156,6 +265,8 @@ mod tests {
     ) -> Result<(), Error> {
         AmethystApplication::blank()
             .with_resource(game_play_status_setup)
+            .with_resource(game_mode)
+            .with_resource(stage_progress)
             .with_setup(GamePlayEndDetectionSystemData::setup)
             .with_setup(register_event_reader)
             .with_effect(move |world| {
@@ -208,6 +319,8 @@ mod tests {
 
     struct SetupParams {
         game_play_status: GamePlayStatus,
+        game_mode: GameMode,
+        stage_progress: StageProgress,
         objects: Vec<ObjectStatus>,
     }
 
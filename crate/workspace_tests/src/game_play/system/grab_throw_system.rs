@@ -0,0 +1,114 @@
+#[cfg(test)]
+mod tests {
+    use amethyst::{
+        ecs::{Builder, Entity, World, WorldExt},
+        shrev::EventChannel,
+        Error,
+    };
+    use amethyst_test::AmethystApplication;
+    use collision_model::{
+        config::{Grab, GrabDuration},
+        loaded::{ThrowingTransition, ThrownTransition},
+        play::{GrabClock, GrabTracker, GrabbedBy},
+    };
+    use game_input_model::{
+        config::ControlAction,
+        play::{ControlActionEventData, ControlInputEvent},
+    };
+    use kinematic_model::config::Velocity;
+    use mirrored_model::play::Mirrored;
+    use sequence_model::loaded::SequenceId;
+
+    use game_play::GrabThrowSystem;
+
+    #[test]
+    fn throws_grabbed_object_on_attack_press() -> Result<(), Error> {
+        run_test(false, ControlAction::Attack, |world, grabber, grabbed| {
+            assert!(!world.read_storage::<GrabTracker>().contains(grabber));
+            assert!(!world.read_storage::<GrabbedBy>().contains(grabbed));
+            assert_eq!(
+                Some(&SequenceId::new(3)),
+                world.read_storage::<SequenceId>().get(grabber)
+            );
+            assert_eq!(
+                Some(&SequenceId::new(4)),
+                world.read_storage::<SequenceId>().get(grabbed)
+            );
+            assert_eq!(
+                Some(&Velocity::new(5., 3., 0.)),
+                world.read_storage::<Velocity<f32>>().get(grabbed)
+            );
+        })
+    }
+
+    #[test]
+    fn throws_grabbed_object_in_mirrored_direction() -> Result<(), Error> {
+        run_test(true, ControlAction::Attack, |world, _grabber, grabbed| {
+            assert_eq!(
+                Some(&Velocity::new(-5., 3., 0.)),
+                world.read_storage::<Velocity<f32>>().get(grabbed)
+            );
+        })
+    }
+
+    #[test]
+    fn does_not_throw_grabbed_object_on_non_attack_press() -> Result<(), Error> {
+        run_test(false, ControlAction::Jump, |world, grabber, grabbed| {
+            assert!(world.read_storage::<GrabTracker>().contains(grabber));
+            assert_eq!(
+                Some(&GrabbedBy::new(grabber)),
+                world.read_storage::<GrabbedBy>().get(grabbed)
+            );
+            assert_eq!(
+                Some(&SequenceId::new(2)),
+                world.read_storage::<SequenceId>().get(grabbed)
+            );
+        })
+    }
+
+    fn run_test(
+        mirrored: bool,
+        control_action: ControlAction,
+        assertion_fn: fn(&mut World, Entity, Entity),
+    ) -> Result<(), Error> {
+        AmethystApplication::blank()
+            .with_system(GrabThrowSystem::new(), "", &[])
+            .with_effect(move |world| {
+                let grabbed = world
+                    .create_entity()
+                    .with(ThrownTransition::new(SequenceId::new(4)))
+                    .with(SequenceId::new(2))
+                    .with(Velocity::<f32>::default())
+                    .build();
+                let grab = Grab::new(GrabDuration::new(10), Velocity::new(5, 3, 0));
+                let grabber = world
+                    .create_entity()
+                    .with(ThrowingTransition::new(SequenceId::new(3)))
+                    .with(GrabTracker::new(grabbed, grab, GrabClock::new(10)))
+                    .with(SequenceId::new(1))
+                    .with(Mirrored::new(mirrored))
+                    .build();
+                world
+                    .write_storage::<GrabbedBy>()
+                    .insert(grabbed, GrabbedBy::new(grabber))
+                    .expect("Failed to insert `GrabbedBy` component.");
+
+                let control_input_event =
+                    ControlInputEvent::ControlActionPress(ControlActionEventData {
+                        controller_id: 0,
+                        entity: grabber,
+                        control_action,
+                    });
+                world
+                    .write_resource::<EventChannel<ControlInputEvent>>()
+                    .single_write(control_input_event);
+
+                world.insert((grabber, grabbed));
+            })
+            .with_assertion(move |world| {
+                let (grabber, grabbed) = *world.read_resource::<(Entity, Entity)>();
+                assertion_fn(world, grabber, grabbed);
+            })
+            .run()
+    }
+}
@@ -25,4 +25,6 @@ mod tests {
     test_mapping!(maps_resume_event, Resume);
     test_mapping!(maps_end_event, End);
     test_mapping!(maps_end_stats_event, EndStats);
+    test_mapping!(maps_save_state_event, SaveState);
+    test_mapping!(maps_load_state_event, LoadState);
 }
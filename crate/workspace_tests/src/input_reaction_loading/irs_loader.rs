@@ -19,12 +19,14 @@ mod tests {
         loaded::{CharacterInputReactions, CharacterIrs, CharacterIrsHandle},
     };
     use charge_model::config::ChargePoints;
-    use game_input_model::config::{Axis, ControlAction, InputDirection};
+    use game_input_model::config::{
+        Axis, CommandInput, ControlAction, InputCommand, InputDirection,
+    };
     use input_reaction_model::{
         config::{InputReactionAppEvent, InputReactionAppEvents},
         loaded::{
-            AxisTransition, FallbackTransition, InputReaction, InputReactions, ReactionEffect,
-            ReactionEffectButton, ReactionEffectData,
+            AxisTransition, CommandTransition, FallbackTransition, InputReaction, InputReactions,
+            ReactionEffect, ReactionEffectButton, ReactionEffectData,
         },
     };
     use object_model::play::{HealthPoints, SkillPoints};
@@ -142,6 +144,39 @@ mod tests {
         )
     }
 
+    #[test]
+    fn loads_command_irses() -> Result<(), Error> {
+        run_test(
+            sequence_with_command(),
+            None,
+            |character_irs, input_reactions_assets| {
+                let expected_character_input_reactions = expected_input_reactions_5();
+                let character_input_reactions_handle = character_irs
+                    .get(0)
+                    .expect("Expected `CharacterInputReactionsHandle` to exist.");
+                let character_input_reactions = input_reactions_assets
+                    .get(character_input_reactions_handle)
+                    .expect("Expected `CharacterInputReactions` to be loaded.");
+                assert_eq!(
+                    &expected_character_input_reactions,
+                    character_input_reactions
+                );
+
+                let expected_character_input_reactions = expected_input_reactions_6();
+                let character_input_reactions_handle = character_irs
+                    .get(1)
+                    .expect("Expected `CharacterInputReactionsHandle` to exist.");
+                let character_input_reactions = input_reactions_assets
+                    .get(character_input_reactions_handle)
+                    .expect("Expected `CharacterInputReactions` to be loaded.");
+                assert_eq!(
+                    &expected_character_input_reactions,
+                    character_input_reactions
+                );
+            },
+        )
+    }
+
     fn run_test(
         sequence: CharacterSequence,
         sequence_default: Option<&'static CharacterSequence>,
@@ -247,6 +282,25 @@ mod tests {
             .expect("Failed to load `irs_loader_sequence_with_press_button.yaml`.")
     }
 
+    fn sequence_with_command() -> CharacterSequence {
+        let sequence_with_command_yaml = "irs_loader_sequence_with_command.yaml";
+        let sequence_with_command_path = PathBuf::from_iter(&[
+            env!("CARGO_MANIFEST_DIR"),
+            "src",
+            "input_reaction_loading",
+            sequence_with_command_yaml,
+        ]);
+        let contents = IoUtils::read_file(&sequence_with_command_path).unwrap_or_else(|e| {
+            panic!(
+                "Failed to read `{}`. Error: {}",
+                sequence_with_command_yaml, e
+            )
+        });
+
+        serde_yaml::from_slice::<CharacterSequence>(&contents)
+            .expect("Failed to load `irs_loader_sequence_with_command.yaml`.")
+    }
+
     fn sequence_id_mappings() -> SequenceIdMappings<CharacterSequenceName> {
         let mut sequence_id_mappings = SequenceIdMappings::new();
         sequence_id_mappings.insert(
@@ -576,6 +630,55 @@ mod tests {
         ])
     }
 
+    // Command input reactions should take priority over other input reactions.
+    fn expected_input_reactions_5() -> CharacterInputReactions {
+        let mut events = InputReactionAppEvents::default();
+        events.push(InputReactionAppEvent::AssetSelection(
+            AssetSelectionEventCommand::Join,
+        ));
+
+        InputReactions::new(vec![
+            InputReaction {
+                effect: ReactionEffect::Command(CommandTransition {
+                    command: InputCommand::new(
+                        vec![CommandInput::Defend, CommandInput::Up, CommandInput::Jump],
+                        20,
+                    ),
+                    sequence_id: SequenceId::new(6),
+                    events,
+                }),
+                requirement: CharacterIrr::default(),
+            },
+            InputReaction {
+                effect: ReactionEffect::ActionPress(ReactionEffectData {
+                    action: ControlAction::Attack,
+                    sequence_id: SequenceId::new(4),
+                    events: InputReactionAppEvents::default(),
+                }),
+                requirement: CharacterIrr::default(),
+            },
+        ])
+    }
+
+    // Should inherit sequence command input reactions, with the default window.
+    fn expected_input_reactions_6() -> CharacterInputReactions {
+        InputReactions::new(vec![InputReaction {
+            effect: ReactionEffect::Command(CommandTransition {
+                command: InputCommand::new(
+                    vec![
+                        CommandInput::Defend,
+                        CommandInput::Forward,
+                        CommandInput::Attack,
+                    ],
+                    InputCommand::WINDOW_DEFAULT,
+                ),
+                sequence_id: SequenceId::new(8),
+                events: InputReactionAppEvents::default(),
+            }),
+            requirement: CharacterIrr::default(),
+        }])
+    }
+
     type TestSystemData<'s> = (
         ReadExpect<'s, Loader>,
         Read<'s, AssetStorage<CharacterInputReactions>>,
@@ -23,10 +23,10 @@ mod tests {
         play::{ChargeTrackerClock, ChargeUseEvent},
     };
     use game_input_model::{
-        config::{Axis, ControlAction},
+        config::{Axis, CommandInput, ControlAction},
         play::{
-            AxisMoveEventData, ControlActionEventData, ControlInputEvent, ControllerInput,
-            InputControlled,
+            AxisMoveEventData, ControlActionEventData, ControlInputEvent, ControlInputHistory,
+            ControllerInput, InputControlled,
         },
     };
     use input_reaction_loading::{IrsLoader, IrsLoaderParams};
@@ -45,6 +45,7 @@ mod tests {
             SetupParams {
                 sequence_id: SequenceId::new(0),
                 controller_input: ControllerInput::default(),
+                control_input_history: ControlInputHistory::default(),
                 control_input_event_fn: Some(|entity| {
                     let control_action_event_data = ControlActionEventData {
                         controller_id: 0,
@@ -68,6 +69,7 @@ mod tests {
             SetupParams {
                 sequence_id: SequenceId::new(0),
                 controller_input: ControllerInput::default(),
+                control_input_history: ControlInputHistory::default(),
                 control_input_event_fn: Some(|entity| {
                     let control_action_event_data = ControlActionEventData {
                         controller_id: 0,
@@ -95,6 +97,7 @@ mod tests {
             SetupParams {
                 sequence_id: SequenceId::new(0),
                 controller_input,
+                control_input_history: ControlInputHistory::default(),
                 control_input_event_fn: None,
                 charge_tracker_clock: ChargeTrackerClock::new_with_value(100, 100),
             },
@@ -114,6 +117,7 @@ mod tests {
             SetupParams {
                 sequence_id: SequenceId::new(0),
                 controller_input,
+                control_input_history: ControlInputHistory::default(),
                 control_input_event_fn: Some(|entity| {
                     let control_action_event_data = ControlActionEventData {
                         controller_id: 0,
@@ -141,6 +145,7 @@ mod tests {
             SetupParams {
                 sequence_id: SequenceId::new(0),
                 controller_input,
+                control_input_history: ControlInputHistory::default(),
                 control_input_event_fn: Some(|entity| {
                     let control_action_event_data = ControlActionEventData {
                         controller_id: 0,
@@ -158,12 +163,62 @@ mod tests {
         )
     }
 
+    #[test]
+    fn inserts_transition_for_command_when_command_entered() -> Result<(), Error> {
+        run_test(
+            SetupParams {
+                sequence_id: SequenceId::new(0),
+                controller_input: ControllerInput::default(),
+                control_input_history: defend_forward_attack_history(),
+                control_input_event_fn: Some(|entity| {
+                    let control_action_event_data = ControlActionEventData {
+                        controller_id: 0,
+                        entity,
+                        control_action: ControlAction::Attack,
+                    };
+                    ControlInputEvent::ControlActionPress(control_action_event_data)
+                }),
+                charge_tracker_clock: ChargeTrackerClock::new_with_value(100, 100),
+            },
+            ExpectedParams {
+                sequence_id: SequenceId::new(5),
+                charge_use_events_fn: None,
+            },
+        )
+    }
+
+    #[test]
+    fn does_not_insert_command_transition_when_event_is_not_last_command_input() -> Result<(), Error>
+    {
+        run_test(
+            SetupParams {
+                sequence_id: SequenceId::new(0),
+                controller_input: ControllerInput::default(),
+                control_input_history: defend_forward_attack_history(),
+                control_input_event_fn: Some(|entity| {
+                    let control_action_event_data = ControlActionEventData {
+                        controller_id: 0,
+                        entity,
+                        control_action: ControlAction::Jump,
+                    };
+                    ControlInputEvent::ControlActionPress(control_action_event_data)
+                }),
+                charge_tracker_clock: ChargeTrackerClock::new_with_value(100, 100),
+            },
+            ExpectedParams {
+                sequence_id: SequenceId::new(6),
+                charge_use_events_fn: None,
+            },
+        )
+    }
+
     #[test]
     fn inserts_transition_for_axis_press_event() -> Result<(), Error> {
         run_test(
             SetupParams {
                 sequence_id: SequenceId::new(0),
                 controller_input: ControllerInput::default(),
+                control_input_history: ControlInputHistory::default(),
                 control_input_event_fn: Some(|entity| {
                     let axis_move_event_data = AxisMoveEventData {
                         controller_id: 0,
@@ -188,6 +243,7 @@ mod tests {
             SetupParams {
                 sequence_id: SequenceId::new(0),
                 controller_input: ControllerInput::default(),
+                control_input_history: ControlInputHistory::default(),
                 control_input_event_fn: Some(|entity| {
                     let axis_move_event_data = AxisMoveEventData {
                         controller_id: 0,
@@ -215,6 +271,7 @@ mod tests {
             SetupParams {
                 sequence_id: SequenceId::new(0),
                 controller_input,
+                control_input_history: ControlInputHistory::default(),
                 control_input_event_fn: None,
                 charge_tracker_clock: ChargeTrackerClock::new_with_value(100, 100),
             },
@@ -234,6 +291,7 @@ mod tests {
             SetupParams {
                 sequence_id: SequenceId::new(0),
                 controller_input,
+                control_input_history: ControlInputHistory::default(),
                 control_input_event_fn: Some(|entity| {
                     let axis_move_event_data = AxisMoveEventData {
                         controller_id: 0,
@@ -262,6 +320,7 @@ mod tests {
             SetupParams {
                 sequence_id: SequenceId::new(0),
                 controller_input,
+                control_input_history: ControlInputHistory::default(),
                 control_input_event_fn: Some(|entity| {
                     let axis_move_event_data = AxisMoveEventData {
                         controller_id: 0,
@@ -286,6 +345,7 @@ mod tests {
             SetupParams {
                 sequence_id: SequenceId::new(0),
                 controller_input: ControllerInput::default(),
+                control_input_history: ControlInputHistory::default(),
                 control_input_event_fn: None,
                 charge_tracker_clock: ChargeTrackerClock::new_with_value(100, 100),
             },
@@ -305,6 +365,7 @@ mod tests {
             SetupParams {
                 sequence_id: SequenceId::new(0),
                 controller_input,
+                control_input_history: ControlInputHistory::default(),
                 control_input_event_fn: None,
                 charge_tracker_clock: ChargeTrackerClock::new_with_value(100, 100),
             },
@@ -321,6 +382,7 @@ mod tests {
             SetupParams {
                 sequence_id: SequenceId::new(0),
                 controller_input: ControllerInput::default(),
+                control_input_history: ControlInputHistory::default(),
                 control_input_event_fn: Some(|entity| {
                     let control_action_event_data = ControlActionEventData {
                         controller_id: 0,
@@ -350,6 +412,7 @@ mod tests {
             SetupParams {
                 sequence_id: SequenceId::new(0),
                 controller_input: ControllerInput::default(),
+                control_input_history: ControlInputHistory::default(),
                 control_input_event_fn: Some(|entity| {
                     let control_action_event_data = ControlActionEventData {
                         controller_id: 0,
@@ -371,6 +434,7 @@ mod tests {
         SetupParams {
             sequence_id: sequence_id_setup,
             controller_input: controller_input_setup,
+            control_input_history: control_input_history_setup,
             control_input_event_fn,
             charge_tracker_clock: charge_tracker_clock_setup,
         }: SetupParams,
@@ -441,6 +505,7 @@ mod tests {
                         mut charge_tracker_clocks,
                         mut mirroreds,
                         mut controller_inputs,
+                        mut control_input_histories,
                         mut input_controlleds,
                     } = world.system_data::<TestSystemData>();
 
@@ -466,6 +531,9 @@ mod tests {
                     controller_inputs
                         .insert(entity, controller_input_setup)
                         .expect("Failed to insert `ControllerInput` component.");
+                    control_input_histories
+                        .insert(entity, control_input_history_setup)
+                        .expect("Failed to insert `ControlInputHistory` component.");
                     input_controlleds
                         .insert(entity, InputControlled::new(0))
                         .expect("Failed to insert `InputControlled` component.");
@@ -500,6 +568,14 @@ mod tests {
             .run_winit_loop()
     }
 
+    fn defend_forward_attack_history() -> ControlInputHistory {
+        let mut control_input_history = ControlInputHistory::new();
+        control_input_history.push(CommandInput::Defend);
+        control_input_history.push(CommandInput::Forward);
+        control_input_history.push(CommandInput::Attack);
+        control_input_history
+    }
+
     fn test_character_sequence() -> CharacterSequence {
         let test_character_sequence_yaml = "test_character_sequence.yaml";
         let test_character_sequence_path = PathBuf::from_iter(&[
@@ -628,12 +704,14 @@ mod tests {
         charge_tracker_clocks: WriteStorage<'s, ChargeTrackerClock>,
         mirroreds: WriteStorage<'s, Mirrored>,
         controller_inputs: WriteStorage<'s, ControllerInput>,
+        control_input_histories: WriteStorage<'s, ControlInputHistory>,
         input_controlleds: WriteStorage<'s, InputControlled>,
     }
 
     struct SetupParams {
         sequence_id: SequenceId,
         controller_input: ControllerInput,
+        control_input_history: ControlInputHistory,
         control_input_event_fn: Option<fn(Entity) -> ControlInputEvent>,
         charge_tracker_clock: ChargeTrackerClock,
     }
@@ -0,0 +1,4 @@
+mod item_follow_holder_system;
+mod item_land_system;
+mod item_pickup_system;
+mod item_throw_system;
@@ -0,0 +1,118 @@
+#[cfg(test)]
+mod tests {
+    use amethyst::{
+        core::{math::Vector3, transform::Transform},
+        ecs::{Builder, Entity, World, WorldExt},
+        Error,
+    };
+    use amethyst_test::AmethystApplication;
+    use item_model::{loaded::ItemTransitions, play::HeldBy};
+    use kinematic_model::config::Position;
+    use mirrored_model::play::Mirrored;
+    use sequence_model::loaded::SequenceId;
+
+    use item_play::ItemFollowHolderSystem;
+
+    #[test]
+    fn updates_held_item_position_translation_and_mirrored_to_match_holder() -> Result<(), Error> {
+        AmethystApplication::blank()
+            .with_system(ItemFollowHolderSystem::new(), "", &[])
+            .with_effect(|world| create_holder_and_item(world, true))
+            .with_assertion(|world| assert_item(world, Position::new(1., 2., 3.), true))
+            .run()
+    }
+
+    #[test]
+    fn does_not_update_item_that_is_not_held() -> Result<(), Error> {
+        AmethystApplication::blank()
+            .with_system(ItemFollowHolderSystem::new(), "", &[])
+            .with_effect(|world| create_holder_and_item(world, false))
+            .with_assertion(|world| assert_item(world, Position::default(), false))
+            .run()
+    }
+
+    #[test]
+    fn drops_item_when_holder_is_deleted() -> Result<(), Error> {
+        AmethystApplication::blank()
+            .with_effect(|world| create_holder_and_item(world, true))
+            .with_effect(|world| {
+                let item = *world.read_resource::<Entity>();
+                let holder = world
+                    .read_storage::<HeldBy>()
+                    .get(item)
+                    .map(|held_by| held_by.0)
+                    .expect("Expected item to have `HeldBy` component.");
+                world
+                    .delete_entity(holder)
+                    .expect("Failed to delete holder.");
+            })
+            .with_system_single(ItemFollowHolderSystem::new(), "", &[])
+            .with_assertion(|world| {
+                let item = *world.read_resource::<Entity>();
+
+                assert!(!world.read_storage::<HeldBy>().contains(item));
+                assert_eq!(
+                    Some(&SequenceId::new(2)),
+                    world.read_storage::<SequenceId>().get(item)
+                );
+            })
+            .run()
+    }
+
+    fn create_holder_and_item(world: &mut World, held: bool) {
+        let holder = {
+            let mut transform = Transform::default();
+            transform.set_translation_xyz(1., 2., 3.);
+            world
+                .create_entity()
+                .with(Position::<f32>::new(1., 2., 3.))
+                .with(transform)
+                .with(Mirrored::new(true))
+                .build()
+        };
+
+        let item = {
+            let mut entity_builder = world
+                .create_entity()
+                .with(ItemTransitions::new(
+                    SequenceId::new(0),
+                    SequenceId::new(1),
+                    SequenceId::new(2),
+                ))
+                .with(Position::<f32>::default())
+                .with(Transform::default())
+                .with(Mirrored::new(false));
+            if held {
+                entity_builder = entity_builder.with(HeldBy::new(holder));
+            }
+            entity_builder.build()
+        };
+
+        world.insert(item);
+    }
+
+    fn assert_item(world: &mut World, position_expected: Position<f32>, mirrored_expected: bool) {
+        let item = *world.read_resource::<Entity>();
+
+        assert_eq!(
+            Some(&position_expected),
+            world.read_storage::<Position<f32>>().get(item)
+        );
+        assert_eq!(
+            &Vector3::new(
+                position_expected.x,
+                position_expected.y,
+                position_expected.z
+            ),
+            world
+                .read_storage::<Transform>()
+                .get(item)
+                .expect("Expected item to have `Transform` component.")
+                .translation()
+        );
+        assert_eq!(
+            Some(&Mirrored::new(mirrored_expected)),
+            world.read_storage::<Mirrored>().get(item)
+        );
+    }
+}
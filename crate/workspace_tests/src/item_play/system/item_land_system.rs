@@ -0,0 +1,103 @@
+#[cfg(test)]
+mod tests {
+    use amethyst::{
+        ecs::{Builder, Entity, WorldExt},
+        Error,
+    };
+    use amethyst_test::AmethystApplication;
+    use item_model::loaded::ItemTransitions;
+    use kinematic_model::config::Velocity;
+    use object_model::play::Grounding;
+    use sequence_model::loaded::SequenceId;
+
+    use item_play::ItemLandSystem;
+
+    #[test]
+    fn transitions_thrown_item_to_rest_when_landed() -> Result<(), Error> {
+        run_test(
+            SetupParams {
+                sequence_id: SequenceId::new(2),
+                grounding: Grounding::OnGround,
+                velocity_y: -1.,
+            },
+            SequenceId::new(0),
+        )
+    }
+
+    #[test]
+    fn does_not_transition_thrown_item_when_airborne() -> Result<(), Error> {
+        run_test(
+            SetupParams {
+                sequence_id: SequenceId::new(2),
+                grounding: Grounding::Airborne,
+                velocity_y: -1.,
+            },
+            SequenceId::new(2),
+        )
+    }
+
+    #[test]
+    fn does_not_transition_thrown_item_when_moving_upwards() -> Result<(), Error> {
+        run_test(
+            SetupParams {
+                sequence_id: SequenceId::new(2),
+                grounding: Grounding::OnGround,
+                velocity_y: 6.,
+            },
+            SequenceId::new(2),
+        )
+    }
+
+    #[test]
+    fn does_not_transition_item_that_is_not_thrown() -> Result<(), Error> {
+        run_test(
+            SetupParams {
+                sequence_id: SequenceId::new(3),
+                grounding: Grounding::OnGround,
+                velocity_y: 0.,
+            },
+            SequenceId::new(3),
+        )
+    }
+
+    fn run_test(
+        SetupParams {
+            sequence_id,
+            grounding,
+            velocity_y,
+        }: SetupParams,
+        sequence_id_expected: SequenceId,
+    ) -> Result<(), Error> {
+        AmethystApplication::blank()
+            .with_system(ItemLandSystem::new(), "", &[])
+            .with_effect(move |world| {
+                let item = world
+                    .create_entity()
+                    .with(ItemTransitions::new(
+                        SequenceId::new(0),
+                        SequenceId::new(1),
+                        SequenceId::new(2),
+                    ))
+                    .with(sequence_id)
+                    .with(grounding)
+                    .with(Velocity::<f32>::new(0., velocity_y, 0.))
+                    .build();
+
+                world.insert(item);
+            })
+            .with_assertion(move |world| {
+                let item = *world.read_resource::<Entity>();
+                assert_eq!(
+                    Some(&sequence_id_expected),
+                    world.read_storage::<SequenceId>().get(item)
+                );
+            })
+            .run()
+    }
+
+    struct SetupParams {
+        sequence_id: SequenceId,
+        grounding: Grounding,
+        velocity_y: f32,
+    }
+}
@@ -0,0 +1,206 @@
+#[cfg(test)]
+mod tests {
+    use amethyst::{
+        ecs::{Builder, Entity, World, WorldExt},
+        shrev::EventChannel,
+        Error,
+    };
+    use amethyst_test::AmethystApplication;
+    use collision_model::{
+        config::{Hit, Interaction, InteractionKind, Pickup},
+        play::ContactEvent,
+    };
+    use item_model::{
+        loaded::ItemTransitions,
+        play::{HeldBy, HeldItem},
+    };
+    use kinematic_model::config::Velocity;
+    use parent_model::play::ParentEntity;
+    use sequence_model::loaded::SequenceId;
+    use shape_model::Volume;
+
+    use item_play::ItemPickupSystem;
+
+    #[test]
+    fn picks_up_item_on_pickup_contact() -> Result<(), Error> {
+        run_test(
+            SetupParams {
+                holder_has_item: false,
+                item_is_held: false,
+                interaction_kind: InteractionKind::Pickup(Pickup::new()),
+            },
+            |world, holder, item| {
+                assert_eq!(
+                    Some(&HeldItem::new(item)),
+                    world.read_storage::<HeldItem>().get(holder)
+                );
+                assert_eq!(
+                    Some(&HeldBy::new(holder)),
+                    world.read_storage::<HeldBy>().get(item)
+                );
+                assert_eq!(
+                    Some(&SequenceId::new(1)),
+                    world.read_storage::<SequenceId>().get(item)
+                );
+                assert_eq!(
+                    Some(&Velocity::default()),
+                    world.read_storage::<Velocity<f32>>().get(item)
+                );
+            },
+        )
+    }
+
+    #[test]
+    fn picks_up_spawned_item_with_parent_entity() -> Result<(), Error> {
+        run_test(
+            SetupParams {
+                holder_has_item: false,
+                item_is_held: false,
+                interaction_kind: InteractionKind::Pickup(Pickup::new()),
+            },
+            |world, holder, item| {
+                assert_eq!(
+                    Some(&HeldItem::new(item)),
+                    world.read_storage::<HeldItem>().get(holder)
+                );
+                // The spawner link is untouched.
+                assert!(world.read_storage::<ParentEntity>().contains(item));
+            },
+        )
+    }
+
+    #[test]
+    fn does_not_pick_up_item_on_hit_contact() -> Result<(), Error> {
+        run_test(
+            SetupParams {
+                holder_has_item: false,
+                item_is_held: false,
+                interaction_kind: InteractionKind::Hit(Hit::default()),
+            },
+            |world, holder, item| {
+                assert!(!world.read_storage::<HeldItem>().contains(holder));
+                assert!(!world.read_storage::<HeldBy>().contains(item));
+                assert_eq!(
+                    Some(&SequenceId::new(0)),
+                    world.read_storage::<SequenceId>().get(item)
+                );
+            },
+        )
+    }
+
+    #[test]
+    fn does_not_pick_up_item_when_holder_has_item() -> Result<(), Error> {
+        run_test(
+            SetupParams {
+                holder_has_item: true,
+                item_is_held: false,
+                interaction_kind: InteractionKind::Pickup(Pickup::new()),
+            },
+            |world, holder, item| {
+                assert_ne!(
+                    Some(&HeldItem::new(item)),
+                    world.read_storage::<HeldItem>().get(holder)
+                );
+                assert!(!world.read_storage::<HeldBy>().contains(item));
+            },
+        )
+    }
+
+    #[test]
+    fn does_not_pick_up_item_when_item_is_held() -> Result<(), Error> {
+        run_test(
+            SetupParams {
+                holder_has_item: false,
+                item_is_held: true,
+                interaction_kind: InteractionKind::Pickup(Pickup::new()),
+            },
+            |world, holder, item| {
+                assert!(!world.read_storage::<HeldItem>().contains(holder));
+                assert_ne!(
+                    Some(&HeldBy::new(holder)),
+                    world.read_storage::<HeldBy>().get(item)
+                );
+            },
+        )
+    }
+
+    fn run_test(
+        SetupParams {
+            holder_has_item,
+            item_is_held,
+            interaction_kind,
+        }: SetupParams,
+        assertion_fn: fn(&mut World, Entity, Entity),
+    ) -> Result<(), Error> {
+        AmethystApplication::blank()
+            .with_system(ItemPickupSystem::new(), "", &[])
+            .with_effect(move |world| {
+                let item = world
+                    .create_entity()
+                    .with(ItemTransitions::new(
+                        SequenceId::new(0),
+                        SequenceId::new(1),
+                        SequenceId::new(2),
+                    ))
+                    .with(SequenceId::new(0))
+                    .with(Velocity::<f32>::new(1., 2., 3.))
+                    .build();
+                let other = world.create_entity().build();
+
+                // Items spawned by another object are linked to their spawner.
+                let spawner = world.create_entity().build();
+                world
+                    .write_storage::<ParentEntity>()
+                    .insert(item, ParentEntity::new(spawner))
+                    .expect("Failed to insert `ParentEntity` component.");
+                if item_is_held {
+                    world
+                        .write_storage::<HeldBy>()
+                        .insert(item, HeldBy::new(other))
+                        .expect("Failed to insert `HeldBy` component.");
+                }
+
+                let holder = {
+                    let mut entity_builder = world.create_entity();
+                    if holder_has_item {
+                        entity_builder = entity_builder.with(HeldItem::new(other));
+                    }
+                    entity_builder.build()
+                };
+
+                let contact_event = ContactEvent::new(
+                    holder,
+                    item,
+                    Interaction::new(interaction_kind, vec![], false),
+                    body(),
+                );
+                world
+                    .write_resource::<EventChannel<ContactEvent>>()
+                    .single_write(contact_event);
+
+                world.insert((holder, item));
+            })
+            .with_assertion(move |world| {
+                let (holder, item) = *world.read_resource::<(Entity, Entity)>();
+                assertion_fn(world, holder, item);
+            })
+            .run()
+    }
+
+    fn body() -> Volume {
+        Volume::Box {
+            x: 0,
+            y: 0,
+            z: 0,
+            w: 1,
+            h: 1,
+            d: 1,
+        }
+    }
+
+    struct SetupParams {
+        holder_has_item: bool,
+        item_is_held: bool,
+        interaction_kind: InteractionKind,
+    }
+}
@@ -0,0 +1,177 @@
+#[cfg(test)]
+mod tests {
+    use amethyst::{
+        ecs::{Builder, Entity, World, WorldExt},
+        Error,
+    };
+    use amethyst_test::AmethystApplication;
+    use collision_model::{
+        config::Grab,
+        play::{GrabClock, GrabTracker, HitRepeatClock, HitRepeatTrackers},
+    };
+    use game_input_model::{config::CommandInput, play::ControlInputHistory};
+    use item_model::{
+        loaded::ItemTransitions,
+        play::{HeldBy, HeldItem},
+    };
+    use kinematic_model::config::Velocity;
+    use mirrored_model::play::Mirrored;
+    use sequence_model::loaded::SequenceId;
+
+    use item_play::{ItemThrowSystem, ITEM_THROW_HIT_REPEAT_DELAY, ITEM_THROW_VELOCITY};
+
+    #[test]
+    fn throws_held_item_when_attack_is_entered() -> Result<(), Error> {
+        run_test(false, false, CommandInput::Attack, |world, holder, item| {
+            assert!(!world.read_storage::<HeldItem>().contains(holder));
+            assert!(!world.read_storage::<HeldBy>().contains(item));
+            assert_eq!(
+                Some(&SequenceId::new(2)),
+                world.read_storage::<SequenceId>().get(item)
+            );
+            assert_eq!(
+                Some(&Velocity::from(ITEM_THROW_VELOCITY)),
+                world.read_storage::<Velocity<f32>>().get(item)
+            );
+
+            let hit_repeat_trackerses = world.read_storage::<HitRepeatTrackers>();
+            let hit_repeat_trackers = hit_repeat_trackerses
+                .get(item)
+                .expect("Expected `HitRepeatTrackers` to exist.");
+            assert!(hit_repeat_trackers.values().any(|hit_repeat_tracker| {
+                hit_repeat_tracker.entity == holder
+                    && hit_repeat_tracker.clock == HitRepeatClock::new(ITEM_THROW_HIT_REPEAT_DELAY)
+            }));
+        })
+    }
+
+    #[test]
+    fn throws_item_in_mirrored_direction() -> Result<(), Error> {
+        run_test(true, false, CommandInput::Attack, |world, _holder, item| {
+            let [x, y, z] = ITEM_THROW_VELOCITY;
+            assert_eq!(
+                Some(&Velocity::new(-x, y, z)),
+                world.read_storage::<Velocity<f32>>().get(item)
+            );
+        })
+    }
+
+    #[test]
+    fn does_not_throw_item_when_other_input_is_entered() -> Result<(), Error> {
+        run_test(false, false, CommandInput::Jump, |world, holder, item| {
+            assert_eq!(
+                Some(&HeldItem::new(item)),
+                world.read_storage::<HeldItem>().get(holder)
+            );
+            assert_eq!(
+                Some(&HeldBy::new(holder)),
+                world.read_storage::<HeldBy>().get(item)
+            );
+            assert_eq!(
+                Some(&SequenceId::new(1)),
+                world.read_storage::<SequenceId>().get(item)
+            );
+        })
+    }
+
+    #[test]
+    fn does_not_throw_item_when_attack_was_entered_on_previous_tick() -> Result<(), Error> {
+        AmethystApplication::blank()
+            .with_system(ItemThrowSystem::new(), "", &[])
+            .with_effect(|world| {
+                let item = world.create_entity().build();
+                let holder = world.create_entity().with(HeldItem::new(item)).build();
+
+                let mut control_input_history = ControlInputHistory::new();
+                control_input_history.push(CommandInput::Attack);
+                control_input_history.advance();
+                world
+                    .write_storage::<ControlInputHistory>()
+                    .insert(holder, control_input_history)
+                    .expect("Failed to insert `ControlInputHistory` component.");
+
+                world.insert((holder, item));
+            })
+            .with_assertion(|world| {
+                let (holder, item) = *world.read_resource::<(Entity, Entity)>();
+                assert_eq!(
+                    Some(&HeldItem::new(item)),
+                    world.read_storage::<HeldItem>().get(holder)
+                );
+            })
+            .run()
+    }
+
+    #[test]
+    fn does_not_throw_held_item_when_grabbing() -> Result<(), Error> {
+        run_test(false, true, CommandInput::Attack, |world, holder, item| {
+            assert_eq!(
+                Some(&HeldItem::new(item)),
+                world.read_storage::<HeldItem>().get(holder)
+            );
+            assert_eq!(
+                Some(&HeldBy::new(holder)),
+                world.read_storage::<HeldBy>().get(item)
+            );
+            assert_eq!(
+                Some(&SequenceId::new(1)),
+                world.read_storage::<SequenceId>().get(item)
+            );
+        })
+    }
+
+    fn run_test(
+        mirrored: bool,
+        grabbing: bool,
+        command_input: CommandInput,
+        assertion_fn: fn(&mut World, Entity, Entity),
+    ) -> Result<(), Error> {
+        AmethystApplication::blank()
+            .with_system(ItemThrowSystem::new(), "", &[])
+            .with_effect(move |world| {
+                let item = world
+                    .create_entity()
+                    .with(ItemTransitions::new(
+                        SequenceId::new(0),
+                        SequenceId::new(1),
+                        SequenceId::new(2),
+                    ))
+                    .with(SequenceId::new(1))
+                    .with(Velocity::<f32>::default())
+                    .build();
+                let holder = world
+                    .create_entity()
+                    .with(HeldItem::new(item))
+                    .with(Mirrored::new(mirrored))
+                    .build();
+                world
+                    .write_storage::<HeldBy>()
+                    .insert(item, HeldBy::new(holder))
+                    .expect("Failed to insert `HeldBy` component.");
+
+                if grabbing {
+                    let grabbed = world.create_entity().build();
+                    let grab_tracker =
+                        GrabTracker::new(grabbed, Grab::default(), GrabClock::new(10));
+                    world
+                        .write_storage::<GrabTracker>()
+                        .insert(holder, grab_tracker)
+                        .expect("Failed to insert `GrabTracker` component.");
+                }
+
+                let mut control_input_history = ControlInputHistory::new();
+                control_input_history.push(command_input);
+                world
+                    .write_storage::<ControlInputHistory>()
+                    .insert(holder, control_input_history)
+                    .expect("Failed to insert `ControlInputHistory` component.");
+
+                world.insert((holder, item));
+            })
+            .with_assertion(move |world| {
+                let (holder, item) = *world.read_resource::<(Entity, Entity)>();
+                assertion_fn(world, holder, item);
+            })
+            .run()
+    }
+}
@@ -0,0 +1,111 @@
+#[cfg(test)]
+mod test {
+    use std::{iter::FromIterator, str::FromStr};
+
+    use amethyst::{
+        ecs::{Builder, Read, World, WorldExt, Write},
+        shred::SystemData,
+        Error,
+    };
+    use amethyst_test::AmethystApplication;
+    use asset_model::{
+        config::AssetSlug,
+        loaded::{AssetId, AssetIdMappings},
+    };
+    use collision_model::loaded::{HitTransition, HittingTransition};
+    use item_model::{config::ItemSequenceName, loaded::ItemTransitions};
+    use map_model::play::MapBounded;
+    use sequence_model::{
+        config::SequenceNameString,
+        loaded::{AssetSequenceIdMappings, SequenceId, SequenceIdMappings},
+    };
+
+    use item_prefab::{ItemComponentStorages, ItemEntityAugmenter, ItemSpawningResources};
+
+    #[test]
+    fn augments_entity_with_item_components() -> Result<(), Error> {
+        run_test(
+            &[
+                ItemSequenceName::Rest,
+                ItemSequenceName::Held,
+                ItemSequenceName::Thrown,
+                ItemSequenceName::Hit,
+            ],
+            ItemTransitions::new(SequenceId::new(0), SequenceId::new(1), SequenceId::new(2)),
+            SequenceId::new(3),
+        )
+    }
+
+    #[test]
+    fn falls_back_to_rest_sequence_when_sequence_missing() -> Result<(), Error> {
+        run_test(
+            &[ItemSequenceName::Hit, ItemSequenceName::Rest],
+            ItemTransitions::new(SequenceId::new(1), SequenceId::new(1), SequenceId::new(1)),
+            SequenceId::new(0),
+        )
+    }
+
+    fn run_test(
+        sequence_names: &'static [ItemSequenceName],
+        item_transitions_expected: ItemTransitions,
+        hit_sequence_id_expected: SequenceId,
+    ) -> Result<(), Error> {
+        let assertion = move |world: &mut World| {
+            let entity = world.create_entity().build();
+            {
+                let asset_id = *world.read_resource::<AssetId>();
+                let (item_spawning_resources, mut item_component_storages) =
+                    world.system_data::<(ItemSpawningResources<'_>, ItemComponentStorages<'_>)>();
+                ItemEntityAugmenter::augment(
+                    &item_spawning_resources,
+                    &mut item_component_storages,
+                    asset_id,
+                    entity,
+                );
+            }
+
+            assert!(world.read_storage::<MapBounded>().contains(entity));
+            assert_eq!(
+                Some(&HitTransition::new(hit_sequence_id_expected)),
+                world.read_storage::<HitTransition>().get(entity)
+            );
+            assert_eq!(
+                Some(&HittingTransition::new(hit_sequence_id_expected)),
+                world.read_storage::<HittingTransition>().get(entity)
+            );
+            assert_eq!(
+                Some(&item_transitions_expected),
+                world.read_storage::<ItemTransitions>().get(entity)
+            );
+        };
+
+        AmethystApplication::blank()
+            .with_setup(|world| {
+                <Read<'_, AssetIdMappings> as SystemData>::setup(world);
+                <ItemSpawningResources as SystemData>::setup(world);
+                <ItemComponentStorages as SystemData>::setup(world);
+            })
+            .with_effect(move |world| {
+                let asset_id = {
+                    let mut asset_id_mappings = world.write_resource::<AssetIdMappings>();
+                    let asset_slug =
+                        AssetSlug::from_str("test/item").expect("Expected asset slug to be valid.");
+                    asset_id_mappings.insert(asset_slug)
+                };
+
+                {
+                    let mut asset_sequence_id_mappings_item =
+                        world.system_data::<Write<'_, AssetSequenceIdMappings<ItemSequenceName>>>();
+
+                    let sequence_id_mappings = SequenceIdMappings::from_iter(
+                        sequence_names.iter().copied().map(SequenceNameString::Name),
+                    );
+                    asset_sequence_id_mappings_item.insert(asset_id, sequence_id_mappings);
+                }
+
+                world.insert(asset_id);
+            })
+            .with_assertion(assertion)
+            .run()
+    }
+}
@@ -9,6 +9,8 @@ extern crate hamcrest;
 #[macro_use]
 extern crate pretty_assertions;
 
+#[cfg(test)]
+mod ai_play;
 #[cfg(test)]
 mod application;
 #[cfg(test)]
@@ -70,6 +72,10 @@ mod collision_model;
 #[cfg(test)]
 mod collision_play;
 #[cfg(test)]
+mod control_settings_loading;
+#[cfg(test)]
+mod control_settings_model;
+#[cfg(test)]
 mod debug_util_amethyst;
 #[cfg(test)]
 mod energy_loading;
@@ -104,6 +110,12 @@ mod input_reaction_model;
 #[cfg(test)]
 mod input_reaction_play;
 #[cfg(test)]
+mod item_loading;
+#[cfg(test)]
+mod item_play;
+#[cfg(test)]
+mod item_prefab;
+#[cfg(test)]
 mod kinematic_loading;
 #[cfg(test)]
 mod kinematic_model;
@@ -122,10 +134,16 @@ mod map_selection;
 #[cfg(test)]
 mod map_selection_ui_model;
 #[cfg(test)]
+mod net_model;
+#[cfg(test)]
+mod net_play;
+#[cfg(test)]
 mod network_input_play;
 #[cfg(test)]
 mod network_mode_selection_stdio;
 #[cfg(test)]
+mod network_session_model;
+#[cfg(test)]
 mod object_loading;
 #[cfg(test)]
 mod object_model;
@@ -136,10 +154,20 @@ mod object_status_play;
 #[cfg(test)]
 mod parent_play;
 #[cfg(test)]
+mod replay_model;
+#[cfg(test)]
+mod rollback_model;
+#[cfg(test)]
+mod rollback_play;
+#[cfg(test)]
 mod sequence_loading;
 #[cfg(test)]
 mod sequence_play;
 #[cfg(test)]
+mod session_browse_model;
+#[cfg(test)]
+mod session_browse_stdio;
+#[cfg(test)]
 mod session_host_play;
 #[cfg(test)]
 mod session_host_stdio;
@@ -148,10 +176,18 @@ mod session_join_play;
 #[cfg(test)]
 mod session_join_stdio;
 #[cfg(test)]
+mod session_lobby_model;
+#[cfg(test)]
 mod session_lobby_play;
 #[cfg(test)]
+mod session_lobby_stdio;
+#[cfg(test)]
 mod session_lobby_ui_model;
 #[cfg(test)]
+mod simulation_model;
+#[cfg(test)]
+mod snapshot_model;
+#[cfg(test)]
 mod spawn_loading;
 #[cfg(test)]
 mod spawn_model;
@@ -1,13 +1,17 @@
 #[cfg(test)]
 mod test {
+    use std::str::FromStr;
+
+    use asset_model::config::AssetSlug;
     use background_model::config::BackgroundDefinition;
     use indexmap::IndexMap;
-    use kinematic_model::config::PositionInit;
+    use kinematic_model::config::{Position, PositionInit, Velocity};
     use sequence_model::config::{SequenceEndTransition, Wait};
     use serde_yaml;
+    use spawn_model::config::{Spawn, Spawns};
     use sprite_model::config::{SpriteFrame, SpriteItem, SpriteRef, SpriteSequence};
 
-    use map_model::config::{MapBounds, MapDefinition, MapHeader};
+    use map_model::config::{MapBounds, MapDefinition, MapHeader, MapPhase};
 
     const MAP_NO_SPRITE_SEQUENCES: &str = r#"---
 header:
@@ -33,6 +37,18 @@ layers:
     frames: [{ wait: 1, sprite: { sheet: 0, index: 0 } }]
 "#;
 
+    const MAP_WITH_PHASES: &str = r#"---
+header:
+  name: "Stage"
+  bounds: { x: 1, y: 2, z: 3, width: 800, height: 600, depth: 200 }
+
+phases:
+  - spawns: [{ object: "test/char_0", position: { x: 100, y: 0, z: 50 } }]
+  - spawns:
+      - { object: "test/char_0", position: { x: 200, y: 0, z: 50 }, sequence: "walk" }
+      - { object: "test/char_1", position: { x: 300, y: 0, z: 100 } }
+"#;
+
     #[test]
     fn deserialize_minimal_definition() {
         let map_definition = serde_yaml::from_str::<MapDefinition>(MAP_NO_SPRITE_SEQUENCES)
@@ -88,4 +104,43 @@ layers:
 
         assert_eq!(expected, map_definition);
     }
+
+    #[test]
+    fn deserialize_with_phases() {
+        let map_definition = serde_yaml::from_str::<MapDefinition>(MAP_WITH_PHASES)
+            .expect("Failed to deserialize map definition.");
+
+        let bounds = MapBounds::new(1, 2, 3, 800, 600, 200);
+        let header = MapHeader::new("Stage".to_string(), bounds);
+        let char_0 = AssetSlug::from_str("test/char_0")
+            .expect("Expected `test/char_0` to be a valid asset slug.");
+        let char_1 = AssetSlug::from_str("test/char_1")
+            .expect("Expected `test/char_1` to be a valid asset slug.");
+        let phases = vec![
+            MapPhase::new(Spawns::new(vec![Spawn::new(
+                char_0.clone(),
+                Position::new(100, 0, 50),
+                Velocity::default(),
+                None,
+            )])),
+            MapPhase::new(Spawns::new(vec![
+                Spawn::new(
+                    char_0,
+                    Position::new(200, 0, 50),
+                    Velocity::default(),
+                    Some(String::from("walk")),
+                ),
+                Spawn::new(
+                    char_1,
+                    Position::new(300, 0, 100),
+                    Velocity::default(),
+                    None,
+                ),
+            ])),
+        ];
+        let mut expected = MapDefinition::new(header, BackgroundDefinition::default());
+        expected.phases = phases;
+
+        assert_eq!(expected, map_definition);
+    }
 }
@@ -6,6 +6,7 @@ mod tests {
     };
     use amethyst_test::AmethystApplication;
     use map_model::play::OutOfBoundsDeleteClock;
+    use snapshot_model::play::{DeferredDelete, DeleteMode};
 
     use map_play::MapOutOfBoundsDeletionSystem;
 
@@ -14,6 +15,7 @@ mod tests {
         run_test(
             SetupParams {
                 out_of_bounds_delete_clock: OutOfBoundsDeleteClock::new(10),
+                delete_mode: DeleteMode::Immediate,
             },
             ExpectedParams {
                 entity_state: EntityState::Alive(OutOfBoundsDeleteClock::new_with_value(10, 1)),
@@ -26,6 +28,7 @@ mod tests {
         run_test(
             SetupParams {
                 out_of_bounds_delete_clock: OutOfBoundsDeleteClock::new_with_value(10, 10),
+                delete_mode: DeleteMode::Immediate,
             },
             ExpectedParams {
                 entity_state: EntityState::Dead,
@@ -33,9 +36,24 @@ mod tests {
         )
     }
 
+    #[test]
+    fn defers_deletion_of_entities_with_completed_clocks_when_delete_mode_deferred(
+    ) -> Result<(), Error> {
+        run_test(
+            SetupParams {
+                out_of_bounds_delete_clock: OutOfBoundsDeleteClock::new_with_value(10, 10),
+                delete_mode: DeleteMode::Deferred,
+            },
+            ExpectedParams {
+                entity_state: EntityState::DeferredDelete,
+            },
+        )
+    }
+
     fn run_test(
         SetupParams {
             out_of_bounds_delete_clock,
+            delete_mode,
         }: SetupParams,
         ExpectedParams { entity_state }: ExpectedParams,
     ) -> Result<(), Error> {
@@ -48,6 +66,7 @@ mod tests {
                     .build();
 
                 world.insert(entity);
+                world.insert(delete_mode);
             })
             .with_system_single(MapOutOfBoundsDeletionSystem::new(), "", &[])
             .with_effect(|world| world.maintain())
@@ -69,6 +88,15 @@ mod tests {
                             out_of_bounds_delete_clock_actual
                         );
                     }
+                    EntityState::DeferredDelete => {
+                        let deferred_deletes = world.read_storage::<DeferredDelete>();
+
+                        assert!(entities.is_alive(entity));
+                        assert_eq!(
+                            Some(&DeferredDelete::default()),
+                            deferred_deletes.get(entity)
+                        );
+                    }
                     EntityState::Dead => {
                         assert!(!entities.is_alive(entity));
                     }
@@ -83,6 +111,7 @@ mod tests {
 
     struct SetupParams {
         out_of_bounds_delete_clock: OutOfBoundsDeleteClock,
+        delete_mode: DeleteMode,
     }
 
     struct ExpectedParams {
@@ -91,6 +120,7 @@ mod tests {
 
     enum EntityState {
         Alive(OutOfBoundsDeleteClock),
+        DeferredDelete,
         Dead,
     }
 }
@@ -0,0 +1 @@
+mod net_server_discoveries;
@@ -0,0 +1,58 @@
+#[cfg(test)]
+mod tests {
+    use std::{
+        net::{Ipv4Addr, SocketAddr},
+        time::{Duration, Instant},
+    };
+
+    use net_model::play::{NetProtocolVersion, NetServerDiscoveries, NetServerDiscovery};
+
+    #[test]
+    fn insert_replaces_discovery_with_same_address() {
+        let now = Instant::now();
+        let later = now + Duration::from_secs(1);
+        let mut net_server_discoveries = NetServerDiscoveries::default();
+
+        net_server_discoveries.insert(discovery(1234, now));
+        net_server_discoveries.insert(discovery(2345, now));
+        net_server_discoveries.insert(discovery(1234, later));
+
+        assert_eq!(
+            NetServerDiscoveries::new(vec![discovery(1234, later), discovery(2345, now)]),
+            net_server_discoveries
+        );
+    }
+
+    #[test]
+    fn retain_recent_removes_expired_discoveries() {
+        let now = Instant::now();
+        let later = now + Duration::from_secs(5);
+        let mut net_server_discoveries =
+            NetServerDiscoveries::new(vec![discovery(1234, now), discovery(2345, later)]);
+
+        net_server_discoveries.retain_recent(later, Duration::from_secs(5));
+
+        assert_eq!(
+            NetServerDiscoveries::new(vec![discovery(2345, later)]),
+            net_server_discoveries
+        );
+    }
+
+    #[test]
+    fn position_returns_index_of_address() {
+        let now = Instant::now();
+        let net_server_discoveries =
+            NetServerDiscoveries::new(vec![discovery(1234, now), discovery(2345, now)]);
+
+        assert_eq!(Some(1), net_server_discoveries.position(socket_addr(2345)));
+        assert_eq!(None, net_server_discoveries.position(socket_addr(3456)));
+    }
+
+    fn discovery(port: u16, seen_at: Instant) -> NetServerDiscovery {
+        NetServerDiscovery::new(socket_addr(port), NetProtocolVersion::CURRENT, seen_at)
+    }
+
+    fn socket_addr(port: u16) -> SocketAddr {
+        SocketAddr::new(Ipv4Addr::LOCALHOST.into(), port)
+    }
+}
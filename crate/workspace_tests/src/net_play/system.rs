@@ -0,0 +1 @@
+mod net_handshake_responder_system;
@@ -0,0 +1,155 @@
+#[cfg(test)]
+mod tests {
+    use std::{
+        any,
+        net::{Ipv4Addr, TcpListener},
+        time::{Duration, Instant},
+    };
+
+    use amethyst::{
+        ecs::WorldExt, network::simulation::web_socket::WebSocketNetworkBundle,
+        shrev::EventChannel, Error, State, StateData, Trans,
+    };
+    use amethyst_test::{AmethystApplication, GameUpdate};
+    use net_model::play::{
+        NetHandshakeEvent, NetHandshakeStatus, NetMessageEvent, NetPeerVersions, NetProtocolVersion,
+    };
+    use network_session_model::config::SessionServerConfig;
+
+    use net_play::{
+        NetHandshakeResponderSystem, NetHandshakeResponderSystemDesc, NetHandshakeResponseSystem,
+        NetHandshakeResponseSystemDesc, NetListenerSystem, NetListenerSystemDesc,
+        NetMessageRequestSystem, NetMessageRequestSystemDesc,
+    };
+
+    /// Maximum time to wait for the loopback server to respond.
+    const RESPONSE_TIMEOUT: Duration = Duration::from_secs(10);
+
+    #[test]
+    fn accepts_handshake_with_current_protocol_version() -> Result<(), Error> {
+        run_test(
+            NetProtocolVersion::CURRENT,
+            ExpectedParams {
+                net_handshake_status: NetHandshakeStatus::Accepted,
+                peer_compatible: true,
+            },
+        )
+    }
+
+    #[test]
+    fn rejects_handshake_with_different_protocol_version() -> Result<(), Error> {
+        run_test(
+            NetProtocolVersion::new(!NetProtocolVersion::CURRENT.0),
+            ExpectedParams {
+                net_handshake_status: NetHandshakeStatus::Rejected {
+                    server_version: NetProtocolVersion::CURRENT,
+                },
+                peer_compatible: false,
+            },
+        )
+    }
+
+    fn run_test(
+        net_protocol_version: NetProtocolVersion,
+        ExpectedParams {
+            net_handshake_status: net_handshake_status_expected,
+            peer_compatible: peer_compatible_expected,
+        }: ExpectedParams,
+    ) -> Result<(), Error> {
+        // The application is both the session server and its client, over loopback.
+        let tcp_listener = TcpListener::bind((Ipv4Addr::LOCALHOST, 0))?;
+        tcp_listener.set_nonblocking(true)?;
+        let server_socket_addr = tcp_listener.local_addr()?;
+        let session_server_config = SessionServerConfig {
+            address: server_socket_addr.ip(),
+            port: server_socket_addr.port(),
+        };
+
+        AmethystApplication::blank()
+            .with_bundle(WebSocketNetworkBundle::new(Some(tcp_listener)))
+            .with_system_desc(
+                NetMessageRequestSystemDesc::default(),
+                any::type_name::<NetMessageRequestSystem>(),
+                &[],
+            )
+            .with_system_desc(
+                NetListenerSystemDesc::default(),
+                any::type_name::<NetListenerSystem>(),
+                &["network_recv"],
+            )
+            .with_system_desc(
+                NetHandshakeResponderSystemDesc::default(),
+                any::type_name::<NetHandshakeResponderSystem>(),
+                &[any::type_name::<NetListenerSystem>()],
+            )
+            .with_system_desc(
+                NetHandshakeResponseSystemDesc::default(),
+                any::type_name::<NetHandshakeResponseSystem>(),
+                &[any::type_name::<NetListenerSystem>()],
+            )
+            .with_setup(move |world| world.insert(session_server_config))
+            .with_effect(move |world| {
+                world
+                    .write_resource::<EventChannel<NetMessageEvent>>()
+                    .single_write(NetMessageEvent::NetHandshakeEvent(
+                        NetHandshakeEvent::Request(net_protocol_version),
+                    ));
+            })
+            .with_state(WaitForHandshake::new)
+            .with_assertion(move |world| {
+                let net_handshake_status = *world.read_resource::<NetHandshakeStatus>();
+                let net_peer_versions = world.read_resource::<NetPeerVersions>();
+                let peer_versions = net_peer_versions.values().copied().collect::<Vec<_>>();
+                let peer_compatible = net_peer_versions
+                    .keys()
+                    .any(|socket_addr| net_peer_versions.is_compatible(socket_addr));
+
+                assert_eq!(
+                    (
+                        net_handshake_status_expected,
+                        vec![net_protocol_version],
+                        peer_compatible_expected
+                    ),
+                    (net_handshake_status, peer_versions, peer_compatible)
+                );
+            })
+            .run()
+    }
+
+    struct ExpectedParams {
+        net_handshake_status: NetHandshakeStatus,
+        peer_compatible: bool,
+    }
+
+    #[derive(Debug)]
+    struct WaitForHandshake {
+        start: Instant,
+    }
+
+    impl WaitForHandshake {
+        fn new() -> Self {
+            WaitForHandshake {
+                start: Instant::now(),
+            }
+        }
+    }
+
+    impl<T, E> State<T, E> for WaitForHandshake
+    where
+        T: GameUpdate,
+        E: Send + Sync + 'static,
+    {
+        fn update(&mut self, data: StateData<'_, T>) -> Trans<T, E> {
+            data.data.update(&data.world);
+
+            let net_handshake_status = *data.world.read_resource::<NetHandshakeStatus>();
+            if net_handshake_status == NetHandshakeStatus::Pending
+                && self.start.elapsed() < RESPONSE_TIMEOUT
+            {
+                Trans::None
+            } else {
+                Trans::Pop
+            }
+        }
+    }
+}
@@ -1,2 +1,3 @@
 mod network_input_request_system;
 mod network_input_response_system;
+mod session_input_catch_up_system;
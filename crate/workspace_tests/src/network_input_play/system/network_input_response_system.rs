@@ -14,6 +14,7 @@ mod tests {
     };
     use net_model::play::{NetData, NetEventChannel};
     use network_session_model::play::SessionStatus;
+    use rollback_model::play::SessionInputHistory;
 
     use network_input_play::NetworkInputResponseSystemDesc;
 
@@ -22,9 +23,11 @@ mod tests {
         run_test(
             SetupParams {
                 session_status: SessionStatus::None,
+                session_input_history: SessionInputHistory::default(),
                 game_input_event: None,
             },
             ExpectedParams {
+                session_input_history: SessionInputHistory::default(),
                 game_input_event: None,
             },
         )
@@ -35,12 +38,14 @@ mod tests {
         run_test(
             SetupParams {
                 session_status: SessionStatus::HostEstablished,
+                session_input_history: SessionInputHistory::default(),
                 game_input_event: Some(GameInputEvent::ActionPressed(PlayerActionControl::new(
                     0,
                     ControlAction::Attack,
                 ))),
             },
             ExpectedParams {
+                session_input_history: SessionInputHistory::default(),
                 game_input_event: Some(GameInputEvent::ActionPressed(PlayerActionControl::new(
                     0,
                     ControlAction::Attack,
@@ -49,12 +54,39 @@ mod tests {
         )
     }
 
+    #[test]
+    fn records_game_input_event_in_history_when_catching_up() -> Result<(), Error> {
+        let mut session_input_history_setup = SessionInputHistory::default();
+        session_input_history_setup.tick();
+        let mut session_input_history_expected = session_input_history_setup.clone();
+        session_input_history_expected.push_game_input_event(GameInputEvent::ActionPressed(
+            PlayerActionControl::new(0, ControlAction::Attack),
+        ));
+
+        run_test(
+            SetupParams {
+                session_status: SessionStatus::JoinEstablished,
+                session_input_history: session_input_history_setup,
+                game_input_event: Some(GameInputEvent::ActionPressed(PlayerActionControl::new(
+                    0,
+                    ControlAction::Attack,
+                ))),
+            },
+            ExpectedParams {
+                session_input_history: session_input_history_expected,
+                game_input_event: None,
+            },
+        )
+    }
+
     fn run_test(
         SetupParams {
             session_status: session_status_setup,
+            session_input_history: session_input_history_setup,
             game_input_event,
         }: SetupParams,
         ExpectedParams {
+            session_input_history: session_input_history_expected,
             game_input_event: game_input_event_expected,
         }: ExpectedParams,
     ) -> Result<(), Error> {
@@ -63,6 +95,7 @@ mod tests {
             .with_setup(setup_game_input_event_reader)
             .with_system_desc(NetworkInputResponseSystemDesc::default(), "", &[])
             .with_resource(session_status_setup)
+            .with_resource(session_input_history_setup)
             .with_effect(move |world| {
                 if let Some(game_input_event) = game_input_event {
                     let socket_addr = SocketAddr::from((Ipv4Addr::LOCALHOST, 1234));
@@ -75,13 +108,16 @@ mod tests {
                 }
             })
             .with_assertion(move |world| {
-                let (mut game_input_event_rid, network_input_ec) = world.system_data::<(
-                    WriteExpect<'_, ReaderId<GameInputEvent>>,
-                    Read<'_, EventChannel<GameInputEvent>>,
-                )>();
+                let (mut game_input_event_rid, network_input_ec, session_input_history) = world
+                    .system_data::<(
+                        WriteExpect<'_, ReaderId<GameInputEvent>>,
+                        Read<'_, EventChannel<GameInputEvent>>,
+                        Read<'_, SessionInputHistory>,
+                    )>();
                 let game_input_event = network_input_ec.read(&mut *game_input_event_rid).next();
 
                 assert_eq!(game_input_event_expected.as_ref(), game_input_event);
+                assert_eq!(&session_input_history_expected, &*session_input_history);
             })
             .run()
     }
@@ -95,10 +131,12 @@ mod tests {
 
     struct SetupParams {
         session_status: SessionStatus,
+        session_input_history: SessionInputHistory,
         game_input_event: Option<GameInputEvent>,
     }
 
     struct ExpectedParams {
+        session_input_history: SessionInputHistory,
         game_input_event: Option<GameInputEvent>,
     }
 }
@@ -0,0 +1,224 @@
+#[cfg(test)]
+mod tests {
+    use amethyst::{
+        ecs::{Read, SystemData, World, WorldExt, WriteExpect},
+        shrev::{EventChannel, ReaderId},
+        Error,
+    };
+    use amethyst_test::AmethystApplication;
+    use game_input_model::{
+        config::{ControlAction, PlayerActionControl},
+        GameInputEvent,
+    };
+    use game_play_model::GamePlayStatus;
+    use network_session_model::play::{SessionCondition, SessionStatus};
+    use rollback_model::{
+        config::SessionSyncMode,
+        play::{
+            InputHistory, RollbackStatus, RollbackTick, SessionInputHistory, TickedGameInputEvent,
+        },
+    };
+
+    use network_input_play::SessionInputCatchUpSystem;
+
+    #[test]
+    fn does_nothing_when_history_empty() -> Result<(), Error> {
+        run_test(
+            SetupParams {
+                session_condition: SessionCondition::PendingGameInputTick,
+                session_input_history: SessionInputHistory::default(),
+            },
+            ExpectedParams {
+                session_condition: SessionCondition::PendingGameInputTick,
+                session_input_history: SessionInputHistory::default(),
+                game_input_events: vec![],
+            },
+        )
+    }
+
+    #[test]
+    fn applies_earliest_tick_when_pending_game_input_tick() -> Result<(), Error> {
+        let mut session_input_history_setup = SessionInputHistory::default();
+        session_input_history_setup.push_game_input_event(press(0));
+        session_input_history_setup.tick();
+        session_input_history_setup.push_game_input_event(press(1));
+        session_input_history_setup.tick();
+
+        let mut session_input_history_expected = SessionInputHistory::default();
+        session_input_history_expected.push_game_input_event(press(1));
+        session_input_history_expected.tick();
+
+        run_test(
+            SetupParams {
+                session_condition: SessionCondition::PendingGameInputTick,
+                session_input_history: session_input_history_setup,
+            },
+            ExpectedParams {
+                session_condition: SessionCondition::Ready,
+                session_input_history: session_input_history_expected,
+                game_input_events: vec![press(0)],
+            },
+        )
+    }
+
+    #[test]
+    fn applies_pending_events_when_caught_up_to_tick_in_progress() -> Result<(), Error> {
+        let mut session_input_history_setup = SessionInputHistory::default();
+        session_input_history_setup.push_game_input_event(press(0));
+
+        run_test(
+            SetupParams {
+                session_condition: SessionCondition::PendingGameInputTick,
+                session_input_history: session_input_history_setup,
+            },
+            ExpectedParams {
+                session_condition: SessionCondition::PendingGameInputTick,
+                session_input_history: SessionInputHistory::default(),
+                game_input_events: vec![press(0)],
+            },
+        )
+    }
+
+    #[test]
+    fn waits_while_ready() -> Result<(), Error> {
+        let mut session_input_history_setup = SessionInputHistory::default();
+        session_input_history_setup.push_game_input_event(press(0));
+        session_input_history_setup.tick();
+
+        run_test(
+            SetupParams {
+                session_condition: SessionCondition::Ready,
+                session_input_history: session_input_history_setup.clone(),
+            },
+            ExpectedParams {
+                session_condition: SessionCondition::Ready,
+                session_input_history: session_input_history_setup,
+                game_input_events: vec![],
+            },
+        )
+    }
+
+    #[test]
+    fn discards_ticked_events_older_than_rollback_window() -> Result<(), Error> {
+        let mut session_input_history = SessionInputHistory::default();
+        session_input_history.push_ticked_game_input_event(TickedGameInputEvent::new(
+            RollbackTick::new(2),
+            press(0),
+        ));
+        session_input_history.push_ticked_game_input_event(TickedGameInputEvent::new(
+            RollbackTick::new(15),
+            press(1),
+        ));
+
+        AmethystApplication::blank()
+            .with_system(SessionInputCatchUpSystem::new(), "", &[])
+            .with_resource(SessionStatus::JoinEstablished)
+            .with_resource(SessionSyncMode::Rollback)
+            .with_resource(GamePlayStatus::Playing)
+            .with_resource(RollbackStatus {
+                tick: RollbackTick::new(20),
+                ..Default::default()
+            })
+            .with_resource(session_input_history)
+            .with_assertion(|world| {
+                let input_history = world.read_resource::<InputHistory>();
+                let rollback_status = world.read_resource::<RollbackStatus>();
+
+                assert!(input_history.events(RollbackTick::new(2)).is_empty());
+                assert!(input_history.events(RollbackTick::new(20)).is_empty());
+                assert_eq!(&[press(1)], input_history.events(RollbackTick::new(15)));
+                assert_eq!(Some(RollbackTick::new(15)), rollback_status.rollback_tick);
+                assert!(world.read_resource::<SessionInputHistory>().is_empty());
+            })
+            .run()
+    }
+
+    #[test]
+    fn records_confirmed_tick_for_spectator() -> Result<(), Error> {
+        let mut session_input_history = SessionInputHistory::default();
+        session_input_history.push_ticked_game_input_event(TickedGameInputEvent::new(
+            RollbackTick::new(3),
+            press(0),
+        ));
+        session_input_history.tick_confirmed = Some(RollbackTick::new(4));
+
+        AmethystApplication::blank()
+            .with_system(SessionInputCatchUpSystem::new(), "", &[])
+            .with_resource(SessionStatus::JoinEstablished)
+            .with_resource(SessionSyncMode::Rollback)
+            .with_resource(GamePlayStatus::Playing)
+            .with_resource(session_input_history)
+            .with_assertion(|world| {
+                let rollback_status = world.read_resource::<RollbackStatus>();
+
+                assert_eq!(Some(RollbackTick::new(4)), rollback_status.tick_confirmed);
+                assert_eq!(Some(RollbackTick::new(3)), rollback_status.rollback_tick);
+                assert!(world.read_resource::<SessionInputHistory>().is_empty());
+            })
+            .run()
+    }
+
+    fn run_test(
+        SetupParams {
+            session_condition: session_condition_setup,
+            session_input_history: session_input_history_setup,
+        }: SetupParams,
+        ExpectedParams {
+            session_condition: session_condition_expected,
+            session_input_history: session_input_history_expected,
+            game_input_events: game_input_events_expected,
+        }: ExpectedParams,
+    ) -> Result<(), Error> {
+        AmethystApplication::blank()
+            .with_setup(<Read<'_, EventChannel<GameInputEvent>> as SystemData>::setup)
+            .with_setup(setup_game_input_event_reader)
+            .with_system(SessionInputCatchUpSystem::new(), "", &[])
+            .with_resource(SessionStatus::JoinEstablished)
+            .with_resource(session_condition_setup)
+            .with_resource(session_input_history_setup)
+            .with_assertion(move |world| {
+                let (
+                    mut game_input_event_rid,
+                    game_input_ec,
+                    session_condition,
+                    session_input_history,
+                ) = world.system_data::<(
+                    WriteExpect<'_, ReaderId<GameInputEvent>>,
+                    Read<'_, EventChannel<GameInputEvent>>,
+                    Read<'_, SessionCondition>,
+                    Read<'_, SessionInputHistory>,
+                )>();
+                let game_input_events = game_input_ec
+                    .read(&mut *game_input_event_rid)
+                    .copied()
+                    .collect::<Vec<GameInputEvent>>();
+
+                assert_eq!(game_input_events_expected, game_input_events);
+                assert_eq!(session_condition_expected, *session_condition);
+                assert_eq!(&session_input_history_expected, &*session_input_history);
+            })
+            .run()
+    }
+
+    fn setup_game_input_event_reader(world: &mut World) {
+        let game_input_event_rid = world
+            .write_resource::<EventChannel<GameInputEvent>>()
+            .register_reader();
+        world.insert(game_input_event_rid);
+    }
+
+    fn press(player: usize) -> GameInputEvent {
+        GameInputEvent::ActionPressed(PlayerActionControl::new(player, ControlAction::Attack))
+    }
+
+    struct SetupParams {
+        session_condition: SessionCondition,
+        session_input_history: SessionInputHistory,
+    }
+
+    struct ExpectedParams {
+        session_condition: SessionCondition,
+        session_input_history: SessionInputHistory,
+        game_input_events: Vec<GameInputEvent>,
+    }
+}
@@ -0,0 +1,34 @@
+#[cfg(test)]
+mod tests {
+    use std::net::{IpAddr, Ipv4Addr, Ipv6Addr};
+
+    use network_session_model::config::SessionServerConfig;
+
+    #[test]
+    fn resolve_parses_ip_address() {
+        let session_server_config = SessionServerConfig::resolve("::1", 1234)
+            .expect("Expected `::1` to be parsed as an IP address.");
+
+        assert_eq!(
+            SessionServerConfig {
+                address: IpAddr::V6(Ipv6Addr::LOCALHOST),
+                port: 1234,
+            },
+            session_server_config
+        );
+    }
+
+    #[test]
+    fn resolve_prefers_ipv4_address_for_hostname() {
+        let session_server_config = SessionServerConfig::resolve("localhost", 2345)
+            .expect("Expected `localhost` to resolve to an address.");
+
+        assert_eq!(
+            SessionServerConfig {
+                address: IpAddr::V4(Ipv4Addr::LOCALHOST),
+                port: 2345,
+            },
+            session_server_config
+        );
+    }
+}
@@ -0,0 +1,3 @@
+mod session_checksum;
+mod session_desync;
+mod session_latency;
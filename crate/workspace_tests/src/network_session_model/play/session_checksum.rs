@@ -0,0 +1,91 @@
+#[cfg(test)]
+mod tests {
+    use network_session_model::play::{SessionChecksum, SessionChecksumEntry, SessionTickCount};
+
+    #[test]
+    fn checksum_is_equal_for_equal_entries() {
+        let session_checksum_0 = SessionChecksum::from_entries(SessionTickCount(1), entries());
+        let session_checksum_1 = SessionChecksum::from_entries(SessionTickCount(1), entries());
+
+        assert_eq!(session_checksum_0.checksum, session_checksum_1.checksum);
+    }
+
+    #[test]
+    fn checksum_differs_when_translation_differs() {
+        let mut entries_diverged = entries();
+        entries_diverged[1].translation[0] += 0.001;
+
+        let session_checksum_0 = SessionChecksum::from_entries(SessionTickCount(1), entries());
+        let session_checksum_1 =
+            SessionChecksum::from_entries(SessionTickCount(1), entries_diverged);
+
+        assert_ne!(session_checksum_0.checksum, session_checksum_1.checksum);
+    }
+
+    #[test]
+    fn checksum_differs_when_health_points_presence_differs() {
+        let mut entries_diverged = entries();
+        entries_diverged[1].health_points = Some(0);
+
+        let session_checksum_0 = SessionChecksum::from_entries(SessionTickCount(1), entries());
+        let session_checksum_1 =
+            SessionChecksum::from_entries(SessionTickCount(1), entries_diverged);
+
+        assert_ne!(session_checksum_0.checksum, session_checksum_1.checksum);
+    }
+
+    #[test]
+    fn checksum_is_equal_when_entry_order_differs() {
+        let mut entries_reordered = entries();
+        entries_reordered.reverse();
+
+        let session_checksum_0 = SessionChecksum::from_entries(SessionTickCount(1), entries());
+        let session_checksum_1 =
+            SessionChecksum::from_entries(SessionTickCount(1), entries_reordered);
+
+        assert_eq!(session_checksum_0.checksum, session_checksum_1.checksum);
+        assert_eq!(session_checksum_0.entries, session_checksum_1.entries);
+    }
+
+    #[test]
+    fn checksum_differs_when_controller_id_differs() {
+        let mut entries_diverged = entries();
+        entries_diverged[0].controller_id = Some(1);
+
+        let session_checksum_0 = SessionChecksum::from_entries(SessionTickCount(1), entries());
+        let session_checksum_1 =
+            SessionChecksum::from_entries(SessionTickCount(1), entries_diverged);
+
+        assert_ne!(session_checksum_0.checksum, session_checksum_1.checksum);
+    }
+
+    #[test]
+    fn entries_are_sorted_by_controller_id_then_values() {
+        let session_checksum = SessionChecksum::from_entries(
+            SessionTickCount(1),
+            vec![
+                SessionChecksumEntry::new(None, [0., 0., 0.], None, 2),
+                SessionChecksumEntry::new(Some(1), [0., 0., 0.], Some(50), 3),
+                SessionChecksumEntry::new(None, [0., 0., 0.], None, 1),
+                SessionChecksumEntry::new(Some(0), [0., 0., 0.], Some(100), 3),
+            ],
+        );
+
+        assert_eq!(
+            vec![
+                SessionChecksumEntry::new(Some(0), [0., 0., 0.], Some(100), 3),
+                SessionChecksumEntry::new(Some(1), [0., 0., 0.], Some(50), 3),
+                SessionChecksumEntry::new(None, [0., 0., 0.], None, 1),
+                SessionChecksumEntry::new(None, [0., 0., 0.], None, 2),
+            ],
+            session_checksum.entries
+        );
+    }
+
+    fn entries() -> Vec<SessionChecksumEntry> {
+        vec![
+            SessionChecksumEntry::new(Some(0), [10., 20., 0.], Some(100), 3),
+            SessionChecksumEntry::new(None, [15., 0., 5.], None, 1),
+        ]
+    }
+}
@@ -0,0 +1,68 @@
+#[cfg(test)]
+mod tests {
+    use network_session_model::play::{
+        SessionChecksum, SessionChecksumDiff, SessionChecksumEntry, SessionDesync, SessionDeviceId,
+        SessionTickCount,
+    };
+
+    #[test]
+    fn detect_returns_none_when_checksums_match() {
+        let session_checksum_0 = SessionChecksum::from_entries(SessionTickCount(5), entries());
+        let session_checksum_1 = SessionChecksum::from_entries(SessionTickCount(5), entries());
+
+        let session_desync = SessionDesync::detect(
+            (SessionDeviceId::new(0), &session_checksum_0),
+            vec![(SessionDeviceId::new(1), &session_checksum_1)].into_iter(),
+        );
+
+        assert_eq!(None, session_desync);
+    }
+
+    #[test]
+    fn detect_returns_differing_entries_when_checksums_differ() {
+        let entry_diverged = SessionChecksumEntry::new(Some(0), [11., 20., 0.], Some(100), 3);
+        let entry_extra = SessionChecksumEntry::new(None, [0., 0., 0.], None, 5);
+        let mut entries_diverged = entries();
+        entries_diverged[0] = entry_diverged;
+        entries_diverged.push(entry_extra);
+
+        let session_checksum_0 = SessionChecksum::from_entries(SessionTickCount(5), entries());
+        let session_checksum_1 = SessionChecksum::from_entries(SessionTickCount(5), entries());
+        let session_checksum_2 =
+            SessionChecksum::from_entries(SessionTickCount(5), entries_diverged);
+
+        let session_desync = SessionDesync::detect(
+            (SessionDeviceId::new(0), &session_checksum_0),
+            vec![
+                (SessionDeviceId::new(1), &session_checksum_1),
+                (SessionDeviceId::new(2), &session_checksum_2),
+            ]
+            .into_iter(),
+        );
+
+        assert_eq!(
+            Some(SessionDesync::new(
+                SessionTickCount(5),
+                SessionDeviceId::new(0),
+                vec![SessionDeviceId::new(2)],
+                vec![
+                    SessionChecksumDiff::new(
+                        SessionDeviceId::new(2),
+                        0,
+                        Some(entries()[0]),
+                        Some(entry_diverged),
+                    ),
+                    SessionChecksumDiff::new(SessionDeviceId::new(2), 2, None, Some(entry_extra)),
+                ],
+            )),
+            session_desync
+        );
+    }
+
+    fn entries() -> Vec<SessionChecksumEntry> {
+        vec![
+            SessionChecksumEntry::new(Some(0), [10., 20., 0.], Some(100), 3),
+            SessionChecksumEntry::new(None, [15., 0., 5.], None, 1),
+        ]
+    }
+}
@@ -0,0 +1,40 @@
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use network_session_model::play::SessionLatency;
+
+    #[test]
+    fn from_sample_initializes_jitter_to_half_rtt() {
+        let session_latency = SessionLatency::from_sample(Duration::from_millis(100));
+
+        assert_eq!(
+            SessionLatency::new(Duration::from_millis(100), Duration::from_millis(50)),
+            session_latency
+        );
+    }
+
+    #[test]
+    fn update_smooths_rtt_and_jitter() {
+        let mut session_latency = SessionLatency::from_sample(Duration::from_millis(100));
+
+        session_latency.update(Duration::from_millis(180));
+
+        // rtt: (100 * 7 + 180) / 8 = 110
+        // jitter: (50 * 3 + |100 - 180|) / 4 = 57.5
+        assert_eq!(
+            SessionLatency::new(Duration::from_millis(110), Duration::from_micros(57_500)),
+            session_latency
+        );
+    }
+
+    #[test]
+    fn update_converges_to_stable_rtt() {
+        let mut session_latency = SessionLatency::from_sample(Duration::from_millis(100));
+
+        (0..100).for_each(|_| session_latency.update(Duration::from_millis(20)));
+
+        assert_eq!(20, session_latency.rtt.as_millis());
+        assert_eq!(0, session_latency.jitter.as_millis());
+    }
+}
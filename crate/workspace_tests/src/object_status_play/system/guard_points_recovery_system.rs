@@ -0,0 +1,39 @@
+#[cfg(test)]
+mod tests {
+    use amethyst::{
+        ecs::{Builder, Join, ReadStorage, World, WorldExt},
+        Error,
+    };
+    use amethyst_test::AmethystApplication;
+    use object_model::play::{GuardPoints, GUARD_POINTS_DEFAULT};
+
+    use object_status_play::GuardPointsRecoverySystem;
+
+    #[test]
+    fn recovers_guard_points_each_tick_until_full() -> Result<(), Error> {
+        AmethystApplication::blank()
+            .with_system(GuardPointsRecoverySystem::new(), "", &[])
+            .with_effect(|world| create_entity_with_guard_points(world, GUARD_POINTS_DEFAULT - 2))
+            .with_assertion(|world| {
+                assert_entity_with_guard_points(world, GUARD_POINTS_DEFAULT - 1)
+            })
+            .with_assertion(|world| assert_entity_with_guard_points(world, GUARD_POINTS_DEFAULT))
+            .with_assertion(|world| assert_entity_with_guard_points(world, GUARD_POINTS_DEFAULT))
+            .run()
+    }
+
+    fn create_entity_with_guard_points(world: &mut World, points: u32) {
+        world.create_entity().with(GuardPoints::new(points)).build();
+    }
+
+    fn assert_entity_with_guard_points(world: &mut World, points: u32) {
+        let guard_points = world
+            .system_data::<ReadStorage<'_, GuardPoints>>()
+            .join()
+            .next()
+            .cloned()
+            .expect("Expected entity with `GuardPoints` to exist.");
+
+        assert_eq!(GuardPoints::new(points), guard_points);
+    }
+}
@@ -0,0 +1,2 @@
+mod replay;
+mod replay_control_input_event;
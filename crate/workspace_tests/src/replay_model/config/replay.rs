@@ -0,0 +1,71 @@
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use asset_model::config::AssetSlug;
+    use game_input_model::{
+        config::{ControlAction, PlayerActionControl},
+        GameInputEvent,
+    };
+    use game_play_model::{play::GamePlaySeed, GameMode};
+
+    use replay_model::config::{Replay, ReplayTick};
+
+    #[test]
+    fn tick_returns_recorded_input_for_tick() {
+        let replay = replay(vec![replay_tick(3, 0), replay_tick(5, 1)]);
+
+        assert_eq!(Some(&replay_tick(3, 0)), replay.tick(3));
+        assert_eq!(Some(&replay_tick(5, 1)), replay.tick(5));
+    }
+
+    #[test]
+    fn tick_returns_none_when_tick_has_no_input() {
+        let replay = replay(vec![replay_tick(3, 0), replay_tick(5, 1)]);
+
+        assert_eq!(None, replay.tick(0));
+        assert_eq!(None, replay.tick(4));
+        assert_eq!(None, replay.tick(6));
+    }
+
+    #[test]
+    fn deserializes_with_defaults() {
+        let replay_yaml = "\
+characters:
+  - { controller_id: 0, character: \"default/hero\" }
+map: \"default/arena\"
+tick_count: 10
+";
+
+        let replay = serde_yaml::from_str::<Replay>(replay_yaml).expect("Failed to deserialize.");
+
+        assert_eq!(GameMode::default(), replay.game_mode);
+        assert_eq!(1, replay.characters.len());
+        assert_eq!(GamePlaySeed::default(), replay.seed);
+        assert_eq!(10, replay.tick_count);
+        assert!(replay.ticks.is_empty());
+    }
+
+    fn replay(ticks: Vec<ReplayTick>) -> Replay {
+        let map = AssetSlug::from_str("default/arena").expect("Expected asset slug to be valid.");
+        Replay::new(
+            GameMode::default(),
+            Vec::new(),
+            map,
+            GamePlaySeed::default(),
+            10,
+            ticks,
+        )
+    }
+
+    fn replay_tick(tick: u32, player: usize) -> ReplayTick {
+        ReplayTick::new(
+            tick,
+            vec![GameInputEvent::ActionPressed(PlayerActionControl::new(
+                player,
+                ControlAction::Attack,
+            ))],
+            Vec::new(),
+        )
+    }
+}
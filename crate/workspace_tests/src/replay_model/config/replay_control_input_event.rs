@@ -0,0 +1,83 @@
+#[cfg(test)]
+mod tests {
+    use amethyst::ecs::{Builder, World, WorldExt};
+    use game_input_model::{
+        config::{Axis, ControlAction},
+        play::{AxisMoveEventData, ControlActionEventData, ControlInputEvent},
+    };
+
+    use replay_model::config::ReplayControlInputEvent;
+
+    #[test]
+    fn from_axis_moved_discards_entity() {
+        let entity = World::new().create_entity().build();
+        let control_input_event = ControlInputEvent::AxisMoved(AxisMoveEventData {
+            controller_id: 1,
+            entity,
+            axis: Axis::X,
+            value: -1.,
+        });
+
+        assert_eq!(
+            ReplayControlInputEvent::AxisMoved {
+                controller_id: 1,
+                axis: Axis::X,
+                value: -1.,
+            },
+            ReplayControlInputEvent::from(control_input_event)
+        );
+    }
+
+    #[test]
+    fn from_control_action_press_discards_entity() {
+        let entity = World::new().create_entity().build();
+        let control_input_event = ControlInputEvent::ControlActionPress(ControlActionEventData {
+            controller_id: 2,
+            entity,
+            control_action: ControlAction::Jump,
+        });
+
+        assert_eq!(
+            ReplayControlInputEvent::ControlActionPress {
+                controller_id: 2,
+                control_action: ControlAction::Jump,
+            },
+            ReplayControlInputEvent::from(control_input_event)
+        );
+    }
+
+    #[test]
+    fn from_control_action_release_discards_entity() {
+        let entity = World::new().create_entity().build();
+        let control_input_event = ControlInputEvent::ControlActionRelease(ControlActionEventData {
+            controller_id: 0,
+            entity,
+            control_action: ControlAction::Defend,
+        });
+
+        assert_eq!(
+            ReplayControlInputEvent::ControlActionRelease {
+                controller_id: 0,
+                control_action: ControlAction::Defend,
+            },
+            ReplayControlInputEvent::from(control_input_event)
+        );
+    }
+
+    #[test]
+    fn control_input_event_round_trips_with_entity() {
+        let entity = World::new().create_entity().build();
+        let control_input_event = ControlInputEvent::ControlActionPress(ControlActionEventData {
+            controller_id: 2,
+            entity,
+            control_action: ControlAction::Attack,
+        });
+        let replay_control_input_event = ReplayControlInputEvent::from(control_input_event);
+
+        assert_eq!(2, replay_control_input_event.controller_id());
+        assert_eq!(
+            control_input_event,
+            replay_control_input_event.control_input_event(entity)
+        );
+    }
+}
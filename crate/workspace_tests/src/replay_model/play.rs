@@ -0,0 +1 @@
+mod replay_playback;
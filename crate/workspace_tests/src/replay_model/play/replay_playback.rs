@@ -0,0 +1,33 @@
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use asset_model::config::AssetSlug;
+    use game_play_model::{play::GamePlaySeed, GameMode};
+
+    use replay_model::{config::Replay, play::ReplayPlayback};
+
+    #[test]
+    fn is_complete_when_all_ticks_played_back() {
+        let mut replay_playback = ReplayPlayback::new(replay(2));
+        assert!(!replay_playback.is_complete());
+
+        replay_playback.tick = 1;
+        assert!(!replay_playback.is_complete());
+
+        replay_playback.tick = 2;
+        assert!(replay_playback.is_complete());
+    }
+
+    fn replay(tick_count: u32) -> Replay {
+        let map = AssetSlug::from_str("default/arena").expect("Expected asset slug to be valid.");
+        Replay::new(
+            GameMode::default(),
+            Vec::new(),
+            map,
+            GamePlaySeed::default(),
+            tick_count,
+            Vec::new(),
+        )
+    }
+}
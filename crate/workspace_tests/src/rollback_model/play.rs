@@ -0,0 +1,3 @@
+mod input_history;
+mod rollback_status;
+mod session_input_history;
@@ -0,0 +1,40 @@
+#[cfg(test)]
+mod tests {
+    use game_input_model::{
+        config::{ControlAction, PlayerActionControl},
+        GameInputEvent,
+    };
+
+    use rollback_model::play::{InputHistory, RollbackTick, TickedGameInputEvent};
+
+    #[test]
+    fn events_returns_events_in_insertion_order_for_tick() {
+        let mut input_history = InputHistory::default();
+        input_history.insert(TickedGameInputEvent::new(RollbackTick::new(3), press(0)));
+        input_history.insert(TickedGameInputEvent::new(RollbackTick::new(5), press(1)));
+        input_history.insert(TickedGameInputEvent::new(RollbackTick::new(3), press(2)));
+
+        assert_eq!(
+            &[press(0), press(2)],
+            input_history.events(RollbackTick::new(3))
+        );
+        assert_eq!(&[press(1)], input_history.events(RollbackTick::new(5)));
+        assert!(input_history.events(RollbackTick::new(4)).is_empty());
+    }
+
+    #[test]
+    fn prune_before_discards_earlier_ticks() {
+        let mut input_history = InputHistory::default();
+        input_history.insert(TickedGameInputEvent::new(RollbackTick::new(3), press(0)));
+        input_history.insert(TickedGameInputEvent::new(RollbackTick::new(5), press(1)));
+
+        input_history.prune_before(RollbackTick::new(5));
+
+        assert!(input_history.events(RollbackTick::new(3)).is_empty());
+        assert_eq!(&[press(1)], input_history.events(RollbackTick::new(5)));
+    }
+
+    fn press(player: usize) -> GameInputEvent {
+        GameInputEvent::ActionPressed(PlayerActionControl::new(player, ControlAction::Attack))
+    }
+}
@@ -0,0 +1,56 @@
+#[cfg(test)]
+mod tests {
+    use rollback_model::play::{RollbackStatus, RollbackTick, ROLLBACK_TICKS_MAX};
+
+    #[test]
+    fn request_rollback_keeps_earliest_tick() {
+        let mut rollback_status = RollbackStatus::default();
+
+        rollback_status.request_rollback(RollbackTick::new(5));
+        rollback_status.request_rollback(RollbackTick::new(7));
+        rollback_status.request_rollback(RollbackTick::new(3));
+
+        assert_eq!(Some(RollbackTick::new(3)), rollback_status.rollback_tick);
+    }
+
+    #[test]
+    fn input_tick_is_live_tick_while_resimulating() {
+        let rollback_status = RollbackStatus {
+            tick: RollbackTick::new(4),
+            tick_live: Some(RollbackTick::new(20)),
+            rollback_tick: None,
+            tick_confirmed: None,
+        };
+
+        assert_eq!(RollbackTick::new(20), rollback_status.input_tick());
+        assert_eq!(
+            RollbackTick::new(20 - ROLLBACK_TICKS_MAX),
+            rollback_status.tick_min()
+        );
+    }
+
+    #[test]
+    fn tick_min_saturates_at_zero() {
+        let rollback_status = RollbackStatus {
+            tick: RollbackTick::new(ROLLBACK_TICKS_MAX - 1),
+            ..Default::default()
+        };
+
+        assert_eq!(RollbackTick::new(0), rollback_status.tick_min());
+    }
+
+    #[test]
+    fn tick_is_confirmed_up_to_confirmed_tick() {
+        let mut rollback_status = RollbackStatus {
+            tick: RollbackTick::new(5),
+            ..Default::default()
+        };
+        assert!(rollback_status.is_tick_confirmed());
+
+        rollback_status.tick_confirmed = Some(RollbackTick::new(4));
+        assert!(!rollback_status.is_tick_confirmed());
+
+        rollback_status.tick_confirmed = Some(RollbackTick::new(5));
+        assert!(rollback_status.is_tick_confirmed());
+    }
+}
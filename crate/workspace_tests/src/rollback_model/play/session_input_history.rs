@@ -0,0 +1,167 @@
+#[cfg(test)]
+mod tests {
+    use game_input_model::{
+        config::{ControlAction, PlayerActionControl},
+        GameInputEvent,
+    };
+
+    use rollback_model::play::{
+        RollbackTick, SessionInputHistory, TickedGameInputEvent,
+        SESSION_INPUT_HISTORY_TICKED_EVENTS_MAX, SESSION_INPUT_HISTORY_TICKS_MAX,
+    };
+
+    #[test]
+    fn tick_completes_pending_events() {
+        let mut session_input_history = SessionInputHistory::default();
+        session_input_history.push_game_input_event(press(0));
+        session_input_history.tick();
+        session_input_history.tick();
+        session_input_history.push_game_input_event(press(1));
+
+        assert_eq!(Some(vec![press(0)]), session_input_history.pop_tick());
+        assert_eq!(Some(vec![]), session_input_history.pop_tick());
+        assert_eq!(None, session_input_history.pop_tick());
+        assert_eq!(
+            vec![press(1)],
+            session_input_history.game_input_events_pending
+        );
+    }
+
+    #[test]
+    fn is_empty_when_no_input_left_to_apply() {
+        let mut session_input_history = SessionInputHistory::default();
+        assert!(session_input_history.is_empty());
+
+        session_input_history.tick();
+        assert!(!session_input_history.is_empty());
+
+        session_input_history.pop_tick();
+        assert!(session_input_history.is_empty());
+
+        session_input_history.push_ticked_game_input_event(TickedGameInputEvent::new(
+            RollbackTick::new(3),
+            press(0),
+        ));
+        assert!(!session_input_history.is_empty());
+    }
+
+    #[test]
+    fn since_tick_skips_applied_ticks() {
+        let mut session_input_history = SessionInputHistory::default();
+        session_input_history.push_game_input_event(press(0));
+        session_input_history.tick();
+        session_input_history.push_game_input_event(press(1));
+        session_input_history.tick();
+        session_input_history.push_game_input_event(press(2));
+
+        let mut session_input_history = session_input_history
+            .since_tick(1)
+            .expect("Expected applied ticks to be in history.");
+
+        assert_eq!(Some(vec![press(1)]), session_input_history.pop_tick());
+        assert_eq!(None, session_input_history.pop_tick());
+        assert_eq!(
+            vec![press(2)],
+            session_input_history.game_input_events_pending
+        );
+    }
+
+    #[test]
+    fn discards_earliest_tick_when_exceeding_tick_limit() {
+        let mut session_input_history = SessionInputHistory::default();
+        session_input_history.push_game_input_event(press(0));
+        session_input_history.tick();
+        session_input_history.push_game_input_event(press(1));
+        session_input_history.tick();
+        (2..SESSION_INPUT_HISTORY_TICKS_MAX).for_each(|_| session_input_history.tick());
+        assert!(session_input_history.is_complete());
+
+        session_input_history.tick();
+
+        assert!(!session_input_history.is_complete());
+        assert_eq!(1, session_input_history.ticks_discarded);
+        assert_eq!(
+            SESSION_INPUT_HISTORY_TICKS_MAX,
+            session_input_history.game_input_ticks.len()
+        );
+        assert_eq!(Some(vec![press(1)]), session_input_history.pop_tick());
+    }
+
+    #[test]
+    fn since_tick_returns_none_when_unapplied_ticks_discarded() {
+        let mut session_input_history = SessionInputHistory::default();
+        (0..=SESSION_INPUT_HISTORY_TICKS_MAX).for_each(|_| session_input_history.tick());
+
+        assert_eq!(None, session_input_history.since_tick(0));
+        assert_eq!(
+            Some(SESSION_INPUT_HISTORY_TICKS_MAX - 1),
+            session_input_history
+                .since_tick(2)
+                .map(|session_input_history| session_input_history.game_input_ticks.len())
+        );
+    }
+
+    #[test]
+    fn confirmed_keeps_only_events_of_confirmed_ticks() {
+        let mut session_input_history = SessionInputHistory::default();
+        session_input_history.push_ticked_game_input_event(TickedGameInputEvent::new(
+            RollbackTick::new(3),
+            press(0),
+        ));
+        session_input_history.push_ticked_game_input_event(TickedGameInputEvent::new(
+            RollbackTick::new(5),
+            press(1),
+        ));
+        session_input_history.push_ticked_game_input_event(TickedGameInputEvent::new(
+            RollbackTick::new(6),
+            press(2),
+        ));
+        assert!(session_input_history
+            .confirmed()
+            .ticked_game_input_events
+            .is_empty());
+
+        session_input_history.tick_confirmed = Some(RollbackTick::new(5));
+        let session_input_history = session_input_history.confirmed();
+
+        assert_eq!(
+            vec![
+                TickedGameInputEvent::new(RollbackTick::new(3), press(0)),
+                TickedGameInputEvent::new(RollbackTick::new(5), press(1)),
+            ],
+            session_input_history
+                .ticked_game_input_events
+                .iter()
+                .copied()
+                .collect::<Vec<_>>()
+        );
+        assert_eq!(
+            Some(RollbackTick::new(5)),
+            session_input_history.tick_confirmed
+        );
+    }
+
+    #[test]
+    fn is_incomplete_when_ticked_events_discarded() {
+        let mut session_input_history = SessionInputHistory::default();
+        (0..SESSION_INPUT_HISTORY_TICKED_EVENTS_MAX).for_each(|_| {
+            session_input_history.push_ticked_game_input_event(TickedGameInputEvent::new(
+                RollbackTick::new(0),
+                press(0),
+            ))
+        });
+        assert!(session_input_history.is_complete());
+
+        session_input_history.push_ticked_game_input_event(TickedGameInputEvent::new(
+            RollbackTick::new(1),
+            press(0),
+        ));
+
+        assert!(!session_input_history.is_complete());
+        assert_eq!(1, session_input_history.ticked_game_input_events_discarded);
+    }
+
+    fn press(player: usize) -> GameInputEvent {
+        GameInputEvent::ActionPressed(PlayerActionControl::new(player, ControlAction::Attack))
+    }
+}
@@ -0,0 +1,4 @@
+mod rollback_event_channels;
+mod rollback_simulator;
+mod simulated_latency_transport;
+mod system;
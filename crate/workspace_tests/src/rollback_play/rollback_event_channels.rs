@@ -0,0 +1,47 @@
+#[cfg(test)]
+mod tests {
+    use amethyst::{
+        ecs::{World, WorldExt},
+        shrev::EventChannel,
+    };
+
+    use rollback_play::RollbackEventChannels;
+
+    #[test]
+    fn swap_isolates_events_written_to_swapped_channels() {
+        let mut world = World::new();
+        world.insert(EventChannel::<u32>::new());
+        let mut reader_id = world
+            .write_resource::<EventChannel<u32>>()
+            .register_reader();
+        let mut rollback_event_channels = RollbackEventChannels::default();
+        rollback_event_channels.register::<u32>();
+
+        rollback_event_channels.swap(&mut world);
+        let mut reader_id_swapped = world
+            .write_resource::<EventChannel<u32>>()
+            .register_reader();
+        world.write_resource::<EventChannel<u32>>().single_write(1);
+        rollback_event_channels.swap(&mut world);
+        world.write_resource::<EventChannel<u32>>().single_write(2);
+
+        assert_eq!(
+            vec![2],
+            world
+                .read_resource::<EventChannel<u32>>()
+                .read(&mut reader_id)
+                .copied()
+                .collect::<Vec<u32>>()
+        );
+
+        rollback_event_channels.swap(&mut world);
+        assert_eq!(
+            vec![1],
+            world
+                .read_resource::<EventChannel<u32>>()
+                .read(&mut reader_id_swapped)
+                .copied()
+                .collect::<Vec<u32>>()
+        );
+    }
+}
@@ -0,0 +1,396 @@
+#[cfg(test)]
+mod tests {
+    use std::net::{Ipv4Addr, SocketAddr};
+
+    use amethyst::{
+        core::SystemDesc,
+        ecs::{
+            Builder, Dispatcher, DispatcherBuilder, Entity, Join, Read, ReadStorage, System, World,
+            WorldExt, WriteStorage,
+        },
+        input::InputEvent,
+        shrev::{EventChannel, ReaderId},
+    };
+    use game_input_model::{
+        config::{Axis, ControlBindings, ControllerId, PlayerAxisControl},
+        play::{ControllerInput, InputControlled},
+        GameInputEvent,
+    };
+    use game_play::GamePlaySnapshot;
+    use game_play_model::GamePlayStatus;
+    use kinematic_model::config::Position;
+    use net_model::play::{NetData, NetEventChannel, NetMessageEvent};
+    use network_session_model::play::SessionStatus;
+    use rollback_model::{
+        config::SessionSyncMode,
+        play::{
+            InputHistory, RollbackStatus, RollbackTick, TickedGameInputEvent, ROLLBACK_TICKS_MAX,
+        },
+    };
+    use snapshot_model::play::{DeferredDelete, DeleteMode, SnapshotRegistry};
+
+    use rollback_play::{
+        RollbackEventChannels, RollbackInputReplaySystem, RollbackInputRequestSystemDesc,
+        RollbackInputResponseSystemDesc, RollbackSimulator, SimulatedLatencyTransport,
+    };
+
+    const CONTROLLER_IDS: [ControllerId; 2] = [0, 1];
+
+    #[test]
+    fn resimulates_late_remote_input_to_match_input_timeline() {
+        let tick_count = 30;
+        let positions = run_test(4, tick_count);
+
+        let positions_expected = [
+            expected_position_x(0, tick_count),
+            expected_position_x(1, tick_count),
+        ];
+        assert_eq!([positions_expected, positions_expected], positions);
+    }
+
+    #[test]
+    fn matches_input_timeline_when_input_arrives_on_time() {
+        let tick_count = 30;
+        let positions = run_test(0, tick_count);
+
+        let positions_expected = [
+            expected_position_x(0, tick_count),
+            expected_position_x(1, tick_count),
+        ];
+        assert_eq!([positions_expected, positions_expected], positions);
+    }
+
+    #[test]
+    fn predicts_remote_input_is_unchanged_until_late_input_arrives() {
+        // Controller 0 moves on tick 2, which arrives at client 1 on tick 6.
+        // Controller 1 moves on tick 4, which arrives at client 0 on tick 8.
+        let tick_count = 6;
+        let positions = run_test(4, tick_count);
+
+        assert_eq!(
+            [
+                [expected_position_x(0, tick_count), 0.],
+                [0., expected_position_x(1, tick_count)],
+            ],
+            positions
+        );
+    }
+
+    #[test]
+    fn restores_components_of_entity_deleted_after_captured_tick() {
+        let mut world = snapshot_world();
+        let entity = world
+            .create_entity()
+            .with(Position::<f32>::new(1., 2., 3.))
+            .build();
+        let mut rollback_dispatcher = DispatcherBuilder::new().build();
+        let mut rollback_simulator = RollbackSimulator::<GamePlaySnapshot>::new();
+
+        rollback_simulator.update(&mut world, &mut rollback_dispatcher, |_| {});
+        rollback_simulator.update(&mut world, &mut rollback_dispatcher, |world| {
+            delete_entity(world, entity)
+        });
+
+        assert!(world.is_alive(entity));
+        assert_eq!(None, world.read_storage::<Position<f32>>().get(entity));
+
+        world
+            .write_resource::<RollbackStatus>()
+            .request_rollback(RollbackTick::new(0));
+        rollback_simulator.update(&mut world, &mut rollback_dispatcher, |_| {});
+
+        assert!(world.is_alive(entity));
+        assert_eq!(
+            Some(&Position::<f32>::new(1., 2., 3.)),
+            world.read_storage::<Position<f32>>().get(entity)
+        );
+        assert_eq!(None, world.read_storage::<DeferredDelete>().get(entity));
+    }
+
+    #[test]
+    fn deletes_entity_once_deletion_is_older_than_rollback_window() {
+        let mut world = snapshot_world();
+        let entity = world
+            .create_entity()
+            .with(Position::<f32>::new(1., 2., 3.))
+            .build();
+        let mut rollback_dispatcher = DispatcherBuilder::new().build();
+        let mut rollback_simulator = RollbackSimulator::<GamePlaySnapshot>::new();
+
+        rollback_simulator.update(&mut world, &mut rollback_dispatcher, |world| {
+            delete_entity(world, entity)
+        });
+        (1..ROLLBACK_TICKS_MAX).for_each(|_| {
+            rollback_simulator.update(&mut world, &mut rollback_dispatcher, |_| {});
+        });
+
+        assert!(world.is_alive(entity));
+
+        rollback_simulator.update(&mut world, &mut rollback_dispatcher, |_| {});
+
+        assert!(!world.is_alive(entity));
+    }
+
+    /// Runs two clients that each control one character, and returns each client's view of each
+    /// character's x position.
+    fn run_test(latency: u32, tick_count: u32) -> [[f32; 2]; 2] {
+        let mut clients = [client(), client()];
+        let mut transports = [
+            SimulatedLatencyTransport::<TickedGameInputEvent>::new(latency),
+            SimulatedLatencyTransport::<TickedGameInputEvent>::new(latency),
+        ];
+        let socket_addr = SocketAddr::from((Ipv4Addr::LOCALHOST, 1234));
+
+        (0..tick_count).for_each(|tick| {
+            let tick = RollbackTick::new(tick);
+            clients
+                .iter_mut()
+                .zip(CONTROLLER_IDS.iter().copied())
+                .enumerate()
+                .for_each(|(index, (client, controller_id))| {
+                    let Client {
+                        world,
+                        dispatcher,
+                        rollback_dispatcher,
+                        rollback_simulator,
+                        net_message_event_rid,
+                    } = client;
+
+                    if let Some(value) = axis_value_change(controller_id, tick.0) {
+                        world
+                            .write_resource::<EventChannel<InputEvent<ControlBindings>>>()
+                            .single_write(InputEvent::AxisMoved {
+                                axis: PlayerAxisControl::new(controller_id, Axis::X),
+                                value,
+                            });
+                    }
+
+                    let ticked_game_input_events = transports[index].receive(tick);
+                    world
+                        .write_resource::<NetEventChannel<TickedGameInputEvent>>()
+                        .iter_write(
+                            ticked_game_input_events
+                                .into_iter()
+                                .map(|ev| NetData::new(socket_addr, ev)),
+                        );
+
+                    rollback_simulator.update(world, rollback_dispatcher, |world| {
+                        dispatcher.dispatch(world)
+                    });
+
+                    let net_message_ec = world.read_resource::<EventChannel<NetMessageEvent>>();
+                    let transport_remote = &mut transports[1 - index];
+                    net_message_ec
+                        .read(net_message_event_rid)
+                        .for_each(|net_message_event| {
+                            if let NetMessageEvent::TickedGameInputEvent(ev) = net_message_event {
+                                transport_remote.send(tick, *ev);
+                            }
+                        });
+                });
+        });
+
+        let [client_0, client_1] = clients;
+        [positions_x(&client_0.world), positions_x(&client_1.world)]
+    }
+
+    /// Returns a `World` whose `Position<f32>` and `ControllerInput` components are captured in
+    /// snapshots.
+    fn snapshot_world() -> World {
+        let mut world = World::new();
+        world.insert(RollbackStatus::default());
+        world.insert(InputHistory::default());
+
+        let mut snapshot_registry = SnapshotRegistry::default();
+        snapshot_registry
+            .register::<Position<f32>>("position")
+            .register::<ControllerInput>("controller_input")
+            .register::<DeferredDelete>("deferred_delete");
+        snapshot_registry.setup(&mut world);
+        world.insert(snapshot_registry);
+
+        world
+    }
+
+    /// Deletes the entity the way game play systems do.
+    fn delete_entity(world: &mut World, entity: Entity) {
+        let delete_mode = *world.read_resource::<DeleteMode>();
+        delete_mode
+            .delete(
+                &world.entities(),
+                &mut world.write_storage::<DeferredDelete>(),
+                entity,
+            )
+            .expect("Failed to delete entity.");
+    }
+
+    fn client() -> Client {
+        let mut world = snapshot_world();
+        world.insert(SessionSyncMode::Rollback);
+        world.insert(SessionStatus::HostEstablished);
+        world.insert(GamePlayStatus::Playing);
+        world.insert(EventChannel::<GameInputEvent>::new());
+        let mut rollback_event_channels = RollbackEventChannels::default();
+        rollback_event_channels.register::<GameInputEvent>();
+        world.insert(rollback_event_channels.clone());
+        world.register::<InputControlled>();
+
+        let rollback_input_request_system =
+            RollbackInputRequestSystemDesc::default().build(&mut world);
+        let rollback_input_response_system =
+            RollbackInputResponseSystemDesc::default().build(&mut world);
+        let net_message_event_rid = world
+            .write_resource::<EventChannel<NetMessageEvent>>()
+            .register_reader();
+
+        let mut dispatcher = DispatcherBuilder::new()
+            .with(rollback_input_request_system, "request", &[])
+            .with(rollback_input_response_system, "response", &[])
+            .with(
+                RollbackInputReplaySystem::new(),
+                "replay",
+                &["request", "response"],
+            )
+            .with(
+                ControllerPositionSystem::new(&mut world),
+                "position",
+                &["replay"],
+            )
+            .build();
+        dispatcher.setup(&mut world);
+
+        // Resimulated ticks read and write events on their own channels.
+        rollback_event_channels.swap(&mut world);
+        let mut rollback_dispatcher = DispatcherBuilder::new()
+            .with(RollbackInputReplaySystem::new(), "replay", &[])
+            .with(
+                ControllerPositionSystem::new(&mut world),
+                "position",
+                &["replay"],
+            )
+            .build();
+        rollback_dispatcher.setup(&mut world);
+        rollback_event_channels.swap(&mut world);
+
+        CONTROLLER_IDS.iter().copied().for_each(|controller_id| {
+            world
+                .create_entity()
+                .with(InputControlled::new(controller_id))
+                .with(ControllerInput::default())
+                .with(Position::<f32>::new(0., 0., 0.))
+                .build();
+        });
+
+        Client {
+            world,
+            dispatcher,
+            rollback_dispatcher,
+            rollback_simulator: RollbackSimulator::new(),
+            net_message_event_rid,
+        }
+    }
+
+    /// Returns the new x axis value for the controller, if it changes on the given tick.
+    fn axis_value_change(controller_id: ControllerId, tick: u32) -> Option<f32> {
+        match (controller_id, tick) {
+            (0, 2) => Some(1.),
+            (0, 9) => Some(-1.),
+            (0, 15) => Some(0.),
+            (1, 4) => Some(1.),
+            (1, 7) => Some(0.),
+            (1, 12) => Some(-1.),
+            (1, 20) => Some(0.),
+            _ => None,
+        }
+    }
+
+    /// Returns the x position of the controller's character if every input arrived on time.
+    fn expected_position_x(controller_id: ControllerId, tick_count: u32) -> f32 {
+        let mut value = 0.;
+        (0..tick_count).fold(0., |position_x, tick| {
+            if let Some(value_new) = axis_value_change(controller_id, tick) {
+                value = value_new;
+            }
+            position_x + value
+        })
+    }
+
+    fn positions_x(world: &World) -> [f32; 2] {
+        let (input_controlleds, positions) = world.system_data::<(
+            ReadStorage<'_, InputControlled>,
+            ReadStorage<'_, Position<f32>>,
+        )>();
+
+        let mut positions_x = [0.; 2];
+        (&input_controlleds, &positions)
+            .join()
+            .for_each(|(input_controlled, position)| {
+                positions_x[input_controlled.controller_id] = position.x;
+            });
+        positions_x
+    }
+
+    struct Client {
+        world: World,
+        dispatcher: Dispatcher<'static, 'static>,
+        rollback_dispatcher: Dispatcher<'static, 'static>,
+        rollback_simulator: RollbackSimulator<GamePlaySnapshot>,
+        net_message_event_rid: ReaderId<NetMessageEvent>,
+    }
+
+    /// Moves characters along the x axis by their controller's axis value each tick.
+    #[derive(Debug)]
+    struct ControllerPositionSystem {
+        game_input_event_rid: ReaderId<GameInputEvent>,
+    }
+
+    impl ControllerPositionSystem {
+        fn new(world: &mut World) -> Self {
+            let game_input_event_rid = world
+                .write_resource::<EventChannel<GameInputEvent>>()
+                .register_reader();
+            ControllerPositionSystem {
+                game_input_event_rid,
+            }
+        }
+    }
+
+    impl<'s> System<'s> for ControllerPositionSystem {
+        type SystemData = (
+            Read<'s, EventChannel<GameInputEvent>>,
+            ReadStorage<'s, InputControlled>,
+            WriteStorage<'s, ControllerInput>,
+            WriteStorage<'s, Position<f32>>,
+        );
+
+        fn run(
+            &mut self,
+            (game_input_ec, input_controlleds, mut controller_inputs, mut positions): Self::SystemData,
+        ) {
+            game_input_ec
+                .read(&mut self.game_input_event_rid)
+                .for_each(|ev| {
+                    if let GameInputEvent::AxisMoved {
+                        axis: PlayerAxisControl { player, .. },
+                        value,
+                    } = *ev
+                    {
+                        (&input_controlleds, &mut controller_inputs)
+                            .join()
+                            .filter(|(input_controlled, _)| {
+                                input_controlled.controller_id == player
+                            })
+                            .for_each(|(_, controller_input)| {
+                                controller_input.x_axis_value = value;
+                            });
+                    }
+                });
+
+            (&controller_inputs, &mut positions)
+                .join()
+                .for_each(|(controller_input, position)| {
+                    position.x += controller_input.x_axis_value;
+                });
+        }
+    }
+}
@@ -0,0 +1,27 @@
+#[cfg(test)]
+mod tests {
+    use rollback_model::play::RollbackTick;
+
+    use rollback_play::SimulatedLatencyTransport;
+
+    #[test]
+    fn receive_returns_messages_after_latency() {
+        let mut transport = SimulatedLatencyTransport::new(3);
+        transport.send(RollbackTick::new(0), 'a');
+        transport.send(RollbackTick::new(1), 'b');
+
+        assert_eq!(Vec::<char>::new(), transport.receive(RollbackTick::new(2)));
+        assert_eq!(vec!['a'], transport.receive(RollbackTick::new(3)));
+        assert!(!transport.is_empty());
+        assert_eq!(vec!['b'], transport.receive(RollbackTick::new(5)));
+        assert!(transport.is_empty());
+    }
+
+    #[test]
+    fn receive_returns_messages_immediately_when_latency_is_zero() {
+        let mut transport = SimulatedLatencyTransport::new(0);
+        transport.send(RollbackTick::new(4), 'a');
+
+        assert_eq!(vec!['a'], transport.receive(RollbackTick::new(4)));
+    }
+}
@@ -0,0 +1 @@
+mod rollback_input_response_system;
@@ -0,0 +1,185 @@
+#[cfg(test)]
+mod tests {
+    use std::net::{Ipv4Addr, SocketAddr};
+
+    use amethyst::{
+        ecs::{World, WorldExt},
+        Error,
+    };
+    use amethyst_test::AmethystApplication;
+    use game_input_model::{
+        config::{ControlAction, PlayerActionControl},
+        GameInputEvent,
+    };
+    use game_play_model::GamePlayStatus;
+    use net_model::play::{NetData, NetEventChannel};
+    use network_session_model::play::SessionStatus;
+    use rollback_model::{
+        config::SessionSyncMode,
+        play::{
+            InputHistory, RollbackStatus, RollbackTick, RollbackTickConfirm, SessionInputHistory,
+            TickedGameInputEvent,
+        },
+    };
+
+    use rollback_play::RollbackInputResponseSystemDesc;
+
+    const GAME_INPUT_EVENT: GameInputEvent = GameInputEvent::ActionPressed(PlayerActionControl {
+        player: 1,
+        action: ControlAction::Attack,
+    });
+
+    #[test]
+    fn records_input_and_requests_rollback_for_past_tick() -> Result<(), Error> {
+        run_test(
+            SetupParams {
+                session_sync_mode: SessionSyncMode::Rollback,
+                tick: RollbackTick::new(10),
+                event_tick: RollbackTick::new(7),
+            },
+            ExpectedParams {
+                rollback_tick: Some(RollbackTick::new(7)),
+                history_tick: Some(RollbackTick::new(7)),
+            },
+        )
+    }
+
+    #[test]
+    fn applies_input_to_current_tick_when_older_than_rollback_window() -> Result<(), Error> {
+        run_test(
+            SetupParams {
+                session_sync_mode: SessionSyncMode::Rollback,
+                tick: RollbackTick::new(30),
+                event_tick: RollbackTick::new(2),
+            },
+            ExpectedParams {
+                rollback_tick: Some(RollbackTick::new(30)),
+                history_tick: Some(RollbackTick::new(30)),
+            },
+        )
+    }
+
+    #[test]
+    fn ignores_input_in_lockstep_mode() -> Result<(), Error> {
+        run_test(
+            SetupParams {
+                session_sync_mode: SessionSyncMode::Lockstep,
+                tick: RollbackTick::new(10),
+                event_tick: RollbackTick::new(7),
+            },
+            ExpectedParams {
+                rollback_tick: None,
+                history_tick: None,
+            },
+        )
+    }
+
+    #[test]
+    fn records_confirmed_tick() -> Result<(), Error> {
+        AmethystApplication::blank()
+            .with_system_desc(RollbackInputResponseSystemDesc::default(), "", &[])
+            .with_resource(SessionSyncMode::Rollback)
+            .with_resource(SessionStatus::JoinEstablished)
+            .with_resource(GamePlayStatus::Playing)
+            .with_effect(|world| write_tick_confirms(world, &[3, 5]))
+            .with_assertion(|world| {
+                assert_eq!(
+                    Some(RollbackTick::new(5)),
+                    world.read_resource::<RollbackStatus>().tick_confirmed
+                );
+            })
+            .run()
+    }
+
+    #[test]
+    fn records_confirmed_tick_in_session_input_history_while_catching_up() -> Result<(), Error> {
+        let mut session_input_history = SessionInputHistory::default();
+        session_input_history.tick();
+
+        AmethystApplication::blank()
+            .with_system_desc(RollbackInputResponseSystemDesc::default(), "", &[])
+            .with_resource(SessionSyncMode::Rollback)
+            .with_resource(SessionStatus::JoinEstablished)
+            .with_resource(GamePlayStatus::Playing)
+            .with_resource(session_input_history)
+            .with_effect(|world| write_tick_confirms(world, &[4]))
+            .with_assertion(|world| {
+                assert_eq!(
+                    Some(RollbackTick::new(4)),
+                    world.read_resource::<SessionInputHistory>().tick_confirmed
+                );
+                assert_eq!(None, world.read_resource::<RollbackStatus>().tick_confirmed);
+            })
+            .run()
+    }
+
+    fn write_tick_confirms(world: &mut World, ticks: &[u32]) {
+        let socket_addr = SocketAddr::from((Ipv4Addr::LOCALHOST, 1234));
+        let mut rollback_tick_confirm_nec =
+            world.write_resource::<NetEventChannel<RollbackTickConfirm>>();
+        ticks.iter().copied().for_each(|tick| {
+            rollback_tick_confirm_nec.single_write(NetData {
+                socket_addr,
+                data: RollbackTickConfirm::new(RollbackTick::new(tick)),
+            })
+        });
+    }
+
+    fn run_test(
+        SetupParams {
+            session_sync_mode,
+            tick,
+            event_tick,
+        }: SetupParams,
+        ExpectedParams {
+            rollback_tick: rollback_tick_expected,
+            history_tick: history_tick_expected,
+        }: ExpectedParams,
+    ) -> Result<(), Error> {
+        AmethystApplication::blank()
+            .with_system_desc(RollbackInputResponseSystemDesc::default(), "", &[])
+            .with_resource(session_sync_mode)
+            .with_resource(SessionStatus::JoinEstablished)
+            .with_resource(GamePlayStatus::Playing)
+            .with_resource(RollbackStatus {
+                tick,
+                ..Default::default()
+            })
+            .with_resource(InputHistory::default())
+            .with_effect(move |world| {
+                let socket_addr = SocketAddr::from((Ipv4Addr::LOCALHOST, 1234));
+                world
+                    .write_resource::<NetEventChannel<TickedGameInputEvent>>()
+                    .single_write(NetData {
+                        socket_addr,
+                        data: TickedGameInputEvent::new(event_tick, GAME_INPUT_EVENT),
+                    });
+            })
+            .with_assertion(move |world| {
+                let rollback_status = *world.read_resource::<RollbackStatus>();
+                let mut input_history_expected = InputHistory::default();
+                if let Some(history_tick) = history_tick_expected {
+                    input_history_expected
+                        .insert(TickedGameInputEvent::new(history_tick, GAME_INPUT_EVENT));
+                }
+
+                assert_eq!(rollback_tick_expected, rollback_status.rollback_tick);
+                assert_eq!(
+                    input_history_expected,
+                    *world.read_resource::<InputHistory>()
+                );
+            })
+            .run()
+    }
+
+    struct SetupParams {
+        session_sync_mode: SessionSyncMode,
+        tick: RollbackTick,
+        event_tick: RollbackTick,
+    }
+
+    struct ExpectedParams {
+        rollback_tick: Option<RollbackTick>,
+        history_tick: Option<RollbackTick>,
+    }
+}
@@ -0,0 +1 @@
+mod session_listings;
@@ -0,0 +1,65 @@
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use network_session_model::play::{SessionCode, SessionDeviceName};
+
+    use session_browse_model::play::{
+        SessionBrowseModelError, SessionListing, SessionListingStatus, SessionListings,
+    };
+
+    #[test]
+    fn parses_space_separated_session_listings() {
+        let session_listings =
+            SessionListings::from_str("abcd:azriel:2:lobby efgh:byron:3:in_game");
+
+        assert_eq!(
+            Ok(SessionListings::new(vec![
+                session_listing("abcd", "azriel", 2, SessionListingStatus::Lobby),
+                session_listing("efgh", "byron", 3, SessionListingStatus::InGame),
+            ])),
+            session_listings
+        );
+    }
+
+    #[test]
+    fn parses_empty_session_listings() {
+        let session_listings = SessionListings::from_str("");
+
+        assert_eq!(Ok(SessionListings::default()), session_listings);
+    }
+
+    #[test]
+    fn returns_error_when_session_listing_is_invalid() {
+        let session_listings = SessionListings::from_str("abcd:azriel:2:lobby efgh:byron:three");
+
+        assert_eq!(
+            Err(SessionBrowseModelError::SessionListingsParseError),
+            session_listings
+        );
+    }
+
+    #[test]
+    fn returns_error_when_session_listing_has_extra_fields() {
+        let session_listing = SessionListing::from_str("abcd:azriel:2:lobby:extra");
+
+        assert_eq!(
+            Err(SessionBrowseModelError::SessionListingParseError),
+            session_listing
+        );
+    }
+
+    fn session_listing(
+        session_code: &str,
+        session_host_name: &str,
+        session_device_count: usize,
+        session_listing_status: SessionListingStatus,
+    ) -> SessionListing {
+        SessionListing::new(
+            SessionCode::new(String::from(session_code)),
+            SessionDeviceName::new(String::from(session_host_name)),
+            session_device_count,
+            session_listing_status,
+        )
+    }
+}
@@ -0,0 +1,2 @@
+mod session_browse_event_stdin_mapper;
+mod session_listings_log_system;
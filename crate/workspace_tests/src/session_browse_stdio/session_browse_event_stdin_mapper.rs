@@ -0,0 +1,40 @@
+#[cfg(test)]
+mod tests {
+    use network_session_model::play::SessionCode;
+    use session_browse_model::{play::SessionSelectParams, SessionBrowseEvent};
+    use stdio_spi::StdinMapper;
+
+    use session_browse_stdio::SessionBrowseEventStdinMapper;
+
+    #[test]
+    fn maps_session_list_request_event() {
+        let args = SessionBrowseEvent::SessionListRequest;
+
+        let result = SessionBrowseEventStdinMapper::map(&(), args.clone());
+
+        assert!(result.is_ok());
+        assert_eq!(args, result.unwrap())
+    }
+
+    #[test]
+    fn maps_session_select_event() {
+        let args = SessionBrowseEvent::SessionSelect(SessionSelectParams::new(SessionCode::new(
+            String::from("abcd"),
+        )));
+
+        let result = SessionBrowseEventStdinMapper::map(&(), args.clone());
+
+        assert!(result.is_ok());
+        assert_eq!(args, result.unwrap())
+    }
+
+    #[test]
+    fn maps_back_event() {
+        let args = SessionBrowseEvent::Back;
+
+        let result = SessionBrowseEventStdinMapper::map(&(), args.clone());
+
+        assert!(result.is_ok());
+        assert_eq!(args, result.unwrap())
+    }
+}
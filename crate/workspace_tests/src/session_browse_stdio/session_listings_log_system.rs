@@ -0,0 +1,42 @@
+#[cfg(test)]
+mod tests {
+    use network_session_model::play::{SessionCode, SessionDeviceName};
+    use session_browse_model::play::{SessionListing, SessionListingStatus, SessionListings};
+
+    use session_browse_stdio::SessionListingsLogSystem;
+
+    #[test]
+    fn listing_lines_reports_no_sessions_when_empty() {
+        assert_eq!(
+            vec![String::from("No public sessions found.")],
+            SessionListingsLogSystem::listing_lines(&SessionListings::default())
+        );
+    }
+
+    #[test]
+    fn listing_lines_lists_each_session() {
+        let session_listings = SessionListings::new(vec![
+            SessionListing::new(
+                SessionCode::from(String::from("abcd")),
+                SessionDeviceName::from(String::from("azriel")),
+                2,
+                SessionListingStatus::Lobby,
+            ),
+            SessionListing::new(
+                SessionCode::from(String::from("efgh")),
+                SessionDeviceName::from(String::from("byron")),
+                3,
+                SessionListingStatus::InGame,
+            ),
+        ]);
+
+        assert_eq!(
+            vec![
+                String::from("Public sessions:"),
+                String::from("* `abcd` hosted by `azriel`, 2 device(s), lobby"),
+                String::from("* `efgh` hosted by `byron`, 3 device(s), in_game"),
+            ],
+            SessionListingsLogSystem::listing_lines(&session_listings)
+        );
+    }
+}
@@ -35,6 +35,7 @@ mod tests {
                 0,
                 String::from("p0"),
             )]),
+            session_public: false,
         });
 
         run_test(
@@ -61,6 +62,7 @@ mod tests {
                             0,
                             String::from("p0"),
                         )]),
+                        session_public: false,
                     },
                 )),
             },
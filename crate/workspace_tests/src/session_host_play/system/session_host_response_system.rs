@@ -11,7 +11,7 @@ mod tests {
     use net_model::play::{NetData, NetEventChannel};
     use network_session_model::play::{
         Session, SessionCode, SessionDevice, SessionDeviceId, SessionDeviceName, SessionDevices,
-        SessionStatus,
+        SessionReconnectToken, SessionStatus,
     };
     use session_host_model::{play::SessionAcceptResponse, SessionHostEvent};
 
@@ -65,6 +65,7 @@ mod tests {
                         )]),
                     },
                     player_controllers: player_controllers.clone(),
+                    session_reconnect_token: SessionReconnectToken::default(),
                 })),
             },
             ExpectedParams {
@@ -107,6 +108,7 @@ mod tests {
                         PlayerController::new(1, String::from("p1")),
                         PlayerController::new(2, String::from("p2")),
                     ]),
+                    session_reconnect_token: SessionReconnectToken::default(),
                 })),
             },
             ExpectedParams {
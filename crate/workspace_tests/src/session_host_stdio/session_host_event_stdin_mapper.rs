@@ -3,6 +3,7 @@ mod tests {
     use game_input_model::loaded::{PlayerController, PlayerControllers};
     use network_session_model::play::{
         Session, SessionCode, SessionDevice, SessionDeviceId, SessionDeviceName, SessionDevices,
+        SessionReconnectToken,
     };
     use session_host_model::{
         play::{SessionAcceptResponse, SessionHostRequestParams},
@@ -20,6 +21,7 @@ mod tests {
         let args = SessionHostEvent::SessionHostRequest(SessionHostRequestParams {
             session_device_name,
             player_controllers,
+            session_public: true,
         });
 
         let result = SessionHostEventStdinMapper::map(&(), args.clone());
@@ -80,6 +82,7 @@ mod tests {
                 session_devices,
             },
             player_controllers,
+            session_reconnect_token: SessionReconnectToken::default(),
         });
 
         let result = SessionHostEventStdinMapper::map(&(), args.clone());
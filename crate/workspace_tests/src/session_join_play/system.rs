@@ -1,2 +1,3 @@
 mod session_join_request_system;
 mod session_join_response_system;
+mod session_reconnect_system;
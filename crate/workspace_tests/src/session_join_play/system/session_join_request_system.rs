@@ -9,7 +9,10 @@ mod tests {
     use game_input_model::loaded::{PlayerController, PlayerControllers};
     use net_model::play::NetMessageEvent;
     use network_session_model::play::{SessionCode, SessionDeviceName, SessionStatus};
-    use session_join_model::{play::SessionJoinRequestParams, SessionJoinEvent};
+    use session_join_model::{
+        play::{SessionJoinRequestParams, SessionSpectateRequestParams},
+        SessionJoinEvent,
+    };
 
     use session_join_play::SessionJoinRequestSystemDesc;
 
@@ -52,6 +55,28 @@ mod tests {
         )
     }
 
+    #[test]
+    fn sends_session_spectate_request() -> Result<(), Error> {
+        let session_join_event =
+            SessionJoinEvent::SessionSpectateRequest(SessionSpectateRequestParams {
+                session_code: SessionCode::new(String::from("abcd")),
+                session_device_name: SessionDeviceName::new(String::from("byron")),
+            });
+
+        run_test(
+            SetupParams {
+                session_status: SessionStatus::None,
+                session_join_event: Some(session_join_event.clone()),
+            },
+            ExpectedParams {
+                session_status: SessionStatus::JoinRequested {
+                    session_code: SessionCode::new(String::from("abcd")),
+                },
+                net_message_event: Some(NetMessageEvent::SessionJoinEvent(session_join_event)),
+            },
+        )
+    }
+
     #[test]
     fn ignores_session_join_request_when_already_requested() -> Result<(), Error> {
         run_test(
@@ -14,9 +14,12 @@ mod tests {
     use net_model::play::{NetData, NetEventChannel};
     use network_session_model::play::{
         Session, SessionCode, SessionDevice, SessionDeviceId, SessionDeviceName, SessionDevices,
-        SessionStatus,
+        SessionReconnectToken, SessionStatus,
+    };
+    use session_join_model::{
+        play::{SessionAcceptResponse, SessionJoinError, SessionRejectResponse},
+        SessionJoinEvent,
     };
-    use session_join_model::{play::SessionAcceptResponse, SessionJoinEvent};
 
     use session_join_play::SessionJoinResponseSystemDesc;
 
@@ -72,6 +75,7 @@ mod tests {
                     },
                     player_controllers: player_controllers.clone(),
                     controller_id_offset: ControllerIdOffset::new(3),
+                    session_reconnect_token: SessionReconnectToken::default(),
                 })),
             },
             ExpectedParams {
@@ -89,6 +93,34 @@ mod tests {
         )
     }
 
+    #[test]
+    fn resets_session_status_on_session_rejected() -> Result<(), Error> {
+        run_test(
+            SetupParams {
+                session_code: SessionCode::new(String::from("abcd")),
+                session_device_id: SessionDeviceId::new(123),
+                session_devices: SessionDevices::new(vec![]),
+                session_status: SessionStatus::JoinRequested {
+                    session_code: SessionCode::new(String::from("defg")),
+                },
+                session_join_event: Some(SessionJoinEvent::SessionReject(
+                    SessionRejectResponse::new(
+                        SessionCode::new(String::from("defg")),
+                        SessionJoinError::SessionFull,
+                    ),
+                )),
+            },
+            ExpectedParams {
+                session_code: SessionCode::new(String::from("abcd")),
+                session_device_id: SessionDeviceId::new(123),
+                session_devices: SessionDevices::new(vec![]),
+                session_status: SessionStatus::None,
+                player_controllers: PlayerControllers::default(),
+                controller_id_offset: ControllerIdOffset::default(),
+            },
+        )
+    }
+
     #[test]
     fn ignores_session_accept_event_when_no_longer_waiting() -> Result<(), Error> {
         let player_controllers = PlayerControllers::new(vec![
@@ -118,6 +150,7 @@ mod tests {
                     },
                     player_controllers,
                     controller_id_offset: ControllerIdOffset::new(3),
+                    session_reconnect_token: SessionReconnectToken::default(),
                 })),
             },
             ExpectedParams {
@@ -0,0 +1,245 @@
+#[cfg(test)]
+mod tests {
+    use std::net::{IpAddr, Ipv4Addr, SocketAddr};
+
+    use amethyst::{
+        ecs::{Read, SystemData, World, WorldExt, WriteExpect},
+        network::simulation::NetworkSimulationEvent,
+        shrev::{EventChannel, ReaderId},
+        Error,
+    };
+    use amethyst_test::AmethystApplication;
+    use game_input_model::{
+        loaded::{PlayerController, PlayerControllers},
+        play::ControllerIdOffset,
+    };
+    use net_model::play::{NetData, NetEventChannel, NetMessageEvent};
+    use network_session_model::{
+        config::SessionServerConfig,
+        play::{
+            Session, SessionCode, SessionDevice, SessionDeviceId, SessionDeviceName,
+            SessionDevices, SessionReconnectToken, SessionStatus, SessionTickCount,
+        },
+    };
+    use rollback_model::play::SessionInputHistory;
+    use session_join_model::{
+        play::{
+            SessionJoinError, SessionReconnectAcceptResponse, SessionReconnectRequestParams,
+            SessionRejectResponse,
+        },
+        SessionJoinEvent,
+    };
+
+    use session_join_play::SessionReconnectSystemDesc;
+
+    const SERVER_PORT: u16 = 1234;
+
+    #[test]
+    fn sends_reconnect_request_when_server_disconnects() -> Result<(), Error> {
+        run_test(
+            SetupParams {
+                session_status: SessionStatus::JoinEstablished,
+                network_simulation_event: Some(NetworkSimulationEvent::Disconnect(server_addr())),
+                session_join_event: None,
+            },
+            ExpectedParams {
+                session_status: SessionStatus::JoinEstablished,
+                session_devices: SessionDevices::new(vec![]),
+                net_message_event: Some(session_reconnect_request()),
+            },
+        )
+    }
+
+    #[test]
+    fn does_nothing_when_session_not_established() -> Result<(), Error> {
+        run_test(
+            SetupParams {
+                session_status: SessionStatus::None,
+                network_simulation_event: Some(NetworkSimulationEvent::Disconnect(server_addr())),
+                session_join_event: None,
+            },
+            ExpectedParams {
+                session_status: SessionStatus::None,
+                session_devices: SessionDevices::new(vec![]),
+                net_message_event: None,
+            },
+        )
+    }
+
+    #[test]
+    fn does_nothing_when_other_address_disconnects() -> Result<(), Error> {
+        run_test(
+            SetupParams {
+                session_status: SessionStatus::HostEstablished,
+                network_simulation_event: Some(NetworkSimulationEvent::Disconnect(
+                    SocketAddr::from((Ipv4Addr::LOCALHOST, SERVER_PORT + 1)),
+                )),
+                session_join_event: None,
+            },
+            ExpectedParams {
+                session_status: SessionStatus::HostEstablished,
+                session_devices: SessionDevices::new(vec![]),
+                net_message_event: None,
+            },
+        )
+    }
+
+    #[test]
+    fn inserts_resources_on_session_reconnect_accepted() -> Result<(), Error> {
+        let session_device = SessionDevice::new(
+            SessionDeviceId::new(1),
+            SessionDeviceName::new(String::from("azriel")),
+            PlayerControllers::new(vec![PlayerController::new(0, String::from("p0"))]),
+        );
+
+        run_test(
+            SetupParams {
+                session_status: SessionStatus::JoinEstablished,
+                network_simulation_event: Some(NetworkSimulationEvent::Disconnect(server_addr())),
+                session_join_event: Some(SessionJoinEvent::SessionReconnectAccept(
+                    SessionReconnectAcceptResponse {
+                        session: Session {
+                            session_code: SessionCode::new(String::from("abcd")),
+                            session_devices: SessionDevices::new(vec![session_device.clone()]),
+                        },
+                        session_device_id: SessionDeviceId::new(1),
+                        player_controllers: PlayerControllers::new(vec![PlayerController::new(
+                            0,
+                            String::from("p0"),
+                        )]),
+                        controller_id_offset: ControllerIdOffset::new(0),
+                        session_input_history: SessionInputHistory::default(),
+                    },
+                )),
+            },
+            ExpectedParams {
+                session_status: SessionStatus::JoinEstablished,
+                session_devices: SessionDevices::new(vec![session_device]),
+                net_message_event: Some(session_reconnect_request()),
+            },
+        )
+    }
+
+    #[test]
+    fn resets_session_status_on_session_reconnect_rejected() -> Result<(), Error> {
+        run_test(
+            SetupParams {
+                session_status: SessionStatus::JoinEstablished,
+                network_simulation_event: Some(NetworkSimulationEvent::Disconnect(server_addr())),
+                session_join_event: Some(SessionJoinEvent::SessionReject(
+                    SessionRejectResponse::new(
+                        SessionCode::new(String::from("abcd")),
+                        SessionJoinError::ReconnectTokenInvalid,
+                    ),
+                )),
+            },
+            ExpectedParams {
+                session_status: SessionStatus::None,
+                session_devices: SessionDevices::new(vec![]),
+                net_message_event: Some(session_reconnect_request()),
+            },
+        )
+    }
+
+    fn run_test(
+        SetupParams {
+            session_status: session_status_setup,
+            network_simulation_event,
+            session_join_event,
+        }: SetupParams,
+        ExpectedParams {
+            session_status: session_status_expected,
+            session_devices: session_devices_expected,
+            net_message_event: net_message_event_expected,
+        }: ExpectedParams,
+    ) -> Result<(), Error> {
+        AmethystApplication::blank()
+            .with_setup(<Read<'_, EventChannel<NetMessageEvent>> as SystemData>::setup)
+            .with_setup(setup_net_message_event_reader)
+            .with_resource(SessionServerConfig {
+                address: IpAddr::V4(Ipv4Addr::LOCALHOST),
+                port: SERVER_PORT,
+            })
+            .with_system_desc(SessionReconnectSystemDesc::default(), "", &[])
+            .with_setup(move |world| {
+                world.insert(SessionCode::new(String::from("abcd")));
+                world.insert(SessionDeviceId::new(1));
+                world.insert(SessionReconnectToken::new(123));
+                world.insert(SessionTickCount::new(10));
+                world.insert(session_status_setup);
+            })
+            .with_effect(move |world| {
+                if let Some(network_simulation_event) = network_simulation_event {
+                    world
+                        .write_resource::<EventChannel<NetworkSimulationEvent>>()
+                        .single_write(network_simulation_event);
+                }
+            })
+            .with_effect(move |world| {
+                if let Some(session_join_event) = session_join_event {
+                    world
+                        .write_resource::<NetEventChannel<SessionJoinEvent>>()
+                        .single_write(NetData {
+                            socket_addr: server_addr(),
+                            data: session_join_event,
+                        });
+                }
+            })
+            .with_assertion(move |world| {
+                let (session_status, session_devices, mut net_message_event_rid, net_message_ec) =
+                    world.system_data::<(
+                        Read<'_, SessionStatus>,
+                        Read<'_, SessionDevices>,
+                        WriteExpect<'_, ReaderId<NetMessageEvent>>,
+                        Read<'_, EventChannel<NetMessageEvent>>,
+                    )>();
+                let session_status = &*session_status;
+                let session_devices = &*session_devices;
+                let net_message_event = net_message_ec.read(&mut *net_message_event_rid).next();
+
+                assert_eq!(
+                    (
+                        &session_status_expected,
+                        &session_devices_expected,
+                        net_message_event_expected.as_ref()
+                    ),
+                    (session_status, session_devices, net_message_event)
+                );
+            })
+            .run()
+    }
+
+    fn setup_net_message_event_reader(world: &mut World) {
+        let net_message_event_rid = world
+            .write_resource::<EventChannel<NetMessageEvent>>()
+            .register_reader();
+        world.insert(net_message_event_rid);
+    }
+
+    fn server_addr() -> SocketAddr {
+        SocketAddr::from((Ipv4Addr::LOCALHOST, SERVER_PORT))
+    }
+
+    fn session_reconnect_request() -> NetMessageEvent {
+        NetMessageEvent::SessionJoinEvent(SessionJoinEvent::SessionReconnectRequest(
+            SessionReconnectRequestParams {
+                session_code: SessionCode::new(String::from("abcd")),
+                session_device_id: SessionDeviceId::new(1),
+                session_reconnect_token: SessionReconnectToken::new(123),
+                session_tick_count: SessionTickCount::new(10),
+            },
+        ))
+    }
+
+    struct SetupParams {
+        session_status: SessionStatus,
+        network_simulation_event: Option<NetworkSimulationEvent>,
+        session_join_event: Option<SessionJoinEvent>,
+    }
+
+    struct ExpectedParams {
+        session_status: SessionStatus,
+        session_devices: SessionDevices,
+        net_message_event: Option<NetMessageEvent>,
+    }
+}
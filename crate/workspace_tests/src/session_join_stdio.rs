@@ -1,2 +1,3 @@
 mod session_join_event_stdin_mapper;
+mod session_join_status_log_system;
 mod session_join_stdio_bundle;
@@ -6,6 +6,7 @@ mod tests {
     };
     use network_session_model::play::{
         Session, SessionCode, SessionDevice, SessionDeviceId, SessionDeviceName, SessionDevices,
+        SessionReconnectToken,
     };
     use session_join_model::{
         play::{SessionAcceptResponse, SessionJoinRequestParams},
@@ -87,6 +88,7 @@ mod tests {
             },
             player_controllers,
             controller_id_offset,
+            session_reconnect_token: SessionReconnectToken::default(),
         });
 
         let result = SessionJoinEventStdinMapper::map(&(), args.clone());
@@ -0,0 +1,33 @@
+#[cfg(test)]
+mod tests {
+    use network_session_model::play::SessionCode;
+    use session_join_model::{
+        play::{SessionJoinError, SessionRejectResponse},
+        SessionJoinEvent,
+    };
+
+    use session_join_stdio::SessionJoinStatusLogSystem;
+
+    #[test]
+    fn status_message_includes_session_reject_reason() {
+        let session_join_event = SessionJoinEvent::SessionReject(SessionRejectResponse::new(
+            SessionCode::from(String::from("abcd")),
+            SessionJoinError::SessionFull,
+        ));
+
+        assert_eq!(
+            Some(String::from(
+                "Failed to join session `abcd`: Session is full."
+            )),
+            SessionJoinStatusLogSystem::status_message(&session_join_event)
+        );
+    }
+
+    #[test]
+    fn status_message_is_none_for_local_events() {
+        assert_eq!(
+            None,
+            SessionJoinStatusLogSystem::status_message(&SessionJoinEvent::JoinCancel)
+        );
+    }
+}
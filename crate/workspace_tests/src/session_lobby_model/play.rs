@@ -0,0 +1,2 @@
+mod session_character_selections;
+mod session_device_readies;
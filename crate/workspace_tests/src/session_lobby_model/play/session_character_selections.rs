@@ -0,0 +1,39 @@
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use asset_model::config::AssetSlug;
+
+    use session_lobby_model::play::{
+        SessionCharacterSelection, SessionCharacterSelections, SessionLobbyModelError,
+    };
+
+    #[test]
+    fn parses_space_separated_character_selections() {
+        let session_character_selections =
+            SessionCharacterSelections::from_str("0:default/bat 1:default/spinner");
+
+        assert_eq!(
+            Ok(SessionCharacterSelections::new(vec![
+                SessionCharacterSelection::new(0, asset_slug("default/bat")),
+                SessionCharacterSelection::new(1, asset_slug("default/spinner")),
+            ])),
+            session_character_selections
+        );
+    }
+
+    #[test]
+    fn returns_error_when_character_selection_is_invalid() {
+        let session_character_selections =
+            SessionCharacterSelections::from_str("0:default/bat bat");
+
+        assert_eq!(
+            Err(SessionLobbyModelError::SessionCharacterSelectionsParseError),
+            session_character_selections
+        );
+    }
+
+    fn asset_slug(asset_slug_str: &str) -> AssetSlug {
+        AssetSlug::from_str(asset_slug_str).expect("Expected asset slug to be valid.")
+    }
+}
@@ -0,0 +1,64 @@
+#[cfg(test)]
+mod tests {
+    use game_input_model::loaded::{PlayerController, PlayerControllers};
+    use network_session_model::play::{
+        SessionDevice, SessionDeviceId, SessionDeviceName, SessionDevices,
+    };
+
+    use session_lobby_model::play::SessionDeviceReadies;
+
+    #[test]
+    fn all_ready_returns_false_when_no_devices() {
+        let session_device_readies = SessionDeviceReadies::default();
+
+        assert!(!session_device_readies.all_ready(&SessionDevices::default()));
+    }
+
+    #[test]
+    fn all_ready_returns_false_when_a_device_is_not_ready() {
+        let mut session_device_readies = SessionDeviceReadies::default();
+        session_device_readies.set(SessionDeviceId::new(0), true);
+
+        assert!(!session_device_readies.all_ready(&session_devices()));
+    }
+
+    #[test]
+    fn all_ready_returns_true_when_every_player_device_is_ready() {
+        let mut session_device_readies = SessionDeviceReadies::default();
+        session_device_readies.set(SessionDeviceId::new(0), true);
+        session_device_readies.set(SessionDeviceId::new(1), true);
+
+        // Spectator does not need to be ready.
+        assert!(session_device_readies.all_ready(&session_devices()));
+    }
+
+    #[test]
+    fn set_false_removes_ready_flag() {
+        let mut session_device_readies = SessionDeviceReadies::default();
+        session_device_readies.set(SessionDeviceId::new(0), true);
+        session_device_readies.set(SessionDeviceId::new(1), true);
+        session_device_readies.set(SessionDeviceId::new(1), false);
+
+        assert!(!session_device_readies.all_ready(&session_devices()));
+    }
+
+    fn session_devices() -> SessionDevices {
+        SessionDevices::new(vec![
+            SessionDevice::new(
+                SessionDeviceId::new(0),
+                SessionDeviceName::from(String::from("azriel")),
+                PlayerControllers::new(vec![PlayerController::new(0, String::from("p0"))]),
+            ),
+            SessionDevice::new(
+                SessionDeviceId::new(1),
+                SessionDeviceName::from(String::from("byron")),
+                PlayerControllers::new(vec![PlayerController::new(1, String::from("p1"))]),
+            ),
+            SessionDevice::new(
+                SessionDeviceId::new(2),
+                SessionDeviceName::from(String::from("spectator")),
+                PlayerControllers::default(),
+            ),
+        ])
+    }
+}
@@ -1,2 +1,3 @@
 mod session_lobby_request_system;
 mod session_lobby_response_system;
+mod session_selection_request_system;
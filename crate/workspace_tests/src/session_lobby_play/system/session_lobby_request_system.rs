@@ -1,14 +1,27 @@
 #[cfg(test)]
 mod tests {
+    use std::str::FromStr;
+
     use amethyst::{
         ecs::{Read, SystemData, World, WorldExt, WriteExpect},
         shrev::{EventChannel, ReaderId},
         Error,
     };
     use amethyst_test::AmethystApplication;
+    use asset_model::config::AssetSlug;
+    use game_input_model::loaded::{PlayerController, PlayerControllers};
     use net_model::play::NetMessageEvent;
-    use network_session_model::play::{SessionCode, SessionStatus};
-    use session_lobby_model::{play::SessionStartRequestParams, SessionLobbyEvent};
+    use network_session_model::play::{
+        SessionCode, SessionDevice, SessionDeviceId, SessionDeviceName, SessionDevices,
+        SessionStatus,
+    };
+    use session_lobby_model::{
+        play::{
+            SessionChatRequestParams, SessionDeviceReadies, SessionMapSelectionParams,
+            SessionReadyRequestParams, SessionStartRequestParams,
+        },
+        SessionLobbyEvent,
+    };
 
     use session_lobby_play::SessionLobbyRequestSystemDesc;
 
@@ -18,6 +31,7 @@ mod tests {
             SetupParams {
                 session_status: SessionStatus::HostEstablished,
                 session_lobby_event: None,
+                session_device_readies: SessionDeviceReadies::default(),
             },
             ExpectedParams {
                 session_status: SessionStatus::HostEstablished,
@@ -38,6 +52,7 @@ mod tests {
             SetupParams {
                 session_status: SessionStatus::HostEstablished,
                 session_lobby_event: Some(session_lobby_event.clone()),
+                session_device_readies: session_device_readies_all(),
             },
             ExpectedParams {
                 session_status: SessionStatus::HostEstablished,
@@ -47,17 +62,58 @@ mod tests {
     }
 
     #[test]
-    fn sends_net_message_event_on_session_start_request_when_join_established() -> Result<(), Error>
-    {
+    fn does_not_send_session_start_request_when_join_established() -> Result<(), Error> {
+        let session_lobby_event =
+            SessionLobbyEvent::SessionStartRequest(SessionStartRequestParams {
+                session_code: SessionCode::new(String::from("abcd")),
+            });
+
+        run_test(
+            SetupParams {
+                session_status: SessionStatus::JoinEstablished,
+                session_lobby_event: Some(session_lobby_event),
+                session_device_readies: session_device_readies_all(),
+            },
+            ExpectedParams {
+                session_status: SessionStatus::JoinEstablished,
+                net_message_event: None,
+            },
+        )
+    }
+
+    #[test]
+    fn does_not_send_session_start_request_when_not_all_devices_ready() -> Result<(), Error> {
         let session_lobby_event =
             SessionLobbyEvent::SessionStartRequest(SessionStartRequestParams {
                 session_code: SessionCode::new(String::from("abcd")),
             });
+        let mut session_device_readies = SessionDeviceReadies::default();
+        session_device_readies.set(SessionDeviceId::new(0), true);
+
+        run_test(
+            SetupParams {
+                session_status: SessionStatus::HostEstablished,
+                session_lobby_event: Some(session_lobby_event),
+                session_device_readies,
+            },
+            ExpectedParams {
+                session_status: SessionStatus::HostEstablished,
+                net_message_event: None,
+            },
+        )
+    }
+
+    #[test]
+    fn sends_net_message_event_on_session_chat_request() -> Result<(), Error> {
+        let session_lobby_event = SessionLobbyEvent::SessionChatRequest(SessionChatRequestParams {
+            message: String::from("I'll be Bat"),
+        });
 
         run_test(
             SetupParams {
                 session_status: SessionStatus::JoinEstablished,
                 session_lobby_event: Some(session_lobby_event.clone()),
+                session_device_readies: session_device_readies_all(),
             },
             ExpectedParams {
                 session_status: SessionStatus::JoinEstablished,
@@ -66,6 +122,45 @@ mod tests {
         )
     }
 
+    #[test]
+    fn sends_net_message_event_on_session_ready_request() -> Result<(), Error> {
+        let session_lobby_event =
+            SessionLobbyEvent::SessionReadyRequest(SessionReadyRequestParams { ready: true });
+
+        run_test(
+            SetupParams {
+                session_status: SessionStatus::JoinEstablished,
+                session_lobby_event: Some(session_lobby_event.clone()),
+                session_device_readies: SessionDeviceReadies::default(),
+            },
+            ExpectedParams {
+                session_status: SessionStatus::JoinEstablished,
+                net_message_event: Some(NetMessageEvent::SessionLobbyEvent(session_lobby_event)),
+            },
+        )
+    }
+
+    #[test]
+    fn does_not_send_session_map_select_request_when_join_established() -> Result<(), Error> {
+        let session_lobby_event =
+            SessionLobbyEvent::SessionMapSelectRequest(SessionMapSelectionParams {
+                map_slug: AssetSlug::from_str("default/eruption")
+                    .expect("Expected asset slug to be valid."),
+            });
+
+        run_test(
+            SetupParams {
+                session_status: SessionStatus::JoinEstablished,
+                session_lobby_event: Some(session_lobby_event),
+                session_device_readies: session_device_readies_all(),
+            },
+            ExpectedParams {
+                session_status: SessionStatus::JoinEstablished,
+                net_message_event: None,
+            },
+        )
+    }
+
     #[test]
     fn ignores_session_lobby_request_when_session_not_established() -> Result<(), Error> {
         run_test(
@@ -76,6 +171,7 @@ mod tests {
                         session_code: SessionCode::new(String::from("abcd")),
                     },
                 )),
+                session_device_readies: session_device_readies_all(),
             },
             ExpectedParams {
                 session_status: SessionStatus::None,
@@ -88,6 +184,7 @@ mod tests {
         SetupParams {
             session_status: session_status_setup,
             session_lobby_event,
+            session_device_readies,
         }: SetupParams,
         ExpectedParams {
             session_status: session_status_expected,
@@ -99,6 +196,8 @@ mod tests {
             .with_setup(setup_net_message_event_reader)
             .with_system_desc(SessionLobbyRequestSystemDesc::default(), "", &[])
             .with_resource(session_status_setup)
+            .with_resource(session_devices())
+            .with_resource(session_device_readies)
             .with_effect(move |world| {
                 if let Some(session_lobby_event) = session_lobby_event {
                     world
@@ -134,9 +233,32 @@ mod tests {
         world.insert(net_message_event_rid);
     }
 
+    fn session_devices() -> SessionDevices {
+        SessionDevices::new(vec![
+            SessionDevice::new(
+                SessionDeviceId::new(0),
+                SessionDeviceName::from(String::from("azriel")),
+                PlayerControllers::new(vec![PlayerController::new(0, String::from("p0"))]),
+            ),
+            SessionDevice::new(
+                SessionDeviceId::new(1),
+                SessionDeviceName::from(String::from("byron")),
+                PlayerControllers::new(vec![PlayerController::new(1, String::from("p1"))]),
+            ),
+        ])
+    }
+
+    fn session_device_readies_all() -> SessionDeviceReadies {
+        let mut session_device_readies = SessionDeviceReadies::default();
+        session_device_readies.set(SessionDeviceId::new(0), true);
+        session_device_readies.set(SessionDeviceId::new(1), true);
+        session_device_readies
+    }
+
     struct SetupParams {
         session_status: SessionStatus,
         session_lobby_event: Option<SessionLobbyEvent>,
+        session_device_readies: SessionDeviceReadies,
     }
 
     struct ExpectedParams {
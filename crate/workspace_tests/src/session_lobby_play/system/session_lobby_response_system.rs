@@ -1,6 +1,9 @@
 #[cfg(test)]
 mod tests {
-    use std::net::{Ipv4Addr, SocketAddr};
+    use std::{
+        net::{Ipv4Addr, SocketAddr},
+        str::FromStr,
+    };
 
     use amethyst::{
         ecs::{Read, SystemData, World, WorldExt, WriteExpect},
@@ -8,9 +11,19 @@ mod tests {
         Error,
     };
     use amethyst_test::AmethystApplication;
+    use asset_model::{config::AssetSlug, loaded::AssetIdMappings};
+    use character_selection_model::CharacterSelections;
+    use map_selection_model::MapSelection;
     use net_model::play::{NetData, NetEventChannel};
-    use network_session_model::play::SessionStatus;
-    use session_lobby_model::SessionLobbyEvent;
+    use network_session_model::play::{SessionDeviceId, SessionDeviceName, SessionStatus};
+    use session_lobby_model::{
+        play::{
+            SessionCharacterSelection, SessionCharacterSelectionParams, SessionCharacterSelections,
+            SessionChatLog, SessionChatMessage, SessionDeviceReadies, SessionDeviceReady,
+            SessionMapSelectionParams,
+        },
+        SessionLobbyEvent,
+    };
 
     use session_lobby_play::SessionLobbyResponseSystemDesc;
 
@@ -23,6 +36,8 @@ mod tests {
             },
             ExpectedParams {
                 session_lobby_event: None,
+                session_chat_log: SessionChatLog::default(),
+                session_device_readies: SessionDeviceReadies::default(),
             },
         )
     }
@@ -36,10 +51,100 @@ mod tests {
             },
             ExpectedParams {
                 session_lobby_event: Some(SessionLobbyEvent::SessionStartNotify),
+                session_chat_log: SessionChatLog::default(),
+                session_device_readies: SessionDeviceReadies::default(),
+            },
+        )
+    }
+
+    #[test]
+    fn records_chat_message_on_session_chat_notify() -> Result<(), Error> {
+        let session_chat_message = SessionChatMessage {
+            session_device_name: SessionDeviceName::new(String::from("azriel")),
+            message: String::from("I'll be Bat"),
+        };
+
+        run_test(
+            SetupParams {
+                session_status: SessionStatus::JoinEstablished,
+                session_lobby_event: Some(SessionLobbyEvent::SessionChatNotify(
+                    session_chat_message.clone(),
+                )),
+            },
+            ExpectedParams {
+                session_lobby_event: Some(SessionLobbyEvent::SessionChatNotify(
+                    session_chat_message.clone(),
+                )),
+                session_chat_log: SessionChatLog::new(vec![session_chat_message]),
+                session_device_readies: SessionDeviceReadies::default(),
+            },
+        )
+    }
+
+    #[test]
+    fn records_ready_flag_on_session_ready_notify() -> Result<(), Error> {
+        let session_device_ready = SessionDeviceReady::new(SessionDeviceId::new(1), true);
+        let mut session_device_readies = SessionDeviceReadies::default();
+        session_device_readies.set(SessionDeviceId::new(1), true);
+
+        run_test(
+            SetupParams {
+                session_status: SessionStatus::JoinEstablished,
+                session_lobby_event: Some(SessionLobbyEvent::SessionReadyNotify(
+                    session_device_ready,
+                )),
+            },
+            ExpectedParams {
+                session_lobby_event: Some(SessionLobbyEvent::SessionReadyNotify(
+                    session_device_ready,
+                )),
+                session_chat_log: SessionChatLog::default(),
+                session_device_readies,
             },
         )
     }
 
+    #[test]
+    fn writes_character_and_map_selections_on_session_select_notify() -> Result<(), Error> {
+        let bat_slug = AssetSlug::from_str("default/bat").expect("Expected slug to be valid.");
+        let map_slug = AssetSlug::from_str("default/eruption").expect("Expected slug to be valid.");
+        let mut asset_id_mappings = AssetIdMappings::new();
+        let bat_id = asset_id_mappings.insert(bat_slug.clone());
+        let map_id = asset_id_mappings.insert(map_slug.clone());
+
+        AmethystApplication::blank()
+            .with_system_desc(SessionLobbyResponseSystemDesc::default(), "", &[])
+            .with_resource(SessionStatus::JoinEstablished)
+            .with_resource(asset_id_mappings)
+            .with_effect(move |world| {
+                let socket_addr = SocketAddr::from((Ipv4Addr::LOCALHOST, 1234));
+                let mut session_lobby_nec =
+                    world.write_resource::<NetEventChannel<SessionLobbyEvent>>();
+                session_lobby_nec.single_write(NetData {
+                    socket_addr,
+                    data: SessionLobbyEvent::SessionCharacterSelectNotify(
+                        SessionCharacterSelectionParams::new(SessionCharacterSelections::new(
+                            vec![SessionCharacterSelection::new(1, bat_slug)],
+                        )),
+                    ),
+                });
+                session_lobby_nec.single_write(NetData {
+                    socket_addr,
+                    data: SessionLobbyEvent::SessionMapSelectNotify(
+                        SessionMapSelectionParams::new(map_slug),
+                    ),
+                });
+            })
+            .with_assertion(move |world| {
+                let character_selections = world.read_resource::<CharacterSelections>();
+                let map_selection = world.read_resource::<MapSelection>();
+
+                assert_eq!(Some(&bat_id), character_selections.selections.get(&1));
+                assert_eq!(MapSelection::Id(map_id), *map_selection);
+            })
+            .run()
+    }
+
     fn run_test(
         SetupParams {
             session_status: session_status_setup,
@@ -47,6 +152,8 @@ mod tests {
         }: SetupParams,
         ExpectedParams {
             session_lobby_event: session_lobby_event_expected,
+            session_chat_log: session_chat_log_expected,
+            session_device_readies: session_device_readies_expected,
         }: ExpectedParams,
     ) -> Result<(), Error> {
         AmethystApplication::blank()
@@ -66,14 +173,32 @@ mod tests {
                 }
             })
             .with_assertion(move |world| {
-                let (mut session_lobby_event_rid, session_lobby_ec) = world.system_data::<(
+                let (
+                    mut session_lobby_event_rid,
+                    session_lobby_ec,
+                    session_chat_log,
+                    session_device_readies,
+                ) = world.system_data::<(
                     WriteExpect<'_, ReaderId<SessionLobbyEvent>>,
                     Read<'_, EventChannel<SessionLobbyEvent>>,
+                    Read<'_, SessionChatLog>,
+                    Read<'_, SessionDeviceReadies>,
                 )>();
                 let session_lobby_event =
                     session_lobby_ec.read(&mut *session_lobby_event_rid).next();
 
-                assert_eq!(session_lobby_event_expected.as_ref(), session_lobby_event);
+                assert_eq!(
+                    (
+                        session_lobby_event_expected.as_ref(),
+                        &session_chat_log_expected,
+                        &session_device_readies_expected
+                    ),
+                    (
+                        session_lobby_event,
+                        &*session_chat_log,
+                        &*session_device_readies
+                    )
+                );
             })
             .run()
     }
@@ -92,5 +217,7 @@ mod tests {
 
     struct ExpectedParams {
         session_lobby_event: Option<SessionLobbyEvent>,
+        session_chat_log: SessionChatLog,
+        session_device_readies: SessionDeviceReadies,
     }
 }
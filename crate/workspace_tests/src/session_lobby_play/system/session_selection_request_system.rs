@@ -0,0 +1,146 @@
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use amethyst::{
+        ecs::{Read, SystemData, World, WorldExt, WriteExpect},
+        shrev::{EventChannel, ReaderId},
+        Error,
+    };
+    use amethyst_test::AmethystApplication;
+    use asset_model::{config::AssetSlug, loaded::AssetIdMappings};
+    use asset_selection_model::play::AssetSelectionEvent;
+    use character_selection_model::CharacterSelections;
+    use map_selection_model::MapSelection;
+    use network_session_model::play::SessionStatus;
+    use session_lobby_model::{
+        play::{
+            SessionCharacterSelection, SessionCharacterSelectionParams, SessionCharacterSelections,
+            SessionMapSelectionParams,
+        },
+        SessionLobbyEvent,
+    };
+    use state_registry::StateId;
+
+    use session_lobby_play::SessionSelectionRequestSystemDesc;
+
+    #[test]
+    fn sends_character_selections_on_confirm_in_character_selection() -> Result<(), Error> {
+        run_test(
+            SetupParams {
+                session_status: SessionStatus::JoinEstablished,
+                state_id: StateId::CharacterSelection,
+            },
+            ExpectedParams {
+                session_lobby_event: Some(SessionLobbyEvent::SessionCharacterSelectRequest(
+                    SessionCharacterSelectionParams::new(SessionCharacterSelections::new(vec![
+                        SessionCharacterSelection::new(0, asset_slug("default/bat")),
+                    ])),
+                )),
+            },
+        )
+    }
+
+    #[test]
+    fn sends_map_selection_on_confirm_in_map_selection_when_host() -> Result<(), Error> {
+        run_test(
+            SetupParams {
+                session_status: SessionStatus::HostEstablished,
+                state_id: StateId::MapSelection,
+            },
+            ExpectedParams {
+                session_lobby_event: Some(SessionLobbyEvent::SessionMapSelectRequest(
+                    SessionMapSelectionParams::new(asset_slug("default/eruption")),
+                )),
+            },
+        )
+    }
+
+    #[test]
+    fn does_not_send_map_selection_when_not_host() -> Result<(), Error> {
+        run_test(
+            SetupParams {
+                session_status: SessionStatus::JoinEstablished,
+                state_id: StateId::MapSelection,
+            },
+            ExpectedParams {
+                session_lobby_event: None,
+            },
+        )
+    }
+
+    #[test]
+    fn does_not_send_selections_when_session_not_established() -> Result<(), Error> {
+        run_test(
+            SetupParams {
+                session_status: SessionStatus::None,
+                state_id: StateId::CharacterSelection,
+            },
+            ExpectedParams {
+                session_lobby_event: None,
+            },
+        )
+    }
+
+    fn run_test(
+        SetupParams {
+            session_status,
+            state_id,
+        }: SetupParams,
+        ExpectedParams {
+            session_lobby_event: session_lobby_event_expected,
+        }: ExpectedParams,
+    ) -> Result<(), Error> {
+        let mut asset_id_mappings = AssetIdMappings::new();
+        let bat_id = asset_id_mappings.insert(asset_slug("default/bat"));
+        let map_id = asset_id_mappings.insert(asset_slug("default/eruption"));
+        let mut character_selections = CharacterSelections::default();
+        character_selections.selections.insert(0, bat_id);
+
+        AmethystApplication::blank()
+            .with_setup(<Read<'_, EventChannel<SessionLobbyEvent>> as SystemData>::setup)
+            .with_setup(setup_session_lobby_event_reader)
+            .with_system_desc(SessionSelectionRequestSystemDesc::default(), "", &[])
+            .with_resource(session_status)
+            .with_resource(state_id)
+            .with_resource(asset_id_mappings)
+            .with_resource(character_selections)
+            .with_resource(MapSelection::Id(map_id))
+            .with_effect(|world| {
+                world
+                    .write_resource::<EventChannel<AssetSelectionEvent>>()
+                    .single_write(AssetSelectionEvent::Confirm);
+            })
+            .with_assertion(move |world| {
+                let (mut session_lobby_event_rid, session_lobby_ec) = world.system_data::<(
+                    WriteExpect<'_, ReaderId<SessionLobbyEvent>>,
+                    Read<'_, EventChannel<SessionLobbyEvent>>,
+                )>();
+                let session_lobby_event =
+                    session_lobby_ec.read(&mut *session_lobby_event_rid).next();
+
+                assert_eq!(session_lobby_event_expected.as_ref(), session_lobby_event);
+            })
+            .run()
+    }
+
+    fn setup_session_lobby_event_reader(world: &mut World) {
+        let session_lobby_event_rid = world
+            .write_resource::<EventChannel<SessionLobbyEvent>>()
+            .register_reader();
+        world.insert(session_lobby_event_rid);
+    }
+
+    fn asset_slug(asset_slug_str: &str) -> AssetSlug {
+        AssetSlug::from_str(asset_slug_str).expect("Expected asset slug to be valid.")
+    }
+
+    struct SetupParams {
+        session_status: SessionStatus,
+        state_id: StateId,
+    }
+
+    struct ExpectedParams {
+        session_lobby_event: Option<SessionLobbyEvent>,
+    }
+}
@@ -0,0 +1,3 @@
+mod session_chat_log_system;
+mod session_lobby_event_stdin_mapper;
+mod session_lobby_stdio_bundle;
@@ -0,0 +1,20 @@
+#[cfg(test)]
+mod tests {
+    use network_session_model::play::SessionDeviceName;
+    use session_lobby_model::play::SessionChatMessage;
+
+    use session_lobby_stdio::SessionChatLogSystem;
+
+    #[test]
+    fn chat_line_prefixes_message_with_device_name() {
+        let session_chat_message = SessionChatMessage::new(
+            SessionDeviceName::from(String::from("azriel")),
+            String::from("hello"),
+        );
+
+        assert_eq!(
+            "azriel: hello",
+            SessionChatLogSystem::chat_line(&session_chat_message)
+        );
+    }
+}
@@ -0,0 +1,35 @@
+#[cfg(test)]
+mod tests {
+    use network_session_model::play::SessionCode;
+    use session_lobby_model::{
+        play::{SessionChatRequestParams, SessionStartRequestParams},
+        SessionLobbyEvent,
+    };
+    use stdio_spi::StdinMapper;
+
+    use session_lobby_stdio::SessionLobbyEventStdinMapper;
+
+    #[test]
+    fn maps_session_start_request_event() {
+        let args = SessionLobbyEvent::SessionStartRequest(SessionStartRequestParams {
+            session_code: SessionCode::from(String::from("abcd")),
+        });
+
+        let result = SessionLobbyEventStdinMapper::map(&(), args.clone());
+
+        assert!(result.is_ok());
+        assert_eq!(args, result.unwrap())
+    }
+
+    #[test]
+    fn maps_session_chat_request_event() {
+        let args = SessionLobbyEvent::SessionChatRequest(SessionChatRequestParams {
+            message: String::from("hello"),
+        });
+
+        let result = SessionLobbyEventStdinMapper::map(&(), args.clone());
+
+        assert!(result.is_ok());
+        assert_eq!(args, result.unwrap())
+    }
+}
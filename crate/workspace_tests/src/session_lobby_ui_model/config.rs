@@ -93,6 +93,7 @@ session_devices:
         let session_lobby_ui_expected = SessionLobbyUi {
             session_code,
             session_devices,
+            session_chat_log: None,
         };
 
         assert_eq!(session_lobby_ui_expected, session_lobby_ui);
@@ -0,0 +1,3 @@
+mod simulation_character;
+mod simulation_controller;
+mod simulation_script;
@@ -0,0 +1,56 @@
+#[cfg(test)]
+mod tests {
+    use std::{path::PathBuf, str::FromStr};
+
+    use ai_model::config::AiDifficulty;
+    use asset_model::config::AssetSlug;
+
+    use simulation_model::config::{SimulationArgError, SimulationCharacter, SimulationController};
+
+    #[test]
+    fn from_str_defaults_to_ai_controller() {
+        assert_eq!(
+            Ok(SimulationCharacter::new(
+                slug("default/hero"),
+                SimulationController::Ai(AiDifficulty::default())
+            )),
+            SimulationCharacter::from_str("default/hero")
+        );
+    }
+
+    #[test]
+    fn from_str_parses_controller() {
+        assert_eq!(
+            Ok(SimulationCharacter::new(
+                slug("default/hero"),
+                SimulationController::Ai(AiDifficulty::Hard)
+            )),
+            SimulationCharacter::from_str("default/hero=ai:hard")
+        );
+        assert_eq!(
+            Ok(SimulationCharacter::new(
+                slug("default/rival"),
+                SimulationController::Scripted(PathBuf::from("scripts/rival.yaml"))
+            )),
+            SimulationCharacter::from_str("default/rival=script:scripts/rival.yaml")
+        );
+    }
+
+    #[test]
+    fn from_str_returns_error_when_slug_invalid() {
+        let result = SimulationCharacter::from_str("hero=ai");
+
+        if let Err(SimulationArgError::CharacterInvalid { value, .. }) = result {
+            assert_eq!("hero", value);
+        } else {
+            panic!(
+                "Expected `SimulationArgError::CharacterInvalid`, but was: {:?}",
+                result
+            );
+        }
+    }
+
+    fn slug(slug: &str) -> AssetSlug {
+        AssetSlug::from_str(slug).expect("Expected asset slug to be valid.")
+    }
+}
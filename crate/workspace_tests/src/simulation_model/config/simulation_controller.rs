@@ -0,0 +1,48 @@
+#[cfg(test)]
+mod tests {
+    use std::{path::PathBuf, str::FromStr};
+
+    use ai_model::config::AiDifficulty;
+
+    use simulation_model::config::{SimulationArgError, SimulationController};
+
+    #[test]
+    fn from_str_parses_ai_with_default_difficulty() {
+        assert_eq!(
+            Ok(SimulationController::Ai(AiDifficulty::Normal)),
+            SimulationController::from_str("ai")
+        );
+    }
+
+    #[test]
+    fn from_str_parses_ai_with_difficulty() {
+        assert_eq!(
+            Ok(SimulationController::Ai(AiDifficulty::Easy)),
+            SimulationController::from_str("ai:easy")
+        );
+    }
+
+    #[test]
+    fn from_str_parses_script_path() {
+        assert_eq!(
+            Ok(SimulationController::Scripted(PathBuf::from(
+                "C:/scripts/hero.yaml"
+            ))),
+            SimulationController::from_str("script:C:/scripts/hero.yaml")
+        );
+    }
+
+    #[test]
+    fn from_str_returns_error_when_controller_invalid() {
+        ["", "ai:unbeatable", "script:", "keyboard"]
+            .iter()
+            .for_each(|value| {
+                assert_eq!(
+                    Err(SimulationArgError::ControllerInvalid {
+                        value: value.to_string()
+                    }),
+                    SimulationController::from_str(value)
+                );
+            });
+    }
+}
@@ -0,0 +1,69 @@
+#[cfg(test)]
+mod tests {
+    use game_input_model::config::{Axis, ControlAction};
+
+    use simulation_model::config::{SimulationScript, SimulationScriptEvent, SimulationScriptTick};
+
+    #[test]
+    fn deserializes_list_of_ticks() {
+        let script_yaml = "\
+- tick: 0
+  events:
+    - axis_moved: { axis: \"x\", value: 1.0 }
+- tick: 30
+  events:
+    - control_action_press: { control_action: \"attack\" }
+";
+
+        let simulation_script =
+            serde_yaml::from_str::<SimulationScript>(script_yaml).expect("Failed to deserialize.");
+
+        assert_eq!(
+            SimulationScript {
+                ticks: vec![
+                    SimulationScriptTick::new(
+                        0,
+                        vec![SimulationScriptEvent::AxisMoved {
+                            axis: Axis::X,
+                            value: 1.,
+                        }],
+                    ),
+                    SimulationScriptTick::new(30, vec![attack_press()]),
+                ],
+            },
+            simulation_script
+        );
+    }
+
+    #[test]
+    fn events_returns_events_for_tick() {
+        let simulation_script = SimulationScript {
+            ticks: vec![
+                SimulationScriptTick::new(30, vec![attack_press()]),
+                SimulationScriptTick::new(31, vec![attack_release()]),
+            ],
+        };
+
+        assert_eq!(
+            vec![attack_press()],
+            simulation_script.events(30).collect::<Vec<_>>()
+        );
+        assert_eq!(
+            vec![attack_release()],
+            simulation_script.events(31).collect::<Vec<_>>()
+        );
+        assert!(simulation_script.events(32).next().is_none());
+    }
+
+    fn attack_press() -> SimulationScriptEvent {
+        SimulationScriptEvent::ControlActionPress {
+            control_action: ControlAction::Attack,
+        }
+    }
+
+    fn attack_release() -> SimulationScriptEvent {
+        SimulationScriptEvent::ControlActionRelease {
+            control_action: ControlAction::Attack,
+        }
+    }
+}
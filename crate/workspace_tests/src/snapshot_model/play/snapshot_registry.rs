@@ -0,0 +1,250 @@
+#[cfg(test)]
+mod tests {
+    use amethyst::ecs::{BitSet, Builder, Entity, World, WorldExt};
+    use game_play_model::GamePlayEntity;
+    use item_model::play::HeldItem;
+    use kinematic_model::config::Position;
+    use object_model::play::HealthPoints;
+
+    use snapshot_model::play::{SnapshotError, SnapshotRegistry};
+
+    #[test]
+    fn restores_component_values_captured_in_snapshot() {
+        let snapshot_registry = snapshot_registry();
+        let mut world = setup_world(&snapshot_registry);
+        let entity = world
+            .create_entity()
+            .with(Position::<f32>::new(1., 2., 3.))
+            .with(HealthPoints::new(100))
+            .build();
+
+        let bytes = snapshot_registry
+            .capture(&world)
+            .expect("Failed to capture snapshot.");
+
+        *world
+            .write_storage::<Position<f32>>()
+            .get_mut(entity)
+            .unwrap() = Position::<f32>::new(10., 20., 30.);
+        world.write_storage::<HealthPoints>().remove(entity);
+
+        snapshot_registry
+            .restore(&world, &bytes)
+            .expect("Failed to restore snapshot.");
+        world.maintain();
+
+        assert_eq!(
+            Some(&Position::<f32>::new(1., 2., 3.)),
+            world.read_storage::<Position<f32>>().get(entity)
+        );
+        assert_eq!(
+            Some(&HealthPoints::new(100)),
+            world.read_storage::<HealthPoints>().get(entity)
+        );
+    }
+
+    #[test]
+    fn deletes_entities_created_after_snapshot() {
+        let snapshot_registry = snapshot_registry();
+        let mut world = setup_world(&snapshot_registry);
+        let entity = world.create_entity().with(HealthPoints::new(100)).build();
+
+        let bytes = snapshot_registry
+            .capture(&world)
+            .expect("Failed to capture snapshot.");
+
+        let entity_created = world.create_entity().with(HealthPoints::new(50)).build();
+
+        snapshot_registry
+            .restore(&world, &bytes)
+            .expect("Failed to restore snapshot.");
+        world.maintain();
+
+        assert!(world.is_alive(entity));
+        assert!(!world.is_alive(entity_created));
+    }
+
+    #[test]
+    fn does_not_modify_entities_outside_scope() {
+        let mut snapshot_registry = snapshot_registry();
+        snapshot_registry.scope::<GamePlayEntity>();
+        let mut world = setup_world(&snapshot_registry);
+        let entity_scoped = world
+            .create_entity()
+            .with(GamePlayEntity)
+            .with(HealthPoints::new(100))
+            .build();
+
+        let bytes = snapshot_registry
+            .capture(&world)
+            .expect("Failed to capture snapshot.");
+
+        let entity_unscoped = world.create_entity().with(HealthPoints::new(50)).build();
+        let entity_scoped_created = world
+            .create_entity()
+            .with(GamePlayEntity)
+            .with(HealthPoints::new(50))
+            .build();
+
+        snapshot_registry
+            .restore(&world, &bytes)
+            .expect("Failed to restore snapshot.");
+        world.maintain();
+
+        assert!(world.is_alive(entity_scoped));
+        assert!(world.is_alive(entity_unscoped));
+        assert!(!world.is_alive(entity_scoped_created));
+        assert_eq!(
+            Some(&HealthPoints::new(50)),
+            world.read_storage::<HealthPoints>().get(entity_unscoped)
+        );
+    }
+
+    #[test]
+    fn remove_removes_registered_components_of_given_entities() {
+        let snapshot_registry = snapshot_registry();
+        let mut world = setup_world(&snapshot_registry);
+        world.register::<GamePlayEntity>();
+        let entity_removed = world
+            .create_entity()
+            .with(GamePlayEntity)
+            .with(Position::<f32>::new(1., 2., 3.))
+            .with(HealthPoints::new(100))
+            .build();
+        let entity_kept = world.create_entity().with(HealthPoints::new(50)).build();
+
+        let mut entities_removed = BitSet::new();
+        entities_removed.add(entity_removed.id());
+        snapshot_registry.remove(&world, &entities_removed);
+
+        assert!(world.is_alive(entity_removed));
+        assert_eq!(
+            None,
+            world.read_storage::<Position<f32>>().get(entity_removed)
+        );
+        assert_eq!(
+            None,
+            world.read_storage::<HealthPoints>().get(entity_removed)
+        );
+        assert!(world
+            .read_storage::<GamePlayEntity>()
+            .contains(entity_removed));
+        assert_eq!(
+            Some(&HealthPoints::new(50)),
+            world.read_storage::<HealthPoints>().get(entity_kept)
+        );
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "Snapshot storage key `position` is already registered for a different component type."
+    )]
+    fn register_panics_when_key_is_registered_for_different_type() {
+        let mut snapshot_registry = snapshot_registry();
+
+        snapshot_registry.register::<HealthPoints>("position");
+    } // kcov-ignore
+
+    #[test]
+    fn restores_mapped_component_when_referenced_entity_is_alive() {
+        let snapshot_registry = snapshot_registry();
+        let mut world = setup_world(&snapshot_registry);
+        let item = world.create_entity().build();
+        let holder = world.create_entity().with(HeldItem::new(item)).build();
+
+        let bytes = snapshot_registry
+            .capture(&world)
+            .expect("Failed to capture snapshot.");
+
+        world.write_storage::<HeldItem>().remove(holder);
+
+        snapshot_registry
+            .restore(&world, &bytes)
+            .expect("Failed to restore snapshot.");
+        world.maintain();
+
+        assert_eq!(
+            Some(&HeldItem::new(item)),
+            world.read_storage::<HeldItem>().get(holder)
+        );
+    }
+
+    #[test]
+    fn drops_mapped_component_when_referenced_entity_is_deleted() {
+        let snapshot_registry = snapshot_registry();
+        let mut world = setup_world(&snapshot_registry);
+        let item = world.create_entity().build();
+        let holder = world.create_entity().with(HeldItem::new(item)).build();
+
+        let bytes = snapshot_registry
+            .capture(&world)
+            .expect("Failed to capture snapshot.");
+
+        delete_entity(&mut world, item);
+
+        snapshot_registry
+            .restore(&world, &bytes)
+            .expect("Failed to restore snapshot.");
+        world.maintain();
+
+        assert!(world.is_alive(holder));
+        assert_eq!(None, world.read_storage::<HeldItem>().get(holder));
+    }
+
+    #[test]
+    fn restore_returns_error_when_registered_storage_is_missing() {
+        let mut snapshot_registry_captured = SnapshotRegistry::default();
+        snapshot_registry_captured.register::<HealthPoints>("health_points");
+        let mut world = setup_world(&snapshot_registry_captured);
+        world.create_entity().with(HealthPoints::new(100)).build();
+
+        let bytes = snapshot_registry_captured
+            .capture(&world)
+            .expect("Failed to capture snapshot.");
+
+        let snapshot_registry = snapshot_registry();
+        snapshot_registry.setup(&mut world);
+        let result = snapshot_registry.restore(&world, &bytes);
+
+        if let Err(SnapshotError::StorageMissing { key }) = result {
+            assert_eq!("position", key);
+        } else {
+            panic!(
+                "Expected `SnapshotError::StorageMissing` error, but was: `{:?}`.",
+                result
+            );
+        }
+    }
+
+    #[test]
+    fn restore_returns_error_when_bytes_are_invalid() {
+        let snapshot_registry = snapshot_registry();
+        let world = setup_world(&snapshot_registry);
+
+        let result = snapshot_registry.restore(&world, &[1, 2, 3]);
+
+        assert!(matches!(result, Err(SnapshotError::Deserialize(_))));
+    }
+
+    fn snapshot_registry() -> SnapshotRegistry {
+        let mut snapshot_registry = SnapshotRegistry::default();
+        snapshot_registry
+            .register::<Position<f32>>("position")
+            .register::<HealthPoints>("health_points")
+            .register_mapped::<HeldItem>("held_item");
+        snapshot_registry
+    }
+
+    fn setup_world(snapshot_registry: &SnapshotRegistry) -> World {
+        let mut world = World::new();
+        snapshot_registry.setup(&mut world);
+        world
+    }
+
+    fn delete_entity(world: &mut World, entity: Entity) {
+        world
+            .delete_entity(entity)
+            .expect("Failed to delete entity.");
+        world.maintain();
+    }
+}